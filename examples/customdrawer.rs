@@ -0,0 +1,213 @@
+//! A custom chart implemented outside the library through the `Drawer` trait.
+//!
+//! Run with a TrueType font, e.g.:
+//! `cargo run --example customdrawer -- /usr/share/fonts/truetype/dejavu/DejaVuSans.ttf`
+
+use std::any::Any;
+
+use dataviz::figure::{
+    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    drawers::drawer::Drawer,
+    utilities::{
+        axistype::AxisType,
+        linetype::LineType,
+        scaling::{data_bounds, scale_factor, tick_values, to_pixel_x, to_pixel_y},
+    },
+};
+
+/// A lollipop chart: one stem with a dot on top per category.
+struct LollipopChart {
+    title: String,
+    values: Vec<(String, f64)>,
+    color: [u8; 3],
+    config: FigureConfig,
+}
+
+impl LollipopChart {
+    /// Returns the value range, always including zero so stems start at the axis.
+    fn value_range(&self) -> (f64, f64) {
+        let points = self.values.iter().map(|(_, value)| (0.0, *value));
+        let (_, _, y_min, y_max) = data_bounds(points).unwrap_or((0.0, 0.0, 0.0, 1.0));
+        (y_min.min(0.0), y_max.max(0.0))
+    }
+}
+
+impl Drawer for LollipopChart {
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        let cfg = &self.config;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+        let margin = canvas.margin as f64;
+
+        canvas.set_transparent(cfg.transparent_background);
+
+        self.fill_background(canvas, cfg);
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let (y_min, y_max) = self.value_range();
+        let scale_x = scale_factor(0.0, self.values.len() as f64, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, height - 2.0 * margin);
+        let base_y = to_pixel_y(0.0, y_min, scale_y, height, margin) as i32;
+
+        // Value axis with formatted ticks
+        for value in tick_values(y_min, y_max, cfg.num_axis_ticks) {
+            let y = to_pixel_y(value, y_min, scale_y, height, margin) as u32;
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.1}"));
+            self.draw_axis_value(canvas, cfg, canvas.margin - 10, y, &label, AxisType::AxisY);
+        }
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            margin as i32,
+            margin as i32,
+            (height - margin) as i32,
+        );
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            base_y,
+            (width - margin) as i32,
+            base_y,
+        );
+
+        // Stems, heads and category labels
+        for (index, (category, value)) in self.values.iter().enumerate() {
+            let x = to_pixel_x(index as f64 + 0.5, 0.0, scale_x, margin) as i32;
+            let y = to_pixel_y(*value, y_min, scale_y, height, margin) as i32;
+            canvas.draw_line(x, base_y, x, y, self.color, LineType::Solid);
+
+            for dy in -4..=4_i32 {
+                for dx in -4..=4_i32 {
+                    if dx * dx + dy * dy <= 16 {
+                        canvas.draw_pixel((x + dx) as u32, (y + dy) as u32, self.color);
+                    }
+                }
+            }
+
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                x as u32,
+                canvas.height - canvas.margin,
+                category,
+                AxisType::AxisX,
+            );
+        }
+
+        self.draw_axis_units(canvas, cfg);
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // A single series needs no legend
+    }
+
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let cfg = &self.config;
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let color = self.rgb_to_svg_color(self.color);
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+        self.fill_svg_background(svg_canvas, cfg);
+        svg_canvas.draw_title(width / 2.0, margin / 2.0, &self.title, 24.0, "black");
+
+        let (y_min, y_max) = self.value_range();
+        let scale_x = scale_factor(0.0, self.values.len() as f64, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, height - 2.0 * margin);
+        let base_y = to_pixel_y(0.0, y_min, scale_y, height, margin);
+
+        for value in tick_values(y_min, y_max, cfg.num_axis_ticks) {
+            let y = to_pixel_y(value, y_min, scale_y, height, margin);
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.1}"));
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+        svg_canvas.draw_line(margin, margin, margin, height - margin, "black", 2.0);
+        svg_canvas.draw_line(margin, base_y, width - margin, base_y, "black", 2.0);
+
+        for (index, (category, value)) in self.values.iter().enumerate() {
+            let x = to_pixel_x(index as f64 + 0.5, 0.0, scale_x, margin);
+            let y = to_pixel_y(*value, y_min, scale_y, height, margin);
+            svg_canvas.draw_line(x, base_y, x, y, &color, 2.0);
+            svg_canvas.draw_circle(x, y, 5.0, &color);
+            svg_canvas.draw_tick_label(
+                x,
+                height - margin + font_size * 1.5,
+                category,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+
+        self.draw_svg_axis_units(svg_canvas, cfg, font_size);
+    }
+}
+
+fn main() {
+    let Some(font_path) = std::env::args().nth(1) else {
+        eprintln!("usage: customdrawer <font.ttf>");
+        return;
+    };
+
+    let mut config = FigureConfig::default();
+    config.set_font_paths(font_path.clone(), font_path);
+    config.set_unit(AxisType::AxisY, "ms");
+
+    let mut chart = LollipopChart {
+        title: "Request Latency".to_string(),
+        values: vec![
+            ("auth".to_string(), 42.0),
+            ("search".to_string(), 118.0),
+            ("upload".to_string(), 260.0),
+            ("render".to_string(), 75.0),
+        ],
+        color: [200, 60, 60],
+        config,
+    };
+
+    let mut canvas = PixelCanvas::new(800, 600, [255, 255, 255], 80);
+    chart.draw(&mut canvas);
+    canvas.save_as_image("lollipop.png");
+
+    let mut svg_canvas = SvgCanvas::new(800, 600, "white", 80);
+    chart.draw_svg(&mut svg_canvas);
+    svg_canvas.save("lollipop.svg").expect("Failed to save SVG");
+}
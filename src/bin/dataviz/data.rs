@@ -0,0 +1,200 @@
+//! Tabular data loaded from CSV or JSON files.
+
+use std::{fs, path::Path};
+
+use dataviz::figure::utilities::json::{self, JsonValue};
+
+/// A table of named columns, with every cell kept as text until a chart asks for numbers.
+pub struct Table {
+    /// Column names, in file order.
+    pub columns: Vec<String>,
+    /// Rows of cells, each as long as `columns`.
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Loads a table from a `.csv` or `.json` file, chosen by extension.
+    ///
+    /// # Errors
+    /// Returns a message naming the file if it cannot be read or parsed.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text =
+            fs::read_to_string(path).map_err(|error| format!("{}: {}", path.display(), error))?;
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or("")
+            .to_ascii_lowercase();
+
+        let table = match extension.as_str() {
+            "csv" => Self::from_csv(&text),
+            "json" => Self::from_json(&text),
+            _ => Err("unsupported data format, expected .csv or .json".to_string()),
+        };
+        table.map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    /// Parses CSV text whose first record holds the column names.
+    ///
+    /// Fields may be quoted with `"`, with `""` standing for a literal quote inside them.
+    pub fn from_csv(text: &str) -> Result<Self, String> {
+        let mut records = parse_csv(text)?.into_iter();
+        let columns = records.next().ok_or("CSV file is empty")?;
+
+        let mut rows = Vec::new();
+        for (index, record) in records.enumerate() {
+            if record.len() == 1 && record[0].is_empty() {
+                continue;
+            }
+            if record.len() != columns.len() {
+                return Err(format!(
+                    "CSV record {} has {} fields, expected {}",
+                    index + 2,
+                    record.len(),
+                    columns.len()
+                ));
+            }
+            rows.push(record);
+        }
+        Ok(Self { columns, rows })
+    }
+
+    /// Parses a JSON array of records (`[{"x": 1, "y": 2}, ...]`) or an object of columns
+    /// (`{"x": [1, ...], "y": [2, ...]}`).
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        match json::parse(text)? {
+            JsonValue::Array(records) => {
+                let mut columns: Vec<String> = Vec::new();
+                for record in &records {
+                    let JsonValue::Object(members) = record else {
+                        return Err("expected an array of objects".to_string());
+                    };
+                    for (name, _) in members {
+                        if !columns.contains(name) {
+                            columns.push(name.clone());
+                        }
+                    }
+                }
+                let rows = records
+                    .iter()
+                    .map(|record| {
+                        columns
+                            .iter()
+                            .map(|name| record.get(name).map(cell_text).unwrap_or_default())
+                            .collect()
+                    })
+                    .collect();
+                Ok(Self { columns, rows })
+            }
+            JsonValue::Object(members) => {
+                let mut columns = Vec::new();
+                let mut cells = Vec::new();
+                for (name, value) in members {
+                    let values = value
+                        .as_array()
+                        .ok_or_else(|| format!("column \"{name}\" is not an array"))?;
+                    columns.push(name);
+                    cells.push(values.iter().map(cell_text).collect::<Vec<_>>());
+                }
+                let row_count = cells.iter().map(Vec::len).max().unwrap_or(0);
+                let rows = (0..row_count)
+                    .map(|row| {
+                        cells
+                            .iter()
+                            .map(|column| column.get(row).cloned().unwrap_or_default())
+                            .collect()
+                    })
+                    .collect();
+                Ok(Self { columns, rows })
+            }
+            _ => Err("expected an array of records or an object of columns".to_string()),
+        }
+    }
+
+    /// Returns the cells of the column `name` as text.
+    pub fn text(&self, name: &str) -> Result<Vec<&str>, String> {
+        let index = self.index(name)?;
+        Ok(self.rows.iter().map(|row| row[index].as_str()).collect())
+    }
+
+    /// Returns the cells of the column `name` as numbers.
+    ///
+    /// # Errors
+    /// Returns a message naming the row if a cell is not a number.
+    pub fn numbers(&self, name: &str) -> Result<Vec<f64>, String> {
+        let index = self.index(name)?;
+        self.rows
+            .iter()
+            .enumerate()
+            .map(|(row, cells)| {
+                cells[index].trim().parse::<f64>().map_err(|_| {
+                    format!(
+                        "column \"{}\", row {}: \"{}\" is not a number",
+                        name,
+                        row + 1,
+                        cells[index]
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn index(&self, name: &str) -> Result<usize, String> {
+        self.columns
+            .iter()
+            .position(|column| column == name)
+            .ok_or_else(|| format!("no column named \"{name}\""))
+    }
+}
+
+/// Converts a JSON scalar into the text of a cell.
+fn cell_text(value: &JsonValue) -> String {
+    match value {
+        JsonValue::Null => String::new(),
+        JsonValue::Bool(flag) => flag.to_string(),
+        JsonValue::Number(number) => number.to_string(),
+        JsonValue::String(text) => text.clone(),
+        JsonValue::Array(_) | JsonValue::Object(_) => String::new(),
+    }
+}
+
+/// Splits CSV text into records of unquoted fields.
+fn parse_csv(text: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => quoted = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' => quoted = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if quoted {
+        return Err("unterminated quoted field".to_string());
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
@@ -0,0 +1,321 @@
+//! Headless batch renderer: reads declarative chart specs and writes PNG and SVG files.
+//!
+//! Build with `cargo build --release --features cli`, then run
+//! `dataviz [--jobs N] [--font FILE] SPEC.json...`.
+//!
+//! A spec file holds one chart object or an array of them, e.g.:
+//!
+//! ```json
+//! {
+//!   "type": "line",
+//!   "title": "Monthly Revenue",
+//!   "x_label": "Month",
+//!   "y_label": "Revenue",
+//!   "data": "revenue.csv",
+//!   "series": [
+//!     { "label": "2023", "x": "month", "y": "revenue_2023", "color": "#1f77b4" },
+//!     { "label": "2024", "x": "month", "y": "revenue_2024" }
+//!   ],
+//!   "outputs": ["revenue.png", "revenue.svg"]
+//! }
+//! ```
+//!
+//! Chart types are `line`, `scatter`, `area`, `bar`, `pie` and `histogram`. Optional fields
+//! are `width`, `height`, `margin`, `background`, `font` and `bins`, and `visible` on a
+//! series of a line, scatter, area or bar chart to hide it. Data and output paths
+//! are relative to the spec file; data is read from CSV (with a header row) or JSON (an
+//! array of records or an object of columns).
+
+mod data;
+mod spec;
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use dataviz::figure::{
+    canvas::{pixelcanvas::PixelCanvas, pngmetadata::PngMetadata, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    datasets::{
+        areachartdataset::AreaChartDataset, bardataset::BarDataset,
+        cartesiangraphdataset::CartesianDataset, scattergraphdataset::ScatterGraphDataset,
+    },
+    drawers::drawer::Drawer,
+    figuretypes::{
+        areachart::AreaChart, cartesiangraph::CartesianGraph, groupbarchart::GroupBarChart,
+        histogram::Histogram, piechart::PieChart, scattergraph::ScatterGraph,
+    },
+    utilities::{linetype::LineType, orientation::Orientation, scatterdottype::ScatterDotType},
+};
+
+use data::Table;
+use spec::{ChartKind, ChartSpec};
+
+const USAGE: &str = "usage: dataviz [--jobs N] [--font FILE] SPEC.json...";
+
+/// Command line options.
+struct Options {
+    /// Number of charts rendered at the same time.
+    jobs: usize,
+    /// Font used by charts whose spec does not name one.
+    font: Option<PathBuf>,
+    specs: Vec<PathBuf>,
+}
+
+fn parse_args() -> Result<Options, String> {
+    let mut options = Options {
+        jobs: thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+        font: std::env::var_os("DATAVIZ_FONT").map(PathBuf::from),
+        specs: Vec::new(),
+    };
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-j" | "--jobs" => {
+                options.jobs = args
+                    .next()
+                    .and_then(|jobs| jobs.parse().ok())
+                    .filter(|&jobs| jobs > 0)
+                    .ok_or("--jobs expects a positive number")?;
+            }
+            "--font" => {
+                options.font = Some(args.next().ok_or("--font expects a file")?.into());
+            }
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ if arg.starts_with('-') => return Err(format!("unknown option {arg}\n{USAGE}")),
+            _ => options.specs.push(arg.into()),
+        }
+    }
+    if options.specs.is_empty() {
+        return Err(USAGE.to_string());
+    }
+    Ok(options)
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args() {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut charts = Vec::new();
+    let mut failed = false;
+    for path in &options.specs {
+        match spec::load(path) {
+            Ok(specs) => charts.extend(specs),
+            Err(error) => {
+                eprintln!("error: {error}");
+                failed = true;
+            }
+        }
+    }
+
+    // Workers take the next chart from a shared counter, so a slow chart does not hold up
+    // the ones queued behind it.
+    let next = AtomicUsize::new(0);
+    let errors = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(charts.len()) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(chart) = charts.get(index) else {
+                    break;
+                };
+                match render(chart, options.font.as_deref()) {
+                    Ok(outputs) => {
+                        for output in outputs {
+                            println!("{}", output.display());
+                        }
+                    }
+                    Err(error) => errors.lock().expect("Error list is poisoned").push(format!(
+                        "{}: {}",
+                        chart.source.display(),
+                        error
+                    )),
+                }
+            });
+        }
+    });
+
+    for error in errors.into_inner().expect("Error list is poisoned") {
+        eprintln!("error: {error}");
+        failed = true;
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Renders one chart to all of its outputs.
+///
+/// # Returns
+/// The paths written, or a message describing the first problem.
+fn render(chart: &ChartSpec, default_font: Option<&Path>) -> Result<Vec<PathBuf>, String> {
+    let table = Table::load(&chart.data)?;
+
+    let mut config = FigureConfig::default();
+    if let Some(font) = chart.font.as_deref().or(default_font) {
+        let font = font.to_string_lossy().into_owned();
+        config.set_font_paths(font.clone(), font);
+    }
+
+    let mut figure = build_figure(chart, &table, config)?;
+
+    let mut written = Vec::new();
+    for output in &chart.outputs {
+        let extension = output
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        let raster = match extension.as_str() {
+            "png" => true,
+            "svg" => false,
+            _ => {
+                return Err(format!(
+                    "{}: expected a .png or .svg output",
+                    output.display()
+                ))
+            }
+        };
+        // Drawers panic on invalid settings, so check them here to report an error instead
+        figure
+            .get_figure_config()
+            .validate_for_canvas(chart.width, chart.height, chart.margin, raster)
+            .map_err(|problems| format!("{}: {}", output.display(), problems))?;
+
+        if let Some(parent) = output
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent)
+                .map_err(|error| format!("{}: {}", parent.display(), error))?;
+        }
+        let path = output.to_string_lossy();
+        let result = if raster {
+            let mut canvas =
+                PixelCanvas::new(chart.width, chart.height, chart.background, chart.margin);
+            figure.draw(&mut canvas);
+            // No creation time, so unchanged specs produce identical files
+            let metadata = PngMetadata {
+                title: Some(chart.title.clone()),
+                spec: Some(chart.text.clone()),
+                ..PngMetadata::default()
+            };
+            canvas.save_as_png_with_metadata(&path, &metadata)
+        } else {
+            let [r, g, b] = chart.background;
+            let mut canvas = SvgCanvas::new(
+                chart.width,
+                chart.height,
+                &format!("#{r:02x}{g:02x}{b:02x}"),
+                chart.margin,
+            );
+            figure.draw_svg(&mut canvas);
+            canvas.save(&path)
+        };
+        result.map_err(|error| format!("{}: {}", output.display(), error))?;
+        written.push(output.clone());
+    }
+    Ok(written)
+}
+
+/// Builds the figure described by a spec from the columns of its table.
+fn build_figure(
+    chart: &ChartSpec,
+    table: &Table,
+    config: FigureConfig,
+) -> Result<Box<dyn Drawer>, String> {
+    let points = |series: &spec::SeriesSpec| -> Result<Vec<(f64, f64)>, String> {
+        let y = table.numbers(&series.y)?;
+        let x = match &series.x {
+            Some(column) => table.numbers(column)?,
+            None => (1..=y.len()).map(|row| row as f64).collect(),
+        };
+        Ok(x.into_iter().zip(y).collect())
+    };
+    let (title, x_label, y_label) = (&chart.title, &chart.x_label, &chart.y_label);
+
+    Ok(match chart.kind {
+        ChartKind::Line => {
+            let mut figure = CartesianGraph::new(title, x_label, y_label, &config);
+            for series in &chart.series {
+                let mut dataset =
+                    CartesianDataset::new(series.color, &series.label, LineType::Solid);
+                dataset.points = points(series)?;
+                dataset.visible = series.visible;
+                figure.add_dataset(dataset);
+            }
+            Box::new(figure)
+        }
+        ChartKind::Scatter => {
+            let mut figure = ScatterGraph::new(title, x_label, y_label, config);
+            for series in &chart.series {
+                let mut dataset = ScatterGraphDataset::new(
+                    series.color,
+                    &series.label,
+                    ScatterDotType::Circle(4),
+                );
+                dataset.points = points(series)?;
+                dataset.visible = series.visible;
+                figure.add_dataset(dataset);
+            }
+            Box::new(figure)
+        }
+        ChartKind::Area => {
+            let mut figure = AreaChart::new(title, x_label, y_label, config);
+            for series in &chart.series {
+                let mut dataset = AreaChartDataset::new(series.color, &series.label, 0.5);
+                dataset.points = points(series)?;
+                dataset.visible = series.visible;
+                figure.add_dataset(dataset);
+            }
+            Box::new(figure)
+        }
+        ChartKind::Bar => {
+            let mut figure =
+                GroupBarChart::new(title, x_label, y_label, Orientation::Vertical, config);
+            for series in &chart.series {
+                let mut dataset = BarDataset::new(&series.label, series.color);
+                for (x, y) in points(series)? {
+                    dataset.add_data(x, y);
+                }
+                dataset.visible = series.visible;
+                figure.add_dataset(dataset);
+            }
+            Box::new(figure)
+        }
+        ChartKind::Pie => {
+            let series = &chart.series[0];
+            let values = table.numbers(&series.y)?;
+            let labels = match &series.x {
+                Some(column) => table.text(column)?,
+                None => vec![series.label.as_str(); values.len()],
+            };
+            let mut figure = PieChart::new(title, config);
+            for (index, (label, value)) in labels.into_iter().zip(values).enumerate() {
+                figure.add_slice(label, value, spec::palette_color(index));
+            }
+            Box::new(figure)
+        }
+        ChartKind::Histogram => {
+            let series = &chart.series[0];
+            let mut figure =
+                Histogram::new(title, x_label, y_label, chart.bins, series.color, config);
+            figure.add_data_vec(table.numbers(&series.y)?);
+            Box::new(figure)
+        }
+    })
+}
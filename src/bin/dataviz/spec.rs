@@ -0,0 +1,288 @@
+//! Declarative chart specifications read from JSON files.
+
+use std::path::{Path, PathBuf};
+
+use dataviz::figure::utilities::json::{self, JsonValue};
+
+/// Series colors used when a spec does not name one.
+const PALETTE: [[u8; 3]; 8] = [
+    [31, 119, 180],
+    [255, 127, 14],
+    [44, 160, 44],
+    [214, 39, 40],
+    [148, 103, 189],
+    [140, 86, 75],
+    [227, 119, 194],
+    [127, 127, 127],
+];
+
+/// The chart types a spec can describe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChartKind {
+    /// A `CartesianGraph` with one line per series.
+    Line,
+    /// A `ScatterGraph` with one dot style per series.
+    Scatter,
+    /// An `AreaChart` with one filled area per series.
+    Area,
+    /// A vertical `GroupBarChart` with one bar group per series.
+    Bar,
+    /// A `PieChart` with one slice per row of the first series, labeled by its `x` column.
+    Pie,
+    /// A `Histogram` of the `y` column of the first series.
+    Histogram,
+}
+
+/// One series of a chart: the columns it is read from and how it is drawn.
+#[derive(Clone, Debug)]
+pub struct SeriesSpec {
+    /// Legend label of the series.
+    pub label: String,
+    /// Column with the x values, or slice labels for pie charts. Row numbers are used
+    /// when missing.
+    pub x: Option<String>,
+    /// Column with the y values.
+    pub y: String,
+    /// RGB color of the series.
+    pub color: [u8; 3],
+    /// Whether the series is drawn and listed in the legend; hidden series keep their
+    /// palette color so variants of a chart match.
+    pub visible: bool,
+}
+
+/// A chart to render, with all paths resolved against the directory of its spec file.
+#[derive(Clone, Debug)]
+pub struct ChartSpec {
+    /// Spec file the chart was read from, for error messages.
+    pub source: PathBuf,
+    /// JSON text of the chart, embedded into PNG outputs.
+    pub text: String,
+    pub kind: ChartKind,
+    pub title: String,
+    pub x_label: String,
+    pub y_label: String,
+    pub width: u32,
+    pub height: u32,
+    pub margin: u32,
+    /// Background color of the canvas.
+    pub background: [u8; 3],
+    /// TrueType font for titles and labels.
+    pub font: Option<PathBuf>,
+    /// CSV or JSON file with the data.
+    pub data: PathBuf,
+    pub series: Vec<SeriesSpec>,
+    /// Number of bins, for histograms.
+    pub bins: usize,
+    /// Files to write, as `.png` or `.svg`.
+    pub outputs: Vec<PathBuf>,
+}
+
+/// Reads a spec file holding one chart object or an array of them.
+///
+/// # Parameters
+/// - `path`: The spec file.
+///
+/// # Errors
+/// Returns a message naming the file and the chart if the file cannot be read or a field is
+/// missing or invalid.
+pub fn load(path: &Path) -> Result<Vec<ChartSpec>, String> {
+    let text =
+        std::fs::read_to_string(path).map_err(|error| format!("{}: {}", path.display(), error))?;
+    let document = json::parse(&text).map_err(|error| format!("{}: {}", path.display(), error))?;
+    let base = path.parent().unwrap_or(Path::new(""));
+
+    let charts = match &document {
+        JsonValue::Array(charts) => charts.iter().collect(),
+        _ => vec![&document],
+    };
+    charts
+        .into_iter()
+        .enumerate()
+        .map(|(index, chart)| {
+            parse_chart(chart, path, base)
+                .map_err(|error| format!("{} (chart {}): {}", path.display(), index + 1, error))
+        })
+        .collect()
+}
+
+fn parse_chart(chart: &JsonValue, source: &Path, base: &Path) -> Result<ChartSpec, String> {
+    let JsonValue::Object(_) = chart else {
+        return Err("expected an object".to_string());
+    };
+
+    let kind = match required_str(chart, "type")? {
+        "line" | "cartesian" => ChartKind::Line,
+        "scatter" => ChartKind::Scatter,
+        "area" => ChartKind::Area,
+        "bar" => ChartKind::Bar,
+        "pie" => ChartKind::Pie,
+        "histogram" => ChartKind::Histogram,
+        other => return Err(format!("unknown chart type \"{other}\"")),
+    };
+
+    let series = chart
+        .get("series")
+        .and_then(JsonValue::as_array)
+        .ok_or("missing \"series\" array")?
+        .iter()
+        .enumerate()
+        .map(|(index, series)| parse_series(series, index))
+        .collect::<Result<Vec<_>, _>>()?;
+    if series.is_empty() {
+        return Err("\"series\" is empty".to_string());
+    }
+
+    let outputs = chart
+        .get("outputs")
+        .and_then(JsonValue::as_array)
+        .ok_or("missing \"outputs\" array")?
+        .iter()
+        .map(|output| {
+            output
+                .as_str()
+                .map(|output| base.join(output))
+                .ok_or_else(|| "\"outputs\" must hold file names".to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if outputs.is_empty() {
+        return Err("\"outputs\" is empty".to_string());
+    }
+
+    Ok(ChartSpec {
+        source: source.to_path_buf(),
+        text: json_text(chart),
+        kind,
+        title: optional_str(chart, "title")?.unwrap_or_default(),
+        x_label: optional_str(chart, "x_label")?.unwrap_or_default(),
+        y_label: optional_str(chart, "y_label")?.unwrap_or_default(),
+        width: optional_u32(chart, "width")?.unwrap_or(800),
+        height: optional_u32(chart, "height")?.unwrap_or(600),
+        margin: optional_u32(chart, "margin")?.unwrap_or(80),
+        background: match chart.get("background") {
+            Some(color) => parse_color(color)?,
+            None => [255, 255, 255],
+        },
+        font: optional_str(chart, "font")?.map(|font| base.join(font)),
+        data: base.join(required_str(chart, "data")?),
+        series,
+        bins: optional_u32(chart, "bins")?.unwrap_or(10).max(1) as usize,
+        outputs,
+    })
+}
+
+fn parse_series(series: &JsonValue, index: usize) -> Result<SeriesSpec, String> {
+    let y = required_str(series, "y").map_err(|error| format!("series {}: {error}", index + 1))?;
+    Ok(SeriesSpec {
+        label: optional_str(series, "label")?.unwrap_or_else(|| y.to_string()),
+        x: optional_str(series, "x")?,
+        y: y.to_string(),
+        color: match series.get("color") {
+            Some(color) => parse_color(color)?,
+            None => palette_color(index),
+        },
+        visible: optional_bool(series, "visible")?.unwrap_or(true),
+    })
+}
+
+/// Returns the default color of the series or pie slice at `index`.
+pub fn palette_color(index: usize) -> [u8; 3] {
+    PALETTE[index % PALETTE.len()]
+}
+
+/// Parses a color given as `"#rrggbb"` or as an `[r, g, b]` array.
+fn parse_color(value: &JsonValue) -> Result<[u8; 3], String> {
+    let invalid = || "colors must be \"#rrggbb\" or [r, g, b]".to_string();
+    if let Some(hex) = value.as_str() {
+        let hex = hex.strip_prefix('#').ok_or_else(invalid)?;
+        if hex.len() != 6 {
+            return Err(invalid());
+        }
+        let channel = |range| u8::from_str_radix(&hex[range], 16).map_err(|_| invalid());
+        return Ok([channel(0..2)?, channel(2..4)?, channel(4..6)?]);
+    }
+
+    let channels = value.as_array().ok_or_else(invalid)?;
+    if channels.len() != 3 {
+        return Err(invalid());
+    }
+    let mut color = [0; 3];
+    for (target, channel) in color.iter_mut().zip(channels) {
+        *target = channel
+            .as_f64()
+            .filter(|channel| (0.0..=255.0).contains(channel))
+            .ok_or_else(invalid)? as u8;
+    }
+    Ok(color)
+}
+
+fn required_str<'a>(object: &'a JsonValue, key: &str) -> Result<&'a str, String> {
+    object
+        .get(key)
+        .and_then(JsonValue::as_str)
+        .ok_or_else(|| format!("missing string \"{key}\""))
+}
+
+fn optional_str(object: &JsonValue, key: &str) -> Result<Option<String>, String> {
+    match object.get(key) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(value) => value
+            .as_str()
+            .map(|text| Some(text.to_string()))
+            .ok_or_else(|| format!("\"{key}\" must be a string")),
+    }
+}
+
+fn optional_bool(object: &JsonValue, key: &str) -> Result<Option<bool>, String> {
+    match object.get(key) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(JsonValue::Bool(flag)) => Ok(Some(*flag)),
+        Some(_) => Err(format!("\"{key}\" must be true or false")),
+    }
+}
+
+fn optional_u32(object: &JsonValue, key: &str) -> Result<Option<u32>, String> {
+    match object.get(key) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(value) => value
+            .as_f64()
+            .filter(|number| number.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(number))
+            .map(|number| Some(number as u32))
+            .ok_or_else(|| format!("\"{key}\" must be a non-negative integer")),
+    }
+}
+
+/// Serializes a JSON value compactly.
+fn json_text(value: &JsonValue) -> String {
+    match value {
+        JsonValue::Null => "null".to_string(),
+        JsonValue::Bool(flag) => flag.to_string(),
+        JsonValue::Number(number) => number.to_string(),
+        JsonValue::String(text) => json_string(text),
+        JsonValue::Array(values) => {
+            let values: Vec<String> = values.iter().map(json_text).collect();
+            format!("[{}]", values.join(","))
+        }
+        JsonValue::Object(members) => {
+            let members: Vec<String> = members
+                .iter()
+                .map(|(name, value)| format!("{}:{}", json_string(name), json_text(value)))
+                .collect();
+            format!("{{{}}}", members.join(","))
+        }
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
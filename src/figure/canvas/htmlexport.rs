@@ -0,0 +1,107 @@
+//! Standalone interactive HTML pages around SVG output.
+//!
+//! The page embeds the SVG inline together with a small script, so it can be shared as a
+//! single file and opened without a server. The script is driven by attributes the drawers
+//! emit through `SvgCanvas`:
+//! - Elements with a `data-tooltip` attribute show its text in a tooltip on hover.
+//! - Clicking a legend entry (class `dataviz-legend-item`) hides or shows every series group
+//!   (class `dataviz-series`) with the same `data-series` label, unless the entry is a link.
+
+use crate::figure::canvas::svgcanvas::{escape_xml, LEGEND_ITEM_CLASS, SERIES_CLASS};
+
+/// Styles of the tooltip and the legend entries.
+const STYLE: &str = r#"
+body { margin: 0; padding: 16px; font-family: sans-serif; }
+.dataviz-figure { position: relative; }
+.dataviz-figure svg { width: 100%; height: auto; display: block; }
+.dataviz-legend-item { cursor: pointer; }
+.dataviz-legend-item.dataviz-off { opacity: 0.35; }
+[data-tooltip] { cursor: crosshair; }
+#dataviz-tooltip {
+  position: absolute; display: none; pointer-events: none; white-space: pre;
+  padding: 2px 6px; font-size: 12px; background: rgba(255, 255, 255, 0.95);
+  border: 1px solid #333; border-radius: 3px;
+}
+"#;
+
+/// Script wiring the tooltips and the series toggling.
+const SCRIPT: &str = r#"
+(function () {
+  var tooltip = document.getElementById('dataviz-tooltip');
+  document.querySelectorAll('[data-tooltip]').forEach(function (element) {
+    element.addEventListener('mousemove', function (event) {
+      tooltip.textContent = element.getAttribute('data-tooltip');
+      tooltip.style.left = (event.pageX + 12) + 'px';
+      tooltip.style.top = (event.pageY - 28) + 'px';
+      tooltip.style.display = 'block';
+    });
+    element.addEventListener('mouseleave', function () {
+      tooltip.style.display = 'none';
+    });
+  });
+  document.querySelectorAll('.LEGEND_ITEM_CLASS').forEach(function (item) {
+    item.addEventListener('click', function (event) {
+      // Linked entries navigate instead of toggling
+      if (event.target.closest('a')) {
+        return;
+      }
+      var series = item.getAttribute('data-series');
+      var hidden = item.classList.toggle('dataviz-off');
+      document.querySelectorAll('.SERIES_CLASS').forEach(function (group) {
+        if (group.getAttribute('data-series') === series) {
+          group.style.display = hidden ? 'none' : '';
+        }
+      });
+    });
+  });
+})();
+"#;
+
+/// Wraps an SVG document in a standalone HTML page with hover tooltips and series toggling.
+///
+/// # Parameters
+/// - `svg`: The SVG document, as returned by `SvgCanvas::get_svg_as_text`.
+/// - `title`: The page title.
+/// - `max_width`: The largest width the figure is shown at, in CSS pixels. Narrower
+///   windows scale the figure down.
+///
+/// # Returns
+/// The complete HTML page.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::canvas::htmlexport::to_html;
+///
+/// let html = to_html(&svg_canvas.get_svg_as_text(), "Sales", svg_canvas.width);
+/// ```
+pub fn to_html(svg: &str, title: &str, max_width: u32) -> String {
+    // The XML declaration is not allowed inside an HTML document
+    let svg = match svg.trim_start().strip_prefix("<?xml") {
+        Some(rest) => rest.split_once("?>").map_or(rest, |(_, body)| body),
+        None => svg,
+    };
+    let script = SCRIPT
+        .replace("LEGEND_ITEM_CLASS", LEGEND_ITEM_CLASS)
+        .replace("SERIES_CLASS", SERIES_CLASS);
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="UTF-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{}</title>
+<style>{STYLE}.dataviz-figure {{ max-width: {max_width}px; }}
+</style>
+</head>
+<body>
+<div class="dataviz-figure">{}</div>
+<div id="dataviz-tooltip"></div>
+<script>{script}</script>
+</body>
+</html>
+"#,
+        escape_xml(title),
+        svg.trim()
+    )
+}
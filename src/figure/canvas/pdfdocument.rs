@@ -0,0 +1,554 @@
+//! A minimal PDF writer for figures and text.
+//!
+//! Pages hold raster images, lines and text in the standard Helvetica fonts, which every PDF
+//! viewer provides, so no font files are embedded. Coordinates are in points (1/72 inch)
+//! with the origin at the top-left corner of the page and y growing downwards, like the
+//! canvases. Text is encoded in WinAnsi (Latin-1 plus typographic punctuation); other
+//! characters are written as `?`.
+
+use std::{
+    fs::File,
+    io::{self, Write},
+};
+
+use flate2::{write::ZlibEncoder, Compression};
+
+/// Width of an A4 page in points.
+pub const A4_WIDTH: f64 = 595.28;
+/// Height of an A4 page in points.
+pub const A4_HEIGHT: f64 = 841.89;
+
+/// Advance widths of the printable ASCII characters in Helvetica, in 1/1000 em.
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/// Advance widths of the printable ASCII characters in Helvetica-Bold, in 1/1000 em.
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611, 975, 722, 722, 722, 722, 667,
+    611, 778, 722, 278, 556, 722, 611, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 333, 278, 333, 584, 556, 333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556,
+    278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+/// The standard fonts available for text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PdfFont {
+    /// Helvetica.
+    Regular,
+    /// Helvetica-Bold.
+    Bold,
+    /// Helvetica-Oblique.
+    Italic,
+}
+
+impl PdfFont {
+    /// Name of the font resource in page content streams.
+    fn resource(&self) -> &'static str {
+        match self {
+            PdfFont::Regular => "F1",
+            PdfFont::Bold => "F2",
+            PdfFont::Italic => "F3",
+        }
+    }
+
+    /// PostScript name of the standard font.
+    fn base_font(&self) -> &'static str {
+        match self {
+            PdfFont::Regular => "Helvetica",
+            PdfFont::Bold => "Helvetica-Bold",
+            PdfFont::Italic => "Helvetica-Oblique",
+        }
+    }
+
+    /// Measures the width of a text in points.
+    ///
+    /// # Parameters
+    /// - `text`: The text to measure.
+    /// - `size`: The font size in points.
+    pub fn text_width(&self, text: &str, size: f64) -> f64 {
+        let widths = match self {
+            PdfFont::Bold => &HELVETICA_BOLD_WIDTHS,
+            PdfFont::Regular | PdfFont::Italic => &HELVETICA_WIDTHS,
+        };
+        let units: u32 = text
+            .chars()
+            .map(|c| match c {
+                ' '..='~' => widths[c as usize - 32] as u32,
+                _ => 556,
+            })
+            .sum();
+        units as f64 * size / 1000.0
+    }
+}
+
+/// An internal link from a rectangle on one page to a position on another.
+struct PdfLink {
+    rect: (f64, f64, f64, f64),
+    page: usize,
+    y: f64,
+}
+
+/// A page and the operators drawn on it.
+struct PdfPage {
+    width: f64,
+    height: f64,
+    content: String,
+    images: Vec<usize>,
+    links: Vec<PdfLink>,
+}
+
+/// An RGB raster image, compressed once and drawn on any number of pages.
+struct PdfImage {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+/// An entry of the document outline (bookmarks) jumping to a position on a page.
+struct PdfOutlineEntry {
+    title: String,
+    page: usize,
+    y: f64,
+}
+
+/// A PDF document assembled page by page.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::canvas::pdfdocument::{PdfDocument, PdfFont, A4_HEIGHT, A4_WIDTH};
+///
+/// let mut pdf = PdfDocument::new();
+/// let page = pdf.add_page(A4_WIDTH, A4_HEIGHT);
+/// let image = pdf.add_image(canvas.width, canvas.height, &canvas.buffer);
+/// pdf.draw_text(page, 56.0, 56.0, "Monthly Revenue", PdfFont::Bold, 18.0);
+/// pdf.draw_image(page, image, 56.0, 80.0, 480.0, 360.0);
+/// pdf.save("revenue.pdf")?;
+/// ```
+#[derive(Default)]
+pub struct PdfDocument {
+    title: Option<String>,
+    pages: Vec<PdfPage>,
+    images: Vec<PdfImage>,
+    outline: Vec<PdfOutlineEntry>,
+}
+
+impl PdfDocument {
+    /// Creates an empty document.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title shown by PDF viewers instead of the file name.
+    pub fn set_title(&mut self, title: &str) {
+        self.title = Some(title.to_string());
+    }
+
+    /// Returns the number of pages.
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Appends a blank page.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The page size in points, e.g. `A4_WIDTH` and `A4_HEIGHT`.
+    ///
+    /// # Returns
+    /// The index of the new page.
+    pub fn add_page(&mut self, width: f64, height: f64) -> usize {
+        self.pages.push(PdfPage {
+            width,
+            height,
+            content: String::new(),
+            images: Vec::new(),
+            links: Vec::new(),
+        });
+        self.pages.len() - 1
+    }
+
+    /// Adds an image to the document, to be drawn with `draw_image`.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The image size in pixels.
+    /// - `rgb`: The pixels, row by row, 3 bytes per pixel, e.g. `PixelCanvas::buffer`.
+    ///
+    /// # Returns
+    /// The index of the image.
+    pub fn add_image(&mut self, width: u32, height: u32, rgb: &[u8]) -> usize {
+        self.images.push(PdfImage {
+            width,
+            height,
+            data: deflate(rgb),
+        });
+        self.images.len() - 1
+    }
+
+    /// Draws an image scaled into a rectangle.
+    ///
+    /// # Parameters
+    /// - `page`: The index of the page.
+    /// - `image`: The index returned by `add_image`.
+    /// - `x`, `y`: The top-left corner of the rectangle.
+    /// - `width`, `height`: The size of the rectangle.
+    pub fn draw_image(
+        &mut self,
+        page: usize,
+        image: usize,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        let page = &mut self.pages[page];
+        let bottom = page.height - y - height;
+        page.content.push_str(&format!(
+            "q {width:.2} 0 0 {height:.2} {x:.2} {bottom:.2} cm /Im{image} Do Q\n"
+        ));
+        if !page.images.contains(&image) {
+            page.images.push(image);
+        }
+    }
+
+    /// Draws a single line of text.
+    ///
+    /// # Parameters
+    /// - `page`: The index of the page.
+    /// - `x`: The left end of the text.
+    /// - `y`: The baseline of the text.
+    /// - `text`: The text content.
+    /// - `font`: The font.
+    /// - `size`: The font size in points.
+    pub fn draw_text(&mut self, page: usize, x: f64, y: f64, text: &str, font: PdfFont, size: f64) {
+        let page = &mut self.pages[page];
+        let baseline = page.height - y;
+        page.content.push_str(&format!(
+            "BT /{} {size:.2} Tf {x:.2} {baseline:.2} Td {} Tj ET\n",
+            font.resource(),
+            pdf_string(text)
+        ));
+    }
+
+    /// Draws a straight line.
+    ///
+    /// # Parameters
+    /// - `page`: The index of the page.
+    /// - `from`, `to`: The end points.
+    /// - `width`: The line width in points.
+    /// - `color`: The RGB color of the line.
+    pub fn draw_line(
+        &mut self,
+        page: usize,
+        from: (f64, f64),
+        to: (f64, f64),
+        width: f64,
+        color: [u8; 3],
+    ) {
+        let page = &mut self.pages[page];
+        let [r, g, b] = color.map(|channel| channel as f64 / 255.0);
+        page.content.push_str(&format!(
+            "q {r:.3} {g:.3} {b:.3} RG {width:.2} w {:.2} {:.2} m {:.2} {:.2} l S Q\n",
+            from.0,
+            page.height - from.1,
+            to.0,
+            page.height - to.1
+        ));
+    }
+
+    /// Makes a rectangle clickable, jumping to a position on another page.
+    ///
+    /// # Parameters
+    /// - `page`: The index of the page holding the rectangle.
+    /// - `rect`: The `(x, y, width, height)` of the clickable area.
+    /// - `target_page`: The index of the page to jump to.
+    /// - `target_y`: The position on the target page shown at the top of the window.
+    pub fn add_link(
+        &mut self,
+        page: usize,
+        rect: (f64, f64, f64, f64),
+        target_page: usize,
+        target_y: f64,
+    ) {
+        self.pages[page].links.push(PdfLink {
+            rect,
+            page: target_page,
+            y: target_y,
+        });
+    }
+
+    /// Adds a bookmark to the outline shown in the sidebar of PDF viewers.
+    ///
+    /// # Parameters
+    /// - `title`: The bookmark text.
+    /// - `page`: The index of the page to jump to.
+    /// - `y`: The position on the page shown at the top of the window.
+    pub fn add_outline_entry(&mut self, title: &str, page: usize, y: f64) {
+        self.outline.push(PdfOutlineEntry {
+            title: title.to_string(),
+            page,
+            y,
+        });
+    }
+
+    /// Serializes the document.
+    ///
+    /// # Returns
+    /// The bytes of the PDF file.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // Object numbers: catalog, page tree, fonts, info, images, then per page its page
+        // object and content stream, then the outline root and its entries
+        const CATALOG: usize = 1;
+        const PAGES: usize = 2;
+        const FONTS: usize = 3;
+        let fonts = [PdfFont::Regular, PdfFont::Bold, PdfFont::Italic];
+        let info = FONTS + fonts.len();
+        let first_image = info + 1;
+        let first_page = first_image + self.images.len();
+        let page_object = |index: usize| first_page + 2 * index;
+        let outline_root = first_page + 2 * self.pages.len();
+        let first_entry = outline_root + 1;
+
+        let mut writer = PdfWriter::default();
+        writer
+            .bytes
+            .extend_from_slice(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n");
+
+        let outlines = if self.outline.is_empty() {
+            String::new()
+        } else {
+            format!(" /Outlines {outline_root} 0 R /PageMode /UseOutlines")
+        };
+        writer.object(
+            CATALOG,
+            &format!("<< /Type /Catalog /Pages {PAGES} 0 R{outlines} >>"),
+        );
+        let kids: Vec<String> = (0..self.pages.len())
+            .map(|index| format!("{} 0 R", page_object(index)))
+            .collect();
+        writer.object(
+            PAGES,
+            &format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                kids.join(" "),
+                self.pages.len()
+            ),
+        );
+        for (offset, font) in fonts.iter().enumerate() {
+            writer.object(
+                FONTS + offset,
+                &format!(
+                    "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+                    font.base_font()
+                ),
+            );
+        }
+        let title = self
+            .title
+            .as_deref()
+            .map(|title| format!(" /Title {}", pdf_string(title)))
+            .unwrap_or_default();
+        writer.object(
+            info,
+            &format!(
+                "<< /Producer {}{title} >>",
+                pdf_string(concat!("dataviz ", env!("CARGO_PKG_VERSION")))
+            ),
+        );
+
+        for (index, image) in self.images.iter().enumerate() {
+            writer.stream(
+                first_image + index,
+                &format!(
+                    "/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB /BitsPerComponent 8 /Filter /FlateDecode",
+                    image.width, image.height
+                ),
+                &image.data,
+            );
+        }
+
+        for (index, page) in self.pages.iter().enumerate() {
+            let font_resources: Vec<String> = fonts
+                .iter()
+                .enumerate()
+                .map(|(offset, font)| format!("/{} {} 0 R", font.resource(), FONTS + offset))
+                .collect();
+            let image_resources: Vec<String> = page
+                .images
+                .iter()
+                .map(|image| format!("/Im{image} {} 0 R", first_image + image))
+                .collect();
+            let annotations: Vec<String> = page
+                .links
+                .iter()
+                .map(|link| {
+                    let (x, y, width, height) = link.rect;
+                    let target_height = self.pages[link.page].height;
+                    format!(
+                        "<< /Type /Annot /Subtype /Link /Border [0 0 0] /Rect [{x:.2} {:.2} {:.2} {:.2}] /Dest [{} 0 R /XYZ null {:.2} null] >>",
+                        page.height - y - height,
+                        x + width,
+                        page.height - y,
+                        page_object(link.page),
+                        target_height - link.y
+                    )
+                })
+                .collect();
+
+            writer.object(
+                page_object(index),
+                &format!(
+                    "<< /Type /Page /Parent {PAGES} 0 R /MediaBox [0 0 {:.2} {:.2}] /Resources << /Font << {} >> /XObject << {} >> >> /Contents {} 0 R /Annots [{}] >>",
+                    page.width,
+                    page.height,
+                    font_resources.join(" "),
+                    image_resources.join(" "),
+                    page_object(index) + 1,
+                    annotations.join(" ")
+                ),
+            );
+            writer.stream(page_object(index) + 1, "", page.content.as_bytes());
+        }
+
+        if !self.outline.is_empty() {
+            let last_entry = first_entry + self.outline.len() - 1;
+            writer.object(
+                outline_root,
+                &format!(
+                    "<< /Type /Outlines /First {first_entry} 0 R /Last {last_entry} 0 R /Count {} >>",
+                    self.outline.len()
+                ),
+            );
+            for (offset, entry) in self.outline.iter().enumerate() {
+                let number = first_entry + offset;
+                let mut siblings = String::new();
+                if number > first_entry {
+                    siblings.push_str(&format!(" /Prev {} 0 R", number - 1));
+                }
+                if number < last_entry {
+                    siblings.push_str(&format!(" /Next {} 0 R", number + 1));
+                }
+                writer.object(
+                    number,
+                    &format!(
+                        "<< /Title {} /Parent {outline_root} 0 R{siblings} /Dest [{} 0 R /XYZ null {:.2} null] >>",
+                        pdf_string(&entry.title),
+                        page_object(entry.page),
+                        self.pages[entry.page].height - entry.y
+                    ),
+                );
+            }
+        }
+
+        writer.finish(CATALOG, info)
+    }
+
+    /// Saves the document as a PDF file.
+    ///
+    /// # Parameters
+    /// - `file_path`: The path to save the PDF file.
+    ///
+    /// # Errors
+    /// Returns an `io::Result` if the file cannot be written.
+    pub fn save(&self, file_path: &str) -> io::Result<()> {
+        File::create(file_path)?.write_all(&self.to_bytes())
+    }
+}
+
+/// Accumulates numbered objects and the byte offsets the cross-reference table needs.
+#[derive(Default)]
+struct PdfWriter {
+    bytes: Vec<u8>,
+    offsets: Vec<(usize, usize)>,
+}
+
+impl PdfWriter {
+    /// Writes an object holding a dictionary or other direct value.
+    fn object(&mut self, number: usize, value: &str) {
+        self.offsets.push((number, self.bytes.len()));
+        self.bytes
+            .extend_from_slice(format!("{number} 0 obj\n{value}\nendobj\n").as_bytes());
+    }
+
+    /// Writes a stream object with extra dictionary entries.
+    fn stream(&mut self, number: usize, entries: &str, data: &[u8]) {
+        self.offsets.push((number, self.bytes.len()));
+        self.bytes.extend_from_slice(
+            format!(
+                "{number} 0 obj\n<< {entries} /Length {} >>\nstream\n",
+                data.len()
+            )
+            .as_bytes(),
+        );
+        self.bytes.extend_from_slice(data);
+        self.bytes.extend_from_slice(b"\nendstream\nendobj\n");
+    }
+
+    /// Writes the cross-reference table and trailer.
+    fn finish(mut self, root: usize, info: usize) -> Vec<u8> {
+        self.offsets.sort_unstable();
+        let xref = self.bytes.len();
+        let size = self.offsets.len() + 1;
+        let mut table = format!("xref\n0 {size}\n0000000000 65535 f \n");
+        for (_, offset) in &self.offsets {
+            table.push_str(&format!("{offset:010} 00000 n \n"));
+        }
+        table.push_str(&format!(
+            "trailer\n<< /Size {size} /Root {root} 0 R /Info {info} 0 R >>\nstartxref\n{xref}\n%%EOF\n"
+        ));
+        self.bytes.extend_from_slice(table.as_bytes());
+        self.bytes
+    }
+}
+
+/// Compresses data for a `/FlateDecode` stream.
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    // Writing into a vector cannot fail
+    encoder
+        .write_all(data)
+        .expect("In-memory compression failed");
+    encoder.finish().expect("In-memory compression failed")
+}
+
+/// Returns the WinAnsi code of a non-ASCII character: Latin-1 letters and symbols as well
+/// as typographic quotes, dashes, the ellipsis, the bullet and the euro sign.
+fn win_ansi_code(c: char) -> Option<u8> {
+    match c {
+        '\u{a0}'..='\u{ff}' => Some(c as u8),
+        '€' => Some(0x80),
+        '…' => Some(0x85),
+        '‘' => Some(0x91),
+        '’' => Some(0x92),
+        '“' => Some(0x93),
+        '”' => Some(0x94),
+        '•' => Some(0x95),
+        '–' => Some(0x96),
+        '—' => Some(0x97),
+        _ => None,
+    }
+}
+
+/// Encodes text as a PDF literal string in WinAnsi encoding.
+fn pdf_string(text: &str) -> String {
+    let mut encoded = String::from("(");
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                encoded.push('\\');
+                encoded.push(c);
+            }
+            ' '..='~' => encoded.push(c),
+            _ => match win_ansi_code(c) {
+                Some(code) => encoded.push_str(&format!("\\{code:03o}")),
+                None => encoded.push('?'),
+            },
+        }
+    }
+    encoded.push(')');
+    encoded
+}
@@ -2,7 +2,27 @@ use ab_glyph::{FontRef, PxScale};
 use image::Rgb;
 use imageproc::drawing::{draw_text_mut, text_size};
 
-use crate::figure::utilities::linetype::LineType;
+use std::{collections::HashMap, io, sync::Arc};
+
+use crate::figure::{
+    canvas::{
+        pngmetadata::{write_png, PngMetadata},
+        rasterizer::{CpuRasterizer, RasterTarget, Rasterizer},
+        svgcanvas::FRAME_INSET,
+    },
+    utilities::{
+        arrowgeometry::arrow_head,
+        bezierpath::BezierPath,
+        colorvision::ColorVisionDeficiency,
+        cornerradii::CornerRadii,
+        linetype::LineType,
+        sizepreset::SizePreset,
+        texthalo::TextHalo,
+        textmetrics::{
+            load_font, measure_text_with_fallbacks, measure_text_with_path, split_font_runs,
+        },
+    },
+};
 
 /// A structure representing a pixel-based drawing canvas.
 pub struct PixelCanvas {
@@ -16,6 +36,11 @@ pub struct PixelCanvas {
     pub buffer: Vec<u8>,
     /// Margin around the canvas (in pixels).
     pub margin: u32,
+    /// Whether pixels matching the background color are saved as fully transparent. See
+    /// `set_transparent`.
+    pub transparent: bool,
+    /// Backend filling polygons and the shapes built on them. See `set_rasterizer`.
+    pub rasterizer: Arc<dyn Rasterizer>,
 }
 
 impl PixelCanvas {
@@ -39,9 +64,55 @@ impl PixelCanvas {
             background_color,
             buffer,
             margin,
+            transparent: false,
+            rasterizer: Arc::new(CpuRasterizer),
         }
     }
 
+    /// Creates a new `PixelCanvas` with the dimensions and margin of a size preset.
+    ///
+    /// # Parameters
+    /// - `preset`: The named size, e.g. `SizePreset::A4Landscape`.
+    /// - `background_color`: The RGB color of the canvas background.
+    ///
+    /// # Returns
+    /// A new `PixelCanvas` instance.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::sizepreset::SizePreset;
+    ///
+    /// let mut canvas = PixelCanvas::with_preset(SizePreset::SocialCard, [255, 255, 255]);
+    /// ```
+    pub fn with_preset(preset: SizePreset, background_color: [u8; 3]) -> Self {
+        let (width, height) = preset.size();
+        Self::new(width, height, background_color, preset.margin())
+    }
+
+    /// Sets whether the background is saved as fully transparent.
+    ///
+    /// Transparency is applied when saving by color keying: every pixel exactly matching
+    /// `background_color` gets a zero alpha and every other pixel stays opaque. Coverage is
+    /// not tracked, so anti-aliased edges blended with the background keep an opaque halo
+    /// of the background color, and data pixels that happen to match the background color
+    /// become transparent as well. Pick a background color that the data does not use, and
+    /// one close to the color of the surface the image is placed on.
+    ///
+    /// # Parameters
+    /// - `transparent`: `true` to save the background with a zero alpha.
+    pub fn set_transparent(&mut self, transparent: bool) {
+        self.transparent = transparent;
+    }
+
+    /// Sets the backend used to fill polygons, areas, ribbons and Bézier paths, e.g. an
+    /// anti-aliasing rasterizer for smoother exports. The default is `CpuRasterizer`.
+    ///
+    /// # Parameters
+    /// - `rasterizer`: The backend, shared by the canvases derived from this one.
+    pub fn set_rasterizer(&mut self, rasterizer: Arc<dyn Rasterizer>) {
+        self.rasterizer = rasterizer;
+    }
+
     /// Clears the canvas by filling it with the background color.
     pub fn clear(&mut self) {
         for i in (0..self.buffer.len()).step_by(3) {
@@ -58,6 +129,10 @@ impl PixelCanvas {
     /// - `y`: The y-coordinate of the pixel.
     /// - `color`: The RGB color of the pixel.
     pub fn draw_pixel(&mut self, x: u32, y: u32, color: [u8; 3]) {
+        // Offscreen pixels, e.g. wrapped negative coordinates, are skipped
+        if x >= self.width || y >= self.height {
+            return;
+        }
         let index = ((y * self.width + x) * 3) as usize;
         if index + 2 < self.buffer.len() {
             self.buffer[index] = color[0];
@@ -74,6 +149,9 @@ impl PixelCanvas {
     /// - `color`: The RGB color to blend.
     /// - `alpha`: The transparency value (0.0 to 1.0).
     pub fn blend_pixel(&mut self, x: u32, y: u32, color: [u8; 3], alpha: f64) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
         let index = ((y * self.width + x) * 3) as usize;
         if index + 2 < self.buffer.len() {
             let existing_color = [
@@ -130,6 +208,21 @@ impl PixelCanvas {
         }
     }
 
+    /// Draws grid lines across the plot area at the given positions.
+    ///
+    /// # Parameters
+    /// - `x_positions`: The x-coordinates of the vertical grid lines.
+    /// - `y_positions`: The y-coordinates of the horizontal grid lines.
+    /// - `color`: The RGB color of the grid lines.
+    pub fn draw_grid_lines(&mut self, x_positions: &[f64], y_positions: &[f64], color: [u8; 3]) {
+        for &x in x_positions {
+            self.draw_vertical_line(x.round().clamp(0.0, (self.width - 1) as f64) as u32, color);
+        }
+        for &y in y_positions {
+            self.draw_horizontal_line(y.round().clamp(0.0, (self.height - 1) as f64) as u32, color);
+        }
+    }
+
     /// Draws text vertically at the specified position.
     ///
     /// # Parameters
@@ -197,6 +290,253 @@ impl PixelCanvas {
         self.buffer = buffer.into_raw();
     }
 
+    /// Draws text at the specified position using a font loaded from a file.
+    ///
+    /// With the `shaping` feature enabled, the text is laid out with the Unicode
+    /// bidirectional algorithm and shaped with `rustybuzz`, so right-to-left and
+    /// joining scripts render correctly. Otherwise this is equivalent to `draw_text`.
+    ///
+    /// # Parameters
+    /// - `x`: The x-coordinate for the text.
+    /// - `y`: The y-coordinate for the text.
+    /// - `text`: The text content.
+    /// - `color`: The RGB color of the text.
+    /// - `font_path`: Path to the font file (loaded through the font cache).
+    /// - `scale`: The scaling factor for the font size.
+    pub fn draw_text_from_path(
+        &mut self,
+        x: u32,
+        y: u32,
+        text: &str,
+        color: [u8; 3],
+        font_path: &str,
+        scale: PxScale,
+    ) {
+        #[cfg(feature = "shaping")]
+        {
+            self.draw_text_shaped(x, y, text, color, font_path, scale);
+        }
+        #[cfg(not(feature = "shaping"))]
+        {
+            let font = load_font(font_path);
+            self.draw_text(x, y, text, color, &font, scale);
+        }
+    }
+
+    /// Draws text at the specified position, switching to fallback fonts for characters
+    /// the primary font does not contain.
+    ///
+    /// # Parameters
+    /// - `x`: The x-coordinate for the text.
+    /// - `y`: The y-coordinate for the text.
+    /// - `text`: The text content.
+    /// - `color`: The RGB color of the text.
+    /// - `font_path`: Path to the primary font file.
+    /// - `fallbacks`: Paths to fallback font files, in order of preference.
+    /// - `scale`: The scaling factor for the font size.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text_with_fallbacks(
+        &mut self,
+        x: u32,
+        y: u32,
+        text: &str,
+        color: [u8; 3],
+        font_path: &str,
+        fallbacks: &[String],
+        scale: PxScale,
+    ) {
+        let mut x = x;
+        for (run, run_font) in split_font_runs(text, font_path, fallbacks) {
+            self.draw_text_from_path(x, y, run, color, run_font, scale);
+            x += measure_text_with_path(run, run_font, scale.y).0;
+        }
+    }
+
+    /// Draws text rotated by 90° counter-clockwise, reading from bottom to top.
+    ///
+    /// The text is rendered with `draw_text_with_fallbacks` and blended onto the canvas
+    /// using its anti-aliasing coverage.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The top-left corner of the rotated text's bounding box.
+    /// - `text`: The text content.
+    /// - `color`: The RGB color of the text.
+    /// - `font_path`: Path to the primary font file.
+    /// - `fallbacks`: Paths to fallback font files, in order of preference.
+    /// - `scale`: The scaling factor for the font size.
+    ///
+    /// # Details
+    /// The rotated bounding box is as wide as the text is high and as high as the text is
+    /// wide, as measured by `measure_text_with_fallbacks`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text_rotated(
+        &mut self,
+        x: u32,
+        y: u32,
+        text: &str,
+        color: [u8; 3],
+        font_path: &str,
+        fallbacks: &[String],
+        scale: PxScale,
+    ) {
+        let (w, h) = measure_text_with_fallbacks(text, font_path, fallbacks, scale.y);
+        // Glyphs may overhang their measured box slightly
+        let (w, h) = (w + 2, h + 4);
+
+        let mut mask = PixelCanvas::new(w, h, [0, 0, 0], 0);
+        mask.draw_text_with_fallbacks(0, 0, text, [255, 255, 255], font_path, fallbacks, scale);
+
+        for ty in 0..h {
+            for tx in 0..w {
+                let coverage = mask.buffer[((ty * w + tx) * 3) as usize];
+                let (px, py) = (x + ty, y + (w - 1 - tx));
+                if coverage > 0 && px < self.width && py < self.height {
+                    self.blend_pixel(px, py, color, coverage as f64 / 255.0);
+                }
+            }
+        }
+    }
+
+    /// Draws a halo behind text, to be drawn over it at the same position with
+    /// `draw_text_with_fallbacks`, or with `draw_text_rotated` when `rotated` is set.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The position the text is drawn at.
+    /// - `text`: The text content.
+    /// - `font_path`: Path to the primary font file.
+    /// - `fallbacks`: Paths to fallback font files, in order of preference.
+    /// - `scale`: The scaling factor for the font size.
+    /// - `rotated`: Whether the text is rotated by 90° counter-clockwise.
+    /// - `halo`: The outline or pill to draw.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::texthalo::TextHalo;
+    ///
+    /// canvas.draw_text_halo(120, 80, "42.0", font_path, &[], scale, false, &TextHalo::outline());
+    /// canvas.draw_text_with_fallbacks(120, 80, "42.0", [0, 0, 0], font_path, &[], scale);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text_halo(
+        &mut self,
+        x: u32,
+        y: u32,
+        text: &str,
+        font_path: &str,
+        fallbacks: &[String],
+        scale: PxScale,
+        rotated: bool,
+        halo: &TextHalo,
+    ) {
+        let (w, h) = measure_text_with_fallbacks(text, font_path, fallbacks, scale.y);
+        let (box_width, box_height) = if rotated { (h, w) } else { (w, h) };
+
+        let (color, width) = match *halo {
+            TextHalo::Pill {
+                color,
+                padding,
+                opacity,
+            } => {
+                let (width, height) = (
+                    box_width as f64 + 2.0 * padding,
+                    box_height as f64 + 2.0 * padding,
+                );
+                let outline = CornerRadii::uniform(width.min(height) / 2.0).outline(
+                    x as f64 - padding,
+                    y as f64 - padding,
+                    width,
+                    height,
+                );
+                self.blend_polygon(&[outline], color, opacity);
+                return;
+            }
+            TextHalo::Outline { color, width } => (color, width),
+        };
+
+        // Coverage of the glyphs in canvas orientation, as draw_text_rotated lays them out
+        let (mask_width, mask_height) = (w + 2, h + 4);
+        let mut mask = PixelCanvas::new(mask_width, mask_height, [0, 0, 0], 0);
+        mask.draw_text_with_fallbacks(0, 0, text, [255, 255, 255], font_path, fallbacks, scale);
+        let (cols, rows) = if rotated {
+            (mask_height, mask_width)
+        } else {
+            (mask_width, mask_height)
+        };
+        let coverage = |col: i64, row: i64| -> u8 {
+            if col < 0 || row < 0 || col >= cols as i64 || row >= rows as i64 {
+                return 0;
+            }
+            let (tx, ty) = if rotated {
+                (mask_width as i64 - 1 - row, col)
+            } else {
+                (col, row)
+            };
+            mask.buffer[((ty * mask_width as i64 + tx) * 3) as usize]
+        };
+
+        // Spread the coverage over a disk as wide as the outline
+        let radius = width.max(0.0);
+        let reach = radius.ceil() as i64;
+        let offsets: Vec<(i64, i64)> = (-reach..=reach)
+            .flat_map(|dy| (-reach..=reach).map(move |dx| (dx, dy)))
+            .filter(|&(dx, dy)| ((dx * dx + dy * dy) as f64) <= radius * radius)
+            .collect();
+        for row in -reach..rows as i64 + reach {
+            for col in -reach..cols as i64 + reach {
+                let alpha = offsets
+                    .iter()
+                    .map(|&(dx, dy)| coverage(col + dx, row + dy))
+                    .max()
+                    .unwrap_or(0);
+                let (px, py) = (x as i64 + col, y as i64 + row);
+                if alpha > 0
+                    && px >= 0
+                    && py >= 0
+                    && px < self.width as i64
+                    && py < self.height as i64
+                {
+                    self.blend_pixel(px as u32, py as u32, color, alpha as f64 / 255.0);
+                }
+            }
+        }
+    }
+
+    /// Draws shaped text, rasterizing each positioned glyph individually.
+    #[cfg(feature = "shaping")]
+    fn draw_text_shaped(
+        &mut self,
+        x: u32,
+        y: u32,
+        text: &str,
+        color: [u8; 3],
+        font_path: &str,
+        scale: PxScale,
+    ) {
+        use crate::figure::utilities::{textmetrics::load_font_data, textshaping::shape_text};
+        use ab_glyph::{point, Font, GlyphId, ScaleFont};
+
+        let font = load_font(font_path);
+        let ascent = font.as_scaled(scale).ascent();
+        let (glyphs, _) = shape_text(text, load_font_data(font_path), scale.y);
+
+        for shaped in glyphs {
+            let glyph = GlyphId(shaped.glyph_id).with_scale_and_position(
+                scale,
+                point(x as f32 + shaped.x, y as f32 + ascent - shaped.y),
+            );
+            if let Some(outlined) = font.outline_glyph(glyph) {
+                let bounds = outlined.px_bounds();
+                outlined.draw(|gx, gy, coverage| {
+                    let px = bounds.min.x as i32 + gx as i32;
+                    let py = bounds.min.y as i32 + gy as i32;
+                    if px >= 0 && py >= 0 && (px as u32) < self.width && (py as u32) < self.height {
+                        self.blend_pixel(px as u32, py as u32, color, coverage as f64);
+                    }
+                });
+            }
+        }
+    }
+
     /// Draws a line with the specified type (solid, dashed, or dotted).
     ///
     /// # Parameters
@@ -307,15 +647,592 @@ impl PixelCanvas {
         }
     }
 
+    /// Draws an arrow: a solid shaft ending in a filled triangular head at its tip.
+    ///
+    /// # Parameters
+    /// - `start`: The tail of the arrow.
+    /// - `end`: The tip of the arrow.
+    /// - `head_length`: The length of the head in pixels, at most half the arrow.
+    /// - `color`: The RGB color of the arrow.
+    pub fn draw_arrow(
+        &mut self,
+        start: (f64, f64),
+        end: (f64, f64),
+        head_length: f64,
+        color: [u8; 3],
+    ) {
+        let Some((shaft_end, head)) = arrow_head(start, end, head_length) else {
+            return;
+        };
+        self.draw_line(
+            start.0.round() as i32,
+            start.1.round() as i32,
+            shaft_end.0.round() as i32,
+            shaft_end.1.round() as i32,
+            color,
+            LineType::Solid,
+        );
+        self.fill_polygon(&[head.to_vec()], color);
+    }
+
+    /// Draws a solid polyline blended with the canvas, e.g. translucent lines that darken
+    /// where many of them overlap.
+    ///
+    /// Every pixel of the polyline is blended once, including the vertices shared by two
+    /// consecutive segments. Pixels outside the canvas are skipped.
+    ///
+    /// # Parameters
+    /// - `points`: The vertices of the polyline, in canvas coordinates.
+    /// - `color`: The RGB color of the line.
+    /// - `alpha`: The opacity of the line (0.0 to 1.0).
+    pub fn blend_polyline(&mut self, points: &[(f64, f64)], color: [u8; 3], alpha: f64) {
+        let mut last = None;
+        for window in points.windows(2) {
+            let (x1, y1) = (window[0].0.round() as i64, window[0].1.round() as i64);
+            let (x2, y2) = (window[1].0.round() as i64, window[1].1.round() as i64);
+            let dx = (x2 - x1).abs();
+            let dy = -(y2 - y1).abs();
+            let sx = if x1 < x2 { 1 } else { -1 };
+            let sy = if y1 < y2 { 1 } else { -1 };
+            let mut err = dx + dy;
+            let (mut x, mut y) = (x1, y1);
+            loop {
+                if last != Some((x, y))
+                    && (0..self.width as i64).contains(&x)
+                    && (0..self.height as i64).contains(&y)
+                {
+                    self.blend_pixel(x as u32, y as u32, color, alpha);
+                }
+                last = Some((x, y));
+                if x == x2 && y == y2 {
+                    break;
+                }
+                let e2 = 2 * err;
+                if e2 >= dy {
+                    err += dy;
+                    x += sx;
+                }
+                if e2 <= dx {
+                    err += dx;
+                    y += sy;
+                }
+            }
+        }
+    }
+
+    /// Fills a polygon, possibly with holes, using the even-odd rule.
+    ///
+    /// The polygon is filled by the canvas's `rasterizer`; the default `CpuRasterizer` fills
+    /// a pixel when its center lies inside the polygon. Pixels outside the canvas are skipped.
+    ///
+    /// # Parameters
+    /// - `rings`: The outer boundary and holes of the polygon, in canvas coordinates.
+    ///   Rings are closed implicitly.
+    /// - `color`: The RGB fill color.
+    pub fn fill_polygon(&mut self, rings: &[Vec<(f64, f64)>], color: [u8; 3]) {
+        self.blend_polygon(rings, color, 1.0);
+    }
+
+    /// Fills a polygon, possibly with holes, blending its color with the canvas.
+    ///
+    /// Pixels are selected as in `fill_polygon`.
+    ///
+    /// # Parameters
+    /// - `rings`: The outer boundary and holes of the polygon, in canvas coordinates.
+    /// - `color`: The RGB fill color.
+    /// - `alpha`: The opacity of the fill (0.0 to 1.0).
+    pub fn blend_polygon(&mut self, rings: &[Vec<(f64, f64)>], color: [u8; 3], alpha: f64) {
+        let rasterizer = Arc::clone(&self.rasterizer);
+        let mut target = RasterTarget {
+            buffer: &mut self.buffer,
+            width: self.width,
+            height: self.height,
+        };
+        rasterizer.fill_polygon(&mut target, rings, color, alpha);
+    }
+
+    /// Fills a closed path of lines and cubic Bézier curves, e.g. a ribbon of a chord
+    /// diagram, using the even-odd rule.
+    ///
+    /// Curves are flattened by `BezierPath::outline`, then pixels are selected as in
+    /// `fill_polygon`.
+    ///
+    /// # Parameters
+    /// - `path`: The path in canvas coordinates.
+    /// - `color`: The RGB fill color.
+    pub fn fill_bezier_path(&mut self, path: &BezierPath, color: [u8; 3]) {
+        self.fill_polygon(&[path.outline()], color);
+    }
+
+    /// Fills a closed path of lines and cubic Bézier curves, blending its color with the
+    /// canvas.
+    ///
+    /// Pixels are selected as in `fill_bezier_path`.
+    ///
+    /// # Parameters
+    /// - `path`: The path in canvas coordinates.
+    /// - `color`: The RGB fill color.
+    /// - `alpha`: The opacity of the fill (0.0 to 1.0).
+    pub fn blend_bezier_path(&mut self, path: &BezierPath, color: [u8; 3], alpha: f64) {
+        self.blend_polygon(&[path.outline()], color, alpha);
+    }
+
+    /// Fills a circle of any radius, e.g. a bubble sized by a data value.
+    ///
+    /// A pixel is filled when its center lies inside the circle. Pixels outside the canvas
+    /// are skipped.
+    ///
+    /// # Parameters
+    /// - `cx`, `cy`: Coordinates of the center.
+    /// - `radius`: The radius in pixels.
+    /// - `color`: The RGB fill color.
+    pub fn fill_circle(&mut self, cx: f64, cy: f64, radius: f64, color: [u8; 3]) {
+        for (row, start, end) in self.circle_spans(cx, cy, radius) {
+            for x in start..end {
+                self.draw_pixel(x, row, color);
+            }
+        }
+    }
+
+    /// Fills a circle of any radius, blending its color with the canvas.
+    ///
+    /// Pixels are selected as in `fill_circle`.
+    ///
+    /// # Parameters
+    /// - `cx`, `cy`: Coordinates of the center.
+    /// - `radius`: The radius in pixels.
+    /// - `color`: The RGB fill color.
+    /// - `alpha`: The opacity of the fill (0.0 to 1.0).
+    pub fn blend_circle(&mut self, cx: f64, cy: f64, radius: f64, color: [u8; 3], alpha: f64) {
+        for (row, start, end) in self.circle_spans(cx, cy, radius) {
+            for x in start..end {
+                self.blend_pixel(x, row, color, alpha);
+            }
+        }
+    }
+
+    /// Computes the pixels inside a circle as `(row, start, end)` spans with `end`
+    /// exclusive, clipped to the canvas.
+    fn circle_spans(&self, cx: f64, cy: f64, radius: f64) -> Vec<(u32, u32, u32)> {
+        let mut spans = Vec::new();
+        if !(cx.is_finite() && cy.is_finite() && radius > 0.0) {
+            return spans;
+        }
+
+        let first_row = (cy - radius).floor().max(0.0) as u32;
+        let last_row = (cy + radius).ceil().min(self.height as f64).max(0.0) as u32;
+        for row in first_row..last_row {
+            let dy = row as f64 + 0.5 - cy;
+            if dy.abs() > radius {
+                continue;
+            }
+            let half_width = (radius * radius - dy * dy).sqrt();
+            let start = (cx - half_width - 0.5).ceil().max(0.0) as u32;
+            let end = ((cx + half_width - 0.5).floor() + 1.0)
+                .min(self.width as f64)
+                .max(0.0) as u32;
+            if start < end {
+                spans.push((row, start, end));
+            }
+        }
+        spans
+    }
+
+    /// Draws the outline of a rectangle with rounded corners.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the top-left corner.
+    /// - `width`, `height`: Dimensions of the rectangle.
+    /// - `radii`: Radii of the four corners.
+    /// - `color`: The RGB color of the outline.
+    pub fn draw_rounded_rect(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: CornerRadii,
+        color: [u8; 3],
+    ) {
+        // Pixel centers of the outermost pixels, so the outline stays inside the rectangle
+        let outline = radii.outline(x + 0.5, y + 0.5, width - 1.0, height - 1.0);
+        for (index, &(x1, y1)) in outline.iter().enumerate() {
+            let (x2, y2) = outline[(index + 1) % outline.len()];
+            self.draw_line(
+                x1.floor() as i32,
+                y1.floor() as i32,
+                x2.floor() as i32,
+                y2.floor() as i32,
+                color,
+                LineType::Solid,
+            );
+        }
+    }
+
+    /// Fills a rectangle with rounded corners.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the top-left corner.
+    /// - `width`, `height`: Dimensions of the rectangle.
+    /// - `radii`: Radii of the four corners.
+    /// - `color`: The RGB fill color.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::cornerradii::CornerRadii;
+    ///
+    /// canvas.fill_rounded_rect(40.0, 60.0, 20.0, 120.0, CornerRadii::new(4.0, 4.0, 0.0, 0.0), [70, 130, 180]);
+    /// ```
+    pub fn fill_rounded_rect(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: CornerRadii,
+        color: [u8; 3],
+    ) {
+        self.fill_polygon(&[radii.outline(x, y, width, height)], color);
+    }
+
+    /// Fills the contiguous region around a seed pixel that has the seed pixel's color.
+    ///
+    /// Pixels are connected horizontally and vertically, so a one pixel wide diagonal line
+    /// is enough to stop the fill.
+    ///
+    /// # Parameters
+    /// - `x`: The x-coordinate of the seed pixel.
+    /// - `y`: The y-coordinate of the seed pixel.
+    /// - `color`: The RGB fill color.
+    ///
+    /// # Returns
+    /// The number of pixels filled, 0 if the seed lies outside the canvas.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// canvas.draw_line(0, 50, 199, 50, [0, 0, 0], LineType::Solid);
+    /// canvas.flood_fill(10, 10, [200, 220, 255]); // fills everything above the line
+    /// ```
+    pub fn flood_fill(&mut self, x: u32, y: u32, color: [u8; 3]) -> usize {
+        let Some(target) = self.pixel(x, y) else {
+            return 0;
+        };
+        self.scanline_fill(x, y, color, |pixel| pixel == target)
+    }
+
+    /// Fills the region around a seed pixel up to pixels of a boundary color.
+    ///
+    /// Unlike `flood_fill`, the region may contain pixels of any other color, such as
+    /// grid lines or anti-aliased edges, which are filled as well. To fill between two
+    /// curves, draw both curves and the lines closing their ends in the boundary color and
+    /// seed the fill between them.
+    ///
+    /// # Parameters
+    /// - `x`: The x-coordinate of the seed pixel.
+    /// - `y`: The y-coordinate of the seed pixel.
+    /// - `color`: The RGB fill color.
+    /// - `boundary`: The RGB color of the pixels enclosing the region. They are not filled.
+    ///
+    /// # Returns
+    /// The number of pixels filled, 0 if the seed lies outside the canvas or on the boundary.
+    pub fn fill_bounded(&mut self, x: u32, y: u32, color: [u8; 3], boundary: [u8; 3]) -> usize {
+        self.scanline_fill(x, y, color, |pixel| pixel != boundary)
+    }
+
+    /// Returns the color of a pixel, or `None` outside the canvas.
+    fn pixel(&self, x: u32, y: u32) -> Option<[u8; 3]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let index = ((y * self.width + x) * 3) as usize;
+        Some([
+            self.buffer[index],
+            self.buffer[index + 1],
+            self.buffer[index + 2],
+        ])
+    }
+
+    /// Fills the 4-connected region of pixels accepted by `inside`, one horizontal span at a
+    /// time, starting at a seed pixel.
+    fn scanline_fill(
+        &mut self,
+        x: u32,
+        y: u32,
+        color: [u8; 3],
+        inside: impl Fn([u8; 3]) -> bool,
+    ) -> usize {
+        let (width, height) = (self.width as usize, self.height as usize);
+        // Tracks filled pixels, so regions that already contain the fill color terminate
+        let mut filled = vec![false; width * height];
+        let is_open = |canvas: &Self, filled: &[bool], x: usize, y: usize| {
+            !filled[y * width + x] && canvas.pixel(x as u32, y as u32).is_some_and(&inside)
+        };
+
+        let mut count = 0;
+        let mut seeds = vec![(x as usize, y as usize)];
+        while let Some((x, y)) = seeds.pop() {
+            if x >= width || y >= height || !is_open(self, &filled, x, y) {
+                continue;
+            }
+
+            // Extend the span to both sides
+            let mut start = x;
+            while start > 0 && is_open(self, &filled, start - 1, y) {
+                start -= 1;
+            }
+            let mut end = x + 1;
+            while end < width && is_open(self, &filled, end, y) {
+                end += 1;
+            }
+            for span_x in start..end {
+                filled[y * width + span_x] = true;
+                self.draw_pixel(span_x as u32, y as u32, color);
+            }
+            count += end - start;
+
+            // Seed every open run in the rows above and below the span
+            for row in [y.checked_sub(1), Some(y + 1).filter(|&row| row < height)]
+                .into_iter()
+                .flatten()
+            {
+                let mut in_run = false;
+                for span_x in start..end {
+                    let open = is_open(self, &filled, span_x, row);
+                    if open && !in_run {
+                        seeds.push((span_x, row));
+                    }
+                    in_run = open;
+                }
+            }
+        }
+        count
+    }
+
+    /// Computes the bounding box of everything drawn on the canvas.
+    ///
+    /// A pixel counts as content when it differs from the background just inside the outer
+    /// frame, as in `SvgCanvas::content_bounds`. A frame or border drawn along the canvas
+    /// edges is ignored.
+    ///
+    /// # Returns
+    /// `Some((x_min, y_min, x_max, y_max))` with inclusive pixel bounds, or `None` if the
+    /// canvas contains only background pixels.
+    pub fn content_bounds(&self) -> Option<(u32, u32, u32, u32)> {
+        let inset = FRAME_INSET.min(self.width / 2).min(self.height / 2);
+        let pixel = |x: u32, y: u32| {
+            let index = ((y * self.width + x) * 3) as usize;
+            self.buffer.get(index..index + 3)
+        };
+        let reference = pixel(inset, inset)?;
+
+        let mut bounds: Option<(u32, u32, u32, u32)> = None;
+        for y in inset..self.height - inset {
+            for x in inset..self.width - inset {
+                if pixel(x, y) == Some(reference) {
+                    continue;
+                }
+                bounds = Some(match bounds {
+                    None => (x, y, x, y),
+                    Some((x0, y0, x1, y1)) => (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+                });
+            }
+        }
+
+        bounds
+    }
+
+    /// Creates a copy of the canvas with the unused border whitespace removed.
+    ///
+    /// # Parameters
+    /// - `padding`: Number of background pixels to keep around the content on each side.
+    ///
+    /// # Returns
+    /// A new `PixelCanvas` containing only the content area plus padding. If the canvas
+    /// is empty, an unchanged copy is returned.
+    pub fn trimmed(&self, padding: u32) -> PixelCanvas {
+        let Some((x0, y0, x1, y1)) = self.content_bounds() else {
+            return PixelCanvas {
+                width: self.width,
+                height: self.height,
+                background_color: self.background_color,
+                buffer: self.buffer.clone(),
+                margin: self.margin,
+                transparent: self.transparent,
+                rasterizer: self.rasterizer.clone(),
+            };
+        };
+
+        let x0 = x0.saturating_sub(padding);
+        let y0 = y0.saturating_sub(padding);
+        let x1 = (x1 + padding).min(self.width - 1);
+        let y1 = (y1 + padding).min(self.height - 1);
+
+        let width = x1 - x0 + 1;
+        let height = y1 - y0 + 1;
+        let mut buffer = Vec::with_capacity((width * height * 3) as usize);
+        for y in y0..=y1 {
+            let start = ((y * self.width + x0) * 3) as usize;
+            let end = start + (width * 3) as usize;
+            buffer.extend_from_slice(&self.buffer[start..end]);
+        }
+
+        PixelCanvas {
+            width,
+            height,
+            background_color: self.background_color,
+            buffer,
+            margin: self.margin.saturating_sub(x0.min(y0)),
+            transparent: self.transparent,
+            rasterizer: self.rasterizer.clone(),
+        }
+    }
+
+    /// Creates a copy of the canvas as seen with a color vision deficiency, e.g. to check
+    /// a rendered chart for colors that become indistinguishable.
+    ///
+    /// # Parameters
+    /// - `deficiency`: The deficiency to simulate.
+    ///
+    /// # Returns
+    /// A new `PixelCanvas` of the same size with every pixel and the background color
+    /// simulated.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::colorvision::ColorVisionDeficiency;
+    ///
+    /// chart.draw(&mut canvas);
+    /// canvas
+    ///     .simulate_color_vision(ColorVisionDeficiency::Deuteranopia)
+    ///     .save_as_image("chart_deuteranopia.png");
+    /// ```
+    pub fn simulate_color_vision(&self, deficiency: ColorVisionDeficiency) -> PixelCanvas {
+        // Charts use few distinct colors, so each is simulated once
+        let mut simulated: HashMap<[u8; 3], [u8; 3]> = HashMap::new();
+        let mut buffer = Vec::with_capacity(self.buffer.len());
+        for pixel in self.buffer.chunks_exact(3) {
+            let color = [pixel[0], pixel[1], pixel[2]];
+            let seen = *simulated
+                .entry(color)
+                .or_insert_with(|| deficiency.simulate(color));
+            buffer.extend_from_slice(&seen);
+        }
+
+        PixelCanvas {
+            width: self.width,
+            height: self.height,
+            background_color: deficiency.simulate(self.background_color),
+            buffer,
+            margin: self.margin,
+            transparent: self.transparent,
+            rasterizer: self.rasterizer.clone(),
+        }
+    }
+
+    /// Creates a resampled copy of the canvas with the given dimensions.
+    ///
+    /// A Lanczos filter is used, so downsampling a high-resolution rendering produces
+    /// smooth, anti-aliased edges. The margin is scaled proportionally.
+    ///
+    /// # Parameters
+    /// - `width`: The width of the new canvas in pixels.
+    /// - `height`: The height of the new canvas in pixels.
+    ///
+    /// # Returns
+    /// A new `PixelCanvas` with the resampled content.
+    pub fn resized(&self, width: u32, height: u32) -> PixelCanvas {
+        use image::{imageops, ImageBuffer, RgbImage};
+
+        let img: RgbImage = ImageBuffer::from_raw(self.width, self.height, self.buffer.clone())
+            .expect("Failed to create image buffer");
+        let resized = imageops::resize(&img, width, height, imageops::FilterType::Lanczos3);
+
+        PixelCanvas {
+            width,
+            height,
+            background_color: self.background_color,
+            buffer: resized.into_raw(),
+            margin: (self.margin as u64 * width as u64 / self.width.max(1) as u64) as u32,
+            transparent: self.transparent,
+            rasterizer: self.rasterizer.clone(),
+        }
+    }
+
+    /// Saves the canvas as an image file, trimmed to its content.
+    ///
+    /// # Parameters
+    /// - `file_path`: The path to save the image file.
+    /// - `padding`: Number of background pixels to keep around the content on each side.
+    ///
+    /// # Panics
+    /// Panics if the image cannot be saved.
+    pub fn save_as_image_trimmed(&self, file_path: &str, padding: u32) {
+        self.trimmed(padding).save_as_image(file_path);
+    }
+
+    /// Returns the pixels with an alpha channel, where pixels matching the background color
+    /// are fully transparent.
+    fn rgba_buffer(&self) -> Vec<u8> {
+        self.buffer
+            .chunks_exact(3)
+            .flat_map(|rgb| {
+                let alpha = if rgb == self.background_color { 0 } else { 255 };
+                [rgb[0], rgb[1], rgb[2], alpha]
+            })
+            .collect()
+    }
+
+    /// Saves the current canvas as a PNG file with embedded text metadata.
+    ///
+    /// Transparency is handled as in `save_as_image`.
+    ///
+    /// # Parameters
+    /// - `file_path`: The path to save the PNG file.
+    /// - `metadata`: The title, description, creation time and figure spec to embed.
+    ///
+    /// # Errors
+    /// Returns an `io::Result` if a metadata keyword is invalid or the file cannot be written.
+    pub fn save_as_png_with_metadata(
+        &self,
+        file_path: &str,
+        metadata: &PngMetadata,
+    ) -> io::Result<()> {
+        if self.transparent {
+            let rgba = self.rgba_buffer();
+            return write_png(file_path, (self.width, self.height), &rgba, true, metadata);
+        }
+
+        write_png(
+            file_path,
+            (self.width, self.height),
+            &self.buffer,
+            false,
+            metadata,
+        )
+    }
+
     /// Saves the current canvas as an image file.
     ///
+    /// When `transparent` is set, the image is saved with an alpha channel and every pixel
+    /// matching the background color becomes fully transparent. The target format must
+    /// support transparency (e.g. PNG). Anti-aliased edges keep an opaque halo and data
+    /// pixels matching the background color are punched out; see `set_transparent`.
+    ///
     /// # Parameters
     /// - `file_path`: The path to save the image file.
     ///
     /// # Panics
     /// Panics if the image cannot be saved.
     pub fn save_as_image(&self, file_path: &str) {
-        use image::{ImageBuffer, RgbImage};
+        use image::{ImageBuffer, RgbImage, RgbaImage};
+
+        if self.transparent {
+            let rgba = self.rgba_buffer();
+            let img: RgbaImage = ImageBuffer::from_raw(self.width, self.height, rgba)
+                .expect("Failed to create image buffer");
+            img.save(file_path).expect("Failed to save image");
+            return;
+        }
 
         let img: RgbImage = ImageBuffer::from_raw(self.width, self.height, self.buffer.clone())
             .expect("Failed to create image buffer");
@@ -0,0 +1,276 @@
+//! Text metadata embedded in PNG exports.
+//!
+//! Metadata is written as `tEXt` chunks when keyword and text are Latin-1, and as `iTXt`
+//! chunks otherwise. The figure spec is always written as a compressed `iTXt` chunk, so
+//! downstream tooling can trace a chart back to its source and regenerate it.
+
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use png::text_metadata::{ITXtChunk, TEXtChunk};
+
+use crate::figure::utilities::timezone::civil_from_days;
+
+/// Keyword of the title chunk, as registered in the PNG specification.
+pub const KEYWORD_TITLE: &str = "Title";
+/// Keyword of the description chunk, as registered in the PNG specification.
+pub const KEYWORD_DESCRIPTION: &str = "Description";
+/// Keyword of the creation time chunk, as registered in the PNG specification.
+pub const KEYWORD_CREATION_TIME: &str = "Creation Time";
+/// Keyword of the software chunk, as registered in the PNG specification.
+pub const KEYWORD_SOFTWARE: &str = "Software";
+/// Keyword of the serialized figure spec chunk.
+pub const KEYWORD_SPEC: &str = "dataviz:spec";
+
+/// Text metadata written into, or read from, a PNG file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PngMetadata {
+    /// Title of the chart.
+    pub title: Option<String>,
+    /// Longer description of the chart.
+    pub description: Option<String>,
+    /// Creation time, as an RFC 3339 timestamp.
+    pub creation_time: Option<String>,
+    /// Serialized specification of the figure (e.g. JSON) from which it can be regenerated.
+    pub spec: Option<String>,
+    /// Further `(keyword, text)` pairs. Keywords must be 1 to 79 Latin-1 characters.
+    pub entries: Vec<(String, String)>,
+}
+
+impl PngMetadata {
+    /// Creates metadata with a title and the current time as creation time.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the chart.
+    ///
+    /// # Returns
+    /// A new `PngMetadata` instance without description, spec or further entries.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::canvas::pngmetadata::PngMetadata;
+    ///
+    /// let mut metadata = PngMetadata::new("Monthly Revenue");
+    /// metadata.spec = Some(spec_json);
+    /// canvas.save_as_png_with_metadata("revenue.png", &metadata)?;
+    /// ```
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: Some(title.to_string()),
+            creation_time: Some(rfc3339_utc(SystemTime::now())),
+            ..Self::default()
+        }
+    }
+
+    /// Returns all entries as `(keyword, text)` pairs in the order they are written, including
+    /// a `Software` entry naming this library.
+    pub fn chunks(&self) -> Vec<(String, String)> {
+        let mut chunks = Vec::new();
+        for (keyword, text) in [
+            (KEYWORD_TITLE, &self.title),
+            (KEYWORD_DESCRIPTION, &self.description),
+            (KEYWORD_CREATION_TIME, &self.creation_time),
+        ] {
+            if let Some(text) = text {
+                chunks.push((keyword.to_string(), text.clone()));
+            }
+        }
+        chunks.push((
+            KEYWORD_SOFTWARE.to_string(),
+            concat!("dataviz ", env!("CARGO_PKG_VERSION")).to_string(),
+        ));
+        chunks.extend(self.entries.iter().cloned());
+        if let Some(spec) = &self.spec {
+            chunks.push((KEYWORD_SPEC.to_string(), spec.clone()));
+        }
+        chunks
+    }
+
+    /// Reads the text metadata of a PNG file.
+    ///
+    /// # Parameters
+    /// - `file_path`: The path of the PNG file.
+    ///
+    /// # Returns
+    /// The metadata. Registered keywords fill their fields, the `Software` entry is skipped
+    /// and all other chunks end up in `entries`.
+    ///
+    /// # Errors
+    /// Returns an `io::Result` if the file cannot be read or is not a valid PNG.
+    pub fn read(file_path: &str) -> io::Result<Self> {
+        let decoder = png::Decoder::new(BufReader::new(File::open(file_path)?));
+        let reader = decoder.read_info().map_err(io::Error::other)?;
+        let info = reader.info();
+
+        let mut chunks: Vec<(String, String)> = info
+            .uncompressed_latin1_text
+            .iter()
+            .map(|chunk| (chunk.keyword.clone(), chunk.text.clone()))
+            .collect();
+        for chunk in &info.compressed_latin1_text {
+            let text = chunk.get_text().map_err(io::Error::other)?;
+            chunks.push((chunk.keyword.clone(), text));
+        }
+        for chunk in &info.utf8_text {
+            let text = chunk.get_text().map_err(io::Error::other)?;
+            chunks.push((chunk.keyword.clone(), text));
+        }
+
+        let mut metadata = Self::default();
+        for (keyword, text) in chunks {
+            match keyword.as_str() {
+                KEYWORD_TITLE => metadata.title = Some(text),
+                KEYWORD_DESCRIPTION => metadata.description = Some(text),
+                KEYWORD_CREATION_TIME => metadata.creation_time = Some(text),
+                KEYWORD_SPEC => metadata.spec = Some(text),
+                KEYWORD_SOFTWARE => {}
+                _ => metadata.entries.push((keyword, text)),
+            }
+        }
+        Ok(metadata)
+    }
+}
+
+/// Writes 8-bit RGB or RGBA pixels as a PNG file with text metadata.
+///
+/// # Parameters
+/// - `file_path`: The path to save the PNG file.
+/// - `width`, `height`: The image size in pixels.
+/// - `pixels`: The pixels, row by row, 3 bytes per pixel without alpha and 4 with.
+/// - `alpha`: Whether `pixels` contains an alpha channel.
+/// - `metadata`: The text metadata to embed.
+///
+/// # Errors
+/// Returns an `io::Result` if a keyword is invalid or the file cannot be written.
+pub fn write_png(
+    file_path: &str,
+    size: (u32, u32),
+    pixels: &[u8],
+    alpha: bool,
+    metadata: &PngMetadata,
+) -> io::Result<()> {
+    let mut writer = png_writer(file_path, size, alpha, metadata)?;
+    writer.write_image_data(pixels).map_err(io::Error::other)?;
+    writer.finish().map_err(io::Error::other)
+}
+
+/// Writes a PNG file with text metadata from pixels produced one band of rows at a time.
+///
+/// Each band is compressed and written before the next one is requested, so only a single
+/// band is held in memory and images far larger than the available memory can be written.
+///
+/// # Parameters
+/// - `file_path`: The path to save the PNG file.
+/// - `width`, `height`: The image size in pixels.
+/// - `alpha`: Whether the bands contain an alpha channel.
+/// - `metadata`: The text metadata to embed.
+/// - `tile_height`: The number of rows per band, at least 1. The last band may be shorter.
+/// - `render_tile`: Called with the first row and the number of rows of each band, top to
+///   bottom. Returns the pixels of the band, row by row, 3 bytes per pixel without alpha
+///   and 4 with.
+///
+/// # Errors
+/// Returns an `io::Result` if a keyword is invalid, `render_tile` fails or returns the
+/// wrong number of bytes, or the file cannot be written.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::canvas::pngmetadata::{write_png_tiled, PngMetadata};
+///
+/// // A white 20,000 x 20,000 image, 256 rows at a time
+/// let metadata = PngMetadata::default();
+/// write_png_tiled("poster.png", (20_000, 20_000), false, &metadata, 256, |_, rows| {
+///     Ok(vec![255; rows as usize * 20_000 * 3])
+/// })?;
+/// ```
+pub fn write_png_tiled(
+    file_path: &str,
+    (width, height): (u32, u32),
+    alpha: bool,
+    metadata: &PngMetadata,
+    tile_height: u32,
+    mut render_tile: impl FnMut(u32, u32) -> io::Result<Vec<u8>>,
+) -> io::Result<()> {
+    let mut writer = png_writer(file_path, (width, height), alpha, metadata)?;
+    let bytes_per_row = width as usize * if alpha { 4 } else { 3 };
+    let tile_height = tile_height.max(1);
+
+    let mut stream = writer.stream_writer().map_err(io::Error::other)?;
+    for y in (0..height).step_by(tile_height as usize) {
+        let rows = tile_height.min(height - y);
+        let pixels = render_tile(y, rows)?;
+        if pixels.len() != rows as usize * bytes_per_row {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Tile at row {y} has {} bytes, expected {}",
+                    pixels.len(),
+                    rows as usize * bytes_per_row
+                ),
+            ));
+        }
+        stream.write_all(&pixels)?;
+    }
+    stream.finish().map_err(io::Error::other)?;
+    writer.finish().map_err(io::Error::other)
+}
+
+/// Creates a PNG writer for 8-bit RGB or RGBA pixels and writes the header and the text
+/// metadata, leaving the image data to the caller.
+fn png_writer(
+    file_path: &str,
+    (width, height): (u32, u32),
+    alpha: bool,
+    metadata: &PngMetadata,
+) -> io::Result<png::Writer<BufWriter<File>>> {
+    let file = BufWriter::new(File::create(file_path)?);
+    let mut encoder = png::Encoder::new(file, width, height);
+    encoder.set_color(if alpha {
+        png::ColorType::Rgba
+    } else {
+        png::ColorType::Rgb
+    });
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+
+    for (keyword, text) in metadata.chunks() {
+        if keyword == KEYWORD_SPEC {
+            let mut chunk = ITXtChunk::new(keyword, text);
+            chunk.compressed = true;
+            writer.write_text_chunk(&chunk)
+        } else if is_latin1(&keyword) && is_latin1(&text) {
+            writer.write_text_chunk(&TEXtChunk::new(keyword, text))
+        } else {
+            writer.write_text_chunk(&ITXtChunk::new(keyword, text))
+        }
+        .map_err(io::Error::other)?;
+    }
+    Ok(writer)
+}
+
+/// Checks whether text can be stored in a `tEXt` chunk.
+fn is_latin1(text: &str) -> bool {
+    text.chars().all(|c| (c as u32) < 0x100)
+}
+
+/// Formats a time as an RFC 3339 timestamp in UTC, e.g. `2024-05-17T08:30:00Z`.
+///
+/// # Parameters
+/// - `time`: The time to format. Times before the Unix epoch are clamped to it.
+pub fn rfc3339_utc(time: SystemTime) -> String {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let (days, rest) = (seconds / 86_400, seconds % 86_400);
+    let (year, month, day) = civil_from_days(days as i64);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rest / 3_600,
+        rest % 3_600 / 60,
+        rest % 60
+    )
+}
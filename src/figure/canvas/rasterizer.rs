@@ -0,0 +1,127 @@
+/// The RGB pixel buffer of a `PixelCanvas`, as handed to a `Rasterizer`.
+pub struct RasterTarget<'a> {
+    /// Pixel data, three bytes per pixel, row by row.
+    pub buffer: &'a mut [u8],
+    /// Width of the buffer in pixels.
+    pub width: u32,
+    /// Height of the buffer in pixels.
+    pub height: u32,
+}
+
+impl RasterTarget<'_> {
+    /// Blends a color into a pixel. Pixels outside the buffer are skipped.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the pixel.
+    /// - `color`: The RGB color to blend.
+    /// - `alpha`: The opacity of the color (0.0 to 1.0); 1.0 or more replaces the pixel.
+    pub fn blend(&mut self, x: u32, y: u32, color: [u8; 3], alpha: f64) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let index = ((y * self.width + x) * 3) as usize;
+        let Some(pixel) = self.buffer.get_mut(index..index + 3) else {
+            return;
+        };
+        for (channel, &value) in pixel.iter_mut().zip(&color) {
+            *channel = if alpha >= 1.0 {
+                value
+            } else {
+                (value as f64 * alpha + *channel as f64 * (1.0 - alpha)) as u8
+            };
+        }
+    }
+}
+
+/// Backend that fills the shapes of a `PixelCanvas`: polygons, and the area fills, ribbons
+/// and Bézier paths built on them.
+///
+/// `CpuRasterizer` is the default. Another backend is installed per canvas with
+/// `PixelCanvas::set_rasterizer`; with the `antialiasing` feature, `SkiaRasterizer` fills
+/// shapes with anti-aliased edges. Lines, text and single pixels are always drawn by the
+/// canvas itself.
+///
+/// # Example
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use dataviz::figure::canvas::skiarasterizer::SkiaRasterizer;
+///
+/// let mut canvas = PixelCanvas::new(800, 600, [255, 255, 255], 60);
+/// canvas.set_rasterizer(Arc::new(SkiaRasterizer));
+/// area_chart.draw(&mut canvas);
+/// ```
+pub trait Rasterizer: Send + Sync {
+    /// Fills a polygon, possibly with holes, using the even-odd rule, blending its color
+    /// with the target.
+    ///
+    /// # Parameters
+    /// - `target`: The buffer to draw into.
+    /// - `rings`: The outer boundary and holes of the polygon, in canvas coordinates.
+    ///   Rings are closed implicitly.
+    /// - `color`: The RGB fill color.
+    /// - `alpha`: The opacity of the fill (0.0 to 1.0).
+    fn fill_polygon(
+        &self,
+        target: &mut RasterTarget<'_>,
+        rings: &[Vec<(f64, f64)>],
+        color: [u8; 3],
+        alpha: f64,
+    );
+}
+
+/// The default rasterizer: a scanline fill without anti-aliasing, filling every pixel whose
+/// center lies inside the shape.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CpuRasterizer;
+
+impl Rasterizer for CpuRasterizer {
+    fn fill_polygon(
+        &self,
+        target: &mut RasterTarget<'_>,
+        rings: &[Vec<(f64, f64)>],
+        color: [u8; 3],
+        alpha: f64,
+    ) {
+        for (row, start, end) in polygon_spans(rings, target.width, target.height) {
+            for x in start..end {
+                target.blend(x, row, color, alpha);
+            }
+        }
+    }
+}
+
+/// Computes the runs of pixels whose centers lie inside a polygon (even-odd rule), as
+/// `(row, start, end)` with `end` exclusive, clipped to a buffer of the given size.
+fn polygon_spans(rings: &[Vec<(f64, f64)>], width: u32, height: u32) -> Vec<(u32, u32, u32)> {
+    let mut spans = Vec::new();
+    let points = rings.iter().flatten();
+    let y_min = points.clone().map(|p| p.1).fold(f64::INFINITY, f64::min);
+    let y_max = points.map(|p| p.1).fold(f64::NEG_INFINITY, f64::max);
+    if !y_min.is_finite() || !y_max.is_finite() {
+        return spans;
+    }
+
+    let first_row = y_min.floor().max(0.0) as u32;
+    let last_row = y_max.ceil().min(height as f64) as u32;
+    let mut crossings = Vec::new();
+    for row in first_row..last_row {
+        let y = row as f64 + 0.5;
+        crossings.clear();
+        for ring in rings {
+            for (index, &(x1, y1)) in ring.iter().enumerate() {
+                let (x2, y2) = ring[(index + 1) % ring.len()];
+                if (y1 <= y) != (y2 <= y) {
+                    crossings.push(x1 + (y - y1) / (y2 - y1) * (x2 - x1));
+                }
+            }
+        }
+        crossings.sort_by(f64::total_cmp);
+
+        for span in crossings.chunks_exact(2) {
+            let start = (span[0] - 0.5).ceil().max(0.0) as u32;
+            let end = ((span[1] - 0.5).floor() + 1.0).min(width as f64).max(0.0) as u32;
+            spans.push((row, start, end));
+        }
+    }
+    spans
+}
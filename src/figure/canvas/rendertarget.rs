@@ -0,0 +1,49 @@
+use super::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas};
+
+/// The canvas a figure is being drawn on, as passed to render hooks.
+pub enum RenderTarget<'a> {
+    /// A raster canvas, drawn by `Drawer::draw`.
+    Pixel(&'a mut PixelCanvas),
+    /// A vector canvas, drawn by `Drawer::draw_svg`.
+    Svg(&'a mut SvgCanvas),
+}
+
+impl RenderTarget<'_> {
+    /// Returns the width of the canvas in pixels.
+    pub fn width(&self) -> u32 {
+        match self {
+            RenderTarget::Pixel(canvas) => canvas.width,
+            RenderTarget::Svg(canvas) => canvas.width,
+        }
+    }
+
+    /// Returns the height of the canvas in pixels.
+    pub fn height(&self) -> u32 {
+        match self {
+            RenderTarget::Pixel(canvas) => canvas.height,
+            RenderTarget::Svg(canvas) => canvas.height,
+        }
+    }
+
+    /// Returns the margin around the plot area in pixels.
+    pub fn margin(&self) -> u32 {
+        match self {
+            RenderTarget::Pixel(canvas) => canvas.margin,
+            RenderTarget::Svg(canvas) => canvas.margin,
+        }
+    }
+
+    /// Returns the plot area inside the margins.
+    ///
+    /// # Returns
+    /// A tuple `(left, top, right, bottom)` in canvas pixels.
+    pub fn plot_area(&self) -> (f64, f64, f64, f64) {
+        let margin = self.margin() as f64;
+        (
+            margin,
+            margin,
+            self.width() as f64 - margin,
+            self.height() as f64 - margin,
+        )
+    }
+}
@@ -0,0 +1,468 @@
+//! Multi-page PDF reports of figures, headings, text and captions.
+//!
+//! A `Report` collects blocks in reading order and lays them out on pages when it is saved:
+//! headings are numbered and listed in a table of contents with page numbers and links,
+//! figures are scaled to the page width and captioned, and every page gets a page number.
+
+use std::io;
+
+use crate::figure::canvas::{
+    pdfdocument::{PdfDocument, PdfFont, A4_HEIGHT, A4_WIDTH},
+    pixelcanvas::PixelCanvas,
+    svgcanvas::SvgCanvas,
+};
+
+/// Font size of the report title.
+const TITLE_SIZE: f64 = 24.0;
+/// Font size of headings, including the table of contents heading.
+const HEADING_SIZE: f64 = 16.0;
+/// Font size of text and table of contents entries.
+const TEXT_SIZE: f64 = 11.0;
+/// Font size of figure captions and page numbers.
+const CAPTION_SIZE: f64 = 9.0;
+/// Line height relative to the font size.
+const LINE_SPACING: f64 = 1.4;
+/// Height of a table of contents entry in points.
+const CONTENTS_ENTRY_HEIGHT: f64 = TEXT_SIZE * LINE_SPACING * 1.2;
+/// Vertical space between blocks in points.
+const BLOCK_SPACING: f64 = 12.0;
+/// Size of a canvas pixel in points, so figures keep their on-screen size at 96 DPI.
+const POINTS_PER_PIXEL: f64 = 0.75;
+/// Resolution multiplier used when rasterizing SVG figures, for sharp print output.
+const SVG_RASTER_SCALE: f32 = 2.0;
+
+/// A part of the report, in reading order.
+enum ReportBlock {
+    Heading(String),
+    Text(String),
+    Figure {
+        /// Size of the pixel data.
+        pixels: (u32, u32),
+        rgb: Vec<u8>,
+        /// Natural size on the page in points, before fitting to the page.
+        size: (f64, f64),
+        caption: String,
+    },
+    PageBreak,
+}
+
+/// Where the next block is placed while laying out a report.
+struct Cursor {
+    page: usize,
+    y: f64,
+}
+
+/// Builder for multi-page PDF reports.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::canvas::report::Report;
+///
+/// let mut report = Report::new("Quarterly Report");
+/// report.add_heading("Revenue");
+/// report.add_text("Revenue grew in every region except the north-east.");
+/// report.add_figure(&revenue_canvas, "Revenue per region and month");
+/// report.add_heading("Costs");
+/// report.add_svg_figure(&costs_svg, "Costs per department")?;
+/// report.save("report.pdf")?;
+/// ```
+pub struct Report {
+    title: String,
+    page_size: (f64, f64),
+    margin: f64,
+    table_of_contents: bool,
+    blocks: Vec<ReportBlock>,
+}
+
+impl Report {
+    /// Creates an empty report on A4 portrait pages with a table of contents.
+    ///
+    /// # Parameters
+    /// - `title`: The title on the first page and in the document properties.
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            page_size: (A4_WIDTH, A4_HEIGHT),
+            margin: 56.0,
+            table_of_contents: true,
+            blocks: Vec::new(),
+        }
+    }
+
+    /// Sets the page size in points (1/72 inch), e.g. `(A4_HEIGHT, A4_WIDTH)` for landscape.
+    pub fn set_page_size(&mut self, width: f64, height: f64) {
+        self.page_size = (width, height);
+    }
+
+    /// Sets the page margin on all sides in points.
+    pub fn set_margin(&mut self, margin: f64) {
+        self.margin = margin;
+    }
+
+    /// Sets whether a table of contents listing the headings precedes the content.
+    pub fn set_table_of_contents(&mut self, enabled: bool) {
+        self.table_of_contents = enabled;
+    }
+
+    /// Adds a numbered heading, listed in the table of contents and the PDF outline.
+    pub fn add_heading(&mut self, text: &str) {
+        self.blocks.push(ReportBlock::Heading(text.to_string()));
+    }
+
+    /// Adds a paragraph of text, wrapped to the page width. Blank lines start new paragraphs.
+    pub fn add_text(&mut self, text: &str) {
+        self.blocks.push(ReportBlock::Text(text.to_string()));
+    }
+
+    /// Adds a rendered pixel figure with a numbered caption.
+    ///
+    /// # Parameters
+    /// - `canvas`: The canvas the figure was drawn on.
+    /// - `caption`: The caption below the figure; an empty caption only shows the number.
+    pub fn add_figure(&mut self, canvas: &PixelCanvas, caption: &str) {
+        self.blocks.push(ReportBlock::Figure {
+            pixels: (canvas.width, canvas.height),
+            rgb: canvas.buffer.clone(),
+            size: (
+                canvas.width as f64 * POINTS_PER_PIXEL,
+                canvas.height as f64 * POINTS_PER_PIXEL,
+            ),
+            caption: caption.to_string(),
+        });
+    }
+
+    /// Adds a rendered SVG figure with a numbered caption.
+    ///
+    /// The SVG is rasterized at twice its size, so it stays sharp when printed.
+    ///
+    /// # Parameters
+    /// - `canvas`: The canvas the figure was drawn on.
+    /// - `caption`: The caption below the figure; an empty caption only shows the number.
+    ///
+    /// # Errors
+    /// Returns an `io::Result` if the SVG cannot be rendered.
+    pub fn add_svg_figure(&mut self, canvas: &SvgCanvas, caption: &str) -> io::Result<()> {
+        let pixmap = canvas.rasterize(SVG_RASTER_SCALE)?;
+        // Composite the premultiplied pixels over a white page
+        let rgb = pixmap
+            .data()
+            .chunks_exact(4)
+            .flat_map(|pixel| {
+                let uncovered = 255 - pixel[3];
+                [
+                    pixel[0].saturating_add(uncovered),
+                    pixel[1].saturating_add(uncovered),
+                    pixel[2].saturating_add(uncovered),
+                ]
+            })
+            .collect();
+
+        self.blocks.push(ReportBlock::Figure {
+            pixels: (pixmap.width(), pixmap.height()),
+            rgb,
+            size: (
+                canvas.width as f64 * POINTS_PER_PIXEL,
+                canvas.height as f64 * POINTS_PER_PIXEL,
+            ),
+            caption: caption.to_string(),
+        });
+        Ok(())
+    }
+
+    /// Continues the report on a new page.
+    pub fn add_page_break(&mut self) {
+        self.blocks.push(ReportBlock::PageBreak);
+    }
+
+    /// Lays out the report on pages.
+    ///
+    /// # Returns
+    /// The finished `PdfDocument`, which may still be extended before saving.
+    pub fn build(&self) -> PdfDocument {
+        let mut pdf = PdfDocument::new();
+        pdf.set_title(&self.title);
+        let (page_width, page_height) = self.page_size;
+        let content_width = page_width - 2.0 * self.margin;
+        let bottom = page_height - self.margin;
+
+        let headings: Vec<String> = self
+            .blocks
+            .iter()
+            .filter_map(|block| match block {
+                ReportBlock::Heading(text) => Some(text.clone()),
+                _ => None,
+            })
+            .enumerate()
+            .map(|(index, text)| format!("{}. {text}", index + 1))
+            .collect();
+        let with_contents = self.table_of_contents && !headings.is_empty();
+
+        // The title and table of contents come first; their pages are reserved up front and
+        // filled in once the page of every heading is known
+        let first = pdf.add_page(page_width, page_height);
+        let title_bottom = self.margin + TITLE_SIZE * LINE_SPACING;
+        pdf.draw_text(
+            first,
+            self.margin,
+            self.margin + TITLE_SIZE,
+            &self.title,
+            PdfFont::Bold,
+            TITLE_SIZE,
+        );
+        let mut cursor = Cursor {
+            page: first,
+            y: title_bottom + BLOCK_SPACING,
+        };
+        let contents_top = cursor.y;
+        if with_contents {
+            let mut y = contents_top + HEADING_SIZE * LINE_SPACING + BLOCK_SPACING / 2.0;
+            for _ in &headings {
+                if y + CONTENTS_ENTRY_HEIGHT > bottom {
+                    pdf.add_page(page_width, page_height);
+                    y = self.margin;
+                }
+                y += CONTENTS_ENTRY_HEIGHT;
+            }
+            cursor = Cursor {
+                page: pdf.add_page(page_width, page_height),
+                y: self.margin,
+            };
+        }
+
+        let mut heading_positions = Vec::new();
+        let mut figure_number = 0;
+        for block in &self.blocks {
+            match block {
+                ReportBlock::Heading(_) => {
+                    let text = &headings[heading_positions.len()];
+                    let height = HEADING_SIZE * LINE_SPACING;
+                    if cursor.y > self.margin {
+                        cursor.y += BLOCK_SPACING;
+                    }
+                    // Keep the heading on the same page as the first lines below it
+                    self.reserve(
+                        &mut pdf,
+                        &mut cursor,
+                        height + 2.0 * TEXT_SIZE * LINE_SPACING,
+                    );
+                    pdf.draw_text(
+                        cursor.page,
+                        self.margin,
+                        cursor.y + HEADING_SIZE,
+                        text,
+                        PdfFont::Bold,
+                        HEADING_SIZE,
+                    );
+                    pdf.add_outline_entry(text, cursor.page, cursor.y);
+                    heading_positions.push((cursor.page, cursor.y));
+                    cursor.y += height;
+                }
+                ReportBlock::Text(text) => {
+                    for paragraph in text.split("\n\n") {
+                        let line_height = TEXT_SIZE * LINE_SPACING;
+                        for line in wrap(paragraph, PdfFont::Regular, TEXT_SIZE, content_width) {
+                            self.reserve(&mut pdf, &mut cursor, line_height);
+                            pdf.draw_text(
+                                cursor.page,
+                                self.margin,
+                                cursor.y + TEXT_SIZE,
+                                &line,
+                                PdfFont::Regular,
+                                TEXT_SIZE,
+                            );
+                            cursor.y += line_height;
+                        }
+                        cursor.y += BLOCK_SPACING / 2.0;
+                    }
+                }
+                ReportBlock::Figure {
+                    pixels,
+                    rgb,
+                    size,
+                    caption,
+                } => {
+                    figure_number += 1;
+                    let caption = if caption.is_empty() {
+                        format!("Figure {figure_number}")
+                    } else {
+                        format!("Figure {figure_number}: {caption}")
+                    };
+                    let caption_lines =
+                        wrap(&caption, PdfFont::Italic, CAPTION_SIZE, content_width);
+                    let caption_height = caption_lines.len() as f64 * CAPTION_SIZE * LINE_SPACING
+                        + BLOCK_SPACING / 2.0;
+
+                    // Shrink the figure to the page width and, with its caption, the page height
+                    let available_height = bottom - self.margin - caption_height - BLOCK_SPACING;
+                    let fit = (content_width / size.0)
+                        .min(available_height / size.1)
+                        .min(1.0);
+                    let (width, height) = (size.0 * fit, size.1 * fit);
+
+                    self.reserve(
+                        &mut pdf,
+                        &mut cursor,
+                        BLOCK_SPACING + height + caption_height,
+                    );
+                    cursor.y += BLOCK_SPACING;
+                    let image = pdf.add_image(pixels.0, pixels.1, rgb);
+                    let x = self.margin + (content_width - width) / 2.0;
+                    pdf.draw_image(cursor.page, image, x, cursor.y, width, height);
+                    cursor.y += height + BLOCK_SPACING / 2.0;
+
+                    for line in caption_lines {
+                        let line_width = PdfFont::Italic.text_width(&line, CAPTION_SIZE);
+                        pdf.draw_text(
+                            cursor.page,
+                            self.margin + (content_width - line_width) / 2.0,
+                            cursor.y + CAPTION_SIZE,
+                            &line,
+                            PdfFont::Italic,
+                            CAPTION_SIZE,
+                        );
+                        cursor.y += CAPTION_SIZE * LINE_SPACING;
+                    }
+                }
+                ReportBlock::PageBreak => {
+                    cursor = Cursor {
+                        page: pdf.add_page(page_width, page_height),
+                        y: self.margin,
+                    };
+                }
+            }
+        }
+
+        if with_contents {
+            self.draw_contents(&mut pdf, contents_top, &headings, &heading_positions);
+        }
+
+        // Page numbers
+        let page_count = pdf.page_count();
+        for page in 0..page_count {
+            let label = format!("{} / {page_count}", page + 1);
+            let width = PdfFont::Regular.text_width(&label, CAPTION_SIZE);
+            pdf.draw_text(
+                page,
+                (page_width - width) / 2.0,
+                page_height - self.margin / 2.0,
+                &label,
+                PdfFont::Regular,
+                CAPTION_SIZE,
+            );
+        }
+
+        pdf
+    }
+
+    /// Lays out the report and saves it as a PDF file.
+    ///
+    /// # Parameters
+    /// - `file_path`: The path to save the PDF file.
+    ///
+    /// # Errors
+    /// Returns an `io::Result` if the file cannot be written.
+    pub fn save(&self, file_path: &str) -> io::Result<()> {
+        self.build().save(file_path)
+    }
+
+    /// Moves the cursor to a new page unless `height` points fit below it.
+    fn reserve(&self, pdf: &mut PdfDocument, cursor: &mut Cursor, height: f64) {
+        let (page_width, page_height) = self.page_size;
+        if cursor.y + height > page_height - self.margin && cursor.y > self.margin {
+            cursor.page = pdf.add_page(page_width, page_height);
+            cursor.y = self.margin;
+        }
+    }
+
+    /// Draws the table of contents on the pages reserved for it, with dot leaders, page
+    /// numbers and links to the headings.
+    fn draw_contents(
+        &self,
+        pdf: &mut PdfDocument,
+        top: f64,
+        headings: &[String],
+        positions: &[(usize, f64)],
+    ) {
+        let (page_width, page_height) = self.page_size;
+        let right = page_width - self.margin;
+        let dot_width = PdfFont::Regular.text_width(".", TEXT_SIZE);
+
+        let mut page = 0;
+        pdf.draw_text(
+            page,
+            self.margin,
+            top + HEADING_SIZE,
+            "Contents",
+            PdfFont::Bold,
+            HEADING_SIZE,
+        );
+        let mut y = top + HEADING_SIZE * LINE_SPACING + BLOCK_SPACING / 2.0;
+        for (text, &(target_page, target_y)) in headings.iter().zip(positions) {
+            if y + CONTENTS_ENTRY_HEIGHT > page_height - self.margin {
+                page += 1;
+                y = self.margin;
+            }
+            let number = (target_page + 1).to_string();
+            let number_width = PdfFont::Regular.text_width(&number, TEXT_SIZE);
+            let text_width = PdfFont::Regular.text_width(text, TEXT_SIZE);
+            let gap = right - number_width - self.margin - text_width;
+            let dots = ".".repeat(((gap - 2.0 * dot_width) / dot_width).max(0.0) as usize);
+            let baseline = y + TEXT_SIZE;
+
+            pdf.draw_text(
+                page,
+                self.margin,
+                baseline,
+                text,
+                PdfFont::Regular,
+                TEXT_SIZE,
+            );
+            pdf.draw_text(
+                page,
+                right - number_width - dot_width * (dots.len() as f64 + 1.0),
+                baseline,
+                &dots,
+                PdfFont::Regular,
+                TEXT_SIZE,
+            );
+            pdf.draw_text(
+                page,
+                right - number_width,
+                baseline,
+                &number,
+                PdfFont::Regular,
+                TEXT_SIZE,
+            );
+            pdf.add_link(
+                page,
+                (self.margin, y, right - self.margin, CONTENTS_ENTRY_HEIGHT),
+                target_page,
+                target_y,
+            );
+            y += CONTENTS_ENTRY_HEIGHT;
+        }
+    }
+}
+
+/// Breaks text into lines no wider than `max_width`, at spaces. Single words wider than the
+/// line stay on a line of their own.
+fn wrap(text: &str, font: PdfFont, size: f64, max_width: f64) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{line} {word}")
+        };
+        if !line.is_empty() && font.text_width(&candidate, size) > max_width {
+            lines.push(std::mem::replace(&mut line, word.to_string()));
+        } else {
+            line = candidate;
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
@@ -1,21 +1,56 @@
-use crate::figure::utilities::linetype::LineType;
+use crate::figure::{
+    canvas::{
+        htmlexport::to_html,
+        pngmetadata::{write_png, write_png_tiled, PngMetadata},
+        svgelement::SvgElements,
+    },
+    configuration::figureconfig::TICK_LINE_SPACING,
+    utilities::{
+        arrowgeometry::arrow_head, axistype::AxisType, bezierpath::BezierPath,
+        cornerradii::CornerRadii, linetype::LineType, sizepreset::SizePreset, texthalo::TextHalo,
+    },
+};
+use image::{ImageFormat, RgbImage};
+use resvg::{
+    tiny_skia::{self, Pixmap},
+    usvg::{self, fontdb},
+};
 use std::{
     fs::File,
-    io::{self, Write},
+    io::{self, Cursor, Write},
+    sync::Arc,
 };
 
+/// Width of the band along the canvas edges that is ignored when detecting content, so the
+/// frame stroke drawn around the whole figure does not count as content. Shared by
+/// `SvgCanvas` and `PixelCanvas`.
+pub(crate) const FRAME_INSET: u32 = 3;
+
+/// Class of the groups holding the elements of one data series.
+pub const SERIES_CLASS: &str = "dataviz-series";
+
+/// Class of the groups holding one legend entry.
+pub const LEGEND_ITEM_CLASS: &str = "dataviz-legend-item";
+
 /// A structure for creating and managing an SVG-based drawing canvas.
 pub struct SvgCanvas {
     /// Width of the SVG canvas.
     pub width: u32,
     /// Height of the SVG canvas.
     pub height: u32,
-    /// A vector storing SVG elements as strings.
-    pub elements: Vec<String>,
+    /// The SVG elements in drawing order, without the enclosing `<svg>` tag.
+    pub elements: SvgElements,
     /// Margin size for the SVG canvas.
     pub margin: u32,
     /// Background color of the SVG canvas.
     pub background_color: String,
+    /// Accessible name of the SVG, emitted as its `<title>` and `aria-label`.
+    pub title: Option<String>,
+    /// Accessible description of the SVG, emitted as its `<desc>`.
+    pub description: Option<String>,
+    /// Halo drawn behind tick labels, value labels and reference line labels. Drawers set
+    /// it to `FigureConfig::text_halo`.
+    pub text_halo: Option<TextHalo>,
 }
 
 impl SvgCanvas {
@@ -33,16 +68,41 @@ impl SvgCanvas {
         Self {
             width,
             height,
-            elements: vec![format!(
-                r#"<?xml version="1.0" encoding="UTF-8"?>
-<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {} {}">"#,
-                width, height
-            )],
+            elements: SvgElements::default(),
             margin,
             background_color: background_color.to_string(),
+            title: None,
+            description: None,
+            text_halo: None,
         }
     }
 
+    /// Sets the accessible name and description of the SVG for screen readers.
+    ///
+    /// Drawers set the name to the figure title and the description to
+    /// `FigureConfig::description`, unless they were set before drawing.
+    ///
+    /// # Parameters
+    /// - `title`: The accessible name, emitted as `<title>` and `aria-label`.
+    /// - `description`: The longer description, emitted as `<desc>`.
+    pub fn set_accessibility(&mut self, title: Option<&str>, description: Option<&str>) {
+        self.title = title.map(str::to_string);
+        self.description = description.map(str::to_string);
+    }
+
+    /// Creates a new `SvgCanvas` with the dimensions and margin of a size preset.
+    ///
+    /// # Parameters
+    /// - `preset`: The named size, e.g. `SizePreset::Slide16x9`.
+    /// - `background_color`: The background color as a string.
+    ///
+    /// # Returns
+    /// A new `SvgCanvas` instance.
+    pub fn with_preset(preset: SizePreset, background_color: &str) -> Self {
+        let (width, height) = preset.size();
+        Self::new(width, height, background_color, preset.margin())
+    }
+
     /// Clears the SVG canvas by removing all elements and reinitializing.
     pub fn clear(&mut self) {
         // Clear all SVG elements
@@ -159,6 +219,96 @@ impl SvgCanvas {
         ));
     }
 
+    /// Adds a rectangle with rounded corners to the SVG canvas.
+    ///
+    /// Equal radii are written as a `<rect>` with `rx`, different radii as a `<path>`.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the top-left corner.
+    /// - `width`, `height`: Dimensions of the rectangle.
+    /// - `radii`: Radii of the four corners.
+    /// - `fill_color`: Fill color of the rectangle.
+    /// - `stroke_color`: Stroke color of the rectangle.
+    /// - `stroke_width`: Width of the rectangle's border.
+    /// - `opacity`: Opacity of the rectangle fill (0.0 to 1.0).
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_rounded_rect(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: CornerRadii,
+        fill_color: &str,
+        stroke_color: &str,
+        stroke_width: f64,
+        opacity: f64,
+    ) {
+        let radii = radii.clamped(width, height);
+        if radii == CornerRadii::uniform(radii.top_left) {
+            let radius = radii.top_left;
+            self.elements.push(format!(
+                r#"<rect x="{x:.2}" y="{y:.2}" width="{width:.2}" height="{height:.2}" rx="{radius:.2}" fill="{fill_color}" stroke="{stroke_color}" stroke-width="{stroke_width:.2}" fill-opacity="{opacity}"/>"#
+            ));
+        } else {
+            self.elements.push(format!(
+                r#"<path d="{}" fill="{fill_color}" stroke="{stroke_color}" stroke-width="{stroke_width:.2}" fill-opacity="{opacity}"/>"#,
+                radii.svg_path(x, y, width, height)
+            ));
+        }
+    }
+
+    /// Adds a rectangle with rounded corners and no border to the SVG canvas.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the top-left corner.
+    /// - `width`, `height`: Dimensions of the rectangle.
+    /// - `radii`: Radii of the four corners.
+    /// - `fill_color`: Fill color of the rectangle.
+    /// - `opacity`: Opacity of the rectangle fill (0.0 to 1.0).
+    #[allow(clippy::too_many_arguments)]
+    pub fn fill_rounded_rect(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radii: CornerRadii,
+        fill_color: &str,
+        opacity: f64,
+    ) {
+        self.draw_rounded_rect(x, y, width, height, radii, fill_color, "none", 0.0, opacity);
+    }
+
+    /// Adds a closed path of lines and cubic Bézier curves to the SVG canvas, e.g. a
+    /// ribbon of a chord diagram.
+    ///
+    /// # Parameters
+    /// - `path`: The path in canvas coordinates.
+    /// - `fill_color`: Fill color of the path.
+    /// - `stroke_color`: Stroke color of the path.
+    /// - `stroke_width`: Width of the path's border.
+    /// - `opacity`: Opacity of the fill (0.0 to 1.0).
+    /// - `tooltip`: The text shown while the mouse is over the path in the interactive
+    ///   HTML export, if any.
+    pub fn draw_path(
+        &mut self,
+        path: &BezierPath,
+        fill_color: &str,
+        stroke_color: &str,
+        stroke_width: f64,
+        opacity: f64,
+        tooltip: Option<&str>,
+    ) {
+        let tooltip = tooltip
+            .map(|text| format!(r#" data-tooltip="{}""#, escape_xml(text)))
+            .unwrap_or_default();
+        self.elements.push(format!(
+            r#"<path d="{}" fill="{fill_color}" stroke="{stroke_color}" stroke-width="{stroke_width:.2}" fill-opacity="{opacity}"{tooltip}/>"#,
+            path.svg_path()
+        ));
+    }
+
     /// Adds a font style definition to the SVG canvas.
     ///
     /// # Parameters
@@ -188,6 +338,145 @@ impl SvgCanvas {
         ));
     }
 
+    /// Adds an arrow to the SVG canvas: a shaft ending in a filled triangular head at its
+    /// tip.
+    ///
+    /// # Parameters
+    /// - `start`: The tail of the arrow.
+    /// - `end`: The tip of the arrow.
+    /// - `head_length`: The length of the head, at most half the arrow.
+    /// - `color`: The color of the arrow.
+    /// - `stroke_width`: The width of the shaft.
+    pub fn draw_arrow(
+        &mut self,
+        start: (f64, f64),
+        end: (f64, f64),
+        head_length: f64,
+        color: &str,
+        stroke_width: f64,
+    ) {
+        let Some((shaft_end, head)) = arrow_head(start, end, head_length) else {
+            return;
+        };
+        self.draw_line(
+            start.0,
+            start.1,
+            shaft_end.0,
+            shaft_end.1,
+            color,
+            stroke_width,
+        );
+        let points: Vec<String> = head.iter().map(|(x, y)| format!("{x:.2},{y:.2}")).collect();
+        self.elements.push(format!(
+            r#"<polygon points="{}" fill="{color}"/>"#,
+            points.join(" ")
+        ));
+    }
+
+    /// Opens a group holding the elements of one data series.
+    ///
+    /// The group carries a `data-series` attribute so the interactive HTML export can hide
+    /// and show the series from its legend entry. Close it with `end_group`.
+    ///
+    /// # Parameters
+    /// - `label`: The series label, matching the label of its legend entry. It is also the
+    ///   accessible name of the group.
+    /// - `description`: An optional description of the series for screen readers.
+    pub fn begin_series(&mut self, label: &str, description: Option<&str>) {
+        self.elements.push(Self::series_group(label, description));
+    }
+
+    /// Closes a group opened with `begin_series`.
+    pub fn end_group(&mut self) {
+        self.elements.push("</g>".to_string());
+    }
+
+    /// Returns the opening tag of a data series group, for drawers that assemble elements
+    /// in a string before adding them to the canvas.
+    ///
+    /// # Parameters
+    /// - `label`: The series label.
+    /// - `description`: An optional description of the series, emitted as `<desc>`.
+    pub fn series_group(label: &str, description: Option<&str>) -> String {
+        let label = escape_xml(label);
+        let mut group = format!(
+            r#"<g class="{SERIES_CLASS}" data-series="{label}" role="group" aria-label="{label}">"#
+        );
+        if let Some(description) = description {
+            group.push_str(&format!("<desc>{}</desc>", escape_xml(description)));
+        }
+        group
+    }
+
+    /// Returns the opening tag of a legend entry group. In the interactive HTML export,
+    /// clicking the entry toggles the series with the same label.
+    ///
+    /// # Parameters
+    /// - `label`: The series label.
+    pub fn legend_item_group(label: &str) -> String {
+        format!(
+            r#"<g class="{LEGEND_ITEM_CLASS}" data-series="{}">"#,
+            escape_xml(label)
+        )
+    }
+
+    /// Opens a link around the following elements. Close it with `end_link`.
+    ///
+    /// # Parameters
+    /// - `url`: The link target.
+    pub fn begin_link(&mut self, url: &str) {
+        self.elements.push(Self::link_open(url));
+    }
+
+    /// Closes a link opened with `begin_link`.
+    pub fn end_link(&mut self) {
+        self.elements.push("</a>".to_string());
+    }
+
+    /// Returns the opening tag of a link, for drawers that assemble elements in a string
+    /// before adding them to the canvas.
+    ///
+    /// # Parameters
+    /// - `url`: The link target.
+    pub fn link_open(url: &str) -> String {
+        format!(r#"<a href="{}">"#, escape_xml(url))
+    }
+
+    /// Adds an invisible hover target carrying a tooltip for the interactive HTML export.
+    ///
+    /// The target does not change the rendered image.
+    ///
+    /// # Parameters
+    /// - `cx`, `cy`: Coordinates of the data point.
+    /// - `tooltip`: The text shown while the mouse is over the point.
+    pub fn draw_data_point(&mut self, cx: f64, cy: f64, tooltip: &str) {
+        self.elements.push(format!(
+            r#"<circle cx="{cx:.2}" cy="{cy:.2}" r="5" fill="black" fill-opacity="0" data-tooltip="{}"/>"#,
+            escape_xml(tooltip)
+        ));
+    }
+
+    /// Embeds a raster image in the SVG canvas as a base64 encoded PNG.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the image's top-left corner.
+    /// - `image`: The image, drawn at one user unit per pixel.
+    pub fn draw_image(&mut self, x: f64, y: f64, image: &RgbImage) {
+        let mut png = Vec::new();
+        if image
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .is_err()
+        {
+            return;
+        }
+        self.elements.push(format!(
+            r#"<image x="{x:.2}" y="{y:.2}" width="{}" height="{}" preserveAspectRatio="none" href="data:image/png;base64,{}"/>"#,
+            image.width(),
+            image.height(),
+            base64_encode(&png)
+        ));
+    }
+
     /// Adds a text element to the SVG canvas.
     ///
     /// # Parameters
@@ -201,7 +490,154 @@ impl SvgCanvas {
         ));
     }
 
-    /// Adds a text element to the SVG canvas.
+    /// Adds a text rotated by -90°, reading upwards and centered on `(x, y)`, such as the
+    /// title of a y-axis. The text is escaped.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the text's center, which is also the rotation center.
+    /// - `text`: The text content.
+    /// - `font_size`: Font size of the text.
+    /// - `color`: Text color.
+    pub fn draw_rotated_label(&mut self, x: f64, y: f64, text: &str, font_size: f64, color: &str) {
+        self.elements.push(format!(
+            r#"<text x="{x:.2}" y="{y:.2}" font-size="{font_size:.2}" text-anchor="middle" fill="{color}" transform="rotate(-90 {x:.2} {y:.2})">{}</text>"#,
+            escape_xml(text)
+        ));
+    }
+
+    /// Adds a data value label, centered on `x`, over the `text_halo` of the canvas, if any.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the text's baseline center.
+    /// - `text`: The text content.
+    /// - `font_size`: Font size of the text.
+    /// - `color`: Text color.
+    pub fn draw_value_label(&mut self, x: f64, y: f64, text: &str, font_size: f64, color: &str) {
+        let element = format!(
+            r#"<text x="{x:.2}" y="{y:.2}" font-size="{font_size:.2}" text-anchor="middle" fill="{color}">{text}</text>"#
+        );
+        let width = text.chars().count() as f64 * font_size * 0.6;
+        let element = self.with_text_halo(
+            element,
+            (x - width / 2.0, y - font_size * 0.8, width, font_size),
+        );
+        self.elements.push(element);
+    }
+
+    /// Adds the `text_halo` of the canvas, if any, to the markup of a `<text>` element.
+    ///
+    /// # Parameters
+    /// - `text_element`: The markup of the text element.
+    /// - `bounds`: The box covered by the text as `(x, y, width, height)`, estimated from
+    ///   the font size as SVG text is measured by the viewer.
+    ///
+    /// # Returns
+    /// The markup of the text with its halo, see `TextHalo::apply_svg`.
+    pub fn with_text_halo(&self, text_element: String, bounds: (f64, f64, f64, f64)) -> String {
+        match &self.text_halo {
+            Some(halo) => halo.apply_svg(&text_element, bounds),
+            None => text_element,
+        }
+    }
+
+    /// Adds an axis tick label to the SVG canvas.
+    ///
+    /// Labels containing `\n` are split into one `<tspan>` per line. X-axis labels are
+    /// centered on `x` and grow downwards; y-axis labels end at `x` and are centered on `y`.
+    /// Empty labels, such as those dropped by `FigureConfig::thin_tick_labels`, are skipped.
+    /// The `text_halo` of the canvas, if any, is drawn behind the label.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the first line's baseline.
+    /// - `text`: The label text.
+    /// - `font_size`: Font size of the text.
+    /// - `axis`: The axis the label belongs to.
+    pub fn draw_tick_label(&mut self, x: f64, y: f64, text: &str, font_size: f64, axis: AxisType) {
+        self.push_tick_label(x, y, text, None, font_size, axis);
+    }
+
+    /// Adds a category tick label that may have been shortened, see
+    /// `FigureConfig::category_label`.
+    ///
+    /// Shortened labels carry the full text as a `<title>`, shown by viewers as a tooltip.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the first line's baseline.
+    /// - `text`: The label as drawn.
+    /// - `full_text`: The full label.
+    /// - `font_size`: Font size of the text.
+    /// - `axis`: The axis the label belongs to.
+    pub fn draw_category_label(
+        &mut self,
+        x: f64,
+        y: f64,
+        text: &str,
+        full_text: &str,
+        font_size: f64,
+        axis: AxisType,
+    ) {
+        let title = (text != full_text).then_some(full_text);
+        self.push_tick_label(x, y, text, title, font_size, axis);
+    }
+
+    /// Builds a tick label element, with a `<title>` if one is given, and adds it.
+    fn push_tick_label(
+        &mut self,
+        x: f64,
+        y: f64,
+        text: &str,
+        title: Option<&str>,
+        font_size: f64,
+        axis: AxisType,
+    ) {
+        if text.is_empty() {
+            return;
+        }
+        let lines: Vec<&str> = text.lines().collect();
+        let line_height = font_size * TICK_LINE_SPACING as f64;
+        let (anchor, y) = match axis {
+            AxisType::AxisX => ("middle", y),
+            AxisType::AxisY => (
+                "end",
+                y - line_height * lines.len().saturating_sub(1) as f64 / 2.0,
+            ),
+        };
+
+        let title = title.map_or(String::new(), |title| {
+            format!("<title>{}</title>", escape_xml(title))
+        });
+        let spans: String = lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                let dy = if index == 0 { 0.0 } else { line_height };
+                let line = escape_xml(line);
+                format!(r#"<tspan x="{x:.2}" dy="{dy:.2}">{line}</tspan>"#)
+            })
+            .collect();
+        let element = format!(
+            r#"<text x="{x:.2}" y="{y:.2}" font-size="{font_size:.2}" text-anchor="{anchor}" fill="black">{title}{spans}</text>"#
+        );
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0) as f64
+            * font_size
+            * 0.6;
+        let left = match axis {
+            AxisType::AxisX => x - width / 2.0,
+            AxisType::AxisY => x - width,
+        };
+        let height = font_size + line_height * lines.len().saturating_sub(1) as f64;
+        let element = self.with_text_halo(element, (left, y - font_size * 0.8, width, height));
+        self.elements.push(element);
+    }
+
+    /// Adds a title text element to the SVG canvas.
+    ///
+    /// Unless an accessible name is already set, the title also becomes the accessible name
+    /// of the SVG.
     ///
     /// # Parameters
     /// - `x`, `y`: Coordinates of the text's position.
@@ -209,6 +645,9 @@ impl SvgCanvas {
     /// - `font_size`: Font size of the text.
     /// - `color`: Text color.
     pub fn draw_title(&mut self, x: f64, y: f64, text: &str, font_size: f64, color: &str) {
+        if self.title.is_none() && !text.is_empty() {
+            self.title = Some(text.to_string());
+        }
         self.elements.push(format!(
             r#"<text x="{x:.2}" y="{y:.2}" font-size="{font_size:.2}" text-anchor="middle" fill="{color}">{text}</text>"#
         ));
@@ -247,6 +686,285 @@ impl SvgCanvas {
         }
     }
 
+    /// Draws grid lines at the given positions.
+    ///
+    /// # Parameters
+    /// - `x_positions`: The x-coordinates of the vertical grid lines.
+    /// - `y_positions`: The y-coordinates of the horizontal grid lines.
+    /// - `x_range`: The horizontal extent `(start, end)` of the horizontal lines.
+    /// - `y_range`: The vertical extent `(start, end)` of the vertical lines.
+    /// - `color`: The color of the grid lines.
+    pub fn draw_grid_lines(
+        &mut self,
+        x_positions: &[f64],
+        y_positions: &[f64],
+        x_range: (f64, f64),
+        y_range: (f64, f64),
+        color: &str,
+    ) {
+        for &x in x_positions {
+            self.draw_line(x, y_range.0, x, y_range.1, color, 0.5);
+        }
+        for &y in y_positions {
+            self.draw_line(x_range.0, y, x_range.1, y, color, 0.5);
+        }
+    }
+
+    /// Builds the XML declaration and opening `<svg>` tag for the whole canvas.
+    fn full_header(&self) -> String {
+        self.header(0.0, 0.0, self.width as f64, self.height as f64)
+    }
+
+    /// Builds the XML declaration and opening `<svg>` tag for the given view box, followed by
+    /// the accessible `<title>` and `<desc>` when they are set.
+    fn header(&self, x: f64, y: f64, width: f64, height: f64) -> String {
+        let mut header = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<svg xmlns="http://www.w3.org/2000/svg" viewBox="{x} {y} {width} {height}" role="graphics-document document""#
+        );
+        if let Some(title) = &self.title {
+            header.push_str(&format!(r#" aria-label="{}""#, escape_xml(title)));
+        }
+        header.push('>');
+        if let Some(title) = &self.title {
+            header.push_str(&format!("<title>{}</title>", escape_xml(title)));
+        }
+        if let Some(description) = &self.description {
+            header.push_str(&format!("<desc>{}</desc>", escape_xml(description)));
+        }
+        header
+    }
+
+    /// Computes the bounding box of the visible content by rasterizing the SVG.
+    ///
+    /// A pixel counts as content when it differs from the background just inside the
+    /// outer frame. The frame stroke itself is ignored.
+    ///
+    /// # Returns
+    /// `Some((x, y, width, height))` in SVG user units, or `None` if the SVG cannot be
+    /// rendered or contains no content.
+    pub fn content_bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        let mut fontdb = fontdb::Database::new();
+        fontdb.load_system_fonts();
+        let opt = usvg::Options {
+            fontdb: Arc::new(fontdb),
+            ..usvg::Options::default()
+        };
+        let tree = usvg::Tree::from_str(&self.get_svg_as_text(), &opt).ok()?;
+
+        let mut pixmap = Pixmap::new(self.width, self.height)?;
+        resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+
+        let inset = FRAME_INSET.min(self.width / 2).min(self.height / 2);
+        let reference = pixmap.pixel(inset, inset)?;
+
+        let mut bounds: Option<(u32, u32, u32, u32)> = None;
+        for y in inset..self.height - inset {
+            for x in inset..self.width - inset {
+                if pixmap.pixel(x, y) == Some(reference) {
+                    continue;
+                }
+                bounds = Some(match bounds {
+                    None => (x, y, x, y),
+                    Some((x0, y0, x1, y1)) => (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+                });
+            }
+        }
+
+        bounds.map(|(x0, y0, x1, y1)| {
+            (
+                x0 as f64,
+                y0 as f64,
+                (x1 - x0 + 1) as f64,
+                (y1 - y0 + 1) as f64,
+            )
+        })
+    }
+
+    /// Retrieves the SVG content with its view box trimmed to the visible content.
+    ///
+    /// # Parameters
+    /// - `padding`: Space in user units to keep around the content on each side.
+    ///
+    /// # Returns
+    /// The complete SVG as a string. If no content is detected, the view box is unchanged.
+    pub fn get_svg_as_text_trimmed(&self, padding: f64) -> String {
+        let mut svg = String::new();
+        match self.content_bounds() {
+            Some((x, y, w, h)) => {
+                svg.push_str(&self.header(
+                    x - padding,
+                    y - padding,
+                    w + 2.0 * padding,
+                    h + 2.0 * padding,
+                ));
+                for element in &self.elements {
+                    svg.push_str(&element.markup);
+                }
+                svg.push_str("</svg>");
+            }
+            None => svg = self.get_svg_as_text(),
+        }
+        svg
+    }
+
+    /// Saves the SVG content to a file with its view box trimmed to the visible content.
+    ///
+    /// # Parameters
+    /// - `file_path`: The path to save the SVG file.
+    /// - `padding`: Space in user units to keep around the content on each side.
+    ///
+    /// # Errors
+    /// Returns an `io::Result` if saving fails.
+    pub fn save_trimmed(&self, file_path: &str, padding: f64) -> io::Result<()> {
+        let mut file = File::create(file_path)?;
+        file.write_all(self.get_svg_as_text_trimmed(padding).as_bytes())?;
+        Ok(())
+    }
+
+    /// Rasterizes the SVG content and saves it as a PNG file.
+    ///
+    /// Because the SVG is vector based, any scale factor produces a sharp image.
+    ///
+    /// # Parameters
+    /// - `file_path`: The path to save the PNG file.
+    /// - `scale_factor`: The resolution multiplier relative to the canvas size.
+    ///
+    /// # Errors
+    /// Returns an `io::Result` if the SVG cannot be rendered or the file cannot be written.
+    pub fn save_as_png(&self, file_path: &str, scale_factor: f32) -> io::Result<()> {
+        self.rasterize(scale_factor)?
+            .save_png(file_path)
+            .map_err(|e| io::Error::other(e.to_string()))
+    }
+
+    /// Rasterizes the SVG content and saves it as a PNG file with embedded text metadata.
+    ///
+    /// # Parameters
+    /// - `file_path`: The path to save the PNG file.
+    /// - `scale_factor`: The resolution multiplier relative to the canvas size.
+    /// - `metadata`: The title, description, creation time and figure spec to embed.
+    ///
+    /// # Errors
+    /// Returns an `io::Result` if the SVG cannot be rendered, a metadata keyword is invalid
+    /// or the file cannot be written.
+    pub fn save_as_png_with_metadata(
+        &self,
+        file_path: &str,
+        scale_factor: f32,
+        metadata: &PngMetadata,
+    ) -> io::Result<()> {
+        let pixmap = self.rasterize(scale_factor)?;
+        let rgba: Vec<u8> = pixmap
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect();
+        write_png(
+            file_path,
+            (pixmap.width(), pixmap.height()),
+            &rgba,
+            true,
+            metadata,
+        )
+    }
+
+    /// Rasterizes the SVG content in horizontal tiles and streams them into a PNG file with
+    /// embedded text metadata.
+    ///
+    /// Only one tile of `tile_height` rows is held in memory at a time, so posters of tens
+    /// of thousands of pixels per side can be exported without a buffer for the whole
+    /// image. A 20,000 pixel wide image needs about 20 MB for a tile of 256 rows, where
+    /// `save_as_png` would need 1.6 GB for a 20,000 x 20,000 image.
+    ///
+    /// # Parameters
+    /// - `file_path`: The path to save the PNG file.
+    /// - `scale_factor`: The resolution multiplier relative to the canvas size.
+    /// - `tile_height`: The number of rows rendered at a time, at least 1.
+    /// - `metadata`: The title, description, creation time and figure spec to embed.
+    ///
+    /// # Errors
+    /// Returns an `io::Result` if the SVG cannot be rendered, a metadata keyword is invalid
+    /// or the file cannot be written.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut svg_canvas = SvgCanvas::new(800, 800, "white", 50);
+    /// chart.draw_svg(&mut svg_canvas);
+    /// // 20,000 x 20,000 pixels
+    /// svg_canvas.save_as_png_tiled("poster.png", 25.0, 256, &PngMetadata::new("Poster"))?;
+    /// ```
+    pub fn save_as_png_tiled(
+        &self,
+        file_path: &str,
+        scale_factor: f32,
+        tile_height: u32,
+        metadata: &PngMetadata,
+    ) -> io::Result<()> {
+        let tree = self.parse_tree()?;
+        let width = (self.width as f32 * scale_factor).ceil() as u32;
+        let height = (self.height as f32 * scale_factor).ceil() as u32;
+        let tile_height = tile_height.clamp(1, height.max(1));
+        let mut pixmap = Pixmap::new(width, tile_height)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Invalid image size"))?;
+
+        write_png_tiled(
+            file_path,
+            (width, height),
+            true,
+            metadata,
+            tile_height,
+            |y, rows| {
+                pixmap.fill(tiny_skia::Color::TRANSPARENT);
+                // Shift the image up so the tile's first row lands on the pixmap's first row
+                resvg::render(
+                    &tree,
+                    tiny_skia::Transform::from_scale(scale_factor, scale_factor)
+                        .post_translate(0.0, -(y as f32)),
+                    &mut pixmap.as_mut(),
+                );
+                Ok(pixmap.pixels()[..(rows * width) as usize]
+                    .iter()
+                    .flat_map(|pixel| {
+                        let color = pixel.demultiply();
+                        [color.red(), color.green(), color.blue(), color.alpha()]
+                    })
+                    .collect())
+            },
+        )
+    }
+
+    /// Parses the SVG content into a render tree, with the system fonts available for text.
+    fn parse_tree(&self) -> io::Result<usvg::Tree> {
+        let mut fontdb = fontdb::Database::new();
+        fontdb.load_system_fonts();
+        let opt = usvg::Options {
+            fontdb: Arc::new(fontdb),
+            ..usvg::Options::default()
+        };
+        usvg::Tree::from_str(&self.get_svg_as_text(), &opt)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+    }
+
+    /// Renders the SVG content into a pixmap scaled by `scale_factor`.
+    pub(crate) fn rasterize(&self, scale_factor: f32) -> io::Result<Pixmap> {
+        let tree = self.parse_tree()?;
+
+        let width = (self.width as f32 * scale_factor).ceil() as u32;
+        let height = (self.height as f32 * scale_factor).ceil() as u32;
+        let mut pixmap = Pixmap::new(width, height)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Invalid image size"))?;
+        resvg::render(
+            &tree,
+            tiny_skia::Transform::from_scale(scale_factor, scale_factor),
+            &mut pixmap.as_mut(),
+        );
+        Ok(pixmap)
+    }
+
     /// Saves the SVG content to a file.
     ///
     /// # Parameters
@@ -256,23 +974,78 @@ impl SvgCanvas {
     /// Returns an `io::Result` if saving fails.
     pub fn save(&self, file_path: &str) -> io::Result<()> {
         let mut file = File::create(file_path)?;
+        writeln!(file, "{}", self.full_header())?;
         for element in &self.elements {
-            writeln!(file, "{element}")?;
+            writeln!(file, "{}", element.markup)?;
         }
         writeln!(file, "</svg>")?;
         Ok(())
     }
 
+    /// Saves the SVG content as a standalone interactive HTML page.
+    ///
+    /// # Parameters
+    /// - `file_path`: The path to save the HTML file.
+    /// - `title`: The page title.
+    ///
+    /// # Errors
+    /// Returns an `io::Result` if saving fails.
+    ///
+    /// # Details
+    /// See [`to_html`] for the interactions the page supports.
+    pub fn save_as_html(&self, file_path: &str, title: &str) -> io::Result<()> {
+        let mut file = File::create(file_path)?;
+        file.write_all(to_html(&self.get_svg_as_text(), title, self.width).as_bytes())?;
+        Ok(())
+    }
+
     /// Retrieves the SVG content as a single string.
     ///
     /// # Returns
     /// The complete SVG as a string.
     pub fn get_svg_as_text(&self) -> String {
-        let mut svg = String::new();
+        let mut svg = self.full_header();
         for element in &self.elements {
-            svg.push_str(element);
+            svg.push_str(&element.markup);
         }
         svg.push_str("</svg>");
         svg
     }
 }
+
+/// Escapes the characters that are special in XML text and attribute values.
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Encodes bytes as standard base64 with padding, for data URIs.
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let triple = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, &b)| acc | ((b as u32) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[((triple >> (18 - 6 * i)) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
@@ -0,0 +1,291 @@
+use super::svgcanvas::escape_xml;
+
+/// Name of the layer holding the figure and plot area backgrounds.
+pub const LAYER_BACKGROUND: &str = "background";
+
+/// The kind of an SVG element, derived from its tag name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SvgElementKind {
+    /// A `<line>`.
+    Line,
+    /// A `<rect>`.
+    Rect,
+    /// A `<circle>`.
+    Circle,
+    /// A `<path>`.
+    Path,
+    /// A `<polygon>` or `<polyline>`.
+    Polygon,
+    /// A `<text>`.
+    Text,
+    /// An `<image>`.
+    Image,
+    /// A `<style>` block.
+    Style,
+    /// The opening tag of a `<g>` group.
+    GroupStart,
+    /// The opening tag of an `<a>` link.
+    LinkStart,
+    /// A closing tag, such as `</g>` or `</a>`.
+    End,
+    /// Anything else, including several elements pushed as one string.
+    Other,
+}
+
+impl SvgElementKind {
+    /// Determines the kind of the first tag in `markup`.
+    fn of(markup: &str) -> Self {
+        let markup = markup.trim_start();
+        if markup.starts_with("</") {
+            return SvgElementKind::End;
+        }
+        let name: String = markup
+            .strip_prefix('<')
+            .unwrap_or_default()
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect();
+        let self_contained =
+            markup.trim_end().ends_with("/>") || markup.trim_end().ends_with(&format!("</{name}>"));
+        match name.as_str() {
+            "g" if !self_contained => SvgElementKind::GroupStart,
+            "a" if !self_contained => SvgElementKind::LinkStart,
+            _ if markup.matches('<').count() > 1 && !self_contained => SvgElementKind::Other,
+            "line" => SvgElementKind::Line,
+            "rect" => SvgElementKind::Rect,
+            "circle" => SvgElementKind::Circle,
+            "path" => SvgElementKind::Path,
+            "polygon" | "polyline" => SvgElementKind::Polygon,
+            "text" => SvgElementKind::Text,
+            "image" => SvgElementKind::Image,
+            "style" => SvgElementKind::Style,
+            _ => SvgElementKind::Other,
+        }
+    }
+}
+
+/// An element of an `SvgCanvas`: its markup along with an optional id and layer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgElement {
+    /// The kind of the element.
+    pub kind: SvgElementKind,
+    /// The id of the element, also written as its `id` attribute.
+    pub id: Option<String>,
+    /// The layer the element belongs to.
+    pub layer: Option<String>,
+    /// The SVG markup.
+    pub markup: String,
+}
+
+impl SvgElement {
+    /// Creates an element from its markup, without id or layer.
+    ///
+    /// # Parameters
+    /// - `markup`: The SVG markup, e.g. `<rect x="0" y="0" width="10" height="10"/>`.
+    pub fn new(markup: impl Into<String>) -> Self {
+        let markup = markup.into();
+        Self {
+            kind: SvgElementKind::of(&markup),
+            id: None,
+            layer: None,
+            markup,
+        }
+    }
+
+    /// Sets the id of the element and writes it into the first tag of the markup.
+    ///
+    /// # Parameters
+    /// - `id`: The id, unique within the canvas.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let rect = SvgElement::new(r#"<rect x="0" y="0" width="10" height="10"/>"#).with_id("marker");
+    /// ```
+    pub fn with_id(mut self, id: &str) -> Self {
+        if let Some(end) = self.markup.find([' ', '>', '/']) {
+            if self.kind != SvgElementKind::End && self.markup.trim_start().starts_with('<') {
+                self.markup
+                    .insert_str(end, &format!(r#" id="{}""#, escape_xml(id)));
+            }
+        }
+        self.id = Some(id.to_string());
+        self
+    }
+}
+
+impl From<String> for SvgElement {
+    fn from(markup: String) -> Self {
+        SvgElement::new(markup)
+    }
+}
+
+impl From<&str> for SvgElement {
+    fn from(markup: &str) -> Self {
+        SvgElement::new(markup)
+    }
+}
+
+/// The ordered elements of an `SvgCanvas`.
+///
+/// Pushed elements join the current layer, if one is open. Elements can be inserted
+/// relative to a layer, removed by id and iterated by kind, so drawing order does not have
+/// to be tracked by index.
+#[derive(Clone, Debug, Default)]
+pub struct SvgElements {
+    elements: Vec<SvgElement>,
+    current_layer: Option<String>,
+}
+
+impl SvgElements {
+    /// Appends an element to the current layer.
+    ///
+    /// # Parameters
+    /// - `element`: The element, or markup converted into one.
+    pub fn push(&mut self, element: impl Into<SvgElement>) {
+        let element = self.in_current_layer(element.into());
+        self.elements.push(element);
+    }
+
+    /// Inserts an element at `index`, shifting later elements.
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than the number of elements.
+    pub fn insert(&mut self, index: usize, element: impl Into<SvgElement>) {
+        self.elements.insert(index, element.into());
+    }
+
+    /// Inserts an element below (before) every element of `layer`, so it is drawn under them.
+    ///
+    /// # Returns
+    /// `true` if the layer exists. Otherwise the element is appended and `false` is returned.
+    pub fn insert_below(&mut self, layer: &str, element: impl Into<SvgElement>) -> bool {
+        match self.layer_range(layer) {
+            Some((first, _)) => {
+                self.elements.insert(first, element.into());
+                true
+            }
+            None => {
+                self.elements.push(element.into());
+                false
+            }
+        }
+    }
+
+    /// Inserts an element above (after) every element of `layer`, so it is drawn over them.
+    ///
+    /// # Returns
+    /// `true` if the layer exists. Otherwise the element is appended and `false` is returned.
+    pub fn insert_above(&mut self, layer: &str, element: impl Into<SvgElement>) -> bool {
+        match self.layer_range(layer) {
+            Some((_, last)) => {
+                self.elements.insert(last + 1, element.into());
+                true
+            }
+            None => {
+                self.elements.push(element.into());
+                false
+            }
+        }
+    }
+
+    /// Removes the element with the given id.
+    ///
+    /// # Returns
+    /// The removed element, or `None` if no element has the id.
+    pub fn remove_by_id(&mut self, id: &str) -> Option<SvgElement> {
+        let index = self
+            .elements
+            .iter()
+            .position(|element| element.id.as_deref() == Some(id))?;
+        Some(self.elements.remove(index))
+    }
+
+    /// Returns the element with the given id, if any.
+    pub fn get_by_id(&self, id: &str) -> Option<&SvgElement> {
+        self.elements
+            .iter()
+            .find(|element| element.id.as_deref() == Some(id))
+    }
+
+    /// Starts a named layer: elements pushed until `end_layer` belong to it.
+    pub fn begin_layer(&mut self, layer: &str) {
+        self.current_layer = Some(layer.to_string());
+    }
+
+    /// Ends the current layer.
+    pub fn end_layer(&mut self) {
+        self.current_layer = None;
+    }
+
+    /// Iterates over the elements in drawing order.
+    pub fn iter(&self) -> impl Iterator<Item = &SvgElement> {
+        self.elements.iter()
+    }
+
+    /// Iterates over the elements of one kind in drawing order.
+    pub fn of_kind(&self, kind: SvgElementKind) -> impl Iterator<Item = &SvgElement> {
+        self.elements
+            .iter()
+            .filter(move |element| element.kind == kind)
+    }
+
+    /// Iterates over the elements of a layer in drawing order.
+    pub fn in_layer<'a>(&'a self, layer: &'a str) -> impl Iterator<Item = &'a SvgElement> {
+        self.elements
+            .iter()
+            .filter(move |element| element.layer.as_deref() == Some(layer))
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Removes all elements and ends the current layer.
+    pub fn clear(&mut self) {
+        self.elements.clear();
+        self.current_layer = None;
+    }
+
+    /// Assigns the current layer to an element that has none.
+    fn in_current_layer(&self, mut element: SvgElement) -> SvgElement {
+        if element.layer.is_none() {
+            element.layer = self.current_layer.clone();
+        }
+        element
+    }
+
+    /// Returns the indices of the first and last element of `layer`.
+    fn layer_range(&self, layer: &str) -> Option<(usize, usize)> {
+        let mut indices = self
+            .elements
+            .iter()
+            .enumerate()
+            .filter(|(_, element)| element.layer.as_deref() == Some(layer))
+            .map(|(index, _)| index);
+        let first = indices.next()?;
+        Some((first, indices.next_back().unwrap_or(first)))
+    }
+}
+
+impl<E: Into<SvgElement>> Extend<E> for SvgElements {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, elements: I) {
+        for element in elements {
+            self.push(element);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a SvgElements {
+    type Item = &'a SvgElement;
+    type IntoIter = std::slice::Iter<'a, SvgElement>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter()
+    }
+}
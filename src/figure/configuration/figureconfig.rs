@@ -1,3 +1,33 @@
+use std::{collections::HashMap, path::Path, sync::Arc};
+
+use crate::figure::{
+    canvas::rendertarget::RenderTarget,
+    utilities::{
+        axistype::AxisType,
+        formatcontext::FormatContext,
+        gridalignment::GridAlignment,
+        legendposition::LegendPosition,
+        scaling::{lock_aspect, tick_values},
+        secondaryaxis::SecondaryAxis,
+        texthalo::TextHalo,
+        textmetrics::{ellipsize, measure_text_with_fallbacks},
+        tickprecision::TickPrecision,
+        timezone::TimeZone,
+        unitplacement::UnitPlacement,
+    },
+};
+
+/// Line height of multi-line tick labels, relative to the axis font size.
+pub const TICK_LINE_SPACING: f32 = 1.25;
+
+/// Formats a tick value into its label. Labels may contain `\n` to span several lines
+/// (e.g. a date on the first line and a time on the second).
+pub type TickFormatter = Arc<dyn Fn(f64) -> String + Send + Sync>;
+
+/// Draws custom decorations on the canvas of a figure while it renders. The hook receives
+/// the canvas and the figure's configuration.
+pub type RenderHook = Arc<dyn Fn(RenderTarget<'_>, &FigureConfig) + Send + Sync>;
+
 /// Configuration structure for customizing the appearance of a figure.
 #[derive(Clone)]
 pub struct FigureConfig {
@@ -27,6 +57,76 @@ pub struct FigureConfig {
     pub font_label: Option<String>,
     /// File path to the font used for the title.
     pub font_title: Option<String>,
+    /// File paths to fonts tried, in order, for characters missing from the label or
+    /// title font (e.g. CJK ideographs or symbols such as `µ` and `°`).
+    pub font_fallbacks: Vec<String>,
+    /// Custom formatter for x-axis tick labels. Figures use their built-in format when unset.
+    pub tick_formatter_x: Option<TickFormatter>,
+    /// Custom formatter for y-axis tick labels. Figures use their built-in format when unset.
+    pub tick_formatter_y: Option<TickFormatter>,
+    /// Decimals of the x-axis tick labels; `None` keeps each figure's built-in format.
+    pub tick_precision_x: Option<TickPrecision>,
+    /// Decimals of the y-axis tick labels; `None` keeps each figure's built-in format.
+    pub tick_precision_y: Option<TickPrecision>,
+    /// Pattern formatting x-axis tick values as Unix timestamps (see `TimeZone::format`);
+    /// `None` treats them as plain numbers.
+    pub datetime_format_x: Option<String>,
+    /// Pattern formatting y-axis tick values as Unix timestamps (see `TimeZone::format`);
+    /// `None` treats them as plain numbers.
+    pub datetime_format_y: Option<String>,
+    /// Time zone datetime tick labels are shown in.
+    pub time_zone: TimeZone,
+    /// Separators, percent style, currency and date format of the numbers and dates in
+    /// tick labels, value labels and legends.
+    pub format: FormatContext,
+    /// Whether the x-axis values are amounts in the currency of `format`.
+    pub currency_x: bool,
+    /// Whether the y-axis values are amounts in the currency of `format`.
+    pub currency_y: bool,
+    /// Unit of the x-axis values (e.g. `"ms"`), shown according to `unit_placement`.
+    pub unit_x: Option<String>,
+    /// Unit of the y-axis values (e.g. `"MB/s"`), shown according to `unit_placement`.
+    pub unit_y: Option<String>,
+    /// Where axis units are displayed.
+    pub unit_placement: UnitPlacement,
+    /// Side of the plot the legend is placed on, for figures with a positionable legend.
+    pub legend_position: LegendPosition,
+    /// Whether the figure draws its own series legend. Turned off for figures whose entries
+    /// are shown in a shared `FigureLegend` instead.
+    pub show_legend: bool,
+    /// Description of the figure for screen readers, emitted as the `<desc>` of SVG output.
+    pub description: Option<String>,
+    /// Descriptions of individual series for screen readers, keyed by series label.
+    pub series_descriptions: HashMap<String, String>,
+    /// URLs that series and their legend entries link to in SVG output, keyed by series label.
+    pub series_links: HashMap<String, String>,
+    /// Whether the figure background is fully transparent (alpha channel in PNG,
+    /// no background rectangles in SVG). PNG transparency is color keyed, with the
+    /// limitations described on `PixelCanvas::set_transparent`.
+    pub transparent_background: bool,
+    /// Length of one y unit on screen relative to one x unit. `Some(1.0)` draws both axes
+    /// at the same scale, as maps and geometric plots need; `None` stretches the data over
+    /// the whole plot area.
+    pub aspect_ratio: Option<f64>,
+    /// Placement of the vertical grid lines, along the x axis.
+    pub grid_alignment_x: GridAlignment,
+    /// Placement of the horizontal grid lines, along the y axis.
+    pub grid_alignment_y: GridAlignment,
+    /// Hook run after the background, grid and axes are drawn and before the datasets.
+    pub before_data_hook: Option<RenderHook>,
+    /// Hook run after the datasets are drawn and before the legend.
+    pub after_data_hook: Option<RenderHook>,
+    /// Whether every tick label is drawn even where neighbouring labels overlap. When `false`,
+    /// labels are thinned to every second, third, ... label until they fit.
+    pub force_all_tick_labels: bool,
+    /// Second y-axis on the right of the plot, derived from the primary y-axis.
+    pub secondary_axis: Option<SecondaryAxis>,
+    /// Halo drawn behind tick labels, data value labels and reference line labels, so they
+    /// stay readable over dense data.
+    pub text_halo: Option<TextHalo>,
+    /// Widest category tick label in pixels; longer labels are shortened with an ellipsis.
+    /// `None` keeps labels whole.
+    pub max_category_label_width: Option<f64>,
 }
 
 impl Default for FigureConfig {
@@ -46,6 +146,27 @@ impl Default for FigureConfig {
     /// - `font_size_axis`: 10.0
     /// - `font_label`: `../../resources/fonts/Arial.ttf`
     /// - `font_title`: `../../resources/fonts/Arial.ttf`
+    /// - `font_fallbacks`: empty
+    /// - `tick_formatter_x`, `tick_formatter_y`: `None`
+    /// - `tick_precision_x`, `tick_precision_y`: `None`
+    /// - `datetime_format_x`, `datetime_format_y`: `None`
+    /// - `time_zone`: `TimeZone::Utc`
+    /// - `format`: `FormatContext::default()` (Rust's `format!` style)
+    /// - `currency_x`, `currency_y`: `false`
+    /// - `unit_x`, `unit_y`: `None`
+    /// - `unit_placement`: `UnitPlacement::TickLabels`
+    /// - `legend_position`: `LegendPosition::Bottom`
+    /// - `description`: `None`
+    /// - `series_descriptions`: empty
+    /// - `series_links`: empty
+    /// - `transparent_background`: `false`
+    /// - `aspect_ratio`: `None`
+    /// - `grid_alignment_x`, `grid_alignment_y`: `GridAlignment::Ticks`
+    /// - `before_data_hook`, `after_data_hook`: `None`
+    /// - `force_all_tick_labels`: `false`
+    /// - `secondary_axis`: `None`
+    /// - `text_halo`: `None`
+    /// - `max_category_label_width`: `None`
     ///
     /// # Returns
     /// A `FigureConfig` instance with default settings.
@@ -64,6 +185,35 @@ impl Default for FigureConfig {
             font_size_axis: 10.0,
             font_label: None,
             font_title: None,
+            font_fallbacks: Vec::new(),
+            tick_formatter_x: None,
+            tick_formatter_y: None,
+            tick_precision_x: None,
+            tick_precision_y: None,
+            datetime_format_x: None,
+            datetime_format_y: None,
+            time_zone: TimeZone::Utc,
+            format: FormatContext::default(),
+            currency_x: false,
+            currency_y: false,
+            unit_x: None,
+            unit_y: None,
+            unit_placement: UnitPlacement::TickLabels,
+            legend_position: LegendPosition::Bottom,
+            show_legend: true,
+            description: None,
+            series_descriptions: HashMap::new(),
+            series_links: HashMap::new(),
+            transparent_background: false,
+            aspect_ratio: None,
+            grid_alignment_x: GridAlignment::Ticks,
+            grid_alignment_y: GridAlignment::Ticks,
+            before_data_hook: None,
+            after_data_hook: None,
+            force_all_tick_labels: false,
+            secondary_axis: None,
+            text_halo: None,
+            max_category_label_width: None,
         }
     }
 }
@@ -74,11 +224,766 @@ impl FigureConfig {
         self.font_title = Some(title_path);
     }
 
+    /// Appends a fallback font used for characters missing from the primary fonts.
+    ///
+    /// # Parameters
+    /// - `font_path`: Path to the fallback font file.
+    pub fn add_font_fallback(&mut self, font_path: String) {
+        self.font_fallbacks.push(font_path);
+    }
+
+    /// Sets the formatter used for tick labels along an axis.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis whose tick labels are formatted.
+    /// - `formatter`: Converts a tick value into its label; `\n` starts a new line. Labels
+    ///   are plain text and are escaped in SVG output.
+    ///
+    /// # Details
+    /// The formatter must accept any finite value, and must produce the same number of lines
+    /// for every value: the space reserved for multi-line labels is measured by calling it
+    /// once with `0.0`, whether or not `0.0` is a tick (see `tick_label_lines`).
+    pub fn set_tick_formatter<F>(&mut self, axis: AxisType, formatter: F)
+    where
+        F: Fn(f64) -> String + Send + Sync + 'static,
+    {
+        match axis {
+            AxisType::AxisX => self.tick_formatter_x = Some(Arc::new(formatter)),
+            AxisType::AxisY => self.tick_formatter_y = Some(Arc::new(formatter)),
+        }
+    }
+
+    /// Sets a hook drawing on the canvas before the datasets, e.g. to shade a range of the
+    /// plot area behind the data.
+    ///
+    /// # Parameters
+    /// - `hook`: Called with the canvas and this configuration on every render.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::canvas::rendertarget::RenderTarget;
+    ///
+    /// config.set_before_data_hook(|target, _config| {
+    ///     let (left, top, right, bottom) = target.plot_area();
+    ///     match target {
+    ///         RenderTarget::Pixel(canvas) => {
+    ///             canvas.draw_line(left as i32, top as i32, right as i32, bottom as i32,
+    ///                 [255, 0, 0], LineType::Dashed(4));
+    ///         }
+    ///         RenderTarget::Svg(canvas) => {
+    ///             canvas.draw_line(left, top, right, bottom, "red", 1.0);
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    pub fn set_before_data_hook<F>(&mut self, hook: F)
+    where
+        F: Fn(RenderTarget<'_>, &FigureConfig) + Send + Sync + 'static,
+    {
+        self.before_data_hook = Some(Arc::new(hook));
+    }
+
+    /// Sets a hook drawing on the canvas after the datasets, e.g. to annotate data or add a
+    /// watermark on top of it.
+    ///
+    /// # Parameters
+    /// - `hook`: Called with the canvas and this configuration on every render.
+    pub fn set_after_data_hook<F>(&mut self, hook: F)
+    where
+        F: Fn(RenderTarget<'_>, &FigureConfig) + Send + Sync + 'static,
+    {
+        self.after_data_hook = Some(Arc::new(hook));
+    }
+
+    /// Runs the hook set with `set_before_data_hook`, if any. Drawers call it once the
+    /// background, grid and axes are drawn.
+    ///
+    /// # Parameters
+    /// - `target`: The canvas being drawn on.
+    pub fn run_before_data_hook(&self, target: RenderTarget<'_>) {
+        if let Some(hook) = &self.before_data_hook {
+            hook(target, self);
+        }
+    }
+
+    /// Runs the hook set with `set_after_data_hook`, if any. Drawers call it once the
+    /// datasets are drawn, before the legend.
+    ///
+    /// # Parameters
+    /// - `target`: The canvas being drawn on.
+    pub fn run_after_data_hook(&self, target: RenderTarget<'_>) {
+        if let Some(hook) = &self.after_data_hook {
+            hook(target, self);
+        }
+    }
+
+    /// Formats a tick value with the formatter configured for `axis`.
+    ///
+    /// # Returns
+    /// The formatted label, or `None` if no formatter is set for the axis.
+    pub fn format_tick(&self, axis: AxisType, value: f64) -> Option<String> {
+        self.tick_formatter(axis).map(|format| format(value))
+    }
+
+    /// Returns the tick formatter configured for `axis`, if any.
+    pub fn tick_formatter(&self, axis: AxisType) -> Option<&TickFormatter> {
+        match axis {
+            AxisType::AxisX => self.tick_formatter_x.as_ref(),
+            AxisType::AxisY => self.tick_formatter_y.as_ref(),
+        }
+    }
+
+    /// Sets the number of decimals shown in the tick labels of an axis.
+    ///
+    /// A tick formatter set with `set_tick_formatter` takes precedence.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis whose tick labels are formatted.
+    /// - `precision`: Fixed or trimmed decimals, or `TickPrecision::Auto` for the fewest
+    ///   decimals that tell the ticks apart.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::{axistype::AxisType, tickprecision::TickPrecision};
+    ///
+    /// config.set_tick_precision(AxisType::AxisX, TickPrecision::Auto);
+    /// config.set_tick_precision(AxisType::AxisY, TickPrecision::Trimmed(2));
+    /// ```
+    pub fn set_tick_precision(&mut self, axis: AxisType, precision: TickPrecision) {
+        match axis {
+            AxisType::AxisX => self.tick_precision_x = Some(precision),
+            AxisType::AxisY => self.tick_precision_y = Some(precision),
+        }
+    }
+
+    /// Returns the tick precision configured for `axis`, if any.
+    pub fn tick_precision(&self, axis: AxisType) -> Option<TickPrecision> {
+        match axis {
+            AxisType::AxisX => self.tick_precision_x,
+            AxisType::AxisY => self.tick_precision_y,
+        }
+    }
+
+    /// Formats the tick values of an axis as dates and times, reading them as Unix
+    /// timestamps in seconds shown in the configured time zone.
+    ///
+    /// A tick formatter set with `set_tick_formatter` takes precedence; a tick precision set
+    /// with `set_tick_precision` is ignored for the axis.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis whose tick labels are formatted.
+    /// - `pattern`: The format, e.g. `"%Y-%m-%d"` or `"%d %b\n%H:%M"` (see `TimeZone::format`).
+    pub fn set_datetime_format(&mut self, axis: AxisType, pattern: &str) {
+        match axis {
+            AxisType::AxisX => self.datetime_format_x = Some(pattern.to_string()),
+            AxisType::AxisY => self.datetime_format_y = Some(pattern.to_string()),
+        }
+    }
+
+    /// Returns the datetime pattern of an axis, if its ticks are timestamps.
+    pub fn datetime_format(&self, axis: AxisType) -> Option<&str> {
+        match axis {
+            AxisType::AxisX => self.datetime_format_x.as_deref(),
+            AxisType::AxisY => self.datetime_format_y.as_deref(),
+        }
+    }
+
+    /// Sets the time zone datetime tick labels are shown in, e.g. to show UTC timestamps
+    /// from a server in local business time.
+    ///
+    /// # Parameters
+    /// - `zone`: `TimeZone::Utc`, a fixed offset such as `TimeZone::fixed(5, 30)`, or a tz
+    ///   database zone from `TimeZone::named` (requires the `tzdb` feature).
+    pub fn set_time_zone(&mut self, zone: TimeZone) {
+        self.time_zone = zone;
+    }
+
+    /// Sets how numbers, percentages, amounts and dates are written in the labels of the
+    /// figure: tick labels, value labels, percentages and legend summaries. Tick formatters
+    /// set with `set_tick_formatter` and datetime tick formats are used as they are.
+    ///
+    /// # Parameters
+    /// - `context`: The separators, percent style, currency and date format.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::formatcontext::FormatContext;
+    ///
+    /// // 1.234,5 and 45 % on every axis, value label and legend
+    /// config.set_format_context(FormatContext::de_de());
+    /// ```
+    pub fn set_format_context(&mut self, context: FormatContext) {
+        self.format = context;
+    }
+
+    /// Marks the values of an axis as amounts of money, so its tick labels and the value
+    /// labels along it show the currency of the format context.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis holding amounts.
+    pub fn set_currency_axis(&mut self, axis: AxisType) {
+        match axis {
+            AxisType::AxisX => self.currency_x = true,
+            AxisType::AxisY => self.currency_y = true,
+        }
+    }
+
+    /// Returns whether the values of `axis` are amounts of money.
+    pub fn is_currency_axis(&self, axis: AxisType) -> bool {
+        match axis {
+            AxisType::AxisX => self.currency_x,
+            AxisType::AxisY => self.currency_y,
+        }
+    }
+
+    /// Localizes a number formatted for an axis: separators and percent signs follow the
+    /// format context, and amounts on currency axes get the currency symbol.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis the number belongs to.
+    /// - `label`: The number formatted the Rust way, e.g. `"-1234.50"`.
+    ///
+    /// # Returns
+    /// The localized label.
+    pub fn localize_value(&self, axis: AxisType, label: &str) -> String {
+        let localized = self.format.localize(label);
+        if self.is_currency_axis(axis) {
+            self.format.with_currency(localized)
+        } else {
+            localized
+        }
+    }
+
+    /// Formats a Unix timestamp in seconds as a date with the date format of the format
+    /// context, in the configured time zone.
+    ///
+    /// # Returns
+    /// The date, or `None` if the format context has no date format.
+    pub fn format_date(&self, timestamp: f64) -> Option<String> {
+        self.format.format_date(timestamp, &self.time_zone)
+    }
+
+    /// Formats a tick value as a date and time if `axis` has a datetime format.
+    ///
+    /// # Returns
+    /// The formatted label, or `None` if the axis holds plain numbers.
+    pub fn format_datetime_tick(&self, axis: AxisType, value: f64) -> Option<String> {
+        self.datetime_format(axis)
+            .map(|pattern| self.time_zone.format(value, pattern))
+    }
+
+    /// Sets the unit displayed for an axis.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis the unit applies to.
+    /// - `unit`: The unit string (e.g. `"ms"` or `"MB/s"`).
+    pub fn set_unit(&mut self, axis: AxisType, unit: &str) {
+        match axis {
+            AxisType::AxisX => self.unit_x = Some(unit.to_string()),
+            AxisType::AxisY => self.unit_y = Some(unit.to_string()),
+        }
+    }
+
+    /// Returns the unit configured for `axis`, if any.
+    pub fn unit(&self, axis: AxisType) -> Option<&str> {
+        match axis {
+            AxisType::AxisX => self.unit_x.as_deref(),
+            AxisType::AxisY => self.unit_y.as_deref(),
+        }
+    }
+
+    /// Sets how the grid lines along an axis are placed.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis whose grid lines are placed (vertical lines for the x axis).
+    /// - `alignment`: `GridAlignment::Ticks` to follow the ticks, or `GridAlignment::Even`
+    ///   to divide the plot area by `num_grid_vertical`/`num_grid_horizontal` instead.
+    pub fn set_grid_alignment(&mut self, axis: AxisType, alignment: GridAlignment) {
+        match axis {
+            AxisType::AxisX => self.grid_alignment_x = alignment,
+            AxisType::AxisY => self.grid_alignment_y = alignment,
+        }
+    }
+
+    /// Computes the pixel positions of the grid lines along an axis.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis the grid lines belong to.
+    /// - `start`, `end`: The pixel extent of the plot area along the axis.
+    /// - `ticks`: The pixel positions of the ticks drawn on the axis.
+    ///
+    /// # Returns
+    /// The tick positions inside the plot area, or evenly spaced positions from `start` to
+    /// `end` when the axis uses `GridAlignment::Even`.
+    pub fn grid_positions(&self, axis: AxisType, start: f64, end: f64, ticks: &[f64]) -> Vec<f64> {
+        let (alignment, divisions) = match axis {
+            AxisType::AxisX => (self.grid_alignment_x, self.num_grid_vertical),
+            AxisType::AxisY => (self.grid_alignment_y, self.num_grid_horizontal),
+        };
+        match alignment {
+            GridAlignment::Ticks => {
+                let (low, high) = (start.min(end) - 0.5, start.max(end) + 0.5);
+                ticks
+                    .iter()
+                    .copied()
+                    .filter(|position| (low..=high).contains(position))
+                    .collect()
+            }
+            GridAlignment::Even => tick_values(start, end, divisions),
+        }
+    }
+
+    /// Sets the description of a series, read by screen readers in SVG output.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the series (dataset or slice).
+    /// - `description`: The description, e.g. a summary of the trend it shows.
+    pub fn set_series_description(&mut self, label: &str, description: &str) {
+        self.series_descriptions
+            .insert(label.to_string(), description.to_string());
+    }
+
+    /// Returns the description configured for the series labelled `label`, if any.
+    pub fn series_description(&self, label: &str) -> Option<&str> {
+        self.series_descriptions.get(label).map(String::as_str)
+    }
+
+    /// Links a series and its legend entry to a URL in SVG output.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the series (dataset or slice).
+    /// - `url`: The link target, e.g. a drill-down page.
+    pub fn set_series_link(&mut self, label: &str, url: &str) {
+        self.series_links.insert(label.to_string(), url.to_string());
+    }
+
+    /// Returns the URL configured for the series labelled `label`, if any.
+    pub fn series_link(&self, label: &str) -> Option<&str> {
+        self.series_links.get(label).map(String::as_str)
+    }
+
+    /// Applies the configured `aspect_ratio` to the data ranges of a plot.
+    ///
+    /// # Parameters
+    /// - `x_range`: The `(min, max)` range of the x-axis.
+    /// - `y_range`: The `(min, max)` range of the y-axis.
+    /// - `plot_width`, `plot_height`: The size of the plot area in pixels.
+    ///
+    /// # Returns
+    /// The `(x_range, y_range)` pair, widened by `lock_aspect` if an aspect ratio is set and
+    /// unchanged otherwise.
+    pub fn aspect_ranges(
+        &self,
+        x_range: (f64, f64),
+        y_range: (f64, f64),
+        plot_width: f64,
+        plot_height: f64,
+    ) -> ((f64, f64), (f64, f64)) {
+        match self.aspect_ratio {
+            Some(aspect) => lock_aspect(x_range, y_range, plot_width, plot_height, aspect),
+            None => (x_range, y_range),
+        }
+    }
+
+    /// Builds the complete tick label for a value.
+    ///
+    /// The configured formatter for `axis` is used, then the configured tick precision,
+    /// falling back to `default_format`. Numbers from the tick precision and the default
+    /// format are localized with `localize_value`. With `UnitPlacement::TickLabels`, the
+    /// axis unit is appended to the first line.
+    ///
+    /// Prefer `tick_labels` when all ticks of the axis are known, so that
+    /// `TickPrecision::Auto` can choose decimals that tell them apart.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis the tick belongs to.
+    /// - `value`: The tick value.
+    /// - `default_format`: The figure's built-in format, used when no formatter is set.
+    pub fn tick_label<F>(&self, axis: AxisType, value: f64, default_format: F) -> String
+    where
+        F: FnOnce(f64) -> String,
+    {
+        let label = self
+            .format_tick(axis, value)
+            .or_else(|| self.format_datetime_tick(axis, value))
+            .unwrap_or_else(|| {
+                let number = match self.tick_precision(axis) {
+                    Some(precision) => precision.format(value),
+                    None => default_format(value),
+                };
+                self.localize_value(axis, &number)
+            });
+        self.append_unit(axis, label)
+    }
+
+    /// Builds the tick label of a timestamp on a time axis.
+    ///
+    /// The configured formatter for `axis` is used, then the datetime format of the axis,
+    /// falling back to `pattern`. Dates are not localized as numbers. With
+    /// `UnitPlacement::TickLabels`, the axis unit is appended to the first line.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis the tick belongs to.
+    /// - `timestamp`: The tick value, in seconds since the Unix epoch.
+    /// - `pattern`: The figure's date pattern (see `TimeZone::format`).
+    pub fn time_tick_label(&self, axis: AxisType, timestamp: f64, pattern: &str) -> String {
+        let label = self
+            .format_tick(axis, timestamp)
+            .or_else(|| self.format_datetime_tick(axis, timestamp))
+            .unwrap_or_else(|| self.time_zone.format(timestamp, pattern));
+        self.append_unit(axis, label)
+    }
+
+    /// Builds the tick labels for all ticks of an axis.
+    ///
+    /// Works like `tick_label`, except that `TickPrecision::Auto` picks one number of
+    /// decimals for the whole axis.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis the ticks belong to.
+    /// - `values`: The tick values, in axis order.
+    /// - `default_format`: The figure's built-in format, used when neither a formatter nor a
+    ///   precision is set.
+    ///
+    /// # Returns
+    /// One label per value.
+    pub fn tick_labels<F>(&self, axis: AxisType, values: &[f64], default_format: F) -> Vec<String>
+    where
+        F: Fn(f64) -> String,
+    {
+        let labels = match self.tick_precision(axis) {
+            Some(precision)
+                if self.tick_formatter(axis).is_none() && self.datetime_format(axis).is_none() =>
+            {
+                precision.format_all(values)
+            }
+            _ => {
+                return values
+                    .iter()
+                    .map(|&value| self.tick_label(axis, value, &default_format))
+                    .collect()
+            }
+        };
+        labels
+            .into_iter()
+            .map(|label| self.append_unit(axis, self.localize_value(axis, &label)))
+            .collect()
+    }
+
+    /// Sets whether every tick label is drawn even where neighbouring labels overlap.
+    ///
+    /// # Parameters
+    /// - `force`: `true` to draw all labels, `false` to thin overlapping labels.
+    pub fn set_force_all_tick_labels(&mut self, force: bool) {
+        self.force_all_tick_labels = force;
+    }
+
+    /// Adds a second y-axis on the right of the plot, showing the primary y values in
+    /// other units. Figures with a numeric y-axis (Cartesian, scatter, area, quadrant 1
+    /// and candlestick charts) draw it.
+    ///
+    /// # Parameters
+    /// - `axis`: The transforms between primary and secondary units, and the axis label.
+    pub fn set_secondary_axis(&mut self, axis: SecondaryAxis) {
+        self.secondary_axis = Some(axis);
+    }
+
+    /// Draws a halo behind tick labels, data value labels and reference line labels, on
+    /// both raster and SVG output.
+    ///
+    /// # Parameters
+    /// - `halo`: An outline around the glyphs or a pill behind the text.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::texthalo::TextHalo;
+    ///
+    /// // Heatmap values stay readable on dark and light cells alike
+    /// config.set_text_halo(TextHalo::pill());
+    /// ```
+    pub fn set_text_halo(&mut self, halo: TextHalo) {
+        self.text_halo = Some(halo);
+    }
+
+    /// Shortens category tick labels wider than `width` pixels with an ellipsis, e.g.
+    /// `"Very long category na…"`, so long names do not squeeze the plot.
+    ///
+    /// Affects the category labels of box, violin, strip and swarm plots, the row and column
+    /// labels of heatmaps and correlation matrices, and the task names of Gantt charts.
+    /// Tooltips keep the full names, and SVG output adds them as `<title>` elements.
+    ///
+    /// # Parameters
+    /// - `width`: The widest label in pixels.
+    pub fn set_max_category_label_width(&mut self, width: f64) {
+        self.max_category_label_width = Some(width);
+    }
+
+    /// Shortens a category label to `max_category_label_width`, measured like
+    /// `thin_tick_labels` measures labels.
+    ///
+    /// # Parameters
+    /// - `label`: The full label.
+    /// - `font_size`: The font size the label is drawn with.
+    ///
+    /// # Returns
+    /// The label, ending with `…` if it was shortened, or `…` alone if no character fits.
+    pub fn category_label(&self, label: &str, font_size: f32) -> String {
+        match self.max_category_label_width {
+            Some(width) => ellipsize(label, width, |text| self.label_width(text, font_size))
+                .unwrap_or_else(|| "…".to_string()),
+            None => label.to_string(),
+        }
+    }
+
+    /// Drops tick labels that would overlap their neighbours, keeping every Nth label
+    /// starting with the first. Dropped labels are replaced by empty strings, so ticks and
+    /// grid lines stay in place.
+    ///
+    /// Label widths are measured with the label font when it is available, and estimated
+    /// from the number of characters otherwise (e.g. for SVG output without fonts).
+    ///
+    /// # Parameters
+    /// - `axis`: The axis the labels belong to. X-axis labels are compared by width, y-axis
+    ///   labels by height.
+    /// - `labels`: The tick labels, evenly spaced along the axis.
+    /// - `axis_length`: The distance in pixels between the first and the last tick.
+    /// - `font_size`: The font size the labels are drawn with.
+    ///
+    /// # Returns
+    /// The labels, unchanged if they fit or `force_all_tick_labels` is set.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let labels = config.tick_labels(AxisType::AxisX, &values, |v| format!("{v:.1}"));
+    /// let labels = config.thin_tick_labels(AxisType::AxisX, labels, plot_width, 10.0);
+    /// ```
+    pub fn thin_tick_labels(
+        &self,
+        axis: AxisType,
+        labels: Vec<String>,
+        axis_length: f64,
+        font_size: f32,
+    ) -> Vec<String> {
+        if self.force_all_tick_labels || labels.len() < 2 || axis_length <= 0.0 {
+            return labels;
+        }
+
+        let extent = labels
+            .iter()
+            .map(|label| match axis {
+                AxisType::AxisX => label
+                    .lines()
+                    .map(|line| self.label_width(line, font_size))
+                    .fold(0.0, f64::max),
+                AxisType::AxisY => {
+                    label.lines().count() as f64 * (font_size * TICK_LINE_SPACING) as f64
+                }
+            })
+            .fold(0.0, f64::max);
+        let spacing = axis_length.abs() / (labels.len() - 1) as f64;
+        let gap = font_size as f64 * 0.5;
+        let step = ((extent + gap) / spacing).ceil().max(1.0) as usize;
+        if step == 1 {
+            return labels;
+        }
+
+        labels
+            .into_iter()
+            .enumerate()
+            .map(|(index, label)| {
+                if index % step == 0 {
+                    label
+                } else {
+                    String::new()
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the width of a single line of label text, measured with the label font and
+    /// its fallbacks if they exist, or estimated at 0.6 em per character.
+    fn label_width(&self, text: &str, font_size: f32) -> f64 {
+        match &self.font_label {
+            Some(path)
+                if Path::new(path).is_file()
+                    && self
+                        .font_fallbacks
+                        .iter()
+                        .all(|font| Path::new(font).is_file()) =>
+            {
+                measure_text_with_fallbacks(text, path, &self.font_fallbacks, font_size).0 as f64
+            }
+            _ => text.chars().count() as f64 * font_size as f64 * 0.6,
+        }
+    }
+
+    /// Appends the unit of `axis` to the first line of a tick label when units are placed on
+    /// the tick labels.
+    fn append_unit(&self, axis: AxisType, label: String) -> String {
+        match (self.unit_placement, self.unit(axis)) {
+            (UnitPlacement::TickLabels, Some(unit)) => match label.split_once('\n') {
+                Some((first, rest)) => format!("{first} {unit}\n{rest}"),
+                None => format!("{label} {unit}"),
+            },
+            _ => label,
+        }
+    }
+
+    /// Returns the number of lines in the tick labels of `axis`.
+    ///
+    /// The formatter is sampled once at `0.0`, which relies on the requirement of
+    /// `set_tick_formatter` that every value produces the same number of lines.
+    pub fn tick_label_lines(&self, axis: AxisType) -> usize {
+        self.format_tick(axis, 0.0)
+            .map_or(1, |label| label.lines().count().max(1))
+    }
+
+    /// Returns the extra vertical space, in pixels, that multi-line x-axis tick labels
+    /// occupy below the first line. Legends below the x-axis are shifted down by this amount.
+    ///
+    /// # Parameters
+    /// - `font_size`: The font size of the tick labels.
+    pub fn x_tick_label_overflow(&self, font_size: f32) -> f32 {
+        let extra_lines = self.tick_label_lines(AxisType::AxisX) - 1;
+        extra_lines as f32 * font_size * TICK_LINE_SPACING
+    }
+
+    /// Multiplies all font sizes and the text halo width or padding by `factor`.
+    ///
+    /// Used to render the same figure at a higher resolution without changing its proportions.
+    ///
+    /// # Parameters
+    /// - `factor`: The scale multiplier.
+    pub fn scale(&mut self, factor: f32) {
+        self.font_size_label *= factor;
+        self.font_size_title *= factor;
+        self.font_size_legend *= factor;
+        self.font_size_axis *= factor;
+        self.text_halo = self.text_halo.map(|halo| halo.scaled(factor as f64));
+    }
+
+    /// Checks the configuration for settings that would produce empty or broken images.
+    ///
+    /// # Returns
+    /// `Ok(())` if the configuration is usable, or an error listing every problem found,
+    /// separated by `"; "`.
+    ///
+    /// # Details
+    /// Reports missing or unreadable font files, zero grid and tick counts, font sizes that are
+    /// zero, negative or not finite, and axis or title colors identical to the background. The
+    /// colors are not compared when `transparent_background` is set, because the background
+    /// is then not shown.
     pub fn validate(&self) -> Result<(), String> {
-        if self.font_label.is_none() || self.font_title.is_none() {
-            Err("Both font_label and font_title must be provided.".to_string())
+        let mut problems = self.font_problems();
+        problems.extend(self.style_problems());
+        Self::into_result(problems)
+    }
+
+    /// Checks that the label and title fonts are set and point to existing files.
+    ///
+    /// # Returns
+    /// `Ok(())` if both fonts are usable, or an error describing the problems.
+    pub fn validate_fonts(&self) -> Result<(), String> {
+        Self::into_result(self.font_problems())
+    }
+
+    /// Checks the configuration against the canvas it is about to be rendered on.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    /// - `fonts_required`: Whether the font files are needed (raster output) or not (SVG output).
+    ///
+    /// # Returns
+    /// `Ok(())` if the figure can be rendered, or an error listing every problem found.
+    pub fn validate_for_canvas(
+        &self,
+        width: u32,
+        height: u32,
+        margin: u32,
+        fonts_required: bool,
+    ) -> Result<(), String> {
+        let mut problems = if fonts_required {
+            self.font_problems()
         } else {
+            Vec::new()
+        };
+        problems.extend(self.style_problems());
+
+        if u64::from(margin) * 2 >= u64::from(width) || u64::from(margin) * 2 >= u64::from(height) {
+            problems.push(format!(
+                "margin {margin} leaves no plot area on a {width}x{height} canvas, \
+                 use a margin below {}",
+                width.min(height).div_ceil(2)
+            ));
+        }
+
+        Self::into_result(problems)
+    }
+
+    fn font_problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        for (name, font) in [
+            ("font_label", &self.font_label),
+            ("font_title", &self.font_title),
+        ] {
+            match font {
+                None => problems.push(format!(
+                    "{name} is not set, call set_font_paths with a TrueType font file"
+                )),
+                Some(path) if !Path::new(path).is_file() => {
+                    problems.push(format!("{name} \"{path}\" does not exist or is not a file"))
+                }
+                Some(_) => {}
+            }
+        }
+        problems
+    }
+
+    fn style_problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        for (name, count) in [
+            ("num_axis_ticks", self.num_axis_ticks),
+            ("num_grid_horizontal", self.num_grid_horizontal),
+            ("num_grid_vertical", self.num_grid_vertical),
+        ] {
+            if count == 0 {
+                problems.push(format!("{name} is 0, use at least 1"));
+            }
+        }
+
+        for (name, size) in [
+            ("font_size_label", self.font_size_label),
+            ("font_size_title", self.font_size_title),
+            ("font_size_legend", self.font_size_legend),
+            ("font_size_axis", self.font_size_axis),
+        ] {
+            if !size.is_finite() || size <= 0.0 {
+                problems.push(format!("{name} is {size}, use a positive font size"));
+            }
+        }
+
+        // A transparent background is not shown, so any axis or title color stays visible
+        for (name, color) in [
+            ("color_axis", self.color_axis),
+            ("color_title", self.color_title),
+        ] {
+            if !self.transparent_background && color == self.color_background {
+                problems.push(format!(
+                    "{name} {color:?} equals color_background, so it would be invisible"
+                ));
+            }
+        }
+
+        problems
+    }
+
+    fn into_result(problems: Vec<String>) -> Result<(), String> {
+        if problems.is_empty() {
             Ok(())
+        } else {
+            Err(problems.join("; "))
         }
     }
 }
@@ -1,4 +1,9 @@
+use crate::figure::utilities::{
+    formatcontext::FormatContext, legendsummary::format_legend, referenceline::ReferenceLine,
+};
+
 /// A dataset for an area chart, containing data points, appearance properties, and metadata.
+#[derive(Clone)]
 pub struct AreaChartDataset {
     /// Transparency level of the area fill (0.0 for fully transparent, 1.0 for fully opaque).
     pub alpha: f64,
@@ -8,6 +13,12 @@ pub struct AreaChartDataset {
     pub color: [u8; 3],
     /// Label for the dataset, used in legends or annotations.
     pub label: String,
+    /// Legend entry format with value summaries, or `None` to show the label.
+    pub legend_format: Option<String>,
+    /// Statistic marked by a horizontal line, or `None` for no line.
+    pub reference_line: Option<ReferenceLine>,
+    /// Whether the dataset is drawn, listed in the legend and fitted by autoscaling.
+    pub visible: bool,
 }
 
 impl AreaChartDataset {
@@ -31,6 +42,56 @@ impl AreaChartDataset {
             color,
             label: label.to_string(),
             alpha,
+            legend_format: None,
+            reference_line: None,
+            visible: true,
+        }
+    }
+
+    /// Draws a dashed horizontal line at a statistic of the y values, labelled with its
+    /// value, e.g. `mean 4.20`. The line follows the data at every render.
+    ///
+    /// # Parameters
+    /// - `line`: The statistic to mark (`ReferenceLine::Mean` or `ReferenceLine::Median`).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::referenceline::ReferenceLine;
+    ///
+    /// dataset.set_reference_line(ReferenceLine::Mean);
+    /// ```
+    pub fn set_reference_line(&mut self, line: ReferenceLine) {
+        self.reference_line = Some(line);
+    }
+
+    /// Shows value summaries in the legend entry of the dataset, recomputed at every render.
+    ///
+    /// # Parameters
+    /// - `format`: The entry text, with the placeholders `{label}`, `{last}`, `{mean}`,
+    ///   `{min}`, `{max}`, `{count}`, `{median}`, `{std}` and percentiles such as `{p95}`
+    ///   over the y values, e.g.
+    ///   `"{label} (last {last}, avg {mean:.1})"`. See `legendsummary::format_legend`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// dataset.set_legend_format("{label}: {last} (min {min}, max {max})");
+    /// ```
+    pub fn set_legend_format(&mut self, format: &str) {
+        self.legend_format = Some(format.to_string());
+    }
+
+    /// Returns the text of the legend entry: the label, or the formatted summary if a
+    /// legend format is set.
+    ///
+    /// # Parameters
+    /// - `context`: The format context of the figure, used for the summary numbers.
+    pub fn legend_label(&self, context: &FormatContext) -> String {
+        match &self.legend_format {
+            Some(format) => {
+                let values: Vec<f64> = self.points.iter().map(|&(_, y)| y).collect();
+                format_legend(format, &self.label, &values, context)
+            }
+            None => self.label.clone(),
         }
     }
 }
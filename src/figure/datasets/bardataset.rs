@@ -1,4 +1,5 @@
 /// A dataset for a bar chart, containing data points, appearance properties, and metadata.
+#[derive(Clone)]
 pub struct BarDataset {
     /// Label for the dataset, used in legends or annotations.
     pub label: String,
@@ -6,6 +7,10 @@ pub struct BarDataset {
     pub color: [u8; 3],
     /// A collection of `(x, y)` data points where `x` is the category and `y` is the value.
     pub data: Vec<(f64, f64)>,
+    /// URLs of individual bars as `(x, url)` pairs, linked in SVG output.
+    pub links: Vec<(f64, String)>,
+    /// Whether the bars are drawn, listed in the legend and included in the value range.
+    pub visible: bool,
 }
 
 impl BarDataset {
@@ -25,8 +30,10 @@ impl BarDataset {
     pub fn new(label: &str, color: [u8; 3]) -> Self {
         Self {
             data: Vec::new(),
+            links: Vec::new(),
             label: label.to_string(),
             color,
+            visible: true,
         }
     }
 
@@ -45,4 +52,29 @@ impl BarDataset {
     pub fn add_data(&mut self, x: f64, y: f64) {
         self.data.push((x, y));
     }
+
+    /// Links the bar of category `x` to a URL in SVG output.
+    ///
+    /// A bar link takes precedence over the link of the whole series.
+    ///
+    /// # Parameters
+    /// - `x`: The category of the bar.
+    /// - `url`: The link target, e.g. a drill-down page.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// dataset.set_link(2021.0, "https://example.com/revenue/2021");
+    /// ```
+    pub fn set_link(&mut self, x: f64, url: &str) {
+        self.links.retain(|(link_x, _)| *link_x != x);
+        self.links.push((x, url.to_string()));
+    }
+
+    /// Returns the URL of the bar of category `x`, if any.
+    pub fn link(&self, x: f64) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|(link_x, _)| *link_x == x)
+            .map(|(_, url)| url.as_str())
+    }
 }
@@ -0,0 +1,105 @@
+use crate::figure::utilities::statistics::BoxStats;
+
+/// A dataset holding one box of a box plot: either raw samples, summarized automatically,
+/// or a precomputed summary with its outliers.
+#[derive(Clone)]
+pub struct BoxPlotDataset {
+    /// Name of the category, shown on the category axis.
+    pub label: String,
+    /// Fill color of the box in RGB format.
+    pub color: [u8; 3],
+    /// The observed values, summarized when no `stats` are given.
+    pub samples: Vec<f64>,
+    /// A precomputed summary, used instead of the samples.
+    pub stats: Option<BoxStats>,
+    /// The outliers drawn with a precomputed summary.
+    pub outliers: Vec<f64>,
+}
+
+impl BoxPlotDataset {
+    /// Creates a new `BoxPlotDataset` with no samples.
+    ///
+    /// # Parameters
+    /// - `label`: The name of the category.
+    /// - `color`: The RGB fill color of the box.
+    ///
+    /// # Returns
+    /// A new `BoxPlotDataset` instance.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::datasets::boxplotdataset::BoxPlotDataset;
+    ///
+    /// let mut control = BoxPlotDataset::new("Control", [0, 120, 200]);
+    /// control.add_samples(&[4.1, 3.9, 4.4, 9.0]);
+    /// ```
+    pub fn new(label: &str, color: [u8; 3]) -> Self {
+        Self {
+            label: label.to_string(),
+            color,
+            samples: Vec::new(),
+            stats: None,
+            outliers: Vec::new(),
+        }
+    }
+
+    /// Adds a single observation.
+    ///
+    /// # Parameters
+    /// - `value`: The observed value.
+    pub fn add_sample(&mut self, value: f64) {
+        self.samples.push(value);
+    }
+
+    /// Adds several observations.
+    ///
+    /// # Parameters
+    /// - `values`: The observed values.
+    pub fn add_samples(&mut self, values: &[f64]) {
+        self.samples.extend_from_slice(values);
+    }
+
+    /// Sets a precomputed summary, e.g. from a database aggregate, instead of raw samples.
+    ///
+    /// # Parameters
+    /// - `stats`: The quartiles and whisker ends.
+    /// - `outliers`: The values drawn beyond the whiskers.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::statistics::BoxStats;
+    ///
+    /// dataset.set_summary(
+    ///     BoxStats { q1: 2.0, median: 3.0, q3: 4.5, lower_whisker: 1.0, upper_whisker: 6.0 },
+    ///     &[9.5],
+    /// );
+    /// ```
+    pub fn set_summary(&mut self, stats: BoxStats, outliers: &[f64]) {
+        self.stats = Some(stats);
+        self.outliers = outliers.to_vec();
+    }
+
+    /// Returns the summary of the box: the precomputed one, or that of the samples.
+    ///
+    /// # Returns
+    /// The summary, or `None` without a summary or finite samples.
+    pub fn box_stats(&self) -> Option<BoxStats> {
+        self.stats.or_else(|| BoxStats::from_samples(&self.samples))
+    }
+
+    /// Returns the values drawn beyond the whiskers: the precomputed outliers, or the samples
+    /// outside the whiskers of their summary.
+    pub fn outlier_values(&self) -> Vec<f64> {
+        if self.stats.is_some() {
+            return self.outliers.clone();
+        }
+        let Some(stats) = self.box_stats() else {
+            return Vec::new();
+        };
+        self.samples
+            .iter()
+            .copied()
+            .filter(|&value| value < stats.lower_whisker || value > stats.upper_whisker)
+            .collect()
+    }
+}
@@ -0,0 +1,65 @@
+/// A dataset holding the price candles of a candlestick chart.
+///
+/// Each candle is a `(timestamp, open, high, low, close)` tuple. Candles are expected in
+/// ascending timestamp order, so indicators computed from `closes` line up with them.
+/// Traded volumes are optional and kept apart, one per candle.
+#[derive(Clone)]
+pub struct CandlestickDataset {
+    /// Name of the instrument, shown in tooltips.
+    pub label: String,
+    /// The candles as `(timestamp, open, high, low, close)` tuples.
+    pub candles: Vec<(f64, f64, f64, f64, f64)>,
+    /// The traded volume of each candle, in candle order; empty without volumes.
+    pub volumes: Vec<f64>,
+}
+
+impl CandlestickDataset {
+    /// Creates a new `CandlestickDataset` with no candles.
+    ///
+    /// # Parameters
+    /// - `label`: The name of the instrument.
+    ///
+    /// # Returns
+    /// A new `CandlestickDataset` instance.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::datasets::candlestickdataset::CandlestickDataset;
+    ///
+    /// let mut prices = CandlestickDataset::new("ACME");
+    /// prices.add_candle(1_700_000_000.0, 101.2, 104.8, 100.5, 103.9);
+    /// ```
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            candles: Vec::new(),
+            volumes: Vec::new(),
+        }
+    }
+
+    /// Adds a candle.
+    ///
+    /// # Parameters
+    /// - `timestamp`: The start of the candle's period, e.g. in Unix seconds.
+    /// - `open`, `high`, `low`, `close`: The prices of the period.
+    pub fn add_candle(&mut self, timestamp: f64, open: f64, high: f64, low: f64, close: f64) {
+        self.candles.push((timestamp, open, high, low, close));
+    }
+
+    /// Sets the traded volumes, drawn in a panel below the candles.
+    ///
+    /// # Parameters
+    /// - `volumes`: One volume per candle, in candle order. Candles without a volume get no
+    ///   volume bar.
+    pub fn set_volumes(&mut self, volumes: Vec<f64>) {
+        self.volumes = volumes;
+    }
+
+    /// Returns the closing prices, in candle order, e.g. as input for `utilities::indicators`.
+    pub fn closes(&self) -> Vec<f64> {
+        self.candles
+            .iter()
+            .map(|&(_, _, _, _, close)| close)
+            .collect()
+    }
+}
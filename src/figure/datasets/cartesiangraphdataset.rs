@@ -1,6 +1,11 @@
-use crate::figure::utilities::linetype::LineType;
+use crate::figure::utilities::{
+    axistype::AxisType, datatransform::TransformPipeline, errorbars::ErrorBars,
+    formatcontext::FormatContext, interpolation::Interpolation, legendsummary::format_legend,
+    linetype::LineType, referenceline::ReferenceLine,
+};
 
 /// A dataset for Cartesian graphs, representing data points and line appearance properties.
+#[derive(Clone)]
 pub struct CartesianDataset {
     /// A collection of `(x, y)` data points for the Cartesian graph.
     pub points: Vec<(f64, f64)>,
@@ -10,6 +15,21 @@ pub struct CartesianDataset {
     pub label: String,
     /// Style of the line (solid, dashed, dotted).
     pub line_type: LineType,
+    /// Legend entry format with value summaries, or `None` to show the label.
+    pub legend_format: Option<String>,
+    /// Statistic marked by a horizontal line, or `None` for no line.
+    pub reference_line: Option<ReferenceLine>,
+    /// Error bars of the points, aligned with `points` by index.
+    pub error_bars: ErrorBars,
+    /// Indices into `points` where a new line segment starts, in ascending order. The line
+    /// is not drawn across these gaps.
+    pub segment_starts: Vec<usize>,
+    /// How consecutive points are connected (straight lines or steps).
+    pub interpolation: Interpolation,
+    /// Transforms applied to each segment at render time, or `None` to draw the raw points.
+    pub transform: Option<TransformPipeline>,
+    /// Whether the dataset is drawn, listed in the legend and fitted by autoscaling.
+    pub visible: bool,
 }
 
 impl CartesianDataset {
@@ -35,6 +55,199 @@ impl CartesianDataset {
             color,
             label: label.to_string(),
             line_type,
+            legend_format: None,
+            reference_line: None,
+            error_bars: ErrorBars::default(),
+            segment_starts: Vec::new(),
+            interpolation: Interpolation::Linear,
+            transform: None,
+            visible: true,
+        }
+    }
+
+    /// Ends the current line segment: the next point added starts a new polyline, leaving a
+    /// gap in the line (e.g. between sessions or days). The dataset keeps one legend entry.
+    ///
+    /// Calling this before any point was added, or twice in a row, has no effect.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// for session in sessions {
+    ///     dataset.points.extend(session);
+    ///     dataset.start_new_segment();
+    /// }
+    /// ```
+    pub fn start_new_segment(&mut self) {
+        let start = self.points.len();
+        if start > 0 && self.segment_starts.last() != Some(&start) {
+            self.segment_starts.push(start);
+        }
+    }
+
+    /// Splits the points into the segments drawn as separate polylines.
+    ///
+    /// # Returns
+    /// The non-empty segments in order; a single segment with all points if
+    /// `start_new_segment` was never called.
+    pub fn segments(&self) -> Vec<&[(f64, f64)]> {
+        let mut segments = Vec::new();
+        let mut start = 0;
+        for &end in self.segment_starts.iter().chain([self.points.len()].iter()) {
+            let end = end.min(self.points.len());
+            if end > start {
+                segments.push(&self.points[start..end]);
+                start = end;
+            }
+        }
+        segments
+    }
+
+    /// Sets how consecutive points are connected, e.g. as steps for sampled values that hold
+    /// until the next sample.
+    ///
+    /// # Parameters
+    /// - `interpolation`: The interpolation mode; `Interpolation::Linear` by default.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::interpolation::Interpolation;
+    ///
+    /// dataset.set_interpolation(Interpolation::StepPost);
+    /// ```
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Attaches a transform pipeline (resampling, gap filling, smoothing) evaluated at every
+    /// render, so points appended later, e.g. by streaming updates, are transformed too. The
+    /// raw `points` are kept unchanged; error bars and reference lines use them.
+    ///
+    /// # Parameters
+    /// - `transform`: The pipeline, applied to each segment separately.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::datatransform::{ema, Gap, TransformPipeline};
+    ///
+    /// dataset.set_transform(
+    ///     TransformPipeline::new()
+    ///         .resample(60.0)
+    ///         .fill_gaps(Gap::Null)
+    ///         .smooth(ema(0.2)),
+    /// );
+    /// ```
+    pub fn set_transform(&mut self, transform: TransformPipeline) {
+        self.transform = Some(transform);
+    }
+
+    /// Applies the transform pipeline to the segments.
+    ///
+    /// # Returns
+    /// The transformed segments; the raw segments if no transform is set. Gaps left by the
+    /// pipeline split a segment further.
+    pub fn transformed_segments(&self) -> Vec<Vec<(f64, f64)>> {
+        match &self.transform {
+            Some(transform) => self
+                .segments()
+                .into_iter()
+                .flat_map(|segment| transform.apply(segment))
+                .collect(),
+            None => self.segments().into_iter().map(<[_]>::to_vec).collect(),
+        }
+    }
+
+    /// Returns the points as drawn: the transformed points of all segments, in order.
+    pub fn transformed_points(&self) -> Vec<(f64, f64)> {
+        match &self.transform {
+            Some(_) => self.transformed_segments().concat(),
+            None => self.points.clone(),
+        }
+    }
+
+    /// Computes the polylines drawn for the dataset: its transformed segments with the step
+    /// vertices of the interpolation mode inserted.
+    ///
+    /// # Returns
+    /// The vertices of each drawn polyline, in segment order.
+    pub fn drawn_segments(&self) -> Vec<Vec<(f64, f64)>> {
+        self.transformed_segments()
+            .iter()
+            .map(|segment| self.interpolation.apply(segment))
+            .collect()
+    }
+
+    /// Attaches symmetric error bars to the points, drawn with caps by `CartesianGraph` and
+    /// included in its axis ranges.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis of the errors (`AxisType::AxisY` for vertical bars).
+    /// - `errors`: The error of each point, in point order; the bar spans `value ± error`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// dataset.points = vec![(1.0, 4.2), (2.0, 5.1), (3.0, 4.8)];
+    /// dataset.set_errors(AxisType::AxisY, &[0.3, 0.5, 0.2]);
+    /// ```
+    pub fn set_errors(&mut self, axis: AxisType, errors: &[f64]) {
+        let errors: Vec<(f64, f64)> = errors.iter().map(|&error| (error, error)).collect();
+        self.error_bars.set(axis, &errors);
+    }
+
+    /// Attaches asymmetric error bars to the points, e.g. confidence intervals that are not
+    /// centered on the value.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis of the errors.
+    /// - `errors`: `(minus, plus)` errors of each point, in point order; the bar spans from
+    ///   `value - minus` to `value + plus`.
+    pub fn set_asymmetric_errors(&mut self, axis: AxisType, errors: &[(f64, f64)]) {
+        self.error_bars.set(axis, errors);
+    }
+
+    /// Draws a dashed horizontal line at a statistic of the y values, labelled with its
+    /// value, e.g. `mean 4.20`. The line follows the data at every render.
+    ///
+    /// # Parameters
+    /// - `line`: The statistic to mark (`ReferenceLine::Mean` or `ReferenceLine::Median`).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::referenceline::ReferenceLine;
+    ///
+    /// dataset.set_reference_line(ReferenceLine::Mean);
+    /// ```
+    pub fn set_reference_line(&mut self, line: ReferenceLine) {
+        self.reference_line = Some(line);
+    }
+
+    /// Shows value summaries in the legend entry of the dataset, recomputed at every render.
+    ///
+    /// # Parameters
+    /// - `format`: The entry text, with the placeholders `{label}`, `{last}`, `{mean}`,
+    ///   `{min}`, `{max}`, `{count}`, `{median}`, `{std}` and percentiles such as `{p95}`
+    ///   over the y values, e.g.
+    ///   `"{label} (last {last}, avg {mean:.1})"`. See `legendsummary::format_legend`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// dataset.set_legend_format("{label}: {last} (min {min}, max {max})");
+    /// ```
+    pub fn set_legend_format(&mut self, format: &str) {
+        self.legend_format = Some(format.to_string());
+    }
+
+    /// Returns the text of the legend entry: the label, or the formatted summary if a
+    /// legend format is set.
+    ///
+    /// # Parameters
+    /// - `context`: The format context of the figure, used for the summary numbers.
+    pub fn legend_label(&self, context: &FormatContext) -> String {
+        match &self.legend_format {
+            Some(format) => {
+                let values: Vec<f64> = self.points.iter().map(|&(_, y)| y).collect();
+                format_legend(format, &self.label, &values, context)
+            }
+            None => self.label.clone(),
         }
     }
 }
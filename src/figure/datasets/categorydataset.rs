@@ -0,0 +1,52 @@
+/// A dataset holding the raw observations of one category, used by distribution figures.
+#[derive(Clone)]
+pub struct CategoryDataset {
+    /// Name of the category, shown on the category axis and in legends.
+    pub label: String,
+    /// Color of the category's marks in RGB format.
+    pub color: [u8; 3],
+    /// The observed values.
+    pub samples: Vec<f64>,
+}
+
+impl CategoryDataset {
+    /// Creates a new `CategoryDataset` with no samples.
+    ///
+    /// # Parameters
+    /// - `label`: The name of the category.
+    /// - `color`: The RGB color of the category's marks.
+    ///
+    /// # Returns
+    /// A new `CategoryDataset` instance.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::datasets::categorydataset::CategoryDataset;
+    ///
+    /// let mut control = CategoryDataset::new("Control", [0, 120, 200]);
+    /// control.add_samples(&[4.1, 3.9, 4.4]);
+    /// ```
+    pub fn new(label: &str, color: [u8; 3]) -> Self {
+        Self {
+            label: label.to_string(),
+            color,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Adds a single observation.
+    ///
+    /// # Parameters
+    /// - `value`: The observed value.
+    pub fn add_sample(&mut self, value: f64) {
+        self.samples.push(value);
+    }
+
+    /// Adds several observations.
+    ///
+    /// # Parameters
+    /// - `values`: The observed values.
+    pub fn add_samples(&mut self, values: &[f64]) {
+        self.samples.extend_from_slice(values);
+    }
+}
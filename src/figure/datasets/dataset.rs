@@ -1,6 +1,10 @@
+use crate::figure::utilities::statistics;
+
 use super::{
-    areachartdataset::AreaChartDataset, bardataset::BarDataset,
-    cartesiangraphdataset::CartesianDataset, scattergraphdataset::ScatterGraphDataset,
+    areachartdataset::AreaChartDataset, bardataset::BarDataset, boxplotdataset::BoxPlotDataset,
+    cartesiangraphdataset::CartesianDataset, categorydataset::CategoryDataset,
+    densityplotdataset::DensityPlotDataset, radardataset::RadarDataset,
+    scattergraphdataset::ScatterGraphDataset, violinplotdataset::ViolinPlotDataset,
 };
 
 /// A trait for managing datasets used in different types of charts or graphs.
@@ -73,3 +77,142 @@ impl Dataset for AreaChartDataset {
         self.points.push(point);
     }
 }
+
+/// A trait exposing summary statistics of a dataset's values, so that annotations, reference
+/// lines and legends can use them without collecting the values themselves.
+///
+/// Statistics are computed from the current values at every call; non-finite values are
+/// ignored. Point datasets summarize their y values, sample datasets their samples.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::datasets::dataset::DatasetStatistics;
+///
+/// // Draw a dashed mean line across the range of a series
+/// if let (Some(mean), Some((x_min, x_max))) = (latency.mean(), x_range) {
+///     let mut mean_line = CartesianDataset::new([120, 120, 120], "Mean", LineType::Dashed(6));
+///     mean_line.points = vec![(x_min, mean), (x_max, mean)];
+///     graph.add_dataset(mean_line);
+/// }
+/// let p95 = latency.quantile(0.95);
+/// ```
+pub trait DatasetStatistics {
+    /// Returns the values the statistics are computed over, in dataset order.
+    fn values(&self) -> Vec<f64>;
+
+    /// Returns the number of finite values.
+    fn count(&self) -> usize {
+        self.values().iter().filter(|v| v.is_finite()).count()
+    }
+
+    /// Returns the smallest finite value, or `None` without finite values.
+    fn min(&self) -> Option<f64> {
+        self.values()
+            .into_iter()
+            .filter(|v| v.is_finite())
+            .reduce(f64::min)
+    }
+
+    /// Returns the largest finite value, or `None` without finite values.
+    fn max(&self) -> Option<f64> {
+        self.values()
+            .into_iter()
+            .filter(|v| v.is_finite())
+            .reduce(f64::max)
+    }
+
+    /// Returns the arithmetic mean, or `None` without finite values.
+    fn mean(&self) -> Option<f64> {
+        statistics::mean(&self.values())
+    }
+
+    /// Returns the sample standard deviation, or `None` with fewer than two finite values.
+    fn std_dev(&self) -> Option<f64> {
+        statistics::std_dev(&self.values())
+    }
+
+    /// Returns a quantile, interpolated linearly between the closest ranks.
+    ///
+    /// # Parameters
+    /// - `q`: The quantile, between `0.0` and `1.0` (e.g. `0.95` for the 95th percentile).
+    ///
+    /// # Returns
+    /// The quantile, or `None` without finite values.
+    fn quantile(&self, q: f64) -> Option<f64> {
+        let mut sorted: Vec<f64> = self
+            .values()
+            .into_iter()
+            .filter(|v| v.is_finite())
+            .collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        statistics::quantile(&sorted, q)
+    }
+
+    /// Returns the median, or `None` without finite values.
+    fn median(&self) -> Option<f64> {
+        self.quantile(0.5)
+    }
+}
+
+impl DatasetStatistics for BarDataset {
+    /// The bar values.
+    fn values(&self) -> Vec<f64> {
+        self.data.iter().map(|&(_, y)| y).collect()
+    }
+}
+
+impl DatasetStatistics for CartesianDataset {
+    /// The y values of the points.
+    fn values(&self) -> Vec<f64> {
+        self.points.iter().map(|&(_, y)| y).collect()
+    }
+}
+
+impl DatasetStatistics for ScatterGraphDataset {
+    /// The y values of the points.
+    fn values(&self) -> Vec<f64> {
+        self.points.iter().map(|&(_, y)| y).collect()
+    }
+}
+
+impl DatasetStatistics for AreaChartDataset {
+    /// The y values of the points.
+    fn values(&self) -> Vec<f64> {
+        self.points.iter().map(|&(_, y)| y).collect()
+    }
+}
+
+impl DatasetStatistics for CategoryDataset {
+    /// The raw observations.
+    fn values(&self) -> Vec<f64> {
+        self.samples.clone()
+    }
+}
+
+impl DatasetStatistics for BoxPlotDataset {
+    /// The raw samples; empty for datasets built from precomputed summaries.
+    fn values(&self) -> Vec<f64> {
+        self.samples.clone()
+    }
+}
+
+impl DatasetStatistics for ViolinPlotDataset {
+    /// The raw samples.
+    fn values(&self) -> Vec<f64> {
+        self.samples.clone()
+    }
+}
+
+impl DatasetStatistics for DensityPlotDataset {
+    /// The raw samples.
+    fn values(&self) -> Vec<f64> {
+        self.samples.clone()
+    }
+}
+
+impl DatasetStatistics for RadarDataset {
+    /// The values on the axes.
+    fn values(&self) -> Vec<f64> {
+        self.values.clone()
+    }
+}
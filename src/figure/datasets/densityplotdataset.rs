@@ -0,0 +1,115 @@
+use crate::figure::utilities::statistics::{
+    gaussian_density_at, kde_bandwidth, kernel_density_between,
+};
+
+/// A dataset holding the raw samples of one curve of a density plot.
+#[derive(Clone)]
+pub struct DensityPlotDataset {
+    /// Legend label of the curve.
+    pub label: String,
+    /// Color of the curve and its fill in RGB format.
+    pub color: [u8; 3],
+    /// The observed values the density is estimated from.
+    pub samples: Vec<f64>,
+    /// The bandwidth of the kernel density estimate, in data units. Silverman's rule of thumb
+    /// is used when `None`.
+    pub bandwidth: Option<f64>,
+    /// Whether the curve is drawn, listed in the legend and fitted by the axis ranges of a
+    /// `DensityPlot`.
+    pub visible: bool,
+}
+
+impl DensityPlotDataset {
+    /// Creates a new `DensityPlotDataset` with no samples.
+    ///
+    /// # Parameters
+    /// - `label`: The legend label of the curve.
+    /// - `color`: The RGB color of the curve.
+    ///
+    /// # Returns
+    /// A new `DensityPlotDataset` instance.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::datasets::densityplotdataset::DensityPlotDataset;
+    ///
+    /// let mut latencies = DensityPlotDataset::new("Latency", [0, 120, 200]);
+    /// latencies.add_samples(&[12.1, 13.4, 11.8, 25.0, 12.9]);
+    /// ```
+    pub fn new(label: &str, color: [u8; 3]) -> Self {
+        Self {
+            label: label.to_string(),
+            color,
+            samples: Vec::new(),
+            bandwidth: None,
+            visible: true,
+        }
+    }
+
+    /// Adds a single observation.
+    ///
+    /// # Parameters
+    /// - `value`: The observed value.
+    pub fn add_sample(&mut self, value: f64) {
+        self.samples.push(value);
+    }
+
+    /// Adds several observations.
+    ///
+    /// # Parameters
+    /// - `values`: The observed values.
+    pub fn add_samples(&mut self, values: &[f64]) {
+        self.samples.extend_from_slice(values);
+    }
+
+    /// Sets the bandwidth of the kernel density estimate. Smaller bandwidths show more detail,
+    /// larger ones a smoother curve.
+    ///
+    /// # Parameters
+    /// - `bandwidth`: The standard deviation of the Gaussian kernel, in data units.
+    pub fn set_bandwidth(&mut self, bandwidth: f64) {
+        self.bandwidth = Some(bandwidth);
+    }
+
+    /// Returns the bandwidth used for the estimate: the one set, or Silverman's rule of
+    /// thumb.
+    ///
+    /// # Returns
+    /// The bandwidth, or `None` if none is set and the samples have fewer than two distinct
+    /// finite values.
+    pub fn effective_bandwidth(&self) -> Option<f64> {
+        kde_bandwidth(&self.finite_samples(), self.bandwidth)
+    }
+
+    /// Estimates the density of the samples at a single value.
+    ///
+    /// # Returns
+    /// The density, or `None` if no bandwidth can be resolved.
+    pub fn density_at(&self, value: f64) -> Option<f64> {
+        let finite = self.finite_samples();
+        let bandwidth = kde_bandwidth(&finite, self.bandwidth)?;
+        Some(gaussian_density_at(&finite, bandwidth, value))
+    }
+
+    /// Returns the samples without non-finite values.
+    fn finite_samples(&self) -> Vec<f64> {
+        self.samples
+            .iter()
+            .copied()
+            .filter(|v| v.is_finite())
+            .collect()
+    }
+
+    /// Estimates the density of the samples over a range.
+    ///
+    /// # Parameters
+    /// - `range`: The `(start, end)` values the density is evaluated between.
+    /// - `points`: The number of values the density is evaluated at.
+    ///
+    /// # Returns
+    /// Pairs of `(value, density)` in ascending order of value, empty if no bandwidth can be
+    /// resolved.
+    pub fn density(&self, range: (f64, f64), points: usize) -> Vec<(f64, f64)> {
+        kernel_density_between(&self.samples, self.bandwidth, range, points)
+    }
+}
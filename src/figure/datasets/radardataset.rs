@@ -0,0 +1,44 @@
+/// A dataset holding one series of a radar chart: a value for each axis.
+#[derive(Clone)]
+pub struct RadarDataset {
+    /// Name of the series, shown in the legend and tooltips.
+    pub label: String,
+    /// The values, in the order of the chart's axes. Missing values are drawn at the center.
+    pub values: Vec<f64>,
+    /// Color of the series in RGB format.
+    pub color: [u8; 3],
+    /// Whether the series is drawn, listed in the legend and fitted by the outer ring.
+    pub visible: bool,
+}
+
+impl RadarDataset {
+    /// Creates a new `RadarDataset`.
+    ///
+    /// # Parameters
+    /// - `label`: The name of the series.
+    /// - `values`: The value for each axis, in axis order.
+    /// - `color`: The RGB color of the series.
+    ///
+    /// # Returns
+    /// A new `RadarDataset` instance.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::datasets::radardataset::RadarDataset;
+    ///
+    /// let player = RadarDataset::new("Player A", &[7.0, 9.0, 4.0, 6.0, 8.0], [200, 50, 50]);
+    /// ```
+    pub fn new(label: &str, values: &[f64], color: [u8; 3]) -> Self {
+        Self {
+            label: label.to_string(),
+            values: values.to_vec(),
+            color,
+            visible: true,
+        }
+    }
+
+    /// Returns the value on an axis, or `0.0` if the series has none.
+    pub fn value(&self, axis: usize) -> f64 {
+        self.values.get(axis).copied().unwrap_or(0.0)
+    }
+}
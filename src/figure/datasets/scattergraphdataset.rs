@@ -1,6 +1,10 @@
-use crate::figure::utilities::scatterdottype::ScatterDotType;
+use crate::figure::utilities::{
+    axistype::AxisType, errorbars::ErrorBars, formatcontext::FormatContext,
+    legendsummary::format_legend, referenceline::ReferenceLine, scatterdottype::ScatterDotType,
+};
 
 /// A dataset for scatter graphs, representing points and their appearance.
+#[derive(Clone)]
 pub struct ScatterGraphDataset {
     /// A collection of `(x, y)` data points for the scatter graph.
     pub points: Vec<(f64, f64)>,
@@ -10,6 +14,17 @@ pub struct ScatterGraphDataset {
     pub label: String,
     /// Shape of the scatter points (circle, square, triangle, etc.).
     pub dot_type: ScatterDotType,
+    /// Legend entry format with value summaries, or `None` to show the label.
+    pub legend_format: Option<String>,
+    /// Statistic marked by a horizontal line, or `None` for no line.
+    pub reference_line: Option<ReferenceLine>,
+    /// Error bars of the points, aligned with `points` by index.
+    pub error_bars: ErrorBars,
+    /// Third value of each point, aligned with `points` by index, drawn as the area of a
+    /// bubble. Points without a size are drawn with `dot_type`.
+    pub sizes: Vec<f64>,
+    /// Whether the dataset is drawn, listed in the legend and fitted by autoscaling.
+    pub visible: bool,
 }
 
 impl ScatterGraphDataset {
@@ -39,6 +54,115 @@ impl ScatterGraphDataset {
             color,
             label: label.to_string(),
             dot_type,
+            legend_format: None,
+            reference_line: None,
+            error_bars: ErrorBars::default(),
+            sizes: Vec::new(),
+            visible: true,
+        }
+    }
+
+    /// Turns the points into bubbles whose area encodes a third value.
+    ///
+    /// Sizes are mapped to radii between the minimum and maximum bubble radius of the
+    /// graph, over the sizes of all its datasets.
+    ///
+    /// # Parameters
+    /// - `sizes`: The size of each point, in point order. Points without a size, or with a
+    ///   non-finite one, keep their `dot_type`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// dataset.points = vec![(1.0, 4.2), (2.0, 5.1), (3.0, 4.8)];
+    /// dataset.set_sizes(&[120.0, 45.0, 300.0]);
+    /// ```
+    pub fn set_sizes(&mut self, sizes: &[f64]) {
+        self.sizes = sizes.to_vec();
+    }
+
+    /// Returns the size of a point, or `None` if it is drawn with `dot_type`.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the point.
+    pub fn size(&self, index: usize) -> Option<f64> {
+        self.sizes
+            .get(index)
+            .copied()
+            .filter(|size| size.is_finite())
+    }
+
+    /// Attaches symmetric error bars to the points, drawn with caps and included in the
+    /// axis ranges.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis of the errors (`AxisType::AxisY` for vertical bars).
+    /// - `errors`: The error of each point, in point order; the bar spans `value ± error`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// dataset.points = vec![(1.0, 4.2), (2.0, 5.1), (3.0, 4.8)];
+    /// dataset.set_errors(AxisType::AxisY, &[0.3, 0.5, 0.2]);
+    /// ```
+    pub fn set_errors(&mut self, axis: AxisType, errors: &[f64]) {
+        let errors: Vec<(f64, f64)> = errors.iter().map(|&error| (error, error)).collect();
+        self.error_bars.set(axis, &errors);
+    }
+
+    /// Attaches asymmetric error bars to the points, e.g. confidence intervals that are not
+    /// centered on the value.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis of the errors.
+    /// - `errors`: `(minus, plus)` errors of each point, in point order; the bar spans from
+    ///   `value - minus` to `value + plus`.
+    pub fn set_asymmetric_errors(&mut self, axis: AxisType, errors: &[(f64, f64)]) {
+        self.error_bars.set(axis, errors);
+    }
+
+    /// Draws a dashed horizontal line at a statistic of the y values, labelled with its
+    /// value, e.g. `mean 4.20`. The line follows the data at every render.
+    ///
+    /// # Parameters
+    /// - `line`: The statistic to mark (`ReferenceLine::Mean` or `ReferenceLine::Median`).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::referenceline::ReferenceLine;
+    ///
+    /// dataset.set_reference_line(ReferenceLine::Mean);
+    /// ```
+    pub fn set_reference_line(&mut self, line: ReferenceLine) {
+        self.reference_line = Some(line);
+    }
+
+    /// Shows value summaries in the legend entry of the dataset, recomputed at every render.
+    ///
+    /// # Parameters
+    /// - `format`: The entry text, with the placeholders `{label}`, `{last}`, `{mean}`,
+    ///   `{min}`, `{max}`, `{count}`, `{median}`, `{std}` and percentiles such as `{p95}`
+    ///   over the y values, e.g.
+    ///   `"{label} (last {last}, avg {mean:.1})"`. See `legendsummary::format_legend`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// dataset.set_legend_format("{label}: {last} (min {min}, max {max})");
+    /// ```
+    pub fn set_legend_format(&mut self, format: &str) {
+        self.legend_format = Some(format.to_string());
+    }
+
+    /// Returns the text of the legend entry: the label, or the formatted summary if a
+    /// legend format is set.
+    ///
+    /// # Parameters
+    /// - `context`: The format context of the figure, used for the summary numbers.
+    pub fn legend_label(&self, context: &FormatContext) -> String {
+        match &self.legend_format {
+            Some(format) => {
+                let values: Vec<f64> = self.points.iter().map(|&(_, y)| y).collect();
+                format_legend(format, &self.label, &values, context)
+            }
+            None => self.label.clone(),
         }
     }
 }
@@ -0,0 +1,52 @@
+/// A dataset holding one segment of a stacked figure, with one value per category.
+#[derive(Clone)]
+pub struct SegmentDataset {
+    /// Name of the segment, shown in legends and tooltips.
+    pub label: String,
+    /// Color of the segment's cells in RGB format.
+    pub color: [u8; 3],
+    /// The segment's value in each category, in category order.
+    pub values: Vec<f64>,
+}
+
+impl SegmentDataset {
+    /// Creates a new `SegmentDataset` with no values.
+    ///
+    /// # Parameters
+    /// - `label`: The name of the segment.
+    /// - `color`: The RGB color of the segment's cells.
+    ///
+    /// # Returns
+    /// A new `SegmentDataset` instance.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::datasets::segmentdataset::SegmentDataset;
+    ///
+    /// let mut premium = SegmentDataset::new("Premium", [120, 40, 140]);
+    /// premium.add_values(&[12.0, 30.0, 8.0]);
+    /// ```
+    pub fn new(label: &str, color: [u8; 3]) -> Self {
+        Self {
+            label: label.to_string(),
+            color,
+            values: Vec::new(),
+        }
+    }
+
+    /// Adds the segment's value in the next category.
+    ///
+    /// # Parameters
+    /// - `value`: The value of the segment in that category.
+    pub fn add_value(&mut self, value: f64) {
+        self.values.push(value);
+    }
+
+    /// Adds the segment's values in the next categories.
+    ///
+    /// # Parameters
+    /// - `values`: The values of the segment, one per category.
+    pub fn add_values(&mut self, values: &[f64]) {
+        self.values.extend_from_slice(values);
+    }
+}
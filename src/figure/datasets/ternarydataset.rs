@@ -0,0 +1,67 @@
+use crate::figure::utilities::{linetype::LineType, scatterdottype::ScatterDotType};
+
+/// A dataset for ternary plots, holding three-component compositions.
+#[derive(Clone)]
+pub struct TernaryDataset {
+    /// Label for the dataset, used in legends or annotations.
+    pub label: String,
+    /// Color of the points and line in RGB format.
+    pub color: [u8; 3],
+    /// The compositions as `[a, b, c]` fractions summing to 1.
+    pub points: Vec<[f64; 3]>,
+    /// Shape of the points.
+    pub dot_type: ScatterDotType,
+    /// Style of the line connecting the points in order, or `None` for points only.
+    pub line_type: Option<LineType>,
+}
+
+impl TernaryDataset {
+    /// Creates a new `TernaryDataset` drawn as unconnected points.
+    ///
+    /// # Parameters
+    /// - `color`: The RGB color of the points.
+    /// - `label`: A descriptive label for the dataset.
+    /// - `dot_type`: The shape of the points (`ScatterDotType`).
+    ///
+    /// # Returns
+    /// A new `TernaryDataset` instance with no points.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::datasets::ternarydataset::TernaryDataset;
+    /// use dataviz::figure::utilities::scatterdottype::ScatterDotType;
+    ///
+    /// let mut soils = TernaryDataset::new([150, 90, 40], "Soils", ScatterDotType::Circle(3));
+    /// soils.add_point(40.0, 40.0, 20.0); // sand, silt and clay percentages
+    /// ```
+    pub fn new(color: [u8; 3], label: &str, dot_type: ScatterDotType) -> Self {
+        Self {
+            label: label.to_string(),
+            color,
+            points: Vec::new(),
+            dot_type,
+            line_type: None,
+        }
+    }
+
+    /// Adds a composition, normalized so that its components sum to 1.
+    ///
+    /// Compositions with a non-positive sum are ignored.
+    ///
+    /// # Parameters
+    /// - `a`, `b`, `c`: The three components, in any common unit (fractions, percentages, ...).
+    pub fn add_point(&mut self, a: f64, b: f64, c: f64) {
+        let total = a + b + c;
+        if total > 0.0 {
+            self.points.push([a / total, b / total, c / total]);
+        }
+    }
+
+    /// Connects the points in order with a line.
+    ///
+    /// # Parameters
+    /// - `line_type`: The style of the line (`LineType`).
+    pub fn set_line(&mut self, line_type: LineType) {
+        self.line_type = Some(line_type);
+    }
+}
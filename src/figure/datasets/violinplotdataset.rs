@@ -0,0 +1,87 @@
+use crate::figure::utilities::statistics::{kernel_density, BoxStats};
+
+/// A dataset holding the raw samples of one violin of a violin plot.
+#[derive(Clone)]
+pub struct ViolinPlotDataset {
+    /// Name of the category, shown on the category axis.
+    pub label: String,
+    /// Fill color of the violin in RGB format.
+    pub color: [u8; 3],
+    /// The observed values the density is estimated from.
+    pub samples: Vec<f64>,
+    /// The bandwidth of the kernel density estimate, in data units. Silverman's rule of thumb
+    /// is used when `None`.
+    pub bandwidth: Option<f64>,
+}
+
+impl ViolinPlotDataset {
+    /// Creates a new `ViolinPlotDataset` with no samples.
+    ///
+    /// # Parameters
+    /// - `label`: The name of the category.
+    /// - `color`: The RGB fill color of the violin.
+    ///
+    /// # Returns
+    /// A new `ViolinPlotDataset` instance.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::datasets::violinplotdataset::ViolinPlotDataset;
+    ///
+    /// let mut control = ViolinPlotDataset::new("Control", [0, 120, 200]);
+    /// control.add_samples(&[4.1, 3.9, 4.4, 5.2, 3.7]);
+    /// ```
+    pub fn new(label: &str, color: [u8; 3]) -> Self {
+        Self {
+            label: label.to_string(),
+            color,
+            samples: Vec::new(),
+            bandwidth: None,
+        }
+    }
+
+    /// Adds a single observation.
+    ///
+    /// # Parameters
+    /// - `value`: The observed value.
+    pub fn add_sample(&mut self, value: f64) {
+        self.samples.push(value);
+    }
+
+    /// Adds several observations.
+    ///
+    /// # Parameters
+    /// - `values`: The observed values.
+    pub fn add_samples(&mut self, values: &[f64]) {
+        self.samples.extend_from_slice(values);
+    }
+
+    /// Sets the bandwidth of the kernel density estimate. Smaller bandwidths show more detail,
+    /// larger ones a smoother shape.
+    ///
+    /// # Parameters
+    /// - `bandwidth`: The standard deviation of the Gaussian kernel, in data units.
+    pub fn set_bandwidth(&mut self, bandwidth: f64) {
+        self.bandwidth = Some(bandwidth);
+    }
+
+    /// Returns the quartiles and whiskers of the samples.
+    ///
+    /// # Returns
+    /// The summary, or `None` without finite samples.
+    pub fn box_stats(&self) -> Option<BoxStats> {
+        BoxStats::from_samples(&self.samples)
+    }
+
+    /// Estimates the density of the samples between the smallest and the largest sample.
+    ///
+    /// # Parameters
+    /// - `points`: The number of values the density is evaluated at.
+    ///
+    /// # Returns
+    /// Pairs of `(value, density)` in ascending order of value, empty if the samples have
+    /// fewer than two distinct finite values.
+    pub fn density(&self, points: usize) -> Vec<(f64, f64)> {
+        kernel_density(&self.samples, self.bandwidth, points)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::figure::canvas::pixelcanvas::PixelCanvas;
+
+/// A pair of window pixel buffers for flicker-free live display.
+///
+/// New frames are written into the back buffer while the window keeps showing the front
+/// buffer, and the two are swapped once the frame is complete, so the window never shows a
+/// partially converted or resized frame. Buffers hold `0RGB` pixels as expected by minifb.
+pub struct FrameBuffers {
+    /// The complete frame shown in the window.
+    front: Vec<u32>,
+    /// The frame being written.
+    back: Vec<u32>,
+    /// Width of the frames in pixels.
+    width: usize,
+    /// Height of the frames in pixels.
+    height: usize,
+}
+
+impl FrameBuffers {
+    /// Creates a pair of black buffers.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The size of the frames in pixels.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            front: vec![0; width * height],
+            back: vec![0; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a pair of buffers showing the current content of a canvas.
+    ///
+    /// # Parameters
+    /// - `canvas`: The canvas, whose size becomes the frame size.
+    pub fn from_canvas(canvas: &PixelCanvas) -> Self {
+        let mut buffers = Self::new(canvas.width as usize, canvas.height as usize);
+        buffers.present(canvas);
+        buffers
+    }
+
+    /// Returns the frame shown in the window.
+    pub fn front(&self) -> &[u32] {
+        &self.front
+    }
+
+    /// Returns the size of the frames as `(width, height)`.
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Writes a finished canvas into the back buffer and swaps it to the front.
+    ///
+    /// The buffers follow the canvas if its size changed.
+    ///
+    /// # Parameters
+    /// - `canvas`: The canvas holding the new frame.
+    pub fn present(&mut self, canvas: &PixelCanvas) {
+        let (width, height) = (canvas.width as usize, canvas.height as usize);
+        self.back.clear();
+        self.back.extend(canvas.buffer.chunks_exact(3).map(|rgb| {
+            let r = rgb[0] as u32;
+            let g = rgb[1] as u32;
+            let b = rgb[2] as u32;
+            0xFF000000 | (r << 16) | (g << 8) | b
+        }));
+        self.back.resize(width * height, 0);
+        std::mem::swap(&mut self.front, &mut self.back);
+        (self.width, self.height) = (width, height);
+    }
+}
@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+/// Limits on how often a live display updates its data and redraws the window, so
+/// dashboards showing slowly changing data do not render identical frames at full speed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameThrottle {
+    /// Data updates per second.
+    pub update_fps: u32,
+    /// Upper bound on window refreshes per second, including hover redraws; `0` for no limit.
+    pub max_fps: u32,
+    /// Whether a data update that reports no change skips drawing the figure.
+    pub skip_unchanged: bool,
+}
+
+impl FrameThrottle {
+    /// Default upper bound on window refreshes per second.
+    pub const DEFAULT_MAX_FPS: u32 = 60;
+
+    /// Creates a throttle that updates the data at a fixed rate, refreshes the window at
+    /// most `DEFAULT_MAX_FPS` times per second and skips redraws of unchanged data.
+    ///
+    /// # Parameters
+    /// - `update_fps`: Data updates per second.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut throttle = FrameThrottle::new(10);
+    /// throttle.set_max_fps(30);
+    /// ```
+    pub fn new(update_fps: u32) -> Self {
+        Self {
+            update_fps,
+            max_fps: Self::DEFAULT_MAX_FPS,
+            skip_unchanged: true,
+        }
+    }
+
+    /// Sets the upper bound on window refreshes per second.
+    ///
+    /// # Parameters
+    /// - `max_fps`: The refresh limit; `0` refreshes as fast as possible.
+    pub fn set_max_fps(&mut self, max_fps: u32) {
+        self.max_fps = max_fps;
+    }
+
+    /// Sets whether data updates that report no change skip drawing the figure.
+    ///
+    /// # Parameters
+    /// - `skip_unchanged`: `false` redraws after every update.
+    pub fn set_skip_unchanged(&mut self, skip_unchanged: bool) {
+        self.skip_unchanged = skip_unchanged;
+    }
+
+    /// Returns the time between data updates; updates at least once per second.
+    pub fn update_interval(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.update_fps.max(1) as f64)
+    }
+
+    /// Returns whether an update must be followed by drawing the figure.
+    ///
+    /// # Parameters
+    /// - `changed`: Whether the update reported changed data.
+    pub fn needs_redraw(&self, changed: bool) -> bool {
+        changed || !self.skip_unchanged
+    }
+}
@@ -1,5 +1,12 @@
-use crate::figure::canvas::pixelcanvas::PixelCanvas;
-use ab_glyph::FontRef;
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, utilities::textmetrics::load_font};
+use ab_glyph::{FontRef, PxScale};
+use image::{ImageBuffer, Rgb, RgbImage};
+use imageproc::{
+    drawing::{
+        draw_filled_rect_mut, draw_hollow_rect_mut, draw_line_segment_mut, draw_text_mut, text_size,
+    },
+    rect::Rect,
+};
 
 /// A trait for plots that support hover functionality, allowing interactive
 /// features like highlighting and displaying information about data points.
@@ -63,3 +70,69 @@ pub trait Hover {
     /// If no changes are made, returns `None`.
     fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>>;
 }
+
+/// Draws a hover tooltip over a copy of the canvas.
+///
+/// A red line connects the hovered point to the cursor, and `text` is shown in a
+/// bordered box next to the cursor, kept inside the canvas. Hover implementations
+/// can use it to build the buffer returned by `Hover::handle_hover`.
+///
+/// # Parameters
+/// - `canvas`: The `PixelCanvas` holding the rendered plot.
+/// - `font_path`: Path to the font used for the tooltip text.
+/// - `point`: The canvas coordinates of the hovered point.
+/// - `mouse_x`, `mouse_y`: The mouse position in canvas space.
+/// - `text`: The tooltip text.
+///
+/// # Returns
+/// The pixel buffer of the canvas with the tooltip, in `0RGB` format.
+pub fn render_tooltip(
+    canvas: &PixelCanvas,
+    font_path: &str,
+    point: (u32, u32),
+    (mouse_x, mouse_y): (u32, u32),
+    text: &str,
+) -> Vec<u32> {
+    let mut img: RgbImage =
+        ImageBuffer::from_raw(canvas.width, canvas.height, canvas.buffer.clone()).unwrap();
+
+    draw_line_segment_mut(
+        &mut img,
+        (point.0 as f32, point.1 as f32),
+        (mouse_x as f32, mouse_y as f32),
+        Rgb([255, 0, 0]),
+    );
+
+    let font = load_font(font_path);
+    let scale = PxScale { x: 12.0, y: 12.0 };
+    let text_width = text_size(scale, &font, text).0 as i32;
+
+    let rect_width = text_width + 20;
+    let rect_height = 25;
+    let rect_x = (mouse_x as i32 + 10)
+        .min(canvas.width as i32 - rect_width)
+        .max(0);
+    let rect_y = (mouse_y as i32 - 30)
+        .min(canvas.height as i32 - rect_height)
+        .max(0);
+
+    let rect = Rect::at(rect_x, rect_y).of_size(rect_width as u32, rect_height as u32);
+    draw_filled_rect_mut(&mut img, rect, Rgb([255, 255, 255]));
+    draw_hollow_rect_mut(&mut img, rect, Rgb([0, 0, 0]));
+    draw_text_mut(
+        &mut img,
+        Rgb([0, 0, 0]),
+        rect_x + 10,
+        rect_y + 5,
+        scale,
+        &font,
+        text,
+    );
+
+    img.pixels()
+        .map(|pixel| {
+            let [r, g, b] = pixel.0;
+            (r as u32) << 16 | (g as u32) << 8 | b as u32
+        })
+        .collect()
+}
@@ -0,0 +1,69 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas,
+    figuretypes::candlestickchart::CandlestickChart,
+    utilities::scaling::{scale_factor, to_pixel_x},
+};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for CandlestickChart {
+    /// Finds the candle closest to the mouse column, returning its closing price.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        _mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let margin = canvas.margin as f64;
+        let (x_min, x_max) = self.time_range();
+        let scale_x = scale_factor(x_min, x_max, canvas.width as f64 - 2.0 * margin);
+        let timestamp = x_min + (mouse_x as f64 - margin) / scale_x;
+
+        self.dataset
+            .candles
+            .iter()
+            .min_by(|a, b| (a.0 - timestamp).abs().total_cmp(&(b.0 - timestamp).abs()))
+            .map(|&(timestamp, _, _, _, close)| ((timestamp, close), close))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+        let margin = canvas.margin as f64;
+
+        let (x_min, x_max) = self.time_range();
+        let (y_min, y_max) = self.value_range();
+        let ((price_top, price_bottom), _) = self.panels(height, margin);
+        let scale_x = scale_factor(x_min, x_max, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, price_bottom - price_top);
+
+        (
+            to_pixel_x(x, x_min, scale_x, margin) as u32,
+            (price_bottom - (y - y_min) * scale_y) as u32,
+        )
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((x, y), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let &candle = self.dataset.candles.iter().find(|candle| candle.0 == x)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &self.price_summary(candle),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -4,6 +4,7 @@ use imageproc::drawing::{draw_line_segment_mut, draw_text_mut, text_size};
 
 use crate::figure::{
     canvas::pixelcanvas::PixelCanvas, figuretypes::cartesiangraph::CartesianGraph,
+    utilities::textmetrics::load_font,
 };
 
 use super::hover::Hover;
@@ -19,8 +20,7 @@ impl Hover for CartesianGraph {
                 .font_label
                 .as_ref()
                 .expect("Font path is not set");
-            let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-            let font = FontRef::try_from_slice(&font_bytes).unwrap();
+            let font = load_font(font_path);
             let scale = ab_glyph::PxScale { x: 12.0, y: 12.0 };
             let coord_text = format!("({x:.2}, {y:.2}) = {value:.2}");
             let text_size = text_size(scale, &font, &coord_text).0 as i32;
@@ -102,10 +102,9 @@ impl Hover for CartesianGraph {
         let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (self.x_max - self.x_min);
         let scale_y = (canvas.height - 2 * canvas.margin) as f64 / (self.y_max - self.y_min);
 
-        self.datasets
-            .iter()
+        self.visible_datasets()
             .flat_map(|dataset| {
-                dataset.points.iter().map(move |&(x, y)| {
+                dataset.transformed_points().into_iter().map(move |(x, y)| {
                     let px = canvas.margin as f64 + (x - self.x_min) * scale_x;
                     let py =
                         canvas.height as f64 - canvas.margin as f64 - (y - self.y_min) * scale_y;
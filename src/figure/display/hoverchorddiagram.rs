@@ -0,0 +1,61 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::chorddiagram::ChordDiagram};
+
+use super::hover::{render_tooltip, Hover};
+
+impl ChordDiagram {
+    /// Finds the arc or ribbon under the mouse.
+    fn hovered_element(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), String)> {
+        let geometry = Self::geometry(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        self.element_at(mouse_x as f64, mouse_y as f64, geometry, &self.layout())
+    }
+}
+
+impl Hover for ChordDiagram {
+    /// Finds the arc or ribbon under the mouse, returned as the mouse position; the value
+    /// is 0, as the tooltip carries the flows.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let (point, _) = self.hovered_element(mouse_x, mouse_y, canvas)?;
+        Some((point, 0.0))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, _canvas: &PixelCanvas) -> (u32, u32) {
+        (x as u32, y as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((x, y), tooltip) = self.hovered_element(mouse_x, mouse_y, canvas)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &tooltip,
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -0,0 +1,59 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::choroplethmap::ChoroplethMap};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for ChoroplethMap {
+    /// Finds the region under the mouse. The returned point is the mouse position in canvas
+    /// coordinates, as regions have no single representative point.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let layout = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )?;
+        let (x, y) = (mouse_x as f64 + 0.5, mouse_y as f64 + 0.5);
+        self.feature_at(x, y, layout).map(|_| ((x, y), 0.0))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, _canvas: &PixelCanvas) -> (u32, u32) {
+        (x as u32, y as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((x, y), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let layout = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )?;
+        let feature = &self.features[self.feature_at(x, y, layout)?];
+        let value = match self.value(&feature.name) {
+            Some(value) => format!("{value:.2}"),
+            None => "no data".to_string(),
+        };
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &format!("{}: {}", feature.name, value),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
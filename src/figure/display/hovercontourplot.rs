@@ -0,0 +1,69 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::contourplot::ContourPlot};
+
+use super::hover::{render_tooltip, Hover};
+
+impl ContourPlot {
+    /// Computes the plot area on a canvas.
+    fn canvas_area(&self, canvas: &PixelCanvas) -> (f64, f64, f64, f64) {
+        self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )
+    }
+}
+
+impl Hover for ContourPlot {
+    /// Samples the field under the mouse, returned as the `(column, row)` grid position and
+    /// the interpolated value.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let area = self.canvas_area(canvas);
+        let value = self.value_at(mouse_x as f64, mouse_y as f64, area);
+        if value.is_nan() {
+            return None;
+        }
+        Some((
+            self.canvas_to_grid(mouse_x as f64, mouse_y as f64, area),
+            value,
+        ))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let (px, py) = self.grid_to_canvas(x, y, self.canvas_area(canvas));
+        (px.round() as u32, py.round() as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((column, row), value) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let (x, y) = self.grid_to_data(column, row);
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(column, row, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "({}, {}): {}",
+                self.format_value(x),
+                self.format_value(y),
+                self.format_value(value)
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
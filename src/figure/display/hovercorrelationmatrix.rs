@@ -0,0 +1,71 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas, figuretypes::correlationmatrix::CorrelationMatrix,
+};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for CorrelationMatrix {
+    /// Finds the cell under the mouse, returned as the `(column, row)` of its center.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let (left, top, cell_size) = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        if cell_size <= 0.0 {
+            return None;
+        }
+
+        let col = ((mouse_x as f64 - left) / cell_size).floor();
+        let row = ((mouse_y as f64 - top) / cell_size).floor();
+        let cells = self.series.len() as f64;
+        if col < 0.0 || row < 0.0 || col >= cells || row >= cells {
+            return None;
+        }
+        Some(((col + 0.5, row + 0.5), 0.0))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let (left, top, cell_size) = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        ((left + x * cell_size) as u32, (top + y * cell_size) as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((x, y), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let (col, row) = (x as usize, y as usize);
+        let value = self.matrix()[row][col];
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "{} vs {}: {}",
+                self.series[row].0,
+                self.series[col].0,
+                self.format_value(value)
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
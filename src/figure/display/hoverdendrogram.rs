@@ -0,0 +1,71 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::dendrogram::Dendrogram};
+
+use super::hover::{render_tooltip, Hover};
+
+/// Largest distance in pixels between the mouse and a hovered node.
+const HOVER_DISTANCE: f64 = 8.0;
+
+impl Dendrogram {
+    /// Computes the plot area on a canvas.
+    fn canvas_area(&self, canvas: &PixelCanvas) -> (f64, f64, f64, f64) {
+        self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )
+    }
+
+    /// Finds the node under the mouse.
+    fn hovered_node(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<usize> {
+        self.node_at(
+            mouse_x as f64,
+            mouse_y as f64,
+            self.canvas_area(canvas),
+            HOVER_DISTANCE,
+        )
+    }
+}
+
+impl Hover for Dendrogram {
+    /// Finds the leaf or merge closest to the mouse, returned as its position along the
+    /// leaves and its distance.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let node = self.hovered_node(mouse_x, mouse_y, canvas)?;
+        let (position, distance) = self.tree.node_positions()[node];
+        Some(((position, distance), distance))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let (px, py) = self.to_canvas(x, y, self.canvas_area(canvas));
+        (px.round() as u32, py.round() as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let node = self.hovered_node(mouse_x, mouse_y, canvas)?;
+        let (position, distance) = self.tree.node_positions()[node];
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(position, distance, canvas),
+            (mouse_x, mouse_y),
+            &self.node_tooltip(node),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
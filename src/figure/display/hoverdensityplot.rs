@@ -0,0 +1,76 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas,
+    figuretypes::densityplot::DensityPlot,
+    utilities::scaling::{scale_factor, to_pixel_x, to_pixel_y},
+};
+
+use super::hover::{render_tooltip, Hover};
+
+impl DensityPlot {
+    /// Returns the scales of the value and density axes on a canvas.
+    fn hover_scales(&self, canvas: &PixelCanvas) -> (f64, f64) {
+        let margin = canvas.margin as f64;
+        let (x_min, x_max) = self.value_range();
+        (
+            scale_factor(x_min, x_max, canvas.width as f64 - 2.0 * margin),
+            scale_factor(0.0, self.density_max(), canvas.height as f64 - 2.0 * margin),
+        )
+    }
+}
+
+impl Hover for DensityPlot {
+    /// Finds the curve closest to the mouse at the value under it, returning the point on
+    /// the curve and its density.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let margin = canvas.margin as f64;
+        let (scale_x, scale_y) = self.hover_scales(canvas);
+        if scale_x == 0.0 || scale_y == 0.0 {
+            return None;
+        }
+        let value = self.value_range().0 + (mouse_x as f64 - margin) / scale_x;
+        let density = (canvas.height as f64 - margin - mouse_y as f64) / scale_y;
+        let (_, at) = self.closest_curve(value, density)?;
+        Some(((value, at), at))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let margin = canvas.margin as f64;
+        let (scale_x, scale_y) = self.hover_scales(canvas);
+        (
+            to_pixel_x(x, self.value_range().0, scale_x, margin) as u32,
+            to_pixel_y(y, 0.0, scale_y, canvas.height as f64, margin) as u32,
+        )
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((value, density), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let (index, _) = self.closest_curve(value, density)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(value, density, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "{}: density {:.4} at {:.2}",
+                self.datasets[index].label, density, value
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -0,0 +1,60 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::funnelchart::FunnelChart};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for FunnelChart {
+    /// Finds the segment under the mouse, returned as its center relative to the plot area;
+    /// the value is the stage's value.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let margin = canvas.margin as f64;
+        let segments = self.layout(
+            canvas.width as f64 - 2.0 * margin,
+            canvas.height as f64 - 2.0 * margin,
+        );
+        let segment =
+            self.segment_at(mouse_x as f64 - margin, mouse_y as f64 - margin, &segments)?;
+        Some((
+            (segment.center_x, (segment.y0 + segment.y1) / 2.0),
+            self.stages[segment.index].value,
+        ))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let margin = canvas.margin as f64;
+        ((margin + x) as u32, (margin + y) as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let margin = canvas.margin as f64;
+        let segments = self.layout(
+            canvas.width as f64 - 2.0 * margin,
+            canvas.height as f64 - 2.0 * margin,
+        );
+        let segment =
+            self.segment_at(mouse_x as f64 - margin, mouse_y as f64 - margin, &segments)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(segment.center_x, (segment.y0 + segment.y1) / 2.0, canvas),
+            (mouse_x, mouse_y),
+            &self.stage_tooltip(segment.index),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -0,0 +1,71 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::ganttchart::GanttChart};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for GanttChart {
+    /// Finds the task in the row under the mouse, returned as the middle of its time span
+    /// and its row index; the value is the task's duration in seconds.
+    fn find_closest_point(
+        &self,
+        _mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let margin = canvas.margin as f64;
+        let plot_height = canvas.height as f64 - 2.0 * margin;
+        if plot_height <= 0.0 {
+            return None;
+        }
+
+        let index = self.task_at((mouse_y as f64 - margin) / plot_height)?;
+        let task = &self.tasks[index];
+        Some((
+            ((task.start + task.end) / 2.0, index as f64),
+            task.duration(),
+        ))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let margin = canvas.margin as f64;
+        let plot_width = canvas.width as f64 - 2.0 * margin;
+        let plot_height = canvas.height as f64 - 2.0 * margin;
+        let (t_min, t_max) = self.time_range();
+        let (top, bottom) = self.bar_edges(y as usize);
+        (
+            (margin + (x - t_min) / (t_max - t_min) * plot_width) as u32,
+            (margin + (top + bottom) / 2.0 * plot_height) as u32,
+        )
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((x, y), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let task = &self.tasks[y as usize];
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        let name = match &task.group {
+            Some(group) => format!("{} ({group})", task.name),
+            None => task.name.clone(),
+        };
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "{name}: {} - {}",
+                self.format_time(task.start),
+                self.format_time(task.end)
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
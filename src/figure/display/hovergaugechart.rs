@@ -0,0 +1,61 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::gaugechart::GaugeChart};
+
+use super::hover::{render_tooltip, Hover};
+
+impl GaugeChart {
+    /// Finds the zone, track or needle under the mouse.
+    fn hovered_element(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), String)> {
+        let layout = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        self.element_at(mouse_x as f64, mouse_y as f64, &layout)
+    }
+}
+
+impl Hover for GaugeChart {
+    /// Finds the dial or needle under the mouse, returned as a point on it; the value is
+    /// the gauge's value.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let (point, _) = self.hovered_element(mouse_x, mouse_y, canvas)?;
+        Some((point, self.value))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, _canvas: &PixelCanvas) -> (u32, u32) {
+        (x as u32, y as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((x, y), tooltip) = self.hovered_element(mouse_x, mouse_y, canvas)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &tooltip,
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -0,0 +1,63 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::geoscatter::GeoScatter};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for GeoScatter {
+    /// Finds the visible point closest to the mouse, returned as `(longitude, latitude)`.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        self.datasets
+            .iter()
+            .flat_map(|dataset| dataset.points.iter().copied())
+            .filter(|&(longitude, latitude)| self.contains(longitude, latitude))
+            .map(|(longitude, latitude)| {
+                let (px, py) = self.to_canvas_coordinates(longitude, latitude, canvas);
+                let dist = (mouse_x as f64 - px as f64).hypot(mouse_y as f64 - py as f64);
+                ((longitude, latitude), dist)
+            })
+            .min_by(|&(_, d1), &(_, d2)| d1.total_cmp(&d2))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let layout = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (px, py) = self.to_canvas((x, y), layout);
+        (px.round() as u32, py.round() as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((longitude, latitude), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let label = self
+            .datasets
+            .iter()
+            .find(|dataset| dataset.points.contains(&(longitude, latitude)))
+            .map(|dataset| dataset.label.as_str())
+            .unwrap_or_default();
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(longitude, latitude, canvas),
+            (mouse_x, mouse_y),
+            &format!("{label}: {latitude:.4}°, {longitude:.4}°"),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
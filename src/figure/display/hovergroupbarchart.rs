@@ -2,7 +2,10 @@ use ab_glyph::FontRef;
 use image::ImageBuffer;
 use imageproc::drawing::{draw_line_segment_mut, draw_text_mut, text_size};
 
-use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::groupbarchart::GroupBarChart};
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas, figuretypes::groupbarchart::GroupBarChart,
+    utilities::textmetrics::load_font,
+};
 
 use super::hover::Hover;
 
@@ -26,9 +29,10 @@ impl Hover for GroupBarChart {
 
                 // Collect values for this group
                 let values = self
-                    .datasets
-                    .iter()
-                    .filter_map(|dataset| dataset.data.get(group_index).map(|&(_, value)| value))
+                    .visible_datasets()
+                    .filter_map(|(_, dataset)| {
+                        dataset.data.get(group_index).map(|&(_, value)| value)
+                    })
                     .collect::<Vec<f64>>();
 
                 closest_bar_group = Some((group_center_x, values));
@@ -56,8 +60,7 @@ impl Hover for GroupBarChart {
                 .font_label
                 .as_ref()
                 .expect("Font path is not set");
-            let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-            let font = FontRef::try_from_slice(&font_bytes).unwrap();
+            let font = load_font(font_path);
             let scale = ab_glyph::PxScale { x: 12.0, y: 12.0 };
             let text_size = text_size(scale, &font, &tooltip_text).0 as i32;
 
@@ -101,7 +104,10 @@ impl Hover for GroupBarChart {
                 if dataset_index == 0 {
                     tooltip_text.push_str(&format!("{value:.2}"));
                 } else {
-                    let dataset_label = &self.datasets[dataset_index].label;
+                    let dataset_label = self
+                        .visible_datasets()
+                        .nth(dataset_index)
+                        .map_or("", |(_, dataset)| dataset.label.as_str());
                     tooltip_text = format!("{dataset_label} : {value:.2}");
                 }
 
@@ -144,7 +150,7 @@ impl Hover for GroupBarChart {
         let mut closest_bar = None;
         let mut min_distance = f64::MAX;
 
-        for dataset in &self.datasets {
+        for (_, dataset) in self.visible_datasets() {
             for &(x, y) in &dataset.data {
                 let px = (x) * scale_x + canvas.margin as f64;
                 let py = canvas.height as f64 - canvas.margin as f64 - y * scale_y;
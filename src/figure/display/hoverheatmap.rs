@@ -0,0 +1,87 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::heatmap::Heatmap};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Heatmap {
+    /// Finds the cell under the mouse.
+    ///
+    /// # Returns
+    /// The `(column, row)` of the cell's center in the grid as drawn, and its
+    /// `(row, column)` indices into `values`.
+    fn hovered_cell(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), (usize, usize))> {
+        let area = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (grid_row, grid_column) = self.grid_cell_at(mouse_x as f64, mouse_y as f64, area)?;
+        let (rows, columns) = self.cell_order();
+        let (row, column) = (*rows.get(grid_row)?, *columns.get(grid_column)?);
+        self.values.get(row)?.get(column)?;
+        Some((
+            (grid_column as f64 + 0.5, grid_row as f64 + 0.5),
+            (row, column),
+        ))
+    }
+}
+
+impl Hover for Heatmap {
+    /// Finds the cell under the mouse, returned as the `(column, row)` of its center in the
+    /// grid as drawn.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let (point, (row, column)) = self.hovered_cell(mouse_x, mouse_y, canvas)?;
+        Some((point, self.values[row][column]))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let (left, top, right, bottom) = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let cell_width = (right - left) / self.num_columns().max(1) as f64;
+        let cell_height = (bottom - top) / self.num_rows().max(1) as f64;
+        (
+            (left + x * cell_width) as u32,
+            (top + y * cell_height) as u32,
+        )
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((x, y), (row, column)) = self.hovered_cell(mouse_x, mouse_y, canvas)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "{}, {}: {}",
+                self.row_label(row),
+                self.column_label(column),
+                self.format_value(self.values[row][column])
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
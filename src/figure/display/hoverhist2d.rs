@@ -0,0 +1,70 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas, figuretypes::hist2d::Hist2D, utilities::axistype::AxisType,
+};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hist2D {
+    /// Computes the plot area on a canvas.
+    fn canvas_area(&self, canvas: &PixelCanvas) -> (f64, f64, f64, f64) {
+        self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )
+    }
+}
+
+impl Hover for Hist2D {
+    /// Finds the bin under the mouse, returning its center and count.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let (x, y) = self.to_data(
+            mouse_x as f64,
+            mouse_y as f64,
+            self.ranges(),
+            self.canvas_area(canvas),
+        );
+        let cell = self.cell_at(x, y)?;
+        Some((cell.center, cell.count))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let (px, py) = self.to_canvas(x, y, self.ranges(), self.canvas_area(canvas));
+        (px.round() as u32, py.round() as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((x, y), count) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "({}, {}): {}",
+                self.config
+                    .localize_value(AxisType::AxisX, &format!("{x:.2}")),
+                self.config
+                    .localize_value(AxisType::AxisY, &format!("{y:.2}")),
+                self.format_count(count)
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -2,11 +2,16 @@ use ab_glyph::FontRef;
 use image::ImageBuffer;
 use imageproc::drawing::{draw_line_segment_mut, draw_text_mut, text_size};
 
-use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::histogram::Histogram};
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas, figuretypes::histogram::Histogram,
+    utilities::textmetrics::load_font,
+};
 
 use super::hover::Hover;
 
 impl Hover for Histogram {
+    /// Finds the bin closest to the mouse, returned as its range and bar height. The
+    /// underflow and overflow bins have an infinite start and end respectively.
     fn find_closest_point(
         &self,
         mouse_x: u32,
@@ -16,14 +21,28 @@ impl Hover for Histogram {
         let bin_data = self.calculate_bins();
 
         let bin_width = (bin_data[1].0 - bin_data[0].0).abs();
-        let scale_x = (canvas.width - 2 * canvas.margin) as f64 / self.bins as f64;
+        let edge_slots = usize::from(self.shows_overflow_bins());
+        let scale_x =
+            (canvas.width - 2 * canvas.margin) as f64 / (self.bins + 2 * edge_slots) as f64;
+
+        // Bins as (slot, range, height), the underflow and overflow bins in the outer slots
+        let mut bins: Vec<(usize, (f64, f64), f64)> = bin_data
+            .iter()
+            .enumerate()
+            .map(|(i, &(bin_start, freq))| {
+                (i + edge_slots, (bin_start, bin_start + bin_width), freq)
+            })
+            .collect();
+        if let Some([(_, under), (_, over)]) = self.calculate_overflow_bins().first().copied() {
+            bins.push((0, (f64::NEG_INFINITY, self.min), under));
+            bins.push((self.bins + 1, (self.max, f64::INFINITY), over));
+        }
 
         let mut closest_bin = None;
         let mut min_distance = f64::MAX;
 
-        for (i, &(bin_start, freq)) in bin_data.iter().enumerate() {
-            let bin_end = bin_start + bin_width; // End of the bin
-            let bin_x = canvas.margin as f64 + i as f64 * scale_x;
+        for (slot, (bin_start, bin_end), freq) in bins {
+            let bin_x = canvas.margin as f64 + slot as f64 * scale_x;
 
             let distance = (mouse_x as f64 - bin_x).abs(); // Distance to mouse x
             if distance < min_distance {
@@ -36,14 +55,10 @@ impl Hover for Histogram {
     }
 
     fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
-        let bin_data = self.calculate_bins();
-        let bin_width = (bin_data[1].0 - bin_data[0].0).abs();
-        let x_min = bin_data[0].0; // Start of the first bin
-        let x_max = x_min + bin_width * self.bins as f64;
+        let (x_min, x_max) = self.x_range();
 
         let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (x_max - x_min);
-        let scale_y = (canvas.height - 2 * canvas.margin) as f64
-            / bin_data.iter().map(|(_, f)| *f).fold(0.0, f64::max);
+        let scale_y = (canvas.height - 2 * canvas.margin) as f64 / self.y_max();
 
         let px = ((x - x_min) * scale_x + canvas.margin as f64) as u32;
         let py = (canvas.height as f64 - canvas.margin as f64 - y * scale_y) as u32;
@@ -69,8 +84,7 @@ impl Hover for Histogram {
                 .font_label
                 .as_ref()
                 .expect("Font path is not set");
-            let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-            let font = FontRef::try_from_slice(&font_bytes).unwrap();
+            let font = load_font(font_path);
             let scale = ab_glyph::PxScale { x: 12.0, y: 12.0 };
             let text_size = text_size(scale, &font, &bin_info).0 as i32;
 
@@ -127,9 +141,14 @@ impl Hover for Histogram {
             );
 
             // Draw a line from the bin's top point to the mouse location
-            if let Some((bin_px, bin_py)) = self
-                .to_canvas_coordinates((bin_start + bin_end) / 2.0, freq, canvas)
-                .into()
+            let center = if bin_start.is_infinite() {
+                bin_end - self.bin_width / 2.0
+            } else if bin_end.is_infinite() {
+                bin_start + self.bin_width / 2.0
+            } else {
+                (bin_start + bin_end) / 2.0
+            };
+            if let Some((bin_px, bin_py)) = self.to_canvas_coordinates(center, freq, canvas).into()
             {
                 draw_line_segment_mut(
                     &mut img,
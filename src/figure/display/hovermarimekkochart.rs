@@ -0,0 +1,67 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas, figuretypes::marimekkochart::MarimekkoChart,
+};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for MarimekkoChart {
+    /// Finds the cell under the mouse, returned as its center in plot area fractions.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let margin = canvas.margin as f64;
+        let plot_width = canvas.width as f64 - 2.0 * margin;
+        let plot_height = canvas.height as f64 - 2.0 * margin;
+        if plot_width <= 0.0 || plot_height <= 0.0 {
+            return None;
+        }
+
+        let x = (mouse_x as f64 - margin) / plot_width;
+        let y = (canvas.height as f64 - margin - mouse_y as f64) / plot_height;
+        let cell = self.cell_at(x, y)?;
+        Some((((cell.x0 + cell.x1) / 2.0, (cell.y0 + cell.y1) / 2.0), 0.0))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let margin = canvas.margin as f64;
+        let plot_width = canvas.width as f64 - 2.0 * margin;
+        let plot_height = canvas.height as f64 - 2.0 * margin;
+        (
+            (margin + x * plot_width) as u32,
+            (canvas.height as f64 - margin - y * plot_height) as u32,
+        )
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((x, y), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let cell = self.cell_at(x, y)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "{} / {}: {:.2} ({})",
+                self.categories[cell.category],
+                self.datasets[cell.segment].label,
+                cell.value,
+                self.cell_label(&cell)
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
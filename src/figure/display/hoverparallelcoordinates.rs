@@ -0,0 +1,73 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas, figuretypes::parallelcoordinates::ParallelCoordinates,
+};
+
+use super::hover::{render_tooltip, Hover};
+
+/// Largest distance in pixels between the mouse and a hovered line.
+const HOVER_DISTANCE: f64 = 4.0;
+
+impl ParallelCoordinates {
+    /// Computes the plot area on a canvas.
+    fn canvas_area(&self, canvas: &PixelCanvas) -> (f64, f64, f64, f64) {
+        self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )
+    }
+}
+
+impl Hover for ParallelCoordinates {
+    /// Finds the line under the mouse, returned as the axis index and value of its vertex
+    /// closest to the mouse.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let (record, axis) = self.record_at(
+            mouse_x as f64,
+            mouse_y as f64,
+            self.canvas_area(canvas),
+            HOVER_DISTANCE,
+        )?;
+        let value = self.records[record].values[axis];
+        Some(((axis as f64, value), value))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let (px, py) = self.to_canvas(x as usize, y, self.canvas_area(canvas));
+        (px.round() as u32, py.round() as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let (record, axis) = self.record_at(
+            mouse_x as f64,
+            mouse_y as f64,
+            self.canvas_area(canvas),
+            HOVER_DISTANCE,
+        )?;
+        let value = self.records[record].values[axis];
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(axis as f64, value, canvas),
+            (mouse_x, mouse_y),
+            &self.record_tooltip(record),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -2,7 +2,11 @@ use ab_glyph::FontRef;
 use image::ImageBuffer;
 use imageproc::drawing::{draw_line_segment_mut, draw_text_mut, text_size};
 
-use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::piechart::PieChart};
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas,
+    figuretypes::piechart::PieChart,
+    utilities::{arcgeometry::angle_of, textmetrics::load_font},
+};
 
 use super::hover::Hover;
 
@@ -13,19 +17,22 @@ impl Hover for PieChart {
         mouse_y: u32,
         canvas: &PixelCanvas,
     ) -> Option<((f64, f64), f64)> {
-        let center_x = canvas.width as f64 / 2.0;
-        let center_y = canvas.height as f64 / 2.0;
-        let total_value: f64 = self.datasets.iter().map(|(_, value, _)| *value).sum();
+        let layout = self.pixel_layout(canvas);
+        let (center_x, center_y) = layout.center;
         let mut start_angle = 0.0;
 
         let dx = mouse_x as f64 - center_x;
         let dy = mouse_y as f64 - center_y;
-        let mut angle = dy.atan2(dx);
-        if angle < 0.0 {
-            angle += 2.0 * std::f64::consts::PI;
+        if layout.radius <= 0.0 {
+            return None;
         }
+        // None in the hollow center of a donut chart
+        let ring = self.ring_at(dx.hypot(dy) / layout.radius)?;
+        let slices = self.ring_slices(ring);
+        let total_value: f64 = slices.iter().map(|(_, value, _)| *value).sum();
+        let angle = angle_of(dx, dy);
 
-        for (_, value, _) in &self.datasets {
+        for (_, value, _) in &slices {
             let sweep_angle = (value / total_value) * 2.0 * std::f64::consts::PI;
             let mid_angle = start_angle + sweep_angle / 2.0;
 
@@ -63,8 +70,7 @@ impl Hover for PieChart {
                 .font_label
                 .as_ref()
                 .expect("Font path is not set");
-            let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-            let font = FontRef::try_from_slice(&font_bytes).unwrap();
+            let font = load_font(font_path);
 
             let scale = ab_glyph::PxScale { x: 12.0, y: 12.0 };
             let coord_text = format!("{}: {:.2}", self.title, value);
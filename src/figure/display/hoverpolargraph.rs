@@ -0,0 +1,60 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::polargraph::PolarGraph};
+
+use super::hover::{render_tooltip, Hover};
+
+impl PolarGraph {
+    /// Computes the layout of the graph on a canvas.
+    fn canvas_layout(&self, canvas: &PixelCanvas) -> (f64, f64, f64) {
+        self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )
+    }
+}
+
+impl Hover for PolarGraph {
+    /// Finds the point under the mouse, returned as its `(theta, r)` pair and radius.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let layout = self.canvas_layout(canvas);
+        let (dataset, point) = self.point_at(mouse_x as f64, mouse_y as f64, layout)?;
+        let (theta, r) = self.datasets[dataset].points[point];
+        Some(((theta, r), r))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let (px, py) = self.point_position(x, y, self.canvas_layout(canvas));
+        (px.round() as u32, py.round() as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let layout = self.canvas_layout(canvas);
+        let (dataset, point) = self.point_at(mouse_x as f64, mouse_y as f64, layout)?;
+        let dataset = &self.datasets[dataset];
+        let (theta, r) = dataset.points[point];
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(theta, r, canvas),
+            (mouse_x, mouse_y),
+            &self.point_tooltip(dataset, theta, r),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -4,6 +4,7 @@ use imageproc::drawing::{draw_line_segment_mut, draw_text_mut, text_size};
 
 use crate::figure::{
     canvas::pixelcanvas::PixelCanvas, figuretypes::quadrant1graph::Quadrant1Graph,
+    utilities::textmetrics::load_font,
 };
 
 use super::hover::Hover;
@@ -37,6 +38,12 @@ impl Hover for Quadrant1Graph {
         let y_min = y_min.min(0.0);
 
         // Calculate scales
+        let ((x_min, x_max), (y_min, y_max)) = self.config.aspect_ranges(
+            (x_min, x_max),
+            (y_min, y_max),
+            (canvas.width - 2 * canvas.margin) as f64,
+            (canvas.height - 2 * canvas.margin) as f64,
+        );
         let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (x_max - x_min);
         let scale_y = (canvas.height - 2 * canvas.margin) as f64 / (y_max - y_min);
 
@@ -78,6 +85,12 @@ impl Hover for Quadrant1Graph {
         let y_min = y_min.min(0.0);
 
         // Calculate scales
+        let ((x_min, x_max), (y_min, y_max)) = self.config.aspect_ranges(
+            (x_min, x_max),
+            (y_min, y_max),
+            (canvas.width - 2 * canvas.margin) as f64,
+            (canvas.height - 2 * canvas.margin) as f64,
+        );
         let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (x_max - x_min);
         let scale_y = (canvas.height - 2 * canvas.margin) as f64 / (y_max - y_min);
 
@@ -108,8 +121,7 @@ impl Hover for Quadrant1Graph {
                 .font_label
                 .as_ref()
                 .expect("Font path is not set");
-            let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-            let font = FontRef::try_from_slice(&font_bytes).unwrap();
+            let font = load_font(font_path);
             let scale = ab_glyph::PxScale { x: 12.0, y: 12.0 };
             let coord_text = format!("({x:.2}, {y:.2})");
             let text_size = text_size(scale, &font, &coord_text).0 as i32;
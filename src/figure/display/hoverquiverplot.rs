@@ -0,0 +1,80 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas, figuretypes::quiverplot::QuiverPlot,
+    utilities::axistype::AxisType,
+};
+
+use super::hover::{render_tooltip, Hover};
+
+/// Largest distance in pixels between the mouse and the tail of a hovered arrow.
+const HOVER_DISTANCE: f64 = 10.0;
+
+impl QuiverPlot {
+    /// Computes the plot area on a canvas.
+    fn canvas_area(&self, canvas: &PixelCanvas) -> (f64, f64, f64, f64) {
+        self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )
+    }
+}
+
+impl Hover for QuiverPlot {
+    /// Finds the arrow whose tail is closest to the mouse, returning its tail and
+    /// magnitude.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let (vector, _) = self.closest_vector(
+            mouse_x as f64,
+            mouse_y as f64,
+            self.canvas_area(canvas),
+            HOVER_DISTANCE,
+        )?;
+        Some(((vector.x, vector.y), vector.magnitude()))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let (px, py) = self.to_canvas(x, y, self.ranges(), self.canvas_area(canvas));
+        (px.round() as u32, py.round() as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let (vector, (px, py)) = self.closest_vector(
+            mouse_x as f64,
+            mouse_y as f64,
+            self.canvas_area(canvas),
+            HOVER_DISTANCE,
+        )?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            (px.round() as u32, py.round() as u32),
+            (mouse_x, mouse_y),
+            &format!(
+                "({}, {}): {}, {}",
+                self.config
+                    .localize_value(AxisType::AxisX, &format!("{:.2}", vector.x)),
+                self.config
+                    .localize_value(AxisType::AxisY, &format!("{:.2}", vector.y)),
+                self.format_value(vector.u),
+                self.format_value(vector.v)
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -0,0 +1,65 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::radarchart::RadarChart};
+
+use super::hover::{render_tooltip, Hover};
+
+impl RadarChart {
+    /// Computes the layout of the chart on a canvas.
+    fn canvas_layout(&self, canvas: &PixelCanvas) -> (f64, f64, f64) {
+        self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )
+    }
+}
+
+impl Hover for RadarChart {
+    /// Finds the vertex under the mouse, returned as its axis index and value.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let layout = self.canvas_layout(canvas);
+        let (dataset, axis) = self.vertex_at(mouse_x as f64, mouse_y as f64, layout)?;
+        let value = self.datasets[dataset].value(axis);
+        Some(((axis as f64, value), value))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let (px, py) = self.value_position(x as usize, y, self.canvas_layout(canvas));
+        (px.round() as u32, py.round() as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let layout = self.canvas_layout(canvas);
+        let (dataset, axis) = self.vertex_at(mouse_x as f64, mouse_y as f64, layout)?;
+        let dataset = &self.datasets[dataset];
+        let value = dataset.value(axis);
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(axis as f64, value, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "{}, {}: {}",
+                dataset.label,
+                self.axes[axis],
+                self.format_value(value)
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
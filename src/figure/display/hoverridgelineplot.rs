@@ -0,0 +1,84 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas,
+    figuretypes::ridgelineplot::RidgelinePlot,
+    utilities::scaling::{scale_factor, to_pixel_x},
+};
+
+use super::hover::{render_tooltip, Hover};
+
+impl RidgelinePlot {
+    /// Returns the scale of the value axis on a canvas.
+    fn hover_scale(&self, canvas: &PixelCanvas) -> f64 {
+        let (x_min, x_max) = self.value_range();
+        scale_factor(
+            x_min,
+            x_max,
+            canvas.width as f64 - 2.0 * canvas.margin as f64,
+        )
+    }
+}
+
+impl Hover for RidgelinePlot {
+    /// Finds the ridge under the mouse, returned as the value under the mouse and the index
+    /// of the ridge's row, with its density at that value.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let scale_x = self.hover_scale(canvas);
+        if scale_x == 0.0 {
+            return None;
+        }
+        let value = self.value_range().0 + (mouse_x as f64 - canvas.margin as f64) / scale_x;
+        let (index, density) = self.ridge_at(
+            value,
+            mouse_y as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )?;
+        Some(((value, index as f64), density))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let index = y as usize;
+        let density = self
+            .datasets
+            .get(index)
+            .and_then(|dataset| dataset.density_at(x))
+            .unwrap_or(0.0);
+        let height = canvas.height as f64;
+        let margin = canvas.margin as f64;
+        (
+            to_pixel_x(x, self.value_range().0, self.hover_scale(canvas), margin) as u32,
+            self.to_ridge_y(index, density, self.density_max(), height, margin) as u32,
+        )
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((value, index), density) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(value, index, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "{}: density {:.4} at {:.2}",
+                self.datasets[index as usize].label, density, value
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -0,0 +1,57 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::sankeydiagram::SankeyDiagram};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for SankeyDiagram {
+    /// Finds the bar or ribbon under the mouse, returned as a point on it relative to the
+    /// plot area; the value is 0, as the tooltip carries the flow.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let margin = canvas.margin as f64;
+        let layout = self.layout(
+            canvas.width as f64 - 2.0 * margin,
+            canvas.height as f64 - 2.0 * margin,
+        );
+        let (point, _) =
+            self.element_at(mouse_x as f64 - margin, mouse_y as f64 - margin, &layout)?;
+        Some((point, 0.0))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let margin = canvas.margin as f64;
+        ((margin + x) as u32, (margin + y) as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let margin = canvas.margin as f64;
+        let layout = self.layout(
+            canvas.width as f64 - 2.0 * margin,
+            canvas.height as f64 - 2.0 * margin,
+        );
+        let ((x, y), tooltip) =
+            self.element_at(mouse_x as f64 - margin, mouse_y as f64 - margin, &layout)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &tooltip,
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
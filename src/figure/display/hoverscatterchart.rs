@@ -2,7 +2,10 @@ use ab_glyph::FontRef;
 use image::ImageBuffer;
 use imageproc::drawing::{draw_line_segment_mut, draw_text_mut, text_size};
 
-use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::scattergraph::ScatterGraph};
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas, figuretypes::scattergraph::ScatterGraph,
+    utilities::textmetrics::load_font,
+};
 
 use super::hover::Hover;
 
@@ -14,40 +17,38 @@ impl Hover for ScatterGraph {
         canvas: &PixelCanvas,
     ) -> Option<((f64, f64), f64)> {
         // Calculate dataset limits
-        let (x_min, x_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
-                (min.min(x), max.max(x))
-            });
-
-        let (y_min, y_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
-                (min.min(y), max.max(y))
-            });
+        let ((x_min, x_max), (y_min, y_max)) = self.data_range();
 
         // Adjust limits to include (0, 0)
         let x_min = x_min.min(0.0);
         let y_min = y_min.min(0.0);
 
         // Calculate scales
+        let ((x_min, x_max), (y_min, y_max)) = self.config.aspect_ranges(
+            (x_min, x_max),
+            (y_min, y_max),
+            (canvas.width - 2 * canvas.margin) as f64,
+            (canvas.height - 2 * canvas.margin) as f64,
+        );
         let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (x_max - x_min);
         let scale_y = (canvas.height - 2 * canvas.margin) as f64 / (y_max - y_min);
 
-        self.datasets
-            .iter()
+        self.visible_datasets()
             .flat_map(|dataset| {
-                dataset.points.iter().map(|&(x, y)| {
-                    let px = canvas.margin as f64 + (x - x_min) * scale_x;
-                    let py = canvas.height as f64 - canvas.margin as f64 - (y - y_min) * scale_y;
-                    let dist =
-                        ((mouse_x as f64 - px).powi(2) + (mouse_y as f64 - py).powi(2)).sqrt();
-                    ((x, y), dist)
-                })
+                let to_pixel = |x: f64, y: f64| {
+                    (
+                        canvas.margin as f64 + (x - x_min) * scale_x,
+                        canvas.height as f64 - canvas.margin as f64 - (y - y_min) * scale_y,
+                    )
+                };
+                self.visible_points(dataset, to_pixel)
+                    .into_iter()
+                    .map(move |(x, y)| {
+                        let (px, py) = to_pixel(x, y);
+                        let dist =
+                            ((mouse_x as f64 - px).powi(2) + (mouse_y as f64 - py).powi(2)).sqrt();
+                        ((x, y), dist)
+                    })
             })
             .min_by(|&(_, d1), &(_, d2)| d1.partial_cmp(&d2).unwrap())
     }
@@ -55,27 +56,19 @@ impl Hover for ScatterGraph {
     fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
         // todo!(); add max min values to scatter graph
         // Calculate dataset limits
-        let (x_min, x_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
-                (min.min(x), max.max(x))
-            });
-
-        let (y_min, y_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
-                (min.min(y), max.max(y))
-            });
+        let ((x_min, x_max), (y_min, y_max)) = self.data_range();
 
         // Adjust limits to include (0, 0)
         let x_min = x_min.min(0.0);
         let y_min = y_min.min(0.0);
 
         // Calculate scales
+        let ((x_min, x_max), (y_min, y_max)) = self.config.aspect_ranges(
+            (x_min, x_max),
+            (y_min, y_max),
+            (canvas.width - 2 * canvas.margin) as f64,
+            (canvas.height - 2 * canvas.margin) as f64,
+        );
         let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (x_max - x_min);
         let scale_y = (canvas.height - 2 * canvas.margin) as f64 / (y_max - y_min);
 
@@ -106,8 +99,7 @@ impl Hover for ScatterGraph {
                 .font_label
                 .as_ref()
                 .expect("Font path is not set");
-            let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-            let font = FontRef::try_from_slice(&font_bytes).unwrap();
+            let font = load_font(font_path);
             let scale = ab_glyph::PxScale { x: 12.0, y: 12.0 };
             let coord_text = format!("({x:.2}, {y:.2})");
             let text_size = text_size(scale, &font, &coord_text).0 as i32;
@@ -0,0 +1,65 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::spectrogram::Spectrogram};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Spectrogram {
+    /// Computes the plot area on a canvas.
+    fn canvas_area(&self, canvas: &PixelCanvas) -> (f64, f64, f64, f64) {
+        self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )
+    }
+}
+
+impl Hover for Spectrogram {
+    /// Finds the cell under the mouse, returned as the `(time, frequency)` of its center.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let area = self.canvas_area(canvas);
+        let (column, row) = self.cell_at(mouse_x as f64, mouse_y as f64, area)?;
+        let time = (column as f64 + 0.5) * self.time_step;
+        Some(((time, row as f64 * self.frequency_step), 0.0))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let (left, top, right, bottom) = self.canvas_area(canvas);
+        let (t0, t1) = self.time_extent();
+        let (f0, f1) = self.frequency_extent();
+        (
+            (left + (x - t0) / (t1 - t0).max(f64::EPSILON) * (right - left)) as u32,
+            (bottom - (y - f0) / (f1 - f0).max(f64::EPSILON) * (bottom - top)) as u32,
+        )
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((time, frequency), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let (column, row) =
+            self.cell_at(mouse_x as f64, mouse_y as f64, self.canvas_area(canvas))?;
+        let level = self.levels()[column][row];
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(time, frequency, canvas),
+            (mouse_x, mouse_y),
+            &format!("{time:.3} s, {frequency:.1} Hz: {level:.1} dB"),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
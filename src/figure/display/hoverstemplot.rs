@@ -0,0 +1,29 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::stemplot::StemPlot};
+
+use super::hover::Hover;
+
+/// Stem tops are the points of the underlying `CartesianGraph`, so hovering is delegated to it.
+impl Hover for StemPlot {
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        self.graph.handle_hover(mouse_x, mouse_y, canvas)
+    }
+
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        self.graph.find_closest_point(mouse_x, mouse_y, canvas)
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        self.graph.to_canvas_coordinates(x, y, canvas)
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        self.graph.get_font(font_data)
+    }
+}
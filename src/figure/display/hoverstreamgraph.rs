@@ -0,0 +1,100 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas,
+    figuretypes::streamgraph::Streamgraph,
+    utilities::{
+        axistype::AxisType,
+        scaling::{scale_factor, to_pixel_x, to_pixel_y},
+    },
+};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Streamgraph {
+    /// Returns the ranges and scales of the axes on a canvas.
+    fn hover_scales(&self, canvas: &PixelCanvas) -> ((f64, f64), f64, f64) {
+        let margin = canvas.margin as f64;
+        let ((x_min, x_max), (y_min, y_max)) = self.ranges(&self.layers());
+        (
+            (x_min, y_min),
+            scale_factor(x_min, x_max, canvas.width as f64 - 2.0 * margin),
+            scale_factor(y_min, y_max, canvas.height as f64 - 2.0 * margin),
+        )
+    }
+
+    /// Finds the series under the mouse and the closest x value.
+    ///
+    /// # Returns
+    /// The series index, the x value index, and the middle of the layer at the mouse in
+    /// data coordinates.
+    fn hovered(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<(usize, usize, (f64, f64))> {
+        let margin = canvas.margin as f64;
+        let ((x_min, y_min), scale_x, scale_y) = self.hover_scales(canvas);
+        if scale_x == 0.0 || scale_y == 0.0 {
+            return None;
+        }
+        let x = x_min + (mouse_x as f64 - margin) / scale_x;
+        let y = y_min + (canvas.height as f64 - margin - mouse_y as f64) / scale_y;
+        let (series, bottom, top) = self.series_at(&self.layers(), x, y)?;
+        let index = self.closest_index(x)?;
+        Some((series, index, (x, (bottom + top) / 2.0)))
+    }
+}
+
+impl Hover for Streamgraph {
+    /// Finds the layer under the mouse, returning the middle of the layer and the value of
+    /// its series at the closest x value.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let (series, index, point) = self.hovered(mouse_x, mouse_y, canvas)?;
+        Some((point, self.value(series, index)))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let margin = canvas.margin as f64;
+        let ((x_min, y_min), scale_x, scale_y) = self.hover_scales(canvas);
+        (
+            to_pixel_x(x, x_min, scale_x, margin) as u32,
+            to_pixel_y(y, y_min, scale_y, canvas.height as f64, margin) as u32,
+        )
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let (series, index, (x, y)) = self.hovered(mouse_x, mouse_y, canvas)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "{} ({}): {}",
+                self.series[series].label,
+                self.config
+                    .localize_value(AxisType::AxisX, &format!("{:.2}", self.x_values[index])),
+                self.config
+                    .format
+                    .localize(&self.value(series, index).to_string())
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -0,0 +1,59 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::sunburstchart::SunburstChart};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for SunburstChart {
+    /// Finds the segment under the mouse, returned as the middle of its arc in canvas
+    /// space; the value is the segment's total.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let segment = self.segment_at(mouse_x as f64, mouse_y as f64, canvas)?;
+        let ((center_x, center_y), radius) = Self::layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (inner, outer) = self.ring_radii(segment.depth());
+        let mid_radius = (inner + outer) / 2.0 * radius;
+        let angle = segment.mid_angle();
+        Some((
+            (
+                center_x + mid_radius * angle.cos(),
+                center_y - mid_radius * angle.sin(),
+            ),
+            segment.value,
+        ))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, _canvas: &PixelCanvas) -> (u32, u32) {
+        (x as u32, y as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let segment = self.segment_at(mouse_x as f64, mouse_y as f64, canvas)?;
+        let ((x, y), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &self.segment_tooltip(&segment),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
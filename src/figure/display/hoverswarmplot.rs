@@ -0,0 +1,87 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas,
+    figuretypes::swarmplot::SwarmPlot,
+    utilities::scaling::{scale_factor, to_pixel_x, to_pixel_y},
+};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for SwarmPlot {
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let (scale_x, scale_y) = self.scales(canvas);
+
+        // Points are reported in category units, with the packing offset folded into `x`
+        self.swarm_points(scale_y)
+            .into_iter()
+            .enumerate()
+            .flat_map(|(index, points)| {
+                points
+                    .into_iter()
+                    .map(move |(offset, y)| (index as f64 + 0.5 + offset / scale_x, y))
+            })
+            .map(|(x, y)| {
+                let (px, py) = self.to_canvas_coordinates(x, y, canvas);
+                let dist = (mouse_x as f64 - px as f64).hypot(mouse_y as f64 - py as f64);
+                ((x, y), dist)
+            })
+            .min_by(|&(_, d1), &(_, d2)| d1.total_cmp(&d2))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let height = canvas.height as f64;
+        let margin = canvas.margin as f64;
+        let (y_min, _) = self.value_range();
+        let (scale_x, scale_y) = self.scales(canvas);
+
+        (
+            to_pixel_x(x, 0.0, scale_x, margin) as u32,
+            to_pixel_y(y, y_min, scale_y, height, margin) as u32,
+        )
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((x, y), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let index = (x.max(0.0) as usize).min(self.datasets.len() - 1);
+        let category = &self.datasets[index].label;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &format!("{category}: {y:.2}"),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
+
+impl SwarmPlot {
+    /// Computes the horizontal (per category) and vertical (per data unit) scales for a canvas.
+    fn scales(&self, canvas: &PixelCanvas) -> (f64, f64) {
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+        let margin = canvas.margin as f64;
+        let (y_min, y_max) = self.value_range();
+        let categories = self.datasets.len().max(1) as f64;
+
+        (
+            scale_factor(0.0, categories, width - 2.0 * margin),
+            scale_factor(y_min, y_max, height - 2.0 * margin),
+        )
+    }
+}
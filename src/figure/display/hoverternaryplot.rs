@@ -0,0 +1,67 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::ternaryplot::TernaryPlot};
+
+use super::hover::{render_tooltip, Hover};
+
+impl TernaryPlot {
+    /// Computes the layout of the triangle on a canvas.
+    fn canvas_layout(&self, canvas: &PixelCanvas) -> (f64, f64, f64) {
+        self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )
+    }
+}
+
+impl Hover for TernaryPlot {
+    /// Finds the point closest to the mouse, returned as its canvas position.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let layout = self.canvas_layout(canvas);
+        let (_, point, dist) = self.closest_point(mouse_x as f64, mouse_y as f64, layout)?;
+        Some((self.to_canvas(point, layout), dist))
+    }
+
+    /// Rounds a canvas position; ternary points are already converted by `find_closest_point`.
+    fn to_canvas_coordinates(&self, x: f64, y: f64, _canvas: &PixelCanvas) -> (u32, u32) {
+        (x.round() as u32, y.round() as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let layout = self.canvas_layout(canvas);
+        let (index, point, _) = self.closest_point(mouse_x as f64, mouse_y as f64, layout)?;
+        let (x, y) = self.to_canvas(point, layout);
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "{}: {} {:.2}, {} {:.2}, {} {:.2}",
+                self.datasets[index].label,
+                self.a_label,
+                point[0],
+                self.b_label,
+                point[1],
+                self.c_label,
+                point[2]
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
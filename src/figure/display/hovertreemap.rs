@@ -0,0 +1,64 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::treemap::Treemap};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for Treemap {
+    /// Finds the innermost cell under the mouse, returned as its center relative to the
+    /// plot area; the value is the cell's total.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let margin = canvas.margin as f64;
+        let cell = self.cell_at(
+            mouse_x as f64 - margin,
+            mouse_y as f64 - margin,
+            canvas.width as f64 - 2.0 * margin,
+            canvas.height as f64 - 2.0 * margin,
+        )?;
+        Some((
+            ((cell.x0 + cell.x1) / 2.0, (cell.y0 + cell.y1) / 2.0),
+            cell.value,
+        ))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let margin = canvas.margin as f64;
+        ((margin + x) as u32, (margin + y) as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let margin = canvas.margin as f64;
+        let cell = self.cell_at(
+            mouse_x as f64 - margin,
+            mouse_y as f64 - margin,
+            canvas.width as f64 - 2.0 * margin,
+            canvas.height as f64 - 2.0 * margin,
+        )?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(
+                (cell.x0 + cell.x1) / 2.0,
+                (cell.y0 + cell.y1) / 2.0,
+                canvas,
+            ),
+            (mouse_x, mouse_y),
+            &self.cell_tooltip(&cell),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
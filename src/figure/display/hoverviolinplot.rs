@@ -0,0 +1,72 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas,
+    figuretypes::violinplot::ViolinPlot,
+    utilities::scaling::{scale_factor, to_pixel_x, to_pixel_y},
+};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for ViolinPlot {
+    /// Finds the violin under the mouse column, returning its median.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        _mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let margin = canvas.margin as f64;
+        let categories = self.datasets.len().max(1) as f64;
+        let scale_x = scale_factor(0.0, categories, canvas.width as f64 - 2.0 * margin);
+        let position = (mouse_x as f64 - margin) / scale_x;
+        if position < 0.0 {
+            return None;
+        }
+        let index = position as usize;
+        let stats = self.datasets.get(index)?.box_stats()?;
+        Some(((index as f64 + 0.5, stats.median), stats.median))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+        let margin = canvas.margin as f64;
+
+        let (y_min, y_max) = self.value_range();
+        let categories = self.datasets.len().max(1) as f64;
+        let scale_x = scale_factor(0.0, categories, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, height - 2.0 * margin);
+
+        (
+            to_pixel_x(x, 0.0, scale_x, margin) as u32,
+            to_pixel_y(y, y_min, scale_y, height, margin) as u32,
+        )
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((x, y), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let dataset = &self.datasets[x as usize];
+        let stats = dataset.box_stats()?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "{}: median {:.2}, Q1 {:.2}, Q3 {:.2}",
+                dataset.label, stats.median, stats.q1, stats.q3
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -0,0 +1,69 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas, figuretypes::waterfallchart::WaterfallChart,
+};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Hover for WaterfallChart {
+    /// Finds the bar under the mouse column, returned as the top center of the bar with the
+    /// x position as a fraction of the plot width and the y position as a value.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        _mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let margin = canvas.margin as f64;
+        let plot_width = canvas.width as f64 - 2.0 * margin;
+        if plot_width <= 0.0 {
+            return None;
+        }
+
+        let index = self.step_at((mouse_x as f64 - margin) / plot_width)?;
+        let bar = self.bars()[index];
+        let (left, right) = self.bar_edges(index);
+        Some((((left + right) / 2.0, bar.span().1), bar.value()))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let margin = canvas.margin as f64;
+        let plot_width = canvas.width as f64 - 2.0 * margin;
+        let plot_height = canvas.height as f64 - 2.0 * margin;
+        let (y_min, y_max) = self.value_range();
+        (
+            (margin + x * plot_width) as u32,
+            (canvas.height as f64 - margin - (y - y_min) / (y_max - y_min) * plot_height) as u32,
+        )
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((x, y), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let index = self.step_at(x)?;
+        let bar = self.bars()[index];
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(x, y, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "{}: {} (running total {:.*})",
+                self.steps[index].label,
+                self.bar_label(&bar),
+                self.precision,
+                bar.end
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
@@ -0,0 +1,75 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas,
+    figuretypes::waveform::{format_mm_ss, Waveform},
+};
+
+use super::hover::{render_tooltip, Hover};
+
+impl Waveform {
+    /// Computes the plot area on a canvas.
+    fn canvas_area(&self, canvas: &PixelCanvas) -> (f64, f64, f64, f64) {
+        self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )
+    }
+
+    /// Finds the envelope column under the mouse and its `(min, max)`.
+    fn column_at(&self, mouse_x: u32, canvas: &PixelCanvas) -> Option<(usize, (f64, f64))> {
+        let (left, _, right, _) = self.canvas_area(canvas);
+        let x = mouse_x as f64;
+        if x < left || x >= right {
+            return None;
+        }
+        let column = (x - left) as usize;
+        let range = self.envelope_column(column, (right - left).round() as usize)?;
+        Some((column, range))
+    }
+}
+
+impl Hover for Waveform {
+    /// Finds the envelope column under the mouse, returned as its start time and maximum.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        _mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let (left, _, right, _) = self.canvas_area(canvas);
+        let (column, (_, max)) = self.column_at(mouse_x, canvas)?;
+        let time = column as f64 / (right - left) * self.duration();
+        Some(((time, max), 0.0))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let area = self.canvas_area(canvas);
+        let (left, _, right, _) = area;
+        let px = left + x / self.duration().max(f64::EPSILON) * (right - left);
+        (px as u32, self.to_canvas_y(y, self.peak(), area) as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((time, max), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let (_, (min, _)) = self.column_at(mouse_x, canvas)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(time, max, canvas),
+            (mouse_x, mouse_y),
+            &format!("{}: min {min:.3}, max {max:.3}", format_mm_ss(time, 3)),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
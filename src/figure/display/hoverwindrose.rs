@@ -0,0 +1,71 @@
+use ab_glyph::FontRef;
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::windrose::WindRose};
+
+use super::hover::{render_tooltip, Hover};
+
+impl WindRose {
+    /// Computes the layout of the rose on a canvas.
+    fn canvas_layout(&self, canvas: &PixelCanvas) -> (f64, f64, f64) {
+        self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        )
+    }
+}
+
+impl Hover for WindRose {
+    /// Finds the bar segment under the mouse, returned as the bearing and distance from the
+    /// center (in pixels) of its middle.
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let layout = self.canvas_layout(canvas);
+        let (sector, class) = self.wedge_at(mouse_x as f64, mouse_y as f64, layout)?;
+        let outer = self.ring_values().last().copied().unwrap_or(1.0);
+        let frequencies = &self.frequencies()[sector];
+        let inner: f64 = frequencies[..class].iter().sum();
+        let middle = (inner + frequencies[class] / 2.0) / outer * layout.2;
+        Some(((sector as f64 * self.sector_width(), middle), 0.0))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let (px, py) = self.to_canvas(x, y, self.canvas_layout(canvas));
+        (px.round() as u32, py.round() as u32)
+    }
+
+    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+        let ((bearing, distance), _) = self.find_closest_point(mouse_x, mouse_y, canvas)?;
+        let layout = self.canvas_layout(canvas);
+        let (sector, class) = self.wedge_at(mouse_x as f64, mouse_y as f64, layout)?;
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+
+        Some(render_tooltip(
+            canvas,
+            font_path,
+            self.to_canvas_coordinates(bearing, distance, canvas),
+            (mouse_x, mouse_y),
+            &format!(
+                "{}, {} {}: {}",
+                self.sector_label(sector),
+                self.class_label(class),
+                self.unit,
+                self.config
+                    .format
+                    .format_percent(self.frequencies()[sector][class], 1)
+            ),
+        ))
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
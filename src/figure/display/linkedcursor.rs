@@ -0,0 +1,132 @@
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, drawers::drawer::Drawer};
+
+use super::{
+    hover::Hover,
+    viewstate::{ViewBookmark, ViewState},
+};
+
+/// Figures that can be shown as linked panels: they draw and hover like any displayed
+/// figure and expose their axis ranges, which map window columns to shared x values.
+///
+/// Implemented for every figure that is `Hover + Drawer + ViewBookmark`.
+pub trait LinkedFigure: Hover + Drawer + ViewBookmark {}
+
+impl<T: Hover + Drawer + ViewBookmark> LinkedFigure for T {}
+
+/// A figure together with the canvas it is drawn on, shown as one panel of a linked
+/// display.
+pub struct LinkedPanel<'a> {
+    /// The canvas the figure is drawn on; its size is the size of the panel.
+    pub canvas: &'a mut PixelCanvas,
+    /// The figure shown in the panel.
+    pub figure: &'a mut dyn LinkedFigure,
+}
+
+impl<'a> LinkedPanel<'a> {
+    /// Creates a panel.
+    ///
+    /// # Parameters
+    /// - `canvas`: The canvas the figure is drawn on.
+    /// - `figure`: The figure shown in the panel.
+    pub fn new(canvas: &'a mut PixelCanvas, figure: &'a mut dyn LinkedFigure) -> Self {
+        Self { canvas, figure }
+    }
+}
+
+/// Options of a linked display, deciding what the panels share.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CursorLink {
+    /// Draw the crosshair in every panel at the x value under the mouse, not only in the
+    /// hovered panel.
+    pub sync_cursor: bool,
+    /// Give every panel the union of the panels' x-ranges, so equal x values line up.
+    pub sync_x_range: bool,
+    /// The color of the crosshair lines.
+    pub color: [u8; 3],
+}
+
+impl Default for CursorLink {
+    fn default() -> Self {
+        Self {
+            sync_cursor: true,
+            sync_x_range: true,
+            color: [255, 0, 0],
+        }
+    }
+}
+
+impl CursorLink {
+    /// Computes the x-range shared by linked panels: the union of their x-ranges.
+    ///
+    /// # Parameters
+    /// - `views`: The views of the panels; panels without data have no view and are skipped.
+    ///
+    /// # Returns
+    /// The shared `(min, max)` range, or `None` if no panel has a view.
+    pub fn shared_x_range(views: &[Option<ViewState>]) -> Option<(f64, f64)> {
+        views
+            .iter()
+            .flatten()
+            .map(|view| view.x_range)
+            .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+    }
+
+    /// Converts a canvas column into an x value of a view.
+    ///
+    /// # Parameters
+    /// - `view`: The axis ranges the panel was drawn with.
+    /// - `canvas`: The canvas of the panel.
+    /// - `column`: The column in canvas space.
+    ///
+    /// # Returns
+    /// The x value, or `None` if the column is outside the plot area.
+    pub fn x_at(view: &ViewState, canvas: &PixelCanvas, column: u32) -> Option<f64> {
+        let margin = canvas.margin as f64;
+        let plot_width = canvas.width as f64 - 2.0 * margin;
+        let offset = column as f64 - margin;
+        if plot_width <= 0.0 || !(0.0..=plot_width).contains(&offset) {
+            return None;
+        }
+        let (x_min, x_max) = view.x_range;
+        Some(x_min + offset / plot_width * (x_max - x_min))
+    }
+
+    /// Converts an x value into a canvas column of a view.
+    ///
+    /// # Parameters
+    /// - `view`: The axis ranges the panel was drawn with.
+    /// - `canvas`: The canvas of the panel.
+    /// - `x`: The x value.
+    ///
+    /// # Returns
+    /// The column, or `None` if the value is outside the view's x-range.
+    pub fn column_of(view: &ViewState, canvas: &PixelCanvas, x: f64) -> Option<u32> {
+        let (x_min, x_max) = view.x_range;
+        if !(x_min..=x_max).contains(&x) {
+            return None;
+        }
+        let margin = canvas.margin as f64;
+        let plot_width = canvas.width as f64 - 2.0 * margin;
+        Some((margin + (x - x_min) / (x_max - x_min) * plot_width).round() as u32)
+    }
+
+    /// Draws a vertical crosshair line over the plot area of a panel's window pixels.
+    ///
+    /// # Parameters
+    /// - `pixels`: The panel's pixels in `0RGB` format, row by row.
+    /// - `canvas`: The canvas of the panel, giving its size and margin.
+    /// - `column`: The column of the line in canvas space.
+    pub fn draw_crosshair(&self, pixels: &mut [u32], canvas: &PixelCanvas, column: u32) {
+        if column >= canvas.width {
+            return;
+        }
+        let [r, g, b] = self.color.map(u32::from);
+        let color = 0xFF000000 | (r << 16) | (g << 8) | b;
+        let bottom = canvas.height.saturating_sub(canvas.margin);
+        for y in canvas.margin..bottom {
+            if let Some(pixel) = pixels.get_mut((y * canvas.width + column) as usize) {
+                *pixel = color;
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::figure::figuretypes::{cartesiangraph::CartesianGraph, stemplot::StemPlot};
+
+/// The visible axis ranges of a figure, captured as a bookmark that can be stored as text,
+/// shared, and applied later to the same or another figure.
+///
+/// The encoded form is `x=<min>..<max>;y=<min>..<max>`, with values written exactly, so a
+/// parsed bookmark restores the same ranges.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewState {
+    /// The `(min, max)` range of the x-axis.
+    pub x_range: (f64, f64),
+    /// The `(min, max)` range of the y-axis.
+    pub y_range: (f64, f64),
+}
+
+impl ViewState {
+    /// Creates a view from axis ranges.
+    ///
+    /// # Parameters
+    /// - `x_range`: The `(min, max)` range of the x-axis.
+    /// - `y_range`: The `(min, max)` range of the y-axis.
+    pub fn new(x_range: (f64, f64), y_range: (f64, f64)) -> Self {
+        Self { x_range, y_range }
+    }
+
+    /// Encodes the view as text.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let view = ViewState::new((0.0, 10.0), (-1.5, 2.0));
+    /// assert_eq!(view.encode(), "x=0..10;y=-1.5..2");
+    /// ```
+    pub fn encode(&self) -> String {
+        format!(
+            "x={}..{};y={}..{}",
+            self.x_range.0, self.x_range.1, self.y_range.0, self.y_range.1
+        )
+    }
+
+    /// Parses a view encoded by `encode`.
+    ///
+    /// # Parameters
+    /// - `text`: The encoded view; surrounding whitespace is ignored.
+    ///
+    /// # Returns
+    /// The view, or an error if an axis is missing or a range is not a finite `min..max`
+    /// pair with `min < max`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut x_range = None;
+        let mut y_range = None;
+        for part in text
+            .trim()
+            .split(';')
+            .filter(|part| !part.trim().is_empty())
+        {
+            let (axis, range) = part
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid view component '{part}'"))?;
+            let range = Self::parse_range(range.trim())?;
+            match axis.trim() {
+                "x" => x_range = Some(range),
+                "y" => y_range = Some(range),
+                other => return Err(format!("Unknown view axis '{other}'")),
+            }
+        }
+        Ok(Self {
+            x_range: x_range.ok_or("View has no x range")?,
+            y_range: y_range.ok_or("View has no y range")?,
+        })
+    }
+
+    /// Parses a `min..max` range.
+    fn parse_range(text: &str) -> Result<(f64, f64), String> {
+        let (min, max) = text
+            .split_once("..")
+            .ok_or_else(|| format!("Invalid view range '{text}'"))?;
+        let parse = |value: &str| {
+            value
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid view bound '{value}'"))
+        };
+        let (min, max) = (parse(min)?, parse(max)?);
+        if !(min.is_finite() && max.is_finite() && min < max) {
+            return Err(format!("Empty or non-finite view range '{text}'"));
+        }
+        Ok((min, max))
+    }
+}
+
+/// Figures whose visible axis ranges can be captured and restored as a `ViewState`.
+///
+/// Figures keep all their points visible: a view narrower than the data is widened to it
+/// again when the figure is drawn. Applying the view of one figure to another therefore
+/// aligns their axes when comparing panels.
+///
+/// # Example
+/// ```rust,ignore
+/// let bookmark = graph.view_state().map(|view| view.encode());
+/// // Later, or on another figure:
+/// other.apply_view_state(&ViewState::parse(&bookmark.unwrap())?);
+/// ```
+pub trait ViewBookmark {
+    /// Captures the current axis ranges.
+    ///
+    /// # Returns
+    /// The view, or `None` while the figure has no data and thus no ranges.
+    fn view_state(&self) -> Option<ViewState>;
+
+    /// Sets the axis ranges to a view.
+    ///
+    /// # Parameters
+    /// - `view`: The view to restore.
+    fn apply_view_state(&mut self, view: &ViewState);
+}
+
+impl ViewBookmark for CartesianGraph {
+    fn view_state(&self) -> Option<ViewState> {
+        // The ranges are infinite until data is added
+        [self.x_min, self.x_max, self.y_min, self.y_max]
+            .iter()
+            .all(|value| value.is_finite())
+            .then_some(ViewState::new(
+                (self.x_min, self.x_max),
+                (self.y_min, self.y_max),
+            ))
+    }
+
+    fn apply_view_state(&mut self, view: &ViewState) {
+        (self.x_min, self.x_max) = view.x_range;
+        (self.y_min, self.y_max) = view.y_range;
+    }
+}
+
+impl ViewBookmark for StemPlot {
+    fn view_state(&self) -> Option<ViewState> {
+        self.graph.view_state()
+    }
+
+    fn apply_view_state(&mut self, view: &ViewState) {
+        self.graph.apply_view_state(view);
+    }
+}
@@ -3,9 +3,15 @@ use resvg::{
     tiny_skia::{self, Pixmap},
     usvg::{self, fontdb},
 };
-use std::time::{Duration, Instant};
-
-use super::hover::Hover;
+use std::time::Instant;
+
+use super::{
+    framebuffer::FrameBuffers,
+    framethrottle::FrameThrottle,
+    hover::Hover,
+    linkedcursor::{CursorLink, LinkedPanel},
+    viewstate::ViewState,
+};
 use crate::figure::{
     canvas::pixelcanvas::PixelCanvas, configuration::figureconfig::FigureConfig,
     drawers::drawer::Drawer,
@@ -43,9 +49,12 @@ impl Winop {
         // Initialize a font database.
         let mut fontdb = fontdb::Database::new();
         fontdb.load_system_fonts();
-        if figure_config.validate().is_ok() {
+        if figure_config.validate_fonts().is_ok() {
             fontdb.load_font_data(figure_config.font_label.clone().unwrap().into_bytes());
         }
+        for font_path in &figure_config.font_fallbacks {
+            let _ = fontdb.load_font_file(font_path);
+        }
 
         // Parse the SVG content.
         let mut opt = usvg::Options::default();
@@ -97,6 +106,11 @@ impl Winop {
 
     /// Displays a plot in real-time with continuous updates.
     ///
+    /// Frames are double buffered: each update is drawn offscreen and swapped into the
+    /// window only once complete, so live updates at high frame rates do not flicker or
+    /// tear. The window refreshes at most `FrameThrottle::DEFAULT_MAX_FPS` times per second;
+    /// use `display_real_time_throttled` to skip redraws of unchanged data.
+    ///
     /// # Parameters
     /// - `canvas`: The `PixelCanvas` to draw on.
     /// - `plot`: The plot to be rendered, implementing `Hover` and `Drawer`.
@@ -112,6 +126,59 @@ impl Winop {
         title: &str,
         mut update_data: impl FnMut(&mut T) + 'static,
         fps: u32,
+    ) {
+        let mut throttle = FrameThrottle::new(fps);
+        throttle.set_max_fps(fps.max(FrameThrottle::DEFAULT_MAX_FPS));
+        Self::display_real_time_throttled(
+            canvas,
+            plot,
+            title,
+            move |plot| {
+                update_data(plot);
+                true
+            },
+            throttle,
+        );
+    }
+
+    /// Displays a plot in real-time, redrawing only when its data changed.
+    ///
+    /// The update closure reports whether it changed the plot; unchanged updates skip
+    /// drawing the figure, and the window is refreshed no faster than the throttle's
+    /// `max_fps`, so idle dashboards use little CPU. Hover highlights are recomputed only
+    /// when the mouse moves or a new frame is drawn.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw on.
+    /// - `plot`: The plot to be rendered, implementing `Hover` and `Drawer`.
+    /// - `title`: The title of the window.
+    /// - `update_data`: A closure updating the plot's data and returning `true` if anything
+    ///   changed.
+    /// - `throttle`: The update rate, refresh limit and change detection.
+    ///
+    /// # Panics
+    /// - If the window cannot be created.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut throttle = FrameThrottle::new(10);
+    /// throttle.set_max_fps(30);
+    /// Winop::display_real_time_throttled(&mut canvas, &mut graph, "Live", move |graph| {
+    ///     match receiver.try_recv() {
+    ///         Ok(point) => {
+    ///             graph.datasets[0].points.push(point);
+    ///             true
+    ///         }
+    ///         Err(_) => false,
+    ///     }
+    /// }, throttle);
+    /// ```
+    pub fn display_real_time_throttled<T: Hover + Drawer>(
+        canvas: &mut PixelCanvas,
+        plot: &mut T,
+        title: &str,
+        mut update_data: impl FnMut(&mut T) -> bool + 'static,
+        throttle: FrameThrottle,
     ) {
         let width = canvas.width as usize;
         let height = canvas.height as usize;
@@ -127,70 +194,71 @@ impl Winop {
             },
         )
         .unwrap_or_else(|e| panic!("Unable to open Window: {e}"));
+        window.set_target_fps(throttle.max_fps as usize);
 
-        let frame_duration = Duration::from_secs_f32(1.0 / fps as f32);
-        let mut last_frame_time = Instant::now();
+        let update_interval = throttle.update_interval();
+        let mut last_update_time = Instant::now();
 
         let mut hover_enabled = false;
         let mut show_hints = false;
+        let mut frames = FrameBuffers::from_canvas(canvas);
+        let mut hover_buffer: Option<Vec<u32>> = None;
+        let mut last_mouse_pos = None;
+        // The first frame must reach the window even if the data never changes.
+        let mut dirty = true;
 
         while window.is_open() && !window.is_key_pressed(Key::Escape, minifb::KeyRepeat::No) {
-            // Update data for real-time rendering.
-            if last_frame_time.elapsed() >= frame_duration {
-                update_data(plot);
-                plot.draw(canvas);
-                last_frame_time = Instant::now();
+            // Update data and draw the next frame offscreen, then swap it in.
+            let mut new_frame = false;
+            if last_update_time.elapsed() >= update_interval {
+                if throttle.needs_redraw(update_data(plot)) {
+                    plot.draw(canvas);
+                    frames.present(canvas);
+                    new_frame = true;
+                }
+                last_update_time = Instant::now();
             }
 
-            // Render the canvas to a buffer.
-            let mut buffer: Vec<u32> = Self::canvas_to_buffer(canvas);
-
-            if hover_enabled {
-                if let Some(mouse_pos) = window.get_mouse_pos(MouseMode::Pass) {
-                    let (mouse_x, mouse_y) = (mouse_pos.0 as u32, mouse_pos.1 as u32);
+            if window.is_key_pressed(Key::C, minifb::KeyRepeat::No) {
+                hover_enabled = !hover_enabled;
+                hover_buffer = None;
+                last_mouse_pos = None;
+                dirty = true;
+            }
 
-                    if let Some(updated_buffer) = plot.handle_hover(mouse_x, mouse_y, canvas) {
-                        buffer = updated_buffer;
-                    }
-                }
+            if window.is_key_pressed(Key::H, minifb::KeyRepeat::No) {
+                show_hints = !show_hints;
             }
 
             if show_hints {
                 Self::render_hints(canvas);
             }
 
-            if window.is_key_pressed(Key::C, minifb::KeyRepeat::No) {
-                hover_enabled = !hover_enabled;
+            if hover_enabled {
+                let mouse_pos = window.get_mouse_pos(MouseMode::Pass);
+                if new_frame || mouse_pos != last_mouse_pos {
+                    hover_buffer = mouse_pos.and_then(|(mouse_x, mouse_y)| {
+                        plot.handle_hover(mouse_x as u32, mouse_y as u32, canvas)
+                    });
+                    last_mouse_pos = mouse_pos;
+                    dirty = true;
+                }
             }
 
-            if window.is_key_pressed(Key::H, minifb::KeyRepeat::No) {
-                show_hints = !show_hints;
+            if new_frame || dirty {
+                let (frame_width, frame_height) = frames.size();
+                let buffer = hover_buffer.as_deref().unwrap_or(frames.front());
+                window
+                    .update_with_buffer(buffer, frame_width, frame_height)
+                    .unwrap();
+                dirty = false;
+            } else {
+                // Nothing changed: only process window events.
+                window.update();
             }
-
-            window.update_with_buffer(&buffer, width, height).unwrap();
         }
     }
 
-    /// Converts the canvas buffer into a format compatible with minifb.
-    ///
-    /// # Parameters
-    /// - `canvas`: The `PixelCanvas` whose buffer needs conversion.
-    ///
-    /// # Returns
-    /// A vector of `u32` representing the pixel data in ARGB format.
-    fn canvas_to_buffer(canvas: &PixelCanvas) -> Vec<u32> {
-        canvas
-            .buffer
-            .chunks_exact(3)
-            .map(|rgb| {
-                let r = rgb[0] as u32;
-                let g = rgb[1] as u32;
-                let b = rgb[2] as u32;
-                0xFF000000 | (r << 16) | (g << 8) | b
-            })
-            .collect()
-    }
-
     /// Displays a plot in an interactive window with hover functionality.
     ///
     /// # Parameters
@@ -215,37 +283,192 @@ impl Winop {
             },
         )
         .unwrap_or_else(|e| panic!("Unable to open Window: {e}"));
+        window.set_target_fps(FrameThrottle::DEFAULT_MAX_FPS as usize);
 
         let mut hover_enabled = false;
         let mut show_hints = false;
+        let frames = FrameBuffers::from_canvas(canvas);
 
         while window.is_open() && !window.is_key_pressed(Key::Escape, minifb::KeyRepeat::No) {
-            // Render the canvas to a buffer.
-            let mut buffer: Vec<u32> = Self::canvas_to_buffer(canvas);
-
             if show_hints {
                 Self::render_hints(canvas);
             }
 
-            if hover_enabled {
-                if let Some(mouse_pos) = window.get_mouse_pos(MouseMode::Pass) {
-                    let (mouse_x, mouse_y) = (mouse_pos.0 as u32, mouse_pos.1 as u32);
+            let hover_buffer = if hover_enabled {
+                window
+                    .get_mouse_pos(MouseMode::Pass)
+                    .and_then(|(mouse_x, mouse_y)| {
+                        plot.handle_hover(mouse_x as u32, mouse_y as u32, canvas)
+                    })
+            } else {
+                None
+            };
+
+            if window.is_key_pressed(Key::H, minifb::KeyRepeat::No) {
+                show_hints = !show_hints;
+            }
 
-                    if let Some(updated_buffer) = plot.handle_hover(mouse_x, mouse_y, canvas) {
-                        buffer = updated_buffer;
+            if window.is_key_pressed(Key::C, minifb::KeyRepeat::No) {
+                hover_enabled = !hover_enabled;
+            }
+
+            // The figure is static, so the canvas was converted only once.
+            let buffer = hover_buffer.as_deref().unwrap_or(frames.front());
+            window.update_with_buffer(buffer, width, height).unwrap();
+        }
+    }
+
+    /// Displays several figures as panels stacked top to bottom in one window, with a
+    /// crosshair linked by x value.
+    ///
+    /// Each figure is drawn on its own canvas. Moving the mouse over a panel draws a
+    /// vertical crosshair at the x value under the mouse in that panel and, if
+    /// `link.sync_cursor` is set, at the same x value in every other panel, so aligned
+    /// time series can be compared. With `link.sync_x_range` the panels are drawn with
+    /// the union of their x-ranges, so their x-axes match; apply a `ViewState` to a
+    /// panel beforehand to widen the shared range. Press C to show the hovered panel's
+    /// tooltip as well.
+    ///
+    /// # Parameters
+    /// - `panels`: The figures and their canvases, from top to bottom.
+    /// - `title`: The title of the window.
+    /// - `link`: What the panels share.
+    ///
+    /// # Panics
+    /// - If the window cannot be created.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut panels = [
+    ///     LinkedPanel::new(&mut price_canvas, &mut price),
+    ///     LinkedPanel::new(&mut volume_canvas, &mut volume),
+    /// ];
+    /// Winop::display_linked(&mut panels, "Market", CursorLink::default());
+    /// ```
+    pub fn display_linked(panels: &mut [LinkedPanel], title: &str, link: CursorLink) {
+        if link.sync_x_range {
+            let views: Vec<Option<ViewState>> = panels
+                .iter()
+                .map(|panel| panel.figure.view_state())
+                .collect();
+            if let Some(x_range) = CursorLink::shared_x_range(&views) {
+                for (panel, view) in panels.iter_mut().zip(views) {
+                    if let Some(view) = view {
+                        panel
+                            .figure
+                            .apply_view_state(&ViewState::new(x_range, view.y_range));
                     }
                 }
             }
+        }
 
-            if window.is_key_pressed(Key::H, minifb::KeyRepeat::No) {
-                show_hints = !show_hints;
-            }
+        for panel in panels.iter_mut() {
+            panel.figure.draw(panel.canvas);
+        }
+        // Drawers may adjust the ranges, so the views are taken after drawing.
+        let views: Vec<Option<ViewState>> = panels
+            .iter()
+            .map(|panel| panel.figure.view_state())
+            .collect();
+        let frames: Vec<FrameBuffers> = panels
+            .iter()
+            .map(|panel| FrameBuffers::from_canvas(panel.canvas))
+            .collect();
 
+        let width = panels
+            .iter()
+            .map(|panel| panel.canvas.width as usize)
+            .max()
+            .unwrap_or(1);
+        let height = panels
+            .iter()
+            .map(|panel| panel.canvas.height as usize)
+            .sum::<usize>()
+            .max(1);
+
+        let mut window = Window::new(
+            title,
+            width,
+            height,
+            WindowOptions {
+                resize: false,
+                scale: minifb::Scale::X1,
+                ..WindowOptions::default()
+            },
+        )
+        .unwrap_or_else(|e| panic!("Unable to open Window: {e}"));
+        window.set_target_fps(FrameThrottle::DEFAULT_MAX_FPS as usize);
+
+        let mut hover_enabled = false;
+        let mut buffer = vec![0; width * height];
+        let mut last_mouse_pos = None;
+        let mut dirty = true;
+
+        while window.is_open() && !window.is_key_pressed(Key::Escape, minifb::KeyRepeat::No) {
             if window.is_key_pressed(Key::C, minifb::KeyRepeat::No) {
                 hover_enabled = !hover_enabled;
+                dirty = true;
+            }
+
+            let mouse_pos = window.get_mouse_pos(MouseMode::Discard);
+            if mouse_pos != last_mouse_pos {
+                last_mouse_pos = mouse_pos;
+                dirty = true;
+            }
+
+            if !dirty {
+                // Nothing changed: only process window events.
+                window.update();
+                continue;
+            }
+
+            // Find the hovered panel and the mouse position on its canvas.
+            let mut hovered = None;
+            if let Some((mouse_x, mouse_y)) = mouse_pos {
+                let mut top = 0;
+                for (index, panel) in panels.iter().enumerate() {
+                    let bottom = top + panel.canvas.height;
+                    if (top..bottom).contains(&(mouse_y as u32)) {
+                        hovered = Some((index, mouse_x as u32, mouse_y as u32 - top));
+                        break;
+                    }
+                    top = bottom;
+                }
+            }
+            let cursor_x = hovered.and_then(|(index, mouse_x, _)| {
+                views[index].and_then(|view| CursorLink::x_at(&view, panels[index].canvas, mouse_x))
+            });
+
+            buffer.fill(0);
+            let mut top = 0;
+            for (index, panel) in panels.iter().enumerate() {
+                let is_hovered = hovered.is_some_and(|(hovered, _, _)| hovered == index);
+                let mut pixels = match hovered {
+                    Some((_, mouse_x, mouse_y)) if is_hovered && hover_enabled => panel
+                        .figure
+                        .handle_hover(mouse_x, mouse_y, panel.canvas)
+                        .unwrap_or_else(|| frames[index].front().to_vec()),
+                    _ => frames[index].front().to_vec(),
+                };
+                if is_hovered || link.sync_cursor {
+                    if let Some(column) = cursor_x
+                        .zip(views[index])
+                        .and_then(|(x, view)| CursorLink::column_of(&view, panel.canvas, x))
+                    {
+                        link.draw_crosshair(&mut pixels, panel.canvas, column);
+                    }
+                }
+
+                let panel_width = panel.canvas.width as usize;
+                for (row, line) in pixels.chunks_exact(panel_width).enumerate() {
+                    let start = (top + row) * width;
+                    buffer[start..start + panel_width].copy_from_slice(line);
+                }
+                top += panel.canvas.height as usize;
             }
 
             window.update_with_buffer(&buffer, width, height).unwrap();
+            dirty = false;
         }
     }
 
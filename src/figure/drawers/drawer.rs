@@ -1,19 +1,83 @@
-use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
+use ab_glyph::PxScale;
 
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
-    configuration::figureconfig::FigureConfig,
-    utilities::{axistype::AxisType, linetype::LineType},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        pngmetadata::PngMetadata,
+        svgcanvas::{escape_xml, SvgCanvas},
+        svgelement::LAYER_BACKGROUND,
+    },
+    configuration::figureconfig::{FigureConfig, TICK_LINE_SPACING},
+    utilities::{
+        axistype::AxisType, colorbar::COLOR_BAR_WIDTH, linetype::LineType,
+        referenceline::ReferenceLine, textmetrics::measure_text_with_fallbacks,
+        unitplacement::UnitPlacement,
+    },
 };
 
-use std::any::Any;
+use std::{any::Any, io};
 
 /// A trait for rendering charts and graphs, supporting multiple output formats.
+///
+/// # Implementing a custom chart
+/// `Drawer` is the extension point for charts defined outside this crate. The supported
+/// surface is:
+/// - **Required methods**: `as_any`, `get_figure_config`, `draw`, `draw_legend` and
+///   `draw_svg`.
+/// - **Provided helpers**: backgrounds (`fill_background`, `fill_svg_background`,
+///   `fill_svg_canvas_background`), `draw_grid`, `draw_tick_grid`, `draw_svg_tick_grid`,
+///   `draw_axis`, `draw_label`, `draw_title`, `draw_axis_value`, `draw_axis_units`,
+///   `draw_secondary_axis`, `draw_color_bar` and their SVG counterparts. They honor the `FigureConfig`
+///   (fonts, fallbacks, colors, tick formatters, units, transparency), so a custom chart
+///   looks like the built-in ones.
+/// - **Canvas primitives**: the public methods and fields of `PixelCanvas` and `SvgCanvas`.
+/// - **Scaling helpers**: the functions in `utilities::scaling` (`data_bounds`,
+///   `scale_factor`, `to_pixel_x`, `to_pixel_y`, `tick_values`), which map data onto the
+///   plot area the same way the built-in figures do.
+///
+/// Export helpers such as `render_scaled` and `save_as_image_scaled` work for any
+/// implementation; to have its fonts scaled too, a chart that reads its fonts from
+/// `get_figure_config` also overrides `get_figure_config_mut`. A chart with a series legend
+/// overrides `legend_entries` to have its entries shown in a `FigureLegend`.
+///
+/// # Example
+/// ```rust,ignore
+/// struct Lollipop {
+///     values: Vec<f64>,
+///     config: FigureConfig,
+/// }
+///
+/// impl Drawer for Lollipop {
+///     fn draw(&mut self, canvas: &mut PixelCanvas) {
+///         let cfg = self.config.clone();
+///         canvas.set_transparent(cfg.transparent_background);
+///         self.fill_background(canvas, &cfg);
+///         let (_, _, y_min, y_max) = data_bounds(self.values.iter().map(|&v| (0.0, v))).unwrap();
+///         let scale_y = scale_factor(y_min, y_max, (canvas.height - 2 * canvas.margin) as f64);
+///         // ...
+///     }
+///     // ...
+/// }
+/// ```
+///
+/// See `examples/customdrawer.rs` for a complete implementation.
 pub trait Drawer: Any {
+    /// Returns the figure as `Any`, so callers holding a `Box<dyn Drawer>` can downcast it
+    /// to its concrete type.
     fn as_any(&mut self) -> &mut (dyn Any + 'static);
+
+    /// Returns the configuration used to style the figure.
     fn get_figure_config(&self) -> &FigureConfig;
 
+    /// Returns the configuration used to style the figure, for modification.
+    ///
+    /// # Returns
+    /// The configuration, or `None` (the default) if the figure does not expose it. Charts
+    /// that return `None` are rendered by `render_scaled` with unscaled fonts.
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        None
+    }
+
     /// Draws the main content of the plot on a `PixelCanvas`.
     ///
     /// # Parameters
@@ -26,12 +90,140 @@ pub trait Drawer: Any {
     /// - `canvas`: The `PixelCanvas` to draw the legend on.
     fn draw_legend(&self, canvas: &mut PixelCanvas);
 
+    /// Returns the entries of the figure's series legend as `(label, color)` pairs, so a
+    /// `FigureLegend` can show them for several composed figures at once.
+    ///
+    /// # Returns
+    /// The entries, or an empty list (the default) for figures without a series legend.
+    fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        Vec::new()
+    }
+
     /// Draws the plot content on an `SvgCanvas`.
     ///
     /// # Parameters
     /// - `svg_canvas`: The `SvgCanvas` to render the plot on.
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas);
 
+    /// Checks the figure configuration before rendering on a canvas of the given size.
+    ///
+    /// Called at the start of `draw` and `draw_svg` by the built-in figures, so invalid settings
+    /// fail with a description of the problem instead of producing an empty or broken image.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    /// - `fonts_required`: Whether the font files are needed (raster output) or not (SVG output).
+    ///
+    /// # Panics
+    /// Panics if `FigureConfig::validate_for_canvas` reports any problem.
+    fn check_render_config(&self, width: u32, height: u32, margin: u32, fonts_required: bool) {
+        if let Err(problems) =
+            self.get_figure_config()
+                .validate_for_canvas(width, height, margin, fonts_required)
+        {
+            panic!("Invalid figure configuration: {problems}");
+        }
+    }
+
+    /// Renders the plot at `scale_factor` times the resolution of `canvas`.
+    ///
+    /// The canvas dimensions, margin, and all font sizes are multiplied by the scale factor,
+    /// so the same figure definition produces a crisp high-DPI image. The figure
+    /// configuration is restored afterwards.
+    ///
+    /// Only sizes held by the canvas and the `FigureConfig` are scaled: line and stroke
+    /// widths, tick lengths and other offsets the drawers use in pixels stay at their
+    /// original size, so they look thinner and shorter at higher scale factors. Fonts are
+    /// scaled only if `get_figure_config_mut` returns the configuration.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` whose size, margin, and background are used as the base.
+    /// - `scale_factor`: The resolution multiplier (1 renders at the original size).
+    ///
+    /// # Returns
+    /// A new `PixelCanvas` holding the high-resolution rendering.
+    fn render_scaled(&mut self, canvas: &PixelCanvas, scale_factor: u32) -> PixelCanvas {
+        let scale_factor = scale_factor.max(1);
+        let original_config = self.get_figure_config().clone();
+        if let Some(config) = self.get_figure_config_mut() {
+            config.scale(scale_factor as f32);
+        }
+
+        let mut scaled_canvas = PixelCanvas::new(
+            canvas.width * scale_factor,
+            canvas.height * scale_factor,
+            canvas.background_color,
+            canvas.margin * scale_factor,
+        );
+        self.draw(&mut scaled_canvas);
+
+        if let Some(config) = self.get_figure_config_mut() {
+            *config = original_config;
+        }
+        scaled_canvas
+    }
+
+    /// Renders the plot at `scale_factor` times the resolution of `canvas` and saves it.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` whose size, margin, and background are used as the base.
+    /// - `file_path`: The path to save the image file.
+    /// - `scale_factor`: The resolution multiplier.
+    /// - `downsample`: If `true`, the high-resolution rendering is filtered back down to the
+    ///   size of `canvas`, producing a supersampled (anti-aliased) image. Otherwise the image
+    ///   is saved at the scaled size.
+    ///
+    /// # Panics
+    /// Panics if the image cannot be saved.
+    fn save_as_image_scaled(
+        &mut self,
+        canvas: &PixelCanvas,
+        file_path: &str,
+        scale_factor: u32,
+        downsample: bool,
+    ) {
+        let scaled_canvas = self.render_scaled(canvas, scale_factor);
+        if downsample {
+            scaled_canvas
+                .resized(canvas.width, canvas.height)
+                .save_as_image(file_path);
+        } else {
+            scaled_canvas.save_as_image(file_path);
+        }
+    }
+
+    /// Renders the plot at `scale_factor` times the size of `svg_canvas` and saves it as a
+    /// PNG file, rasterized in tiles of `tile_height` rows.
+    ///
+    /// The plot is drawn once as SVG and only one tile of pixels is held in memory at a
+    /// time, so poster sized exports (e.g. 20,000 x 20,000 pixels) do not need a buffer
+    /// for the whole image. See `SvgCanvas::save_as_png_tiled`.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The `SvgCanvas` the plot is drawn on, whose size is the base size.
+    /// - `file_path`: The path to save the PNG file.
+    /// - `scale_factor`: The resolution multiplier.
+    /// - `tile_height`: The number of rows rendered at a time.
+    ///
+    /// # Errors
+    /// Returns an `io::Result` if the SVG cannot be rendered or the file cannot be written.
+    fn save_as_png_tiled(
+        &mut self,
+        svg_canvas: &mut SvgCanvas,
+        file_path: &str,
+        scale_factor: f32,
+        tile_height: u32,
+    ) -> io::Result<()> {
+        self.draw_svg(svg_canvas);
+        svg_canvas.save_as_png_tiled(
+            file_path,
+            scale_factor,
+            tile_height,
+            &PngMetadata::default(),
+        )
+    }
+
     /// Converts RGB color array to SVG color string format.
     ///
     /// # Parameters
@@ -43,17 +235,70 @@ pub trait Drawer: Any {
         format!("rgb({},{},{})", color[0], color[1], color[2])
     }
 
+    /// Fills the whole SVG canvas with the canvas background color and draws the outer frame.
+    ///
+    /// Nothing is drawn when `config.transparent_background` is set. Unless the canvas already
+    /// has an accessible description, `config.description` becomes its `<desc>`, and
+    /// `config.text_halo` becomes the halo of its tick labels.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The `SvgCanvas` to draw on.
+    /// - `config`: The `FigureConfig` containing the transparency setting and description.
+    /// - `stroke_width`: The width of the outer frame stroke.
+    fn fill_svg_canvas_background(
+        &self,
+        svg_canvas: &mut SvgCanvas,
+        config: &FigureConfig,
+        stroke_width: f64,
+    ) {
+        if svg_canvas.description.is_none() {
+            if let Some(description) = &config.description {
+                let title = svg_canvas.title.clone();
+                svg_canvas.set_accessibility(title.as_deref(), Some(description));
+            }
+        }
+        svg_canvas.text_halo = config.text_halo;
+
+        if config.transparent_background {
+            return;
+        }
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let bg_color = svg_canvas.background_color.clone();
+
+        svg_canvas.elements.begin_layer(LAYER_BACKGROUND);
+        svg_canvas.draw_rect(
+            0.0,
+            0.0,
+            width,
+            height,
+            &bg_color,
+            "black",
+            stroke_width,
+            1.0,
+        );
+        svg_canvas.elements.end_layer();
+    }
+
     /// Fills the SVG chart background area (inside margins) with the background color.
     ///
+    /// Nothing is drawn when `config.transparent_background` is set.
+    ///
     /// # Parameters
     /// - `svg_canvas`: The `SvgCanvas` to draw on.
     /// - `config`: The `FigureConfig` containing the background color.
     fn fill_svg_background(&self, svg_canvas: &mut SvgCanvas, config: &FigureConfig) {
+        if config.transparent_background {
+            return;
+        }
+
         let margin = svg_canvas.margin as f64;
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
         let bg_color = self.rgb_to_svg_color(config.color_background);
 
+        svg_canvas.elements.begin_layer(LAYER_BACKGROUND);
         svg_canvas.draw_rect(
             margin,
             margin,
@@ -64,14 +309,23 @@ pub trait Drawer: Any {
             0.0,
             1.0,
         );
+        svg_canvas.elements.end_layer();
     }
 
     /// Fills the chart background area (inside margins) with the background color.
     ///
+    /// When `config.transparent_background` is set, the area is left untouched. The canvas
+    /// is not marked as transparent here; call `PixelCanvas::set_transparent` to save its
+    /// background with a zero alpha.
+    ///
     /// # Parameters
     /// - `canvas`: The `PixelCanvas` to fill.
     /// - `config`: The `FigureConfig` containing the background color.
     fn fill_background(&self, canvas: &mut PixelCanvas, config: &FigureConfig) {
+        if config.transparent_background {
+            return;
+        }
+
         for y in canvas.margin..canvas.height - canvas.margin {
             for x in canvas.margin..canvas.width - canvas.margin {
                 canvas.draw_pixel(x, y, config.color_background);
@@ -91,6 +345,62 @@ pub trait Drawer: Any {
         );
     }
 
+    /// Draws the grid lines of both axes at the tick positions, or evenly divided for axes
+    /// set to `GridAlignment::Even`.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the grid on.
+    /// - `config`: The `FigureConfig` containing the grid alignment and color.
+    /// - `x_ticks`: The pixel x-coordinates of the ticks on the x axis.
+    /// - `y_ticks`: The pixel y-coordinates of the ticks on the y axis.
+    fn draw_tick_grid(
+        &self,
+        canvas: &mut PixelCanvas,
+        config: &FigureConfig,
+        x_ticks: &[f64],
+        y_ticks: &[f64],
+    ) {
+        let margin = canvas.margin as f64;
+        let (right, bottom) = (
+            (canvas.width - canvas.margin) as f64,
+            (canvas.height - canvas.margin) as f64,
+        );
+        canvas.draw_grid_lines(
+            &config.grid_positions(AxisType::AxisX, margin, right, x_ticks),
+            &config.grid_positions(AxisType::AxisY, bottom, margin, y_ticks),
+            config.color_grid,
+        );
+    }
+
+    /// Draws the grid lines of both axes at the tick positions on an `SvgCanvas`, or evenly
+    /// divided for axes set to `GridAlignment::Even`.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The `SvgCanvas` to draw the grid on.
+    /// - `config`: The `FigureConfig` containing the grid alignment and color.
+    /// - `x_ticks`: The x-coordinates of the ticks on the x axis.
+    /// - `y_ticks`: The y-coordinates of the ticks on the y axis.
+    fn draw_svg_tick_grid(
+        &self,
+        svg_canvas: &mut SvgCanvas,
+        config: &FigureConfig,
+        x_ticks: &[f64],
+        y_ticks: &[f64],
+    ) {
+        let margin = svg_canvas.margin as f64;
+        let (right, bottom) = (
+            (svg_canvas.width - svg_canvas.margin) as f64,
+            (svg_canvas.height - svg_canvas.margin) as f64,
+        );
+        svg_canvas.draw_grid_lines(
+            &config.grid_positions(AxisType::AxisX, margin, right, x_ticks),
+            &config.grid_positions(AxisType::AxisY, bottom, margin, y_ticks),
+            (margin, right),
+            (margin, bottom),
+            &self.rgb_to_svg_color(config.color_grid),
+        );
+    }
+
     /// Draws an axis line on the canvas.
     ///
     /// # Parameters
@@ -124,23 +434,46 @@ pub trait Drawer: Any {
         x: u32,
         y: u32,
         text: &str,
+    ) {
+        self.draw_label_colored(canvas, config, (x, y), text, config.color_axis);
+    }
+
+    /// Draws a text label in a given color, e.g. one contrasting with the shape it lies on.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the label on.
+    /// - `config`: The `FigureConfig` containing label appearance settings.
+    /// - `(x, y)`: The position to draw the label, centered on `(x, y)`.
+    /// - `text`: The label text.
+    /// - `color`: The RGB color of the text.
+    fn draw_label_colored(
+        &self,
+        canvas: &mut PixelCanvas,
+        config: &FigureConfig,
+        (x, y): (u32, u32),
+        text: &str,
+        color: [u8; 3],
     ) {
         let font_path = config.font_label.as_ref().expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
         let scale = ab_glyph::PxScale {
             x: config.font_size_label,
             y: config.font_size_label,
         };
 
-        let (w, h) = text_size(scale, &font, text);
+        let (w, h) = measure_text_with_fallbacks(
+            text,
+            font_path,
+            &config.font_fallbacks,
+            config.font_size_label,
+        );
 
-        canvas.draw_text(
+        canvas.draw_text_with_fallbacks(
             x.saturating_sub(w / 2),
             y.saturating_sub(h / 2),
             text,
-            config.color_axis,
-            &font,
+            color,
+            font_path,
+            &config.font_fallbacks,
             scale,
         );
     }
@@ -161,27 +494,80 @@ pub trait Drawer: Any {
         text: &str,
     ) {
         let font_path = config.font_title.as_ref().expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
         let scale = PxScale {
             x: config.font_size_title,
             y: config.font_size_title,
         };
 
-        let (w, h) = text_size(scale, &font, text);
+        let (w, h) = measure_text_with_fallbacks(
+            text,
+            font_path,
+            &config.font_fallbacks,
+            config.font_size_title,
+        );
 
-        canvas.draw_text(
+        canvas.draw_text_with_fallbacks(
             x.saturating_sub(w / 2),
             y.saturating_sub(h / 2),
             text,
             config.color_title,
-            &font,
+            font_path,
+            &config.font_fallbacks,
             scale,
         );
     }
 
+    /// Draws a category tick label, shortened to `config.max_category_label_width`.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the label on.
+    /// - `config`: The `FigureConfig` containing the label width and appearance settings.
+    /// - `x`, `y`: The position of the label, as for `draw_axis_value`.
+    /// - `label`: The full category label.
+    /// - `axis`: The type of axis (`AxisType::AxisX` or `AxisType::AxisY`).
+    fn draw_category_label(
+        &self,
+        canvas: &mut PixelCanvas,
+        config: &FigureConfig,
+        x: u32,
+        y: u32,
+        label: &str,
+        axis: AxisType,
+    ) {
+        let text = config.category_label(label, config.font_size_axis);
+        self.draw_axis_value(canvas, config, x, y, &text, axis);
+    }
+
+    /// Adds a category tick label to the SVG canvas, shortened to
+    /// `config.max_category_label_width` with the full label as its `<title>`.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The `SvgCanvas` to draw the label on.
+    /// - `config`: The `FigureConfig` containing the label width.
+    /// - `x`, `y`: Coordinates of the label's baseline, as for `SvgCanvas::draw_tick_label`.
+    /// - `label`: The full category label.
+    /// - `font_size`: Font size of the label.
+    /// - `axis`: The type of axis (`AxisType::AxisX` or `AxisType::AxisY`).
+    #[allow(clippy::too_many_arguments)]
+    fn draw_svg_category_label(
+        &self,
+        svg_canvas: &mut SvgCanvas,
+        config: &FigureConfig,
+        x: f64,
+        y: f64,
+        label: &str,
+        font_size: f64,
+        axis: AxisType,
+    ) {
+        let text = config.category_label(label, font_size as f32);
+        svg_canvas.draw_category_label(x, y, &text, label, font_size, axis);
+    }
+
     /// Draws a value on the axis (tick label) based on its type.
     ///
+    /// Labels containing `\n` are drawn as several lines: below the tick for the x-axis,
+    /// and centered on the tick for the y-axis.
+    ///
     /// # Parameters
     /// - `canvas`: The `PixelCanvas` to draw the axis value on.
     /// - `config`: The `FigureConfig` containing axis value appearance settings.
@@ -198,27 +584,483 @@ pub trait Drawer: Any {
         axis: AxisType,
     ) {
         let font_path = config.font_label.as_ref().expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
         let scale = ab_glyph::PxScale {
             x: config.font_size_axis,
             y: config.font_size_axis,
         };
 
-        let (w, h) = text_size(scale, &font, text);
-        let mut x = x;
-        let mut y = y;
-        match axis {
-            AxisType::AxisX => {
-                x = x.saturating_sub(w / 2);
-                y = y.saturating_add(h);
+        let lines: Vec<&str> = text.lines().collect();
+        let line_advance = (config.font_size_axis * TICK_LINE_SPACING) as u32;
+        let block_offset = line_advance * lines.len().saturating_sub(1) as u32 / 2;
+
+        for (index, line) in lines.iter().enumerate() {
+            let (w, h) = measure_text_with_fallbacks(
+                line,
+                font_path,
+                &config.font_fallbacks,
+                config.font_size_axis,
+            );
+            let line_offset = index as u32 * line_advance;
+            let (line_x, line_y) = match axis {
+                AxisType::AxisX => (x.saturating_sub(w / 2), y.saturating_add(h) + line_offset),
+                AxisType::AxisY => (
+                    x.saturating_sub(w),
+                    (y + line_offset).saturating_sub(h / 2 + block_offset),
+                ),
+            };
+
+            self.draw_text_with_halo(
+                canvas,
+                config,
+                (line_x, line_y),
+                line,
+                config.color_axis,
+                scale,
+            );
+        }
+    }
+
+    /// Draws a label with the label font, over the halo of `config.text_halo`, if any.
+    ///
+    /// Used for tick labels, data value labels and reference line labels, which may lie
+    /// over dense data.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the label on.
+    /// - `config`: The `FigureConfig` containing the fonts and the halo.
+    /// - `x`, `y`: The top-left corner of the text.
+    /// - `text`: The label text.
+    /// - `color`: The RGB color of the text.
+    /// - `scale`: The font size.
+    fn draw_text_with_halo(
+        &self,
+        canvas: &mut PixelCanvas,
+        config: &FigureConfig,
+        (x, y): (u32, u32),
+        text: &str,
+        color: [u8; 3],
+        scale: PxScale,
+    ) {
+        let font_path = config.font_label.as_ref().expect("Font path is not set");
+        if let Some(halo) = &config.text_halo {
+            canvas.draw_text_halo(
+                x,
+                y,
+                text,
+                font_path,
+                &config.font_fallbacks,
+                scale,
+                false,
+                halo,
+            );
+        }
+        canvas.draw_text_with_fallbacks(
+            x,
+            y,
+            text,
+            color,
+            font_path,
+            &config.font_fallbacks,
+            scale,
+        );
+    }
+
+    /// Draws the axis units once at the end of each axis.
+    ///
+    /// Does nothing unless `config.unit_placement` is `UnitPlacement::AxisEnd`. The x-axis unit
+    /// is drawn in the right margin on the tick label row, and the y-axis unit above the
+    /// topmost tick label.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the units on.
+    /// - `config`: The `FigureConfig` containing the units and font settings.
+    fn draw_axis_units(&self, canvas: &mut PixelCanvas, config: &FigureConfig) {
+        if config.unit_placement != UnitPlacement::AxisEnd {
+            return;
+        }
+        let margin = canvas.margin;
+
+        if let Some(unit) = config.unit(AxisType::AxisX) {
+            let x = canvas.width - margin / 2;
+            let y = canvas.height - margin;
+            self.draw_axis_value(canvas, config, x, y, unit, AxisType::AxisX);
+        }
+        if let Some(unit) = config.unit(AxisType::AxisY) {
+            let y = margin.saturating_sub((config.font_size_axis * 2.0) as u32);
+            self.draw_axis_value(canvas, config, margin - 10, y, unit, AxisType::AxisY);
+        }
+    }
+
+    /// Adds the axis units once at the end of each axis to the SVG canvas.
+    ///
+    /// SVG counterpart of `draw_axis_units`.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The `SvgCanvas` to draw the units on.
+    /// - `config`: The `FigureConfig` containing the units.
+    /// - `font_size`: Font size of the tick labels.
+    fn draw_svg_axis_units(
+        &self,
+        svg_canvas: &mut SvgCanvas,
+        config: &FigureConfig,
+        font_size: f64,
+    ) {
+        if config.unit_placement != UnitPlacement::AxisEnd {
+            return;
+        }
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+
+        if let Some(unit) = config.unit(AxisType::AxisX) {
+            let y = height - margin + font_size * 1.5;
+            svg_canvas.draw_tick_label(width - margin / 2.0, y, unit, font_size, AxisType::AxisX);
+        }
+        if let Some(unit) = config.unit(AxisType::AxisY) {
+            let y = margin - font_size * 1.5;
+            svg_canvas.draw_tick_label(margin - 5.0, y, unit, font_size, AxisType::AxisY);
+        }
+    }
+
+    /// Draws the secondary y-axis on the right edge of the plot area, with tick marks,
+    /// tick labels and its label in the top right corner.
+    ///
+    /// Does nothing unless `config.secondary_axis` is set.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the axis on.
+    /// - `config`: The `FigureConfig` containing the secondary axis and font settings.
+    /// - `y_min`, `y_max`: The range of the primary y-axis, from the bottom to the top of the
+    ///   plot area.
+    fn draw_secondary_axis(
+        &self,
+        canvas: &mut PixelCanvas,
+        config: &FigureConfig,
+        y_min: f64,
+        y_max: f64,
+    ) {
+        let Some(axis) = &config.secondary_axis else {
+            return;
+        };
+        let font_path = config.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: config.font_size_axis,
+            y: config.font_size_axis,
+        };
+        let margin = canvas.margin as f64;
+        let right = canvas.width.saturating_sub(canvas.margin) as i32;
+        let plot_height = canvas.height as f64 - 2.0 * margin;
+        let bottom = canvas.height as f64 - margin;
+
+        self.draw_axis(canvas, config, right, margin as i32, right, bottom as i32);
+
+        let (positions, labels): (Vec<f64>, Vec<String>) = axis
+            .ticks(y_min, y_max, config.num_axis_ticks)
+            .into_iter()
+            .unzip();
+        let labels =
+            config.thin_tick_labels(AxisType::AxisY, labels, plot_height, config.font_size_axis);
+        for (position, label) in positions.into_iter().zip(labels) {
+            let y = (bottom - (position - y_min) / (y_max - y_min) * plot_height) as i32;
+            self.draw_axis(canvas, config, right, y, right + 5, y);
+            let (_, h) =
+                measure_text_with_fallbacks(&label, font_path, &config.font_fallbacks, scale.y);
+            canvas.draw_text_with_fallbacks(
+                right as u32 + 8,
+                (y as u32).saturating_sub(h / 2),
+                &label,
+                config.color_axis,
+                font_path,
+                &config.font_fallbacks,
+                scale,
+            );
+        }
+
+        self.draw_label(canvas, config, right as u32, canvas.margin / 2, &axis.label);
+    }
+
+    /// Adds the secondary y-axis on the right edge of the plot area to the SVG canvas.
+    ///
+    /// SVG counterpart of `draw_secondary_axis`; the axis label is rotated along the right
+    /// edge of the canvas.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The `SvgCanvas` to draw the axis on.
+    /// - `config`: The `FigureConfig` containing the secondary axis.
+    /// - `y_min`, `y_max`: The range of the primary y-axis.
+    /// - `font_size`: Font size of the tick labels.
+    fn draw_svg_secondary_axis(
+        &self,
+        svg_canvas: &mut SvgCanvas,
+        config: &FigureConfig,
+        y_min: f64,
+        y_max: f64,
+        font_size: f64,
+    ) {
+        let Some(axis) = &config.secondary_axis else {
+            return;
+        };
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let right = width - margin;
+        let plot_height = height - 2.0 * margin;
+
+        svg_canvas.draw_line(right, margin, right, height - margin, "black", 2.0);
+
+        let (positions, labels): (Vec<f64>, Vec<String>) = axis
+            .ticks(y_min, y_max, config.num_axis_ticks)
+            .into_iter()
+            .unzip();
+        let labels =
+            config.thin_tick_labels(AxisType::AxisY, labels, plot_height, font_size as f32);
+        for (position, label) in positions.into_iter().zip(labels) {
+            let y = height - margin - (position - y_min) / (y_max - y_min) * plot_height;
+            svg_canvas.draw_line(right, y, right + 5.0, y, "black", 1.0);
+            if !label.is_empty() {
+                svg_canvas.elements.push(format!(
+                    r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                    right + 8.0,
+                    y + font_size * 0.3,
+                    font_size,
+                    escape_xml(&label)
+                ));
             }
-            AxisType::AxisY => {
-                x = x.saturating_sub(w);
-                y = y.saturating_sub(h / 2);
+        }
+
+        let label_x = width - margin / 3.0;
+        svg_canvas.elements.push(format!(
+            r#"<text x="{label_x:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(90 {label_x:.2} {:.2})">{}</text>"#,
+            height / 2.0,
+            font_size * 1.5,
+            height / 2.0,
+            escape_xml(&axis.label)
+        ));
+    }
+
+    /// Draws a vertical color bar with its labels on a `PixelCanvas`, e.g. right of the
+    /// plot area of a heatmap.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the color bar on.
+    /// - `config`: The `FigureConfig` providing the label font, size and color.
+    /// - `x`: The left edge of the bar.
+    /// - `top`, `bottom`: The vertical extent of the bar.
+    /// - `color_at`: The color at a fraction of the height, from 0.0 at the bottom to 1.0
+    ///   at the top.
+    /// - `labels`: The labels right of the bar as `(fraction of the height from the bottom,
+    ///   text)` pairs.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_color_bar(
+        &self,
+        canvas: &mut PixelCanvas,
+        config: &FigureConfig,
+        x: f64,
+        (top, bottom): (f64, f64),
+        color_at: &dyn Fn(f64) -> [u8; 3],
+        labels: &[(f64, String)],
+    ) {
+        let bar_height = bottom - top;
+        let (x, top_row) = (x as u32, top as u32);
+        let rows = bar_height as u32;
+        for dy in 0..rows {
+            let color = color_at(1.0 - (dy as f64 + 0.5) / rows as f64);
+            for dx in 0..COLOR_BAR_WIDTH as u32 {
+                canvas.draw_pixel(x + dx, top_row + dy, color);
             }
         }
 
-        canvas.draw_text(x, y, text, config.color_axis, &font, scale);
+        let font_path = config.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: config.font_size_axis,
+            y: config.font_size_axis,
+        };
+        for (fraction, text) in labels {
+            let (_, h) =
+                measure_text_with_fallbacks(text, font_path, &config.font_fallbacks, scale.y);
+            canvas.draw_text_with_fallbacks(
+                x + COLOR_BAR_WIDTH as u32 + 5,
+                ((bottom - fraction * bar_height).max(0.0) as u32).saturating_sub(h / 2),
+                text,
+                config.color_axis,
+                font_path,
+                &config.font_fallbacks,
+                scale,
+            );
+        }
+    }
+
+    /// Adds a vertical color bar with its labels to the SVG canvas.
+    ///
+    /// SVG counterpart of `draw_color_bar`; the bar is drawn as `steps` blocks of equal
+    /// height, each filled with the color at its center.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The `SvgCanvas` to draw the color bar on.
+    /// - `config`: The `FigureConfig` providing the label color.
+    /// - `x`: The left edge of the bar.
+    /// - `top`, `bottom`: The vertical extent of the bar.
+    /// - `steps`: The number of blocks, e.g. the number of bands of a stepped scale.
+    /// - `color_at`: The color at a fraction of the height, from 0.0 at the bottom to 1.0
+    ///   at the top.
+    /// - `labels`: The labels right of the bar as `(fraction of the height from the bottom,
+    ///   text)` pairs.
+    /// - `font_size`: Font size of the labels.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_svg_color_bar(
+        &self,
+        svg_canvas: &mut SvgCanvas,
+        config: &FigureConfig,
+        x: f64,
+        (top, bottom): (f64, f64),
+        steps: usize,
+        color_at: &dyn Fn(f64) -> [u8; 3],
+        labels: &[(f64, String)],
+        font_size: f64,
+    ) {
+        let bar_height = bottom - top;
+        let steps = steps.max(1);
+        let step_height = bar_height / steps as f64;
+        for step in 0..steps {
+            let color = color_at(1.0 - (step as f64 + 0.5) / steps as f64);
+            svg_canvas.draw_rect(
+                x,
+                top + step as f64 * step_height,
+                COLOR_BAR_WIDTH,
+                step_height + 0.5,
+                &self.rgb_to_svg_color(color),
+                "none",
+                0.0,
+                1.0,
+            );
+        }
+
+        let label_color = self.rgb_to_svg_color(config.color_axis);
+        for (fraction, text) in labels {
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="{}">{}</text>"#,
+                x + COLOR_BAR_WIDTH + 5.0,
+                bottom - fraction * bar_height + font_size * 0.35,
+                font_size,
+                label_color,
+                escape_xml(text)
+            ));
+        }
+    }
+
+    /// Builds the label of a reference line, e.g. `mean 4.20`, with the value formatted like
+    /// the y-axis tick labels.
+    ///
+    /// # Parameters
+    /// - `config`: The `FigureConfig` containing the y-axis tick formatting.
+    /// - `line`: The statistic the line marks.
+    /// - `value`: The value of the statistic.
+    fn reference_line_label(
+        &self,
+        config: &FigureConfig,
+        line: ReferenceLine,
+        value: f64,
+    ) -> String {
+        let value = config.tick_label(AxisType::AxisY, value, |v| format!("{v:.2}"));
+        format!("{} {}", line.name(), value)
+    }
+
+    /// Draws a dashed reference line across the plot area, labelled at its right end.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the line on.
+    /// - `config`: The `FigureConfig` containing font settings.
+    /// - `line`: The statistic the line marks.
+    /// - `value`: The value of the statistic.
+    /// - `y`: The canvas row of the value.
+    /// - `color`: The RGB color of the line and its label.
+    fn draw_reference_line(
+        &self,
+        canvas: &mut PixelCanvas,
+        config: &FigureConfig,
+        line: ReferenceLine,
+        value: f64,
+        y: i32,
+        color: [u8; 3],
+    ) {
+        let left = canvas.margin as i32;
+        let right = canvas.width.saturating_sub(canvas.margin) as i32;
+        if y < canvas.margin as i32 || y > canvas.height.saturating_sub(canvas.margin) as i32 {
+            return;
+        }
+        canvas.draw_line(left, y, right, y, color, LineType::Dashed(6));
+
+        let font_path = config.font_label.as_ref().expect("Font path is not set");
+        let label = self.reference_line_label(config, line, value);
+        let (w, h) = measure_text_with_fallbacks(
+            &label,
+            font_path,
+            &config.font_fallbacks,
+            config.font_size_axis,
+        );
+        self.draw_text_with_halo(
+            canvas,
+            config,
+            (
+                (right as u32).saturating_sub(w + 2),
+                (y as u32).saturating_sub(h + 2),
+            ),
+            &label,
+            color,
+            PxScale {
+                x: config.font_size_axis,
+                y: config.font_size_axis,
+            },
+        );
+    }
+
+    /// Adds a dashed reference line across the plot area to the SVG canvas, labelled at its
+    /// right end.
+    ///
+    /// SVG counterpart of `draw_reference_line`.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The `SvgCanvas` to draw the line on.
+    /// - `config`: The `FigureConfig` containing the y-axis tick formatting.
+    /// - `line`: The statistic the line marks.
+    /// - `value`: The value of the statistic.
+    /// - `y`: The canvas y coordinate of the value.
+    /// - `color`: The RGB color of the line and its label.
+    /// - `font_size`: Font size of the label.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_svg_reference_line(
+        &self,
+        svg_canvas: &mut SvgCanvas,
+        config: &FigureConfig,
+        line: ReferenceLine,
+        value: f64,
+        y: f64,
+        color: [u8; 3],
+        font_size: f64,
+    ) {
+        let margin = svg_canvas.margin as f64;
+        let right = svg_canvas.width as f64 - margin;
+        if y < margin || y > svg_canvas.height as f64 - margin {
+            return;
+        }
+        svg_canvas.draw_line_rgb_styled(margin, y, right, y, color, 1.0, LineType::Dashed(6));
+        let label = self.reference_line_label(config, line, value);
+        let text_element = format!(
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="{}">{}</text>"#,
+            right - 2.0,
+            y - 4.0,
+            font_size * 0.9,
+            self.rgb_to_svg_color(color),
+            escape_xml(&label)
+        );
+        let width = label.chars().count() as f64 * font_size * 0.9 * 0.6;
+        let bounds = (
+            right - 2.0 - width,
+            y - 4.0 - font_size * 0.9 * 0.8,
+            width,
+            font_size * 0.9,
+        );
+        let element = svg_canvas.with_text_halo(text_element, bounds);
+        svg_canvas.elements.push(element);
     }
 }
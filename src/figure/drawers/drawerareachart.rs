@@ -1,17 +1,30 @@
-use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
+use ab_glyph::PxScale;
 
 use super::drawer::Drawer;
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
     figuretypes::areachart::AreaChart,
-    utilities::axistype::AxisType,
+    utilities::{
+        areafill::band_path, axistype::AxisType, scaling::tick_values,
+        textmetrics::measure_text_with_fallbacks,
+    },
 };
 use std::any::Any;
 
 impl Drawer for AreaChart {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
         let margin = svg_canvas.margin as f64;
@@ -19,8 +32,7 @@ impl Drawer for AreaChart {
         let cfg = &self.config;
 
         // Draw margin background (using SvgCanvas background_color parameter)
-        let margin_bg_color = svg_canvas.background_color.clone();
-        svg_canvas.draw_rect(0.0, 0.0, width, height, &margin_bg_color, "black", 1.0, 1.0);
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
         // Draw chart background (using FigureConfig color)
         self.fill_svg_background(svg_canvas, cfg);
 
@@ -35,16 +47,14 @@ impl Drawer for AreaChart {
 
         // Determine dataset range
         let (x_min, x_max) = self
-            .datasets
-            .iter()
+            .visible_datasets()
             .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
             .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
                 (min.min(x), max.max(x))
             });
 
         let (y_min, y_max) = self
-            .datasets
-            .iter()
+            .visible_datasets()
             .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
             .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
                 (min.min(y), max.max(y))
@@ -59,15 +69,9 @@ impl Drawer for AreaChart {
 
         // Draw grid
         let num_ticks = 10;
-        svg_canvas.draw_grid(
-            margin,
-            width - margin,
-            margin,
-            height - margin,
-            num_ticks,
-            num_ticks,
-            "lightgray",
-        );
+        let x_ticks = tick_values(margin, width - margin, num_ticks);
+        let y_ticks = tick_values(height - margin, margin, num_ticks);
+        self.draw_svg_tick_grid(svg_canvas, &self.config, &x_ticks, &y_ticks);
 
         // Draw axes
         let origin_x = margin + (0.0 - x_min) * scale_x;
@@ -78,9 +82,16 @@ impl Drawer for AreaChart {
 
         // X-axis
         let mut x_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = x_min + i as f64 * (x_max - x_min) / num_ticks as f64;
-            let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
+        let x_values = tick_values(x_min, x_max, num_ticks);
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            font_size as f32,
+        );
+        for (i, label) in x_labels.into_iter().enumerate() {
+            let x = x_ticks[i];
             let tick_start_y = origin_y - 5.0;
             let tick_end_y = origin_y + 5.0;
 
@@ -89,9 +100,13 @@ impl Drawer for AreaChart {
             ));
 
             // Draw value as text (fallback to basic SVG <text>)
-            svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{:.1}</text>"#,
-            x, height - margin + font_size * 1.5, font_size, value));
+            svg_canvas.draw_tick_label(
+                x,
+                height - margin + font_size * 1.5,
+                &label,
+                font_size,
+                AxisType::AxisX,
+            );
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{x_axis_ticks}" stroke="black" stroke-width="1" fill="none"/>"#
@@ -99,9 +114,16 @@ impl Drawer for AreaChart {
 
         // Y-axis
         let mut y_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = y_min + i as f64 * (y_max - y_min) / num_ticks as f64;
-            let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
+        let y_values = tick_values(y_min, y_max, num_ticks);
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.1}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            height - 2.0 * margin,
+            font_size as f32,
+        );
+        for (i, label) in y_labels.into_iter().enumerate() {
+            let y = y_ticks[i];
             let tick_start_x = origin_x - 5.0;
             let tick_end_x = origin_x + 5.0;
 
@@ -110,10 +132,13 @@ impl Drawer for AreaChart {
             ));
 
             // Draw value as text (fallback to basic SVG <text>)
-            svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{:.1}</text>"#,
-            margin - 5.0, y + font_size * 0.3, font_size, value
-        ));
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{y_axis_ticks}" stroke="black" stroke-width="1" fill="none"/>"#
@@ -128,39 +153,39 @@ impl Drawer for AreaChart {
         );
 
         // Draw Y-axis label (rotated)
-        svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(-90 {:.2} {:.2})">{}</text>"#,
+        svg_canvas.draw_rotated_label(
             margin / 3.0,
             height / 2.0,
+            &self.y_label,
             font_size * 1.5,
-            margin / 3.0,
-            height / 2.0,
-            self.y_label
-        ));
+            "black",
+        );
 
-        // Draw areas under the datasets
-        for dataset in &self.datasets {
-            let mut path_data = String::new();
-            let mut first_point = true;
-
-            // Move to the initial point
-            for &(x, y) in &dataset.points {
-                let svg_x = margin + (x - x_min) * scale_x;
-                let svg_y = height - margin - (y - y_min) * scale_y;
-
-                if first_point {
-                    path_data.push_str(&format!("M {svg_x:.2},{origin_y:.2} "));
-                    first_point = false;
-                }
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
 
-                path_data.push_str(&format!("L {svg_x:.2},{svg_y:.2} "));
-            }
-
-            // Close the path back to the x-axis
-            if let Some(&(last_x, _)) = dataset.points.last() {
-                let svg_x = margin + (last_x - x_min) * scale_x;
-                path_data.push_str(&format!("L {svg_x:.2},{origin_y:.2} Z"));
-            }
+        // Draw areas under the datasets
+        for dataset in self.visible_datasets() {
+            let (Some(&(first_x, _)), Some(&(last_x, _))) =
+                (dataset.points.first(), dataset.points.last())
+            else {
+                continue;
+            };
+            let line: Vec<(f64, f64)> = dataset
+                .points
+                .iter()
+                .map(|&(x, y)| {
+                    (
+                        margin + (x - x_min) * scale_x,
+                        height - margin - (y - y_min) * scale_y,
+                    )
+                })
+                .collect();
+            let axis = [
+                (margin + (first_x - x_min) * scale_x, origin_y),
+                (margin + (last_x - x_min) * scale_x, origin_y),
+            ];
+            let path_data = band_path(&line, &axis);
 
             svg_canvas.elements.push(format!(
                 r#"<path d="{}" fill="rgba({}, {}, {}, 0.5)" stroke="rgb({}, {}, {})" stroke-width="1"/>"#,
@@ -174,14 +199,45 @@ impl Drawer for AreaChart {
             ));
         }
 
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+        self.draw_svg_secondary_axis(svg_canvas, &self.config, y_min, y_max, font_size);
+
+        // Reference lines at dataset statistics
+        for dataset in self.visible_datasets() {
+            let Some(line) = dataset.reference_line else {
+                continue;
+            };
+            let Some(value) = line.value(dataset) else {
+                continue;
+            };
+            let y = height - margin - (value - y_min) * scale_y;
+            self.draw_svg_reference_line(
+                svg_canvas,
+                &self.config,
+                line,
+                value,
+                y,
+                dataset.color,
+                font_size,
+            );
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        if !self.config.show_legend {
+            return;
+        }
         // Draw legend
         let legend_x_start = margin + 10.0; // Start inside chart area with margin spacing
-        let legend_y = height - margin + font_size * 1.5 + 10.0; // Position below x-axis labels
+        let tick_overflow = self.config.x_tick_label_overflow(font_size as f32) as f64;
+        let legend_y = height - margin + font_size * 1.5 + 10.0 + tick_overflow; // Position below x-axis labels
         let mut legend_x = legend_x_start; // Reset starting position for legend items
         let mut elements = String::new();
         let legend_bg_color = svg_canvas.background_color.clone();
 
-        for dataset in &self.datasets {
+        for dataset in self.visible_datasets() {
+            let label = dataset.legend_label(&self.config.format);
             // Draw color square
             elements.push_str(&format!(
                 r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
@@ -203,11 +259,11 @@ impl Drawer for AreaChart {
                 dataset.color[0],
                 dataset.color[1],
                 dataset.color[2],
-                dataset.label
+                escape_xml(&label)
             ));
 
             // Update legend_x to position the next item
-            legend_x += font_size * 5.0 + dataset.label.len() as f64 * font_size * 0.6;
+            legend_x += font_size * 5.0 + label.len() as f64 * font_size * 0.6;
         }
 
         // Draw a background rectangle for the legend
@@ -229,9 +285,12 @@ impl Drawer for AreaChart {
     }
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
         canvas.clear();
 
         let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
         self.fill_background(canvas, cfg);
 
         let margin = canvas.margin;
@@ -243,16 +302,14 @@ impl Drawer for AreaChart {
 
         // Calculate dataset limits
         let (x_min, x_max) = self
-            .datasets
-            .iter()
+            .visible_datasets()
             .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
             .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
                 (min.min(x), max.max(x))
             });
 
         let (y_min, y_max) = self
-            .datasets
-            .iter()
+            .visible_datasets()
             .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
             .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
                 (min.min(y), max.max(y))
@@ -266,12 +323,6 @@ impl Drawer for AreaChart {
         let scale_x = (width - 2 * margin) as f64 / (x_max - x_min);
         let scale_y = (height - 2 * margin) as f64 / (y_max - y_min);
 
-        // Draw grids
-        canvas.draw_grid(
-            &[cfg.num_grid_horizontal, cfg.num_grid_vertical],
-            cfg.color_grid,
-        );
-
         // Draw axes
         let origin_x = canvas.margin + ((0.0 - x_min) * scale_x) as u32;
         let origin_y = height - margin - ((0.0 - y_min) * scale_y) as u32;
@@ -279,38 +330,56 @@ impl Drawer for AreaChart {
         self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.x_label);
         self.draw_label(canvas, cfg, margin, margin / 2, &self.y_label);
 
-        // Draw axis tick values
+        // Tick values and their pixel positions, shared by the grid and the tick labels
         let num_ticks = cfg.num_axis_ticks;
+        let x_values = tick_values(x_min, x_max, num_ticks);
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.2}"));
+        let x_ticks: Vec<(String, u32)> = cfg
+            .thin_tick_labels(
+                AxisType::AxisX,
+                x_labels,
+                (x_max - x_min) * scale_x,
+                cfg.font_size_axis,
+            )
+            .into_iter()
+            .zip(&x_values)
+            .map(|(label, &value)| (label, origin_x + ((value - x_min) * scale_x) as u32))
+            .collect();
+        let y_values = tick_values(y_min, y_max, num_ticks);
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.2}"));
+        let y_ticks: Vec<(String, u32)> = cfg
+            .thin_tick_labels(
+                AxisType::AxisY,
+                y_labels,
+                (y_max - y_min) * scale_y,
+                cfg.font_size_axis,
+            )
+            .into_iter()
+            .zip(&y_values)
+            .map(|(label, &value)| (label, origin_y - ((value - y_min) * scale_y) as u32))
+            .collect();
+        let positions = |ticks: &[(String, u32)]| -> Vec<f64> {
+            ticks.iter().map(|(_, position)| *position as f64).collect()
+        };
 
-        // X-axis ticks
-        let x_tick_step = (x_max - x_min) / num_ticks as f64;
-        for i in 0..=num_ticks {
-            let value_x = x_min + i as f64 * x_tick_step;
-            let tick_x = origin_x + ((value_x - x_min) * scale_x) as u32;
+        // Draw grids
+        self.draw_tick_grid(canvas, cfg, &positions(&x_ticks), &positions(&y_ticks));
 
-            let value_label = format!("{value_x:.2}");
-            self.draw_axis_value(canvas, cfg, tick_x, origin_y, &value_label, AxisType::AxisX);
+        // X-axis ticks
+        for (label, tick_x) in &x_ticks {
+            self.draw_axis_value(canvas, cfg, *tick_x, origin_y, label, AxisType::AxisX);
         }
 
         // Y-axis ticks
-        let y_tick_step = (y_max - y_min) / num_ticks as f64;
-        for i in 0..=num_ticks {
-            let value_y = y_min + i as f64 * y_tick_step;
-            let tick_y = origin_y - ((value_y - y_min) * scale_y) as u32;
-
-            let value_label = format!("{value_y:.2}");
-            self.draw_axis_value(
-                canvas,
-                cfg,
-                origin_x - 10,
-                tick_y,
-                &value_label,
-                AxisType::AxisY,
-            );
+        for (label, tick_y) in &y_ticks {
+            self.draw_axis_value(canvas, cfg, origin_x - 10, *tick_y, label, AxisType::AxisY);
         }
 
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
         // Draw areas under the curves
-        for dataset in &self.datasets {
+        for dataset in self.visible_datasets() {
             self.draw_area(
                 canvas,
                 dataset,
@@ -326,8 +395,26 @@ impl Drawer for AreaChart {
         canvas.draw_horizontal_line(canvas.height - canvas.margin, [0, 0, 0]);
         canvas.draw_horizontal_line(canvas.margin, [0, 0, 0]);
 
+        // Reference lines at dataset statistics
+        for dataset in self.visible_datasets() {
+            let Some(line) = dataset.reference_line else {
+                continue;
+            };
+            let Some(value) = line.value(dataset) else {
+                continue;
+            };
+            let y = origin_y as i32 - (value * scale_y) as i32;
+            self.draw_reference_line(canvas, &self.config, line, value, y, dataset.color);
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
         // Draw legend
-        self.draw_legend(canvas);
+        self.draw_axis_units(canvas, &self.config);
+        self.draw_secondary_axis(canvas, &self.config, y_min, y_max);
+        if self.config.show_legend {
+            self.draw_legend(canvas);
+        }
     }
 
     fn draw_legend(&self, canvas: &mut PixelCanvas) {
@@ -336,20 +423,31 @@ impl Drawer for AreaChart {
             .font_label
             .as_ref()
             .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
-        let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
-
-        let square_size = 10; // Size of the colored square
-        let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+        let line_height = square_size * 2; // Vertical space for each legend entry
         let legend_margin = canvas.margin; // Margin from the bottom of the canvas
 
         let mut x = canvas.margin;
-        let mut y = canvas.height - legend_margin; // Legend starts from the bottom
-
-        for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.label);
+        let tick_overflow = self
+            .config
+            .x_tick_label_overflow(self.config.font_size_axis) as u32;
+        let mut y = canvas.height - legend_margin + tick_overflow; // Legend starts from the bottom
+
+        for dataset in self.visible_datasets() {
+            let label = dataset.legend_label(&self.config.format);
+            let (w, h) = measure_text_with_fallbacks(
+                &label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
             // Draw the square
             for dy in 0..square_size {
                 for dx in 0..square_size {
@@ -363,12 +461,13 @@ impl Drawer for AreaChart {
 
             // Draw the label text next to the square
             let text_x: u32 = x + square_size + padding;
-            canvas.draw_text(
+            canvas.draw_text_with_fallbacks(
                 text_x,
                 y + 2 * square_size + h,
-                &dataset.label,
+                &label,
                 dataset.color,
-                &font,
+                font_path,
+                &self.config.font_fallbacks,
                 scale,
             );
 
@@ -389,4 +488,14 @@ impl Drawer for AreaChart {
     fn get_figure_config(&self) -> &FigureConfig {
         &self.config
     }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+
+    fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        self.visible_datasets()
+            .map(|dataset| (dataset.legend_label(&self.config.format), dataset.color))
+            .collect()
+    }
 }
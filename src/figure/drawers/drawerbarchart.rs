@@ -1,27 +1,38 @@
 use super::drawer::Drawer;
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
     configuration::figureconfig::FigureConfig,
     figuretypes::groupbarchart::GroupBarChart,
-    utilities::orientation::Orientation,
+    utilities::{
+        axistype::AxisType,
+        colormap::text_color_for,
+        orientation::Orientation,
+        scaling::{scale_factor, tick_values, to_pixel_x, to_pixel_y},
+        textmetrics::measure_text_with_fallbacks,
+    },
 };
-use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
+use ab_glyph::PxScale;
 use std::any::Any;
 
 impl Drawer for GroupBarChart {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
         let margin = svg_canvas.margin as f64;
         let font_size = 12.0;
         let cfg = &self.config;
-        let margin_bg_color = svg_canvas.background_color.clone();
 
         match self.orientation {
             Orientation::Vertical => {
                 // Draw margin background (using SvgCanvas background_color parameter)
-                svg_canvas.draw_rect(0.0, 0.0, width, height, &margin_bg_color, "black", 1.0, 1.0);
+                self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
                 // Draw chart background (using FigureConfig color)
                 self.fill_svg_background(svg_canvas, cfg);
 
@@ -36,35 +47,31 @@ impl Drawer for GroupBarChart {
 
                 // Calculate unique axis values
                 let unique_x_values: Vec<u32> = self
-                    .datasets
-                    .iter()
-                    .flat_map(|dataset| dataset.data.iter().map(|(x, _)| *x as u32))
+                    .visible_datasets()
+                    .flat_map(|(_, dataset)| dataset.data.iter().map(|(x, _)| *x as u32))
                     .collect::<std::collections::BTreeSet<_>>()
                     .into_iter()
                     .collect();
 
                 let x_count = unique_x_values.len();
 
-                let y_max = self
-                    .datasets
-                    .iter()
-                    .flat_map(|dataset| dataset.data.iter().map(|(_, y)| *y))
-                    .fold(0.0_f64, |max, y| max.max(y));
+                let (y_min, y_max) = self.value_range();
 
                 // Calculate scales
                 let scale_x = (width - 2.0 * margin) / x_count as f64;
-                let scale_y = (height - 2.0 * margin) / y_max;
+                let scale_y = scale_factor(y_min, y_max, height - 2.0 * margin);
+                let to_y = |value| to_pixel_y(value, y_min, scale_y, height, margin);
+                let base_y = to_y(self.baseline());
 
-                // Draw grid
-                svg_canvas.draw_grid(
-                    margin,
-                    width - margin,
-                    margin,
-                    height - margin,
-                    10,
-                    10,
-                    "lightgray",
-                );
+                // Draw grid: value lines at the y ticks, category lines between the groups
+                let num_ticks = 10;
+                let x_ticks: Vec<f64> =
+                    (0..=x_count).map(|i| margin + i as f64 * scale_x).collect();
+                let y_ticks: Vec<f64> = tick_values(y_min, y_max, num_ticks)
+                    .into_iter()
+                    .map(to_y)
+                    .collect();
+                self.draw_svg_tick_grid(svg_canvas, cfg, &x_ticks, &y_ticks);
 
                 // Draw axes
                 let origin_x = margin;
@@ -74,11 +81,17 @@ impl Drawer for GroupBarChart {
                 svg_canvas.draw_line(origin_x, origin_y, width - margin, origin_y, "black", 2.0); // X-axis
 
                 // Y-axis
-                let num_ticks = 10;
                 let mut y_axis_ticks = String::new();
-                for i in 0..=num_ticks {
-                    let value = 0.0 + i as f64 * (y_max - 0.0) / num_ticks as f64;
-                    let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
+                let y_values = tick_values(y_min, y_max, num_ticks);
+                let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.1}"));
+                let y_labels = cfg.thin_tick_labels(
+                    AxisType::AxisY,
+                    y_labels,
+                    height - 2.0 * margin,
+                    font_size as f32,
+                );
+                for (&value, label) in y_values.iter().zip(y_labels) {
+                    let y = to_y(value);
                     let tick_start_x = origin_x - 5.0;
                     let tick_end_x = origin_x + 5.0;
 
@@ -87,18 +100,25 @@ impl Drawer for GroupBarChart {
                     ));
 
                     // Draw value as text (fallback to basic SVG <text>)
-                    svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{:.1}</text>"#,
-            margin - 10.0, y + font_size * 0.3, font_size, value
-        ));
+                    svg_canvas.draw_tick_label(
+                        margin - 10.0,
+                        y + font_size * 0.3,
+                        &label,
+                        font_size,
+                        AxisType::AxisY,
+                    );
                 }
                 svg_canvas.elements.push(format!(
                     r#"<path d="{y_axis_ticks}" stroke="black" stroke-width="1" fill="none"/>"#
                 ));
 
-                // Draw X-axis labels and grouped bars
+                self.config
+                    .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+                // Draw X-axis labels and grouped bars; value labels go on top of all bars
+                let mut value_labels = Vec::new();
                 let group_width = scale_x * 0.8; // Width of each group of bars
-                let bar_width = group_width / self.datasets.len() as f64; // Width of each bar
+                let bar_width = group_width / self.bar_slots() as f64; // Width of each bar
 
                 for (group_index, x_label) in unique_x_values.iter().enumerate() {
                     let group_center_x = origin_x + (group_index as f64 + 0.4) * scale_x;
@@ -114,30 +134,61 @@ impl Drawer for GroupBarChart {
                     );
 
                     // Draw bars for each dataset in the group
-                    for (dataset_index, dataset) in self.datasets.iter().enumerate() {
-                        if let Some(&(_, value)) = dataset
+                    for (dataset_index, dataset) in self.visible_datasets() {
+                        if let Some(&(category, value)) = dataset
                             .data
                             .iter()
                             .find(|(x, _)| (*x as u32).to_string() == x_label.to_string())
                         {
-                            let bar_height = value * scale_y;
+                            let link = dataset
+                                .link(category)
+                                .or_else(|| self.config.series_link(&dataset.label));
+                            if let Some(url) = link {
+                                svg_canvas.begin_link(url);
+                            }
+                            let span = self.bar_span(dataset_index, *x_label, value);
+                            let (start_y, value_y) = (to_y(span.0), to_y(span.1));
                             let bar_left = group_center_x - group_width / 2.0
-                                + dataset_index as f64 * bar_width;
+                                + self.bar_slot(dataset_index) as f64 * bar_width;
+                            let color = self.bar_color(dataset, value);
 
                             // Draw bar
                             svg_canvas.elements.push(format!(
                         r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})" stroke="black" stroke-width="1"/>"#,
                         bar_left,
-                        origin_y - bar_height,
+                        value_y.min(start_y),
                         bar_width,
-                        bar_height,
-                        dataset.color[0],
-                        dataset.color[1],
-                        dataset.color[2]
+                        (value_y - start_y).abs(),
+                        color[0],
+                        color[1],
+                        color[2]
                     ));
+                            if link.is_some() {
+                                svg_canvas.end_link();
+                            }
+
+                            let bar = (
+                                bar_left,
+                                value_y.min(start_y),
+                                bar_left + bar_width,
+                                value_y.max(start_y),
+                            );
+                            value_labels.extend(svg_value_label(
+                                self,
+                                svg_canvas,
+                                bar,
+                                self.segment_value(span),
+                                color,
+                                font_size,
+                            ));
                         }
                     }
                 }
+                svg_canvas.elements.extend(value_labels);
+
+                if self.diverging.is_some() {
+                    svg_canvas.draw_line(margin, base_y, width - margin, base_y, "black", 1.0);
+                }
             }
             Orientation::Horizontal => {
                 let width = svg_canvas.width as f64;
@@ -146,7 +197,7 @@ impl Drawer for GroupBarChart {
                 let font_size = 12.0;
 
                 // Draw margin background (using SvgCanvas background_color parameter)
-                svg_canvas.draw_rect(0.0, 0.0, width, height, &margin_bg_color, "black", 1.0, 1.0);
+                self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
                 // Draw chart background (using FigureConfig color)
                 self.fill_svg_background(svg_canvas, cfg);
 
@@ -161,124 +212,231 @@ impl Drawer for GroupBarChart {
 
                 // Calculate unique axis values
                 let unique_y_values: Vec<u32> = self
-                    .datasets
-                    .iter()
-                    .flat_map(|dataset| dataset.data.iter().map(|(y, _)| *y as u32))
+                    .visible_datasets()
+                    .flat_map(|(_, dataset)| dataset.data.iter().map(|(y, _)| *y as u32))
                     .collect::<std::collections::BTreeSet<_>>()
                     .into_iter()
                     .collect();
 
                 let y_count = unique_y_values.len();
 
-                let x_max = self
-                    .datasets
-                    .iter()
-                    .flat_map(|dataset| dataset.data.iter().map(|(_, x)| *x))
-                    .fold(0.0_f64, |max, x| max.max(x));
+                let (x_min, x_max) = self.value_range();
 
                 // Calculate scales
                 let scale_y = (height - 2.0 * margin) / y_count as f64;
-                let scale_x = (width - 2.0 * margin) / x_max;
+                let scale_x = scale_factor(x_min, x_max, width - 2.0 * margin);
+                let base_x = to_pixel_x(self.baseline(), x_min, scale_x, margin);
 
-                // Draw grid
-                svg_canvas.draw_grid(
-                    margin,
-                    width - margin,
-                    margin,
-                    height - margin,
-                    10,
-                    10,
-                    "lightgray",
-                );
+                // Draw grid: value lines at the x ticks, category lines between the groups
+                let num_ticks = 10;
+                let x_ticks: Vec<f64> = tick_values(x_min, x_max, num_ticks)
+                    .into_iter()
+                    .map(|value| to_pixel_x(value, x_min, scale_x, margin))
+                    .collect();
+                let y_ticks: Vec<f64> =
+                    (0..=y_count).map(|i| margin + i as f64 * scale_y).collect();
+                self.draw_svg_tick_grid(svg_canvas, cfg, &x_ticks, &y_ticks);
 
                 // Draw axes
                 let origin_x = margin;
                 let origin_y = height - margin;
 
                 svg_canvas.draw_line(origin_x, margin, origin_x, origin_y, "black", 2.0); // Y-axis
-                svg_canvas.draw_line(origin_x, origin_y, width - margin, origin_y, "black", 2.0); // X-axis
-
-                // Draw X-axis tick marks and labels
-                let num_ticks = 10;
-                let x_tick_step = x_max / num_ticks as f64;
-                for i in 0..=num_ticks {
-                    let value_x = i as f64 * x_tick_step;
-                    let tick_x = origin_x + (value_x * scale_x);
-                    let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
+                let value_axis_y = if self.value_axis_top {
+                    margin
+                } else {
+                    origin_y
+                };
+                svg_canvas.draw_line(
+                    origin_x,
+                    value_axis_y,
+                    width - margin,
+                    value_axis_y,
+                    "black",
+                    2.0,
+                ); // X-axis
+
+                // Draw X-axis tick marks and labels, below the plot or above it
+                let (tick_direction, tick_label_y) = if self.value_axis_top {
+                    let overflow = cfg.x_tick_label_overflow(font_size as f32) as f64;
+                    (-1.0, margin - font_size * 0.6 - overflow)
+                } else {
+                    (1.0, origin_y + font_size * 1.5)
+                };
+                let x_values = tick_values(x_min, x_max, num_ticks);
+                let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+                let x_labels = cfg.thin_tick_labels(
+                    AxisType::AxisX,
+                    x_labels,
+                    width - 2.0 * margin,
+                    font_size as f32,
+                );
+                for (&value_x, label) in x_values.iter().zip(x_labels) {
+                    let tick_x = to_pixel_x(value_x, x_min, scale_x, margin);
                     // Draw tick line
-                    svg_canvas.draw_line(tick_x, origin_y, tick_x, origin_y + 5.0, "black", 1.0);
+                    svg_canvas.draw_line(
+                        tick_x,
+                        value_axis_y,
+                        tick_x,
+                        value_axis_y + tick_direction * 5.0,
+                        "black",
+                        1.0,
+                    );
 
                     // Draw tick label
-                    svg_canvas.draw_text(
-                        x,
-                        origin_y + font_size * 1.5,
-                        &format!("{value_x:.1}"),
+                    svg_canvas.draw_tick_label(
+                        tick_x,
+                        tick_label_y,
+                        &label,
                         font_size,
-                        "black",
+                        AxisType::AxisX,
                     );
                 }
 
-                // Draw Y-axis labels and grouped bars
+                self.config
+                    .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+                // Draw Y-axis labels and grouped bars; value labels go on top of all bars
+                let mut value_labels = Vec::new();
                 let group_height = scale_y * 0.8; // Height of each group of bars
-                let bar_height = group_height / self.datasets.len() as f64; // Height of each bar
+                let bar_height = group_height / self.bar_slots() as f64; // Height of each bar
+                let label_column_x = (origin_x
+                    - 10.0
+                    - unique_y_values
+                        .iter()
+                        .map(|y_label| y_label.to_string().chars().count())
+                        .max()
+                        .unwrap_or(0) as f64
+                        * font_size
+                        * 0.6)
+                    .max(0.0);
 
                 for (group_index, y_label) in unique_y_values.iter().enumerate() {
                     let group_center_y = origin_y - (group_index as f64 + 0.5) * scale_y;
 
-                    // Draw Y-axis label
-                    svg_canvas.draw_text(
-                        origin_x - font_size * 3.0,
-                        group_center_y,
-                        &y_label.to_string(),
-                        font_size,
-                        "black",
-                    );
+                    // Draw Y-axis label, left-aligned in a column when the value axis is on top
+                    if self.value_axis_top {
+                        let text = y_label.to_string();
+                        let element = format!(
+                            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="start" fill="black">{}</text>"#,
+                            label_column_x,
+                            group_center_y + font_size * 0.35,
+                            font_size,
+                            text
+                        );
+                        let label_width = text.chars().count() as f64 * font_size * 0.6;
+                        let element = svg_canvas.with_text_halo(
+                            element,
+                            (
+                                label_column_x,
+                                group_center_y - font_size * 0.45,
+                                label_width,
+                                font_size,
+                            ),
+                        );
+                        svg_canvas.elements.push(element);
+                    } else {
+                        svg_canvas.draw_text(
+                            origin_x - font_size * 3.0,
+                            group_center_y,
+                            &y_label.to_string(),
+                            font_size,
+                            "black",
+                        );
+                    }
 
                     // Draw bars for each dataset in the group
-                    for (dataset_index, dataset) in self.datasets.iter().enumerate() {
-                        if let Some(&(_, value)) = dataset
+                    for (dataset_index, dataset) in self.visible_datasets() {
+                        if let Some(&(category, value)) = dataset
                             .data
                             .iter()
                             .find(|(y, _)| (*y as u32).to_string() == y_label.to_string())
                         {
-                            let bar_length = value * scale_x;
+                            let link = dataset
+                                .link(category)
+                                .or_else(|| self.config.series_link(&dataset.label));
+                            if let Some(url) = link {
+                                svg_canvas.begin_link(url);
+                            }
+                            let span = self.bar_span(dataset_index, *y_label, value);
+                            let start_x = to_pixel_x(span.0, x_min, scale_x, margin);
+                            let value_x = to_pixel_x(span.1, x_min, scale_x, margin);
                             let bar_top = group_center_y - group_height / 2.0
-                                + dataset_index as f64 * bar_height;
+                                + self.bar_slot(dataset_index) as f64 * bar_height;
+                            let color = self.bar_color(dataset, value);
 
                             // Draw bar
                             svg_canvas.elements.push(format!(
                         r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})" stroke="black" stroke-width="1"/>"#,
-                        origin_x,
+                        value_x.min(start_x),
                         bar_top,
-                        bar_length,
+                        (value_x - start_x).abs(),
                         bar_height,
-                        dataset.color[0],
-                        dataset.color[1],
-                        dataset.color[2]
+                        color[0],
+                        color[1],
+                        color[2]
                     ));
+                            if link.is_some() {
+                                svg_canvas.end_link();
+                            }
+
+                            let bar = (
+                                value_x.min(start_x),
+                                bar_top,
+                                value_x.max(start_x),
+                                bar_top + bar_height,
+                            );
+                            value_labels.extend(svg_value_label(
+                                self,
+                                svg_canvas,
+                                bar,
+                                self.segment_value(span),
+                                color,
+                                font_size,
+                            ));
                         }
                     }
                 }
+                svg_canvas.elements.extend(value_labels);
+
+                if self.diverging.is_some() {
+                    svg_canvas.draw_line(base_x, margin, base_x, height - margin, "black", 1.0);
+                }
             }
         }
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+
+        if !self.config.show_legend {
+            return;
+        }
         // Draw legend in the bottom-left corner
         let legend_x_start = margin + 10.0; // Start inside chart area with margin spacing
-        let legend_y = height - margin + font_size * 1.5 + 10.0; // Position below x-axis labels
+
+        // Horizontal bars carry formatted value ticks on the x-axis
+        let tick_overflow = match self.orientation {
+            Orientation::Horizontal if !self.value_axis_top => {
+                self.config.x_tick_label_overflow(font_size as f32) as f64
+            }
+            _ => 0.0,
+        };
+        // Position below x-axis labels
+        let legend_y = height - margin + font_size * 1.5 + 10.0 + tick_overflow;
 
         let mut legend_x = legend_x_start;
         let mut elements = String::new();
         let legend_bg_color = svg_canvas.background_color.clone();
 
-        for dataset in &self.datasets {
+        for (label, color) in self.legend_entries() {
+            let link = self.config.series_link(&label);
+            if let Some(url) = link {
+                elements.push_str(&SvgCanvas::link_open(url));
+            }
             elements.push_str(&format!(
                 r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
-                legend_x,
-                legend_y,
-                font_size,
-                font_size,
-                dataset.color[0],
-                dataset.color[1],
-                dataset.color[2]
+                legend_x, legend_y, font_size, font_size, color[0], color[1], color[2]
             ));
 
             elements.push_str(&format!(
@@ -286,10 +444,13 @@ impl Drawer for GroupBarChart {
                 legend_x + font_size * 1.3,
                 legend_y + font_size - 2.0,
                 font_size,
-                dataset.label
+                label
             ));
+            if link.is_some() {
+                elements.push_str("</a>");
+            }
 
-            legend_x += font_size * 5.0 + dataset.label.len() as f64 * font_size * 0.6;
+            legend_x += font_size * 5.0 + label.len() as f64 * font_size * 0.6;
         }
 
         svg_canvas.draw_rect(
@@ -307,6 +468,8 @@ impl Drawer for GroupBarChart {
     }
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
         match self.orientation {
             Orientation::Vertical => self.draw_vertical(canvas),
             Orientation::Horizontal => self.draw_horizontal(canvas),
@@ -319,39 +482,55 @@ impl Drawer for GroupBarChart {
             .font_label
             .as_ref()
             .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
-        let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
-
-        let square_size = 10; // Size of the colored square
-        let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+        let line_height = square_size * 2; // Vertical space for each legend entry
         let legend_margin = canvas.margin; // Margin from the bottom of the canvas
 
+        let tick_overflow = match self.orientation {
+            Orientation::Horizontal if !self.value_axis_top => {
+                self.config
+                    .x_tick_label_overflow(self.config.font_size_axis) as u32
+            }
+            _ => 0,
+        };
+
         let mut x = canvas.margin;
-        let mut y = canvas.height - legend_margin; // Legend starts from the bottom
+        let mut y = canvas.height - legend_margin + tick_overflow; // Legend starts from the bottom
 
-        for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.label);
+        for (label, color) in self.legend_entries() {
+            let (w, h) = measure_text_with_fallbacks(
+                &label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
             // Draw the square
             for dy in 0..square_size {
                 for dx in 0..square_size {
                     canvas.draw_pixel(
                         x + dx,
                         y + square_size * 2 + dy + h, // Adjust to align above baseline
-                        dataset.color,
+                        color,
                     );
                 }
             }
 
             // Draw the label text next to the square
             let text_x: u32 = x + square_size + padding;
-            canvas.draw_text(
+            canvas.draw_text_with_fallbacks(
                 text_x,
                 y + 2 * square_size + h,
-                &dataset.label,
-                dataset.color,
-                &font,
+                &label,
+                color,
+                font_path,
+                &self.config.font_fallbacks,
                 scale,
             );
 
@@ -372,4 +551,65 @@ impl Drawer for GroupBarChart {
     fn get_figure_config(&self) -> &FigureConfig {
         &self.config
     }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+
+    fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        GroupBarChart::legend_entries(self)
+    }
+}
+
+/// Builds the SVG element of a bar's value label, if value labels are enabled.
+///
+/// The label size is estimated from the font size, as SVG text is measured by the viewer.
+/// The label gets the text halo of the canvas, if any.
+fn svg_value_label(
+    chart: &GroupBarChart,
+    svg_canvas: &SvgCanvas,
+    bar: (f64, f64, f64, f64),
+    value: f64,
+    bar_color: [u8; 3],
+    font_size: f64,
+) -> Option<String> {
+    chart.value_label_position()?;
+
+    let text = chart.value_label_text(value);
+    let (w, h) = (text.chars().count() as f64 * font_size * 0.6, font_size);
+    let label_size = if chart.rotate_value_labels {
+        (h, w)
+    } else {
+        (w, h)
+    };
+    let ((x, y), inside) = chart.value_label_placement(bar, value, label_size);
+    let color = if inside {
+        text_color_for(
+            chart
+                .config
+                .text_halo
+                .map_or(bar_color, |halo| halo.color()),
+        )
+    } else {
+        chart.config.color_axis
+    };
+
+    let (cx, cy) = (x + label_size.0 / 2.0, y + label_size.1 / 2.0);
+    let transform = if chart.rotate_value_labels {
+        format!(r#" transform="rotate(-90 {cx:.2} {cy:.2})""#)
+    } else {
+        String::new()
+    };
+    let element = format!(
+        r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="rgb({},{},{})"{}>{}</text>"#,
+        cx,
+        cy + font_size * 0.35,
+        font_size,
+        color[0],
+        color[1],
+        color[2],
+        transform,
+        text
+    );
+    Some(svg_canvas.with_text_halo(element, (x, y, label_size.0, label_size.1)))
 }
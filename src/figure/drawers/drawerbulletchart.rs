@@ -0,0 +1,296 @@
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::bulletchart::{BulletChart, BulletLayout},
+    utilities::{
+        axistype::AxisType, bezierpath::BezierPath, linetype::LineType, orientation::Orientation,
+        scaling::tick_values,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Length in pixels of the tick marks outside the bands.
+const TICK_LENGTH: f64 = 5.0;
+
+impl BulletChart {
+    /// Returns the axis the values are measured along.
+    fn value_axis(&self) -> AxisType {
+        match self.orientation {
+            Orientation::Horizontal => AxisType::AxisX,
+            Orientation::Vertical => AxisType::AxisY,
+        }
+    }
+
+    /// Computes the ticks of the scale as `(value, canvas coordinate)` pairs, the
+    /// coordinate being along the value axis.
+    fn scale_ticks(&self, layout: &BulletLayout) -> Vec<(f64, f64)> {
+        let (min, max) = self.scale_range();
+        tick_values(min, max, self.config.num_axis_ticks)
+            .into_iter()
+            .filter(|value| (min..=max).contains(value))
+            .map(|value| (value, self.value_position(value, layout)))
+            .collect()
+    }
+
+    /// Computes the tick marks outside the bands, as line end points.
+    fn tick_marks(&self, layout: &BulletLayout) -> Vec<((f64, f64), (f64, f64))> {
+        let (c0, c1) = layout.across;
+        self.scale_ticks(layout)
+            .into_iter()
+            .map(|(_, p)| match self.orientation {
+                Orientation::Horizontal => ((p, c1), (p, c1 + TICK_LENGTH)),
+                Orientation::Vertical => ((c0 - TICK_LENGTH, p), (c0, p)),
+            })
+            .collect()
+    }
+}
+
+/// Builds the outline of a rectangle given as `(x, y, width, height)`.
+fn rect_path((x, y, w, h): (f64, f64, f64, f64)) -> BezierPath {
+    BezierPath::rectangle(x, y, w, h)
+}
+
+impl Drawer for BulletChart {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let layout = self.layout(width, height, margin);
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Ranges, then the bar and the marker over them
+        for (from, to, range) in self.range_spans() {
+            svg_canvas.draw_path(
+                &rect_path(self.span_rect(from, to, 1.0, &layout)),
+                &self.rgb_to_svg_color(range.color),
+                "none",
+                0.0,
+                1.0,
+                Some(&self.range_tooltip(from, to, range)),
+            );
+        }
+        let measure = format!("{}: {}", self.label, self.format_value(self.value));
+        svg_canvas.draw_path(
+            &rect_path(self.measure_rect(&layout)),
+            &self.rgb_to_svg_color(self.measure_color),
+            "none",
+            0.0,
+            1.0,
+            Some(&measure),
+        );
+        if let (Some(rect), Some(tooltip)) = (self.target_rect(&layout), self.target_tooltip()) {
+            svg_canvas.draw_path(
+                &rect_path(rect),
+                &self.rgb_to_svg_color(self.target_color),
+                "none",
+                0.0,
+                1.0,
+                Some(&tooltip),
+            );
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Scale ticks outside the bands, and the label of the measure
+        for ((x1, y1), (x2, y2)) in self.tick_marks(&layout) {
+            svg_canvas.draw_line(x1, y1, x2, y2, "black", 1.0);
+        }
+        let axis = self.value_axis();
+        let ticks = self.scale_ticks(&layout);
+        let values: Vec<f64> = ticks.iter().map(|&(value, _)| value).collect();
+        let labels = cfg.tick_labels(axis, &values, |v| format!("{v:.*}", self.precision));
+        let labels = cfg.thin_tick_labels(
+            axis,
+            labels,
+            (layout.end - layout.start).abs(),
+            font_size as f32,
+        );
+        let (c0, c1) = layout.across;
+        let middle = (c0 + c1) / 2.0;
+        match self.orientation {
+            Orientation::Horizontal => {
+                for (&(_, x), label) in ticks.iter().zip(&labels) {
+                    svg_canvas.draw_tick_label(
+                        x,
+                        c1 + TICK_LENGTH + font_size * 1.2,
+                        label,
+                        font_size,
+                        axis,
+                    );
+                }
+                self.draw_svg_category_label(
+                    svg_canvas,
+                    cfg,
+                    layout.start - 10.0,
+                    middle + font_size * 0.3,
+                    &self.label,
+                    font_size,
+                    AxisType::AxisY,
+                );
+            }
+            Orientation::Vertical => {
+                for (&(_, y), label) in ticks.iter().zip(&labels) {
+                    svg_canvas.draw_tick_label(
+                        c0 - TICK_LENGTH - 3.0,
+                        y + font_size * 0.3,
+                        label,
+                        font_size,
+                        axis,
+                    );
+                }
+                self.draw_svg_category_label(
+                    svg_canvas,
+                    cfg,
+                    middle,
+                    layout.start + font_size * 1.5,
+                    &self.label,
+                    font_size,
+                    AxisType::AxisX,
+                );
+            }
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let layout = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Ranges, then the bar and the marker over them
+        for (from, to, range) in self.range_spans() {
+            canvas.fill_bezier_path(
+                &rect_path(self.span_rect(from, to, 1.0, &layout)),
+                range.color,
+            );
+        }
+        canvas.fill_bezier_path(&rect_path(self.measure_rect(&layout)), self.measure_color);
+        if let Some(rect) = self.target_rect(&layout) {
+            canvas.fill_bezier_path(&rect_path(rect), self.target_color);
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Scale ticks outside the bands, and the label of the measure
+        for ((x1, y1), (x2, y2)) in self.tick_marks(&layout) {
+            canvas.draw_line(
+                x1.round() as i32,
+                y1.round() as i32,
+                x2.round() as i32,
+                y2.round() as i32,
+                cfg.color_axis,
+                LineType::Solid,
+            );
+        }
+        let axis = self.value_axis();
+        let ticks = self.scale_ticks(&layout);
+        let values: Vec<f64> = ticks.iter().map(|&(value, _)| value).collect();
+        let labels = cfg.tick_labels(axis, &values, |v| format!("{v:.*}", self.precision));
+        let labels = cfg.thin_tick_labels(
+            axis,
+            labels,
+            (layout.end - layout.start).abs(),
+            cfg.font_size_axis,
+        );
+        let (c0, c1) = layout.across;
+        let middle = (c0 + c1) / 2.0;
+        match self.orientation {
+            Orientation::Horizontal => {
+                for (&(_, x), label) in ticks.iter().zip(&labels) {
+                    self.draw_axis_value(canvas, cfg, x as u32, c1 as u32, label, axis);
+                }
+                self.draw_category_label(
+                    canvas,
+                    cfg,
+                    (layout.start - 10.0).max(0.0) as u32,
+                    middle as u32,
+                    &self.label,
+                    AxisType::AxisY,
+                );
+            }
+            Orientation::Vertical => {
+                for (&(_, y), label) in ticks.iter().zip(&labels) {
+                    self.draw_axis_value(
+                        canvas,
+                        cfg,
+                        (c0 - TICK_LENGTH - 3.0).max(0.0) as u32,
+                        y as u32,
+                        label,
+                        axis,
+                    );
+                }
+                self.draw_category_label(
+                    canvas,
+                    cfg,
+                    middle as u32,
+                    layout.start as u32,
+                    &self.label,
+                    AxisType::AxisX,
+                );
+            }
+        }
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // Ranges are explained by their tooltips, so no legend is drawn
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
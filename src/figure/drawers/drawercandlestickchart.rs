@@ -0,0 +1,625 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::candlestickchart::CandlestickChart,
+    utilities::{
+        axistype::AxisType,
+        linetype::LineType,
+        scaling::{scale_factor, tick_values, to_pixel_x},
+        textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Opacity of the Bollinger band ribbon.
+const BAND_OPACITY: f64 = 0.15;
+
+/// Opacity of the volume bars.
+const VOLUME_OPACITY: f64 = 0.6;
+
+impl CandlestickChart {
+    /// Returns half the body width in pixels, at least one pixel.
+    fn half_body(&self, scale_x: f64) -> f64 {
+        (self.body_width.clamp(0.0, 1.0) * self.candle_spacing() * scale_x / 2.0).max(1.0)
+    }
+
+    /// Returns the connected runs of per-candle values as `(timestamp, value)` pairs, split
+    /// where a value is missing.
+    fn value_runs<T: Copy>(&self, values: &[Option<T>]) -> Vec<Vec<(f64, T)>> {
+        let mut runs = vec![Vec::new()];
+        for (candle, value) in self.dataset.candles.iter().zip(values) {
+            match value {
+                Some(value) => runs.last_mut().unwrap().push((candle.0, *value)),
+                None if runs.last().is_some_and(|run| !run.is_empty()) => runs.push(Vec::new()),
+                None => {}
+            }
+        }
+        runs.retain(|run| run.len() > 1);
+        runs
+    }
+
+    /// Returns the connected runs of an overlay in data coordinates, split where a value is
+    /// missing.
+    fn overlay_runs(&self, values: &[Option<f64>]) -> Vec<Vec<(f64, f64)>> {
+        let values: Vec<Option<f64>> = values
+            .iter()
+            .map(|value| value.filter(|value| value.is_finite()))
+            .collect();
+        self.value_runs(&values)
+    }
+
+    /// Returns the outlines of the Bollinger band ribbon in data coordinates: the upper band
+    /// from left to right, then the lower band back, once per connected run.
+    fn band_outlines(&self) -> Vec<Vec<(f64, f64)>> {
+        self.value_runs(&self.bollinger_points())
+            .into_iter()
+            .map(|run| {
+                let upper = run.iter().map(|&(time, point)| (time, point.upper));
+                let lower = run.iter().rev().map(|&(time, point)| (time, point.lower));
+                upper.chain(lower).collect()
+            })
+            .collect()
+    }
+
+    /// Returns the volume bars as `(timestamp, volume, color)`, skipping candles without a
+    /// positive volume.
+    fn volume_bars(&self) -> Vec<(f64, f64, [u8; 3])> {
+        self.dataset
+            .candles
+            .iter()
+            .zip(&self.dataset.volumes)
+            .filter(|(_, volume)| volume.is_finite() && **volume > 0.0)
+            .map(|(&(timestamp, open, _, _, close), &volume)| {
+                (timestamp, volume, self.candle_color(open, close))
+            })
+            .collect()
+    }
+}
+
+impl Drawer for CandlestickChart {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+        self.fill_svg_background(svg_canvas, cfg);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let (x_min, x_max) = self.time_range();
+        let (y_min, y_max) = self.value_range();
+        let ((price_top, price_bottom), volume_panel) = self.panels(height, margin);
+        let scale_x = scale_factor(x_min, x_max, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, price_bottom - price_top);
+        let to_x = |value| to_pixel_x(value, x_min, scale_x, margin);
+        let to_y = |value| price_bottom - (value - y_min) * scale_y;
+        let overlays = self.line_overlays();
+
+        // Price grid lines and tick labels
+        let y_values = tick_values(y_min, y_max, cfg.num_axis_ticks);
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.2}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            price_bottom - price_top,
+            font_size as f32,
+        );
+        for (&value, label) in y_values.iter().zip(&y_labels) {
+            let y = to_y(value);
+            svg_canvas.draw_line(margin, y, width - margin, y, "lightgray", 0.5);
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Time tick labels
+        let x_values = tick_values(x_min, x_max, cfg.num_axis_ticks);
+        let x_labels = self.time_tick_labels(&x_values);
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            font_size as f32,
+        );
+        for (&value, label) in x_values.iter().zip(&x_labels) {
+            svg_canvas.draw_tick_label(
+                to_x(value),
+                height - margin + font_size * 1.5,
+                label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+
+        // Draw axes
+        svg_canvas.draw_line(margin, margin, margin, height - margin, "black", 2.0);
+        svg_canvas.draw_line(
+            margin,
+            height - margin,
+            width - margin,
+            height - margin,
+            "black",
+            2.0,
+        );
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            width / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Bollinger band ribbon behind the candles
+        if let Some(bands) = self.bollinger {
+            for outline in self.band_outlines() {
+                let points: Vec<String> = outline
+                    .iter()
+                    .map(|&(x, y)| format!("{:.2},{:.2}", to_x(x), to_y(y)))
+                    .collect();
+                svg_canvas.elements.push(format!(
+                    r#"<polygon points="{}" fill="{}" fill-opacity="{BAND_OPACITY}" stroke="none"/>"#,
+                    points.join(" "),
+                    self.rgb_to_svg_color(bands.color)
+                ));
+            }
+        }
+
+        // Wicks above and below the bodies, then the bodies; hollow bodies show what is
+        // behind them
+        let half_body = self.half_body(scale_x);
+        svg_canvas.begin_series(
+            &self.dataset.label,
+            cfg.series_description(&self.dataset.label),
+        );
+        for &candle in &self.dataset.candles {
+            let (timestamp, open, high, low, close) = candle;
+            let color = self.rgb_to_svg_color(self.candle_color(open, close));
+            let x = to_x(timestamp);
+            let body_top = to_y(open.max(close));
+            let body_bottom = to_y(open.min(close));
+            svg_canvas.draw_line(x, to_y(high), x, body_top, &color, 1.0);
+            svg_canvas.draw_line(x, body_bottom, x, to_y(low), &color, 1.0);
+            let fill = if self.hollow_up && close >= open {
+                "none"
+            } else {
+                &color
+            };
+            svg_canvas.draw_rect(
+                x - half_body,
+                body_top,
+                half_body * 2.0,
+                (body_bottom - body_top).max(1.0),
+                fill,
+                &color,
+                1.0,
+                1.0,
+            );
+            svg_canvas.draw_data_point(
+                x,
+                to_y(close),
+                &format!(
+                    "{} {}: {}",
+                    self.dataset.label,
+                    self.format_time(candle.0),
+                    self.price_summary(candle)
+                ),
+            );
+        }
+        svg_canvas.end_group();
+
+        // Volume bars from the bottom of the volume panel, with the largest volume labelled
+        if let Some((volume_top, volume_bottom)) = volume_panel {
+            let scale_volume = (volume_bottom - volume_top) / self.max_volume();
+            for (timestamp, volume, color) in self.volume_bars() {
+                let bar_height = volume * scale_volume;
+                svg_canvas.draw_rect(
+                    to_x(timestamp) - half_body,
+                    volume_bottom - bar_height,
+                    half_body * 2.0,
+                    bar_height,
+                    &self.rgb_to_svg_color(color),
+                    "none",
+                    0.0,
+                    VOLUME_OPACITY,
+                );
+            }
+            svg_canvas.draw_line(
+                margin,
+                volume_top,
+                width - margin,
+                volume_top,
+                "lightgray",
+                0.5,
+            );
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                volume_top + font_size * 0.3,
+                &cfg.format.format_number(self.max_volume(), 0),
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Overlays, interrupted where values are missing
+        for overlay in &overlays {
+            for run in self.overlay_runs(&overlay.values) {
+                let points: Vec<String> = run
+                    .iter()
+                    .map(|&(x, y)| format!("{:.2},{:.2}", to_x(x), to_y(y)))
+                    .collect();
+                svg_canvas.elements.push(format!(
+                    r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="1.5"/>"#,
+                    points.join(" "),
+                    self.rgb_to_svg_color(overlay.color)
+                ));
+            }
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+        // The secondary axis spans the whole plot height, below the price panel as well
+        let axis_min = y_min - (height - margin - price_bottom) / scale_y;
+        self.draw_svg_secondary_axis(svg_canvas, &self.config, axis_min, y_max, font_size);
+
+        // Overlay legend below the x-axis labels
+        if !self.config.show_legend || overlays.is_empty() {
+            return;
+        }
+        let tick_overflow = self.config.x_tick_label_overflow(font_size as f32) as f64;
+        let legend_y = height - margin + font_size * 1.5 + 10.0 + tick_overflow;
+        let mut legend_x = margin + 10.0;
+        for overlay in &overlays {
+            let color = self.rgb_to_svg_color(overlay.color);
+            svg_canvas.draw_line(
+                legend_x,
+                legend_y + font_size / 2.0,
+                legend_x + font_size,
+                legend_y + font_size / 2.0,
+                &color,
+                2.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="{}">{}</text>"#,
+                legend_x + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                color,
+                escape_xml(&overlay.label)
+            ));
+            legend_x += font_size * 3.0 + overlay.label.len() as f64 * font_size * 0.6;
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        let margin = canvas.margin as f64;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let (x_min, x_max) = self.time_range();
+        let (y_min, y_max) = self.value_range();
+        let ((price_top, price_bottom), volume_panel) = self.panels(height, margin);
+        let scale_x = scale_factor(x_min, x_max, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, price_bottom - price_top);
+        let to_x = |value| to_pixel_x(value, x_min, scale_x, margin);
+        let to_y = |value| price_bottom - (value - y_min) * scale_y;
+        let overlays = self.line_overlays();
+
+        // Price grid lines and tick labels
+        let y_values = tick_values(y_min, y_max, cfg.num_axis_ticks);
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.2}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            price_bottom - price_top,
+            cfg.font_size_axis,
+        );
+        for (&value, label) in y_values.iter().zip(&y_labels) {
+            let y = to_y(value) as i32;
+            canvas.draw_line(
+                margin as i32,
+                y,
+                (width - margin) as i32,
+                y,
+                cfg.color_grid,
+                LineType::Solid,
+            );
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                canvas.margin - 10,
+                y as u32,
+                label,
+                AxisType::AxisY,
+            );
+        }
+
+        // Time tick labels
+        let x_values = tick_values(x_min, x_max, cfg.num_axis_ticks);
+        let x_labels = self.time_tick_labels(&x_values);
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            cfg.font_size_axis,
+        );
+        for (&value, label) in x_values.iter().zip(&x_labels) {
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                to_x(value) as u32,
+                canvas.height - canvas.margin,
+                label,
+                AxisType::AxisX,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Bollinger band ribbon behind the candles
+        if let Some(bands) = self.bollinger {
+            for outline in self.band_outlines() {
+                let ring: Vec<(f64, f64)> =
+                    outline.iter().map(|&(x, y)| (to_x(x), to_y(y))).collect();
+                canvas.blend_polygon(&[ring], bands.color, BAND_OPACITY);
+            }
+        }
+
+        // Wicks above and below the bodies, then the bodies; hollow bodies only get an
+        // outline
+        let half_body = self.half_body(scale_x).round() as i32;
+        for &(timestamp, open, high, low, close) in &self.dataset.candles {
+            let color = self.candle_color(open, close);
+            let x = to_x(timestamp).round() as i32;
+            let body_top = to_y(open.max(close)).round() as i32;
+            let body_bottom = to_y(open.min(close)).round() as i32;
+            canvas.draw_line(
+                x,
+                to_y(high).round() as i32,
+                x,
+                body_top,
+                color,
+                LineType::Solid,
+            );
+            canvas.draw_line(
+                x,
+                body_bottom,
+                x,
+                to_y(low).round() as i32,
+                color,
+                LineType::Solid,
+            );
+            if self.hollow_up && close >= open {
+                let (left, right) = (x - half_body, x + half_body);
+                for (x1, y1, x2, y2) in [
+                    (left, body_top, right, body_top),
+                    (right, body_top, right, body_bottom),
+                    (right, body_bottom, left, body_bottom),
+                    (left, body_bottom, left, body_top),
+                ] {
+                    canvas.draw_line(x1, y1, x2, y2, color, LineType::Solid);
+                }
+            } else {
+                for y in body_top..=body_bottom {
+                    for dx in -half_body..=half_body {
+                        canvas.draw_pixel((x + dx) as u32, y as u32, color);
+                    }
+                }
+            }
+        }
+
+        // Volume bars from the bottom of the volume panel, with the largest volume labelled
+        if let Some((volume_top, volume_bottom)) = volume_panel {
+            let scale_volume = (volume_bottom - volume_top) / self.max_volume();
+            for (timestamp, volume, color) in self.volume_bars() {
+                let x = to_x(timestamp).round() as i32;
+                let bar_top = (volume_bottom - volume * scale_volume).round() as i32;
+                for y in bar_top..volume_bottom.round() as i32 {
+                    for dx in -half_body..=half_body {
+                        canvas.blend_pixel((x + dx) as u32, y as u32, color, VOLUME_OPACITY);
+                    }
+                }
+            }
+            canvas.draw_line(
+                margin as i32,
+                volume_top as i32,
+                (width - margin) as i32,
+                volume_top as i32,
+                cfg.color_grid,
+                LineType::Solid,
+            );
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                canvas.margin - 10,
+                volume_top as u32,
+                &cfg.format.format_number(self.max_volume(), 0),
+                AxisType::AxisY,
+            );
+        }
+
+        // Overlays, interrupted where values are missing
+        for overlay in &overlays {
+            for run in self.overlay_runs(&overlay.values) {
+                for pair in run.windows(2) {
+                    canvas.draw_line(
+                        to_x(pair[0].0).round() as i32,
+                        to_y(pair[0].1).round() as i32,
+                        to_x(pair[1].0).round() as i32,
+                        to_y(pair[1].1).round() as i32,
+                        overlay.color,
+                        LineType::Solid,
+                    );
+                }
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw axes and axis labels
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            margin as i32,
+            margin as i32,
+            (height - margin) as i32,
+        );
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            (height - margin) as i32,
+            (width - margin) as i32,
+            (height - margin) as i32,
+        );
+        self.draw_label(
+            canvas,
+            cfg,
+            canvas.width - canvas.margin / 2,
+            canvas.height - canvas.margin,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_axis_units(canvas, &self.config);
+        // The secondary axis spans the whole plot height, below the price panel as well
+        let axis_min = y_min - (height - margin - price_bottom) / scale_y;
+        self.draw_secondary_axis(canvas, &self.config, axis_min, y_max);
+        if self.config.show_legend {
+            self.draw_legend(canvas);
+        }
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let overlays = self.line_overlays();
+        if overlays.is_empty() {
+            return;
+        }
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        // One line sample and label per overlay, in a row below the x-axis labels
+        let line_length = font_size as u32;
+        let padding = line_length / 2;
+        let tick_overflow = self
+            .config
+            .x_tick_label_overflow(self.config.font_size_axis) as u32;
+        let y = canvas.height - canvas.margin + tick_overflow + 2 * line_length;
+        let mut x = canvas.margin;
+        for overlay in &overlays {
+            let (w, h) = measure_text_with_fallbacks(
+                &overlay.label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
+            let line_y = (y + h / 2) as i32;
+            canvas.draw_line(
+                x as i32,
+                line_y,
+                (x + line_length) as i32,
+                line_y,
+                overlay.color,
+                LineType::Solid,
+            );
+            canvas.draw_text_with_fallbacks(
+                x + line_length + padding,
+                y,
+                &overlay.label,
+                overlay.color,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+            x += line_length + padding + w + 2 * padding;
+        }
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+
+    fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        self.line_overlays()
+            .into_iter()
+            .map(|overlay| (overlay.label, overlay.color))
+            .collect()
+    }
+}
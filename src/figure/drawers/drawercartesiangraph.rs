@@ -1,20 +1,31 @@
-use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
+use ab_glyph::PxScale;
 
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
     figuretypes::cartesiangraph::CartesianGraph,
-    utilities::axistype::AxisType,
+    utilities::{
+        autoscale::Autoscale,
+        axistype::AxisType,
+        scaling::{clip_segment, tick_values},
+        textmetrics::measure_text_with_fallbacks,
+    },
 };
 
 use super::drawer::Drawer;
 use std::any::Any;
-impl Drawer for CartesianGraph {
-    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
-        // Clear existing SVG elements
-        // svg_canvas.clear();
-
+impl CartesianGraph {
+    /// Draws the background, title, grid, axes, ticks and axis labels of the graph on an
+    /// SVG canvas, after fitting the axis ranges to the datasets. Figures drawn on
+    /// Cartesian axes, like `StemPlot`, share it.
+    ///
+    /// # Returns
+    /// The x and y scales in pixels per data unit.
+    pub(crate) fn draw_svg_frame(&mut self, svg_canvas: &mut SvgCanvas) -> (f64, f64) {
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
         let margin = svg_canvas.margin as f64;
@@ -22,8 +33,7 @@ impl Drawer for CartesianGraph {
         let cfg = &self.config;
 
         // Draw margin background (using SvgCanvas background_color parameter)
-        let margin_bg_color = svg_canvas.background_color.clone();
-        svg_canvas.draw_rect(0.0, 0.0, width, height, &margin_bg_color, "black", 2.0, 1.0);
+        self.fill_svg_canvas_background(svg_canvas, cfg, 2.0);
         // Draw chart background (using FigureConfig color)
         self.fill_svg_background(svg_canvas, cfg);
 
@@ -38,6 +48,12 @@ impl Drawer for CartesianGraph {
 
         // Symmetric scaling
         self.update_range();
+        ((self.x_min, self.x_max), (self.y_min, self.y_max)) = self.config.aspect_ranges(
+            (self.x_min, self.x_max),
+            (self.y_min, self.y_max),
+            width - 2.0 * margin,
+            height - 2.0 * margin,
+        );
 
         let scale_x = (svg_canvas.width - 2 * svg_canvas.margin) as f64 / (self.x_max - self.x_min);
         let scale_y =
@@ -45,19 +61,14 @@ impl Drawer for CartesianGraph {
 
         // Draw grid
         let num_ticks = 20;
-        svg_canvas.draw_grid(
-            margin,
-            width - margin,
-            margin,
-            height - margin,
-            num_ticks,
-            num_ticks,
-            "lightgray",
-        );
+        let x_ticks = tick_values(margin, width - margin, num_ticks);
+        let y_ticks = tick_values(height - margin, margin, num_ticks);
+        self.draw_svg_tick_grid(svg_canvas, &self.config, &x_ticks, &y_ticks);
 
-        // Draw axes
-        let center_x = margin + (0.0 - self.x_min) * scale_x;
-        let center_y = height - margin - (0.0 - self.y_min) * scale_y;
+        // Draw axes through the origin, or along the plot edge if it is out of range
+        let center_x = (margin + (0.0 - self.x_min) * scale_x).clamp(margin, width - margin);
+        let center_y =
+            (height - margin - (0.0 - self.y_min) * scale_y).clamp(margin, height - margin);
 
         svg_canvas.draw_line(margin, center_y, width - margin, center_y, "black", 2.0);
         svg_canvas.draw_line(center_x, margin, center_x, height - margin, "black", 2.0);
@@ -66,9 +77,18 @@ impl Drawer for CartesianGraph {
 
         // X-axis
         let mut x_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = self.x_min + i as f64 * (self.x_max - self.x_min) / num_ticks as f64;
-            let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
+        let x_values = tick_values(self.x_min, self.x_max, num_ticks);
+        let x_labels = self
+            .config
+            .tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+        let x_labels = self.config.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            font_size as f32,
+        );
+        for (i, label) in x_labels.into_iter().enumerate() {
+            let x = x_ticks[i];
             let tick_start_y = center_y - 5.0;
             let tick_end_y = center_y + 5.0;
 
@@ -77,9 +97,13 @@ impl Drawer for CartesianGraph {
             ));
 
             // Draw value as text (fallback to basic SVG <text>)
-            svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{:.1}</text>"#,
-            x, height - margin + font_size * 1.5, font_size, value));
+            svg_canvas.draw_tick_label(
+                x,
+                height - margin + font_size * 1.5,
+                &label,
+                font_size,
+                AxisType::AxisX,
+            );
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{x_axis_ticks}" stroke="black" stroke-width="1" fill="none"/>"#
@@ -87,9 +111,18 @@ impl Drawer for CartesianGraph {
 
         // Y-axis
         let mut y_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = self.y_min + i as f64 * (self.y_max - self.y_min) / num_ticks as f64;
-            let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
+        let y_values = tick_values(self.y_min, self.y_max, num_ticks);
+        let y_labels = self
+            .config
+            .tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.1}"));
+        let y_labels = self.config.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            height - 2.0 * margin,
+            font_size as f32,
+        );
+        for (i, label) in y_labels.into_iter().enumerate() {
+            let y = y_ticks[i];
             let tick_start_x = center_x - 5.0;
             let tick_end_x = center_x + 5.0;
 
@@ -98,10 +131,13 @@ impl Drawer for CartesianGraph {
             ));
 
             // Draw value as text (fallback to basic SVG <text>)
-            svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{:.1}</text>"#,
-            margin - 5.0, y + font_size * 0.3, font_size, value
-        ));
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{y_axis_ticks}" stroke="black" stroke-width="1" fill="none"/>"#
@@ -113,50 +149,76 @@ impl Drawer for CartesianGraph {
         width / 2.0,
         margin - 5.0,
         font_size * 1.5,
-        self.y_label
+        escape_xml(&self.y_label)
     ));
 
         // Draw Y-axis label (rotated)
-        svg_canvas.elements.push(format!(
-        r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(-90 {:.2} {:.2})">{}</text>"#,
-        margin / 3.0,
-        height / 2.0,
-        font_size * 1.5,
-        margin / 3.0,
-        height / 2.0,
-        self.x_label
-    ));
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
 
-        // Plot datasets
-        for dataset in &self.datasets {
-            for window in dataset.points.windows(2) {
-                if let [p1, p2] = window {
-                    let x1 = margin + (p1.0 - self.x_min) * scale_x;
-                    let y1 = height - margin - (p1.1 - self.y_min) * scale_y;
-                    let x2 = margin + (p2.0 - self.x_min) * scale_x;
-                    let y2 = height - margin - (p2.1 - self.y_min) * scale_y;
+        (scale_x, scale_y)
+    }
 
-                    svg_canvas.draw_line_rgb_styled(
-                        x1,
-                        y1,
-                        x2,
-                        y2,
-                        dataset.color,
-                        2.0,
-                        dataset.line_type.clone(),
-                    );
-                }
-            }
+    /// Draws the axis units, secondary axis, reference lines and legend of the graph on an
+    /// SVG canvas, running the after-data hook before the legend.
+    ///
+    /// # Parameters
+    /// - `scale_y`: The y scale returned by `draw_svg_frame`.
+    pub(crate) fn draw_svg_annotations(&self, svg_canvas: &mut SvgCanvas, scale_y: f64) {
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+        self.draw_svg_secondary_axis(svg_canvas, &self.config, self.y_min, self.y_max, font_size);
+
+        // Reference lines at dataset statistics
+        for dataset in self.visible_datasets() {
+            let Some(line) = dataset.reference_line else {
+                continue;
+            };
+            let Some(value) = line.value(dataset) else {
+                continue;
+            };
+            let y = height - margin - (value - self.y_min) * scale_y;
+            self.draw_svg_reference_line(
+                svg_canvas,
+                &self.config,
+                line,
+                value,
+                y,
+                dataset.color,
+                font_size,
+            );
         }
 
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        if !self.config.show_legend {
+            return;
+        }
         // Draw legend
         let legend_x_start = margin + 10.0; // Start inside chart area with margin spacing
-        let legend_y = height - margin + font_size * 1.5 + 10.0; // Position below x-axis labels
+        let tick_overflow = self.config.x_tick_label_overflow(font_size as f32) as f64;
+        let legend_y = height - margin + font_size * 1.5 + 10.0 + tick_overflow; // Position below x-axis labels
         let mut legend_x = legend_x_start; // Reset starting position for legend items
         let mut elements = String::new();
         let legend_bg_color = svg_canvas.background_color.clone();
 
-        for dataset in &self.datasets {
+        for dataset in self.visible_datasets() {
+            let label = dataset.legend_label(&self.config.format);
+            elements.push_str(&SvgCanvas::legend_item_group(&dataset.label));
+            let link = self.config.series_link(&dataset.label);
+            if let Some(url) = link {
+                elements.push_str(&SvgCanvas::link_open(url));
+            }
+
             // Draw color square
             elements.push_str(&format!(
                 r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
@@ -178,11 +240,15 @@ impl Drawer for CartesianGraph {
                 dataset.color[0],
                 dataset.color[1],
                 dataset.color[2],
-                dataset.label
+                escape_xml(&label)
             ));
+            if link.is_some() {
+                elements.push_str("</a>");
+            }
+            elements.push_str("</g>");
 
             // Update legend_x to position the next item
-            legend_x += font_size * 5.0 + dataset.label.len() as f64 * font_size * 0.6;
+            legend_x += font_size * 5.0 + label.len() as f64 * font_size * 0.6;
         }
 
         // Draw a background rectangle for the legend
@@ -202,90 +268,348 @@ impl Drawer for CartesianGraph {
         svg_canvas.elements.push(elements);
     }
 
-    fn draw(&mut self, canvas: &mut PixelCanvas) {
+    /// Draws the background, title, grid and axes of the graph on a pixel canvas, after
+    /// fitting the axis ranges to the datasets. With the default `Autoscale::Symmetric`, the
+    /// x range is made symmetric around the centered y-axis.
+    ///
+    /// # Returns
+    /// The x and y scales in pixels per data unit.
+    pub(crate) fn draw_frame(&mut self, canvas: &mut PixelCanvas) -> (f64, f64) {
         canvas.clear();
+        self.update_range();
 
         let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
         self.fill_background(canvas, cfg);
 
         let margin = canvas.margin;
         let width = canvas.width;
         let height = canvas.height;
-        let center_x = width / 2;
-        let center_y = height / 2;
 
         // Draw the title
         self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
 
-        // Draw grids
-        canvas.draw_grid(
-            &[cfg.num_grid_horizontal, cfg.num_grid_vertical],
-            cfg.color_grid,
-        );
+        // Draw grids at the tick positions
+        let num_ticks = 10;
+        let x_tick_step = (width - 2 * margin) / num_ticks;
+        let y_tick_step = (height - 2 * margin) / num_ticks;
+        let x_ticks: Vec<f64> = (0..=num_ticks)
+            .map(|i| (margin + i * x_tick_step) as f64)
+            .collect();
+        let y_ticks: Vec<f64> = (0..=num_ticks)
+            .map(|i| (height - margin - i * y_tick_step) as f64)
+            .collect();
+        self.draw_tick_grid(canvas, cfg, &x_ticks, &y_ticks);
 
         // Ensure x_min and x_max are symmetric
-        let abs_x_min = self.x_min.abs();
-        let abs_x_max = self.x_max.abs();
-
-        if abs_x_min > abs_x_max {
-            self.x_max = abs_x_min;
-        } else {
-            self.x_min = -abs_x_max;
+        if self.x_autoscale == Autoscale::Symmetric {
+            let abs_x_min = self.x_min.abs();
+            let abs_x_max = self.x_max.abs();
+
+            if abs_x_min > abs_x_max {
+                self.x_max = abs_x_min;
+            } else {
+                self.x_min = -abs_x_max;
+            }
         }
-
-        // Draw X and Y axes
-        canvas.draw_vertical_line(center_x, [0, 0, 0]);
-        canvas.draw_horizontal_line(center_y, [0, 0, 0]);
+        ((self.x_min, self.x_max), (self.y_min, self.y_max)) = self.config.aspect_ranges(
+            (self.x_min, self.x_max),
+            (self.y_min, self.y_max),
+            (width - 2 * margin) as f64,
+            (height - 2 * margin) as f64,
+        );
 
         let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (self.x_max - self.x_min);
         let scale_y = (canvas.height - 2 * canvas.margin) as f64 / (self.y_max - self.y_min); // Adjust y-range as needed
 
-        for dataset in &self.datasets {
-            for window in dataset.points.windows(2) {
-                if let [p1, p2] = window {
-                    let x1 = center_x as i32 + (p1.0 * scale_x) as i32;
-                    let y1 = center_y as i32 - (p1.1 * scale_y) as i32;
-                    let x2 = center_x as i32 + (p2.0 * scale_x) as i32;
-                    let y2 = center_y as i32 - (p2.1 * scale_y) as i32;
+        // Draw X and Y axes through the origin, or along the plot edge if it is out of range
+        let (origin_x, origin_y) = self.axis_origin(canvas, scale_x, scale_y);
+        canvas.draw_vertical_line(origin_x, [0, 0, 0]);
+        canvas.draw_horizontal_line(origin_y, [0, 0, 0]);
 
-                    canvas.draw_line(x1, y1, x2, y2, dataset.color, dataset.line_type.clone());
-                }
-            }
+        (scale_x, scale_y)
+    }
+
+    /// Returns the canvas position of the data origin `(0, 0)` on a pixel canvas, which may
+    /// lie outside the plot area when the ranges do not contain zero.
+    ///
+    /// # Parameters
+    /// - `scale_x`, `scale_y`: The scales returned by `draw_frame`.
+    pub(crate) fn pixel_origin(
+        &self,
+        canvas: &PixelCanvas,
+        scale_x: f64,
+        scale_y: f64,
+    ) -> (f64, f64) {
+        let margin = canvas.margin as f64;
+        (
+            margin - self.x_min * scale_x,
+            canvas.height as f64 - margin + self.y_min * scale_y,
+        )
+    }
+
+    /// Returns the pixel positions of the axis lines: through the origin, clamped to the
+    /// plot area.
+    fn axis_origin(&self, canvas: &PixelCanvas, scale_x: f64, scale_y: f64) -> (u32, u32) {
+        let margin = canvas.margin as f64;
+        let (x, y) = self.pixel_origin(canvas, scale_x, scale_y);
+        (
+            x.clamp(margin, canvas.width as f64 - margin) as u32,
+            y.clamp(margin, canvas.height as f64 - margin) as u32,
+        )
+    }
+
+    /// Returns whether a point lies within the axis ranges.
+    fn in_range(&self, (x, y): (f64, f64)) -> bool {
+        (self.x_min..=self.x_max).contains(&x) && (self.y_min..=self.y_max).contains(&y)
+    }
+
+    /// Keeps the points within the axis ranges, e.g. inside a sliding x window.
+    ///
+    /// # Returns
+    /// The indices of the visible points and the points themselves, for drawing their
+    /// markers and error bars.
+    pub(crate) fn visible_points(&self, points: &[(f64, f64)]) -> (Vec<usize>, Vec<(f64, f64)>) {
+        points
+            .iter()
+            .enumerate()
+            .filter(|&(_, &point)| self.in_range(point))
+            .map(|(index, &point)| (index, point))
+            .unzip()
+    }
+
+    /// Draws the reference lines, axis labels, tick values, axis units, secondary axis and
+    /// legend of the graph on a pixel canvas, running the after-data hook after the
+    /// reference lines.
+    ///
+    /// # Parameters
+    /// - `scale_y`: The y scale returned by `draw_frame`.
+    pub(crate) fn draw_annotations(&self, canvas: &mut PixelCanvas, scale_y: f64) {
+        let cfg = &self.config;
+        let margin = canvas.margin;
+        let width = canvas.width;
+        let height = canvas.height;
+        let (_, center_y) = self.pixel_origin(canvas, 0.0, scale_y);
+        let num_ticks = 10;
+        let x_tick_step = (width - 2 * margin) / num_ticks;
+        let y_tick_step = (height - 2 * margin) / num_ticks;
+
+        // Reference lines at dataset statistics
+        for dataset in self.visible_datasets() {
+            let Some(line) = dataset.reference_line else {
+                continue;
+            };
+            let Some(value) = line.value(dataset) else {
+                continue;
+            };
+            let y = (center_y - value * scale_y) as i32;
+            self.draw_reference_line(canvas, &self.config, line, value, y, dataset.color);
         }
 
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
         // X-axis label
-        let origin_y = height - margin - ((0.0 - self.y_min) * scale_y) as u32;
+        let (_, origin_y) = self.axis_origin(canvas, 0.0, scale_y);
         self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.x_label);
         self.draw_label(canvas, cfg, margin, margin / 2, &self.y_label);
 
         // Draw X and Y axis tick values
-        let num_ticks = 10;
-        let x_tick_step = (canvas.width - 2 * canvas.margin) / num_ticks;
-        let y_tick_step = (canvas.height - 2 * canvas.margin) / num_ticks;
-
         let y = canvas.height - canvas.margin;
-        for i in 0..=num_ticks {
+        let x_labels = cfg.tick_labels(
+            AxisType::AxisX,
+            &tick_values(self.x_min, self.x_max, num_ticks as usize),
+            |v| format!("{v:+.2}"),
+        );
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            (canvas.width - 2 * canvas.margin) as f64,
+            cfg.font_size_axis,
+        );
+        let y_labels = cfg.tick_labels(
+            AxisType::AxisY,
+            &tick_values(self.y_min, self.y_max, num_ticks as usize),
+            |v| format!("{v:.2}"),
+        );
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            (canvas.height - 2 * canvas.margin) as f64,
+            cfg.font_size_axis,
+        );
+        for (i, (label_x, label_y)) in x_labels.iter().zip(&y_labels).enumerate() {
+            let i = i as u32;
+
             // X-axis ticks
             let x = canvas.margin + i * x_tick_step;
-            let value_x = self.x_min + ((self.x_max - self.x_min) / num_ticks as f64) * i as f64;
-            let label_x = format!("{value_x:+.2}");
-            self.draw_axis_value(canvas, cfg, x, y, &label_x, AxisType::AxisX);
+            self.draw_axis_value(canvas, cfg, x, y, label_x, AxisType::AxisX);
 
             // Y-axis ticks
             let y = canvas.margin + i * y_tick_step;
-            let value_y = self.y_min + ((self.y_max - self.y_min) / num_ticks as f64) * i as f64;
-            let label_y = format!("{value_y:.2}");
             self.draw_axis_value(
                 canvas,
                 cfg,
                 margin - 10,
                 height - y,
-                &label_y,
+                label_y,
                 AxisType::AxisY,
             );
         }
 
-        self.draw_legend(canvas);
+        self.draw_axis_units(canvas, &self.config);
+        self.draw_secondary_axis(canvas, &self.config, self.y_min, self.y_max);
+        if self.config.show_legend {
+            self.draw_legend(canvas);
+        }
+    }
+}
+
+impl Drawer for CartesianGraph {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let (scale_x, scale_y) = self.draw_svg_frame(svg_canvas);
+        let area = (
+            margin,
+            margin,
+            svg_canvas.width as f64 - margin,
+            height - margin,
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Plot datasets
+        for dataset in self.visible_datasets() {
+            svg_canvas.begin_series(
+                &dataset.label,
+                self.config.series_description(&dataset.label),
+            );
+            let link = self.config.series_link(&dataset.label);
+            if let Some(url) = link {
+                svg_canvas.begin_link(url);
+            }
+            for window in dataset
+                .drawn_segments()
+                .iter()
+                .flat_map(|segment| segment.windows(2))
+            {
+                if let [p1, p2] = window {
+                    let start = (
+                        margin + (p1.0 - self.x_min) * scale_x,
+                        height - margin - (p1.1 - self.y_min) * scale_y,
+                    );
+                    let end = (
+                        margin + (p2.0 - self.x_min) * scale_x,
+                        height - margin - (p2.1 - self.y_min) * scale_y,
+                    );
+                    let Some(((x1, y1), (x2, y2))) = clip_segment(start, end, area) else {
+                        continue;
+                    };
+
+                    svg_canvas.draw_line_rgb_styled(
+                        x1,
+                        y1,
+                        x2,
+                        y2,
+                        dataset.color,
+                        2.0,
+                        dataset.line_type.clone(),
+                    );
+                }
+            }
+
+            let (visible, points) = self.visible_points(&dataset.points);
+            dataset.error_bars.select(&visible).draw_svg(
+                svg_canvas,
+                &points,
+                |x, y| {
+                    (
+                        margin + (x - self.x_min) * scale_x,
+                        height - margin - (y - self.y_min) * scale_y,
+                    )
+                },
+                dataset.color,
+            );
+
+            // Hover targets for the interactive HTML export
+            for (x, y) in dataset
+                .transformed_points()
+                .into_iter()
+                .filter(|&point| self.in_range(point))
+            {
+                svg_canvas.draw_data_point(
+                    margin + (x - self.x_min) * scale_x,
+                    height - margin - (y - self.y_min) * scale_y,
+                    &format!("{}: ({x:.2}, {y:.2})", dataset.label),
+                );
+            }
+            if link.is_some() {
+                svg_canvas.end_link();
+            }
+            svg_canvas.end_group();
+        }
+
+        self.draw_svg_annotations(svg_canvas, scale_y);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        let (scale_x, scale_y) = self.draw_frame(canvas);
+        let (center_x, center_y) = self.pixel_origin(canvas, scale_x, scale_y);
+        let margin = canvas.margin as f64;
+        let area = (
+            margin,
+            margin,
+            canvas.width as f64 - margin,
+            canvas.height as f64 - margin,
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        for dataset in self.visible_datasets() {
+            for window in dataset
+                .drawn_segments()
+                .iter()
+                .flat_map(|segment| segment.windows(2))
+            {
+                if let [p1, p2] = window {
+                    let start = (center_x + p1.0 * scale_x, center_y - p1.1 * scale_y);
+                    let end = (center_x + p2.0 * scale_x, center_y - p2.1 * scale_y);
+                    // Parts outside the ranges, e.g. before a sliding window, are cut off
+                    let Some(((x1, y1), (x2, y2))) = clip_segment(start, end, area) else {
+                        continue;
+                    };
+
+                    canvas.draw_line(
+                        x1 as i32,
+                        y1 as i32,
+                        x2 as i32,
+                        y2 as i32,
+                        dataset.color,
+                        dataset.line_type.clone(),
+                    );
+                }
+            }
+            let (visible, points) = self.visible_points(&dataset.points);
+            dataset.error_bars.select(&visible).draw(
+                canvas,
+                &points,
+                |x, y| (center_x + x * scale_x, center_y - y * scale_y),
+                dataset.color,
+            );
+        }
+
+        self.draw_annotations(canvas, scale_y);
     }
 
     fn draw_legend(&self, canvas: &mut PixelCanvas) {
@@ -294,20 +618,31 @@ impl Drawer for CartesianGraph {
             .font_label
             .as_ref()
             .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
-        let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
-
-        let square_size = 10; // Size of the colored square
-        let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+        let line_height = square_size * 2; // Vertical space for each legend entry
         let legend_margin = canvas.margin; // Margin from the bottom of the canvas
 
         let mut x = canvas.margin;
-        let mut y = canvas.height - legend_margin; // Legend starts from the bottom
-
-        for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.label);
+        let tick_overflow = self
+            .config
+            .x_tick_label_overflow(self.config.font_size_axis) as u32;
+        let mut y = canvas.height - legend_margin + tick_overflow; // Legend starts from the bottom
+
+        for dataset in self.visible_datasets() {
+            let label = dataset.legend_label(&self.config.format);
+            let (w, h) = measure_text_with_fallbacks(
+                &label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
             // Draw the square
             for dy in 0..square_size {
                 for dx in 0..square_size {
@@ -321,12 +656,13 @@ impl Drawer for CartesianGraph {
 
             // Draw the label text next to the square
             let text_x: u32 = x + square_size + padding;
-            canvas.draw_text(
+            canvas.draw_text_with_fallbacks(
                 text_x,
                 y + 2 * square_size + h,
-                &dataset.label,
+                &label,
                 dataset.color,
-                &font,
+                font_path,
+                &self.config.font_fallbacks,
                 scale,
             );
 
@@ -347,4 +683,14 @@ impl Drawer for CartesianGraph {
     fn get_figure_config(&self) -> &FigureConfig {
         &self.config
     }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+
+    fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        self.visible_datasets()
+            .map(|dataset| (dataset.legend_label(&self.config.format), dataset.color))
+            .collect()
+    }
 }
@@ -0,0 +1,207 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::chorddiagram::{ChordDiagram, ChordGroup},
+    utilities::textmetrics::measure_text_with_fallbacks,
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Gap in pixels between the arcs and their labels.
+const LABEL_GAP: f64 = 6.0;
+
+impl ChordDiagram {
+    /// Computes the anchor of a group's label just outside the middle of its arc.
+    ///
+    /// # Returns
+    /// The anchor in canvas coordinates and whether the label extends to the right of it.
+    fn label_anchor(group: &ChordGroup, center: (f64, f64), radius: f64) -> ((f64, f64), bool) {
+        let angle = (group.start_angle + group.end_angle) / 2.0;
+        let r = radius + LABEL_GAP;
+        (
+            (center.0 + r * angle.cos(), center.1 + r * angle.sin()),
+            angle.cos() >= 0.0,
+        )
+    }
+}
+
+impl Drawer for ChordDiagram {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let layout = self.layout();
+        let (center, radius) = Self::geometry(width, height, margin);
+        let inner = self.inner_radius(radius);
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Each row with the ribbons it is the source of, so toggling a row hides both
+        for group in &layout.groups {
+            let label = &self.labels[group.index];
+            let color = self.rgb_to_svg_color(self.group_color(group.index));
+            svg_canvas.begin_series(label, cfg.series_description(label));
+            for ribbon in layout
+                .ribbons
+                .iter()
+                .filter(|ribbon| ribbon.source == group.index)
+            {
+                svg_canvas.draw_path(
+                    &ribbon.path(center, inner),
+                    &color,
+                    &color,
+                    0.5,
+                    self.ribbon_opacity,
+                    Some(&self.ribbon_tooltip(ribbon)),
+                );
+            }
+            svg_canvas.draw_path(
+                &self.group_path(group, center, radius),
+                &color,
+                "white",
+                1.0,
+                1.0,
+                Some(&self.group_tooltip(group.index)),
+            );
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Labels outside the arcs
+        if self.show_labels {
+            for group in &layout.groups {
+                let ((x, y), to_right) = Self::label_anchor(group, center, radius);
+                svg_canvas.elements.push(format!(
+                    r#"<text x="{x:.2}" y="{:.2}" font-size="{font_size:.2}" text-anchor="{}" fill="black">{}</text>"#,
+                    y + font_size * 0.3,
+                    if to_right { "start" } else { "end" },
+                    escape_xml(&self.labels[group.index])
+                ));
+            }
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let layout = self.layout();
+        let (center, radius) = Self::geometry(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let inner = self.inner_radius(radius);
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Ribbons below the arcs, colored by their source
+        for ribbon in &layout.ribbons {
+            canvas.blend_bezier_path(
+                &ribbon.path(center, inner),
+                self.group_color(ribbon.source),
+                self.ribbon_opacity,
+            );
+        }
+
+        // Arcs
+        for group in &layout.groups {
+            canvas.fill_bezier_path(
+                &self.group_path(group, center, radius),
+                self.group_color(group.index),
+            );
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Labels outside the arcs
+        if self.show_labels {
+            let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+            let scale = PxScale {
+                x: cfg.font_size_axis,
+                y: cfg.font_size_axis,
+            };
+            for group in &layout.groups {
+                let label = &self.labels[group.index];
+                let ((x, y), to_right) = Self::label_anchor(group, center, radius);
+                let (w, h) = measure_text_with_fallbacks(
+                    label,
+                    font_path,
+                    &cfg.font_fallbacks,
+                    cfg.font_size_axis,
+                );
+                let left = if to_right { x } else { x - w as f64 };
+                self.draw_text_with_halo(
+                    canvas,
+                    cfg,
+                    (left.max(0.0) as u32, (y - h as f64 / 2.0).max(0.0) as u32),
+                    label,
+                    cfg.color_axis,
+                    scale,
+                );
+            }
+        }
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // Rows are labelled outside their arcs, so no separate legend is drawn
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
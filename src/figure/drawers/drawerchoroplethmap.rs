@@ -0,0 +1,209 @@
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::choroplethmap::ChoroplethMap,
+    utilities::{
+        axistype::AxisType,
+        colorbar::{COLOR_BAR_GAP, COLOR_BAR_SPACE},
+        linetype::LineType,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl ChoroplethMap {
+    /// Formats a value of the color bar using the Y-axis tick formatter.
+    fn color_bar_label(&self, value: f64) -> String {
+        self.config
+            .tick_label(AxisType::AxisY, value, |v| format!("{v:.1}"))
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    /// Returns the labels of the color bar: the highest, middle and lowest value, as
+    /// `(fraction of the bar height, text)` pairs.
+    fn color_bar_labels(&self, (min, max): (f64, f64)) -> Vec<(f64, String)> {
+        [1.0, 0.5, 0.0]
+            .into_iter()
+            .map(|fraction| (fraction, self.color_bar_label(min + (max - min) * fraction)))
+            .collect()
+    }
+
+    /// Returns the color at a fraction of the color bar height, from the lowest value at
+    /// 0.0 to the highest at 1.0.
+    fn color_bar_color(&self, fraction: f64, range: (f64, f64)) -> [u8; 3] {
+        self.value_color(range.0 + (range.1 - range.0) * fraction, range)
+    }
+}
+
+impl Drawer for ChoroplethMap {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Draw regions; holes are cut out by the even-odd fill rule
+        let range = self.value_range();
+        let border = self.rgb_to_svg_color(self.color_border);
+        if let Some(layout) = self.layout(width, height, margin) {
+            for feature in &self.features {
+                let fill = self.rgb_to_svg_color(self.fill_color(&feature.name, range));
+                let mut path = String::new();
+                for rings in &feature.polygons {
+                    for ring in self.polygon_to_canvas(rings, layout) {
+                        for (index, (x, y)) in ring.iter().enumerate() {
+                            let command = if index == 0 { 'M' } else { 'L' };
+                            path.push_str(&format!("{command}{x:.2},{y:.2} "));
+                        }
+                        path.push_str("Z ");
+                    }
+                }
+                svg_canvas.elements.push(format!(
+                    r#"<path d="{}" fill="{}" fill-rule="evenodd" stroke="{}" stroke-width="0.5"/>"#,
+                    path.trim_end(),
+                    fill,
+                    border
+                ));
+            }
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Color bar from the lowest (bottom) to the highest value (top)
+        self.draw_svg_color_bar(
+            svg_canvas,
+            cfg,
+            width - margin - COLOR_BAR_SPACE + COLOR_BAR_GAP,
+            (margin, height - margin),
+            50,
+            &|fraction| self.color_bar_color(fraction, range),
+            &self.color_bar_labels(range),
+            font_size,
+        );
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Fill all regions before drawing borders, so shared borders are not painted over
+        let range = self.value_range();
+        if let Some(layout) = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        ) {
+            let figure = &*self;
+            let regions: Vec<_> = figure
+                .features
+                .iter()
+                .flat_map(|feature| {
+                    let color = figure.fill_color(&feature.name, range);
+                    feature
+                        .polygons
+                        .iter()
+                        .map(move |rings| (figure.polygon_to_canvas(rings, layout), color))
+                })
+                .collect();
+
+            for (rings, color) in &regions {
+                canvas.fill_polygon(rings, *color);
+            }
+            for ring in regions.iter().flat_map(|(rings, _)| rings) {
+                for (index, &(x1, y1)) in ring.iter().enumerate() {
+                    let (x2, y2) = ring[(index + 1) % ring.len()];
+                    canvas.draw_line(
+                        x1.round() as i32,
+                        y1.round() as i32,
+                        x2.round() as i32,
+                        y2.round() as i32,
+                        self.color_border,
+                        LineType::Solid,
+                    );
+                }
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let range = self.value_range();
+        let (width, height, margin) = (
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        if height <= 2.0 * margin {
+            return;
+        }
+
+        // Color bar from the lowest (bottom) to the highest value (top), right of the map
+        self.draw_color_bar(
+            canvas,
+            &self.config,
+            width - margin - COLOR_BAR_SPACE + COLOR_BAR_GAP,
+            (margin, height - margin),
+            &|fraction| self.color_bar_color(fraction, range),
+            &self.color_bar_labels(range),
+        );
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
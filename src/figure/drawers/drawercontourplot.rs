@@ -0,0 +1,339 @@
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::contourplot::ContourPlot,
+    utilities::{
+        axistype::AxisType, colorbar::COLOR_BAR_GAP, linetype::LineType, scaling::tick_values,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Returns the color of the block of a stepped color bar at a fraction of its height, from
+/// the bottom block at 0.0 to the top block at 1.0. `blocks` must not be empty.
+fn block_at(blocks: &[[u8; 3]], fraction: f64) -> [u8; 3] {
+    blocks[((fraction * blocks.len() as f64) as usize).min(blocks.len() - 1)]
+}
+
+impl ContourPlot {
+    /// Computes the x and y ticks as `(value, canvas position)` pairs.
+    ///
+    /// X positions are x-coordinates, y positions y-coordinates.
+    #[allow(clippy::type_complexity)]
+    fn axis_ticks(&self, area: (f64, f64, f64, f64)) -> (Vec<(f64, f64)>, Vec<(f64, f64)>) {
+        let (left, top, right, bottom) = area;
+        let num_ticks = self.config.num_axis_ticks;
+        let (x0, x1) = self.x_range();
+        let (y0, y1) = self.y_range();
+        let xs = tick_values(x0, x1, num_ticks)
+            .into_iter()
+            .map(|x| (x, left + (x - x0) / (x1 - x0) * (right - left)))
+            .collect();
+        let ys = tick_values(y0, y1, num_ticks)
+            .into_iter()
+            .map(|y| (y, bottom - (y - y0) / (y1 - y0) * (bottom - top)))
+            .collect();
+        (xs, ys)
+    }
+
+    /// Returns the color of the iso-lines of a level.
+    fn iso_line_color(&self, index: usize, count: usize) -> [u8; 3] {
+        if self.filled {
+            self.line_color
+        } else {
+            self.level_color(index, count)
+        }
+    }
+
+    /// Computes the color bar: its blocks from bottom to top, one per band when filled and
+    /// one per level otherwise, and its labels as `(fraction of the height from the
+    /// bottom, text)` pairs, thinned so they do not overlap.
+    ///
+    /// # Parameters
+    /// - `bar_height`: The height of the color bar in pixels.
+    /// - `font_size`: The font size of the labels.
+    #[allow(clippy::type_complexity)]
+    fn color_bar(&self, bar_height: f64, font_size: f64) -> (Vec<[u8; 3]>, Vec<(f64, String)>) {
+        let levels = self.contour_levels();
+        let count = levels.len();
+        let (blocks, positions): (Vec<[u8; 3]>, Vec<f64>) = if self.filled {
+            (
+                (0..=count)
+                    .map(|band| self.band_color(band, count))
+                    .collect(),
+                (0..count)
+                    .map(|index| (index + 1) as f64 / (count + 1) as f64)
+                    .collect(),
+            )
+        } else {
+            (
+                (0..count)
+                    .map(|index| self.level_color(index, count))
+                    .collect(),
+                (0..count)
+                    .map(|index| (index as f64 + 0.5) / count as f64)
+                    .collect(),
+            )
+        };
+
+        let fitting = (bar_height / (font_size * 1.5)).max(1.0) as usize;
+        let step = count.div_ceil(fitting).max(1);
+        let labels = levels
+            .iter()
+            .zip(positions)
+            .step_by(step)
+            .map(|(&level, position)| (position, self.format_value(level)))
+            .collect();
+        (blocks, labels)
+    }
+}
+
+impl Drawer for ContourPlot {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Filled bands, embedded as an image, with the iso-lines on top
+        let area = self.plot_area(width, height, margin);
+        let (left, top, right, bottom) = area;
+        if self.filled {
+            svg_canvas.draw_image(left, top, &self.render_bands(area));
+        }
+        let iso_lines = self.iso_lines(area);
+        let count = iso_lines.len();
+        for (index, (level, lines)) in iso_lines.into_iter().enumerate() {
+            let path: String = lines
+                .iter()
+                .map(|(x1, y1, x2, y2)| format!("M {x1:.2},{y1:.2} L {x2:.2},{y2:.2} "))
+                .collect();
+            if path.is_empty() {
+                continue;
+            }
+            svg_canvas.elements.push(format!(
+                r#"<path d="{}" stroke="{}" stroke-width="1.5" fill="none" data-level="{}"/>"#,
+                path.trim_end(),
+                self.rgb_to_svg_color(self.iso_line_color(index, count)),
+                self.format_value(level)
+            ));
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Axes with x and y ticks
+        svg_canvas.draw_line(left, bottom, right, bottom, "black", 2.0);
+        svg_canvas.draw_line(left, top, left, bottom, "black", 2.0);
+        let (xs, ys) = self.axis_ticks(area);
+        for (value, x) in xs {
+            let label = cfg.tick_label(AxisType::AxisX, value, |v| format!("{v:.2}"));
+            svg_canvas.draw_tick_label(
+                x,
+                bottom + font_size * 1.5,
+                &label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+        for (value, y) in ys {
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.2}"));
+            svg_canvas.draw_tick_label(
+                left - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            (left + right) / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        // Stepped color bar from the lowest (bottom) to the highest level (top)
+        let (blocks, labels) = self.color_bar(bottom - top, font_size);
+        if !blocks.is_empty() {
+            self.draw_svg_color_bar(
+                svg_canvas,
+                cfg,
+                right + COLOR_BAR_GAP,
+                (top, bottom),
+                blocks.len(),
+                &|fraction| block_at(&blocks, fraction),
+                &labels,
+                font_size,
+            );
+        }
+
+        self.draw_svg_axis_units(svg_canvas, cfg, font_size);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Filled bands with the iso-lines on top
+        let area = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        if self.filled {
+            let image = self.render_bands(area);
+            let (x, y) = (area.0.round() as u32, area.1.round() as u32);
+            for (dx, dy, pixel) in image.enumerate_pixels() {
+                canvas.draw_pixel(x + dx, y + dy, pixel.0);
+            }
+        }
+        let iso_lines = self.iso_lines(area);
+        let count = iso_lines.len();
+        for (index, (_, lines)) in iso_lines.into_iter().enumerate() {
+            let color = self.iso_line_color(index, count);
+            for (x1, y1, x2, y2) in lines {
+                canvas.draw_line(
+                    x1.round() as i32,
+                    y1.round() as i32,
+                    x2.round() as i32,
+                    y2.round() as i32,
+                    color,
+                    LineType::Solid,
+                );
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Axes with x and y ticks
+        let (left, top, right, bottom) = (
+            area.0.round() as i32,
+            area.1.round() as i32,
+            area.2.round() as i32,
+            area.3.round() as i32,
+        );
+        self.draw_axis(canvas, cfg, left, bottom, right, bottom);
+        self.draw_axis(canvas, cfg, left, top, left, bottom);
+        let (xs, ys) = self.axis_ticks(area);
+        for (value, x) in xs {
+            let label = cfg.tick_label(AxisType::AxisX, value, |v| format!("{v:.2}"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                x as u32,
+                bottom as u32,
+                &label,
+                AxisType::AxisX,
+            );
+        }
+        for (value, y) in ys {
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.2}"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                (left as u32).saturating_sub(10),
+                y as u32,
+                &label,
+                AxisType::AxisY,
+            );
+        }
+
+        self.draw_label(
+            canvas,
+            cfg,
+            right as u32,
+            canvas.height - canvas.margin / 3,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_axis_units(canvas, cfg);
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let (_, top, right, bottom) = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (blocks, labels) = self.color_bar(bottom - top, self.config.font_size_axis as f64);
+        if blocks.is_empty() {
+            return;
+        }
+
+        // Stepped color bar from the lowest (bottom) to the highest level (top), right of
+        // the plot
+        self.draw_color_bar(
+            canvas,
+            &self.config,
+            right + COLOR_BAR_GAP,
+            (top, bottom),
+            &|fraction| block_at(&blocks, fraction),
+            &labels,
+        );
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -0,0 +1,263 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::correlationmatrix::CorrelationMatrix,
+    utilities::{
+        axistype::AxisType,
+        colorbar::COLOR_BAR_GAP,
+        colormap::{diverging_color, text_color_for},
+        textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Returns the labels of the color bar, -1, 0 and 1, as `(fraction of the bar height, text)`
+/// pairs.
+fn color_bar_labels() -> Vec<(f64, String)> {
+    [(1.0, "1"), (0.5, "0"), (0.0, "-1")]
+        .into_iter()
+        .map(|(fraction, text)| (fraction, text.to_string()))
+        .collect()
+}
+
+impl Drawer for CorrelationMatrix {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let matrix = self.matrix();
+        let (left, top, cell_size) = self.layout(width, height, margin);
+        let side = cell_size * matrix.len() as f64;
+        let background = self.rgb_to_svg_color(cfg.color_background);
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Draw cells with their coefficients
+        for (row, values) in matrix.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                let x = left + col as f64 * cell_size;
+                let y = top + row as f64 * cell_size;
+                let color = diverging_color(value, 1.0);
+                svg_canvas.draw_rect(
+                    x,
+                    y,
+                    cell_size,
+                    cell_size,
+                    &self.rgb_to_svg_color(color),
+                    &background,
+                    1.0,
+                    1.0,
+                );
+
+                if self.show_values {
+                    svg_canvas.draw_value_label(
+                        x + cell_size / 2.0,
+                        y + cell_size / 2.0 + font_size * 0.35,
+                        &self.format_value(value),
+                        font_size,
+                        &self.rgb_to_svg_color(text_color_for(
+                            cfg.text_halo.map_or(color, |halo| halo.color()),
+                        )),
+                    );
+                }
+            }
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Series labels along both axes
+        for (index, (label, _)) in self.series.iter().enumerate() {
+            let center = index as f64 * cell_size + cell_size / 2.0;
+            self.draw_svg_category_label(
+                svg_canvas,
+                cfg,
+                left - 5.0,
+                top + center + font_size * 0.3,
+                label,
+                font_size,
+                AxisType::AxisY,
+            );
+            self.draw_svg_category_label(
+                svg_canvas,
+                cfg,
+                left + center,
+                top + side + font_size * 1.5,
+                label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+
+        // Color bar from -1 (bottom) to 1 (top)
+        self.draw_svg_color_bar(
+            svg_canvas,
+            cfg,
+            left + side + 2.0 * COLOR_BAR_GAP,
+            (top, top + side),
+            50,
+            &|fraction| diverging_color(2.0 * fraction - 1.0, 1.0),
+            &color_bar_labels(),
+            font_size,
+        );
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let matrix = self.matrix();
+        let (left, top, cell_size) = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let side = (cell_size * matrix.len() as f64) as u32;
+
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: cfg.font_size_axis,
+            y: cfg.font_size_axis,
+        };
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw cells with their coefficients, separated by background colored lines
+        for (row, values) in matrix.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                let x0 = (left + col as f64 * cell_size) as u32;
+                let y0 = (top + row as f64 * cell_size) as u32;
+                let x1 = (left + (col + 1) as f64 * cell_size) as u32;
+                let y1 = (top + (row + 1) as f64 * cell_size) as u32;
+                let color = diverging_color(value, 1.0);
+
+                for y in y0..y1.saturating_sub(1) {
+                    for x in x0..x1.saturating_sub(1) {
+                        canvas.draw_pixel(x, y, color);
+                    }
+                }
+
+                if self.show_values {
+                    let text = self.format_value(value);
+                    let (w, h) = measure_text_with_fallbacks(
+                        &text,
+                        font_path,
+                        &cfg.font_fallbacks,
+                        cfg.font_size_axis,
+                    );
+                    self.draw_text_with_halo(
+                        canvas,
+                        cfg,
+                        (
+                            ((x0 + x1) / 2).saturating_sub(w / 2),
+                            ((y0 + y1) / 2).saturating_sub(h / 2),
+                        ),
+                        &text,
+                        text_color_for(cfg.text_halo.map_or(color, |halo| halo.color())),
+                        scale,
+                    );
+                }
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Series labels along both axes
+        for (index, (label, _)) in self.series.iter().enumerate() {
+            let center = (index as f64 * cell_size + cell_size / 2.0) as u32;
+            self.draw_category_label(
+                canvas,
+                cfg,
+                (left as u32).saturating_sub(10),
+                top as u32 + center,
+                label,
+                AxisType::AxisY,
+            );
+            self.draw_category_label(
+                canvas,
+                cfg,
+                left as u32 + center,
+                top as u32 + side,
+                label,
+                AxisType::AxisX,
+            );
+        }
+
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let (left, top, cell_size) = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let side = cell_size * self.series.len() as f64;
+        if side < 1.0 {
+            return;
+        }
+
+        // Color bar from -1 (bottom) to 1 (top), right of the matrix
+        self.draw_color_bar(
+            canvas,
+            &self.config,
+            left + side + 2.0 * COLOR_BAR_GAP,
+            (top, top + side),
+            &|fraction| diverging_color(2.0 * fraction - 1.0, 1.0),
+            &color_bar_labels(),
+        );
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
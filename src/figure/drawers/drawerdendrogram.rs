@@ -0,0 +1,334 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::dendrogram::Dendrogram,
+    utilities::{
+        axistype::AxisType, linetype::LineType, orientation::Orientation, scaling::tick_values,
+        textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl Dendrogram {
+    /// Returns the axis the distances are measured along.
+    fn distance_axis(&self) -> AxisType {
+        match self.orientation {
+            Orientation::Vertical => AxisType::AxisY,
+            Orientation::Horizontal => AxisType::AxisX,
+        }
+    }
+
+    /// Computes the distance ticks as `(value, canvas coordinate)` pairs, the coordinate
+    /// being along the distance axis.
+    fn distance_ticks(&self, area: (f64, f64, f64, f64)) -> Vec<(f64, f64)> {
+        tick_values(0.0, self.distance_max(), self.config.num_axis_ticks)
+            .into_iter()
+            .map(|value| {
+                let (x, y) = self.to_canvas(0.0, value, area);
+                match self.orientation {
+                    Orientation::Vertical => (value, y),
+                    Orientation::Horizontal => (value, x),
+                }
+            })
+            .collect()
+    }
+
+    /// Computes the tree lines in canvas coordinates.
+    fn tree_lines(&self, area: (f64, f64, f64, f64)) -> Vec<((f64, f64), (f64, f64))> {
+        self.tree
+            .segments()
+            .into_iter()
+            .map(|((p0, d0), (p1, d1))| {
+                (self.to_canvas(p0, d0, area), self.to_canvas(p1, d1, area))
+            })
+            .collect()
+    }
+
+    /// Computes the leaves in the order they are drawn, as `(leaf id, canvas point)` pairs.
+    fn leaf_points(&self, area: (f64, f64, f64, f64)) -> Vec<(usize, (f64, f64))> {
+        self.tree
+            .leaf_order()
+            .into_iter()
+            .enumerate()
+            .map(|(index, leaf)| (leaf, self.to_canvas(index as f64 + 0.5, 0.0, area)))
+            .collect()
+    }
+}
+
+impl Drawer for Dendrogram {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+        self.fill_svg_background(svg_canvas, cfg);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        // Grid lines and tick labels of the distance axis
+        let area = self.plot_area(width, height, margin);
+        let (left, top, right, bottom) = area;
+        let axis = self.distance_axis();
+        let ticks = self.distance_ticks(area);
+        let values: Vec<f64> = ticks.iter().map(|&(value, _)| value).collect();
+        let positions: Vec<f64> = ticks.iter().map(|&(_, position)| position).collect();
+        let labels = cfg.tick_labels(axis, &values, |v| format!("{v:.2}"));
+        match self.orientation {
+            Orientation::Vertical => {
+                self.draw_svg_tick_grid(svg_canvas, cfg, &[], &positions);
+                let labels = cfg.thin_tick_labels(axis, labels, bottom - top, font_size as f32);
+                for (&y, label) in positions.iter().zip(&labels) {
+                    svg_canvas.draw_tick_label(
+                        left - 5.0,
+                        y + font_size * 0.3,
+                        label,
+                        font_size,
+                        axis,
+                    );
+                }
+                svg_canvas.draw_line(left, top, left, bottom, "black", 2.0);
+                svg_canvas.draw_rotated_label(
+                    margin / 3.0,
+                    height / 2.0,
+                    &self.distance_label,
+                    font_size * 1.5,
+                    "black",
+                );
+            }
+            Orientation::Horizontal => {
+                self.draw_svg_tick_grid(svg_canvas, cfg, &positions, &[]);
+                let labels = cfg.thin_tick_labels(axis, labels, right - left, font_size as f32);
+                for (&x, label) in positions.iter().zip(&labels) {
+                    svg_canvas.draw_tick_label(x, bottom + font_size * 1.5, label, font_size, axis);
+                }
+                svg_canvas.draw_line(left, bottom, right, bottom, "black", 2.0);
+                svg_canvas.draw_text(
+                    width / 2.0,
+                    height - margin / 3.0,
+                    &escape_xml(&self.distance_label),
+                    font_size * 1.5,
+                    "black",
+                );
+            }
+        }
+
+        // Leaf labels along the leaves
+        for (leaf, (x, y)) in self.leaf_points(area) {
+            let label = self.leaf_label(leaf);
+            match self.orientation {
+                Orientation::Vertical => self.draw_svg_category_label(
+                    svg_canvas,
+                    cfg,
+                    x,
+                    bottom + font_size * 1.5,
+                    &label,
+                    font_size,
+                    AxisType::AxisX,
+                ),
+                Orientation::Horizontal => svg_canvas.elements.push(format!(
+                    r#"<text x="{:.2}" y="{:.2}" font-size="{font_size:.2}" fill="black">{}</text>"#,
+                    right + 5.0,
+                    y + font_size * 0.3,
+                    escape_xml(&cfg.category_label(&label, font_size as f32))
+                )),
+            }
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Tree lines, with hover targets on the leaves and merges
+        let color = self.rgb_to_svg_color(self.color);
+        for ((x1, y1), (x2, y2)) in self.tree_lines(area) {
+            svg_canvas.draw_line(x1, y1, x2, y2, &color, 1.5);
+        }
+        for (node, (position, distance)) in self.tree.node_positions().into_iter().enumerate() {
+            let (x, y) = self.to_canvas(position, distance, area);
+            svg_canvas.draw_data_point(x, y, &self.node_tooltip(node));
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        // Grid lines and tick labels of the distance axis
+        let area = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (left, top, right, bottom) = area;
+        let axis = self.distance_axis();
+        let ticks = self.distance_ticks(area);
+        let values: Vec<f64> = ticks.iter().map(|&(value, _)| value).collect();
+        let positions: Vec<f64> = ticks.iter().map(|&(_, position)| position).collect();
+        let labels = cfg.tick_labels(axis, &values, |v| format!("{v:.2}"));
+        match self.orientation {
+            Orientation::Vertical => {
+                self.draw_tick_grid(canvas, cfg, &[], &positions);
+                let labels = cfg.thin_tick_labels(axis, labels, bottom - top, cfg.font_size_axis);
+                for (&y, label) in positions.iter().zip(&labels) {
+                    self.draw_axis_value(canvas, cfg, canvas.margin - 10, y as u32, label, axis);
+                }
+            }
+            Orientation::Horizontal => {
+                self.draw_tick_grid(canvas, cfg, &positions, &[]);
+                let labels = cfg.thin_tick_labels(axis, labels, right - left, cfg.font_size_axis);
+                for (&x, label) in positions.iter().zip(&labels) {
+                    self.draw_axis_value(canvas, cfg, x as u32, bottom as u32, label, axis);
+                }
+            }
+        }
+
+        // Leaf labels along the leaves
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: cfg.font_size_axis,
+            y: cfg.font_size_axis,
+        };
+        for (leaf, (x, y)) in self.leaf_points(area) {
+            let label = self.leaf_label(leaf);
+            match self.orientation {
+                Orientation::Vertical => self.draw_category_label(
+                    canvas,
+                    cfg,
+                    x as u32,
+                    bottom as u32,
+                    &label,
+                    AxisType::AxisX,
+                ),
+                Orientation::Horizontal => {
+                    let text = cfg.category_label(&label, cfg.font_size_axis);
+                    let (_, h) = measure_text_with_fallbacks(
+                        &text,
+                        font_path,
+                        &cfg.font_fallbacks,
+                        cfg.font_size_axis,
+                    );
+                    self.draw_text_with_halo(
+                        canvas,
+                        cfg,
+                        ((right + 5.0) as u32, (y as u32).saturating_sub(h / 2)),
+                        &text,
+                        cfg.color_axis,
+                        scale,
+                    );
+                }
+            }
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Tree lines
+        for ((x1, y1), (x2, y2)) in self.tree_lines(area) {
+            canvas.draw_line(
+                x1.round() as i32,
+                y1.round() as i32,
+                x2.round() as i32,
+                y2.round() as i32,
+                self.color,
+                LineType::Solid,
+            );
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw the distance axis and its label
+        match self.orientation {
+            Orientation::Vertical => {
+                self.draw_axis(
+                    canvas,
+                    cfg,
+                    left as i32,
+                    top as i32,
+                    left as i32,
+                    bottom as i32,
+                );
+                self.draw_label(
+                    canvas,
+                    cfg,
+                    canvas.margin,
+                    canvas.margin / 2,
+                    &self.distance_label,
+                );
+            }
+            Orientation::Horizontal => {
+                self.draw_axis(
+                    canvas,
+                    cfg,
+                    left as i32,
+                    bottom as i32,
+                    right as i32,
+                    bottom as i32,
+                );
+                self.draw_label(
+                    canvas,
+                    cfg,
+                    canvas.width - canvas.margin / 2,
+                    canvas.height - canvas.margin,
+                    &self.distance_label,
+                );
+            }
+        }
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // Leaves are labelled along the tree, so no separate legend is drawn
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -0,0 +1,418 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::densityplot::DensityPlot,
+    utilities::{
+        axistype::AxisType,
+        linetype::LineType,
+        scaling::{scale_factor, tick_values, to_pixel_x, to_pixel_y},
+        textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Returns the number of decimals of density tick labels: enough to tell small densities
+/// apart.
+fn density_decimals(density_max: f64) -> usize {
+    if density_max >= 10.0 {
+        1
+    } else {
+        (2.0 - density_max.log10().floor()).clamp(2.0, 6.0) as usize
+    }
+}
+
+impl Drawer for DensityPlot {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+        self.fill_svg_background(svg_canvas, cfg);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let (x_min, x_max) = self.value_range();
+        let y_max = self.density_max();
+        let scale_x = scale_factor(x_min, x_max, width - 2.0 * margin);
+        let scale_y = scale_factor(0.0, y_max, height - 2.0 * margin);
+        let to_x = |value| to_pixel_x(value, x_min, scale_x, margin);
+        let to_y = |density| to_pixel_y(density, 0.0, scale_y, height, margin);
+
+        // Grid lines and tick labels
+        let x_values = tick_values(x_min, x_max, cfg.num_axis_ticks);
+        let y_values = tick_values(0.0, y_max, cfg.num_axis_ticks);
+        let x_ticks: Vec<f64> = x_values.iter().map(|&value| to_x(value)).collect();
+        let y_ticks: Vec<f64> = y_values.iter().map(|&density| to_y(density)).collect();
+        self.draw_svg_tick_grid(svg_canvas, cfg, &x_ticks, &y_ticks);
+
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            font_size as f32,
+        );
+        for (&x, label) in x_ticks.iter().zip(&x_labels) {
+            svg_canvas.draw_tick_label(
+                x,
+                height - margin + font_size * 1.5,
+                label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+        let decimals = density_decimals(y_max);
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.decimals$}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            height - 2.0 * margin,
+            font_size as f32,
+        );
+        for (&y, label) in y_ticks.iter().zip(&y_labels) {
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Draw axes
+        svg_canvas.draw_line(margin, margin, margin, height - margin, "black", 2.0);
+        svg_canvas.draw_line(
+            margin,
+            height - margin,
+            width - margin,
+            height - margin,
+            "black",
+            2.0,
+        );
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            width / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Filled curves, one series each
+        for (dataset, curve) in self.datasets.iter().zip(self.curves()) {
+            if curve.is_empty() {
+                continue;
+            }
+            let color = self.rgb_to_svg_color(dataset.color);
+            let line: Vec<String> = curve
+                .iter()
+                .map(|&(value, density)| format!("{:.2},{:.2}", to_x(value), to_y(density)))
+                .collect();
+
+            svg_canvas.begin_series(&dataset.label, cfg.series_description(&dataset.label));
+            if self.fill {
+                svg_canvas.elements.push(format!(
+                    r#"<polygon points="{:.2},{:.2} {} {:.2},{:.2}" fill="{color}" fill-opacity="{:.2}" stroke="none"/>"#,
+                    to_x(curve[0].0),
+                    to_y(0.0),
+                    line.join(" "),
+                    to_x(curve[curve.len() - 1].0),
+                    to_y(0.0),
+                    self.fill_opacity
+                ));
+            }
+            svg_canvas.elements.push(format!(
+                r#"<polyline points="{}" fill="none" stroke="{color}" stroke-width="2"/>"#,
+                line.join(" ")
+            ));
+            if let Some(&(value, density)) = curve.iter().max_by(|a, b| a.1.total_cmp(&b.1)) {
+                svg_canvas.draw_data_point(
+                    to_x(value),
+                    to_y(density),
+                    &format!(
+                        "{}: peak at {}",
+                        dataset.label,
+                        self.config.format.format_number(value, 2)
+                    ),
+                );
+            }
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+
+        // Legend in the top-right corner
+        if !self.config.show_legend || self.visible_datasets().count() < 2 {
+            return;
+        }
+        let mut legend_y = margin + font_size;
+        for dataset in self.visible_datasets() {
+            let text_width = dataset.label.chars().count() as f64 * font_size * 0.6;
+            let legend_x = width - margin - font_size * 1.8 - text_width;
+            svg_canvas
+                .elements
+                .push(SvgCanvas::legend_item_group(&dataset.label));
+            svg_canvas.draw_rect(
+                legend_x,
+                legend_y,
+                font_size,
+                font_size,
+                &self.rgb_to_svg_color(dataset.color),
+                "none",
+                0.0,
+                1.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                legend_x + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                escape_xml(&dataset.label)
+            ));
+            svg_canvas.end_group();
+            legend_y += font_size * 1.5;
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        let margin = canvas.margin as f64;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let (x_min, x_max) = self.value_range();
+        let y_max = self.density_max();
+        let scale_x = scale_factor(x_min, x_max, width - 2.0 * margin);
+        let scale_y = scale_factor(0.0, y_max, height - 2.0 * margin);
+        let to_x = |value| to_pixel_x(value, x_min, scale_x, margin);
+        let to_y = |density| to_pixel_y(density, 0.0, scale_y, height, margin);
+
+        // Grid lines and tick labels
+        let x_values = tick_values(x_min, x_max, cfg.num_axis_ticks);
+        let y_values = tick_values(0.0, y_max, cfg.num_axis_ticks);
+        let x_ticks: Vec<f64> = x_values.iter().map(|&value| to_x(value)).collect();
+        let y_ticks: Vec<f64> = y_values.iter().map(|&density| to_y(density)).collect();
+        self.draw_tick_grid(canvas, cfg, &x_ticks, &y_ticks);
+
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            cfg.font_size_axis,
+        );
+        for (&x, label) in x_ticks.iter().zip(&x_labels) {
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                x as u32,
+                canvas.height - canvas.margin,
+                label,
+                AxisType::AxisX,
+            );
+        }
+        let decimals = density_decimals(y_max);
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.decimals$}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            height - 2.0 * margin,
+            cfg.font_size_axis,
+        );
+        for (&y, label) in y_ticks.iter().zip(&y_labels) {
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                canvas.margin - 10,
+                y as u32,
+                label,
+                AxisType::AxisY,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Filled curves, outlined in their color
+        for (dataset, curve) in self.datasets.iter().zip(self.curves()) {
+            if curve.len() < 2 {
+                continue;
+            }
+            let line: Vec<(f64, f64)> = curve
+                .iter()
+                .map(|&(value, density)| (to_x(value), to_y(density)))
+                .collect();
+            if self.fill {
+                let mut area = line.clone();
+                area.push((line[line.len() - 1].0, to_y(0.0)));
+                area.push((line[0].0, to_y(0.0)));
+                canvas.blend_polygon(&[area], dataset.color, self.fill_opacity);
+            }
+            for segment in line.windows(2) {
+                canvas.draw_line(
+                    segment[0].0.round() as i32,
+                    segment[0].1.round() as i32,
+                    segment[1].0.round() as i32,
+                    segment[1].1.round() as i32,
+                    dataset.color,
+                    LineType::Solid,
+                );
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw axes and axis labels
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            margin as i32,
+            margin as i32,
+            (height - margin) as i32,
+        );
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            (height - margin) as i32,
+            (width - margin) as i32,
+            (height - margin) as i32,
+        );
+        self.draw_label(
+            canvas,
+            cfg,
+            canvas.width - canvas.margin / 2,
+            canvas.height - canvas.margin,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_axis_units(canvas, &self.config);
+        if self.config.show_legend {
+            self.draw_legend(canvas);
+        }
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        // A single curve is named by the title
+        if self.visible_datasets().count() < 2 {
+            return;
+        }
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+
+        // Series are listed in the top-right corner, where the tails of the curves are low
+        let right = canvas.width - canvas.margin - padding;
+        let mut y = canvas.margin + padding;
+
+        for dataset in self.visible_datasets() {
+            let (w, h) = measure_text_with_fallbacks(
+                &dataset.label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
+            let x = right.saturating_sub(square_size + padding + w);
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(x + dx, y + dy, dataset.color);
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                x + square_size + padding,
+                y,
+                &dataset.label,
+                self.config.color_axis,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+            y += square_size.max(h) + padding;
+        }
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+
+    fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        self.visible_datasets()
+            .map(|dataset| (dataset.label.clone(), dataset.color))
+            .collect()
+    }
+}
@@ -0,0 +1,292 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::funnelchart::{FunnelChart, FunnelSegment},
+    utilities::{
+        colormap::text_color_for,
+        textmetrics::{ellipsize, measure_text_with_fallbacks},
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Minimum free space around a stage label, in pixels.
+const LABEL_PADDING: f64 = 4.0;
+
+/// Space between the widest stage and the percentage annotations, in pixels.
+const ANNOTATION_GAP: f64 = 8.0;
+
+/// Where a stage label is drawn.
+enum LabelPlacement {
+    /// Centered on the segment, in a color contrasting with its fill.
+    Inside(String),
+    /// Right-aligned left of the segment, for segments too narrow for the label.
+    Outside(String),
+}
+
+/// Places the label of a segment: inside if it fits, otherwise left of the segment if it
+/// fits there, otherwise inside shortened with an ellipsis.
+fn place_label(
+    segment: &FunnelSegment,
+    text: &str,
+    text_height: f64,
+    measure: impl Fn(&str) -> f64,
+) -> Option<LabelPlacement> {
+    if text_height + 2.0 * LABEL_PADDING > segment.y1 - segment.y0 {
+        return None;
+    }
+    let inside_width = segment.top_width.min(segment.bottom_width) - 2.0 * LABEL_PADDING;
+    let text_width = measure(text);
+    if text_width <= inside_width {
+        return Some(LabelPlacement::Inside(text.to_string()));
+    }
+    let outside_width =
+        segment.center_x - segment.top_width.max(segment.bottom_width) / 2.0 - ANNOTATION_GAP;
+    if text_width <= outside_width {
+        return Some(LabelPlacement::Outside(text.to_string()));
+    }
+    ellipsize(text, inside_width, measure).map(LabelPlacement::Inside)
+}
+
+impl Drawer for FunnelChart {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let segments = self.layout(width - 2.0 * margin, height - 2.0 * margin);
+        let measure = |text: &str| text.chars().count() as f64 * font_size * 0.6;
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Segments with their labels, and percentages of the first stage beside the funnel
+        for segment in &segments {
+            let label = &self.stages[segment.index].label;
+            let color = self.stage_color(segment.index);
+            let points: Vec<String> = segment
+                .polygon(margin, margin)
+                .iter()
+                .map(|(x, y)| format!("{x:.2},{y:.2}"))
+                .collect();
+            let center_y = margin + (segment.y0 + segment.y1) / 2.0;
+
+            svg_canvas.begin_series(label, cfg.series_description(label));
+            svg_canvas.elements.push(format!(
+                r#"<polygon points="{}" fill="{}" stroke="none" data-tooltip="{}"/>"#,
+                points.join(" "),
+                self.rgb_to_svg_color(color),
+                escape_xml(&self.stage_tooltip(segment.index))
+            ));
+
+            let placement = place_label(
+                segment,
+                &self.stage_label(segment.index),
+                font_size,
+                measure,
+            )
+            .filter(|_| self.show_labels);
+            if let Some(placement) = placement {
+                let (x, anchor, fill, text) = match placement {
+                    LabelPlacement::Inside(text) => (
+                        margin + segment.center_x,
+                        "middle",
+                        self.rgb_to_svg_color(text_color_for(color)),
+                        text,
+                    ),
+                    LabelPlacement::Outside(text) => (
+                        margin + segment.center_x
+                            - segment.top_width.max(segment.bottom_width) / 2.0
+                            - ANNOTATION_GAP,
+                        "end",
+                        "black".to_string(),
+                        text,
+                    ),
+                };
+                svg_canvas.elements.push(format!(
+                    r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="{}" fill="{}">{}</text>"#,
+                    x,
+                    center_y + font_size * 0.35,
+                    font_size,
+                    anchor,
+                    fill,
+                    escape_xml(&text)
+                ));
+            }
+            if let Some(percentage) = self
+                .percentage_label(segment.index)
+                .filter(|_| self.show_percentages)
+            {
+                svg_canvas.elements.push(format!(
+                    r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                    margin + 2.0 * segment.center_x + ANNOTATION_GAP,
+                    center_y + font_size * 0.35,
+                    font_size,
+                    percentage
+                ));
+            }
+            svg_canvas.draw_data_point(
+                margin + segment.center_x,
+                center_y,
+                &self.stage_tooltip(segment.index),
+            );
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        let margin = canvas.margin as f64;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let segments = self.layout(width - 2.0 * margin, height - 2.0 * margin);
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Segments
+        for segment in &segments {
+            canvas.fill_polygon(
+                &[segment.polygon(margin, margin)],
+                self.stage_color(segment.index),
+            );
+        }
+
+        // Labels on the segments they fit in, and percentages of the first stage beside
+        // the funnel
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: cfg.font_size_axis,
+            y: cfg.font_size_axis,
+        };
+        let measure = |text: &str| {
+            measure_text_with_fallbacks(text, font_path, &cfg.font_fallbacks, scale.x).0 as f64
+        };
+        for segment in &segments {
+            let center_y = margin + (segment.y0 + segment.y1) / 2.0;
+            let placement = place_label(
+                segment,
+                &self.stage_label(segment.index),
+                scale.y as f64,
+                measure,
+            )
+            .filter(|_| self.show_labels);
+            if let Some(placement) = placement {
+                let (text, outside) = match placement {
+                    LabelPlacement::Inside(text) => (text, false),
+                    LabelPlacement::Outside(text) => (text, true),
+                };
+                let (w, h) =
+                    measure_text_with_fallbacks(&text, font_path, &cfg.font_fallbacks, scale.x);
+                let (x, color) = if outside {
+                    (
+                        margin + segment.center_x
+                            - segment.top_width.max(segment.bottom_width) / 2.0
+                            - ANNOTATION_GAP
+                            - w as f64,
+                        cfg.color_axis,
+                    )
+                } else {
+                    (
+                        margin + segment.center_x - w as f64 / 2.0,
+                        text_color_for(self.stage_color(segment.index)),
+                    )
+                };
+                canvas.draw_text_with_fallbacks(
+                    x.max(0.0) as u32,
+                    (center_y - h as f64 / 2.0).max(0.0) as u32,
+                    &text,
+                    color,
+                    font_path,
+                    &cfg.font_fallbacks,
+                    scale,
+                );
+            }
+            if let Some(percentage) = self
+                .percentage_label(segment.index)
+                .filter(|_| self.show_percentages)
+            {
+                let (_, h) = measure_text_with_fallbacks(
+                    &percentage,
+                    font_path,
+                    &cfg.font_fallbacks,
+                    scale.x,
+                );
+                canvas.draw_text_with_fallbacks(
+                    (margin + 2.0 * segment.center_x + ANNOTATION_GAP) as u32,
+                    (center_y - h as f64 / 2.0).max(0.0) as u32,
+                    &percentage,
+                    cfg.color_axis,
+                    font_path,
+                    &cfg.font_fallbacks,
+                    scale,
+                );
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // Every stage is labelled on its segment, so no separate legend is drawn
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -0,0 +1,359 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::ganttchart::GanttChart,
+    utilities::{
+        axistype::AxisType,
+        scaling::{scale_factor, tick_values, to_pixel_x},
+        textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl GanttChart {
+    /// Maps a task's bar onto the canvas.
+    ///
+    /// # Returns
+    /// The bar as `(left, top, right, bottom)` in pixels.
+    fn bar_rect(
+        &self,
+        index: usize,
+        (t_min, scale_x): (f64, f64),
+        height: f64,
+        margin: f64,
+    ) -> (f64, f64, f64, f64) {
+        let task = &self.tasks[index];
+        let (top, bottom) = self.bar_edges(index);
+        let plot_height = height - 2.0 * margin;
+        let (x0, x1) = (
+            to_pixel_x(task.start.min(task.end), t_min, scale_x, margin),
+            to_pixel_x(task.start.max(task.end), t_min, scale_x, margin),
+        );
+        (
+            x0,
+            margin + top * plot_height,
+            x1.max(x0 + 1.0),
+            margin + bottom * plot_height,
+        )
+    }
+
+    /// Returns the y-coordinate of the center of a task's row.
+    fn row_center(&self, index: usize, height: f64, margin: f64) -> f64 {
+        let (top, bottom) = self.bar_edges(index);
+        margin + (top + bottom) / 2.0 * (height - 2.0 * margin)
+    }
+}
+
+impl Drawer for GanttChart {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let (t_min, t_max) = self.time_range();
+        let scale_x = scale_factor(t_min, t_max, width - 2.0 * margin);
+
+        // Time grid and tick labels
+        let t_values = tick_values(t_min, t_max, cfg.num_axis_ticks);
+        let x_ticks: Vec<f64> = t_values
+            .iter()
+            .map(|&time| to_pixel_x(time, t_min, scale_x, margin))
+            .collect();
+        self.draw_svg_tick_grid(svg_canvas, cfg, &x_ticks, &[]);
+        for (&time, &x) in t_values.iter().zip(&x_ticks) {
+            let label = cfg.time_tick_label(AxisType::AxisX, time, self.time_pattern());
+            svg_canvas.draw_tick_label(
+                x,
+                height - margin + font_size * 1.5,
+                &label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // One bar per task
+        for (index, task) in self.tasks.iter().enumerate() {
+            let (left, top, right, bottom) = self.bar_rect(index, (t_min, scale_x), height, margin);
+            svg_canvas.begin_series(&task.name, cfg.series_description(&task.name));
+            svg_canvas.draw_rect(
+                left,
+                top,
+                right - left,
+                bottom - top,
+                &self.rgb_to_svg_color(self.task_color(task)),
+                "none",
+                0.0,
+                1.0,
+            );
+            svg_canvas.draw_data_point(
+                (left + right) / 2.0,
+                (top + bottom) / 2.0,
+                &format!(
+                    "{}: {} - {}",
+                    task.name,
+                    self.format_time(task.start),
+                    self.format_time(task.end)
+                ),
+            );
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Task names left of their rows
+        for (index, task) in self.tasks.iter().enumerate() {
+            self.draw_svg_category_label(
+                svg_canvas,
+                cfg,
+                margin - 5.0,
+                self.row_center(index, height, margin) + font_size * 0.3,
+                &task.name,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Draw axes
+        svg_canvas.draw_line(margin, margin, margin, height - margin, "black", 2.0);
+        svg_canvas.draw_line(
+            margin,
+            height - margin,
+            width - margin,
+            height - margin,
+            "black",
+            2.0,
+        );
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            width / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        // Group legend below the time labels
+        let legend_y = height - margin + font_size * 2.5;
+        let mut legend_x = margin + 10.0;
+        for group in self.groups() {
+            svg_canvas.draw_rect(
+                legend_x,
+                legend_y,
+                font_size,
+                font_size,
+                &self.rgb_to_svg_color(self.group_color(group)),
+                "none",
+                0.0,
+                1.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                legend_x + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                escape_xml(group)
+            ));
+            legend_x += font_size * 3.0 + group.len() as f64 * font_size * 0.6;
+        }
+
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        let margin = canvas.margin as f64;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let (t_min, t_max) = self.time_range();
+        let scale_x = scale_factor(t_min, t_max, width - 2.0 * margin);
+
+        // Time grid and tick labels
+        let t_values = tick_values(t_min, t_max, cfg.num_axis_ticks);
+        let x_ticks: Vec<f64> = t_values
+            .iter()
+            .map(|&time| to_pixel_x(time, t_min, scale_x, margin))
+            .collect();
+        self.draw_tick_grid(canvas, cfg, &x_ticks, &[]);
+        for (&time, &x) in t_values.iter().zip(&x_ticks) {
+            let label = cfg.time_tick_label(AxisType::AxisX, time, self.time_pattern());
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                x as u32,
+                canvas.height - canvas.margin,
+                &label,
+                AxisType::AxisX,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // One bar per task
+        for (index, task) in self.tasks.iter().enumerate() {
+            let (left, top, right, bottom) = self.bar_rect(index, (t_min, scale_x), height, margin);
+            let color = self.task_color(task);
+            for y in top.round() as u32..bottom.round() as u32 {
+                for x in left.round() as u32..right.round() as u32 {
+                    canvas.draw_pixel(x, y, color);
+                }
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Task names left of their rows
+        for (index, task) in self.tasks.iter().enumerate() {
+            self.draw_category_label(
+                canvas,
+                cfg,
+                canvas.margin - 10,
+                self.row_center(index, height, margin) as u32,
+                &task.name,
+                AxisType::AxisY,
+            );
+        }
+
+        // Draw axes and axis labels
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            margin as i32,
+            margin as i32,
+            (height - margin) as i32,
+        );
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            (height - margin) as i32,
+            (width - margin) as i32,
+            (height - margin) as i32,
+        );
+        self.draw_label(
+            canvas,
+            cfg,
+            canvas.width - canvas.margin / 2,
+            canvas.height - canvas.margin,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_axis_units(canvas, &self.config);
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+
+        // Groups are listed in a single row below the time labels
+        let mut x = canvas.margin;
+        let y = canvas.height - canvas.margin + square_size * 3;
+
+        for group in self.groups() {
+            let (w, _) = measure_text_with_fallbacks(
+                group,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(x + dx, y + dy, self.group_color(group));
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                x + square_size + padding,
+                y,
+                group,
+                self.config.color_axis,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+            x += square_size + padding + w + square_size;
+        }
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -0,0 +1,267 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::gaugechart::{GaugeChart, GaugeLayout},
+    utilities::{
+        linetype::LineType, scaling::tick_values, textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Gap in pixels between the ends of the dial and the min/max labels.
+const LABEL_GAP: f64 = 8.0;
+
+impl GaugeChart {
+    /// Computes the tick marks along the inner edge of the band, as line end points.
+    fn tick_marks(&self, layout: &GaugeLayout) -> Vec<((f64, f64), (f64, f64))> {
+        let (cx, cy) = layout.center;
+        let length = (layout.radius - layout.inner) * 0.5;
+        tick_values(self.min, self.max, self.config.num_axis_ticks)
+            .into_iter()
+            .filter(|value| (self.min..=self.max).contains(value))
+            .map(|value| {
+                let angle = self.value_angle(value);
+                let (dx, dy) = (angle.cos(), angle.sin());
+                (
+                    (cx + dx * layout.inner, cy + dy * layout.inner),
+                    (
+                        cx + dx * (layout.inner + length),
+                        cy + dy * (layout.inner + length),
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// Computes the centers of the min and max labels, below the ends of the band.
+    fn range_label_anchors(&self, layout: &GaugeLayout) -> [((f64, f64), String); 2] {
+        let (cx, cy) = layout.center;
+        let middle = (layout.inner + layout.radius) / 2.0;
+        [self.min, self.max].map(|value| {
+            let angle = self.value_angle(value);
+            let bottom = cy + layout.radius * angle.sin().max(0.0);
+            (
+                (cx + middle * angle.cos(), bottom + LABEL_GAP),
+                self.format_value(value),
+            )
+        })
+    }
+}
+
+impl Drawer for GaugeChart {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let layout = self.layout(width, height, margin);
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Track, then the zones over it
+        svg_canvas.draw_path(
+            &self.band_path(self.min, self.max, &layout),
+            &self.rgb_to_svg_color(self.track_color),
+            "none",
+            0.0,
+            1.0,
+            None,
+        );
+        for zone in &self.zones {
+            if self.value_angle(zone.from) == self.value_angle(zone.to) {
+                continue;
+            }
+            let tooltip = format!(
+                "{}: {} to {}",
+                zone.label,
+                self.format_value(zone.from),
+                self.format_value(zone.to)
+            );
+            svg_canvas.draw_path(
+                &self.band_path(zone.from, zone.to, &layout),
+                &self.rgb_to_svg_color(zone.color),
+                "none",
+                0.0,
+                1.0,
+                Some(&tooltip),
+            );
+        }
+        for ((x1, y1), (x2, y2)) in self.tick_marks(&layout) {
+            svg_canvas.draw_line(x1, y1, x2, y2, "black", 1.0);
+        }
+
+        // Needle and hub
+        let needle_color = self.rgb_to_svg_color(self.needle_color);
+        let value = self.format_value(self.value);
+        svg_canvas.draw_path(
+            &self.needle_path(&layout),
+            &needle_color,
+            "none",
+            0.0,
+            1.0,
+            Some(&value),
+        );
+        let (cx, cy) = layout.center;
+        svg_canvas.draw_circle(cx, cy, self.hub_radius(&layout), &needle_color);
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Value below the hub, range at the ends of the dial
+        svg_canvas.elements.push(format!(
+            r#"<text x="{cx:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" dominant-baseline="middle" fill="black">{}</text>"#,
+            cy + layout.radius * Self::VALUE_DEPTH,
+            font_size * 2.0,
+            escape_xml(&value)
+        ));
+        for ((x, y), label) in self.range_label_anchors(&layout) {
+            svg_canvas.elements.push(format!(
+                r#"<text x="{x:.2}" y="{:.2}" font-size="{font_size:.2}" text-anchor="middle" fill="black">{}</text>"#,
+                y + font_size * 0.8,
+                escape_xml(&label)
+            ));
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let layout = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Track, then the zones over it
+        canvas.fill_bezier_path(
+            &self.band_path(self.min, self.max, &layout),
+            self.track_color,
+        );
+        for zone in &self.zones {
+            canvas.fill_bezier_path(&self.band_path(zone.from, zone.to, &layout), zone.color);
+        }
+        for ((x1, y1), (x2, y2)) in self.tick_marks(&layout) {
+            canvas.draw_line(
+                x1.round() as i32,
+                y1.round() as i32,
+                x2.round() as i32,
+                y2.round() as i32,
+                cfg.color_axis,
+                LineType::Solid,
+            );
+        }
+
+        // Needle and hub
+        let (cx, cy) = layout.center;
+        canvas.fill_bezier_path(&self.needle_path(&layout), self.needle_color);
+        canvas.fill_circle(cx, cy, self.hub_radius(&layout), self.needle_color);
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Value below the hub, range at the ends of the dial
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let value = self.format_value(self.value);
+        let (w, h) = measure_text_with_fallbacks(
+            &value,
+            font_path,
+            &cfg.font_fallbacks,
+            cfg.font_size_title,
+        );
+        self.draw_text_with_halo(
+            canvas,
+            cfg,
+            (
+                (cx - w as f64 / 2.0).max(0.0) as u32,
+                (cy + layout.radius * Self::VALUE_DEPTH - h as f64 / 2.0).max(0.0) as u32,
+            ),
+            &value,
+            cfg.color_title,
+            PxScale {
+                x: cfg.font_size_title,
+                y: cfg.font_size_title,
+            },
+        );
+        for ((x, y), label) in self.range_label_anchors(&layout) {
+            let (w, _) = measure_text_with_fallbacks(
+                &label,
+                font_path,
+                &cfg.font_fallbacks,
+                cfg.font_size_axis,
+            );
+            self.draw_text_with_halo(
+                canvas,
+                cfg,
+                ((x - w as f64 / 2.0).max(0.0) as u32, y.max(0.0) as u32),
+                &label,
+                cfg.color_axis,
+                PxScale {
+                    x: cfg.font_size_axis,
+                    y: cfg.font_size_axis,
+                },
+            );
+        }
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // A gauge shows a single value, so no legend is drawn
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
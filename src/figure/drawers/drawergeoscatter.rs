@@ -0,0 +1,313 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::geoscatter::GeoScatter,
+    utilities::{
+        axistype::AxisType, linetype::LineType, scaling::tick_values,
+        textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl GeoScatter {
+    /// Computes the longitude and latitude ticks as `(value, canvas position)` pairs.
+    ///
+    /// Longitude positions are x-coordinates, latitude positions y-coordinates.
+    #[allow(clippy::type_complexity)]
+    fn graticule_ticks(&self, layout: (f64, f64, f64)) -> (Vec<(f64, f64)>, Vec<(f64, f64)>) {
+        let (lon_min, lon_max, lat_min, lat_max) = self.geographic_extent();
+        let num_ticks = self.config.num_axis_ticks;
+        let longitudes = tick_values(lon_min, lon_max, num_ticks)
+            .into_iter()
+            .map(|lon| (lon, self.to_canvas((lon, lat_min), layout).0))
+            .collect();
+        let latitudes = tick_values(lat_min, lat_max, num_ticks)
+            .into_iter()
+            .map(|lat| (lat, self.to_canvas((lon_min, lat), layout).1))
+            .collect();
+        (longitudes, latitudes)
+    }
+}
+
+impl Drawer for GeoScatter {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let layout = self.layout(width, height, margin);
+        let (left, top, right, bottom) = self.map_area(layout);
+
+        // Basemap, then coastlines
+        if let Some(((x, y), image)) = self.render_basemap(layout) {
+            svg_canvas.draw_image(x, y, &image);
+        }
+        let coastline = self.rgb_to_svg_color(self.color_coastline);
+        for ((x1, y1), (x2, y2)) in self.coastline_segments(layout) {
+            svg_canvas.draw_line(x1, y1, x2, y2, &coastline, 1.0);
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Points inside the map extent
+        for dataset in &self.datasets {
+            for &(longitude, latitude) in &dataset.points {
+                if !self.contains(longitude, latitude) {
+                    continue;
+                }
+                let (x, y) = self.to_canvas((longitude, latitude), layout);
+                dataset.dot_type.draw_svg(svg_canvas, x, y, dataset.color);
+            }
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Map frame with longitude and latitude ticks
+        svg_canvas.draw_rect(
+            left,
+            top,
+            right - left,
+            bottom - top,
+            "none",
+            "black",
+            1.0,
+            0.0,
+        );
+        let (longitudes, latitudes) = self.graticule_ticks(layout);
+        for (longitude, x) in longitudes {
+            let label = cfg.tick_label(AxisType::AxisX, longitude, |v| format!("{v:.1}°"));
+            svg_canvas.draw_tick_label(
+                x,
+                bottom + font_size * 1.5,
+                &label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+        for (latitude, y) in latitudes {
+            let label = cfg.tick_label(AxisType::AxisY, latitude, |v| format!("{v:.1}°"));
+            svg_canvas.draw_tick_label(
+                left - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Dataset legend below the longitude ticks
+        let legend_y = bottom + font_size * 2.5;
+        let mut legend_x = left;
+        for dataset in &self.datasets {
+            svg_canvas.draw_rect(
+                legend_x,
+                legend_y,
+                font_size,
+                font_size,
+                &self.rgb_to_svg_color(dataset.color),
+                "none",
+                0.0,
+                1.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                legend_x + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                dataset.label
+            ));
+            legend_x += font_size * 3.0 + dataset.label.len() as f64 * font_size * 0.6;
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let layout = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (left, top, right, bottom) = self.map_area(layout);
+
+        // Basemap, then coastlines
+        if let Some(((x, y), image)) = self.render_basemap(layout) {
+            let (x, y) = (x.round() as u32, y.round() as u32);
+            for (dx, dy, pixel) in image.enumerate_pixels() {
+                canvas.draw_pixel(x + dx, y + dy, pixel.0);
+            }
+        }
+        for ((x1, y1), (x2, y2)) in self.coastline_segments(layout) {
+            canvas.draw_line(
+                x1.round() as i32,
+                y1.round() as i32,
+                x2.round() as i32,
+                y2.round() as i32,
+                self.color_coastline,
+                LineType::Solid,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Points inside the map extent
+        for dataset in &self.datasets {
+            for &(longitude, latitude) in &dataset.points {
+                if !self.contains(longitude, latitude) {
+                    continue;
+                }
+                let (x, y) = self.to_canvas((longitude, latitude), layout);
+                dataset
+                    .dot_type
+                    .draw(canvas, x.round() as i32, y.round() as i32, dataset.color);
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Map frame with longitude and latitude ticks
+        let (left, top, right, bottom) = (
+            left.round() as i32,
+            top.round() as i32,
+            right.round() as i32,
+            bottom.round() as i32,
+        );
+        for (x1, y1, x2, y2) in [
+            (left, top, right, top),
+            (left, bottom, right, bottom),
+            (left, top, left, bottom),
+            (right, top, right, bottom),
+        ] {
+            self.draw_axis(canvas, cfg, x1, y1, x2, y2);
+        }
+        let (longitudes, latitudes) = self.graticule_ticks(layout);
+        for (longitude, x) in longitudes {
+            let label = cfg.tick_label(AxisType::AxisX, longitude, |v| format!("{v:.1}°"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                x as u32,
+                bottom as u32,
+                &label,
+                AxisType::AxisX,
+            );
+        }
+        for (latitude, y) in latitudes {
+            let label = cfg.tick_label(AxisType::AxisY, latitude, |v| format!("{v:.1}°"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                (left as u32).saturating_sub(10),
+                y as u32,
+                &label,
+                AxisType::AxisY,
+            );
+        }
+
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+
+        // Datasets are listed in a single row below the longitude ticks
+        let layout = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (left, _, _, bottom) = self.map_area(layout);
+        let mut x = left as u32;
+        let y = bottom as u32 + square_size * 3;
+
+        for dataset in &self.datasets {
+            let (w, _) = measure_text_with_fallbacks(
+                &dataset.label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(x + dx, y + dy, dataset.color);
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                x + square_size + padding,
+                y,
+                &dataset.label,
+                self.config.color_axis,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+            x += square_size + padding + w + square_size;
+        }
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -0,0 +1,378 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::heatmap::Heatmap,
+    utilities::{
+        axistype::AxisType, colorbar::COLOR_BAR_GAP, colormap::text_color_for, linetype::LineType,
+        textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl Heatmap {
+    /// Returns the labels of the drawn rows and columns, given in the order of
+    /// `cell_order`, shortened to `max_category_label_width` and thinned where they would
+    /// overlap.
+    fn axis_labels(
+        &self,
+        (row_order, column_order): (&[usize], &[usize]),
+        columns_width: f64,
+        rows_height: f64,
+        font_size: f32,
+    ) -> (Vec<String>, Vec<String>) {
+        let cfg = &self.config;
+        let rows = row_order.len();
+        let columns = column_order.len();
+        let row_labels = row_order
+            .iter()
+            .map(|&row| cfg.category_label(&self.row_label(row), font_size))
+            .collect();
+        let column_labels = column_order
+            .iter()
+            .map(|&column| cfg.category_label(&self.column_label(column), font_size))
+            .collect();
+        (
+            cfg.thin_tick_labels(
+                AxisType::AxisY,
+                row_labels,
+                rows_height * rows.saturating_sub(1) as f64 / rows.max(1) as f64,
+                font_size,
+            ),
+            cfg.thin_tick_labels(
+                AxisType::AxisX,
+                column_labels,
+                columns_width * columns.saturating_sub(1) as f64 / columns.max(1) as f64,
+                font_size,
+            ),
+        )
+    }
+
+    /// Returns the labels of the color bar, at the top, middle and bottom of the range, as
+    /// `(fraction of the height from the bottom, text)` pairs.
+    fn color_bar_labels(&self) -> Vec<(f64, String)> {
+        let (min, max) = self.range();
+        [(1.0, max), (0.5, (min + max) / 2.0), (0.0, min)]
+            .into_iter()
+            .map(|(fraction, value)| (fraction, self.format_value(value)))
+            .collect()
+    }
+
+    /// Returns the color at a fraction of the color bar's height from the bottom.
+    fn color_bar_color(&self, fraction: f64) -> [u8; 3] {
+        let (min, max) = self.range();
+        self.value_color(min + fraction * (max - min))
+    }
+}
+
+impl Drawer for Heatmap {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let area = self.plot_area(width, height, margin);
+        let (left, top, right, bottom) = area;
+        let rows = self.num_rows();
+        let columns = self.num_columns();
+        let cell_width = (right - left) / columns.max(1) as f64;
+        let cell_height = (bottom - top) / rows.max(1) as f64;
+        let (row_order, column_order) = self.cell_order();
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Draw cells, with their values when enabled; cells missing from short rows stay empty
+        for (grid_row, &row) in row_order.iter().enumerate() {
+            for (grid_column, &column) in column_order.iter().enumerate() {
+                let Some(&value) = self.values[row].get(column) else {
+                    continue;
+                };
+                let color = self.value_color(value);
+                let x = left + grid_column as f64 * cell_width;
+                let y = top + grid_row as f64 * cell_height;
+                svg_canvas.draw_rect(
+                    x,
+                    y,
+                    cell_width,
+                    cell_height,
+                    &self.rgb_to_svg_color(color),
+                    "none",
+                    0.0,
+                    1.0,
+                );
+
+                if self.show_values {
+                    svg_canvas.draw_value_label(
+                        x + cell_width / 2.0,
+                        y + cell_height / 2.0 + font_size * 0.35,
+                        &self.format_value(value),
+                        font_size,
+                        &self.rgb_to_svg_color(text_color_for(
+                            cfg.text_halo.map_or(color, |halo| halo.color()),
+                        )),
+                    );
+                }
+            }
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Dendrograms of a clustered heatmap, above and right of the grid
+        let axis_color = self.rgb_to_svg_color(cfg.color_axis);
+        for ((x1, y1), (x2, y2)) in self.dendrogram_lines(area) {
+            svg_canvas.draw_line(x1, y1, x2, y2, &axis_color, 1.0);
+        }
+
+        // Row labels left of the grid, column labels below it
+        let (row_labels, column_labels) = self.axis_labels(
+            (&row_order, &column_order),
+            right - left,
+            bottom - top,
+            font_size as f32,
+        );
+        for ((grid_row, label), &row) in row_labels.iter().enumerate().zip(&row_order) {
+            svg_canvas.draw_category_label(
+                left - 5.0,
+                top + (grid_row as f64 + 0.5) * cell_height + font_size * 0.3,
+                label,
+                &self.row_label(row),
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+        for ((grid_column, label), &column) in column_labels.iter().enumerate().zip(&column_order) {
+            svg_canvas.draw_category_label(
+                left + (grid_column as f64 + 0.5) * cell_width,
+                bottom + font_size * 1.5,
+                label,
+                &self.column_label(column),
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            (left + right) / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        // Color bar from the lowest (bottom) to the highest value (top)
+        self.draw_svg_color_bar(
+            svg_canvas,
+            &self.config,
+            right + self.dendrogram_space() + COLOR_BAR_GAP,
+            (top, bottom),
+            50,
+            &|fraction| self.color_bar_color(fraction),
+            &self.color_bar_labels(),
+            font_size,
+        );
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let area = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (left, top, right, bottom) = area;
+        let rows = self.num_rows();
+        let columns = self.num_columns();
+        let cell_width = (right - left) / columns.max(1) as f64;
+        let cell_height = (bottom - top) / rows.max(1) as f64;
+        let (row_order, column_order) = self.cell_order();
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Rasterize the cells, one color per pixel of the plot area
+        for y in top.round() as u32..bottom.round() as u32 {
+            for x in left.round() as u32..right.round() as u32 {
+                let value = self
+                    .grid_cell_at(x as f64 + 0.5, y as f64 + 0.5, area)
+                    .and_then(|(row, column)| {
+                        let row = *row_order.get(row)?;
+                        self.values[row].get(*column_order.get(column)?)
+                    });
+                if let Some(&value) = value {
+                    canvas.draw_pixel(x, y, self.value_color(value));
+                }
+            }
+        }
+
+        // Cell values, centered in their cells
+        if self.show_values {
+            let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+            let scale = PxScale {
+                x: cfg.font_size_axis,
+                y: cfg.font_size_axis,
+            };
+            for (grid_row, &row) in row_order.iter().enumerate() {
+                for (grid_column, &column) in column_order.iter().enumerate() {
+                    let Some(&value) = self.values[row].get(column) else {
+                        continue;
+                    };
+                    let text = self.format_value(value);
+                    let (w, h) = measure_text_with_fallbacks(
+                        &text,
+                        font_path,
+                        &cfg.font_fallbacks,
+                        cfg.font_size_axis,
+                    );
+                    let center_x = left + (grid_column as f64 + 0.5) * cell_width;
+                    let center_y = top + (grid_row as f64 + 0.5) * cell_height;
+                    self.draw_text_with_halo(
+                        canvas,
+                        cfg,
+                        (
+                            (center_x as u32).saturating_sub(w / 2),
+                            (center_y as u32).saturating_sub(h / 2),
+                        ),
+                        &text,
+                        text_color_for(
+                            cfg.text_halo
+                                .map_or(self.value_color(value), |halo| halo.color()),
+                        ),
+                        scale,
+                    );
+                }
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Dendrograms of a clustered heatmap, above and right of the grid
+        for ((x1, y1), (x2, y2)) in self.dendrogram_lines(area) {
+            canvas.draw_line(
+                x1.round() as i32,
+                y1.round() as i32,
+                x2.round() as i32,
+                y2.round() as i32,
+                cfg.color_axis,
+                LineType::Solid,
+            );
+        }
+
+        // Row labels left of the grid, column labels below it
+        let (row_labels, column_labels) = self.axis_labels(
+            (&row_order, &column_order),
+            right - left,
+            bottom - top,
+            cfg.font_size_axis,
+        );
+        for (grid_row, label) in row_labels.iter().enumerate() {
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                (left as u32).saturating_sub(10),
+                (top + (grid_row as f64 + 0.5) * cell_height) as u32,
+                label,
+                AxisType::AxisY,
+            );
+        }
+        for (grid_column, label) in column_labels.iter().enumerate() {
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                (left + (grid_column as f64 + 0.5) * cell_width) as u32,
+                bottom as u32,
+                label,
+                AxisType::AxisX,
+            );
+        }
+
+        self.draw_label(
+            canvas,
+            cfg,
+            right as u32,
+            canvas.height - canvas.margin / 3,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let (_, top, right, bottom) = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+
+        // Color bar from the lowest (bottom) to the highest value (top), right of the plot
+        self.draw_color_bar(
+            canvas,
+            &self.config,
+            right + self.dendrogram_space() + COLOR_BAR_GAP,
+            (top, bottom),
+            &|fraction| self.color_bar_color(fraction),
+            &self.color_bar_labels(),
+        );
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -0,0 +1,289 @@
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::hist2d::Hist2D,
+    utilities::{axistype::AxisType, colorbar::COLOR_BAR_GAP, scaling::tick_values},
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Number of labels on the color bar.
+const COLOR_BAR_LABELS: usize = 5;
+
+impl Hist2D {
+    /// Computes the x and y ticks as `(value, canvas position)` pairs.
+    ///
+    /// X positions are x-coordinates, y positions y-coordinates.
+    #[allow(clippy::type_complexity)]
+    fn axis_ticks(&self, area: (f64, f64, f64, f64)) -> (Vec<(f64, f64)>, Vec<(f64, f64)>) {
+        let num_ticks = self.config.num_axis_ticks;
+        let ranges = self.ranges();
+        let ((x0, x1), (y0, y1)) = ranges;
+        let xs = tick_values(x0, x1, num_ticks)
+            .into_iter()
+            .map(|x| (x, self.to_canvas(x, y0, ranges, area).0))
+            .collect();
+        let ys = tick_values(y0, y1, num_ticks)
+            .into_iter()
+            .map(|y| (y, self.to_canvas(x0, y, ranges, area).1))
+            .collect();
+        (xs, ys)
+    }
+
+    /// Computes the labels of the color bar as `(fraction of the height from the bottom,
+    /// text)` pairs, evenly spaced along the bar so log scales label their own counts.
+    fn color_bar_labels(&self, range: (f64, f64)) -> Vec<(f64, String)> {
+        if range.1 <= range.0 {
+            return vec![(1.0, self.format_count(range.1))];
+        }
+        (0..COLOR_BAR_LABELS)
+            .map(|index| {
+                let fraction = index as f64 / (COLOR_BAR_LABELS - 1) as f64;
+                (
+                    fraction,
+                    self.format_count(self.count_at_fraction(fraction, range)),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Drawer for Hist2D {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // One polygon per bin holding points
+        let area = self.plot_area(width, height, margin);
+        let (left, top, right, bottom) = area;
+        let ranges = self.ranges();
+        let cells = self.cells();
+        let range = self.count_range(&cells);
+        for cell in &cells {
+            let points: Vec<String> = cell
+                .outline
+                .iter()
+                .map(|&(x, y)| {
+                    let (px, py) = self.to_canvas(x, y, ranges, area);
+                    format!("{px:.2},{py:.2}")
+                })
+                .collect();
+            let color = self.rgb_to_svg_color(self.count_color(cell.count, range));
+            svg_canvas.elements.push(format!(
+                r#"<polygon points="{}" fill="{color}" stroke="{color}" stroke-width="0.5" data-tooltip="({}, {}): {}"/>"#,
+                points.join(" "),
+                cfg.localize_value(AxisType::AxisX, &format!("{:.2}", cell.center.0)),
+                cfg.localize_value(AxisType::AxisY, &format!("{:.2}", cell.center.1)),
+                self.format_count(cell.count)
+            ));
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Axes with x and y ticks
+        svg_canvas.draw_line(left, bottom, right, bottom, "black", 2.0);
+        svg_canvas.draw_line(left, top, left, bottom, "black", 2.0);
+        let (xs, ys) = self.axis_ticks(area);
+        for (value, x) in xs {
+            let label = cfg.tick_label(AxisType::AxisX, value, |v| format!("{v:.2}"));
+            svg_canvas.draw_tick_label(
+                x,
+                bottom + font_size * 1.5,
+                &label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+        for (value, y) in ys {
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.2}"));
+            svg_canvas.draw_tick_label(
+                left - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            (left + right) / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        // Continuous color bar from the lowest (bottom) to the highest count (top)
+        if !cells.is_empty() {
+            self.draw_svg_color_bar(
+                svg_canvas,
+                cfg,
+                right + COLOR_BAR_GAP,
+                (top, bottom),
+                50,
+                &|fraction| self.count_color(self.count_at_fraction(fraction, range), range),
+                &self.color_bar_labels(range),
+                font_size,
+            );
+        }
+
+        self.draw_svg_axis_units(svg_canvas, cfg, font_size);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // One polygon per bin holding points
+        let area = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let ranges = self.ranges();
+        let cells = self.cells();
+        let range = self.count_range(&cells);
+        for cell in &cells {
+            let outline: Vec<(f64, f64)> = cell
+                .outline
+                .iter()
+                .map(|&(x, y)| self.to_canvas(x, y, ranges, area))
+                .collect();
+            canvas.fill_polygon(&[outline], self.count_color(cell.count, range));
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Axes with x and y ticks
+        let (left, top, right, bottom) = (
+            area.0.round() as i32,
+            area.1.round() as i32,
+            area.2.round() as i32,
+            area.3.round() as i32,
+        );
+        self.draw_axis(canvas, cfg, left, bottom, right, bottom);
+        self.draw_axis(canvas, cfg, left, top, left, bottom);
+        let (xs, ys) = self.axis_ticks(area);
+        for (value, x) in xs {
+            let label = cfg.tick_label(AxisType::AxisX, value, |v| format!("{v:.2}"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                x as u32,
+                bottom as u32,
+                &label,
+                AxisType::AxisX,
+            );
+        }
+        for (value, y) in ys {
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.2}"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                (left as u32).saturating_sub(10),
+                y as u32,
+                &label,
+                AxisType::AxisY,
+            );
+        }
+
+        self.draw_label(
+            canvas,
+            cfg,
+            right as u32,
+            canvas.height - canvas.margin / 3,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_axis_units(canvas, cfg);
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let cells = self.cells();
+        if cells.is_empty() {
+            return;
+        }
+        let range = self.count_range(&cells);
+        let (_, top, right, bottom) = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+
+        // Continuous color bar from the lowest (bottom) to the highest count (top), right
+        // of the plot
+        self.draw_color_bar(
+            canvas,
+            &self.config,
+            right + COLOR_BAR_GAP,
+            (top, bottom),
+            &|fraction| self.count_color(self.count_at_fraction(fraction, range), range),
+            &self.color_bar_labels(range),
+        );
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -1,14 +1,74 @@
+use ab_glyph::PxScale;
+
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
     figuretypes::histogram::Histogram,
-    utilities::axistype::AxisType,
+    utilities::{
+        axistype::AxisType, linetype::LineType, scaling::tick_values,
+        textmetrics::measure_text_with_fallbacks,
+    },
 };
 
 use super::drawer::Drawer;
 use std::any::Any;
+
+impl Histogram {
+    /// Returns the labels of the underflow and overflow bars, e.g. `< 0.0` and `> 100.0`.
+    fn overflow_labels(&self) -> [String; 2] {
+        let labels = self
+            .config
+            .tick_labels(AxisType::AxisX, &[self.min, self.max], |v| {
+                format!("{v:.1}")
+            });
+        [format!("< {}", labels[0]), format!("> {}", labels[1])]
+    }
+
+    /// Fills a bar on a `PixelCanvas` and outlines its left, right and top edges in black.
+    /// Hatched bars are drawn with diagonal stripes over a light fill.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_pixel_bar(
+        canvas: &mut PixelCanvas,
+        (left, right): (i32, i32),
+        (top, bottom): (i32, i32),
+        color: [u8; 3],
+        alpha: f64,
+        hatched: bool,
+    ) {
+        // Fill the bar, see-through where sets overlap
+        for x in left..=right {
+            for y in top..bottom {
+                let stripe = !hatched || (x + y).rem_euclid(6) < 2;
+                let alpha = if stripe { alpha } else { alpha * 0.25 };
+                canvas.blend_pixel(x as u32, y as u32, color, alpha);
+            }
+        }
+
+        // Draw the edges (outline)
+        let edge_color = [0, 0, 0]; // Black color for edges
+        for y in top..bottom {
+            canvas.draw_pixel(left as u32, y as u32, edge_color);
+            canvas.draw_pixel(right as u32, y as u32, edge_color);
+        }
+        for x in left..=right {
+            canvas.draw_pixel(x as u32, top as u32, edge_color);
+        }
+    }
+}
+
 impl Drawer for Histogram {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
         // Clear existing SVG elements
 
         let width = svg_canvas.width as f64;
@@ -16,10 +76,9 @@ impl Drawer for Histogram {
         let margin = svg_canvas.margin as f64;
         let font_size = 12.0;
         let cfg = &self.config;
-        let margin_bg_color = svg_canvas.background_color.clone();
 
         // Draw margin background (using SvgCanvas background_color parameter)
-        svg_canvas.draw_rect(0.0, 0.0, width, height, &margin_bg_color, "black", 1.0, 1.0);
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
         // Draw chart background (using FigureConfig color)
         self.fill_svg_background(svg_canvas, cfg);
 
@@ -32,22 +91,18 @@ impl Drawer for Histogram {
             "black",
         );
 
-        // Calculate range and scales
-        let y_max = self.bin_counts.iter().cloned().fold(0.0, f64::max);
-        let scale_x = (width - 2.0 * margin) / (self.max - self.min);
+        // Calculate range and scales, shared by all sample sets
+        let y_max = self.y_max();
+        let (x_min, x_max) = self.x_range();
+        let scale_x = (width - 2.0 * margin) / (x_max - x_min);
         let scale_y = (height - 2.0 * margin) / y_max;
+        let to_x = |value: f64| margin + (value - x_min) * scale_x;
 
         // Draw grid
         let num_ticks = 10;
-        svg_canvas.draw_grid(
-            margin,
-            width - margin,
-            margin,
-            height - margin,
-            num_ticks,
-            num_ticks,
-            "lightgray",
-        );
+        let x_ticks = tick_values(to_x(self.min), to_x(self.max), num_ticks);
+        let y_ticks = tick_values(height - margin, margin, num_ticks);
+        self.draw_svg_tick_grid(svg_canvas, cfg, &x_ticks, &y_ticks);
 
         // Draw axes
         let origin_x = margin;
@@ -58,9 +113,15 @@ impl Drawer for Histogram {
 
         // X-axis
         let mut x_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = self.max + i as f64 * (self.max - self.min) / num_ticks as f64;
-            let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
+        let x_values = tick_values(self.min, self.max, num_ticks);
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            font_size as f32,
+        );
+        for (&x, label) in x_ticks.iter().zip(x_labels) {
             let tick_start_y = origin_y - 5.0;
             let tick_end_y = origin_y + 5.0;
 
@@ -69,9 +130,13 @@ impl Drawer for Histogram {
             ));
 
             // Draw value as text (fallback to basic SVG <text>)
-            svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{:.1}</text>"#,
-            x, height - margin + font_size * 1.5, font_size, value));
+            svg_canvas.draw_tick_label(
+                x,
+                height - margin + font_size * 1.5,
+                &label,
+                font_size,
+                AxisType::AxisX,
+            );
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{x_axis_ticks}" stroke="black" stroke-width="1" fill="none"/>"#
@@ -79,9 +144,17 @@ impl Drawer for Histogram {
 
         // Y-axis
         let mut y_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = 0.0 + i as f64 * (y_max - 0.0) / num_ticks as f64;
-            let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
+        let y_values = tick_values(0.0, y_max, num_ticks);
+        let decimals = self.y_decimals();
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.decimals$}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            height - 2.0 * margin,
+            font_size as f32,
+        );
+        for (i, label) in y_labels.into_iter().enumerate() {
+            let y = y_ticks[i];
             let tick_start_x = origin_x - 5.0;
             let tick_end_x = origin_x + 5.0;
 
@@ -90,10 +163,13 @@ impl Drawer for Histogram {
             ));
 
             // Draw value as text (fallback to basic SVG <text>)
-            svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{:.1}</text>"#,
-            margin - 5.0, y + font_size * 0.3, font_size, value
-        ));
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{y_axis_ticks}" stroke="black" stroke-width="1" fill="none"/>"#
@@ -109,43 +185,154 @@ impl Drawer for Histogram {
         );
 
         // Draw Y-axis label (rotated)
-        svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(-90 {:.2} {:.2})">{}</text>"#,
+        svg_canvas.draw_rotated_label(
             margin / 3.0,
             height / 2.0,
+            &self.y_label,
             font_size * 1.5,
-            margin / 3.0,
-            height / 2.0,
-            self.y_label
-        ));
+            "black",
+        );
 
-        // Draw histogram bars
-        for (i, &count) in self.bin_counts.iter().enumerate() {
-            let bin_start = self.min + i as f64 * self.bin_width;
-            let bin_end = bin_start + self.bin_width;
-
-            let x_start = margin + (bin_start - self.min) * scale_x;
-            let x_end = margin + (bin_end - self.min) * scale_x;
-            let bar_width = x_end - x_start;
-            let bar_height = count * scale_y;
-
-            svg_canvas.draw_rect(
-                x_start,
-                origin_y - bar_height,
-                bar_width,
-                bar_height,
-                &format!("rgb({},{},{})", self.color[0], self.color[1], self.color[2]),
-                "black",
-                1.0,
-                1.0,
-            );
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Draw histogram bars, one set after the other
+        let entries = self.legend_entries();
+        let alpha = self.bar_alpha();
+        for (set_index, bin_data) in self.calculate_all_bins().iter().enumerate() {
+            let color = entries[set_index].1;
+            let (start, end) = self.bar_fraction(set_index);
+            for &(bin_start, count) in bin_data {
+                let x_start = to_x(bin_start + start * self.bin_width);
+                let x_end = to_x(bin_start + end * self.bin_width);
+                let bar_width = x_end - x_start;
+                let bar_height = count * scale_y;
+
+                svg_canvas.draw_rect(
+                    x_start,
+                    origin_y - bar_height,
+                    bar_width,
+                    bar_height,
+                    &format!("rgb({},{},{})", color[0], color[1], color[2]),
+                    "black",
+                    1.0,
+                    alpha,
+                );
+            }
         }
+
+        // Hatched underflow and overflow bars beyond the edges of the range
+        let overflow_bins = self.calculate_overflow_bins();
+        for (set_index, edge_bars) in overflow_bins.iter().enumerate() {
+            let color = self.rgb_to_svg_color(entries[set_index].1);
+            let (start, end) = self.bar_fraction(set_index);
+            let pattern = format!("histogram-overflow-{set_index}");
+            svg_canvas.elements.push(format!(
+                r#"<defs><pattern id="{pattern}" width="6" height="6" patternUnits="userSpaceOnUse" patternTransform="rotate(45)"><rect width="6" height="6" fill="{color}" fill-opacity="0.25"/><line x1="0" y1="0" x2="0" y2="6" stroke="{color}" stroke-width="3"/></pattern></defs>"#
+            ));
+            for &(bar_start, count) in edge_bars {
+                let x_start = to_x(bar_start + start * self.bin_width);
+                let x_end = to_x(bar_start + end * self.bin_width);
+                let bar_height = count * scale_y;
+                svg_canvas.draw_rect(
+                    x_start,
+                    origin_y - bar_height,
+                    x_end - x_start,
+                    bar_height,
+                    &format!("url(#{pattern})"),
+                    "black",
+                    1.0,
+                    alpha,
+                );
+            }
+        }
+        if !overflow_bins.is_empty() {
+            for (side, label) in self.overflow_labels().iter().enumerate() {
+                let bar_start = overflow_bins[0][side].0;
+                let top = overflow_bins
+                    .iter()
+                    .map(|edge_bars| edge_bars[side].1)
+                    .fold(0.0, f64::max);
+                svg_canvas.draw_tick_label(
+                    to_x(bar_start + self.bin_width / 2.0),
+                    origin_y - top * scale_y - font_size * 0.5,
+                    label,
+                    font_size,
+                    AxisType::AxisX,
+                );
+            }
+        }
+
+        // Density curve over the bars
+        if let Some(overlay) = &self.density_overlay {
+            let points: Vec<String> = self
+                .density_curve()
+                .iter()
+                .map(|&(value, height)| {
+                    format!("{:.2},{:.2}", to_x(value), origin_y - height * scale_y)
+                })
+                .collect();
+            svg_canvas.elements.push(format!(
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="2"/>"#,
+                points.join(" "),
+                self.rgb_to_svg_color(overlay.color)
+            ));
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        self.draw_svg_axis_units(svg_canvas, cfg, font_size);
+
+        // Legend of the compared sample sets
+        if !self.config.show_legend || self.series.is_empty() {
+            return;
+        }
+        let legend_x_start = margin + 10.0;
+        let tick_overflow = cfg.x_tick_label_overflow(font_size as f32) as f64;
+        let legend_y = height - margin + font_size * 1.5 + 10.0 + tick_overflow;
+        let mut legend_x = legend_x_start;
+        let mut elements = String::new();
+        let legend_bg_color = svg_canvas.background_color.clone();
+
+        for (label, color) in &entries {
+            elements.push_str(&format!(
+                r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
+                legend_x, legend_y, font_size, font_size, color[0], color[1], color[2]
+            ));
+            elements.push_str(&format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="rgb({},{},{})">{}</text>"#,
+                legend_x + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                color[0],
+                color[1],
+                color[2],
+                escape_xml(label)
+            ));
+            legend_x += font_size * 5.0 + label.len() as f64 * font_size * 0.6;
+        }
+
+        svg_canvas.draw_rect(
+            legend_x_start - 5.0,
+            legend_y - 5.0,
+            legend_x - legend_x_start + 5.0,
+            font_size + 10.0,
+            &legend_bg_color,
+            "black",
+            0.5,
+            0.5,
+        );
+        svg_canvas.elements.push(elements);
     }
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
         canvas.clear();
 
         let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
         self.fill_background(canvas, cfg);
 
         let margin = canvas.margin;
@@ -156,83 +343,168 @@ impl Drawer for Histogram {
         self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
 
         let bin_data = self.calculate_bins();
-        let y_max = bin_data.iter().map(|&(_, freq)| freq).fold(0.0, f64::max);
+        let y_max = self.y_max();
 
-        let scale_x = (width - 2 * margin) as f64 / self.bins as f64;
+        // The underflow and overflow bars take one bin width beyond each edge
+        let edge_slots = usize::from(self.shows_overflow_bins());
+        let scale_x = (width - 2 * margin) as f64 / (self.bins + 2 * edge_slots) as f64;
         let scale_y = (height - 2 * margin) as f64 / y_max;
 
-        canvas.draw_grid(
-            &[cfg.num_grid_horizontal, cfg.num_grid_vertical],
-            cfg.color_grid,
-        );
-
         // Draw axes
         let origin_x = margin as i32;
         let origin_y = height as i32 - margin as i32;
 
+        // Grid lines follow the bin edges and the y-axis ticks
+        let num_y_ticks = 10;
+        let x_ticks: Vec<f64> = (0..=self.bins)
+            .map(|i| (origin_x + ((i + edge_slots) as f64 * scale_x) as i32) as f64)
+            .collect();
+        let y_ticks: Vec<f64> = (0..=num_y_ticks)
+            .map(|i| (origin_y - (y_max * i as f64 / num_y_ticks as f64 * scale_y) as i32) as f64)
+            .collect();
+        self.draw_tick_grid(canvas, cfg, &x_ticks, &y_ticks);
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
         // Draw bars with edges
         let bin_start = bin_data[0].0; // First bin's start
         let bin_width = (bin_data[1].0 - bin_start).abs(); // Width of each bin
 
-        for (i, &(_, freq)) in bin_data.iter().enumerate() {
-            let bar_height = (freq * scale_y) as i32;
-            let bar_left = origin_x + (i as f64 * scale_x) as i32;
-            let bar_right = bar_left + scale_x as i32;
-
-            // Fill the bar
-            for x in bar_left..=bar_right {
-                for y in (origin_y - bar_height)..origin_y {
-                    canvas.draw_pixel(x as u32, y as u32, self.color);
-                }
+        let entries = self.legend_entries();
+        let alpha = self.bar_alpha();
+        for (set_index, set_bins) in self.calculate_all_bins().iter().enumerate() {
+            let color = entries[set_index].1;
+            let (start, end) = self.bar_fraction(set_index);
+            for (i, &(_, freq)) in set_bins.iter().enumerate() {
+                let bar_height = (freq * scale_y) as i32;
+                let bar_left = origin_x + (((i + edge_slots) as f64 + start) * scale_x) as i32;
+                let bar_right = bar_left + ((end - start) * scale_x) as i32;
+                Self::draw_pixel_bar(
+                    canvas,
+                    (bar_left, bar_right),
+                    (origin_y - bar_height, origin_y),
+                    color,
+                    alpha,
+                    false,
+                );
             }
+        }
 
-            // Draw the edges (outline)
-            let edge_color = [0, 0, 0]; // Black color for edges
-                                        // Left edge
-            for y in (origin_y - bar_height)..origin_y {
-                canvas.draw_pixel(bar_left as u32, y as u32, edge_color);
+        // Hatched underflow and overflow bars beyond the edges of the range
+        let overflow_bins = self.calculate_overflow_bins();
+        let edge_slot_index = [0, self.bins + 1];
+        for (set_index, edge_bars) in overflow_bins.iter().enumerate() {
+            let color = entries[set_index].1;
+            let (start, end) = self.bar_fraction(set_index);
+            for (&(_, freq), &slot) in edge_bars.iter().zip(&edge_slot_index) {
+                let bar_height = (freq * scale_y) as i32;
+                let bar_left = origin_x + ((slot as f64 + start) * scale_x) as i32;
+                let bar_right = bar_left + ((end - start) * scale_x) as i32;
+                Self::draw_pixel_bar(
+                    canvas,
+                    (bar_left, bar_right),
+                    (origin_y - bar_height, origin_y),
+                    color,
+                    alpha,
+                    true,
+                );
             }
-            // Right edge
-            for y in (origin_y - bar_height)..origin_y {
-                canvas.draw_pixel(bar_right as u32, y as u32, edge_color);
+        }
+        if !overflow_bins.is_empty() {
+            for (side, label) in self.overflow_labels().iter().enumerate() {
+                let top = overflow_bins
+                    .iter()
+                    .map(|edge_bars| edge_bars[side].1)
+                    .fold(0.0, f64::max);
+                let center_x = origin_x + ((edge_slot_index[side] as f64 + 0.5) * scale_x) as i32;
+                let label_y = origin_y - (top * scale_y) as i32 - (cfg.font_size_axis * 2.0) as i32;
+                self.draw_axis_value(
+                    canvas,
+                    cfg,
+                    center_x as u32,
+                    label_y.max(0) as u32,
+                    label,
+                    AxisType::AxisX,
+                );
             }
-            // Top edge
-            for x in bar_left..=bar_right {
-                canvas.draw_pixel(x as u32, (origin_y - bar_height) as u32, edge_color);
+        }
+
+        // Density curve over the bars
+        if let Some(overlay) = &self.density_overlay {
+            let line: Vec<(i32, i32)> = self
+                .density_curve()
+                .iter()
+                .map(|&(value, height)| {
+                    (
+                        origin_x
+                            + (((value - self.min) / self.bin_width + edge_slots as f64) * scale_x)
+                                .round() as i32,
+                        origin_y - (height * scale_y).round() as i32,
+                    )
+                })
+                .collect();
+            for segment in line.windows(2) {
+                canvas.draw_line(
+                    segment[0].0,
+                    segment[0].1,
+                    segment[1].0,
+                    segment[1].1,
+                    overlay.color,
+                    LineType::Solid,
+                );
             }
         }
 
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
         // Add x-axis ticks and labels at bin edges
-        for i in 0..=self.bins {
-            let edge_x = origin_x + (i as f64 * scale_x) as i32;
-            let edge_value = bin_start + i as f64 * bin_width;
+        let edge_values: Vec<f64> = (0..=self.bins)
+            .map(|i| bin_start + i as f64 * bin_width)
+            .collect();
+        let edge_labels = cfg.tick_labels(AxisType::AxisX, &edge_values, |v| format!("{v:.1}"));
+        let edge_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            edge_labels,
+            self.bins as f64 * scale_x,
+            cfg.font_size_axis,
+        );
+        for (i, edge_label) in edge_labels.iter().enumerate() {
+            let edge_x = origin_x + ((i + edge_slots) as f64 * scale_x) as i32;
 
             canvas.draw_pixel(edge_x as u32, origin_y as u32, [0, 0, 0]); // Tick mark
-            let edge_label = format!("{edge_value:.1}");
             self.draw_axis_value(
                 canvas,
                 cfg,
                 edge_x as u32,
                 origin_y as u32 + 10,
-                &edge_label,
+                edge_label,
                 AxisType::AxisX,
             );
         }
 
         // Add y-axis ticks and labels
-        let num_y_ticks = 10;
-        for i in 0..=num_y_ticks {
-            let tick_value = y_max * i as f64 / num_y_ticks as f64;
+        let y_values: Vec<f64> = (0..=num_y_ticks)
+            .map(|i| y_max * i as f64 / num_y_ticks as f64)
+            .collect();
+        let decimals = self.y_decimals();
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.decimals$}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            y_max * scale_y,
+            cfg.font_size_axis,
+        );
+        for (&tick_value, tick_label) in y_values.iter().zip(&y_labels) {
             let tick_y = origin_y - (tick_value * scale_y) as i32;
 
             canvas.draw_pixel(origin_x as u32, tick_y as u32, [0, 0, 0]); // Tick mark
-            let tick_label = format!("{tick_value:.1}");
             self.draw_axis_value(
                 canvas,
                 cfg,
-                origin_x as u32 - 10,
+                (origin_x as u32).saturating_sub(10),
                 tick_y as u32,
-                &tick_label,
+                tick_label,
                 AxisType::AxisY,
             );
         }
@@ -245,10 +517,67 @@ impl Drawer for Histogram {
         canvas.draw_vertical_line(canvas.width - canvas.margin, [0, 0, 0]);
         canvas.draw_horizontal_line(canvas.height - canvas.margin, [0, 0, 0]);
         canvas.draw_horizontal_line(canvas.margin, [0, 0, 0]);
+
+        self.draw_axis_units(canvas, cfg);
+        if self.config.show_legend {
+            self.draw_legend(canvas);
+        }
     }
 
-    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
-        // Histogram does not have a legend
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        // Only compared sample sets need a legend
+        if self.series.is_empty() {
+            return;
+        }
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+        let line_height = square_size * 2; // Vertical space for each legend entry
+
+        let mut x = canvas.margin;
+        let tick_overflow = self
+            .config
+            .x_tick_label_overflow(self.config.font_size_axis) as u32;
+        let mut y = canvas.height - canvas.margin + tick_overflow;
+
+        for (label, color) in self.legend_entries() {
+            let (w, h) = measure_text_with_fallbacks(
+                &label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(x + dx, y + square_size * 2 + dy + h, color);
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                x + square_size + padding,
+                y + 2 * square_size + h,
+                &label,
+                color,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+
+            x += square_size + padding + w + padding;
+            if x > canvas.width - canvas.margin {
+                x = canvas.margin;
+                y -= line_height;
+            }
+        }
     }
 
     fn as_any(&mut self) -> &mut (dyn Any + 'static) {
@@ -258,4 +587,12 @@ impl Drawer for Histogram {
     fn get_figure_config(&self) -> &FigureConfig {
         &self.config
     }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+
+    fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        Histogram::legend_entries(self)
+    }
 }
@@ -0,0 +1,359 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::marimekkochart::MarimekkoChart,
+    utilities::{
+        axistype::AxisType,
+        colormap::text_color_for,
+        scaling::{scale_factor, tick_values, to_pixel_x, to_pixel_y},
+        textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Minimum free space around a cell label, in pixels.
+const LABEL_PADDING: f64 = 4.0;
+
+impl Drawer for MarimekkoChart {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let scale_x = scale_factor(0.0, 1.0, width - 2.0 * margin);
+        let scale_y = scale_factor(0.0, 1.0, height - 2.0 * margin);
+
+        // Share tick labels
+        for share in tick_values(0.0, 1.0, cfg.num_axis_ticks) {
+            let y = to_pixel_y(share, 0.0, scale_y, height, margin);
+            let label = cfg.tick_label(AxisType::AxisY, share * 100.0, |v| format!("{v:.0}%"));
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Draw cells, separated by background colored strokes, with their labels
+        let background = self.rgb_to_svg_color(cfg.color_background);
+        for cell in self.cells() {
+            let color = self.datasets[cell.segment].color;
+            let x = to_pixel_x(cell.x0, 0.0, scale_x, margin);
+            let y = to_pixel_y(cell.y1, 0.0, scale_y, height, margin);
+            let cell_width = (cell.x1 - cell.x0) * scale_x;
+            let cell_height = cell.share() * scale_y;
+            svg_canvas.draw_rect(
+                x,
+                y,
+                cell_width,
+                cell_height,
+                &self.rgb_to_svg_color(color),
+                &background,
+                1.0,
+                1.0,
+            );
+
+            let label = self.cell_label(&cell);
+            let label_width = label.chars().count() as f64 * font_size * 0.6;
+            if self.show_labels
+                && label_width + 2.0 * LABEL_PADDING <= cell_width
+                && font_size + 2.0 * LABEL_PADDING <= cell_height
+            {
+                svg_canvas.draw_text(
+                    x + cell_width / 2.0,
+                    y + cell_height / 2.0 + font_size * 0.35,
+                    &label,
+                    font_size,
+                    &self.rgb_to_svg_color(text_color_for(color)),
+                );
+            }
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Category labels below the center of their columns
+        let totals = self.category_totals();
+        let grand_total: f64 = totals.iter().sum();
+        let mut offset = 0.0;
+        for (label, total) in self.categories.iter().zip(totals) {
+            if total <= 0.0 {
+                continue;
+            }
+            let center = offset + total / grand_total / 2.0;
+            offset += total / grand_total;
+            svg_canvas.draw_tick_label(
+                to_pixel_x(center, 0.0, scale_x, margin),
+                height - margin + font_size * 1.5,
+                label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+
+        // Draw axes
+        svg_canvas.draw_line(margin, margin, margin, height - margin, "black", 2.0);
+        svg_canvas.draw_line(
+            margin,
+            height - margin,
+            width - margin,
+            height - margin,
+            "black",
+            2.0,
+        );
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            width / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        // Segment legend below the category labels
+        let legend_y = height - margin + font_size * 2.5;
+        let mut legend_x = margin + 10.0;
+        for dataset in &self.datasets {
+            svg_canvas.draw_rect(
+                legend_x,
+                legend_y,
+                font_size,
+                font_size,
+                &self.rgb_to_svg_color(dataset.color),
+                "none",
+                0.0,
+                1.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                legend_x + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                dataset.label
+            ));
+            legend_x += font_size * 3.0 + dataset.label.len() as f64 * font_size * 0.6;
+        }
+
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        let margin = canvas.margin as f64;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let scale_x = scale_factor(0.0, 1.0, width - 2.0 * margin);
+        let scale_y = scale_factor(0.0, 1.0, height - 2.0 * margin);
+
+        // Share tick labels
+        for share in tick_values(0.0, 1.0, cfg.num_axis_ticks) {
+            let y = to_pixel_y(share, 0.0, scale_y, height, margin) as u32;
+            let label = cfg.tick_label(AxisType::AxisY, share * 100.0, |v| format!("{v:.0}%"));
+            self.draw_axis_value(canvas, cfg, canvas.margin - 10, y, &label, AxisType::AxisY);
+        }
+
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: cfg.font_size_axis,
+            y: cfg.font_size_axis,
+        };
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw cells, separated by background colored lines, with their labels
+        for cell in self.cells() {
+            let color = self.datasets[cell.segment].color;
+            let x0 = to_pixel_x(cell.x0, 0.0, scale_x, margin) as u32;
+            let x1 = to_pixel_x(cell.x1, 0.0, scale_x, margin) as u32;
+            let y0 = to_pixel_y(cell.y1, 0.0, scale_y, height, margin) as u32;
+            let y1 = to_pixel_y(cell.y0, 0.0, scale_y, height, margin) as u32;
+
+            for y in y0..y1.saturating_sub(1) {
+                for x in x0..x1.saturating_sub(1) {
+                    canvas.draw_pixel(x, y, color);
+                }
+            }
+
+            if !self.show_labels {
+                continue;
+            }
+            let label = self.cell_label(&cell);
+            let (w, h) =
+                measure_text_with_fallbacks(&label, font_path, &cfg.font_fallbacks, scale.x);
+            let padding = 2 * LABEL_PADDING as u32;
+            if w + padding <= x1 - x0 && h + padding <= y1 - y0 {
+                canvas.draw_text_with_fallbacks(
+                    (x0 + x1 - w) / 2,
+                    (y0 + y1 - h) / 2,
+                    &label,
+                    text_color_for(color),
+                    font_path,
+                    &cfg.font_fallbacks,
+                    scale,
+                );
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Category labels below the center of their columns
+        let totals = self.category_totals();
+        let grand_total: f64 = totals.iter().sum();
+        let mut offset = 0.0;
+        for (label, total) in self.categories.iter().zip(totals) {
+            if total <= 0.0 {
+                continue;
+            }
+            let center = offset + total / grand_total / 2.0;
+            offset += total / grand_total;
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                to_pixel_x(center, 0.0, scale_x, margin) as u32,
+                canvas.height - canvas.margin,
+                label,
+                AxisType::AxisX,
+            );
+        }
+
+        // Draw axes and axis labels
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            margin as i32,
+            margin as i32,
+            (height - margin) as i32,
+        );
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            (height - margin) as i32,
+            (width - margin) as i32,
+            (height - margin) as i32,
+        );
+        self.draw_label(
+            canvas,
+            cfg,
+            canvas.width - canvas.margin / 2,
+            canvas.height - canvas.margin,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_axis_units(canvas, &self.config);
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+
+        // Segments are listed in a single row below the category labels
+        let mut x = canvas.margin;
+        let y = canvas.height - canvas.margin + square_size * 3;
+
+        for dataset in &self.datasets {
+            let (w, _) = measure_text_with_fallbacks(
+                &dataset.label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(x + dx, y + dy, dataset.color);
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                x + square_size + padding,
+                y,
+                &dataset.label,
+                self.config.color_axis,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+            x += square_size + padding + w + square_size;
+        }
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
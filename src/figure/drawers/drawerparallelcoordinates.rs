@@ -0,0 +1,277 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::parallelcoordinates::{ParallelCoordinates, AXIS_NAME_SPACE},
+    utilities::{
+        axistype::AxisType, scaling::tick_values, textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl ParallelCoordinates {
+    /// Computes the ticks of an axis as `(label, y-coordinate)` pairs.
+    fn axis_ticks(&self, axis: usize, area: (f64, f64, f64, f64)) -> Vec<(String, f64)> {
+        let (min, max) = self.axis_range(axis);
+        tick_values(min, max, self.config.num_axis_ticks)
+            .into_iter()
+            .map(|value| {
+                (
+                    self.format_value(value),
+                    self.to_canvas(axis, value, area).1,
+                )
+            })
+            .collect()
+    }
+}
+
+impl Drawer for ParallelCoordinates {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // One translucent polyline per record, grouped by category
+        let area = self.plot_area(width, height, margin);
+        let (_, top, _, bottom) = area;
+        let mut current_category = None;
+        for record in self.draw_order() {
+            let category = self.records[record].category.as_deref();
+            if category != current_category {
+                if current_category.is_some() {
+                    svg_canvas.end_group();
+                }
+                if let Some(category) = category {
+                    svg_canvas.begin_series(category, cfg.series_description(category));
+                }
+                current_category = category;
+            }
+            let color = self.rgb_to_svg_color(self.record_color(record));
+            let tooltip = escape_xml(&self.record_tooltip(record));
+            for line in self.record_lines(record, area) {
+                let points: Vec<String> =
+                    line.iter().map(|(x, y)| format!("{x:.2},{y:.2}")).collect();
+                svg_canvas.elements.push(format!(
+                    r#"<polyline points="{}" fill="none" stroke="{color}" stroke-opacity="{:.2}" stroke-width="1.5" data-tooltip="{tooltip}"/>"#,
+                    points.join(" "),
+                    self.line_alpha
+                ));
+            }
+        }
+        if current_category.is_some() {
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Axes with their names above and their ticks on the left
+        for (axis, name) in self.axes.iter().enumerate() {
+            let x = self.axis_x(axis, area);
+            svg_canvas.draw_line(x, top, x, bottom, "black", 1.5);
+            svg_canvas.draw_text(
+                x,
+                top - AXIS_NAME_SPACE / 2.0,
+                &escape_xml(name),
+                font_size,
+                "black",
+            );
+            for (label, y) in self.axis_ticks(axis, area) {
+                svg_canvas.draw_line(x - 4.0, y, x, y, "black", 1.0);
+                svg_canvas.draw_tick_label(
+                    x - 6.0,
+                    y + font_size * 0.3,
+                    &label,
+                    font_size * 0.9,
+                    AxisType::AxisY,
+                );
+            }
+        }
+
+        // Legend in a row below the axes
+        let legend_y = bottom + font_size * 1.5;
+        let mut legend_x = margin;
+        for category in self.categories() {
+            svg_canvas
+                .elements
+                .push(SvgCanvas::legend_item_group(category));
+            svg_canvas.draw_rect(
+                legend_x,
+                legend_y,
+                font_size,
+                font_size,
+                &self.rgb_to_svg_color(self.category_color(category)),
+                "none",
+                0.0,
+                1.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                legend_x + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                escape_xml(category)
+            ));
+            svg_canvas.end_group();
+            legend_x += font_size * 2.5 + category.chars().count() as f64 * font_size * 0.6;
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // One translucent polyline per record
+        let area = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        for record in self.draw_order() {
+            let color = self.record_color(record);
+            for line in self.record_lines(record, area) {
+                canvas.blend_polyline(&line, color, self.line_alpha);
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Axes with their names above and their ticks on the left
+        let (_, top, _, bottom) = area;
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: cfg.font_size_label,
+            y: cfg.font_size_label,
+        };
+        for (axis, name) in self.axes.iter().enumerate() {
+            let x = self.axis_x(axis, area).round() as i32;
+            self.draw_axis(canvas, cfg, x, top as i32, x, bottom as i32);
+            let (w, h) = measure_text_with_fallbacks(name, font_path, &cfg.font_fallbacks, scale.y);
+            canvas.draw_text_with_fallbacks(
+                (x as u32).saturating_sub(w / 2),
+                ((top - AXIS_NAME_SPACE / 2.0) as u32).saturating_sub(h / 2),
+                name,
+                cfg.color_axis,
+                font_path,
+                &cfg.font_fallbacks,
+                scale,
+            );
+            for (label, y) in self.axis_ticks(axis, area) {
+                self.draw_axis_value(
+                    canvas,
+                    cfg,
+                    (x - 5).max(0) as u32,
+                    y as u32,
+                    &label,
+                    AxisType::AxisY,
+                );
+            }
+        }
+
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+
+        // Categories are listed in a row below the axes
+        let mut x = canvas.margin;
+        let y = canvas.height - canvas.margin + square_size;
+
+        for category in self.categories() {
+            let (w, _) = measure_text_with_fallbacks(
+                category,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
+            let color = self.category_color(category);
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(x + dx, y + dy, color);
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                x + square_size + padding,
+                y,
+                category,
+                self.config.color_axis,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+            x += square_size + padding + w + square_size;
+        }
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
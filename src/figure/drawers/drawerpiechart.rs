@@ -1,26 +1,44 @@
-use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
+use ab_glyph::PxScale;
 use std::f64::consts::PI;
 
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
     figuretypes::piechart::PieChart,
+    utilities::{
+        arcgeometry::{fill_ring_segment, ring_segment_path},
+        colormap::text_color_for,
+        textmetrics::measure_text_with_fallbacks,
+    },
 };
 
 use super::drawer::Drawer;
 use std::any::Any;
+
+/// Gap in pixels left between the rings of a multi-ring chart on pixel canvases.
+const RING_GAP: f64 = 2.0;
+
 impl Drawer for PieChart {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
         let margin = svg_canvas.margin as f64;
         let font_size = 12.0;
         let cfg = &self.config;
-        let margin_bg_color = svg_canvas.background_color.clone();
 
         // Draw margin background (using SvgCanvas background_color parameter)
-        svg_canvas.draw_rect(0.0, 0.0, width, height, &margin_bg_color, "black", 1.0, 1.0);
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
         // Draw chart background (using FigureConfig color)
         self.fill_svg_background(svg_canvas, cfg);
 
@@ -33,66 +51,147 @@ impl Drawer for PieChart {
             "black",
         );
 
-        // Calculate total value of all slices
-        let total: f64 = self.datasets.iter().map(|dataset| dataset.1).sum();
-
-        // Calculate center and radius
-        let cx = width / 2.0;
-        let cy = height / 2.0;
-        let radius = (width.min(height) - 2.0 * margin) / 2.0;
+        // Slices after merging small ones
+        let slices = self.slices();
+
+        // Calculate center and radius, estimating legend text widths from the font size
+        let entry_sizes: Vec<(f64, f64)> = if self.legend {
+            (0..slices.len())
+                .map(|index| {
+                    let text = self.legend_text(index);
+                    (
+                        font_size * 1.5 + text.chars().count() as f64 * font_size * 0.6,
+                        font_size,
+                    )
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let layout = self.layout(width, height, margin, &entry_sizes);
+        let (cx, cy) = layout.center;
+        let radius = layout.radius;
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
 
         // Begin group for pie chart with transformation
         svg_canvas.elements.push(format!(
             r#"<g transform="translate({cx:.2},{cy:.2})" stroke="black" stroke-width="1">"#
         ));
 
-        // Track the starting angle in radians
-        let mut start_angle = 0.0;
-
-        // Draw pie slices
-        for dataset in &self.datasets {
-            let value_ratio = dataset.1 / total; // Ratio of this slice to the total
-            let sweep_angle = value_ratio * 2.0 * std::f64::consts::PI; // Convert ratio to radians
-            let end_angle = start_angle + sweep_angle;
-
-            // Calculate start and end points of the slice
-            let x1 = radius * start_angle.cos();
-            let y1 = radius * start_angle.sin();
-            let x2 = radius * end_angle.cos();
-            let y2 = radius * end_angle.sin();
-
-            // Determine if the slice is larger than 180 degrees
-            let large_arc_flag = if sweep_angle > std::f64::consts::PI {
-                1
-            } else {
-                0
-            };
-
-            // Generate the path for the slice
-            svg_canvas.elements.push(format!(
-               r#"<path d="M 0 0 L {:.2} {:.2} A {:.2} {:.2} 0 {} 1 {:.2} {:.2} Z" fill="rgb({},{},{})"/>"#,
-               x1, y1, radius, radius, large_arc_flag, x2, y2,
-               dataset.2[0], dataset.2[1], dataset.2[2]
-           ));
+        // Draw the slices of every ring, the chart's own slices outermost
+        for ring in 0..self.ring_count() {
+            let ring_slices = self.ring_slices(ring);
+            let ring_total: f64 = ring_slices.iter().map(|dataset| dataset.1).sum();
+            if ring_total <= 0.0 {
+                continue;
+            }
+            let (inner, outer) = self.ring_radii(ring);
 
-            // Calculate label position (midpoint of the slice angle)
-            let mid_angle = start_angle + sweep_angle / 2.0;
-            let label_x = (radius * 0.6) * mid_angle.cos(); // 60% of radius for better placement
-            let label_y = (radius * 0.6) * mid_angle.sin();
+            // Track the starting angle in radians
+            let mut start_angle = 0.0;
 
-            // Draw percentage label
-            svg_canvas.elements.push(format!(
-               r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black" text-anchor="middle" alignment-baseline="middle">{:.1}%</text>"#,
-               label_x, label_y, font_size, value_ratio * 100.0
-           ));
+            for (index, dataset) in ring_slices.iter().enumerate() {
+                svg_canvas.begin_series(&dataset.0, self.config.series_description(&dataset.0));
+                let link = self.config.series_link(&dataset.0);
+                if let Some(url) = link {
+                    svg_canvas.begin_link(url);
+                }
+                let value_ratio = dataset.1 / ring_total; // Ratio of this slice to the total
+                let sweep_angle = value_ratio * 2.0 * std::f64::consts::PI; // Convert ratio to radians
+                let end_angle = start_angle + sweep_angle;
+
+                // Generate the path for the slice, a ring segment for donut charts and rings
+                let path =
+                    ring_segment_path(radius * inner, radius * outer, start_angle, end_angle);
+                svg_canvas.elements.push(format!(
+                    r#"<path d="{}" fill="rgb({},{},{})" data-tooltip="{}"/>"#,
+                    path,
+                    dataset.2[0],
+                    dataset.2[1],
+                    dataset.2[2],
+                    escape_xml(&self.ring_tooltip_text(ring, index))
+                ));
+
+                // Calculate label position (midpoint of the slice angle)
+                let mid_angle = start_angle + sweep_angle / 2.0;
+                let label_x = (radius * self.ring_label_radius(ring)) * mid_angle.cos();
+                let label_y = (radius * self.ring_label_radius(ring)) * mid_angle.sin();
+
+                // Draw percentage label, unless the legend shows it
+                if !self.legend {
+                    svg_canvas.elements.push(format!(
+                        r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="{}" stroke="none" text-anchor="middle" alignment-baseline="middle">{}</text>"#,
+                        label_x,
+                        label_y,
+                        font_size,
+                        self.rgb_to_svg_color(text_color_for(dataset.2)),
+                        cfg.format.format_percent(value_ratio * 100.0, 1)
+                    ));
+                }
+
+                if link.is_some() {
+                    svg_canvas.end_link();
+                }
+                svg_canvas.end_group();
+
+                // Update start angle for the next slice
+                start_angle = end_angle;
+            }
+        }
 
-            // Update start angle for the next slice
-            start_angle = end_angle;
+        // Center text of donut charts
+        if let (Some(text), true) = (&self.center_text, self.inner_radius > 0.0) {
+            svg_canvas.elements.push(format!(
+                r#"<text x="0" y="0" font-size="{:.2}" fill="black" stroke="none" text-anchor="middle" alignment-baseline="middle">{}</text>"#,
+                font_size * 1.5,
+                escape_xml(text)
+            ));
         }
 
         // Close group
         svg_canvas.elements.push("</g>".to_string());
 
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Legend mode: swatch, label, value and percentage of every slice
+        if self.legend {
+            for (index, &(x, y)) in layout.entries.iter().enumerate() {
+                let color = slices[index].2;
+                svg_canvas
+                    .elements
+                    .push(SvgCanvas::legend_item_group(&slices[index].0));
+                let link = self.config.series_link(&slices[index].0);
+                if let Some(url) = link {
+                    svg_canvas.begin_link(url);
+                }
+                svg_canvas.draw_rect(
+                    x,
+                    y,
+                    font_size,
+                    font_size,
+                    &self.rgb_to_svg_color(color),
+                    "none",
+                    0.0,
+                    1.0,
+                );
+                svg_canvas.elements.push(format!(
+                    r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                    x + font_size * 1.5,
+                    y + font_size - 2.0,
+                    font_size,
+                    self.legend_text(index)
+                ));
+                if link.is_some() {
+                    svg_canvas.end_link();
+                }
+                svg_canvas.end_group();
+            }
+            return;
+        }
+
         // Draw legend in the bottom-left corner
         let legend_x_start = margin + 10.0; // Start inside chart area with margin spacing
         let legend_y = height - margin + font_size * 1.5 + 10.0; // Position below x-axis labels
@@ -101,7 +200,12 @@ impl Drawer for PieChart {
         let mut elements = String::new();
         let legend_bg_color = svg_canvas.background_color.clone();
 
-        for dataset in &self.datasets {
+        for dataset in &slices {
+            elements.push_str(&SvgCanvas::legend_item_group(&dataset.0));
+            let link = self.config.series_link(&dataset.0);
+            if let Some(url) = link {
+                elements.push_str(&SvgCanvas::link_open(url));
+            }
             elements.push_str(&format!(
                 r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
                 legend_x, legend_y, font_size, font_size, dataset.2[0], dataset.2[1], dataset.2[2]
@@ -114,6 +218,10 @@ impl Drawer for PieChart {
                 font_size,
                 dataset.0
             ));
+            if link.is_some() {
+                elements.push_str("</a>");
+            }
+            elements.push_str("</g>");
 
             legend_x += font_size * 5.0 + dataset.0.len() as f64 * font_size * 0.6;
         }
@@ -133,60 +241,89 @@ impl Drawer for PieChart {
     }
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
         canvas.clear();
 
         let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
         self.fill_background(canvas, cfg);
 
         let margin = canvas.margin;
         let width = canvas.width;
-        let height = canvas.height;
 
         // Draw the title
         self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
 
-        // Calculate total value
-        let total: f64 = self.datasets.iter().map(|(_, value, _)| value).sum();
+        // Calculate total value, after merging small slices
+        let slices = self.slices();
+        let total: f64 = slices.iter().map(|(_, value, _)| value).sum();
         if total == 0.0 {
             return;
         }
 
         // Center and radius of the pie chart
-        let center_x = width / 2;
-        let center_y = height / 2;
-        let radius = (width.min(height) / 2 - margin) as i32;
-
-        let mut start_angle = 0.0;
-        for (_label, value, color) in &self.datasets {
-            let percentage = value / total;
-            let sweep_angle = 2.0 * PI * percentage;
-
-            // Draw the slice
-            self.draw_slice(
-                canvas,
-                center_x as i32,
-                center_y as i32,
-                radius,
-                start_angle,
-                start_angle + sweep_angle,
-                *color,
-            );
+        let layout = self.pixel_layout(canvas);
+        let center_x = layout.center.0.round() as u32;
+        let center_y = layout.center.1.round() as u32;
+        let radius = layout.radius as i32;
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // The chart's own slices outermost, then the rings added with `add_ring`
+        for ring in 0..self.ring_count() {
+            let ring_slices = self.ring_slices(ring);
+            let ring_total: f64 = ring_slices.iter().map(|(_, value, _)| value).sum();
+            if ring_total <= 0.0 {
+                continue;
+            }
+            let (inner, outer) = self.ring_radii(ring);
+            let gap = if ring > 0 { RING_GAP } else { 0.0 };
+
+            let mut start_angle = 0.0;
+            for (_label, value, color) in &ring_slices {
+                let percentage = value / ring_total;
+                let sweep_angle = 2.0 * PI * percentage;
+
+                // Draw the slice
+                fill_ring_segment(
+                    canvas,
+                    (center_x as i32, center_y as i32),
+                    (
+                        radius as f64 * inner,
+                        (radius as f64 * outer - gap).round() as i32,
+                    ),
+                    (start_angle, start_angle + sweep_angle),
+                    *color,
+                );
+
+                // Calculate mid-angle for label placement, unless the legend shows the percentage
+                if !self.legend {
+                    let mid_angle = start_angle + sweep_angle / 2.0;
+                    let label_radius = radius as f64 * self.ring_label_radius(ring);
+                    let label_x = center_x as f64 + (label_radius * mid_angle.cos());
+                    let label_y = center_y as f64 - (label_radius * mid_angle.sin());
+                    self.draw_label_colored(
+                        canvas,
+                        cfg,
+                        (label_x as u32, label_y as u32),
+                        &cfg.format.format_percent(percentage * 100.0, 1),
+                        text_color_for(*color),
+                    );
+                }
 
-            // Calculate mid-angle for label placement
-            let mid_angle = start_angle + sweep_angle / 2.0;
-            let label_x = center_x as f64 + (radius as f64 * 0.6 * mid_angle.cos());
-            let label_y = center_y as f64 - (radius as f64 * 0.6 * mid_angle.sin());
-            self.draw_label(
-                canvas,
-                cfg,
-                label_x as u32,
-                label_y as u32,
-                &format!("{:.1}%", percentage * 100.0),
-            );
+                start_angle += sweep_angle;
+            }
+        }
 
-            start_angle += sweep_angle;
+        // Center text of donut charts
+        if let (Some(text), true) = (&self.center_text, self.inner_radius > 0.0) {
+            self.draw_label(canvas, cfg, center_x, center_y, text);
         }
 
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
         // Draw legend
         self.draw_legend(canvas);
     }
@@ -197,20 +334,54 @@ impl Drawer for PieChart {
             .font_label
             .as_ref()
             .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
-        let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+
+        let slices = self.slices();
+
+        // Legend mode: swatch, label, value and percentage of every slice
+        if self.legend {
+            let layout = self.pixel_layout(canvas);
+            for (index, &(x, y)) in layout.entries.iter().enumerate() {
+                let (x, y) = (x.round() as u32, y.round() as u32);
+                let color = slices[index].2;
+                for dy in 0..square_size {
+                    for dx in 0..square_size {
+                        canvas.draw_pixel(x + dx, y + dy, color);
+                    }
+                }
+                canvas.draw_text_with_fallbacks(
+                    x + square_size * 3 / 2,
+                    y,
+                    &self.legend_text(index),
+                    self.config.color_axis,
+                    font_path,
+                    &self.config.font_fallbacks,
+                    scale,
+                );
+            }
+            return;
+        }
 
-        let square_size = 10; // Size of the colored square
-        let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
+        let padding = square_size / 2; // Space between the square and text
+        let line_height = square_size * 2; // Vertical space for each legend entry
         let legend_margin = canvas.margin; // Margin from the bottom of the canvas
 
         let mut x = canvas.margin;
         let mut y = canvas.height - legend_margin; // Legend starts from the bottom
 
-        for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.0);
+        for dataset in &slices {
+            let (w, h) = measure_text_with_fallbacks(
+                &dataset.0,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
             // Draw the square
             for dy in 0..square_size {
                 for dx in 0..square_size {
@@ -224,12 +395,13 @@ impl Drawer for PieChart {
 
             // Draw the label text next to the square
             let text_x: u32 = x + square_size + padding;
-            canvas.draw_text(
+            canvas.draw_text_with_fallbacks(
                 text_x,
                 y + 2 * square_size + h,
                 &dataset.0,
                 dataset.2,
-                &font,
+                font_path,
+                &self.config.font_fallbacks,
                 scale,
             );
 
@@ -250,4 +422,8 @@ impl Drawer for PieChart {
     fn get_figure_config(&self) -> &FigureConfig {
         &self.config
     }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
 }
@@ -0,0 +1,382 @@
+use std::f64::consts::PI;
+
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::polargraph::{PolarGraph, ANGLE_LABEL_SPACE},
+    utilities::{linetype::LineType, textmetrics::measure_text_with_fallbacks},
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl PolarGraph {
+    /// Returns the SVG text anchor of an angle label, so that labels extend away from the
+    /// plot: `start` on the right side, `end` on the left side and `middle` near the top
+    /// and bottom.
+    fn label_anchor(&self, theta: f64) -> &'static str {
+        let horizontal = theta.cos();
+        if horizontal > 0.3 {
+            "start"
+        } else if horizontal < -0.3 {
+            "end"
+        } else {
+            "middle"
+        }
+    }
+
+    /// Returns the angle along which the grid circles are labelled: halfway between the
+    /// first two spokes, clear of the spokes and the angle labels.
+    fn ring_label_angle(&self) -> f64 {
+        PI / self.num_spokes.max(1) as f64
+    }
+}
+
+impl Drawer for PolarGraph {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let layout = self.layout(width, height, margin);
+        let (center_x, center_y, radius) = layout;
+
+        // Grid circles and angular spokes
+        let grid_color = self.rgb_to_svg_color(cfg.color_grid);
+        let rings = self.ring_values();
+        let scale_max = self.scale_max();
+        for &value in &rings {
+            let ring_radius = value / scale_max * radius;
+            svg_canvas.elements.push(format!(
+                r#"<circle cx="{center_x:.2}" cy="{center_y:.2}" r="{ring_radius:.2}" fill="none" stroke="{grid_color}" stroke-width="1"/>"#
+            ));
+        }
+        let spokes = self.spoke_angles();
+        for &theta in &spokes {
+            let (x, y) = self.to_canvas(theta, radius, layout);
+            svg_canvas.draw_line(center_x, center_y, x, y, &grid_color, 1.0);
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Plot datasets
+        for dataset in self.visible_datasets() {
+            svg_canvas.begin_series(&dataset.label, cfg.series_description(&dataset.label));
+            let link = cfg.series_link(&dataset.label);
+            if let Some(url) = link {
+                svg_canvas.begin_link(url);
+            }
+            for window in dataset
+                .segments()
+                .iter()
+                .flat_map(|segment| segment.windows(2))
+            {
+                if let [p1, p2] = window {
+                    let (x1, y1) = self.point_position(p1.0, p1.1, layout);
+                    let (x2, y2) = self.point_position(p2.0, p2.1, layout);
+                    svg_canvas.draw_line_rgb_styled(
+                        x1,
+                        y1,
+                        x2,
+                        y2,
+                        dataset.color,
+                        2.0,
+                        dataset.line_type.clone(),
+                    );
+                }
+            }
+
+            // Hover targets for the interactive HTML export
+            for &(theta, r) in &dataset.points {
+                let (x, y) = self.point_position(theta, r, layout);
+                svg_canvas.draw_data_point(x, y, &self.point_tooltip(dataset, theta, r));
+            }
+            if link.is_some() {
+                svg_canvas.end_link();
+            }
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Radius labels between the first two spokes, angle labels around the perimeter
+        let axis_color = self.rgb_to_svg_color(cfg.color_axis);
+        for (&value, label) in rings.iter().zip(self.ring_labels()) {
+            let (x, y) =
+                self.to_canvas(self.ring_label_angle(), value / scale_max * radius, layout);
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="{}">{}</text>"#,
+                x + 3.0,
+                y - 3.0,
+                font_size * 0.9,
+                axis_color,
+                escape_xml(&label)
+            ));
+        }
+        for (&theta, label) in spokes.iter().zip(self.angle_labels()) {
+            let (x, y) = self.to_canvas(theta, radius + ANGLE_LABEL_SPACE / 3.0, layout);
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="{}" fill="black">{}</text>"#,
+                x,
+                y + font_size * 0.35,
+                font_size,
+                self.label_anchor(theta),
+                escape_xml(&label)
+            ));
+        }
+
+        if !self.config.show_legend {
+            return;
+        }
+        // Series legend in the top-left corner
+        let mut legend_y = margin;
+        for dataset in self.visible_datasets() {
+            svg_canvas
+                .elements
+                .push(SvgCanvas::legend_item_group(&dataset.label));
+            svg_canvas.draw_rect(
+                margin,
+                legend_y,
+                font_size,
+                font_size,
+                &self.rgb_to_svg_color(dataset.color),
+                "none",
+                0.0,
+                1.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                margin + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                escape_xml(&dataset.legend_label(&self.config.format))
+            ));
+            svg_canvas.end_group();
+            legend_y += font_size * 1.5;
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let layout = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (center_x, center_y, radius) = layout;
+        let to_pixel = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+
+        // Grid circles, drawn as polygons with a point every two degrees, and angular spokes
+        let rings = self.ring_values();
+        let scale_max = self.scale_max();
+        for &value in &rings {
+            let ring_radius = value / scale_max * radius;
+            let points: Vec<(i32, i32)> = (0..=180)
+                .map(|step| {
+                    to_pixel(self.to_canvas((step as f64 * 2.0).to_radians(), ring_radius, layout))
+                })
+                .collect();
+            for pair in points.windows(2) {
+                canvas.draw_line(
+                    pair[0].0,
+                    pair[0].1,
+                    pair[1].0,
+                    pair[1].1,
+                    cfg.color_grid,
+                    LineType::Solid,
+                );
+            }
+        }
+        let spokes = self.spoke_angles();
+        for &theta in &spokes {
+            let (x, y) = to_pixel(self.to_canvas(theta, radius, layout));
+            canvas.draw_line(
+                center_x.round() as i32,
+                center_y.round() as i32,
+                x,
+                y,
+                cfg.color_grid,
+                LineType::Solid,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Plot datasets
+        for dataset in self.visible_datasets() {
+            for window in dataset
+                .segments()
+                .iter()
+                .flat_map(|segment| segment.windows(2))
+            {
+                if let [p1, p2] = window {
+                    let (x1, y1) = to_pixel(self.point_position(p1.0, p1.1, layout));
+                    let (x2, y2) = to_pixel(self.point_position(p2.0, p2.1, layout));
+                    canvas.draw_line(x1, y1, x2, y2, dataset.color, dataset.line_type.clone());
+                }
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Radius labels between the first two spokes, angle labels around the perimeter
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: cfg.font_size_axis,
+            y: cfg.font_size_axis,
+        };
+        for (&value, label) in rings.iter().zip(self.ring_labels()) {
+            let (x, y) =
+                self.to_canvas(self.ring_label_angle(), value / scale_max * radius, layout);
+            canvas.draw_text_with_fallbacks(
+                (x + 3.0) as u32,
+                (y - 3.0 - cfg.font_size_axis as f64).max(0.0) as u32,
+                &label,
+                cfg.color_axis,
+                font_path,
+                &cfg.font_fallbacks,
+                scale,
+            );
+        }
+        let label_scale = PxScale {
+            x: cfg.font_size_label,
+            y: cfg.font_size_label,
+        };
+        for (&theta, label) in spokes.iter().zip(self.angle_labels()) {
+            let (x, y) = self.to_canvas(theta, radius + ANGLE_LABEL_SPACE / 3.0, layout);
+            let (w, h) = measure_text_with_fallbacks(
+                &label,
+                font_path,
+                &cfg.font_fallbacks,
+                cfg.font_size_label,
+            );
+            // Share of the label width left of its anchor point
+            let alignment = match self.label_anchor(theta) {
+                "start" => 0.0,
+                "end" => 1.0,
+                _ => 0.5,
+            };
+            canvas.draw_text_with_fallbacks(
+                (x - w as f64 * alignment).max(0.0) as u32,
+                (y - h as f64 / 2.0).max(0.0) as u32,
+                &label,
+                cfg.color_axis,
+                font_path,
+                &cfg.font_fallbacks,
+                label_scale,
+            );
+        }
+
+        if self.config.show_legend {
+            self.draw_legend(canvas);
+        }
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+
+        // Series are listed in the top-left corner
+        let x = canvas.margin;
+        let mut y = canvas.margin;
+
+        for dataset in self.visible_datasets() {
+            let label = dataset.legend_label(&self.config.format);
+            let (_, h) = measure_text_with_fallbacks(
+                &label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(x + dx, y + dy, dataset.color);
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                x + square_size + padding,
+                y,
+                &label,
+                self.config.color_axis,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+            y += square_size.max(h) + padding;
+        }
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+
+    fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        self.visible_datasets()
+            .map(|dataset| (dataset.legend_label(&self.config.format), dataset.color))
+            .collect()
+    }
+}
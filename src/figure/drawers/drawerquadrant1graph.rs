@@ -1,26 +1,37 @@
-use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
+use ab_glyph::PxScale;
 
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
     figuretypes::quadrant1graph::Quadrant1Graph,
-    utilities::axistype::AxisType,
+    utilities::{
+        axistype::AxisType, scaling::tick_values, textmetrics::measure_text_with_fallbacks,
+    },
 };
 
 use super::drawer::Drawer;
 use std::any::Any;
 impl Drawer for Quadrant1Graph {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
         let margin = svg_canvas.margin as f64;
         let font_size = 12.0;
         let cfg = &self.config;
-        let margin_bg_color = svg_canvas.background_color.clone();
 
         // Draw margin background (using SvgCanvas background_color parameter)
-        svg_canvas.draw_rect(0.0, 0.0, width, height, &margin_bg_color, "black", 1.0, 1.0);
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
         // Draw chart background (using FigureConfig color)
         self.fill_svg_background(svg_canvas, cfg);
 
@@ -38,35 +49,33 @@ impl Drawer for Quadrant1Graph {
 
         // Determine dataset range
         let (x_min, x_max) = self
-            .datasets
-            .iter()
+            .visible_datasets()
             .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
             .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
                 (min.min(x), max.max(x))
             });
 
         let (y_min, y_max) = self
-            .datasets
-            .iter()
+            .visible_datasets()
             .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
             .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
                 (min.min(y), max.max(y))
             });
 
+        let ((x_min, x_max), (y_min, y_max)) = self.config.aspect_ranges(
+            (x_min, x_max),
+            (y_min, y_max),
+            width - 2.0 * margin,
+            height - 2.0 * margin,
+        );
         let scale_x = (width - 2.0 * margin) / (x_max - x_min);
         let scale_y = (height - 2.0 * margin) / (y_max - y_min);
 
         // Draw grid
         let num_ticks = 10;
-        svg_canvas.draw_grid(
-            margin,
-            width - margin,
-            margin,
-            height - margin,
-            num_ticks,
-            num_ticks,
-            "lightgray",
-        );
+        let x_ticks = tick_values(margin, width - margin, num_ticks);
+        let y_ticks = tick_values(height - margin, margin, num_ticks);
+        self.draw_svg_tick_grid(svg_canvas, &self.config, &x_ticks, &y_ticks);
 
         // Draw axes (only positive X and Y axes for Quadrant 1)
         svg_canvas.draw_line(
@@ -80,30 +89,48 @@ impl Drawer for Quadrant1Graph {
         svg_canvas.draw_line(margin, margin, margin, height - margin, "black", 2.0); // Y-axis
 
         // Draw tick marks and values for X-axis
-        for i in 0..=num_ticks {
-            let value = x_min + i as f64 * (x_max - x_min) / num_ticks as f64;
-            let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
+        let x_values = tick_values(x_min, x_max, num_ticks);
+        let x_labels = self
+            .config
+            .tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+        let x_labels = self.config.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            font_size as f32,
+        );
+        for (i, label) in x_labels.into_iter().enumerate() {
+            let x = x_ticks[i];
 
-            svg_canvas.draw_text(
+            svg_canvas.draw_tick_label(
                 x,
                 height - margin + font_size * 1.5,
-                &format!("{value:.1}"),
+                &label,
                 font_size,
-                "black",
+                AxisType::AxisX,
             );
         }
 
         // Draw tick marks and values for Y-axis
-        for i in 0..=num_ticks {
-            let value = y_min + i as f64 * (y_max - y_min) / num_ticks as f64;
-            let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
+        let y_values = tick_values(y_min, y_max, num_ticks);
+        let y_labels = self
+            .config
+            .tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.1}"));
+        let y_labels = self.config.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            height - 2.0 * margin,
+            font_size as f32,
+        );
+        for (i, label) in y_labels.into_iter().enumerate() {
+            let y = y_ticks[i];
 
-            svg_canvas.draw_text(
-                margin - font_size * 2.0,
-                y,
-                &format!("{value:.1}"),
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                &label,
                 font_size,
-                "black",
+                AxisType::AxisY,
             );
         }
 
@@ -117,19 +144,32 @@ impl Drawer for Quadrant1Graph {
         );
 
         // Draw Y-axis label (rotated)
-        svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(-90 {:.2} {:.2})">{}</text>"#,
+        svg_canvas.draw_rotated_label(
             margin / 3.0,
             height / 2.0,
+            &self.y_label,
             font_size * 1.5,
-            margin / 3.0,
-            height / 2.0,
-            self.y_label
-        ));
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
 
         // Draw datasets as points or lines
-        for dataset in &self.datasets {
-            for window in dataset.points.windows(2) {
+        for dataset in self.visible_datasets() {
+            svg_canvas.begin_series(
+                &dataset.label,
+                self.config.series_description(&dataset.label),
+            );
+            let link = self.config.series_link(&dataset.label);
+            if let Some(url) = link {
+                svg_canvas.begin_link(url);
+            }
+            for window in dataset
+                .drawn_segments()
+                .iter()
+                .flat_map(|segment| segment.windows(2))
+            {
                 if let [p1, p2] = window {
                     let x1 = margin + (p1.0 - x_min) * scale_x;
                     let y1 = height - margin - (p1.1 - y_min) * scale_y;
@@ -156,17 +196,63 @@ impl Drawer for Quadrant1Graph {
                 let svg_y = height - margin - (y - y_min) * scale_y;
 
                 svg_canvas.draw_circle(svg_x, svg_y, 3.0, "black");
+                svg_canvas.draw_data_point(
+                    svg_x,
+                    svg_y,
+                    &format!("{}: ({x:.2}, {y:.2})", dataset.label),
+                );
             }
+            if link.is_some() {
+                svg_canvas.end_link();
+            }
+            svg_canvas.end_group();
         }
 
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+        self.draw_svg_secondary_axis(svg_canvas, &self.config, y_min, y_max, font_size);
+
+        // Reference lines at dataset statistics
+        for dataset in self.visible_datasets() {
+            let Some(line) = dataset.reference_line else {
+                continue;
+            };
+            let Some(value) = line.value(dataset) else {
+                continue;
+            };
+            let y = height - margin - (value - y_min) * scale_y;
+            self.draw_svg_reference_line(
+                svg_canvas,
+                &self.config,
+                line,
+                value,
+                y,
+                dataset.color,
+                font_size,
+            );
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        if !self.config.show_legend {
+            return;
+        }
         // Draw legend in the bottom-left corner
         let legend_x_start = margin + 10.0; // Start inside chart area with margin spacing
-        let legend_y = height - margin + font_size * 1.5 + 10.0; // Position below x-axis labels
+        let tick_overflow = self.config.x_tick_label_overflow(font_size as f32) as f64;
+        let legend_y = height - margin + font_size * 1.5 + 10.0 + tick_overflow; // Position below x-axis labels
         let mut legend_x = legend_x_start; // Reset starting position for legend items
         let mut elements = String::new();
         let legend_bg_color = svg_canvas.background_color.clone();
 
-        for dataset in &self.datasets {
+        for dataset in self.visible_datasets() {
+            let label = dataset.legend_label(&self.config.format);
+            elements.push_str(&SvgCanvas::legend_item_group(&dataset.label));
+            let link = self.config.series_link(&dataset.label);
+            if let Some(url) = link {
+                elements.push_str(&SvgCanvas::link_open(url));
+            }
+
             // Draw color square
             elements.push_str(&format!(
                 r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
@@ -188,11 +274,15 @@ impl Drawer for Quadrant1Graph {
                 dataset.color[0],
                 dataset.color[1],
                 dataset.color[2],
-                dataset.label
+                escape_xml(&label)
             ));
+            if link.is_some() {
+                elements.push_str("</a>");
+            }
+            elements.push_str("</g>");
 
             // Update legend_x to position the next item
-            legend_x += font_size * 5.0 + dataset.label.len() as f64 * font_size * 0.6;
+            legend_x += font_size * 5.0 + label.len() as f64 * font_size * 0.6;
         }
 
         // Draw a background rectangle for the legend
@@ -214,9 +304,12 @@ impl Drawer for Quadrant1Graph {
     }
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
         canvas.clear();
 
         let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
         self.fill_background(canvas, cfg);
 
         let margin = canvas.margin;
@@ -228,14 +321,12 @@ impl Drawer for Quadrant1Graph {
 
         // Calculate dataset limits
         let (x_min, x_max) = self
-            .datasets
-            .iter()
+            .visible_datasets()
             .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
             .fold((0.0_f64, 0.0_f64), |(min, max), x| (min.min(x), max.max(x)));
 
         let (y_min, y_max) = self
-            .datasets
-            .iter()
+            .visible_datasets()
             .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
             .fold((0.0_f64, 0.0_f64), |(min, max), y| (min.min(y), max.max(y)));
 
@@ -243,15 +334,15 @@ impl Drawer for Quadrant1Graph {
         let x_min = x_min.min(0.0);
         let y_min = y_min.min(0.0);
         // Calculate scales
+        let ((x_min, x_max), (y_min, y_max)) = cfg.aspect_ranges(
+            (x_min, x_max),
+            (y_min, y_max),
+            (width - 2 * margin) as f64,
+            (height - 2 * margin) as f64,
+        );
         let scale_x = (width - 2 * margin) as f64 / (x_max - x_min);
         let scale_y = (height - 2 * margin) as f64 / (y_max - y_min);
 
-        // Draw grids
-        canvas.draw_grid(
-            &[cfg.num_grid_horizontal, cfg.num_grid_vertical],
-            cfg.color_grid,
-        );
-
         // Draw axes
         let origin_x = margin + ((0.0 - x_min) * scale_x) as u32;
         let origin_y = height - margin - ((0.0 - y_min) * scale_y) as u32;
@@ -259,40 +350,61 @@ impl Drawer for Quadrant1Graph {
         self.draw_label(canvas, cfg, margin, margin / 2, &self.y_label);
         self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.x_label);
 
-        // Draw axis tick values
+        // Tick values and their pixel positions, shared by the grid and the tick labels
         let num_ticks = cfg.num_axis_ticks;
+        let x_values = tick_values(x_min, x_max, num_ticks);
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.2}"));
+        let x_ticks: Vec<(String, u32)> = cfg
+            .thin_tick_labels(
+                AxisType::AxisX,
+                x_labels,
+                (x_max - x_min) * scale_x,
+                cfg.font_size_axis,
+            )
+            .into_iter()
+            .zip(&x_values)
+            .map(|(label, &value)| (label, origin_x + ((value - x_min) * scale_x) as u32))
+            .collect();
+        let y_values = tick_values(y_min, y_max, num_ticks);
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.2}"));
+        let y_ticks: Vec<(String, u32)> = cfg
+            .thin_tick_labels(
+                AxisType::AxisY,
+                y_labels,
+                (y_max - y_min) * scale_y,
+                cfg.font_size_axis,
+            )
+            .into_iter()
+            .zip(&y_values)
+            .map(|(label, &value)| (label, origin_y - ((value - y_min) * scale_y) as u32))
+            .collect();
+        let positions = |ticks: &[(String, u32)]| -> Vec<f64> {
+            ticks.iter().map(|(_, position)| *position as f64).collect()
+        };
 
-        // X-axis ticks
-        let x_tick_step = (x_max - x_min) / num_ticks as f64;
-        for i in 0..=num_ticks {
-            let value_x = x_min + i as f64 * x_tick_step;
-            let tick_x = origin_x + ((value_x - x_min) * scale_x) as u32;
-
-            let value_label = format!("{value_x:.2}");
+        // Draw grids
+        self.draw_tick_grid(canvas, cfg, &positions(&x_ticks), &positions(&y_ticks));
 
-            self.draw_axis_value(canvas, cfg, tick_x, origin_y, &value_label, AxisType::AxisX);
+        // X-axis ticks
+        for (label, tick_x) in &x_ticks {
+            self.draw_axis_value(canvas, cfg, *tick_x, origin_y, label, AxisType::AxisX);
         }
 
         // Y-axis ticks
-        let y_tick_step = (y_max - y_min) / num_ticks as f64;
-        for i in 0..=num_ticks {
-            let value_y = y_min + i as f64 * y_tick_step;
-            let tick_y = origin_y - ((value_y - y_min) * scale_y) as u32;
-            let value_label = format!("{value_y:.2}");
-
-            self.draw_axis_value(
-                canvas,
-                cfg,
-                origin_x - 10,
-                tick_y,
-                &value_label,
-                AxisType::AxisY,
-            );
+        for (label, tick_y) in &y_ticks {
+            self.draw_axis_value(canvas, cfg, origin_x - 10, *tick_y, label, AxisType::AxisY);
         }
 
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
         // Draw datasets
-        for dataset in &self.datasets {
-            for window in dataset.points.windows(2) {
+        for dataset in self.visible_datasets() {
+            for window in dataset
+                .drawn_segments()
+                .iter()
+                .flat_map(|segment| segment.windows(2))
+            {
                 if let [p1, p2] = window {
                     let x1 = origin_x + ((p1.0 - x_min) * scale_x) as u32;
                     let y1 = origin_y - ((p1.1 - y_min) * scale_y) as u32;
@@ -314,8 +426,26 @@ impl Drawer for Quadrant1Graph {
         canvas.draw_vertical_line(canvas.width - canvas.margin, [0, 0, 0]);
         canvas.draw_horizontal_line(canvas.height - canvas.margin, [0, 0, 0]);
         canvas.draw_horizontal_line(canvas.margin, [0, 0, 0]);
+        // Reference lines at dataset statistics
+        for dataset in self.visible_datasets() {
+            let Some(line) = dataset.reference_line else {
+                continue;
+            };
+            let Some(value) = line.value(dataset) else {
+                continue;
+            };
+            let y = origin_y as i32 - ((value - y_min) * scale_y) as i32;
+            self.draw_reference_line(canvas, &self.config, line, value, y, dataset.color);
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
         // Draw legend
-        self.draw_legend(canvas);
+        self.draw_axis_units(canvas, &self.config);
+        self.draw_secondary_axis(canvas, &self.config, y_min, y_max);
+        if self.config.show_legend {
+            self.draw_legend(canvas);
+        }
     }
 
     fn draw_legend(&self, canvas: &mut PixelCanvas) {
@@ -324,20 +454,31 @@ impl Drawer for Quadrant1Graph {
             .font_label
             .as_ref()
             .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
-        let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
-
-        let square_size = 10; // Size of the colored square
-        let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+        let line_height = square_size * 2; // Vertical space for each legend entry
         let legend_margin = canvas.margin; // Margin from the bottom of the canvas
 
         let mut x = canvas.margin;
-        let mut y = canvas.height - legend_margin; // Legend starts from the bottom
-
-        for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.label);
+        let tick_overflow = self
+            .config
+            .x_tick_label_overflow(self.config.font_size_axis) as u32;
+        let mut y = canvas.height - legend_margin + tick_overflow; // Legend starts from the bottom
+
+        for dataset in self.visible_datasets() {
+            let label = dataset.legend_label(&self.config.format);
+            let (w, h) = measure_text_with_fallbacks(
+                &label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
             // Draw the square
             for dy in 0..square_size {
                 for dx in 0..square_size {
@@ -351,12 +492,13 @@ impl Drawer for Quadrant1Graph {
 
             // Draw the label text next to the square
             let text_x: u32 = x + square_size + padding;
-            canvas.draw_text(
+            canvas.draw_text_with_fallbacks(
                 text_x,
                 y + 2 * square_size + h,
-                &dataset.label,
+                &label,
                 dataset.color,
-                &font,
+                font_path,
+                &self.config.font_fallbacks,
                 scale,
             );
 
@@ -377,4 +519,14 @@ impl Drawer for Quadrant1Graph {
     fn get_figure_config(&self) -> &FigureConfig {
         &self.config
     }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+
+    fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        self.visible_datasets()
+            .map(|dataset| (dataset.legend_label(&self.config.format), dataset.color))
+            .collect()
+    }
 }
@@ -0,0 +1,286 @@
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::quiverplot::QuiverPlot,
+    utilities::{
+        axistype::AxisType, colorbar::COLOR_BAR_GAP, colormap::interpolate, scaling::tick_values,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Number of labels on the color bar.
+const COLOR_BAR_LABELS: usize = 5;
+
+impl QuiverPlot {
+    /// Computes the x and y ticks as `(value, canvas position)` pairs.
+    ///
+    /// X positions are x-coordinates, y positions y-coordinates.
+    #[allow(clippy::type_complexity)]
+    fn axis_ticks(&self, area: (f64, f64, f64, f64)) -> (Vec<(f64, f64)>, Vec<(f64, f64)>) {
+        let num_ticks = self.config.num_axis_ticks;
+        let ranges = self.ranges();
+        let ((x0, x1), (y0, y1)) = ranges;
+        let xs = tick_values(x0, x1, num_ticks)
+            .into_iter()
+            .map(|x| (x, self.to_canvas(x, y0, ranges, area).0))
+            .collect();
+        let ys = tick_values(y0, y1, num_ticks)
+            .into_iter()
+            .map(|y| (y, self.to_canvas(x0, y, ranges, area).1))
+            .collect();
+        (xs, ys)
+    }
+
+    /// Computes the labels of the color bar as `(fraction of the height from the bottom,
+    /// text)` pairs.
+    fn color_bar_labels(&self, (min, max): (f64, f64)) -> Vec<(f64, String)> {
+        if max <= min {
+            return vec![(1.0, self.format_value(max))];
+        }
+        (0..COLOR_BAR_LABELS)
+            .map(|index| {
+                let fraction = index as f64 / (COLOR_BAR_LABELS - 1) as f64;
+                (fraction, self.format_value(min + fraction * (max - min)))
+            })
+            .collect()
+    }
+
+    /// Returns the tooltip text of a vector.
+    fn vector_tooltip(&self, x: f64, y: f64, u: f64, v: f64) -> String {
+        format!(
+            "({}, {}): {}, {}",
+            self.config
+                .localize_value(AxisType::AxisX, &format!("{x:.2}")),
+            self.config
+                .localize_value(AxisType::AxisY, &format!("{y:.2}")),
+            self.format_value(u),
+            self.format_value(v)
+        )
+    }
+}
+
+impl Drawer for QuiverPlot {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // One arrow per vector, grouped with its tooltip
+        let area = self.plot_area(width, height, margin);
+        let (left, top, right, bottom) = area;
+        let range = self.magnitude_range();
+        for (vector, tail, tip) in self.arrows(area) {
+            svg_canvas.elements.push(format!(
+                r#"<g data-tooltip="{}">"#,
+                self.vector_tooltip(vector.x, vector.y, vector.u, vector.v)
+            ));
+            let color = self.rgb_to_svg_color(self.arrow_color(&vector, range));
+            svg_canvas.draw_arrow(tail, tip, self.head_length, &color, 1.5);
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Axes with x and y ticks
+        svg_canvas.draw_line(left, bottom, right, bottom, "black", 2.0);
+        svg_canvas.draw_line(left, top, left, bottom, "black", 2.0);
+        let (xs, ys) = self.axis_ticks(area);
+        for (value, x) in xs {
+            let label = cfg.tick_label(AxisType::AxisX, value, |v| format!("{v:.2}"));
+            svg_canvas.draw_tick_label(
+                x,
+                bottom + font_size * 1.5,
+                &label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+        for (value, y) in ys {
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.2}"));
+            svg_canvas.draw_tick_label(
+                left - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            (left + right) / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        // Continuous color bar from the lowest (bottom) to the highest magnitude (top)
+        if self.color_by_magnitude && !self.vectors.is_empty() {
+            self.draw_svg_color_bar(
+                svg_canvas,
+                cfg,
+                right + COLOR_BAR_GAP,
+                (top, bottom),
+                50,
+                &|fraction| interpolate(&self.color_stops, fraction),
+                &self.color_bar_labels(range),
+                font_size,
+            );
+        }
+
+        self.draw_svg_axis_units(svg_canvas, cfg, font_size);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // One arrow per vector
+        let area = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let range = self.magnitude_range();
+        for (vector, tail, tip) in self.arrows(area) {
+            canvas.draw_arrow(
+                tail,
+                tip,
+                self.head_length,
+                self.arrow_color(&vector, range),
+            );
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Axes with x and y ticks
+        let (left, top, right, bottom) = (
+            area.0.round() as i32,
+            area.1.round() as i32,
+            area.2.round() as i32,
+            area.3.round() as i32,
+        );
+        self.draw_axis(canvas, cfg, left, bottom, right, bottom);
+        self.draw_axis(canvas, cfg, left, top, left, bottom);
+        let (xs, ys) = self.axis_ticks(area);
+        for (value, x) in xs {
+            let label = cfg.tick_label(AxisType::AxisX, value, |v| format!("{v:.2}"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                x as u32,
+                bottom as u32,
+                &label,
+                AxisType::AxisX,
+            );
+        }
+        for (value, y) in ys {
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.2}"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                (left as u32).saturating_sub(10),
+                y as u32,
+                &label,
+                AxisType::AxisY,
+            );
+        }
+
+        self.draw_label(
+            canvas,
+            cfg,
+            right as u32,
+            canvas.height - canvas.margin / 3,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_axis_units(canvas, cfg);
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        if !self.color_by_magnitude || self.vectors.is_empty() {
+            return;
+        }
+        let (_, top, right, bottom) = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+
+        // Continuous color bar from the lowest (bottom) to the highest magnitude (top),
+        // right of the plot
+        self.draw_color_bar(
+            canvas,
+            &self.config,
+            right + COLOR_BAR_GAP,
+            (top, bottom),
+            &|fraction| interpolate(&self.color_stops, fraction),
+            &self.color_bar_labels(self.magnitude_range()),
+        );
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -0,0 +1,370 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::radarchart::RadarChart,
+    utilities::{linetype::LineType, textmetrics::measure_text_with_fallbacks},
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Distance in pixels between the outer ring and the axis labels.
+const LABEL_OFFSET: f64 = 10.0;
+
+impl RadarChart {
+    /// Returns the SVG text anchor of an axis label, so that labels extend away from the
+    /// chart: `start` on the right side, `end` on the left side and `middle` near the top
+    /// and bottom.
+    fn label_anchor(&self, axis: usize) -> &'static str {
+        let horizontal = self.axis_bearing(axis).to_radians().sin();
+        if horizontal > 0.3 {
+            "start"
+        } else if horizontal < -0.3 {
+            "end"
+        } else {
+            "middle"
+        }
+    }
+
+    /// Returns where a ring's value is labelled: the middle of the ring's edge between the
+    /// first two axes, clear of the spokes and the axis labels.
+    fn ring_label_position(&self, value: f64, layout: (f64, f64, f64)) -> (f64, f64) {
+        let (x1, y1) = self.value_position(0, value, layout);
+        let (x2, y2) = self.value_position(1 % self.axes.len().max(1), value, layout);
+        ((x1 + x2) / 2.0, (y1 + y2) / 2.0)
+    }
+}
+
+impl Drawer for RadarChart {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let layout = self.layout(width, height, margin);
+        let (center_x, center_y, radius) = layout;
+        let to_points = |polygon: &[(f64, f64)]| {
+            polygon
+                .iter()
+                .map(|(x, y)| format!("{x:.2},{y:.2}"))
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+
+        // Gridline rings and axis spokes
+        let grid_color = self.rgb_to_svg_color(cfg.color_grid);
+        let rings = self.ring_values();
+        for &value in &rings {
+            svg_canvas.elements.push(format!(
+                r#"<polygon points="{}" fill="none" stroke="{grid_color}" stroke-width="1"/>"#,
+                to_points(&self.ring_polygon(value, layout))
+            ));
+        }
+        for axis in 0..self.axes.len() {
+            let (x, y) = self.to_canvas(self.axis_bearing(axis), radius, layout);
+            svg_canvas.draw_line(center_x, center_y, x, y, &grid_color, 1.0);
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // One polygon per series, with a marker on every vertex
+        for dataset in self.visible_datasets() {
+            svg_canvas.begin_series(&dataset.label, cfg.series_description(&dataset.label));
+            let color = self.rgb_to_svg_color(dataset.color);
+            let polygon = self.polygon(dataset, layout);
+            svg_canvas.elements.push(format!(
+                r#"<polygon points="{}" fill="{}" fill-opacity="{}" stroke="{}" stroke-width="2"/>"#,
+                to_points(&polygon),
+                if self.fill_alpha.is_some() { color.as_str() } else { "none" },
+                self.fill_alpha.unwrap_or(0.0),
+                color
+            ));
+            for (axis, &(x, y)) in polygon.iter().enumerate() {
+                svg_canvas.elements.push(format!(
+                    r#"<circle cx="{x:.2}" cy="{y:.2}" r="3" fill="{color}" data-tooltip="{}"/>"#,
+                    escape_xml(&format!(
+                        "{}, {}: {}",
+                        dataset.label,
+                        self.axes[axis],
+                        self.format_value(dataset.value(axis))
+                    ))
+                ));
+            }
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Ring values between the first two axes, axis names around the perimeter
+        let axis_color = self.rgb_to_svg_color(cfg.color_axis);
+        for &value in &rings {
+            let (x, y) = self.ring_label_position(value, layout);
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="{}">{}</text>"#,
+                x + 3.0,
+                y - 3.0,
+                font_size * 0.9,
+                axis_color,
+                self.format_value(value)
+            ));
+        }
+        for (axis, name) in self.axes.iter().enumerate() {
+            let (x, y) = self.to_canvas(self.axis_bearing(axis), radius + LABEL_OFFSET, layout);
+            let anchor = self.label_anchor(axis);
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="{}" fill="black">{}</text>"#,
+                x,
+                y + font_size * 0.35,
+                font_size,
+                anchor,
+                escape_xml(name)
+            ));
+        }
+
+        if !self.config.show_legend {
+            return;
+        }
+        // Series legend in the top-left corner
+        let mut legend_y = margin;
+        for dataset in self.visible_datasets() {
+            svg_canvas
+                .elements
+                .push(SvgCanvas::legend_item_group(&dataset.label));
+            svg_canvas.draw_rect(
+                margin,
+                legend_y,
+                font_size,
+                font_size,
+                &self.rgb_to_svg_color(dataset.color),
+                "none",
+                0.0,
+                1.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                margin + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                escape_xml(&dataset.label)
+            ));
+            svg_canvas.end_group();
+            legend_y += font_size * 1.5;
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let layout = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (center_x, center_y, radius) = layout;
+        let to_pixel = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+        let draw_closed = |canvas: &mut PixelCanvas, polygon: &[(f64, f64)], color: [u8; 3]| {
+            for (index, &start) in polygon.iter().enumerate() {
+                let (x1, y1) = to_pixel(start);
+                let (x2, y2) = to_pixel(polygon[(index + 1) % polygon.len()]);
+                canvas.draw_line(x1, y1, x2, y2, color, LineType::Solid);
+            }
+        };
+
+        // Gridline rings and axis spokes
+        let rings = self.ring_values();
+        for &value in &rings {
+            draw_closed(canvas, &self.ring_polygon(value, layout), cfg.color_grid);
+        }
+        for axis in 0..self.axes.len() {
+            let (x, y) = to_pixel(self.to_canvas(self.axis_bearing(axis), radius, layout));
+            canvas.draw_line(
+                center_x.round() as i32,
+                center_y.round() as i32,
+                x,
+                y,
+                cfg.color_grid,
+                LineType::Solid,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // One polygon per series, with a marker on every vertex
+        for dataset in self.visible_datasets() {
+            let polygon = self.polygon(dataset, layout);
+            if let Some(alpha) = self.fill_alpha {
+                canvas.blend_polygon(std::slice::from_ref(&polygon), dataset.color, alpha);
+            }
+            draw_closed(canvas, &polygon, dataset.color);
+            for &vertex in &polygon {
+                let (x, y) = to_pixel(vertex);
+                for dy in -2..=2 {
+                    for dx in -2..=2 {
+                        if dx * dx + dy * dy <= 5 && x + dx >= 0 && y + dy >= 0 {
+                            canvas.draw_pixel((x + dx) as u32, (y + dy) as u32, dataset.color);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Ring values between the first two axes, axis names around the perimeter
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: cfg.font_size_axis,
+            y: cfg.font_size_axis,
+        };
+        for &value in &rings {
+            let (x, y) = self.ring_label_position(value, layout);
+            canvas.draw_text_with_fallbacks(
+                (x + 3.0) as u32,
+                (y - 3.0 - cfg.font_size_axis as f64).max(0.0) as u32,
+                &self.format_value(value),
+                cfg.color_axis,
+                font_path,
+                &cfg.font_fallbacks,
+                scale,
+            );
+        }
+        let label_scale = PxScale {
+            x: cfg.font_size_label,
+            y: cfg.font_size_label,
+        };
+        for (axis, name) in self.axes.iter().enumerate() {
+            let (x, y) = self.to_canvas(self.axis_bearing(axis), radius + LABEL_OFFSET, layout);
+            let (w, h) = measure_text_with_fallbacks(
+                name,
+                font_path,
+                &cfg.font_fallbacks,
+                cfg.font_size_label,
+            );
+            // Share of the label width left of its anchor point
+            let alignment = match self.label_anchor(axis) {
+                "start" => 0.0,
+                "end" => 1.0,
+                _ => 0.5,
+            };
+            canvas.draw_text_with_fallbacks(
+                (x - w as f64 * alignment).max(0.0) as u32,
+                (y - h as f64 / 2.0).max(0.0) as u32,
+                name,
+                cfg.color_axis,
+                font_path,
+                &cfg.font_fallbacks,
+                label_scale,
+            );
+        }
+
+        if self.config.show_legend {
+            self.draw_legend(canvas);
+        }
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+
+        // Series are listed in the top-left corner
+        let x = canvas.margin;
+        let mut y = canvas.margin;
+
+        for dataset in self.visible_datasets() {
+            let (_, h) = measure_text_with_fallbacks(
+                &dataset.label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(x + dx, y + dy, dataset.color);
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                x + square_size + padding,
+                y,
+                &dataset.label,
+                self.config.color_axis,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+            y += square_size.max(h) + padding;
+        }
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+
+    fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        self.visible_datasets()
+            .map(|dataset| (dataset.label.clone(), dataset.color))
+            .collect()
+    }
+}
@@ -0,0 +1,315 @@
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::ridgelineplot::RidgelinePlot,
+    utilities::{
+        axistype::AxisType,
+        linetype::LineType,
+        scaling::{scale_factor, tick_values, to_pixel_x},
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl RidgelinePlot {
+    /// Maps the curve of every row to canvas coordinates.
+    ///
+    /// # Returns
+    /// One polyline per dataset, empty for datasets whose density cannot be estimated.
+    fn ridge_lines(&self, width: f64, height: f64, margin: f64) -> Vec<Vec<(f64, f64)>> {
+        let (x_min, x_max) = self.value_range();
+        let scale_x = scale_factor(x_min, x_max, width - 2.0 * margin);
+        let density_max = self.density_max();
+        self.curves()
+            .iter()
+            .enumerate()
+            .map(|(index, curve)| {
+                curve
+                    .iter()
+                    .map(|&(value, density)| {
+                        (
+                            to_pixel_x(value, x_min, scale_x, margin),
+                            self.to_ridge_y(index, density, density_max, height, margin),
+                        )
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl Drawer for RidgelinePlot {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+        self.fill_svg_background(svg_canvas, cfg);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let (x_min, x_max) = self.value_range();
+        let scale_x = scale_factor(x_min, x_max, width - 2.0 * margin);
+        let to_x = |value| to_pixel_x(value, x_min, scale_x, margin);
+
+        // Grid lines and tick labels of the shared value axis
+        let x_values = tick_values(x_min, x_max, cfg.num_axis_ticks);
+        let x_ticks: Vec<f64> = x_values.iter().map(|&value| to_x(value)).collect();
+        self.draw_svg_tick_grid(svg_canvas, cfg, &x_ticks, &[]);
+
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            font_size as f32,
+        );
+        for (&x, label) in x_ticks.iter().zip(&x_labels) {
+            svg_canvas.draw_tick_label(
+                x,
+                height - margin + font_size * 1.5,
+                label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+
+        // Row names left of their baselines
+        for (index, dataset) in self.datasets.iter().enumerate() {
+            self.draw_svg_category_label(
+                svg_canvas,
+                cfg,
+                margin - 5.0,
+                self.baseline(index, height, margin) + font_size * 0.3,
+                &dataset.label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Draw the value axis and its label
+        svg_canvas.draw_line(
+            margin,
+            height - margin,
+            width - margin,
+            height - margin,
+            "black",
+            2.0,
+        );
+        svg_canvas.draw_text(
+            width / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Ridges from the top row down, each in front of the one above
+        let density_max = self.density_max();
+        let curves = self.curves();
+        let lines = self.ridge_lines(width, height, margin);
+        for (index, (dataset, line)) in self.datasets.iter().zip(&lines).enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let color = self.rgb_to_svg_color(dataset.color);
+            let baseline = self.baseline(index, height, margin);
+            let points: Vec<String> = line.iter().map(|(x, y)| format!("{x:.2},{y:.2}")).collect();
+
+            svg_canvas.begin_series(&dataset.label, cfg.series_description(&dataset.label));
+            svg_canvas.elements.push(format!(
+                r#"<polygon points="{:.2},{baseline:.2} {} {:.2},{baseline:.2}" fill="{color}" fill-opacity="{:.2}" stroke="none"/>"#,
+                line[0].0,
+                points.join(" "),
+                line[line.len() - 1].0,
+                self.fill_opacity
+            ));
+            svg_canvas.elements.push(format!(
+                r#"<polyline points="{}" fill="none" stroke="{color}" stroke-width="1.5"/>"#,
+                points.join(" ")
+            ));
+            svg_canvas.draw_line(
+                line[0].0,
+                baseline,
+                line[line.len() - 1].0,
+                baseline,
+                &color,
+                1.0,
+            );
+            if let Some(&(value, density)) = curves[index].iter().max_by(|a, b| a.1.total_cmp(&b.1))
+            {
+                svg_canvas.draw_data_point(
+                    to_x(value),
+                    self.to_ridge_y(index, density, density_max, height, margin),
+                    &format!(
+                        "{}: peak at {}",
+                        dataset.label,
+                        self.config.format.format_number(value, 2)
+                    ),
+                );
+            }
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        let margin = canvas.margin as f64;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let (x_min, x_max) = self.value_range();
+        let scale_x = scale_factor(x_min, x_max, width - 2.0 * margin);
+
+        // Grid lines and tick labels of the shared value axis
+        let x_values = tick_values(x_min, x_max, cfg.num_axis_ticks);
+        let x_ticks: Vec<f64> = x_values
+            .iter()
+            .map(|&value| to_pixel_x(value, x_min, scale_x, margin))
+            .collect();
+        self.draw_tick_grid(canvas, cfg, &x_ticks, &[]);
+
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            cfg.font_size_axis,
+        );
+        for (&x, label) in x_ticks.iter().zip(&x_labels) {
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                x as u32,
+                canvas.height - canvas.margin,
+                label,
+                AxisType::AxisX,
+            );
+        }
+
+        // Row names left of their baselines
+        for (index, dataset) in self.datasets.iter().enumerate() {
+            self.draw_category_label(
+                canvas,
+                cfg,
+                canvas.margin - 10,
+                self.baseline(index, height, margin) as u32,
+                &dataset.label,
+                AxisType::AxisY,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Ridges from the top row down, each in front of the one above
+        let lines = self.ridge_lines(width, height, margin);
+        for (index, (dataset, line)) in self.datasets.iter().zip(&lines).enumerate() {
+            if line.len() < 2 {
+                continue;
+            }
+            let baseline = self.baseline(index, height, margin);
+            let mut area = line.clone();
+            area.push((line[line.len() - 1].0, baseline));
+            area.push((line[0].0, baseline));
+            canvas.blend_polygon(&[area], dataset.color, self.fill_opacity);
+            for segment in line.windows(2) {
+                canvas.draw_line(
+                    segment[0].0.round() as i32,
+                    segment[0].1.round() as i32,
+                    segment[1].0.round() as i32,
+                    segment[1].1.round() as i32,
+                    dataset.color,
+                    LineType::Solid,
+                );
+            }
+            canvas.draw_line(
+                line[0].0.round() as i32,
+                baseline.round() as i32,
+                line[line.len() - 1].0.round() as i32,
+                baseline.round() as i32,
+                dataset.color,
+                LineType::Solid,
+            );
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw the value axis and its label
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            (height - margin) as i32,
+            (width - margin) as i32,
+            (height - margin) as i32,
+        );
+        self.draw_label(
+            canvas,
+            cfg,
+            canvas.width - canvas.margin / 2,
+            canvas.height - canvas.margin,
+            &self.x_label,
+        );
+
+        self.draw_axis_units(canvas, &self.config);
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // Rows are named left of their baselines, so no separate legend is drawn
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -0,0 +1,214 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::sankeydiagram::SankeyDiagram,
+    utilities::textmetrics::measure_text_with_fallbacks,
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Space between a bar and its label, in pixels.
+const LABEL_GAP: f64 = 6.0;
+
+/// Number of segments approximating each ribbon edge in raster output.
+const RIBBON_SAMPLES: usize = 48;
+
+impl Drawer for SankeyDiagram {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let layout = self.layout(width - 2.0 * margin, height - 2.0 * margin);
+        let last_column = layout.nodes.iter().map(|node| node.column).max();
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Ribbons below the bars, colored by their source
+        for ribbon in &layout.ribbons {
+            let link = &self.links[ribbon.link];
+            let label = format!(
+                "{} → {}",
+                self.nodes[link.source].label, self.nodes[link.target].label
+            );
+            svg_canvas.begin_series(&label, cfg.series_description(&label));
+            svg_canvas.elements.push(format!(
+                r#"<path d="{}" fill="{}" fill-opacity="{:.2}" stroke="none" data-tooltip="{}"/>"#,
+                ribbon.svg_path(margin, margin),
+                self.rgb_to_svg_color(self.node_color(link.source)),
+                self.link_opacity,
+                escape_xml(&self.link_tooltip(ribbon.link))
+            ));
+            svg_canvas.end_group();
+        }
+
+        // Bars with their labels beside them, on the left of the bars in the last column
+        for node in &layout.nodes {
+            let label = &self.nodes[node.index].label;
+            svg_canvas.begin_series(label, cfg.series_description(label));
+            svg_canvas.draw_rect(
+                margin + node.x0,
+                margin + node.y0,
+                node.x1 - node.x0,
+                node.y1 - node.y0,
+                &self.rgb_to_svg_color(self.node_color(node.index)),
+                "none",
+                0.0,
+                1.0,
+            );
+            if self.show_labels {
+                let (x, anchor) = if Some(node.column) == last_column && node.column > 0 {
+                    (margin + node.x0 - LABEL_GAP, "end")
+                } else {
+                    (margin + node.x1 + LABEL_GAP, "start")
+                };
+                svg_canvas.elements.push(format!(
+                    r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="{}" fill="black">{}</text>"#,
+                    x,
+                    margin + (node.y0 + node.y1) / 2.0 + font_size * 0.35,
+                    font_size,
+                    anchor,
+                    escape_xml(label)
+                ));
+            }
+            svg_canvas.draw_data_point(
+                margin + (node.x0 + node.x1) / 2.0,
+                margin + (node.y0 + node.y1) / 2.0,
+                &self.node_tooltip(node),
+            );
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        let margin = canvas.margin as f64;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let layout = self.layout(width - 2.0 * margin, height - 2.0 * margin);
+        let last_column = layout.nodes.iter().map(|node| node.column).max();
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Ribbons below the bars, colored by their source
+        for ribbon in &layout.ribbons {
+            let outline = ribbon
+                .outline(RIBBON_SAMPLES)
+                .into_iter()
+                .map(|(x, y)| (margin + x, margin + y))
+                .collect();
+            canvas.blend_polygon(
+                &[outline],
+                self.node_color(self.links[ribbon.link].source),
+                self.link_opacity,
+            );
+        }
+
+        // Bars
+        for node in &layout.nodes {
+            let rect = vec![
+                (margin + node.x0, margin + node.y0),
+                (margin + node.x1, margin + node.y0),
+                (margin + node.x1, margin + node.y1),
+                (margin + node.x0, margin + node.y1),
+            ];
+            canvas.fill_polygon(&[rect], self.node_color(node.index));
+        }
+
+        // Labels beside the bars, on the left of the bars in the last column
+        if self.show_labels {
+            let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+            let scale = PxScale {
+                x: cfg.font_size_axis,
+                y: cfg.font_size_axis,
+            };
+            for node in &layout.nodes {
+                let label = &self.nodes[node.index].label;
+                let (w, h) =
+                    measure_text_with_fallbacks(label, font_path, &cfg.font_fallbacks, scale.x);
+                let x = if Some(node.column) == last_column && node.column > 0 {
+                    margin + node.x0 - LABEL_GAP - w as f64
+                } else {
+                    margin + node.x1 + LABEL_GAP
+                };
+                let y = margin + (node.y0 + node.y1) / 2.0 - h as f64 / 2.0;
+                canvas.draw_text_with_fallbacks(
+                    x.max(0.0) as u32,
+                    y.max(0.0) as u32,
+                    label,
+                    cfg.color_axis,
+                    font_path,
+                    &cfg.font_fallbacks,
+                    scale,
+                );
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // Every node is labelled beside its bar, so no separate legend is drawn
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -1,26 +1,190 @@
-use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
+use ab_glyph::PxScale;
 
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
-    figuretypes::scattergraph::ScatterGraph,
-    utilities::{axistype::AxisType, scatterdottype::ScatterDotType},
+    figuretypes::scattergraph::{ScatterGraph, BUBBLE_OPACITY},
+    utilities::{
+        axistype::AxisType, cornerradii::CornerRadii, linetype::LineType, scaling::tick_values,
+        textmetrics::measure_text_with_fallbacks,
+    },
 };
 
 use super::drawer::Drawer;
 use std::any::Any;
+
+/// Color of the bubbles in the size legend.
+const SIZE_LEGEND_COLOR: [u8; 3] = [150, 150, 150];
+
+/// Space in pixels around and between the entries of the size legend.
+const SIZE_LEGEND_PADDING: f64 = 6.0;
+
+impl ScatterGraph {
+    /// Computes the entries of the size legend as `(radius, label)` pairs, largest first.
+    fn size_legend_entries(&self) -> Vec<(f64, String)> {
+        let Some(range) = self.size_range() else {
+            return Vec::new();
+        };
+        self.size_legend_values()
+            .into_iter()
+            .map(|size| {
+                let label = format!("{size:.2}");
+                let label = self
+                    .config
+                    .format
+                    .localize(label.trim_end_matches('0').trim_end_matches('.'));
+                (self.bubble_radius_of(size, range), label)
+            })
+            .collect()
+    }
+
+    /// Computes the height of the size legend box for its entries.
+    fn size_legend_height(entries: &[(f64, String)], font_size: f64) -> f64 {
+        entries
+            .iter()
+            .map(|(radius, _)| (radius * 2.0).max(font_size) + SIZE_LEGEND_PADDING)
+            .sum::<f64>()
+            + SIZE_LEGEND_PADDING
+    }
+
+    /// Draws the size legend in the top-right corner of the plot area of a pixel canvas.
+    fn draw_size_legend(&self, canvas: &mut PixelCanvas) {
+        let entries = self.size_legend_entries();
+        if !self.size_legend || entries.is_empty() {
+            return;
+        }
+        let cfg = &self.config;
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let font_size = cfg.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let max_radius = entries[0].0;
+        let text_width = entries
+            .iter()
+            .map(|(_, label)| {
+                measure_text_with_fallbacks(label, font_path, &cfg.font_fallbacks, font_size).0
+            })
+            .max()
+            .unwrap_or(0) as f64;
+        let box_width = max_radius * 2.0 + text_width + SIZE_LEGEND_PADDING * 3.0;
+        let box_height = Self::size_legend_height(&entries, font_size as f64);
+        let box_x = (canvas.width - canvas.margin) as f64 - box_width - SIZE_LEGEND_PADDING;
+        let box_y = canvas.margin as f64 + SIZE_LEGEND_PADDING;
+        canvas.fill_rounded_rect(
+            box_x,
+            box_y,
+            box_width,
+            box_height,
+            CornerRadii::default(),
+            cfg.color_background,
+        );
+        canvas.draw_rounded_rect(
+            box_x,
+            box_y,
+            box_width,
+            box_height,
+            CornerRadii::default(),
+            cfg.color_axis,
+        );
+
+        let circle_x = box_x + SIZE_LEGEND_PADDING + max_radius;
+        let mut y = box_y + SIZE_LEGEND_PADDING;
+        for (radius, label) in &entries {
+            let row_height = (radius * 2.0).max(font_size as f64);
+            let center_y = y + row_height / 2.0;
+            canvas.blend_circle(
+                circle_x,
+                center_y,
+                *radius,
+                SIZE_LEGEND_COLOR,
+                BUBBLE_OPACITY,
+            );
+            canvas.draw_text_with_fallbacks(
+                (circle_x + max_radius + SIZE_LEGEND_PADDING) as u32,
+                (center_y - font_size as f64 / 2.0).max(0.0) as u32,
+                label,
+                cfg.color_axis,
+                font_path,
+                &cfg.font_fallbacks,
+                scale,
+            );
+            y += row_height + SIZE_LEGEND_PADDING;
+        }
+    }
+
+    /// Draws the size legend in the top-right corner of the plot area of an SVG canvas.
+    fn draw_svg_size_legend(&self, svg_canvas: &mut SvgCanvas, font_size: f64) {
+        let entries = self.size_legend_entries();
+        if !self.size_legend || entries.is_empty() {
+            return;
+        }
+        let max_radius = entries[0].0;
+        let text_width = entries
+            .iter()
+            .map(|(_, label)| label.chars().count() as f64 * font_size * 0.6)
+            .fold(0.0, f64::max);
+        let box_width = max_radius * 2.0 + text_width + SIZE_LEGEND_PADDING * 3.0;
+        let box_height = Self::size_legend_height(&entries, font_size);
+        let box_x =
+            svg_canvas.width as f64 - svg_canvas.margin as f64 - box_width - SIZE_LEGEND_PADDING;
+        let box_y = svg_canvas.margin as f64 + SIZE_LEGEND_PADDING;
+        let background = svg_canvas.background_color.clone();
+        svg_canvas.draw_rect(
+            box_x,
+            box_y,
+            box_width,
+            box_height,
+            &background,
+            "black",
+            0.5,
+            1.0,
+        );
+
+        let fill = self.rgb_to_svg_color(SIZE_LEGEND_COLOR);
+        let circle_x = box_x + SIZE_LEGEND_PADDING + max_radius;
+        let mut y = box_y + SIZE_LEGEND_PADDING;
+        for (radius, label) in &entries {
+            let row_height = (radius * 2.0).max(font_size);
+            let center_y = y + row_height / 2.0;
+            svg_canvas.elements.push(format!(
+                r#"<circle cx="{circle_x:.2}" cy="{center_y:.2}" r="{radius:.2}" fill="{fill}" fill-opacity="{BUBBLE_OPACITY}"/>"#
+            ));
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                circle_x + max_radius + SIZE_LEGEND_PADDING,
+                center_y + font_size * 0.35,
+                font_size,
+                escape_xml(label)
+            ));
+            y += row_height + SIZE_LEGEND_PADDING;
+        }
+    }
+}
+
 impl Drawer for ScatterGraph {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
         let margin = svg_canvas.margin as f64;
         let font_size = 12.0;
         let cfg = &self.config;
-        let margin_bg_color = svg_canvas.background_color.clone();
 
         // Draw margin background (using SvgCanvas background_color parameter)
-        svg_canvas.draw_rect(0.0, 0.0, width, height, &margin_bg_color, "black", 1.0, 1.0);
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
         // Draw chart background (using FigureConfig color)
         self.fill_svg_background(svg_canvas, cfg);
 
@@ -34,36 +198,22 @@ impl Drawer for ScatterGraph {
         );
 
         // Determine dataset range
-        let (x_min, x_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
-                (min.min(x), max.max(x))
-            });
-
-        let (y_min, y_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
-                (min.min(y), max.max(y))
-            });
+        let ((x_min, x_max), (y_min, y_max)) = self.data_range();
 
+        let ((x_min, x_max), (y_min, y_max)) = cfg.aspect_ranges(
+            (x_min, x_max),
+            (y_min, y_max),
+            width - 2.0 * margin,
+            height - 2.0 * margin,
+        );
         let scale_x = (width - 2.0 * margin) / (x_max - x_min);
         let scale_y = (height - 2.0 * margin) / (y_max - y_min);
 
         // Draw grid
         let num_ticks = 10;
-        svg_canvas.draw_grid(
-            margin,
-            width - margin,
-            margin,
-            height - margin,
-            num_ticks,
-            num_ticks,
-            "lightgray",
-        );
+        let x_ticks = tick_values(margin, width - margin, num_ticks);
+        let y_ticks = tick_values(height - margin, margin, num_ticks);
+        self.draw_svg_tick_grid(svg_canvas, &self.config, &x_ticks, &y_ticks);
 
         // Draw axes
         let origin_x = margin - x_min * scale_x; // Adjust for negative X values
@@ -75,9 +225,16 @@ impl Drawer for ScatterGraph {
         // Draw tick marks and values for X-axis
         // X-axis
         let mut x_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = x_min + i as f64 * (x_max - x_min) / num_ticks as f64;
-            let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
+        let x_values = tick_values(x_min, x_max, num_ticks);
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            font_size as f32,
+        );
+        for (i, label) in x_labels.into_iter().enumerate() {
+            let x = x_ticks[i];
             let tick_start_y = origin_y - 5.0;
             let tick_end_y = origin_y + 5.0;
 
@@ -86,9 +243,13 @@ impl Drawer for ScatterGraph {
             ));
 
             // Draw value as text (fallback to basic SVG <text>)
-            svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{:.1}</text>"#,
-            x, height - margin + font_size * 1.5, font_size, value));
+            svg_canvas.draw_tick_label(
+                x,
+                height - margin + font_size * 1.5,
+                &label,
+                font_size,
+                AxisType::AxisX,
+            );
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{x_axis_ticks}" stroke="black" stroke-width="1" fill="none"/>"#
@@ -96,9 +257,16 @@ impl Drawer for ScatterGraph {
 
         // Y-axis
         let mut y_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = y_min + i as f64 * (y_max - y_min) / num_ticks as f64;
-            let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
+        let y_values = tick_values(y_min, y_max, num_ticks);
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.1}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            height - 2.0 * margin,
+            font_size as f32,
+        );
+        for (i, label) in y_labels.into_iter().enumerate() {
+            let y = y_ticks[i];
             let tick_start_x = origin_x - 5.0;
             let tick_end_x = origin_x + 5.0;
 
@@ -107,10 +275,13 @@ impl Drawer for ScatterGraph {
             ));
 
             // Draw value as text (fallback to basic SVG <text>)
-            svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{:.1}</text>"#,
-            margin - 5.0, y + font_size * 0.3, font_size, value
-        ));
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{y_axis_ticks}" stroke="black" stroke-width="1" fill="none"/>"#
@@ -126,103 +297,115 @@ impl Drawer for ScatterGraph {
         );
 
         // Draw Y-axis label (rotated)
-        svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(-90 {:.2} {:.2})">{}</text>"#,
+        svg_canvas.draw_rotated_label(
             margin / 3.0,
             height / 2.0,
+            &self.y_label,
             font_size * 1.5,
-            margin / 3.0,
-            height / 2.0,
-            self.y_label
-        ));
+            "black",
+        );
 
-        // Plot datasets with scatter dot types
-        for dataset in &self.datasets {
-            for &(x, y) in &dataset.points {
-                let dot_type = &dataset.dot_type;
-                let svg_x = margin + (x - x_min) * scale_x;
-                let svg_y = height - margin - (y - y_min) * scale_y;
-
-                match dot_type {
-                    ScatterDotType::Circle(radius) => {
-                        svg_canvas.draw_circle(
-                            svg_x,
-                            svg_y,
-                            *radius as f64,
-                            &format!(
-                                "rgb({},{},{})",
-                                dataset.color[0], dataset.color[1], dataset.color[2]
-                            ),
-                        );
-                    }
-                    ScatterDotType::Square(side) => {
-                        let half_side = *side as f64 / 2.0;
-                        svg_canvas.draw_rect(
-                            svg_x - half_side,
-                            svg_y - half_side,
-                            *side as f64,
-                            *side as f64,
-                            &format!(
-                                "rgb({},{},{})",
-                                dataset.color[0], dataset.color[1], dataset.color[2]
-                            ),
-                            "none",
-                            1.0,
-                            1.0,
-                        );
-                    }
-                    ScatterDotType::Cross(thickness) => {
-                        svg_canvas.draw_line(
-                            svg_x - *thickness as f64,
-                            svg_y,
-                            svg_x + *thickness as f64,
-                            svg_y,
-                            &format!(
-                                "rgb({},{},{})",
-                                dataset.color[0], dataset.color[1], dataset.color[2]
-                            ),
-                            2.0,
-                        );
-                        svg_canvas.draw_line(
-                            svg_x,
-                            svg_y - *thickness as f64,
-                            svg_x,
-                            svg_y + *thickness as f64,
-                            &format!(
-                                "rgb({},{},{})",
-                                dataset.color[0], dataset.color[1], dataset.color[2]
-                            ),
-                            2.0,
-                        );
-                    }
-                    ScatterDotType::Triangle(base_size) => {
-                        let half_base = *base_size as f64 / 2.0;
-                        let height = *base_size as f64 * 0.866; // Height of an equilateral triangle
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Plot datasets with scatter dot types, or as bubbles for points with a size
+        let size_range = self.size_range();
+        for dataset in self.visible_datasets() {
+            svg_canvas.begin_series(
+                &dataset.label,
+                self.config.series_description(&dataset.label),
+            );
+            let link = self.config.series_link(&dataset.label);
+            if let Some(url) = link {
+                svg_canvas.begin_link(url);
+            }
+            let to_pixel = |x: f64, y: f64| {
+                (
+                    margin + (x - x_min) * scale_x,
+                    height - margin - (y - y_min) * scale_y,
+                )
+            };
+            let visible = self.visible_indices(dataset, to_pixel);
+            for &index in &visible {
+                for (x1, y1, x2, y2) in
+                    dataset
+                        .error_bars
+                        .lines(index, dataset.points[index], to_pixel)
+                {
+                    svg_canvas.draw_line_rgb(x1, y1, x2, y2, dataset.color, 1.0);
+                }
+            }
+            for index in visible {
+                let (x, y) = dataset.points[index];
+                let (svg_x, svg_y) = to_pixel(x, y);
+
+                match (dataset.size(index), size_range) {
+                    (Some(size), Some(range)) => {
+                        let radius = self.bubble_radius_of(size, range);
+                        let color = self.rgb_to_svg_color(dataset.color);
                         svg_canvas.elements.push(format!(
-                            r#"<polygon points="{:.2},{:.2} {:.2},{:.2} {:.2},{:.2}" fill="rgb({},{},{})"/>"#,
-                            svg_x,
-                            svg_y - height / 2.0,
-                            svg_x - half_base,
-                            svg_y + height / 2.0,
-                            svg_x + half_base,
-                            svg_y + height / 2.0,
-                            dataset.color[0],
-                            dataset.color[1],
-                            dataset.color[2]
+                            r#"<circle cx="{svg_x:.2}" cy="{svg_y:.2}" r="{radius:.2}" fill="{color}" fill-opacity="{BUBBLE_OPACITY}" stroke="{color}" stroke-width="1"/>"#
                         ));
                     }
+                    _ => dataset
+                        .dot_type
+                        .draw_svg(svg_canvas, svg_x, svg_y, dataset.color),
                 }
+                svg_canvas.draw_data_point(svg_x, svg_y, &self.point_tooltip(dataset, index));
             }
+            if link.is_some() {
+                svg_canvas.end_link();
+            }
+            svg_canvas.end_group();
         }
 
+        // Reference lines at dataset statistics
+        for dataset in self.visible_datasets() {
+            let Some(line) = dataset.reference_line else {
+                continue;
+            };
+            let Some(value) = line.value(dataset) else {
+                continue;
+            };
+            let y = height - margin - (value - y_min) * scale_y;
+            self.draw_svg_reference_line(
+                svg_canvas,
+                &self.config,
+                line,
+                value,
+                y,
+                dataset.color,
+                font_size,
+            );
+        }
+
+        self.draw_svg_size_legend(svg_canvas, font_size);
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+        self.draw_svg_secondary_axis(svg_canvas, &self.config, y_min, y_max, font_size);
+
+        if !self.config.show_legend {
+            return;
+        }
         // Draw legend
         let legend_x_start = margin + 10.0; // Start inside chart area with margin spacing
-        let legend_y = height - margin + font_size * 1.5 + 10.0; // Position below x-axis labels
+        let tick_overflow = self.config.x_tick_label_overflow(font_size as f32) as f64;
+        let legend_y = height - margin + font_size * 1.5 + 10.0 + tick_overflow; // Position below x-axis labels
         let mut legend_x = legend_x_start; // Reset starting position for legend items
         let mut elements = String::new();
         let legend_bg_color = svg_canvas.background_color.clone();
 
-        for dataset in &self.datasets {
+        for dataset in self.visible_datasets() {
+            let label = dataset.legend_label(&self.config.format);
+            elements.push_str(&SvgCanvas::legend_item_group(&dataset.label));
+            let link = self.config.series_link(&dataset.label);
+            if let Some(url) = link {
+                elements.push_str(&SvgCanvas::link_open(url));
+            }
+
             // Draw color square
             elements.push_str(&format!(
                 r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
@@ -244,11 +427,15 @@ impl Drawer for ScatterGraph {
                 dataset.color[0],
                 dataset.color[1],
                 dataset.color[2],
-                dataset.label
+                escape_xml(&label)
             ));
+            if link.is_some() {
+                elements.push_str("</a>");
+            }
+            elements.push_str("</g>");
 
             // Update legend_x to position the next item
-            legend_x += font_size * 5.0 + dataset.label.len() as f64 * font_size * 0.6;
+            legend_x += font_size * 5.0 + label.len() as f64 * font_size * 0.6;
         }
 
         // Draw a background rectangle for the legend
@@ -270,9 +457,12 @@ impl Drawer for ScatterGraph {
     }
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
         canvas.clear();
 
         let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
         self.fill_background(canvas, cfg);
 
         let margin = canvas.margin;
@@ -283,102 +473,155 @@ impl Drawer for ScatterGraph {
         self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
 
         // Calculate dataset limits
-        let (x_min, x_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
-                (min.min(x), max.max(x))
-            });
-
-        let (y_min, y_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
-                (min.min(y), max.max(y))
-            });
+        let ((x_min, x_max), (y_min, y_max)) = self.data_range();
 
         // Adjust limits to include (0, 0)
         let x_min = x_min.min(0.0);
         let y_min = y_min.min(0.0);
 
         // Calculate scales
+        let ((x_min, x_max), (y_min, y_max)) = cfg.aspect_ranges(
+            (x_min, x_max),
+            (y_min, y_max),
+            (width - 2 * margin) as f64,
+            (height - 2 * margin) as f64,
+        );
         let scale_x = (width - 2 * margin) as f64 / (x_max - x_min);
         let scale_y = (height - 2 * margin) as f64 / (y_max - y_min);
 
-        // Draw grids
-        canvas.draw_grid(
-            &[cfg.num_grid_horizontal, cfg.num_grid_vertical],
-            cfg.color_grid,
-        );
-
         let origin_x = canvas.margin + ((0.0 - x_min) * scale_x) as u32;
         let origin_y = height - margin - ((0.0 - y_min) * scale_y) as u32;
 
         self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.x_label);
         self.draw_label(canvas, cfg, margin, margin / 2, &self.y_label);
 
-        // Draw axis tick values
+        // Tick values and their pixel positions, shared by the grid and the tick labels
         let num_ticks = 10;
+        let x_values = tick_values(x_min, x_max, num_ticks);
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.2}"));
+        let x_ticks: Vec<(String, u32)> = cfg
+            .thin_tick_labels(
+                AxisType::AxisX,
+                x_labels,
+                (x_max - x_min) * scale_x,
+                cfg.font_size_axis,
+            )
+            .into_iter()
+            .zip(&x_values)
+            .map(|(label, &value)| (label, origin_x + ((value - x_min) * scale_x) as u32))
+            .collect();
+        let y_values = tick_values(y_min, y_max, num_ticks);
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.2}"));
+        let y_ticks: Vec<(String, u32)> = cfg
+            .thin_tick_labels(
+                AxisType::AxisY,
+                y_labels,
+                (y_max - y_min) * scale_y,
+                cfg.font_size_axis,
+            )
+            .into_iter()
+            .zip(&y_values)
+            .map(|(label, &value)| (label, origin_y - ((value - y_min) * scale_y) as u32))
+            .collect();
+        let positions = |ticks: &[(String, u32)]| -> Vec<f64> {
+            ticks.iter().map(|(_, position)| *position as f64).collect()
+        };
 
-        // X-axis ticks
-        let x_tick_step = (x_max - x_min) / num_ticks as f64;
-        for i in 0..=num_ticks {
-            let value_x = x_min + i as f64 * x_tick_step;
-            let tick_x = origin_x + ((value_x - x_min) * scale_x) as u32;
-
-            let value_label = format!("{value_x:.2}");
+        // Draw grids
+        self.draw_tick_grid(canvas, cfg, &positions(&x_ticks), &positions(&y_ticks));
 
-            self.draw_axis_value(canvas, cfg, tick_x, origin_y, &value_label, AxisType::AxisX);
+        // X-axis ticks
+        for (label, tick_x) in &x_ticks {
+            self.draw_axis_value(canvas, cfg, *tick_x, origin_y, label, AxisType::AxisX);
         }
 
         // Y-axis ticks
-        let y_tick_step = (y_max - y_min) / num_ticks as f64;
-        for i in 0..=num_ticks {
-            let value_y = y_min + i as f64 * y_tick_step;
-            let tick_y = origin_y - ((value_y - y_min) * scale_y) as u32;
-
-            let value_label = format!("{value_y:.2}");
-
-            self.draw_axis_value(
-                canvas,
-                cfg,
-                origin_x - 10,
-                tick_y,
-                &value_label,
-                AxisType::AxisY,
-            );
+        for (label, tick_y) in &y_ticks {
+            self.draw_axis_value(canvas, cfg, origin_x - 10, *tick_y, label, AxisType::AxisY);
         }
 
-        // Draw scatter points
-        for dataset in &self.datasets {
-            for &(_x, _y) in &dataset.points {
-                // Draw a small square or circle to represent the point
-                for dataset in &self.datasets {
-                    for &(x, y) in &dataset.points {
-                        let px = origin_x + ((x - x_min) * scale_x) as u32;
-                        let py = origin_y - ((y - y_min) * scale_y) as u32;
-
-                        self.draw_dot(
-                            canvas,
-                            px as i32,
-                            py as i32,
-                            dataset.dot_type.clone(),
-                            dataset.color,
-                        );
-                    }
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw scatter points, or bubbles for points with a size
+        let size_range = self.size_range();
+        for dataset in self.visible_datasets() {
+            let to_pixel = |x: f64, y: f64| {
+                (
+                    origin_x as f64 + (x - x_min) * scale_x,
+                    origin_y as f64 - (y - y_min) * scale_y,
+                )
+            };
+            let visible = self.visible_indices(dataset, to_pixel);
+            for &index in &visible {
+                for (x1, y1, x2, y2) in
+                    dataset
+                        .error_bars
+                        .lines(index, dataset.points[index], to_pixel)
+                {
+                    canvas.draw_line(
+                        x1.round() as i32,
+                        y1.round() as i32,
+                        x2.round() as i32,
+                        y2.round() as i32,
+                        dataset.color,
+                        LineType::Solid,
+                    );
                 }
             }
+            for index in visible {
+                let (x, y) = dataset.points[index];
+                if let (Some(size), Some(range)) = (dataset.size(index), size_range) {
+                    let (px, py) = to_pixel(x, y);
+                    canvas.blend_circle(
+                        px,
+                        py,
+                        self.bubble_radius_of(size, range),
+                        dataset.color,
+                        BUBBLE_OPACITY,
+                    );
+                    continue;
+                }
+                let px = origin_x + ((x - x_min) * scale_x) as u32;
+                let py = origin_y - ((y - y_min) * scale_y) as u32;
+
+                self.draw_dot(
+                    canvas,
+                    px as i32,
+                    py as i32,
+                    dataset.dot_type.clone(),
+                    dataset.color,
+                );
+            }
         }
 
+        // Reference lines at dataset statistics
+        for dataset in self.visible_datasets() {
+            let Some(line) = dataset.reference_line else {
+                continue;
+            };
+            let Some(value) = line.value(dataset) else {
+                continue;
+            };
+            let y = origin_y as i32 - ((value - y_min) * scale_y) as i32;
+            self.draw_reference_line(canvas, &self.config, line, value, y, dataset.color);
+        }
+
+        self.draw_size_legend(canvas);
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
         canvas.draw_vertical_line(canvas.margin, [0, 0, 0]);
         canvas.draw_vertical_line(canvas.width - canvas.margin, [0, 0, 0]);
         canvas.draw_horizontal_line(canvas.height - canvas.margin, [0, 0, 0]);
         canvas.draw_horizontal_line(canvas.margin, [0, 0, 0]);
 
         // Draw legend
-        self.draw_legend(canvas);
+        self.draw_axis_units(canvas, &self.config);
+        self.draw_secondary_axis(canvas, &self.config, y_min, y_max);
+        if self.config.show_legend {
+            self.draw_legend(canvas);
+        }
     }
 
     fn draw_legend(&self, canvas: &mut PixelCanvas) {
@@ -387,20 +630,31 @@ impl Drawer for ScatterGraph {
             .font_label
             .as_ref()
             .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
-        let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
-
-        let square_size = 10; // Size of the colored square
-        let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+        let line_height = square_size * 2; // Vertical space for each legend entry
         let legend_margin = canvas.margin; // Margin from the bottom of the canvas
 
         let mut x = canvas.margin;
-        let mut y = canvas.height - legend_margin; // Legend starts from the bottom
-
-        for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.label);
+        let tick_overflow = self
+            .config
+            .x_tick_label_overflow(self.config.font_size_axis) as u32;
+        let mut y = canvas.height - legend_margin + tick_overflow; // Legend starts from the bottom
+
+        for dataset in self.visible_datasets() {
+            let label = dataset.legend_label(&self.config.format);
+            let (w, h) = measure_text_with_fallbacks(
+                &label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
             // Draw the square
             for dy in 0..square_size {
                 for dx in 0..square_size {
@@ -414,12 +668,13 @@ impl Drawer for ScatterGraph {
 
             // Draw the label text next to the square
             let text_x: u32 = x + square_size + padding;
-            canvas.draw_text(
+            canvas.draw_text_with_fallbacks(
                 text_x,
                 y + 2 * square_size + h,
-                &dataset.label,
+                &label,
                 dataset.color,
-                &font,
+                font_path,
+                &self.config.font_fallbacks,
                 scale,
             );
 
@@ -440,4 +695,14 @@ impl Drawer for ScatterGraph {
     fn get_figure_config(&self) -> &FigureConfig {
         &self.config
     }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+
+    fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        self.visible_datasets()
+            .map(|dataset| (dataset.legend_label(&self.config.format), dataset.color))
+            .collect()
+    }
 }
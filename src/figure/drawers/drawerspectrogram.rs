@@ -0,0 +1,264 @@
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::spectrogram::Spectrogram,
+    utilities::{axistype::AxisType, colorbar::COLOR_BAR_GAP, scaling::tick_values},
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl Spectrogram {
+    /// Computes the time and frequency ticks as `(value, canvas position)` pairs.
+    ///
+    /// Time positions are x-coordinates, frequency positions y-coordinates.
+    #[allow(clippy::type_complexity)]
+    fn axis_ticks(&self, area: (f64, f64, f64, f64)) -> (Vec<(f64, f64)>, Vec<(f64, f64)>) {
+        let (left, top, right, bottom) = area;
+        let num_ticks = self.config.num_axis_ticks;
+        let (t0, t1) = self.time_extent();
+        let (f0, f1) = self.frequency_extent();
+        let times = tick_values(t0, t1, num_ticks)
+            .into_iter()
+            .map(|t| {
+                (
+                    t,
+                    left + (t - t0) / (t1 - t0).max(f64::EPSILON) * (right - left),
+                )
+            })
+            .collect();
+        let frequencies = tick_values(f0, f1, num_ticks)
+            .into_iter()
+            .map(|f| {
+                (
+                    f,
+                    bottom - (f - f0) / (f1 - f0).max(f64::EPSILON) * (bottom - top),
+                )
+            })
+            .collect();
+        (times, frequencies)
+    }
+
+    /// Returns the labels of the color bar: the top, middle and bottom of the dynamic range,
+    /// as `(fraction of the bar height, text)` pairs.
+    fn color_bar_labels(&self) -> Vec<(f64, String)> {
+        [1.0, 0.5, 0.0]
+            .into_iter()
+            .map(|fraction| {
+                let level = (fraction - 1.0) * self.dynamic_range;
+                (fraction, format!("{level:.0} dB"))
+            })
+            .collect()
+    }
+
+    /// Returns the color at a fraction of the color bar height, from the weakest level at
+    /// 0.0 to the strongest at 1.0.
+    fn color_bar_color(&self, fraction: f64) -> [u8; 3] {
+        self.level_color((fraction - 1.0) * self.dynamic_range)
+    }
+}
+
+impl Drawer for Spectrogram {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Heatmap, embedded as an image
+        let area = self.plot_area(width, height, margin);
+        let (left, top, right, bottom) = area;
+        svg_canvas.draw_image(left, top, &self.render(area));
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Axes with time and frequency ticks
+        svg_canvas.draw_line(left, bottom, right, bottom, "black", 2.0);
+        svg_canvas.draw_line(left, top, left, bottom, "black", 2.0);
+        let (times, frequencies) = self.axis_ticks(area);
+        for (time, x) in times {
+            let label = cfg.tick_label(AxisType::AxisX, time, |v| format!("{v:.2}"));
+            svg_canvas.draw_tick_label(
+                x,
+                bottom + font_size * 1.5,
+                &label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+        for (frequency, y) in frequencies {
+            let label = cfg.tick_label(AxisType::AxisY, frequency, |v| format!("{v:.0}"));
+            svg_canvas.draw_tick_label(
+                left - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            (left + right) / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        // Color bar from the weakest (bottom) to the strongest level (top)
+        self.draw_svg_color_bar(
+            svg_canvas,
+            cfg,
+            right + COLOR_BAR_GAP,
+            (top, bottom),
+            50,
+            &|fraction| self.color_bar_color(fraction),
+            &self.color_bar_labels(),
+            font_size,
+        );
+
+        self.draw_svg_axis_units(svg_canvas, cfg, font_size);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Heatmap
+        let area = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let image = self.render(area);
+        let (x, y) = (area.0.round() as u32, area.1.round() as u32);
+        for (dx, dy, pixel) in image.enumerate_pixels() {
+            canvas.draw_pixel(x + dx, y + dy, pixel.0);
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Axes with time and frequency ticks
+        let (left, top, right, bottom) = (
+            area.0.round() as i32,
+            area.1.round() as i32,
+            area.2.round() as i32,
+            area.3.round() as i32,
+        );
+        self.draw_axis(canvas, cfg, left, bottom, right, bottom);
+        self.draw_axis(canvas, cfg, left, top, left, bottom);
+        let (times, frequencies) = self.axis_ticks(area);
+        for (time, x) in times {
+            let label = cfg.tick_label(AxisType::AxisX, time, |v| format!("{v:.2}"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                x as u32,
+                bottom as u32,
+                &label,
+                AxisType::AxisX,
+            );
+        }
+        for (frequency, y) in frequencies {
+            let label = cfg.tick_label(AxisType::AxisY, frequency, |v| format!("{v:.0}"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                (left as u32).saturating_sub(10),
+                y as u32,
+                &label,
+                AxisType::AxisY,
+            );
+        }
+
+        self.draw_label(
+            canvas,
+            cfg,
+            right as u32,
+            canvas.height - canvas.margin / 3,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_axis_units(canvas, cfg);
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let (_, top, right, bottom) = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+
+        // Color bar from the weakest (bottom) to the strongest level (top), right of the plot
+        self.draw_color_bar(
+            canvas,
+            &self.config,
+            right + COLOR_BAR_GAP,
+            (top, bottom),
+            &|fraction| self.color_bar_color(fraction),
+            &self.color_bar_labels(),
+        );
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
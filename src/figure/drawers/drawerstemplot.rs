@@ -0,0 +1,141 @@
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::stemplot::StemPlot,
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl Drawer for StemPlot {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let (scale_x, scale_y) = self.graph.draw_svg_frame(svg_canvas);
+        let graph = &self.graph;
+        let to_canvas = |x: f64, y: f64| {
+            (
+                margin + (x - graph.x_min) * scale_x,
+                height - margin - (y - graph.y_min) * scale_y,
+            )
+        };
+
+        graph
+            .config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Stems from the baseline, topped by markers
+        for dataset in graph.visible_datasets() {
+            svg_canvas.begin_series(
+                &dataset.label,
+                graph.config.series_description(&dataset.label),
+            );
+            let link = graph.config.series_link(&dataset.label);
+            if let Some(url) = link {
+                svg_canvas.begin_link(url);
+            }
+            let (visible, points) = graph.visible_points(&dataset.points);
+            for &(x, y) in &points {
+                let (px, base_y) = to_canvas(x, self.baseline);
+                let base_y = base_y.clamp(margin, height - margin);
+                let (_, py) = to_canvas(x, y);
+                svg_canvas.draw_line_rgb_styled(
+                    px,
+                    base_y,
+                    px,
+                    py,
+                    dataset.color,
+                    2.0,
+                    dataset.line_type.clone(),
+                );
+                self.marker.draw_svg(svg_canvas, px, py, dataset.color);
+            }
+
+            dataset.error_bars.select(&visible).draw_svg(
+                svg_canvas,
+                &points,
+                to_canvas,
+                dataset.color,
+            );
+
+            // Hover targets for the interactive HTML export
+            for &(x, y) in &points {
+                let (px, py) = to_canvas(x, y);
+                svg_canvas.draw_data_point(px, py, &format!("{}: ({x:.2}, {y:.2})", dataset.label));
+            }
+            if link.is_some() {
+                svg_canvas.end_link();
+            }
+            svg_canvas.end_group();
+        }
+
+        graph.draw_svg_annotations(svg_canvas, scale_y);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        let (scale_x, scale_y) = self.graph.draw_frame(canvas);
+        let (center_x, center_y) = self.graph.pixel_origin(canvas, scale_x, scale_y);
+        let to_canvas = |x: f64, y: f64| (center_x + x * scale_x, center_y - y * scale_y);
+
+        self.graph
+            .config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Stems from the baseline, topped by markers
+        let margin = canvas.margin as f64;
+        let bottom = canvas.height as f64 - margin;
+        for dataset in self.graph.visible_datasets() {
+            let (visible, points) = self.graph.visible_points(&dataset.points);
+            for &(x, y) in &points {
+                let (px, base_y) = to_canvas(x, self.baseline);
+                let base_y = base_y.clamp(margin, bottom);
+                let (_, py) = to_canvas(x, y);
+                canvas.draw_line(
+                    px as i32,
+                    base_y as i32,
+                    px as i32,
+                    py as i32,
+                    dataset.color,
+                    dataset.line_type.clone(),
+                );
+                self.marker
+                    .draw(canvas, px as i32, py as i32, dataset.color);
+            }
+            dataset
+                .error_bars
+                .select(&visible)
+                .draw(canvas, &points, to_canvas, dataset.color);
+        }
+
+        self.graph.draw_annotations(canvas, scale_y);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        self.graph.draw_legend(canvas);
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.graph.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.graph.config)
+    }
+
+    fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        self.graph.legend_entries()
+    }
+}
@@ -0,0 +1,375 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::streamgraph::{StreamBaseline, Streamgraph},
+    utilities::{
+        areafill::{band_path, fill_band},
+        axistype::AxisType,
+        scaling::{scale_factor, tick_values, to_pixel_x, to_pixel_y},
+        textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl Streamgraph {
+    /// Converts the layers to canvas coordinates.
+    ///
+    /// # Returns
+    /// For every series, the top and bottom lines of its layer.
+    #[allow(clippy::type_complexity)]
+    fn canvas_layers(
+        &self,
+        layers: &[Vec<(f64, f64, f64)>],
+        to_x: impl Fn(f64) -> f64,
+        to_y: impl Fn(f64) -> f64,
+    ) -> Vec<(Vec<(f64, f64)>, Vec<(f64, f64)>)> {
+        layers
+            .iter()
+            .map(|layer| {
+                layer
+                    .iter()
+                    .map(|&(x, bottom, top)| ((to_x(x), to_y(top)), (to_x(x), to_y(bottom))))
+                    .unzip()
+            })
+            .collect()
+    }
+}
+
+impl Drawer for Streamgraph {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+        self.fill_svg_background(svg_canvas, cfg);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let layers = self.layers();
+        let ((x_min, x_max), (y_min, y_max)) = self.ranges(&layers);
+        let scale_x = scale_factor(x_min, x_max, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, height - 2.0 * margin);
+        let to_x = |x| to_pixel_x(x, x_min, scale_x, margin);
+        let to_y = |y| to_pixel_y(y, y_min, scale_y, height, margin);
+
+        // Grid lines and tick labels; values are only meaningful on a zero baseline
+        let x_values = tick_values(x_min, x_max, cfg.num_axis_ticks);
+        let y_values = if self.baseline == StreamBaseline::Zero {
+            tick_values(y_min, y_max, cfg.num_axis_ticks)
+        } else {
+            Vec::new()
+        };
+        let x_ticks: Vec<f64> = x_values.iter().map(|&x| to_x(x)).collect();
+        let y_ticks: Vec<f64> = y_values.iter().map(|&y| to_y(y)).collect();
+        self.draw_svg_tick_grid(svg_canvas, cfg, &x_ticks, &y_ticks);
+
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            font_size as f32,
+        );
+        for (&x, label) in x_ticks.iter().zip(&x_labels) {
+            svg_canvas.draw_tick_label(
+                x,
+                height - margin + font_size * 1.5,
+                label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.1}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            height - 2.0 * margin,
+            font_size as f32,
+        );
+        for (&y, label) in y_ticks.iter().zip(&y_labels) {
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Draw the x-axis
+        svg_canvas.draw_line(
+            margin,
+            height - margin,
+            width - margin,
+            height - margin,
+            "black",
+            2.0,
+        );
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            width / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // One filled layer per series
+        for (index, (top, bottom)) in self
+            .canvas_layers(&layers, to_x, to_y)
+            .into_iter()
+            .enumerate()
+        {
+            let label = &self.series[index].label;
+            svg_canvas.begin_series(label, cfg.series_description(label));
+            svg_canvas.elements.push(format!(
+                r#"<path d="{}" fill="{}" stroke="none" data-tooltip="{}"/>"#,
+                band_path(&top, &bottom),
+                self.rgb_to_svg_color(self.series_color(index)),
+                escape_xml(&self.series_tooltip(index))
+            ));
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+
+        // Legend in a row below the x tick labels
+        let tick_overflow = cfg.x_tick_label_overflow(font_size as f32) as f64;
+        let legend_y = height - margin + font_size * 2.5 + tick_overflow;
+        let mut legend_x = margin;
+        for (index, series) in self.series.iter().enumerate() {
+            svg_canvas
+                .elements
+                .push(SvgCanvas::legend_item_group(&series.label));
+            svg_canvas.draw_rect(
+                legend_x,
+                legend_y,
+                font_size,
+                font_size,
+                &self.rgb_to_svg_color(self.series_color(index)),
+                "none",
+                0.0,
+                1.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                legend_x + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                escape_xml(&series.label)
+            ));
+            svg_canvas.end_group();
+            legend_x += font_size * 2.5 + series.label.chars().count() as f64 * font_size * 0.6;
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        let margin = canvas.margin as f64;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let layers = self.layers();
+        let ((x_min, x_max), (y_min, y_max)) = self.ranges(&layers);
+        let scale_x = scale_factor(x_min, x_max, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, height - 2.0 * margin);
+        let to_x = |x| to_pixel_x(x, x_min, scale_x, margin);
+        let to_y = |y| to_pixel_y(y, y_min, scale_y, height, margin);
+
+        // Grid lines and tick labels; values are only meaningful on a zero baseline
+        let x_values = tick_values(x_min, x_max, cfg.num_axis_ticks);
+        let y_values = if self.baseline == StreamBaseline::Zero {
+            tick_values(y_min, y_max, cfg.num_axis_ticks)
+        } else {
+            Vec::new()
+        };
+        let x_ticks: Vec<f64> = x_values.iter().map(|&x| to_x(x)).collect();
+        let y_ticks: Vec<f64> = y_values.iter().map(|&y| to_y(y)).collect();
+        self.draw_tick_grid(canvas, cfg, &x_ticks, &y_ticks);
+
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            width - 2.0 * margin,
+            cfg.font_size_axis,
+        );
+        for (&x, label) in x_ticks.iter().zip(&x_labels) {
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                x as u32,
+                canvas.height - canvas.margin,
+                label,
+                AxisType::AxisX,
+            );
+        }
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.1}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            height - 2.0 * margin,
+            cfg.font_size_axis,
+        );
+        for (&y, label) in y_ticks.iter().zip(&y_labels) {
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                canvas.margin - 10,
+                y as u32,
+                label,
+                AxisType::AxisY,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // One filled layer per series
+        for (index, (top, bottom)) in self
+            .canvas_layers(&layers, to_x, to_y)
+            .into_iter()
+            .enumerate()
+        {
+            fill_band(canvas, &top, &bottom, self.series_color(index), 1.0);
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw the x-axis and axis labels
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            (height - margin) as i32,
+            (width - margin) as i32,
+            (height - margin) as i32,
+        );
+        self.draw_label(
+            canvas,
+            cfg,
+            canvas.width - canvas.margin / 2,
+            canvas.height - canvas.margin,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_axis_units(canvas, &self.config);
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+
+        // Series are listed in a row below the x tick labels
+        let tick_overflow = self
+            .config
+            .x_tick_label_overflow(self.config.font_size_axis) as u32;
+        let mut x = canvas.margin;
+        let y = canvas.height - canvas.margin + square_size * 2 + tick_overflow;
+
+        for (index, series) in self.series.iter().enumerate() {
+            let (w, _) = measure_text_with_fallbacks(
+                &series.label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
+            let color = self.series_color(index);
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(x + dx, y + dy, color);
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                x + square_size + padding,
+                y,
+                &series.label,
+                self.config.color_axis,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+            x += square_size + padding + w + square_size;
+        }
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
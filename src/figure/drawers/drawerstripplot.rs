@@ -0,0 +1,333 @@
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::stripplot::StripPlot,
+    utilities::{
+        axistype::AxisType,
+        linetype::LineType,
+        scaling::{scale_factor, tick_values, to_pixel_x, to_pixel_y},
+        statistics::BoxStats,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Width of the box overlay as a fraction of the category width.
+const BOX_WIDTH: f64 = 0.5;
+
+impl Drawer for StripPlot {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+        self.fill_svg_background(svg_canvas, cfg);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let (y_min, y_max) = self.value_range();
+        let categories = self.datasets.len().max(1) as f64;
+        let scale_x = scale_factor(0.0, categories, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, height - 2.0 * margin);
+
+        // Value grid lines and tick labels
+        for value in tick_values(y_min, y_max, cfg.num_axis_ticks) {
+            let y = to_pixel_y(value, y_min, scale_y, height, margin);
+            svg_canvas.draw_line(margin, y, width - margin, y, "lightgray", 0.5);
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.1}"));
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Draw axes
+        svg_canvas.draw_line(margin, margin, margin, height - margin, "black", 2.0);
+        svg_canvas.draw_line(
+            margin,
+            height - margin,
+            width - margin,
+            height - margin,
+            "black",
+            2.0,
+        );
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            width / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Plot the jittered samples
+        for (dataset, points) in self.datasets.iter().zip(self.jittered_points()) {
+            let color = self.rgb_to_svg_color(dataset.color);
+            for (x, y) in points {
+                svg_canvas.draw_circle(
+                    to_pixel_x(x, 0.0, scale_x, margin),
+                    to_pixel_y(y, y_min, scale_y, height, margin),
+                    self.point_radius as f64,
+                    &color,
+                );
+            }
+        }
+
+        // Category labels and box overlays
+        let axis_color = self.rgb_to_svg_color(cfg.color_axis);
+        let half_box = BOX_WIDTH / 2.0 * scale_x;
+        for (index, dataset) in self.datasets.iter().enumerate() {
+            let center = to_pixel_x(index as f64 + 0.5, 0.0, scale_x, margin);
+            self.draw_svg_category_label(
+                svg_canvas,
+                cfg,
+                center,
+                height - margin + font_size * 1.5,
+                &dataset.label,
+                font_size,
+                AxisType::AxisX,
+            );
+
+            if !self.show_box {
+                continue;
+            }
+            let Some(stats) = BoxStats::from_samples(&dataset.samples) else {
+                continue;
+            };
+            let to_y = |value| to_pixel_y(value, y_min, scale_y, height, margin);
+
+            svg_canvas.draw_rect(
+                center - half_box,
+                to_y(stats.q3),
+                half_box * 2.0,
+                to_y(stats.q1) - to_y(stats.q3),
+                "none",
+                &axis_color,
+                1.5,
+                0.0,
+            );
+            svg_canvas.draw_line(
+                center - half_box,
+                to_y(stats.median),
+                center + half_box,
+                to_y(stats.median),
+                &axis_color,
+                2.0,
+            );
+            for (end, whisker) in [
+                (stats.q3, stats.upper_whisker),
+                (stats.q1, stats.lower_whisker),
+            ] {
+                svg_canvas.draw_line(center, to_y(end), center, to_y(whisker), &axis_color, 1.0);
+                svg_canvas.draw_line(
+                    center - half_box / 2.0,
+                    to_y(whisker),
+                    center + half_box / 2.0,
+                    to_y(whisker),
+                    &axis_color,
+                    1.0,
+                );
+            }
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        let margin = canvas.margin as f64;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let (y_min, y_max) = self.value_range();
+        let categories = self.datasets.len().max(1) as f64;
+        let scale_x = scale_factor(0.0, categories, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, height - 2.0 * margin);
+
+        // Value grid lines and tick labels
+        for value in tick_values(y_min, y_max, cfg.num_axis_ticks) {
+            let y = to_pixel_y(value, y_min, scale_y, height, margin) as i32;
+            canvas.draw_line(
+                margin as i32,
+                y,
+                (width - margin) as i32,
+                y,
+                cfg.color_grid,
+                LineType::Solid,
+            );
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.1}"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                canvas.margin - 10,
+                y as u32,
+                &label,
+                AxisType::AxisY,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Plot the jittered samples
+        let radius = self.point_radius as i32;
+        for (dataset, points) in self.datasets.iter().zip(self.jittered_points()) {
+            for (x, y) in points {
+                let px = to_pixel_x(x, 0.0, scale_x, margin) as i32;
+                let py = to_pixel_y(y, y_min, scale_y, height, margin) as i32;
+                for dy in -radius..=radius {
+                    for dx in -radius..=radius {
+                        if dx * dx + dy * dy <= radius * radius {
+                            canvas.draw_pixel((px + dx) as u32, (py + dy) as u32, dataset.color);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Category labels and box overlays
+        let half_box = (BOX_WIDTH / 2.0 * scale_x) as i32;
+        for (index, dataset) in self.datasets.iter().enumerate() {
+            let center = to_pixel_x(index as f64 + 0.5, 0.0, scale_x, margin) as i32;
+            self.draw_category_label(
+                canvas,
+                cfg,
+                center as u32,
+                canvas.height - canvas.margin,
+                &dataset.label,
+                AxisType::AxisX,
+            );
+
+            if !self.show_box {
+                continue;
+            }
+            let Some(stats) = BoxStats::from_samples(&dataset.samples) else {
+                continue;
+            };
+            let to_y = |value| to_pixel_y(value, y_min, scale_y, height, margin) as i32;
+            let (left, right) = (center - half_box, center + half_box);
+            let (top, bottom) = (to_y(stats.q3), to_y(stats.q1));
+
+            for (x1, y1, x2, y2) in [
+                (left, top, right, top),
+                (left, bottom, right, bottom),
+                (left, top, left, bottom),
+                (right, top, right, bottom),
+                (left, to_y(stats.median), right, to_y(stats.median)),
+                (center, top, center, to_y(stats.upper_whisker)),
+                (center, bottom, center, to_y(stats.lower_whisker)),
+            ] {
+                self.draw_axis(canvas, cfg, x1, y1, x2, y2);
+            }
+            for whisker in [stats.upper_whisker, stats.lower_whisker] {
+                let y = to_y(whisker);
+                self.draw_axis(
+                    canvas,
+                    cfg,
+                    center - half_box / 2,
+                    y,
+                    center + half_box / 2,
+                    y,
+                );
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw axes and axis labels
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            margin as i32,
+            margin as i32,
+            (height - margin) as i32,
+        );
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            (height - margin) as i32,
+            (width - margin) as i32,
+            (height - margin) as i32,
+        );
+        self.draw_label(
+            canvas,
+            cfg,
+            canvas.width - canvas.margin / 2,
+            canvas.height - canvas.margin,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_axis_units(canvas, &self.config);
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // Categories are labelled on the x-axis, so no separate legend is drawn
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
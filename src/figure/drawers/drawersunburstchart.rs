@@ -0,0 +1,230 @@
+use ab_glyph::PxScale;
+use std::f64::consts::TAU;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::sunburstchart::SunburstChart,
+    utilities::{
+        arcgeometry::{
+            draw_text_along_arc, fill_ring_segment, ring_segment_path, text_arc_length,
+            text_arc_path,
+        },
+        colormap::text_color_for,
+        linetype::LineType,
+        textmetrics::ellipsize,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Minimum free space around a segment label, in pixels.
+const LABEL_PADDING: f64 = 4.0;
+
+impl Drawer for SunburstChart {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let ((cx, cy), radius) = Self::layout(width, height, margin);
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        svg_canvas
+            .elements
+            .push(format!(r#"<g transform="translate({cx:.2},{cy:.2})">"#));
+
+        // Segments, separated by background colored strokes, with labels along their arcs
+        let background = self.rgb_to_svg_color(cfg.color_background);
+        let measure = |text: &str| text.chars().count() as f64 * font_size * 0.6;
+        for segment in self.segments() {
+            let (inner, outer) = self.ring_radii(segment.depth());
+            let (inner, outer) = (inner * radius, outer * radius);
+            // SVG angles run clockwise on screen, so the span is mirrored to match the
+            // counterclockwise layout of the pixel output
+            let (start_angle, end_angle) = (TAU - segment.end_angle, TAU - segment.start_angle);
+            let color = self.segment_color(&segment);
+            let label = &self.node(&segment.path).label;
+            // A full ring is drawn as two halves, whose seam would show through a stroke
+            let stroke = if end_angle - start_angle >= TAU - 1e-9 {
+                "none"
+            } else {
+                &background
+            };
+
+            svg_canvas.begin_series(label, cfg.series_description(label));
+            svg_canvas.elements.push(format!(
+                r#"<path d="{}" fill="{}" stroke="{}" stroke-width="1" data-tooltip="{}"/>"#,
+                ring_segment_path(inner, outer, start_angle, end_angle),
+                self.rgb_to_svg_color(color),
+                stroke,
+                escape_xml(&self.segment_tooltip(&segment))
+            ));
+
+            let mid_radius = (inner + outer) / 2.0;
+            let arc_length = mid_radius * (end_angle - start_angle);
+            let text = ellipsize(label, arc_length - 2.0 * LABEL_PADDING, measure);
+            if let (Some(text), true) = (
+                text.filter(|_| self.show_labels),
+                font_size + 2.0 * LABEL_PADDING <= outer - inner,
+            ) {
+                // Glyphs stand on the arc in the upper half and hang from it in the lower
+                // half, so the baseline moves to center them on the ring
+                let baseline = if ((start_angle + end_angle) / 2.0).sin() > 0.0 {
+                    mid_radius + font_size * 0.35
+                } else {
+                    mid_radius - font_size * 0.35
+                };
+                let id = format!("sunburst-arc-{}", svg_canvas.elements.len());
+                svg_canvas.elements.push(format!(
+                    r##"<defs><path id="{id}" d="{}"/></defs><text font-size="{:.2}" fill="{}"><textPath href="#{id}" startOffset="50%" text-anchor="middle">{}</textPath></text>"##,
+                    text_arc_path(baseline, start_angle, end_angle),
+                    font_size,
+                    self.rgb_to_svg_color(text_color_for(color)),
+                    escape_xml(&text)
+                ));
+            }
+            svg_canvas.end_group();
+        }
+
+        svg_canvas.end_group();
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let ((cx, cy), radius) = Self::layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let center = (cx.round() as i32, cy.round() as i32);
+        let segments = self.segments();
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Segments, separated by background colored spokes
+        for segment in &segments {
+            let (inner, outer) = self.ring_radii(segment.depth());
+            fill_ring_segment(
+                canvas,
+                center,
+                (inner * radius, (outer * radius).round() as i32),
+                (segment.start_angle, segment.end_angle),
+                self.segment_color(segment),
+            );
+        }
+        for segment in &segments {
+            if segment.end_angle - segment.start_angle >= TAU - 1e-9 {
+                // A full ring has no edge
+                continue;
+            }
+            let (inner, outer) = self.ring_radii(segment.depth());
+            let angle = segment.start_angle;
+            let spoke = |r: f64| {
+                (
+                    (cx + r * radius * angle.cos()).round() as i32,
+                    (cy - r * radius * angle.sin()).round() as i32,
+                )
+            };
+            let ((x1, y1), (x2, y2)) = (spoke(inner), spoke(outer));
+            canvas.draw_line(x1, y1, x2, y2, cfg.color_background, LineType::Solid);
+        }
+
+        // Labels along the arcs of the segments they fit in
+        if self.show_labels {
+            let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+            let scale = PxScale {
+                x: cfg.font_size_axis,
+                y: cfg.font_size_axis,
+            };
+            let measure = |text: &str| text_arc_length(text, font_path, scale);
+            for segment in &segments {
+                let (inner, outer) = self.ring_radii(segment.depth());
+                let (inner, outer) = (inner * radius, outer * radius);
+                if (scale.y as f64) + 2.0 * LABEL_PADDING > outer - inner {
+                    continue;
+                }
+                let mid_radius = (inner + outer) / 2.0;
+                let arc_length = mid_radius * (segment.end_angle - segment.start_angle);
+                let label = &self.node(&segment.path).label;
+                if let Some(text) = ellipsize(label, arc_length - 2.0 * LABEL_PADDING, measure) {
+                    draw_text_along_arc(
+                        canvas,
+                        (cx, cy),
+                        mid_radius,
+                        segment.mid_angle(),
+                        &text,
+                        text_color_for(self.segment_color(segment)),
+                        font_path,
+                        scale,
+                    );
+                }
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // Segments are labelled along their arcs, so no separate legend is drawn
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
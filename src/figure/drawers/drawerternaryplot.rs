@@ -0,0 +1,321 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::ternaryplot::{TernaryPlot, TRIANGLE_HEIGHT},
+    utilities::{linetype::LineType, textmetrics::measure_text_with_fallbacks},
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl TernaryPlot {
+    /// Computes the tick labels and the canvas positions of their centers.
+    ///
+    /// Labels are placed just outside the edge where their grid line starts: A along the
+    /// bottom edge, B along the right edge and C along the left edge.
+    fn tick_label_positions(
+        &self,
+        layout: (f64, f64, f64),
+        offset: f64,
+    ) -> Vec<(String, (f64, f64))> {
+        // Outward normals of the bottom, right and left edges
+        let normals = [
+            (0.0, 1.0),
+            (TRIANGLE_HEIGHT, -0.5),
+            (-TRIANGLE_HEIGHT, -0.5),
+        ];
+        self.grid_lines()
+            .iter()
+            .zip(normals)
+            .flat_map(|(lines, (nx, ny))| {
+                lines.iter().map(move |&(fraction, start, _)| {
+                    let (x, y) = self.to_canvas(start, layout);
+                    (
+                        self.tick_label(fraction),
+                        (x + nx * offset, y + ny * offset),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Computes the component names and the canvas positions of their centers.
+    fn vertex_label_positions(
+        &self,
+        layout: (f64, f64, f64),
+        offset: f64,
+    ) -> [(&str, (f64, f64)); 3] {
+        let (ax, ay) = self.to_canvas([1.0, 0.0, 0.0], layout);
+        let (bx, by) = self.to_canvas([0.0, 1.0, 0.0], layout);
+        let (cx, cy) = self.to_canvas([0.0, 0.0, 1.0], layout);
+        [
+            (&self.a_label, (ax, ay + offset * 2.0)),
+            (&self.b_label, (bx, by + offset * 2.0)),
+            (&self.c_label, (cx, cy - offset)),
+        ]
+    }
+}
+
+impl Drawer for TernaryPlot {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let layout = self.layout(width, height, margin);
+
+        // Grid lines of constant fraction
+        let grid_color = self.rgb_to_svg_color(cfg.color_grid);
+        for lines in self.grid_lines() {
+            for (_, start, end) in lines {
+                let (x1, y1) = self.to_canvas(start, layout);
+                let (x2, y2) = self.to_canvas(end, layout);
+                svg_canvas.draw_line(x1, y1, x2, y2, &grid_color, 1.0);
+            }
+        }
+
+        // Triangle edges
+        let vertices = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        let axis_color = self.rgb_to_svg_color(cfg.color_axis);
+        for index in 0..3 {
+            let (x1, y1) = self.to_canvas(vertices[index], layout);
+            let (x2, y2) = self.to_canvas(vertices[(index + 1) % 3], layout);
+            svg_canvas.draw_line(x1, y1, x2, y2, &axis_color, 1.0);
+        }
+
+        // Tick labels are centered on their position
+        for (label, (x, y)) in self.tick_label_positions(layout, font_size) {
+            svg_canvas.draw_text(x, y + font_size * 0.35, &label, font_size, &axis_color);
+        }
+        for (label, (x, y)) in self.vertex_label_positions(layout, font_size * 1.5) {
+            svg_canvas.draw_text(x, y + font_size * 0.35, label, font_size * 1.2, "black");
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Datasets: optional connecting lines, then the points
+        for dataset in &self.datasets {
+            let points: Vec<(f64, f64)> = dataset
+                .points
+                .iter()
+                .map(|&point| self.to_canvas(point, layout))
+                .collect();
+            if let Some(line_type) = &dataset.line_type {
+                for pair in points.windows(2) {
+                    svg_canvas.draw_line_rgb_styled(
+                        pair[0].0,
+                        pair[0].1,
+                        pair[1].0,
+                        pair[1].1,
+                        dataset.color,
+                        1.5,
+                        line_type.clone(),
+                    );
+                }
+            }
+            for (x, y) in points {
+                dataset.dot_type.draw_svg(svg_canvas, x, y, dataset.color);
+            }
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Dataset legend in the top-left corner, beside the top vertex
+        let mut legend_y = margin;
+        for dataset in &self.datasets {
+            svg_canvas.draw_rect(
+                margin,
+                legend_y,
+                font_size,
+                font_size,
+                &self.rgb_to_svg_color(dataset.color),
+                "none",
+                0.0,
+                1.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                margin + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                dataset.label
+            ));
+            legend_y += font_size * 1.5;
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let layout = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let to_pixel = |point: [f64; 3]| {
+            let (x, y) = self.to_canvas(point, layout);
+            (x.round() as i32, y.round() as i32)
+        };
+
+        // Grid lines of constant fraction
+        for lines in self.grid_lines() {
+            for (_, start, end) in lines {
+                let (x1, y1) = to_pixel(start);
+                let (x2, y2) = to_pixel(end);
+                canvas.draw_line(x1, y1, x2, y2, cfg.color_grid, LineType::Solid);
+            }
+        }
+
+        // Triangle edges
+        let vertices = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        for index in 0..3 {
+            let (x1, y1) = to_pixel(vertices[index]);
+            let (x2, y2) = to_pixel(vertices[(index + 1) % 3]);
+            self.draw_axis(canvas, cfg, x1, y1, x2, y2);
+        }
+
+        // Tick labels are centered on their position
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: cfg.font_size_axis,
+            y: cfg.font_size_axis,
+        };
+        let offset = cfg.font_size_axis as f64;
+        for (label, (x, y)) in self.tick_label_positions(layout, offset) {
+            let (w, h) = measure_text_with_fallbacks(
+                &label,
+                font_path,
+                &cfg.font_fallbacks,
+                cfg.font_size_axis,
+            );
+            canvas.draw_text_with_fallbacks(
+                (x - w as f64 / 2.0).max(0.0) as u32,
+                (y - h as f64 / 2.0).max(0.0) as u32,
+                &label,
+                cfg.color_axis,
+                font_path,
+                &cfg.font_fallbacks,
+                scale,
+            );
+        }
+        for (label, (x, y)) in self.vertex_label_positions(layout, offset * 1.5) {
+            self.draw_label(canvas, cfg, x.max(0.0) as u32, y.max(0.0) as u32, label);
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Datasets: optional connecting lines, then the points
+        for dataset in &self.datasets {
+            let points: Vec<(i32, i32)> = dataset.points.iter().map(|&p| to_pixel(p)).collect();
+            if let Some(line_type) = &dataset.line_type {
+                for pair in points.windows(2) {
+                    canvas.draw_line(
+                        pair[0].0,
+                        pair[0].1,
+                        pair[1].0,
+                        pair[1].1,
+                        dataset.color,
+                        line_type.clone(),
+                    );
+                }
+            }
+            for (x, y) in points {
+                dataset.dot_type.draw(canvas, x, y, dataset.color);
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+
+        // Datasets are listed in the top-left corner, beside the top vertex
+        let x = canvas.margin;
+        let mut y = canvas.margin;
+
+        for dataset in &self.datasets {
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(x + dx, y + dy, dataset.color);
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                x + square_size + padding,
+                y,
+                &dataset.label,
+                self.config.color_axis,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+            y += square_size + padding;
+        }
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -0,0 +1,212 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        rendertarget::RenderTarget,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::treemap::Treemap,
+    utilities::{
+        colormap::text_color_for,
+        textmetrics::{ellipsize, measure_text_with_fallbacks},
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Minimum free space around a cell label, in pixels.
+const LABEL_PADDING: f64 = 4.0;
+
+impl Drawer for Treemap {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Draw cells, parents first, separated by background colored strokes
+        let background = self.rgb_to_svg_color(cfg.color_background);
+        let measure = |text: &str| text.chars().count() as f64 * font_size * 0.6;
+        for cell in self.cells(width - 2.0 * margin, height - 2.0 * margin) {
+            let (x, y) = (margin + cell.x0, margin + cell.y0);
+            let (cell_width, cell_height) = (cell.width(), cell.height());
+            let color = self.cell_color(&cell);
+            let label = &self.node(&cell.path).label;
+
+            svg_canvas.begin_series(label, cfg.series_description(label));
+            svg_canvas.draw_rect(
+                x,
+                y,
+                cell_width,
+                cell_height,
+                &self.rgb_to_svg_color(color),
+                &background,
+                1.0,
+                1.0,
+            );
+
+            let text = if self.show_labels {
+                ellipsize(label, cell_width - 2.0 * LABEL_PADDING, measure)
+            } else {
+                None
+            };
+            let text_color = self.rgb_to_svg_color(text_color_for(color));
+            match text {
+                Some(text) if self.has_header(&cell) => {
+                    svg_canvas.elements.push(format!(
+                        r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="{}">{}</text>"#,
+                        x + LABEL_PADDING,
+                        y + self.header_height / 2.0 + font_size * 0.35,
+                        font_size,
+                        text_color,
+                        escape_xml(&text)
+                    ));
+                }
+                Some(text) if cell.is_leaf && font_size + 2.0 * LABEL_PADDING <= cell_height => {
+                    svg_canvas.draw_text(
+                        x + cell_width / 2.0,
+                        y + cell_height / 2.0 + font_size * 0.35,
+                        &escape_xml(&text),
+                        font_size,
+                        &text_color,
+                    );
+                }
+                _ => {}
+            }
+            if cell.is_leaf {
+                svg_canvas.draw_data_point(
+                    x + cell_width / 2.0,
+                    y + cell_height / 2.0,
+                    &self.cell_tooltip(&cell),
+                );
+            }
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        let margin = canvas.margin as f64;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: cfg.font_size_axis,
+            y: cfg.font_size_axis,
+        };
+        let measure = |text: &str| {
+            measure_text_with_fallbacks(text, font_path, &cfg.font_fallbacks, scale.x).0 as f64
+        };
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw cells, parents first, leaving a background colored line between siblings
+        for cell in self.cells(width - 2.0 * margin, height - 2.0 * margin) {
+            let color = self.cell_color(&cell);
+            let x0 = (margin + cell.x0).round() as u32;
+            let y0 = (margin + cell.y0).round() as u32;
+            let x1 = (margin + cell.x1).round() as u32;
+            let y1 = (margin + cell.y1).round() as u32;
+            for y in y0..y1.saturating_sub(1) {
+                for x in x0..x1.saturating_sub(1) {
+                    canvas.draw_pixel(x, y, color);
+                }
+            }
+
+            if !self.show_labels {
+                continue;
+            }
+            let label = &self.node(&cell.path).label;
+            let Some(text) = ellipsize(label, cell.width() - 2.0 * LABEL_PADDING, measure) else {
+                continue;
+            };
+            let (w, h) =
+                measure_text_with_fallbacks(&text, font_path, &cfg.font_fallbacks, scale.x);
+            let position = if self.has_header(&cell) {
+                Some((
+                    x0 + LABEL_PADDING as u32,
+                    y0 + (self.header_height as u32).saturating_sub(h) / 2,
+                ))
+            } else if cell.is_leaf && h + 2 * LABEL_PADDING as u32 <= y1 - y0 {
+                Some(((x0 + x1).saturating_sub(w) / 2, (y0 + y1 - h) / 2))
+            } else {
+                None
+            };
+            if let Some((x, y)) = position {
+                canvas.draw_text_with_fallbacks(
+                    x,
+                    y,
+                    &text,
+                    text_color_for(color),
+                    font_path,
+                    &cfg.font_fallbacks,
+                    scale,
+                );
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // Every cell is labelled in place, so no separate legend is drawn
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -0,0 +1,361 @@
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::violinplot::{ViolinPlot, DENSITY_POINTS},
+    utilities::{
+        axistype::AxisType,
+        linetype::LineType,
+        scaling::{scale_factor, tick_values, to_pixel_x, to_pixel_y},
+        statistics::BoxStats,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// Returns the tooltip text of a violin, listing its quartiles and sample count.
+fn summary_text(label: &str, stats: &BoxStats, count: usize) -> String {
+    format!(
+        "{label}: median {:.2}, Q1 {:.2}, Q3 {:.2}, n = {count}",
+        stats.median, stats.q1, stats.q3
+    )
+}
+
+/// Returns the outline of a violin in canvas coordinates: the right half from the lowest to
+/// the highest value, then the mirrored left half back down.
+fn outline(
+    density: &[(f64, f64)],
+    center: f64,
+    half_width: f64,
+    max_density: f64,
+    to_y: impl Fn(f64) -> f64,
+) -> Vec<(f64, f64)> {
+    let offset = |value: f64| value / max_density * half_width;
+    let right = density
+        .iter()
+        .map(|&(value, d)| (center + offset(d), to_y(value)));
+    let left = density
+        .iter()
+        .rev()
+        .map(|&(value, d)| (center - offset(d), to_y(value)));
+    right.chain(left).collect()
+}
+
+impl Drawer for ViolinPlot {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+        self.fill_svg_background(svg_canvas, cfg);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let (y_min, y_max) = self.value_range();
+        let categories = self.datasets.len().max(1) as f64;
+        let scale_x = scale_factor(0.0, categories, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, height - 2.0 * margin);
+        let to_y = |value| to_pixel_y(value, y_min, scale_y, height, margin);
+
+        // Value grid lines and tick labels
+        let y_values = tick_values(y_min, y_max, cfg.num_axis_ticks);
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.1}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            height - 2.0 * margin,
+            font_size as f32,
+        );
+        for (&value, label) in y_values.iter().zip(&y_labels) {
+            let y = to_y(value);
+            svg_canvas.draw_line(margin, y, width - margin, y, "lightgray", 0.5);
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        // Draw axes
+        svg_canvas.draw_line(margin, margin, margin, height - margin, "black", 2.0);
+        svg_canvas.draw_line(
+            margin,
+            height - margin,
+            width - margin,
+            height - margin,
+            "black",
+            2.0,
+        );
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            width / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Category labels, violins and the quartile overlay
+        let axis_color = self.rgb_to_svg_color(cfg.color_axis);
+        let half_violin = self.violin_width.clamp(0.0, 1.0) / 2.0 * scale_x;
+        let max_density = self.max_density();
+        for (index, dataset) in self.datasets.iter().enumerate() {
+            let center = to_pixel_x(index as f64 + 0.5, 0.0, scale_x, margin);
+            self.draw_svg_category_label(
+                svg_canvas,
+                cfg,
+                center,
+                height - margin + font_size * 1.5,
+                &dataset.label,
+                font_size,
+                AxisType::AxisX,
+            );
+
+            let density = dataset.density(DENSITY_POINTS);
+            let Some(stats) = dataset.box_stats().filter(|_| !density.is_empty()) else {
+                continue;
+            };
+            svg_canvas.begin_series(&dataset.label, cfg.series_description(&dataset.label));
+            let points: Vec<String> = outline(&density, center, half_violin, max_density, to_y)
+                .iter()
+                .map(|(x, y)| format!("{x:.2},{y:.2}"))
+                .collect();
+            svg_canvas.elements.push(format!(
+                r#"<polygon points="{}" fill="{}" stroke="{axis_color}" stroke-width="1"/>"#,
+                points.join(" "),
+                self.rgb_to_svg_color(dataset.color)
+            ));
+
+            if self.show_quartiles {
+                let half_box = (half_violin * 0.08).max(2.0);
+                svg_canvas.draw_line(
+                    center,
+                    to_y(stats.lower_whisker),
+                    center,
+                    to_y(stats.upper_whisker),
+                    &axis_color,
+                    1.0,
+                );
+                svg_canvas.draw_rect(
+                    center - half_box,
+                    to_y(stats.q3),
+                    half_box * 2.0,
+                    to_y(stats.q1) - to_y(stats.q3),
+                    &axis_color,
+                    &axis_color,
+                    1.0,
+                    1.0,
+                );
+                svg_canvas.draw_circle(center, to_y(stats.median), half_box.max(3.0), "white");
+            }
+            svg_canvas.draw_data_point(
+                center,
+                to_y(stats.median),
+                &summary_text(&dataset.label, &stats, dataset.samples.len()),
+            );
+            svg_canvas.end_group();
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        let margin = canvas.margin as f64;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let (y_min, y_max) = self.value_range();
+        let categories = self.datasets.len().max(1) as f64;
+        let scale_x = scale_factor(0.0, categories, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, height - 2.0 * margin);
+        let to_y = |value| to_pixel_y(value, y_min, scale_y, height, margin);
+
+        // Value grid lines and tick labels
+        let y_values = tick_values(y_min, y_max, cfg.num_axis_ticks);
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.1}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            height - 2.0 * margin,
+            cfg.font_size_axis,
+        );
+        for (&value, label) in y_values.iter().zip(&y_labels) {
+            let y = to_y(value) as i32;
+            canvas.draw_line(
+                margin as i32,
+                y,
+                (width - margin) as i32,
+                y,
+                cfg.color_grid,
+                LineType::Solid,
+            );
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                canvas.margin - 10,
+                y as u32,
+                label,
+                AxisType::AxisY,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Category labels, violins and the quartile overlay
+        let half_violin = self.violin_width.clamp(0.0, 1.0) / 2.0 * scale_x;
+        let max_density = self.max_density();
+        for (index, dataset) in self.datasets.iter().enumerate() {
+            let center = to_pixel_x(index as f64 + 0.5, 0.0, scale_x, margin);
+            self.draw_category_label(
+                canvas,
+                cfg,
+                center as u32,
+                canvas.height - canvas.margin,
+                &dataset.label,
+                AxisType::AxisX,
+            );
+
+            let density = dataset.density(DENSITY_POINTS);
+            let Some(stats) = dataset.box_stats().filter(|_| !density.is_empty()) else {
+                continue;
+            };
+            let shape = outline(&density, center, half_violin, max_density, to_y);
+            canvas.fill_polygon(std::slice::from_ref(&shape), dataset.color);
+            for (start, end) in shape.iter().zip(shape.iter().cycle().skip(1)) {
+                self.draw_axis(
+                    canvas,
+                    cfg,
+                    start.0.round() as i32,
+                    start.1.round() as i32,
+                    end.0.round() as i32,
+                    end.1.round() as i32,
+                );
+            }
+
+            if self.show_quartiles {
+                let x = center.round() as i32;
+                let half_box = (half_violin * 0.08).max(2.0).round() as i32;
+                self.draw_axis(
+                    canvas,
+                    cfg,
+                    x,
+                    to_y(stats.lower_whisker) as i32,
+                    x,
+                    to_y(stats.upper_whisker) as i32,
+                );
+                for y in to_y(stats.q3) as i32..=to_y(stats.q1) as i32 {
+                    for dx in -half_box..=half_box {
+                        canvas.draw_pixel((x + dx) as u32, y as u32, cfg.color_axis);
+                    }
+                }
+                let radius = half_box.max(3);
+                let median = to_y(stats.median) as i32;
+                for dy in -radius..=radius {
+                    for dx in -radius..=radius {
+                        if dx * dx + dy * dy <= radius * radius {
+                            canvas.draw_pixel((x + dx) as u32, (median + dy) as u32, [255; 3]);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw axes and axis labels
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            margin as i32,
+            margin as i32,
+            (height - margin) as i32,
+        );
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            (height - margin) as i32,
+            (width - margin) as i32,
+            (height - margin) as i32,
+        );
+        self.draw_label(
+            canvas,
+            cfg,
+            canvas.width - canvas.margin / 2,
+            canvas.height - canvas.margin,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_axis_units(canvas, &self.config);
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // Categories are labelled on the x-axis, so no separate legend is drawn
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
@@ -0,0 +1,413 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::waterfallchart::{WaterfallBarKind, WaterfallChart},
+    utilities::{
+        axistype::AxisType,
+        linetype::LineType,
+        scaling::{scale_factor, tick_values, to_pixel_x, to_pixel_y},
+        textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl WaterfallChart {
+    /// Returns the legend entries: the bar kinds present in the chart, with their names.
+    fn legend_entries(&self) -> Vec<(WaterfallBarKind, &'static str)> {
+        let bars = self.bars();
+        [
+            (WaterfallBarKind::Increase, "Increase"),
+            (WaterfallBarKind::Decrease, "Decrease"),
+            (WaterfallBarKind::Total, "Total"),
+        ]
+        .into_iter()
+        .filter(|(kind, _)| bars.iter().any(|bar| bar.kind == *kind))
+        .collect()
+    }
+}
+
+impl Drawer for WaterfallChart {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let (y_min, y_max) = self.value_range();
+        let scale_x = scale_factor(0.0, 1.0, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, height - 2.0 * margin);
+        let to_y = |value: f64| to_pixel_y(value, y_min, scale_y, height, margin);
+
+        // Value grid and tick labels
+        let y_values = tick_values(y_min, y_max, cfg.num_axis_ticks);
+        let y_ticks: Vec<f64> = y_values.iter().map(|&value| to_y(value)).collect();
+        self.draw_svg_tick_grid(svg_canvas, cfg, &[], &y_ticks);
+        for (&value, &y) in y_values.iter().zip(&y_ticks) {
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.2}"));
+            svg_canvas.draw_tick_label(
+                margin - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Bars, with connectors carrying the running total to the next bar
+        let bars = self.bars();
+        for (position, bar) in bars.iter().enumerate() {
+            let (left, right) = self.bar_edges(bar.index);
+            let (x0, x1) = (
+                to_pixel_x(left, 0.0, scale_x, margin),
+                to_pixel_x(right, 0.0, scale_x, margin),
+            );
+            let (low, high) = bar.span();
+            let step = &self.steps[bar.index];
+            svg_canvas.begin_series(&step.label, cfg.series_description(&step.label));
+            svg_canvas.draw_rect(
+                x0,
+                to_y(high),
+                x1 - x0,
+                (to_y(low) - to_y(high)).max(1.0),
+                &self.rgb_to_svg_color(self.bar_color(bar.kind)),
+                "none",
+                0.0,
+                1.0,
+            );
+            let label = self.bar_label(bar);
+            if self.show_values {
+                svg_canvas.draw_value_label(
+                    (x0 + x1) / 2.0,
+                    to_y(high) - 4.0,
+                    &label,
+                    font_size,
+                    "black",
+                );
+            }
+            svg_canvas.draw_data_point(
+                (x0 + x1) / 2.0,
+                to_y(high),
+                &format!("{}: {label}", step.label),
+            );
+            svg_canvas.end_group();
+
+            if let Some(next) = bars.get(position + 1) {
+                let next_left = to_pixel_x(self.bar_edges(next.index).0, 0.0, scale_x, margin);
+                svg_canvas.draw_line_rgb_styled(
+                    x1,
+                    to_y(bar.end),
+                    next_left,
+                    to_y(bar.end),
+                    self.connector_color,
+                    1.0,
+                    LineType::Dashed(3),
+                );
+            }
+        }
+
+        // Zero line when the running total goes negative
+        if y_min < 0.0 {
+            svg_canvas.draw_line(margin, to_y(0.0), width - margin, to_y(0.0), "black", 1.0);
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Step labels below the center of their bars
+        for (index, step) in self.steps.iter().enumerate() {
+            let (left, right) = self.bar_edges(index);
+            svg_canvas.draw_tick_label(
+                to_pixel_x((left + right) / 2.0, 0.0, scale_x, margin),
+                height - margin + font_size * 1.5,
+                &step.label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+
+        // Draw axes
+        svg_canvas.draw_line(margin, margin, margin, height - margin, "black", 2.0);
+        svg_canvas.draw_line(
+            margin,
+            height - margin,
+            width - margin,
+            height - margin,
+            "black",
+            2.0,
+        );
+
+        // Draw axis labels
+        svg_canvas.draw_text(
+            width / 2.0,
+            height - margin / 3.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.draw_rotated_label(
+            margin / 3.0,
+            height / 2.0,
+            &self.y_label,
+            font_size * 1.5,
+            "black",
+        );
+
+        // Bar kind legend below the step labels
+        let legend_y = height - margin + font_size * 2.5;
+        let mut legend_x = margin + 10.0;
+        for (kind, name) in self.legend_entries() {
+            svg_canvas.draw_rect(
+                legend_x,
+                legend_y,
+                font_size,
+                font_size,
+                &self.rgb_to_svg_color(self.bar_color(kind)),
+                "none",
+                0.0,
+                1.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                legend_x + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                name
+            ));
+            legend_x += font_size * 3.0 + name.len() as f64 * font_size * 0.6;
+        }
+
+        self.draw_svg_axis_units(svg_canvas, &self.config, font_size);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        let margin = canvas.margin as f64;
+        let width = canvas.width as f64;
+        let height = canvas.height as f64;
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let (y_min, y_max) = self.value_range();
+        let scale_x = scale_factor(0.0, 1.0, width - 2.0 * margin);
+        let scale_y = scale_factor(y_min, y_max, height - 2.0 * margin);
+        let to_y = |value: f64| to_pixel_y(value, y_min, scale_y, height, margin);
+
+        // Value grid and tick labels
+        let y_values = tick_values(y_min, y_max, cfg.num_axis_ticks);
+        let y_ticks: Vec<f64> = y_values.iter().map(|&value| to_y(value)).collect();
+        self.draw_tick_grid(canvas, cfg, &[], &y_ticks);
+        for (&value, &y) in y_values.iter().zip(&y_ticks) {
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.2}"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                canvas.margin - 10,
+                y as u32,
+                &label,
+                AxisType::AxisY,
+            );
+        }
+
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: cfg.font_size_axis,
+            y: cfg.font_size_axis,
+        };
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Bars, with connectors carrying the running total to the next bar
+        let bars = self.bars();
+        for (position, bar) in bars.iter().enumerate() {
+            let (left, right) = self.bar_edges(bar.index);
+            let x0 = to_pixel_x(left, 0.0, scale_x, margin) as u32;
+            let x1 = to_pixel_x(right, 0.0, scale_x, margin) as u32;
+            let (low, high) = bar.span();
+            let y0 = to_y(high) as u32;
+            let y1 = (to_y(low) as u32).max(y0 + 1);
+            let color = self.bar_color(bar.kind);
+            for y in y0..y1 {
+                for x in x0..x1 {
+                    canvas.draw_pixel(x, y, color);
+                }
+            }
+
+            if self.show_values {
+                let label = self.bar_label(bar);
+                let (w, h) =
+                    measure_text_with_fallbacks(&label, font_path, &cfg.font_fallbacks, scale.x);
+                self.draw_text_with_halo(
+                    canvas,
+                    cfg,
+                    (
+                        ((x0 + x1) / 2).saturating_sub(w / 2),
+                        y0.saturating_sub(h + 4),
+                    ),
+                    &label,
+                    cfg.color_axis,
+                    scale,
+                );
+            }
+
+            if let Some(next) = bars.get(position + 1) {
+                let next_left = to_pixel_x(self.bar_edges(next.index).0, 0.0, scale_x, margin);
+                let y = to_y(bar.end) as i32;
+                canvas.draw_line(
+                    x1 as i32,
+                    y,
+                    next_left as i32,
+                    y,
+                    self.connector_color,
+                    LineType::Dashed(3),
+                );
+            }
+        }
+
+        // Zero line when the running total goes negative
+        if y_min < 0.0 {
+            let y = to_y(0.0) as i32;
+            self.draw_axis(canvas, cfg, margin as i32, y, (width - margin) as i32, y);
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Step labels below the center of their bars
+        for (index, step) in self.steps.iter().enumerate() {
+            let (left, right) = self.bar_edges(index);
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                to_pixel_x((left + right) / 2.0, 0.0, scale_x, margin) as u32,
+                canvas.height - canvas.margin,
+                &step.label,
+                AxisType::AxisX,
+            );
+        }
+
+        // Draw axes and axis labels
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            margin as i32,
+            margin as i32,
+            (height - margin) as i32,
+        );
+        self.draw_axis(
+            canvas,
+            cfg,
+            margin as i32,
+            (height - margin) as i32,
+            (width - margin) as i32,
+            (height - margin) as i32,
+        );
+        self.draw_label(
+            canvas,
+            cfg,
+            canvas.width - canvas.margin / 2,
+            canvas.height - canvas.margin,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, canvas.margin, canvas.margin / 2, &self.y_label);
+
+        self.draw_axis_units(canvas, &self.config);
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+
+        // Bar kinds are listed in a single row below the step labels
+        let mut x = canvas.margin;
+        let y = canvas.height - canvas.margin + square_size * 3;
+
+        for (kind, name) in self.legend_entries() {
+            let (w, _) = measure_text_with_fallbacks(
+                name,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(x + dx, y + dy, self.bar_color(kind));
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                x + square_size + padding,
+                y,
+                name,
+                self.config.color_axis,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+            x += square_size + padding + w + square_size;
+        }
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
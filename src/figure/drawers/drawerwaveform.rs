@@ -0,0 +1,243 @@
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::waveform::{format_mm_ss, Waveform},
+    utilities::{axistype::AxisType, linetype::LineType, scaling::tick_values},
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl Waveform {
+    /// Computes the time ticks as `(label, x-coordinate)` pairs.
+    ///
+    /// Times are shown as `m:ss`, with as many decimals as needed to tell ticks apart.
+    fn time_ticks(&self, area: (f64, f64, f64, f64)) -> Vec<(String, f64)> {
+        let (left, _, right, _) = area;
+        let duration = self.duration();
+        let num_ticks = self.config.num_axis_ticks.max(1);
+        let step = duration / num_ticks as f64;
+        let decimals = if step >= 1.0 || step <= 0.0 {
+            0
+        } else {
+            ((-step.log10()).ceil() as usize).min(3)
+        };
+
+        tick_values(0.0, duration, num_ticks)
+            .into_iter()
+            .map(|time| {
+                let label = self
+                    .config
+                    .tick_label(AxisType::AxisX, time, |v| format_mm_ss(v, decimals));
+                let x = left + time / duration.max(f64::EPSILON) * (right - left);
+                (label, x)
+            })
+            .collect()
+    }
+
+    /// Computes the amplitude ticks as `(value, y-coordinate)` pairs, symmetric around zero.
+    fn amplitude_ticks(&self, area: (f64, f64, f64, f64)) -> Vec<(f64, f64)> {
+        let peak = self.peak();
+        // An even number of intervals keeps zero on a tick
+        let num_ticks = (self.config.num_axis_ticks / 2).max(1) * 2;
+        tick_values(-peak, peak, num_ticks)
+            .into_iter()
+            .map(|value| (value, self.to_canvas_y(value, peak, area)))
+            .collect()
+    }
+}
+
+impl Drawer for Waveform {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let area = self.plot_area(width, height, margin);
+        let (left, top, right, bottom) = area;
+
+        // Zero line
+        let peak = self.peak();
+        let zero = self.to_canvas_y(0.0, peak, area);
+        svg_canvas.draw_line(
+            left,
+            zero,
+            right,
+            zero,
+            &self.rgb_to_svg_color(cfg.color_grid),
+            1.0,
+        );
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Envelope as a single polygon: the maxima left to right, then the minima back
+        let envelope = self.envelope((right - left).round() as usize);
+        if !envelope.is_empty() {
+            let maxima = envelope
+                .iter()
+                .enumerate()
+                .map(|(column, &(_, max))| (left + column as f64 + 0.5, max));
+            let minima = envelope
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(column, &(min, _))| (left + column as f64 + 0.5, min));
+            let points: Vec<String> = maxima
+                .chain(minima)
+                .map(|(x, amplitude)| {
+                    format!("{x:.2},{:.2}", self.to_canvas_y(amplitude, peak, area))
+                })
+                .collect();
+            let color = self.rgb_to_svg_color(self.color);
+            svg_canvas.elements.push(format!(
+                r#"<polygon points="{}" fill="{color}" stroke="{color}" stroke-width="1"/>"#,
+                points.join(" ")
+            ));
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Axes with time and amplitude ticks
+        svg_canvas.draw_line(left, bottom, right, bottom, "black", 2.0);
+        svg_canvas.draw_line(left, top, left, bottom, "black", 2.0);
+        for (label, x) in self.time_ticks(area) {
+            svg_canvas.draw_tick_label(
+                x,
+                bottom + font_size * 1.5,
+                &label,
+                font_size,
+                AxisType::AxisX,
+            );
+        }
+        for (value, y) in self.amplitude_ticks(area) {
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.2}"));
+            svg_canvas.draw_tick_label(
+                left - 5.0,
+                y + font_size * 0.3,
+                &label,
+                font_size,
+                AxisType::AxisY,
+            );
+        }
+
+        self.draw_svg_axis_units(svg_canvas, cfg, font_size);
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let area = self.plot_area(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (left, top, right, bottom) = (
+            area.0.round() as i32,
+            area.1.round() as i32,
+            area.2.round() as i32,
+            area.3.round() as i32,
+        );
+
+        // Zero line
+        let peak = self.peak();
+        let zero = self.to_canvas_y(0.0, peak, area).round() as i32;
+        canvas.draw_line(left, zero, right, zero, cfg.color_grid, LineType::Solid);
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Envelope: one vertical span per pixel column
+        for (column, (min, max)) in self
+            .envelope((right - left) as usize)
+            .into_iter()
+            .enumerate()
+        {
+            let x = left + column as i32;
+            let y_top = self.to_canvas_y(max, peak, area).round() as i32;
+            let y_bottom = self.to_canvas_y(min, peak, area).round() as i32;
+            canvas.draw_line(x, y_top, x, y_bottom, self.color, LineType::Solid);
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Axes with time and amplitude ticks
+        self.draw_axis(canvas, cfg, left, bottom, right, bottom);
+        self.draw_axis(canvas, cfg, left, top, left, bottom);
+        for (label, x) in self.time_ticks(area) {
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                x as u32,
+                bottom as u32,
+                &label,
+                AxisType::AxisX,
+            );
+        }
+        for (value, y) in self.amplitude_ticks(area) {
+            let label = cfg.tick_label(AxisType::AxisY, value, |v| format!("{v:.2}"));
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                (left as u32).saturating_sub(10),
+                y as u32,
+                &label,
+                AxisType::AxisY,
+            );
+        }
+
+        self.draw_axis_units(canvas, cfg);
+    }
+
+    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
+        // A waveform has a single series and no legend
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
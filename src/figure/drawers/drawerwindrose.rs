@@ -0,0 +1,296 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget, svgcanvas::SvgCanvas},
+    configuration::figureconfig::FigureConfig,
+    figuretypes::windrose::{WindRose, COMPASS_SPACE},
+    utilities::{linetype::LineType, textmetrics::measure_text_with_fallbacks},
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+/// The compass points labelled around the rose.
+const COMPASS_LABELS: [(&str, f64); 8] = [
+    ("N", 0.0),
+    ("NE", 45.0),
+    ("E", 90.0),
+    ("SE", 135.0),
+    ("S", 180.0),
+    ("SW", 225.0),
+    ("W", 270.0),
+    ("NW", 315.0),
+];
+
+impl WindRose {
+    /// Computes the frequency rings as their percentage and radius in pixels.
+    fn rings(&self, layout: (f64, f64, f64)) -> Vec<(f64, f64)> {
+        let rings = self.ring_values();
+        let outer = rings.last().copied().unwrap_or(1.0);
+        rings
+            .into_iter()
+            .map(|value| (value, value / outer * layout.2))
+            .collect()
+    }
+
+    /// Returns the bearing along which the ring labels are drawn: the edge between the first
+    /// two sectors, where the gap between the bars leaves room.
+    fn ring_label_bearing(&self) -> f64 {
+        self.sector_width() / 2.0
+    }
+}
+
+impl Drawer for WindRose {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        self.check_render_config(
+            svg_canvas.width,
+            svg_canvas.height,
+            svg_canvas.margin,
+            false,
+        );
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let margin = svg_canvas.margin as f64;
+        let font_size = 12.0;
+        let cfg = &self.config;
+
+        self.fill_svg_canvas_background(svg_canvas, cfg, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            margin / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        let layout = self.layout(width, height, margin);
+        let (center_x, center_y, radius) = layout;
+
+        // Frequency rings and compass spokes
+        let grid_color = self.rgb_to_svg_color(cfg.color_grid);
+        let rings = self.rings(layout);
+        for &(_, ring_radius) in &rings {
+            svg_canvas.elements.push(format!(
+                r#"<circle cx="{center_x:.2}" cy="{center_y:.2}" r="{ring_radius:.2}" fill="none" stroke="{grid_color}" stroke-width="1"/>"#
+            ));
+        }
+        for (_, bearing) in COMPASS_LABELS {
+            let (x, y) = self.to_canvas(bearing, radius, layout);
+            svg_canvas.draw_line(center_x, center_y, x, y, &grid_color, 1.0);
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Stacked bars, separated by thin background-colored outlines
+        let background = self.rgb_to_svg_color(cfg.color_background);
+        for (_, class, polygon) in self.wedges(layout) {
+            let points: Vec<String> = polygon
+                .iter()
+                .map(|(x, y)| format!("{x:.2},{y:.2}"))
+                .collect();
+            svg_canvas.elements.push(format!(
+                r#"<polygon points="{}" fill="{}" stroke="{}" stroke-width="0.5"/>"#,
+                points.join(" "),
+                self.rgb_to_svg_color(self.class_colors[class]),
+                background
+            ));
+        }
+
+        self.config
+            .run_after_data_hook(RenderTarget::Svg(svg_canvas));
+
+        // Ring and compass labels
+        let axis_color = self.rgb_to_svg_color(cfg.color_axis);
+        for (value, ring_radius) in rings {
+            let (x, y) = self.to_canvas(self.ring_label_bearing(), ring_radius, layout);
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="{}">{}</text>"#,
+                x + 3.0,
+                y - 3.0,
+                font_size * 0.9,
+                axis_color,
+                cfg.format.format_percent(value, 0)
+            ));
+        }
+        for (label, bearing) in COMPASS_LABELS {
+            let (x, y) = self.to_canvas(bearing, radius + COMPASS_SPACE / 2.0, layout);
+            svg_canvas.draw_text(x, y + font_size * 0.35, label, font_size, "black");
+        }
+
+        // Magnitude class legend in the top-left corner
+        let mut legend_y = margin;
+        for (class, &color) in self.class_colors.iter().enumerate() {
+            svg_canvas.draw_rect(
+                margin,
+                legend_y,
+                font_size,
+                font_size,
+                &self.rgb_to_svg_color(color),
+                "none",
+                0.0,
+                1.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{} {}</text>"#,
+                margin + font_size * 1.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                self.class_label(class),
+                self.unit
+            ));
+            legend_y += font_size * 1.5;
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.check_render_config(canvas.width, canvas.height, canvas.margin, true);
+
+        canvas.clear();
+
+        let cfg = &self.config;
+        canvas.set_transparent(cfg.transparent_background);
+        self.fill_background(canvas, cfg);
+
+        // Draw the title
+        self.draw_title(
+            canvas,
+            cfg,
+            canvas.width / 2,
+            canvas.margin / 2,
+            &self.title,
+        );
+
+        let layout = self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (center_x, center_y, radius) = layout;
+        let to_pixel = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+
+        // Frequency rings, drawn as polygons with a point every two degrees, and compass spokes
+        let rings = self.rings(layout);
+        for &(_, ring_radius) in &rings {
+            let points: Vec<(i32, i32)> = (0..=180)
+                .map(|step| to_pixel(self.to_canvas(step as f64 * 2.0, ring_radius, layout)))
+                .collect();
+            for pair in points.windows(2) {
+                canvas.draw_line(
+                    pair[0].0,
+                    pair[0].1,
+                    pair[1].0,
+                    pair[1].1,
+                    cfg.color_grid,
+                    LineType::Solid,
+                );
+            }
+        }
+        for (_, bearing) in COMPASS_LABELS {
+            let (x, y) = to_pixel(self.to_canvas(bearing, radius, layout));
+            canvas.draw_line(
+                center_x.round() as i32,
+                center_y.round() as i32,
+                x,
+                y,
+                cfg.color_grid,
+                LineType::Solid,
+            );
+        }
+
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Stacked bars
+        for (_, class, polygon) in self.wedges(layout) {
+            canvas.fill_polygon(&[polygon], self.class_colors[class]);
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        // Ring and compass labels
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let scale = PxScale {
+            x: cfg.font_size_axis,
+            y: cfg.font_size_axis,
+        };
+        for (value, ring_radius) in rings {
+            let (x, y) = self.to_canvas(self.ring_label_bearing(), ring_radius, layout);
+            canvas.draw_text_with_fallbacks(
+                (x + 3.0) as u32,
+                (y - 3.0 - cfg.font_size_axis as f64).max(0.0) as u32,
+                &cfg.format.format_percent(value, 0),
+                cfg.color_axis,
+                font_path,
+                &cfg.font_fallbacks,
+                scale,
+            );
+        }
+        for (label, bearing) in COMPASS_LABELS {
+            let (x, y) = self.to_canvas(bearing, radius + COMPASS_SPACE / 2.0, layout);
+            self.draw_label(canvas, cfg, x.max(0.0) as u32, y.max(0.0) as u32, label);
+        }
+
+        self.draw_legend(canvas);
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+
+        let square_size = font_size as u32; // Size of the colored square
+        let padding = square_size / 2; // Space between the square and text
+
+        // Magnitude classes are listed in the top-left corner
+        let x = canvas.margin;
+        let mut y = canvas.margin;
+
+        for (class, &color) in self.class_colors.iter().enumerate() {
+            let label = format!("{} {}", self.class_label(class), self.unit);
+            let (_, h) = measure_text_with_fallbacks(
+                &label,
+                font_path,
+                &self.config.font_fallbacks,
+                font_size,
+            );
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(x + dx, y + dy, color);
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                x + square_size + padding,
+                y,
+                &label,
+                self.config.color_axis,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+            y += square_size.max(h) + padding;
+        }
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+
+    fn get_figure_config_mut(&mut self) -> Option<&mut FigureConfig> {
+        Some(&mut self.config)
+    }
+}
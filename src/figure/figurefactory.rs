@@ -2,8 +2,19 @@ use super::{
     configuration::figureconfig::FigureConfig,
     drawers::drawer::Drawer,
     figuretypes::{
-        areachart::AreaChart, cartesiangraph::CartesianGraph, groupbarchart::GroupBarChart,
-        histogram::Histogram, piechart::PieChart, scattergraph::ScatterGraph,
+        areachart::AreaChart, boxplot::BoxPlot, bulletchart::BulletChart,
+        candlestickchart::CandlestickChart, cartesiangraph::CartesianGraph,
+        chorddiagram::ChordDiagram, contourplot::ContourPlot, correlationmatrix::CorrelationMatrix,
+        dendrogram::Dendrogram, densityplot::DensityPlot, funnelchart::FunnelChart,
+        ganttchart::GanttChart, gaugechart::GaugeChart, geoscatter::GeoScatter,
+        groupbarchart::GroupBarChart, heatmap::Heatmap, hist2d::Hist2D, histogram::Histogram,
+        marimekkochart::MarimekkoChart, parallelcoordinates::ParallelCoordinates,
+        piechart::PieChart, polargraph::PolarGraph, quiverplot::QuiverPlot, radarchart::RadarChart,
+        ridgelineplot::RidgelinePlot, sankeydiagram::SankeyDiagram, scattergraph::ScatterGraph,
+        spectrogram::Spectrogram, stemplot::StemPlot, streamgraph::Streamgraph,
+        stripplot::StripPlot, sunburstchart::SunburstChart, swarmplot::SwarmPlot,
+        ternaryplot::TernaryPlot, treemap::Treemap, violinplot::ViolinPlot,
+        waterfallchart::WaterfallChart, waveform::Waveform, windrose::WindRose,
     },
 };
 
@@ -23,6 +34,75 @@ pub enum FigureType {
     AreaChart,
     /// A histogram, which shows the frequency distribution of data.
     Histogram,
+    /// A strip plot, which shows the jittered raw observations of each category.
+    StripPlot,
+    /// A box plot, which summarizes each category by its quartiles, whiskers and outliers.
+    BoxPlot,
+    /// A violin plot, which shows the kernel density estimate of each category as a mirrored shape.
+    ViolinPlot,
+    /// A candlestick chart, which shows the open, high, low and close prices of consecutive periods.
+    CandlestickChart,
+    /// A beeswarm plot, which packs the raw observations of each category without overlap.
+    SwarmPlot,
+    /// A correlation matrix, which shows pairwise correlations of series as an annotated heatmap.
+    CorrelationMatrix,
+    /// A heatmap, which colors the cells of a matrix of values through a colormap.
+    Heatmap,
+    /// A Marimekko chart, which encodes values in both the width and height of stacked cells.
+    MarimekkoChart,
+    /// A geographic scatter plot, which projects longitude and latitude points onto a map.
+    GeoScatter,
+    /// A ternary plot, which shows three-component compositions inside a triangle.
+    TernaryPlot,
+    /// A wind rose, which shows directional observations as sector bars stacked by magnitude.
+    WindRose,
+    /// A radar chart, which plots one polygon per series over axes spread around a center.
+    RadarChart,
+    /// A polar graph, which plots (theta, r) series over grid circles and angular spokes.
+    PolarGraph,
+    /// A spectrogram, which shows signal magnitudes over time and frequency on a dB color scale.
+    Spectrogram,
+    /// An audio waveform, which draws long sample buffers as a per-pixel min/max envelope.
+    Waveform,
+    /// A contour plot, which draws iso-lines of a gridded field, optionally with filled bands.
+    ContourPlot,
+    /// A stem plot, which draws a line from the baseline to each value, topped by a marker.
+    StemPlot,
+    /// A waterfall chart, which breaks a running total down into increases and decreases.
+    WaterfallChart,
+    /// A Gantt chart, which draws task time spans as horizontal bars on a time axis.
+    GanttChart,
+    /// A treemap, which draws hierarchical values as nested rectangles sized by value.
+    Treemap,
+    /// A sunburst chart, which draws hierarchical values as concentric rings of segments.
+    SunburstChart,
+    /// A Sankey diagram, which draws flows between nodes as ribbons as wide as the flow.
+    SankeyDiagram,
+    /// A funnel chart, which draws ordered stages as segments narrowing with their values.
+    FunnelChart,
+    /// A density plot, which draws kernel density estimates of raw samples as smooth curves.
+    DensityPlot,
+    /// A 2D histogram, which bins large point clouds into cells colored by count.
+    Hist2D,
+    /// A quiver plot, which draws a vector field as arrows at their positions.
+    QuiverPlot,
+    /// A streamgraph, which stacks series over time as flowing layers around a baseline.
+    Streamgraph,
+    /// A parallel coordinates plot, which draws records as polylines across parallel axes.
+    ParallelCoordinates,
+    /// A ridgeline plot, which stacks the density curves of several sample sets as overlapping rows.
+    RidgelinePlot,
+    /// A dendrogram, which draws the merges of a hierarchical clustering as a tree over labelled leaves.
+    Dendrogram,
+    /// A chord diagram, which draws the flows of a square matrix as ribbons between arcs around a circle.
+    ChordDiagram,
+    /// A gauge chart, which shows a single value by a needle on a dial with colored zones.
+    GaugeChart,
+    /// A bullet chart, which shows a single measure as a bar over range bands with a comparative marker.
+    BulletChart,
+    /// A choropleth map, which fills geographic regions by value (requires the `geojson` feature).
+    #[cfg(feature = "geojson")]
+    ChoroplethMap,
 }
 
 /// Represents the output format for the generated plots.
@@ -97,6 +177,188 @@ impl FigureFactory {
                 [0, 0, 255],
                 FigureConfig::default(),
             )),
+            FigureType::StripPlot => Box::new(StripPlot::new(
+                "Strip Plot",
+                "Category",
+                "Value",
+                FigureConfig::default(),
+            )),
+            FigureType::BoxPlot => Box::new(BoxPlot::new(
+                "Box Plot",
+                "Category",
+                "Value",
+                FigureConfig::default(),
+            )),
+            FigureType::ViolinPlot => Box::new(ViolinPlot::new(
+                "Violin Plot",
+                "Category",
+                "Value",
+                FigureConfig::default(),
+            )),
+            FigureType::CandlestickChart => Box::new(CandlestickChart::new(
+                "Candlestick Chart",
+                "Time",
+                "Price",
+                FigureConfig::default(),
+            )),
+            FigureType::SwarmPlot => Box::new(SwarmPlot::new(
+                "Swarm Plot",
+                "Category",
+                "Value",
+                FigureConfig::default(),
+            )),
+            FigureType::CorrelationMatrix => Box::new(CorrelationMatrix::new(
+                "Correlation Matrix",
+                super::utilities::correlationmethod::CorrelationMethod::Pearson,
+                FigureConfig::default(),
+            )),
+            FigureType::Heatmap => Box::new(Heatmap::new(
+                "Heatmap",
+                "Column",
+                "Row",
+                FigureConfig::default(),
+            )),
+            FigureType::MarimekkoChart => Box::new(MarimekkoChart::new(
+                "Marimekko Chart",
+                "Category",
+                "Share",
+                FigureConfig::default(),
+            )),
+            FigureType::GeoScatter => Box::new(GeoScatter::new(
+                "Geo Scatter",
+                super::utilities::projection::Projection::Equirectangular,
+                FigureConfig::default(),
+            )),
+            FigureType::TernaryPlot => Box::new(TernaryPlot::new(
+                "Ternary Plot",
+                "A",
+                "B",
+                "C",
+                FigureConfig::default(),
+            )),
+            FigureType::WindRose => Box::new(WindRose::new(
+                "Wind Rose",
+                16,
+                &[0.0, 2.0, 4.0, 6.0, 8.0],
+                "m/s",
+                FigureConfig::default(),
+            )),
+            FigureType::RadarChart => Box::new(RadarChart::new(
+                "Radar Chart",
+                &["A", "B", "C", "D", "E"],
+                FigureConfig::default(),
+            )),
+            FigureType::PolarGraph => {
+                Box::new(PolarGraph::new("Polar Graph", FigureConfig::default()))
+            }
+            FigureType::Spectrogram => Box::new(Spectrogram::new(
+                "Spectrogram",
+                "Time (s)",
+                "Frequency (Hz)",
+                FigureConfig::default(),
+            )),
+            FigureType::Waveform => Box::new(Waveform::new(
+                "Waveform",
+                44100.0,
+                [40, 90, 160],
+                FigureConfig::default(),
+            )),
+            FigureType::ContourPlot => Box::new(ContourPlot::new(
+                "Contour Plot",
+                "X",
+                "Y",
+                FigureConfig::default(),
+            )),
+            FigureType::StemPlot => Box::new(StemPlot::new(
+                "Stem Plot",
+                "X",
+                "Y",
+                &FigureConfig::default(),
+            )),
+            FigureType::WaterfallChart => Box::new(WaterfallChart::new(
+                "Waterfall Chart",
+                "X",
+                "Y",
+                FigureConfig::default(),
+            )),
+            FigureType::GanttChart => Box::new(GanttChart::new(
+                "Gantt Chart",
+                "Time",
+                "Task",
+                FigureConfig::default(),
+            )),
+            FigureType::Treemap => Box::new(Treemap::new("Treemap", FigureConfig::default())),
+            FigureType::SunburstChart => Box::new(SunburstChart::new(
+                "Sunburst Chart",
+                FigureConfig::default(),
+            )),
+            FigureType::SankeyDiagram => Box::new(SankeyDiagram::new(
+                "Sankey Diagram",
+                FigureConfig::default(),
+            )),
+            FigureType::FunnelChart => {
+                Box::new(FunnelChart::new("Funnel Chart", FigureConfig::default()))
+            }
+            FigureType::DensityPlot => Box::new(DensityPlot::new(
+                "Density Plot",
+                "Value",
+                "Density",
+                FigureConfig::default(),
+            )),
+            FigureType::Hist2D => Box::new(Hist2D::new(
+                "2D Histogram",
+                "X",
+                "Y",
+                FigureConfig::default(),
+            )),
+            FigureType::QuiverPlot => Box::new(QuiverPlot::new(
+                "Quiver Plot",
+                "X",
+                "Y",
+                FigureConfig::default(),
+            )),
+            FigureType::Streamgraph => Box::new(Streamgraph::new(
+                "Streamgraph",
+                "X",
+                "Value",
+                FigureConfig::default(),
+            )),
+            FigureType::ParallelCoordinates => Box::new(ParallelCoordinates::new(
+                "Parallel Coordinates",
+                &["A", "B", "C", "D"],
+                FigureConfig::default(),
+            )),
+            FigureType::RidgelinePlot => Box::new(RidgelinePlot::new(
+                "Ridgeline Plot",
+                "Value",
+                FigureConfig::default(),
+            )),
+            FigureType::Dendrogram => Box::new(Dendrogram::new(
+                "Dendrogram",
+                "Distance",
+                super::utilities::orientation::Orientation::Vertical,
+                FigureConfig::default(),
+            )),
+            FigureType::ChordDiagram => {
+                Box::new(ChordDiagram::new("Chord Diagram", FigureConfig::default()))
+            }
+            FigureType::GaugeChart => {
+                Box::new(GaugeChart::new("Gauge Chart", FigureConfig::default()))
+            }
+            FigureType::BulletChart => Box::new(BulletChart::new(
+                "Bullet Chart",
+                "Measure",
+                super::utilities::orientation::Orientation::Horizontal,
+                FigureConfig::default(),
+            )),
+            #[cfg(feature = "geojson")]
+            FigureType::ChoroplethMap => {
+                Box::new(super::figuretypes::choroplethmap::ChoroplethMap::new(
+                    "Choropleth Map",
+                    super::utilities::projection::Projection::Equirectangular,
+                    FigureConfig::default(),
+                ))
+            }
         }
     }
 }
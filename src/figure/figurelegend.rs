@@ -0,0 +1,276 @@
+use ab_glyph::PxScale;
+
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    drawers::drawer::Drawer,
+    utilities::textmetrics::measure_text_with_fallbacks,
+};
+
+/// The legend entries of one figure, shown under a heading in a `FigureLegend`.
+pub struct LegendGroup {
+    /// Heading shown above the entries, e.g. the panel title or `"Right axis"`.
+    pub heading: String,
+    /// The entries as `(label, color)` pairs.
+    pub entries: Vec<(String, [u8; 3])>,
+}
+
+/// Position of a heading (`entry` is `None`) or an entry, relative to the legend's top-left
+/// corner.
+struct PlacedItem {
+    x: f64,
+    y: f64,
+    group: usize,
+    entry: Option<usize>,
+}
+
+/// One legend for the figures of a composed figure, such as linked panels or an overlay
+/// drawn against a secondary axis.
+///
+/// Each figure contributes a group of entries under its own heading, so the figures don't
+/// each draw a legend over one another. Groups are laid out top to bottom: the heading on
+/// a row of its own, then the entries, wrapped into as many rows as the width requires.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::figurelegend::FigureLegend;
+///
+/// let mut legend = FigureLegend::new(&config);
+/// legend.add_figure("Prices", &mut price_panel);
+/// legend.add_figure("Volume", &mut volume_panel);
+///
+/// let legend_height = legend.height(width as f64) as u32;
+/// let mut canvas = PixelCanvas::new(width, legend_height, [255, 255, 255], 0);
+/// legend.draw(&mut canvas, 0, 0, width);
+/// ```
+pub struct FigureLegend {
+    /// The groups of entries, in the order they are shown.
+    pub groups: Vec<LegendGroup>,
+    /// Configuration providing the fonts, the legend font size and the heading color.
+    pub config: FigureConfig,
+}
+
+impl FigureLegend {
+    /// Creates an empty legend.
+    ///
+    /// # Parameters
+    /// - `config`: Configuration providing the fonts, the legend font size and the heading
+    ///   color, usually the configuration of the composed figures.
+    pub fn new(config: &FigureConfig) -> Self {
+        Self {
+            groups: Vec::new(),
+            config: config.clone(),
+        }
+    }
+
+    /// Adds a group of entries under a heading.
+    ///
+    /// # Parameters
+    /// - `heading`: The heading shown above the entries.
+    /// - `entries`: The entries as `(label, color)` pairs.
+    pub fn add_group(&mut self, heading: &str, entries: Vec<(String, [u8; 3])>) {
+        self.groups.push(LegendGroup {
+            heading: heading.to_string(),
+            entries,
+        });
+    }
+
+    /// Adds the legend entries of a figure under a heading and turns off the figure's own
+    /// legend (`FigureConfig::show_legend`).
+    ///
+    /// Figures without a series legend add nothing. The figure's legend stays on if it does
+    /// not expose its configuration through `Drawer::get_figure_config_mut`.
+    ///
+    /// # Parameters
+    /// - `heading`: The heading shown above the figure's entries.
+    /// - `figure`: The figure whose entries are added.
+    pub fn add_figure(&mut self, heading: &str, figure: &mut dyn Drawer) {
+        let entries = figure.legend_entries();
+        if entries.is_empty() {
+            return;
+        }
+        if let Some(config) = figure.get_figure_config_mut() {
+            config.show_legend = false;
+        }
+        self.add_group(heading, entries);
+    }
+
+    /// Returns the height of one row of the legend.
+    fn line_height(&self) -> f64 {
+        self.config.font_size_legend as f64 * 2.0
+    }
+
+    /// Returns the width of a text at the legend font size, measured with the label font if
+    /// one is set and estimated otherwise.
+    fn text_width(&self, text: &str) -> f64 {
+        let font_size = self.config.font_size_legend;
+        match &self.config.font_label {
+            Some(font_path) => {
+                measure_text_with_fallbacks(text, font_path, &self.config.font_fallbacks, font_size)
+                    .0 as f64
+            }
+            None => text.chars().count() as f64 * font_size as f64 * 0.6,
+        }
+    }
+
+    /// Places the headings and entries in a legend of the given width.
+    ///
+    /// # Returns
+    /// The placed items and the height of the legend.
+    fn layout(&self, width: f64) -> (Vec<PlacedItem>, f64) {
+        let font_size = self.config.font_size_legend as f64;
+        let padding = font_size / 2.0;
+        let line_height = self.line_height();
+        let mut items = Vec::new();
+        let mut y = 0.0;
+
+        for (group_index, group) in self.groups.iter().enumerate() {
+            items.push(PlacedItem {
+                x: 0.0,
+                y,
+                group: group_index,
+                entry: None,
+            });
+            y += line_height;
+
+            let mut x = padding;
+            for (entry_index, (label, _)) in group.entries.iter().enumerate() {
+                let entry_width = font_size + padding + self.text_width(label) + padding * 2.0;
+                // Wrap to the next row, unless the entry is the first of its row
+                if x > padding && x + entry_width > width {
+                    x = padding;
+                    y += line_height;
+                }
+                items.push(PlacedItem {
+                    x,
+                    y,
+                    group: group_index,
+                    entry: Some(entry_index),
+                });
+                x += entry_width;
+            }
+            if !group.entries.is_empty() {
+                y += line_height;
+            }
+        }
+
+        (items, y)
+    }
+
+    /// Returns the height the legend needs at the given width.
+    ///
+    /// # Parameters
+    /// - `width`: The width available to the legend, in pixels.
+    pub fn height(&self, width: f64) -> f64 {
+        self.layout(width).1
+    }
+
+    /// Draws the legend on a `PixelCanvas`.
+    ///
+    /// # Parameters
+    /// - `canvas`: The canvas to draw on.
+    /// - `x`, `y`: Top-left corner of the legend.
+    /// - `width`: The width available to the legend; entries wrap to stay within it.
+    pub fn draw(&self, canvas: &mut PixelCanvas, x: u32, y: u32, width: u32) {
+        let font_path = self
+            .config
+            .font_label
+            .as_ref()
+            .expect("Font path is not set");
+        let font_size = self.config.font_size_legend;
+        let scale = PxScale {
+            x: font_size,
+            y: font_size,
+        };
+        let square_size = font_size as u32;
+
+        for item in self.layout(width as f64).0 {
+            let item_x = x + item.x as u32;
+            let item_y = y + item.y as u32;
+            let group = &self.groups[item.group];
+            let Some(entry) = item.entry else {
+                canvas.draw_text_with_fallbacks(
+                    item_x,
+                    item_y,
+                    &group.heading,
+                    self.config.color_axis,
+                    font_path,
+                    &self.config.font_fallbacks,
+                    scale,
+                );
+                continue;
+            };
+
+            let (label, color) = &group.entries[entry];
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(item_x + dx, item_y + dy, *color);
+                }
+            }
+            canvas.draw_text_with_fallbacks(
+                item_x + square_size + square_size / 2,
+                item_y,
+                label,
+                *color,
+                font_path,
+                &self.config.font_fallbacks,
+                scale,
+            );
+        }
+    }
+
+    /// Draws the legend on an `SvgCanvas`.
+    ///
+    /// Clicking an entry in the interactive HTML export toggles the series with the same
+    /// label, as in the figures' own legends.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The canvas to draw on.
+    /// - `x`, `y`: Top-left corner of the legend.
+    /// - `width`: The width available to the legend; entries wrap to stay within it.
+    pub fn draw_svg(&self, svg_canvas: &mut SvgCanvas, x: f64, y: f64, width: f64) {
+        let font_size = self.config.font_size_legend as f64;
+        let heading_color = format!(
+            "rgb({},{},{})",
+            self.config.color_axis[0], self.config.color_axis[1], self.config.color_axis[2]
+        );
+
+        for item in self.layout(width).0 {
+            let item_x = x + item.x;
+            let item_y = y + item.y;
+            let group = &self.groups[item.group];
+            let Some(entry) = item.entry else {
+                svg_canvas.elements.push(format!(
+                    r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" font-weight="bold" fill="{}">{}</text>"#,
+                    item_x,
+                    item_y + font_size - 2.0,
+                    font_size,
+                    heading_color,
+                    escape_xml(&group.heading)
+                ));
+                continue;
+            };
+
+            let (label, color) = &group.entries[entry];
+            let color = format!("rgb({},{},{})", color[0], color[1], color[2]);
+            svg_canvas
+                .elements
+                .push(SvgCanvas::legend_item_group(label));
+            svg_canvas.draw_rect(
+                item_x, item_y, font_size, font_size, &color, "none", 0.0, 1.0,
+            );
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="{}">{}</text>"#,
+                item_x + font_size * 1.5,
+                item_y + font_size - 2.0,
+                font_size,
+                color,
+                escape_xml(label)
+            ));
+            svg_canvas.end_group();
+        }
+    }
+}
@@ -0,0 +1,868 @@
+use crate::figure::{
+    datasets::{
+        areachartdataset::AreaChartDataset, bardataset::BarDataset, boxplotdataset::BoxPlotDataset,
+        candlestickdataset::CandlestickDataset, cartesiangraphdataset::CartesianDataset,
+        categorydataset::CategoryDataset, densityplotdataset::DensityPlotDataset,
+        radardataset::RadarDataset, scattergraphdataset::ScatterGraphDataset,
+        segmentdataset::SegmentDataset, ternarydataset::TernaryDataset,
+        violinplotdataset::ViolinPlotDataset,
+    },
+    figuretypes::{
+        areachart::AreaChart,
+        boxplot::BoxPlot,
+        bulletchart::BulletChart,
+        candlestickchart::CandlestickChart,
+        cartesiangraph::CartesianGraph,
+        chorddiagram::ChordDiagram,
+        contourplot::ContourPlot,
+        correlationmatrix::CorrelationMatrix,
+        dendrogram::Dendrogram,
+        densityplot::DensityPlot,
+        funnelchart::FunnelChart,
+        ganttchart::{GanttChart, GanttTask},
+        gaugechart::GaugeChart,
+        geoscatter::GeoScatter,
+        groupbarchart::GroupBarChart,
+        heatmap::Heatmap,
+        hist2d::Hist2D,
+        histogram::Histogram,
+        marimekkochart::MarimekkoChart,
+        parallelcoordinates::{ParallelCoordinates, ParallelRecord},
+        piechart::PieChart,
+        polargraph::PolarGraph,
+        quadrant1graph::Quadrant1Graph,
+        quiverplot::{QuiverPlot, QuiverVector},
+        radarchart::RadarChart,
+        ridgelineplot::RidgelinePlot,
+        sankeydiagram::SankeyDiagram,
+        scattergraph::ScatterGraph,
+        spectrogram::Spectrogram,
+        stemplot::StemPlot,
+        streamgraph::{StreamSeries, Streamgraph},
+        stripplot::StripPlot,
+        sunburstchart::SunburstChart,
+        swarmplot::SwarmPlot,
+        ternaryplot::TernaryPlot,
+        treemap::{Treemap, TreemapNode},
+        violinplot::ViolinPlot,
+        waterfallchart::WaterfallChart,
+        waveform::Waveform,
+        windrose::WindRose,
+    },
+    utilities::clustering,
+};
+
+/// A fully styled figure used as a template for figures that share styling but not data.
+///
+/// The template's title, axis labels, configuration and figure-specific settings are copied
+/// into every instance; only the data is replaced. Templates are typically built without
+/// data, but any data they hold is ignored.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::figuretemplate::FigureTemplate;
+///
+/// let template = CartesianGraph::new("Daily Load", "Hour", "Requests", &config);
+/// for (host, points) in hosts {
+///     let mut dataset = CartesianDataset::new([0, 90, 200], host, LineType::Solid);
+///     dataset.points = points;
+///     let mut figure = template.instantiate(vec![dataset]);
+///     figure.draw(&mut canvas);
+/// }
+/// ```
+pub trait FigureTemplate {
+    /// The data an instance is built from.
+    type Data;
+
+    /// Creates a new figure with the template's styling and the given data.
+    ///
+    /// # Parameters
+    /// - `data`: The data of the new figure.
+    ///
+    /// # Returns
+    /// A new figure that does not share any data with the template.
+    fn instantiate(&self, data: Self::Data) -> Self;
+}
+
+impl FigureTemplate for AreaChart {
+    type Data = Vec<AreaChartDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = AreaChart::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for CartesianGraph {
+    type Data = Vec<CartesianDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure =
+            CartesianGraph::new(&self.title, &self.x_label, &self.y_label, &self.config);
+        figure.x_autoscale = self.x_autoscale;
+        figure.y_autoscale = self.y_autoscale;
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for GroupBarChart {
+    type Data = Vec<BarDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = GroupBarChart::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.orientation.clone(),
+            self.config.clone(),
+        );
+        figure.stacking = self.stacking;
+        figure.diverging = self.diverging.clone();
+        figure.color_rule = self.color_rule.clone();
+        figure.rule_legend = self.rule_legend;
+        figure.value_labels = self.value_labels;
+        figure.rotate_value_labels = self.rotate_value_labels;
+        figure.value_axis_top = self.value_axis_top;
+        figure.value_limits = self.value_limits;
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for Histogram {
+    type Data = Vec<f64>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = Histogram::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.bins,
+            self.color,
+            self.config.clone(),
+        );
+        figure.normalization = self.normalization;
+        figure.label = self.label.clone();
+        figure.comparison = self.comparison;
+        figure.overlay_alpha = self.overlay_alpha;
+        figure.density_overlay = self.density_overlay.clone();
+        figure.overflow_bins = self.overflow_bins;
+        if let Some((min, max)) = self.range {
+            // The range was validated when it was set on the template
+            let _ = figure.set_range(min, max);
+        }
+        figure.add_data_vec(data);
+        figure
+    }
+}
+
+impl FigureTemplate for PieChart {
+    /// Slices as `(label, value, color)`.
+    type Data = Vec<(String, f64, [u8; 3])>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = PieChart::new(&self.title, self.config.clone());
+        figure.legend = self.legend;
+        figure.other = self.other.clone();
+        figure.inner_radius = self.inner_radius;
+        figure.center_text = self.center_text.clone();
+        for (label, value, color) in data {
+            figure.add_slice(&label, value, color);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for Quadrant1Graph {
+    type Data = Vec<CartesianDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = Quadrant1Graph::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for ScatterGraph {
+    type Data = Vec<ScatterGraphDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = ScatterGraph::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.thinning = self.thinning.clone();
+        figure.bubble_radius = self.bubble_radius;
+        figure.size_legend = self.size_legend;
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for StripPlot {
+    type Data = Vec<CategoryDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = StripPlot::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.set_jitter(self.jitter, self.seed);
+        figure.show_box = self.show_box;
+        figure.point_radius = self.point_radius;
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for BoxPlot {
+    type Data = Vec<BoxPlotDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = BoxPlot::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.box_width = self.box_width;
+        figure.outlier_radius = self.outlier_radius;
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for DensityPlot {
+    type Data = Vec<DensityPlotDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = DensityPlot::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.fill = self.fill;
+        figure.fill_opacity = self.fill_opacity;
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for Hist2D {
+    type Data = Vec<(f64, f64)>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = Hist2D::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.shape = self.shape;
+        figure.bins_x = self.bins_x;
+        figure.bins_y = self.bins_y;
+        figure.x_range = self.x_range;
+        figure.y_range = self.y_range;
+        figure.min_count = self.min_count;
+        figure.log_scale = self.log_scale;
+        figure.color_stops = self.color_stops.clone();
+        figure.add_points(&data);
+        figure
+    }
+}
+
+impl FigureTemplate for QuiverPlot {
+    type Data = Vec<QuiverVector>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = QuiverPlot::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.arrow_scale = self.arrow_scale;
+        figure.head_length = self.head_length;
+        figure.color = self.color;
+        figure.color_by_magnitude = self.color_by_magnitude;
+        figure.color_stops = self.color_stops.clone();
+        figure.vectors = data;
+        figure
+    }
+}
+
+impl FigureTemplate for Streamgraph {
+    /// The shared x values and the series.
+    type Data = (Vec<f64>, Vec<StreamSeries>);
+
+    fn instantiate(&self, (x_values, series): Self::Data) -> Self {
+        let mut figure = Streamgraph::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.baseline = self.baseline;
+        figure.smooth = self.smooth;
+        figure.palette = self.palette.clone();
+        figure.x_values = x_values;
+        figure.series = series;
+        figure
+    }
+}
+
+impl FigureTemplate for ParallelCoordinates {
+    type Data = Vec<ParallelRecord>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let axes: Vec<&str> = self.axes.iter().map(String::as_str).collect();
+        let mut figure = ParallelCoordinates::new(&self.title, &axes, self.config.clone());
+        figure.axis_ranges = self.axis_ranges.clone();
+        figure.category_colors = self.category_colors.clone();
+        figure.palette = self.palette.clone();
+        figure.color = self.color;
+        figure.line_alpha = self.line_alpha;
+        figure.records = data;
+        figure
+    }
+}
+
+impl FigureTemplate for RidgelinePlot {
+    type Data = Vec<DensityPlotDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = RidgelinePlot::new(&self.title, &self.x_label, self.config.clone());
+        figure.overlap = self.overlap;
+        figure.fill_opacity = self.fill_opacity;
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for ViolinPlot {
+    type Data = Vec<ViolinPlotDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = ViolinPlot::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.violin_width = self.violin_width;
+        figure.show_quartiles = self.show_quartiles;
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for CandlestickChart {
+    /// The candles. Overlays belong to the template's candles and are not copied; moving
+    /// averages and Bollinger bands are computed from the new candles.
+    type Data = CandlestickDataset;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = CandlestickChart::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.set_colors(self.up_color, self.down_color);
+        figure.hollow_up = self.hollow_up;
+        figure.body_width = self.body_width;
+        figure.moving_averages = self.moving_averages.clone();
+        figure.bollinger = self.bollinger;
+        figure.volume_height = self.volume_height;
+        figure.dataset = data;
+        figure
+    }
+}
+
+impl FigureTemplate for SwarmPlot {
+    type Data = Vec<CategoryDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = SwarmPlot::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.point_radius = self.point_radius;
+        figure.point_spacing = self.point_spacing;
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for CorrelationMatrix {
+    /// Series as `(label, values)`.
+    type Data = Vec<(String, Vec<f64>)>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = CorrelationMatrix::new(&self.title, self.method, self.config.clone());
+        figure.show_values = self.show_values;
+        figure.precision = self.precision;
+        for (label, values) in data {
+            figure.add_series(&label, &values);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for Heatmap {
+    /// Values indexed as `[row][column]`.
+    type Data = Vec<Vec<f64>>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = Heatmap::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.set_values(data, self.value_range);
+        figure.row_labels = self.row_labels.clone();
+        figure.column_labels = self.column_labels.clone();
+        figure.color_stops = self.color_stops.clone();
+        figure.show_values = self.show_values;
+        figure.precision = self.precision;
+        figure.clustering = self.clustering;
+        figure
+    }
+}
+
+impl FigureTemplate for ContourPlot {
+    /// Values indexed as `[row][column]`; the extent, levels and colors are kept.
+    type Data = Vec<Vec<f64>>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = ContourPlot::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.set_values(data);
+        figure.x_extent = self.x_extent;
+        figure.y_extent = self.y_extent;
+        figure.levels = self.levels.clone();
+        figure.num_levels = self.num_levels;
+        figure.filled = self.filled;
+        figure.color_stops = self.color_stops.clone();
+        figure.line_color = self.line_color;
+        figure.precision = self.precision;
+        figure
+    }
+}
+
+impl FigureTemplate for StemPlot {
+    type Data = Vec<CartesianDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let graph = &self.graph;
+        let mut figure = StemPlot::new(&graph.title, &graph.x_label, &graph.y_label, &graph.config);
+        figure.set_baseline(self.baseline);
+        figure.marker = self.marker.clone();
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for WaterfallChart {
+    /// Step labels and changes, or `None` for a running total bar.
+    type Data = Vec<(String, Option<f64>)>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = WaterfallChart::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.set_colors(self.increase_color, self.decrease_color, self.total_color);
+        figure.connector_color = self.connector_color;
+        figure.show_values = self.show_values;
+        figure.precision = self.precision;
+        for (label, value) in data {
+            match value {
+                Some(value) => figure.add_step(&label, value),
+                None => figure.add_total(&label),
+            }
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for GanttChart {
+    type Data = Vec<GanttTask>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = GanttChart::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.default_color = self.default_color;
+        figure.time_format = self.time_format.clone();
+        for task in data {
+            figure.add_task(task);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for Treemap {
+    /// Root nodes with their descendants.
+    type Data = Vec<TreemapNode>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = Treemap::new(&self.title, self.config.clone());
+        figure.header_height = self.header_height;
+        figure.show_labels = self.show_labels;
+        figure.precision = self.precision;
+        for node in data {
+            figure.add_root(node);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for Dendrogram {
+    /// Leaf labels and the clustering joining the leaves.
+    type Data = (Vec<String>, clustering::Dendrogram);
+
+    fn instantiate(&self, (labels, tree): Self::Data) -> Self {
+        let mut figure = Dendrogram::new(
+            &self.title,
+            &self.distance_label,
+            self.orientation.clone(),
+            self.config.clone(),
+        );
+        figure.color = self.color;
+        figure.labels = labels;
+        figure.tree = tree;
+        figure
+    }
+}
+
+impl FigureTemplate for SunburstChart {
+    /// Root nodes with their descendants.
+    type Data = Vec<TreemapNode>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = SunburstChart::new(&self.title, self.config.clone());
+        figure.inner_radius = self.inner_radius;
+        figure.show_labels = self.show_labels;
+        figure.precision = self.precision;
+        for node in data {
+            figure.add_root(node);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for SankeyDiagram {
+    /// Flows as `(source, target, value)`. Flows rejected by `add_link` are skipped.
+    type Data = Vec<(String, String, f64)>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = SankeyDiagram::new(&self.title, self.config.clone());
+        figure.node_width = self.node_width;
+        figure.node_padding = self.node_padding;
+        figure.link_opacity = self.link_opacity;
+        figure.show_labels = self.show_labels;
+        figure.precision = self.precision;
+        for (source, target, value) in data {
+            let _ = figure.add_link(&source, &target, value);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for ChordDiagram {
+    /// Row labels and the square flow matrix. A matrix rejected by `set_matrix` leaves the
+    /// diagram empty.
+    type Data = (Vec<String>, Vec<Vec<f64>>);
+
+    fn instantiate(&self, (labels, matrix): Self::Data) -> Self {
+        let mut figure = ChordDiagram::new(&self.title, self.config.clone());
+        figure.padding_angle = self.padding_angle;
+        figure.arc_width = self.arc_width;
+        figure.ribbon_opacity = self.ribbon_opacity;
+        figure.show_labels = self.show_labels;
+        figure.precision = self.precision;
+        let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+        let _ = figure.set_matrix(&labels, matrix);
+        figure
+    }
+}
+
+impl FigureTemplate for GaugeChart {
+    /// The value the needle points at. The range, units and zones are the template's.
+    type Data = f64;
+
+    fn instantiate(&self, value: Self::Data) -> Self {
+        let mut figure = GaugeChart::new(&self.title, self.config.clone());
+        figure.value = value;
+        figure.min = self.min;
+        figure.max = self.max;
+        figure.units = self.units.clone();
+        figure.zones = self.zones.clone();
+        figure.sweep_angle = self.sweep_angle;
+        figure.band_width = self.band_width;
+        figure.track_color = self.track_color;
+        figure.needle_color = self.needle_color;
+        figure.precision = self.precision;
+        figure
+    }
+}
+
+impl FigureTemplate for BulletChart {
+    /// The measure and the comparative value. The label, ranges and units are the
+    /// template's.
+    type Data = (f64, Option<f64>);
+
+    fn instantiate(&self, (value, target): Self::Data) -> Self {
+        let mut figure = BulletChart::new(
+            &self.title,
+            &self.label,
+            self.orientation.clone(),
+            self.config.clone(),
+        );
+        figure.value = value;
+        figure.target = target;
+        figure.min = self.min;
+        figure.ranges = self.ranges.clone();
+        figure.units = self.units.clone();
+        figure.measure_width = self.measure_width;
+        figure.measure_color = self.measure_color;
+        figure.target_color = self.target_color;
+        figure.precision = self.precision;
+        figure
+    }
+}
+
+impl FigureTemplate for FunnelChart {
+    /// Stages as `(label, value)`, from the top. Stages rejected by `add_stage` are skipped.
+    type Data = Vec<(String, f64)>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = FunnelChart::new(&self.title, self.config.clone());
+        figure.shape = self.shape;
+        figure.stage_gap = self.stage_gap;
+        figure.annotation_width = self.annotation_width;
+        figure.show_labels = self.show_labels;
+        figure.show_percentages = self.show_percentages;
+        figure.precision = self.precision;
+        for (label, value) in data {
+            let _ = figure.add_stage(&label, value);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for MarimekkoChart {
+    /// Category names and segments.
+    type Data = (Vec<String>, Vec<SegmentDataset>);
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = MarimekkoChart::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.show_labels = self.show_labels;
+        let (categories, datasets) = data;
+        for category in categories {
+            figure.add_category(&category);
+        }
+        for dataset in datasets {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for GeoScatter {
+    /// Point datasets with `(longitude, latitude)` points; the map layers are kept.
+    type Data = Vec<ScatterGraphDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = GeoScatter::new(&self.title, self.projection, self.config.clone());
+        figure.coastlines = self.coastlines.clone();
+        figure.color_coastline = self.color_coastline;
+        figure.basemap = self.basemap.clone();
+        figure.extent = self.extent;
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for TernaryPlot {
+    /// The datasets of compositions; the component names are kept.
+    type Data = Vec<TernaryDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = TernaryPlot::new(
+            &self.title,
+            &self.a_label,
+            &self.b_label,
+            &self.c_label,
+            self.config.clone(),
+        );
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for WindRose {
+    /// Observations as `(direction, magnitude)`; the sectors, classes and colors are kept.
+    type Data = Vec<(f64, f64)>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = WindRose::new(
+            &self.title,
+            self.num_sectors,
+            &self.class_edges,
+            &self.unit,
+            self.config.clone(),
+        );
+        figure.class_colors = self.class_colors.clone();
+        figure.add_observations(&data);
+        figure
+    }
+}
+
+impl FigureTemplate for RadarChart {
+    /// Series with one value per axis; the axes, scale and fill are kept.
+    type Data = Vec<RadarDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let axes: Vec<&str> = self.axes.iter().map(String::as_str).collect();
+        let mut figure = RadarChart::new(&self.title, &axes, self.config.clone());
+        figure.max_value = self.max_value;
+        figure.num_rings = self.num_rings;
+        figure.fill_alpha = self.fill_alpha;
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for PolarGraph {
+    /// Series of `(theta, r)` points; the scale, grid and angle unit are kept.
+    type Data = Vec<CartesianDataset>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = PolarGraph::new(&self.title, self.config.clone());
+        figure.r_max = self.r_max;
+        figure.num_rings = self.num_rings;
+        figure.num_spokes = self.num_spokes;
+        figure.angle_unit = self.angle_unit;
+        for dataset in data {
+            figure.add_dataset(dataset);
+        }
+        figure
+    }
+}
+
+impl FigureTemplate for Spectrogram {
+    /// Magnitudes indexed as `[time][frequency]`; the resolution and color scale are kept.
+    type Data = Vec<Vec<f64>>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = Spectrogram::new(
+            &self.title,
+            &self.x_label,
+            &self.y_label,
+            self.config.clone(),
+        );
+        figure.set_magnitudes(data, self.time_step, self.frequency_step);
+        figure.dynamic_range = self.dynamic_range;
+        figure.color_stops = self.color_stops.clone();
+        figure
+    }
+}
+
+impl FigureTemplate for Waveform {
+    /// The samples; the sample rate and color are kept.
+    type Data = Vec<f64>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = Waveform::new(
+            &self.title,
+            self.sample_rate,
+            self.color,
+            self.config.clone(),
+        );
+        figure.set_samples(data);
+        figure
+    }
+}
+
+#[cfg(feature = "geojson")]
+impl FigureTemplate for crate::figure::figuretypes::choroplethmap::ChoroplethMap {
+    /// Region values as `(region name, value)`; the regions themselves are kept.
+    type Data = Vec<(String, f64)>;
+
+    fn instantiate(&self, data: Self::Data) -> Self {
+        let mut figure = Self::new(&self.title, self.projection, self.config.clone());
+        figure.features = self.features.clone();
+        figure.color_stops = self.color_stops.clone();
+        figure.color_border = self.color_border;
+        for (name, value) in data {
+            figure.set_value(&name, value);
+        }
+        figure
+    }
+}
@@ -1,9 +1,16 @@
 use crate::figure::{
-    canvas::pixelcanvas::PixelCanvas, configuration::figureconfig::FigureConfig,
+    canvas::pixelcanvas::PixelCanvas,
+    configuration::figureconfig::FigureConfig,
     datasets::areachartdataset::AreaChartDataset,
+    utilities::{
+        areafill::fill_band,
+        scaling::{data_bounds, symmetric_range},
+        seriesvisibility::SeriesVisibility,
+    },
 };
 
 /// Represents an area chart, including its title, axis labels, datasets, and configuration.
+#[derive(Clone)]
 pub struct AreaChart {
     /// Title of the area chart.
     pub title: String,
@@ -75,10 +82,15 @@ impl AreaChart {
         self.update_range();
     }
 
+    /// Returns the datasets that are drawn, in order.
+    pub fn visible_datasets(&self) -> impl Iterator<Item = &AreaChartDataset> {
+        self.datasets.iter().filter(|d| d.visible)
+    }
+
     /// Draws the area under a dataset on the canvas.
     ///
-    /// This method fills the area under the dataset line, interpolating between points
-    /// and blending the pixels into the canvas.
+    /// This method fills the area between the dataset line and the x-axis, blending the
+    /// pixels into the canvas.
     ///
     /// # Parameters
     /// - `canvas`: The `PixelCanvas` on which to draw the area.
@@ -89,8 +101,8 @@ impl AreaChart {
     /// - `scale_y`: The scaling factor for converting Y-axis values to canvas coordinates.
     ///
     /// # Details
-    /// The method interpolates between adjacent points in the dataset to fill the area
-    /// under the line segment and blend it into the canvas using the dataset's color and transparency.
+    /// The points are sorted by x and the band between their line and the x-axis is filled
+    /// with `areafill::fill_band`, using the dataset's color and transparency.
     ///
     /// # Example
     /// ```rust,ignore
@@ -109,70 +121,53 @@ impl AreaChart {
     ) {
         let mut points = dataset.points.clone();
         points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        let (Some(first), Some(last)) = (points.first(), points.last()) else {
+            return;
+        };
 
-        for window in points.windows(2) {
-            if let [p1, p2] = window {
-                let x1 = origin_x + ((p1.0) * scale_x) as i32;
-                let y1 = origin_y - ((p1.1) * scale_y) as i32;
-                let x2 = origin_x + ((p2.0) * scale_x) as i32;
-                let y2 = origin_y - ((p2.1) * scale_y) as i32;
-
-                // Fill the area under the line
-                for x in x1.min(x2)..=x1.max(x2) {
-                    let interpolated_y =
-                        y1 + ((x - x1) as f64 * (y2 - y1) as f64 / (x2 - x1).abs() as f64) as i32;
-                    for y in interpolated_y..=origin_y {
-                        canvas.blend_pixel(x as u32, y as u32, dataset.color, dataset.alpha);
-                    }
-                }
-            }
-        }
+        let line: Vec<(f64, f64)> = points
+            .iter()
+            .map(|&(x, y)| (origin_x as f64 + x * scale_x, origin_y as f64 - y * scale_y))
+            .collect();
+        let axis = [
+            (origin_x as f64 + first.0 * scale_x, origin_y as f64),
+            (origin_x as f64 + last.0 * scale_x, origin_y as f64),
+        ];
+        fill_band(canvas, &line, &axis, dataset.color, dataset.alpha);
     }
 
     pub fn update_range(&mut self) {
-        for dataset in &self.datasets {
-            for &(x, y) in &dataset.points {
-                if x < self.x_min {
-                    self.x_min = x;
-                }
-                if x > self.x_max {
-                    self.x_max = x;
-                }
-                if y < self.y_min {
-                    self.y_min = y;
-                }
-                if y > self.y_max {
-                    self.y_max = y;
-                }
-            }
+        let points = self
+            .visible_datasets()
+            .flat_map(|d| d.points.iter().copied());
+        if let Some((x_min, x_max, y_min, y_max)) = data_bounds(points) {
+            self.x_min = self.x_min.min(x_min);
+            self.x_max = self.x_max.max(x_max);
+            self.y_min = self.y_min.min(y_min);
+            self.y_max = self.y_max.max(y_max);
         }
-        let mut is_empty = self.datasets.is_empty();
 
-        for dataset in &self.datasets {
-            if dataset.points.is_empty() {
-                is_empty = true;
-                break;
-            }
-        }
+        let is_empty = self.visible_datasets().next().is_none()
+            || self.visible_datasets().any(|d| d.points.is_empty());
 
         if !is_empty {
-            let abs_x_min = self.x_min.abs();
-            let abs_x_max = self.x_max.abs();
-
-            if abs_x_min > abs_x_max {
-                self.x_max = abs_x_min;
-            } else {
-                self.x_min = -abs_x_max;
-            }
+            (self.x_min, self.x_max) = symmetric_range(self.x_min, self.x_max);
+            (self.y_min, self.y_max) = symmetric_range(self.y_min, self.y_max);
+        }
+    }
+}
 
-            let abs_y_min = self.y_min.abs();
-            let abs_y_max = self.y_max.abs();
+impl SeriesVisibility for AreaChart {
+    fn series_visibility(&mut self) -> Vec<(&str, &mut bool)> {
+        self.datasets
+            .iter_mut()
+            .map(|dataset| (dataset.label.as_str(), &mut dataset.visible))
+            .collect()
+    }
 
-            if abs_y_min > abs_y_max {
-                self.y_max = abs_y_min;
-            } else {
-                self.y_min = -abs_y_max;
-            }
-        }
+    fn refit_ranges(&mut self) {
+        (self.x_min, self.x_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        (self.y_min, self.y_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        self.update_range();
     }
 }
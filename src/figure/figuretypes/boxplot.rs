@@ -0,0 +1,93 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig, datasets::boxplotdataset::BoxPlotDataset,
+    utilities::scaling::data_bounds,
+};
+
+/// Represents a box plot: the quartiles, Tukey whiskers and outliers of each category.
+#[derive(Clone)]
+pub struct BoxPlot {
+    /// Title of the box plot.
+    pub title: String,
+    /// Label for the X-axis (the category axis).
+    pub x_label: String,
+    /// Label for the Y-axis (the value axis).
+    pub y_label: String,
+    /// One dataset per category, drawn from left to right.
+    pub datasets: Vec<BoxPlotDataset>,
+    /// Width of the boxes as a fraction of the category width (`0.0` to `1.0`).
+    pub box_width: f64,
+    /// Radius of the outlier markers in pixels.
+    pub outlier_radius: u32,
+    /// Configuration settings for rendering the plot (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl BoxPlot {
+    /// Creates a new `BoxPlot` instance with the specified title, labels, and configuration.
+    ///
+    /// The plot starts with boxes half as wide as a category and 3 pixel outlier markers.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the box plot.
+    /// - `x_label`: The label for the category axis.
+    /// - `y_label`: The label for the value axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `BoxPlot` instance with no categories.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::boxplot::BoxPlot;
+    ///
+    /// let mut plot = BoxPlot::new("Reaction Times", "Group", "ms", FigureConfig::default());
+    /// plot.box_width = 0.6;
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            datasets: Vec::new(),
+            box_width: 0.5,
+            outlier_radius: 3,
+            config,
+        }
+    }
+
+    /// Adds a category to the box plot.
+    ///
+    /// # Parameters
+    /// - `dataset`: The `BoxPlotDataset` holding the category's samples or summary.
+    pub fn add_dataset(&mut self, dataset: BoxPlotDataset) {
+        self.datasets.push(dataset);
+    }
+
+    /// Computes the value range of the plot, covering all whiskers and outliers, padded by
+    /// 5% on both sides.
+    ///
+    /// # Returns
+    /// A tuple `(min, max)`. Defaults to `(0.0, 1.0)` when there are no boxes.
+    pub fn value_range(&self) -> (f64, f64) {
+        let values = self.datasets.iter().flat_map(|dataset| {
+            let whiskers = dataset
+                .box_stats()
+                .map(|stats| [stats.lower_whisker, stats.upper_whisker])
+                .into_iter()
+                .flatten();
+            whiskers
+                .chain(dataset.outlier_values())
+                .filter(|value| value.is_finite())
+                .map(|value| (0.0, value))
+        });
+        match data_bounds(values) {
+            None => (0.0, 1.0),
+            Some((_, _, min, max)) if min == max => (min - 1.0, max + 1.0),
+            Some((_, _, min, max)) => {
+                let padding = (max - min) * 0.05;
+                (min - padding, max + padding)
+            }
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig, utilities::orientation::Orientation,
+};
+
+/// A qualitative range of a bullet chart, e.g. "Poor" or "Good", drawn as a band behind
+/// the measure.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BulletRange {
+    /// Name of the range, shown in tooltips.
+    pub label: String,
+    /// The end of the range; it starts where the previous range ends.
+    pub to: f64,
+    /// The RGB color of the band.
+    pub color: [u8; 3],
+}
+
+/// Placement of a bullet on a canvas.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BulletLayout {
+    /// Canvas coordinate of the start of the scale along the value axis.
+    pub start: f64,
+    /// Canvas coordinate of the end of the scale along the value axis.
+    pub end: f64,
+    /// Canvas coordinates of the two edges of the bands across the value axis.
+    pub across: (f64, f64),
+}
+
+/// Represents a bullet chart: a single measure drawn as a bar over qualitative range
+/// bands, with a marker at a comparative value such as a target or last year's result.
+///
+/// It shows the same information as a gauge in a compact strip. With
+/// `Orientation::Horizontal` the scale runs from left to right with the label on the left;
+/// with `Orientation::Vertical` it runs from bottom to top with the label below. The
+/// bullet fills the area inside the margins, so canvases are usually wide and flat
+/// (or narrow and tall).
+#[derive(Clone)]
+pub struct BulletChart {
+    /// Title of the chart.
+    pub title: String,
+    /// Label of the measure, drawn next to the bullet.
+    pub label: String,
+    /// The measure drawn as the bar.
+    pub value: f64,
+    /// The comparative value drawn as the marker, or `None` for no marker.
+    pub target: Option<f64>,
+    /// The start of the scale, where the first range and the bar begin.
+    pub min: f64,
+    /// The qualitative ranges, in ascending order.
+    pub ranges: Vec<BulletRange>,
+    /// Units appended to values in tooltips, e.g. `"%"`.
+    pub units: String,
+    /// Whether the scale runs horizontally or vertically.
+    pub orientation: Orientation,
+    /// Thickness of the bar as a fraction of the thickness of the bands.
+    pub measure_width: f64,
+    /// Color of the bar.
+    pub measure_color: [u8; 3],
+    /// Color of the comparative marker.
+    pub target_color: [u8; 3],
+    /// The number of decimal places of values in tooltips.
+    pub precision: usize,
+    /// Configuration settings for rendering the chart (e.g., colors, fonts).
+    pub config: FigureConfig,
+}
+
+impl BulletChart {
+    /// Shades of gray for up to three ranges, darkest for the poorest.
+    pub const GRAYS: [[u8; 3]; 3] = [[160, 160, 160], [195, 195, 195], [225, 225, 225]];
+
+    /// Length of the comparative marker as a fraction of the thickness of the bands.
+    const TARGET_LENGTH: f64 = 0.7;
+
+    /// Creates a new `BulletChart` instance with the specified title, label and
+    /// orientation.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the chart.
+    /// - `label`: The label of the measure.
+    /// - `orientation`: `Orientation::Horizontal` for a scale from left to right,
+    ///   `Orientation::Vertical` for a scale from bottom to top.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `BulletChart` instance with a measure of 0 on a scale from 0, without ranges
+    /// or marker.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::{figuretypes::bulletchart::BulletChart, utilities::orientation::Orientation};
+    ///
+    /// let mut bullet = BulletChart::new("Q3", "Revenue", Orientation::Horizontal, config);
+    /// bullet.set_units("k$");
+    /// bullet.add_range("Poor", 150.0, BulletChart::GRAYS[0])?;
+    /// bullet.add_range("Satisfactory", 225.0, BulletChart::GRAYS[1])?;
+    /// bullet.add_range("Good", 300.0, BulletChart::GRAYS[2])?;
+    /// bullet.set_value(270.0);
+    /// bullet.set_target(250.0);
+    /// ```
+    pub fn new(title: &str, label: &str, orientation: Orientation, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            label: label.to_string(),
+            value: 0.0,
+            target: None,
+            min: 0.0,
+            ranges: Vec::new(),
+            units: String::new(),
+            orientation,
+            measure_width: 0.35,
+            measure_color: [50, 50, 50],
+            target_color: [0, 0, 0],
+            precision: 1,
+            config,
+        }
+    }
+
+    /// Sets the measure drawn as the bar.
+    pub fn set_value(&mut self, value: f64) {
+        self.value = value;
+    }
+
+    /// Sets the comparative value drawn as the marker.
+    pub fn set_target(&mut self, target: f64) {
+        self.target = Some(target);
+    }
+
+    /// Sets the units appended to values.
+    pub fn set_units(&mut self, units: &str) {
+        self.units = units.to_string();
+    }
+
+    /// Adds a qualitative range, from the end of the previous range (or `min`) to `to`.
+    ///
+    /// # Parameters
+    /// - `label`: The name of the range.
+    /// - `to`: The end of the range.
+    /// - `color`: The RGB color of the band, e.g. one of `GRAYS`.
+    ///
+    /// # Errors
+    /// Returns an error if `to` is not finite or does not lie above the end of the previous
+    /// range.
+    pub fn add_range(&mut self, label: &str, to: f64, color: [u8; 3]) -> Result<(), String> {
+        let from = self.ranges.last().map_or(self.min, |range| range.to);
+        if !(to.is_finite() && to > from) {
+            return Err(format!(
+                "Range '{label}' must end above {from}, but ends at {to}"
+            ));
+        }
+        self.ranges.push(BulletRange {
+            label: label.to_string(),
+            to,
+            color,
+        });
+        Ok(())
+    }
+
+    /// Computes the range of the scale: from `min` to the end of the last range, widened
+    /// to include the measure and the marker.
+    ///
+    /// # Returns
+    /// A tuple `(min, max)` with `min < max`.
+    pub fn scale_range(&self) -> (f64, f64) {
+        let values = [Some(self.value), self.target]
+            .into_iter()
+            .flatten()
+            .chain(self.ranges.iter().map(|range| range.to))
+            .filter(|value| value.is_finite());
+        let (min, max) = values.fold((self.min, self.min), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+        if min < max {
+            (min, max)
+        } else {
+            (min, min + 1.0)
+        }
+    }
+
+    /// Returns the ranges with their starts, as `(from, to, range)`.
+    pub fn range_spans(&self) -> Vec<(f64, f64, &BulletRange)> {
+        let mut from = self.min;
+        self.ranges
+            .iter()
+            .map(|range| {
+                let span = (from, range.to, range);
+                from = range.to;
+                span
+            })
+            .collect()
+    }
+
+    /// Places the bullet on a canvas: the area inside the margins.
+    ///
+    /// # Parameters
+    /// - `width`, `height`, `margin`: The size and margin of the canvas in pixels.
+    pub fn layout(&self, width: f64, height: f64, margin: f64) -> BulletLayout {
+        let (left, top) = (margin, margin);
+        let (right, bottom) = ((width - margin).max(left), (height - margin).max(top));
+        match self.orientation {
+            Orientation::Horizontal => BulletLayout {
+                start: left,
+                end: right,
+                across: (top, bottom),
+            },
+            Orientation::Vertical => BulletLayout {
+                start: bottom,
+                end: top,
+                across: (left, right),
+            },
+        }
+    }
+
+    /// Maps a value to its canvas coordinate along the value axis, clamping it to the
+    /// scale.
+    pub fn value_position(&self, value: f64, layout: &BulletLayout) -> f64 {
+        let (min, max) = self.scale_range();
+        let t = ((value - min) / (max - min)).clamp(0.0, 1.0);
+        layout.start + t * (layout.end - layout.start)
+    }
+
+    /// Computes the rectangle covering a span of values, centered across the bands.
+    ///
+    /// # Parameters
+    /// - `from`, `to`: The span of values.
+    /// - `thickness`: The thickness as a fraction of the thickness of the bands.
+    /// - `layout`: The placement of the bullet.
+    ///
+    /// # Returns
+    /// The rectangle as `(x, y, width, height)` in canvas coordinates.
+    pub fn span_rect(
+        &self,
+        from: f64,
+        to: f64,
+        thickness: f64,
+        layout: &BulletLayout,
+    ) -> (f64, f64, f64, f64) {
+        let (a, b) = (
+            self.value_position(from, layout),
+            self.value_position(to, layout),
+        );
+        let (c0, c1) = layout.across;
+        let inset = (c1 - c0) * (1.0 - thickness.clamp(0.0, 1.0)) / 2.0;
+        let (low, high) = (a.min(b), a.max(b));
+        match self.orientation {
+            Orientation::Horizontal => (low, c0 + inset, high - low, c1 - c0 - 2.0 * inset),
+            Orientation::Vertical => (c0 + inset, low, c1 - c0 - 2.0 * inset, high - low),
+        }
+    }
+
+    /// Computes the rectangle of the measure bar, from the start of the scale to the
+    /// value.
+    pub fn measure_rect(&self, layout: &BulletLayout) -> (f64, f64, f64, f64) {
+        self.span_rect(self.min, self.value, self.measure_width, layout)
+    }
+
+    /// Computes the rectangle of the comparative marker: a short line across the bands.
+    ///
+    /// # Returns
+    /// The rectangle as `(x, y, width, height)`, or `None` without a target.
+    pub fn target_rect(&self, layout: &BulletLayout) -> Option<(f64, f64, f64, f64)> {
+        let target = self.target?;
+        let position = self.value_position(target, layout);
+        let (c0, c1) = layout.across;
+        let half = ((c1 - c0) * 0.02).max(1.5);
+        let (x, y, w, h) = self.span_rect(target, target, Self::TARGET_LENGTH, layout);
+        Some(match self.orientation {
+            Orientation::Horizontal => (position - half, y, 2.0 * half, h),
+            Orientation::Vertical => (x, position - half, w, 2.0 * half),
+        })
+    }
+
+    /// Formats a value with the chart's precision and units, in the format context of its
+    /// configuration, matching the localized tick labels.
+    pub fn format_value(&self, value: f64) -> String {
+        self.config
+            .format
+            .format_with_units(value, self.precision, &self.units)
+    }
+
+    /// Returns the tooltip of a range.
+    pub fn range_tooltip(&self, from: f64, to: f64, range: &BulletRange) -> String {
+        format!(
+            "{}: {} to {}",
+            range.label,
+            self.format_value(from),
+            self.format_value(to)
+        )
+    }
+
+    /// Returns the tooltip of the comparative marker.
+    pub fn target_tooltip(&self) -> Option<String> {
+        self.target
+            .map(|target| format!("Target: {}", self.format_value(target)))
+    }
+
+    /// Finds the element of the bullet at a position: the marker, the bar, or a range.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The position in canvas coordinates.
+    /// - `layout`: The placement of the bullet.
+    ///
+    /// # Returns
+    /// The tooltip of the element and its center, or `None` if the position is empty.
+    pub fn element_at(
+        &self,
+        x: f64,
+        y: f64,
+        layout: &BulletLayout,
+    ) -> Option<((f64, f64), String)> {
+        let contains = |(rx, ry, w, h): (f64, f64, f64, f64)| {
+            (rx..=rx + w).contains(&x) && (ry..=ry + h).contains(&y)
+        };
+        let center = |(rx, ry, w, h): (f64, f64, f64, f64)| (rx + w / 2.0, ry + h / 2.0);
+
+        if let Some(rect) = self.target_rect(layout).filter(|&rect| contains(rect)) {
+            return Some((center(rect), self.target_tooltip()?));
+        }
+        let measure = self.measure_rect(layout);
+        if contains(measure) {
+            let tooltip = format!("{}: {}", self.label, self.format_value(self.value));
+            return Some((center(measure), tooltip));
+        }
+        self.range_spans()
+            .into_iter()
+            .rev()
+            .map(|(from, to, range)| (self.span_rect(from, to, 1.0, layout), from, to, range))
+            .find(|&(rect, ..)| contains(rect))
+            .map(|(rect, from, to, range)| (center(rect), self.range_tooltip(from, to, range)))
+    }
+}
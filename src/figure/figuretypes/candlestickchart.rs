@@ -0,0 +1,387 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    datasets::candlestickdataset::CandlestickDataset,
+    utilities::{
+        axistype::AxisType,
+        indicators::{bollinger, BollingerPoint, MovingAverage},
+        scaling::data_bounds,
+        tickprecision::TickPrecision,
+    },
+};
+
+/// Gap in pixels between the price panel and the volume panel.
+const PANEL_GAP: f64 = 10.0;
+
+/// A line drawn over the candles, such as a moving average from `utilities::indicators`.
+#[derive(Clone)]
+pub struct CandlestickOverlay {
+    /// Name of the overlay, shown in the legend.
+    pub label: String,
+    /// One value per candle, `None` where the line is interrupted.
+    pub values: Vec<Option<f64>>,
+    /// Line color in RGB format.
+    pub color: [u8; 3],
+}
+
+/// Bollinger bands computed from the closing prices, drawn as a translucent ribbon behind
+/// the candles with the moving average through its middle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BollingerBands {
+    /// The number of closes in the window.
+    pub period: usize,
+    /// The width of the bands in standard deviations.
+    pub k: f64,
+    /// Color of the ribbon and its middle line in RGB format.
+    pub color: [u8; 3],
+}
+
+/// Represents a candlestick chart: the open, high, low and close prices of consecutive
+/// periods, drawn as wicks spanning the range and bodies spanning open to close.
+///
+/// Rising candles (close at or above open) use `up_color`, falling candles `down_color`.
+/// Moving averages and Bollinger bands of the closing prices can be drawn over the candles,
+/// and the volumes of the dataset in a panel below them.
+#[derive(Clone)]
+pub struct CandlestickChart {
+    /// Title of the chart.
+    pub title: String,
+    /// Label for the X-axis (time).
+    pub x_label: String,
+    /// Label for the Y-axis (price).
+    pub y_label: String,
+    /// The candles to draw.
+    pub dataset: CandlestickDataset,
+    /// Color of rising candles in RGB format.
+    pub up_color: [u8; 3],
+    /// Color of falling candles in RGB format.
+    pub down_color: [u8; 3],
+    /// Whether rising candles are drawn with hollow bodies, as in traditional charts.
+    pub hollow_up: bool,
+    /// Width of the bodies as a fraction of the smallest spacing between candles
+    /// (`0.0` to `1.0`).
+    pub body_width: f64,
+    /// Lines drawn over the candles.
+    pub overlays: Vec<CandlestickOverlay>,
+    /// Moving averages of the closing prices drawn over the candles, with their colors.
+    pub moving_averages: Vec<(MovingAverage, [u8; 3])>,
+    /// Bollinger bands drawn behind the candles, if any.
+    pub bollinger: Option<BollingerBands>,
+    /// Height of the volume panel as a fraction of the plot area (`0.0` to `0.9`). The
+    /// panel is only drawn when the dataset has volumes.
+    pub volume_height: f64,
+    /// Configuration settings for rendering the chart (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl CandlestickChart {
+    /// Creates a new `CandlestickChart` instance with the specified title, labels, and
+    /// configuration.
+    ///
+    /// Rising candles are green and falling candles red, both with filled bodies 70% as wide
+    /// as the spacing between candles.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the chart.
+    /// - `x_label`: The label for the time axis.
+    /// - `y_label`: The label for the price axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `CandlestickChart` instance without candles.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::candlestickchart::CandlestickChart;
+    /// use dataviz::figure::utilities::indicators::sma;
+    ///
+    /// let mut chart = CandlestickChart::new("ACME", "Day", "USD", config);
+    /// chart.add_overlay("SMA 20", sma(&prices.closes(), 20), [0, 90, 200]);
+    /// chart.dataset = prices;
+    /// chart.hollow_up = true;
+    /// ```
+    ///
+    /// Built-in indicators are computed from the dataset when the chart is drawn:
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::indicators::MovingAverage;
+    ///
+    /// chart.add_moving_average(MovingAverage::Exponential(12), [230, 120, 0]);
+    /// chart.set_bollinger(20, 2.0, [90, 90, 200]);
+    /// chart.dataset.set_volumes(volumes);
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            dataset: CandlestickDataset::new(title),
+            up_color: [38, 166, 91],
+            down_color: [214, 39, 40],
+            hollow_up: false,
+            body_width: 0.7,
+            overlays: Vec::new(),
+            moving_averages: Vec::new(),
+            bollinger: None,
+            volume_height: 0.2,
+            config,
+        }
+    }
+
+    /// Sets the colors of rising and falling candles.
+    ///
+    /// # Parameters
+    /// - `up`: The RGB color of candles closing at or above their open.
+    /// - `down`: The RGB color of candles closing below their open.
+    pub fn set_colors(&mut self, up: [u8; 3], down: [u8; 3]) {
+        self.up_color = up;
+        self.down_color = down;
+    }
+
+    /// Adds a line drawn over the candles.
+    ///
+    /// # Parameters
+    /// - `label`: The name of the overlay, shown in the legend.
+    /// - `values`: One value per candle, in candle order; `None` interrupts the line.
+    /// - `color`: The RGB line color.
+    pub fn add_overlay(&mut self, label: &str, values: Vec<Option<f64>>, color: [u8; 3]) {
+        self.overlays.push(CandlestickOverlay {
+            label: label.to_string(),
+            values,
+            color,
+        });
+    }
+
+    /// Adds a moving average of the closing prices, computed when the chart is drawn.
+    ///
+    /// # Parameters
+    /// - `average`: The kind and period of the average, e.g. `MovingAverage::Simple(20)`.
+    /// - `color`: The RGB line color.
+    pub fn add_moving_average(&mut self, average: MovingAverage, color: [u8; 3]) {
+        self.moving_averages.push((average, color));
+    }
+
+    /// Draws Bollinger bands of the closing prices behind the candles.
+    ///
+    /// # Parameters
+    /// - `period`: The number of closes in the window, commonly 20.
+    /// - `k`: The band width in standard deviations, commonly 2.
+    /// - `color`: The RGB color of the ribbon and its middle line.
+    pub fn set_bollinger(&mut self, period: usize, k: f64, color: [u8; 3]) {
+        self.bollinger = Some(BollingerBands { period, k, color });
+    }
+
+    /// Computes the lines drawn over the candles: the moving averages, the middle of the
+    /// Bollinger bands and the overlays added with `add_overlay`, in legend order.
+    pub fn line_overlays(&self) -> Vec<CandlestickOverlay> {
+        let closes = self.dataset.closes();
+        let averages = self
+            .moving_averages
+            .iter()
+            .map(|&(average, color)| CandlestickOverlay {
+                label: average.label(),
+                values: average.compute(&closes),
+                color,
+            });
+        let middle = self.bollinger.map(|bands| CandlestickOverlay {
+            label: format!("BB {}", bands.period),
+            values: self
+                .bollinger_points()
+                .iter()
+                .map(|point| point.map(|point| point.middle))
+                .collect(),
+            color: bands.color,
+        });
+        averages
+            .chain(middle)
+            .chain(self.overlays.iter().cloned())
+            .collect()
+    }
+
+    /// Computes the Bollinger bands of the closing prices.
+    ///
+    /// # Returns
+    /// One entry per candle, `None` until the window is filled or where it holds a
+    /// non-finite close; empty without bands.
+    pub fn bollinger_points(&self) -> Vec<Option<BollingerPoint>> {
+        let Some(bands) = self.bollinger else {
+            return Vec::new();
+        };
+        bollinger(&self.dataset.closes(), bands.period, bands.k)
+            .into_iter()
+            .map(|point| point.filter(|point| point.lower.is_finite() && point.upper.is_finite()))
+            .collect()
+    }
+
+    /// Splits the plot area between the price panel and, when the dataset has volumes,
+    /// the volume panel below it.
+    ///
+    /// # Parameters
+    /// - `height`: The canvas height in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// The `(top, bottom)` of the price panel, and of the volume panel if there is one.
+    pub fn panels(&self, height: f64, margin: f64) -> ((f64, f64), Option<(f64, f64)>) {
+        let (top, bottom) = (margin, (height - margin).max(margin + 1.0));
+        if self.dataset.volumes.is_empty() {
+            return ((top, bottom), None);
+        }
+        let volume = (bottom - top) * self.volume_height.clamp(0.0, 0.9);
+        let price_bottom = (bottom - volume - PANEL_GAP).max(top + 1.0);
+        (
+            (top, price_bottom),
+            Some(((price_bottom + PANEL_GAP).min(bottom), bottom)),
+        )
+    }
+
+    /// Returns the largest finite volume, the top of the volume panel.
+    ///
+    /// # Returns
+    /// The largest volume, or `1.0` without positive volumes.
+    pub fn max_volume(&self) -> f64 {
+        let max = self
+            .dataset
+            .volumes
+            .iter()
+            .copied()
+            .filter(|volume| volume.is_finite())
+            .fold(0.0, f64::max);
+        if max > 0.0 {
+            max
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns the color of a candle.
+    ///
+    /// # Parameters
+    /// - `open`, `close`: The opening and closing prices of the candle.
+    pub fn candle_color(&self, open: f64, close: f64) -> [u8; 3] {
+        if close >= open {
+            self.up_color
+        } else {
+            self.down_color
+        }
+    }
+
+    /// Returns the smallest distance between two consecutive timestamps, so gaps such as
+    /// weekends do not shrink the candles.
+    ///
+    /// # Returns
+    /// The spacing, or `1.0` with fewer than two distinct timestamps.
+    pub fn candle_spacing(&self) -> f64 {
+        let mut timestamps: Vec<f64> = self
+            .dataset
+            .candles
+            .iter()
+            .map(|candle| candle.0)
+            .filter(|timestamp| timestamp.is_finite())
+            .collect();
+        timestamps.sort_by(f64::total_cmp);
+        timestamps
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .filter(|&gap| gap > 0.0)
+            .fold(None, |min: Option<f64>, gap| {
+                Some(min.map_or(gap, |min| min.min(gap)))
+            })
+            .unwrap_or(1.0)
+    }
+
+    /// Computes the time range of the chart, extended by half a candle spacing on both
+    /// sides so the first and last bodies fit.
+    ///
+    /// # Returns
+    /// A tuple `(min, max)`. Defaults to `(0.0, 1.0)` without candles.
+    pub fn time_range(&self) -> (f64, f64) {
+        let half = self.candle_spacing() / 2.0;
+        let points = self
+            .dataset
+            .candles
+            .iter()
+            .map(|candle| (candle.0, 0.0))
+            .filter(|(timestamp, _)| timestamp.is_finite());
+        match data_bounds(points) {
+            None => (0.0, 1.0),
+            Some((min, max, _, _)) => (min - half, max + half),
+        }
+    }
+
+    /// Computes the price range of the chart, covering all wicks, overlays and Bollinger
+    /// bands, padded by 5% on both sides.
+    ///
+    /// # Returns
+    /// A tuple `(min, max)`. Defaults to `(0.0, 1.0)` without candles.
+    pub fn value_range(&self) -> (f64, f64) {
+        let overlays = self.line_overlays();
+        let bands = self.bollinger_points();
+        let prices = self
+            .dataset
+            .candles
+            .iter()
+            .flat_map(|&(_, _, high, low, _)| [high, low])
+            .chain(
+                overlays
+                    .iter()
+                    .flat_map(|overlay| overlay.values.iter().flatten().copied()),
+            )
+            .chain(
+                bands
+                    .iter()
+                    .flatten()
+                    .flat_map(|point| [point.lower, point.upper]),
+            )
+            .filter(|price| price.is_finite())
+            .map(|price| (0.0, price));
+        match data_bounds(prices) {
+            None => (0.0, 1.0),
+            Some((_, _, min, max)) if min == max => (min - 1.0, max + 1.0),
+            Some((_, _, min, max)) => {
+                let padding = (max - min) * 0.05;
+                (min - padding, max + padding)
+            }
+        }
+    }
+
+    /// Formats the time of a candle: as a date with the date format of the format context,
+    /// or as the raw timestamp without one.
+    pub fn format_time(&self, timestamp: f64) -> String {
+        self.config
+            .format_date(timestamp)
+            .unwrap_or_else(|| timestamp.to_string())
+    }
+
+    /// Formats the prices of a candle, localized like the values of the y-axis, e.g.
+    /// `"O 1.00, H 2.00, L 0.50, C 1.50"`.
+    pub fn price_summary(&self, candle: (f64, f64, f64, f64, f64)) -> String {
+        let (_, open, high, low, close) = candle;
+        let price = |value: f64| {
+            self.config
+                .localize_value(AxisType::AxisY, &format!("{value:.2}"))
+        };
+        format!(
+            "O {}, H {}, L {}, C {}",
+            price(open),
+            price(high),
+            price(low),
+            price(close)
+        )
+    }
+
+    /// Builds the labels of the time ticks: dates if the format context has a date format,
+    /// and timestamps otherwise.
+    ///
+    /// # Parameters
+    /// - `values`: The tick values, in axis order.
+    pub fn time_tick_labels(&self, values: &[f64]) -> Vec<String> {
+        match self.config.format.date_format.as_deref() {
+            Some(pattern) => values
+                .iter()
+                .map(|&time| self.config.time_tick_label(AxisType::AxisX, time, pattern))
+                .collect(),
+            None => self.config.tick_labels(AxisType::AxisX, values, |v| {
+                TickPrecision::Fixed(0).format(v)
+            }),
+        }
+    }
+}
@@ -1,16 +1,21 @@
 use crate::figure::{
-    configuration::figureconfig::FigureConfig, datasets::cartesiangraphdataset::CartesianDataset,
+    configuration::figureconfig::FigureConfig,
+    datasets::cartesiangraphdataset::CartesianDataset,
+    utilities::{autoscale::Autoscale, axistype::AxisType, seriesvisibility::SeriesVisibility},
 };
 
+#[derive(Clone)]
 pub struct CartesianGraph {
     pub datasets: Vec<CartesianDataset>,
     pub title: String,
     pub x_label: String,
     pub y_label: String,
-    pub x_min: f64, // Minimum x-value
-    pub x_max: f64, // Maximum x-value
-    pub y_min: f64, // Minimum y-value
-    pub y_max: f64, // Maximum y-value
+    pub x_min: f64,             // Minimum x-value
+    pub x_max: f64,             // Maximum x-value
+    pub y_min: f64,             // Minimum y-value
+    pub y_max: f64,             // Maximum y-value
+    pub x_autoscale: Autoscale, // How the x range follows the data
+    pub y_autoscale: Autoscale, // How the y range follows the data
     pub config: FigureConfig,
 }
 
@@ -25,6 +30,8 @@ impl CartesianGraph {
             x_max: f64::NEG_INFINITY, // Initialize to min range
             y_min: f64::INFINITY,     // Initialize to max range
             y_max: f64::NEG_INFINITY, // Initialize to min range
+            x_autoscale: Autoscale::Symmetric,
+            y_autoscale: Autoscale::Symmetric,
             config: config.clone(),
         }
     }
@@ -34,50 +41,92 @@ impl CartesianGraph {
         self.update_range();
     }
 
-    pub fn update_range(&mut self) {
-        for dataset in &self.datasets {
-            for &(x, y) in &dataset.points {
-                if x < self.x_min {
-                    self.x_min = x;
-                }
-                if x > self.x_max {
-                    self.x_max = x;
-                }
-                if y < self.y_min {
-                    self.y_min = y;
-                }
-                if y > self.y_max {
-                    self.y_max = y;
-                }
+    /// Sets how the range of an axis follows the data at every render, e.g. for live charts
+    /// whose data keeps growing. The range of the axis is refitted from the data.
+    ///
+    /// By default both axes use `Autoscale::Symmetric`: they grow to include all data and
+    /// stay symmetric around the origin.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis.
+    /// - `mode`: How its range is computed.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::autoscale::Autoscale;
+    ///
+    /// // The last 60 seconds, with y fitted to them but ignoring the top and bottom 1%
+    /// graph.set_autoscale(AxisType::AxisX, Autoscale::Window(60.0));
+    /// graph.set_autoscale(AxisType::AxisY, Autoscale::Percentile { lower: 1.0, upper: 99.0 });
+    /// ```
+    pub fn set_autoscale(&mut self, axis: AxisType, mode: Autoscale) {
+        match axis {
+            AxisType::AxisX => {
+                self.x_autoscale = mode;
+                (self.x_min, self.x_max) = (f64::INFINITY, f64::NEG_INFINITY);
             }
-        }
-        let mut is_empty = self.datasets.is_empty();
-
-        for dataset in &self.datasets {
-            if dataset.points.is_empty() {
-                is_empty = true;
-                break;
+            AxisType::AxisY => {
+                self.y_autoscale = mode;
+                (self.y_min, self.y_max) = (f64::INFINITY, f64::NEG_INFINITY);
             }
         }
+        self.update_range();
+    }
 
-        if !is_empty {
-            let abs_x_min = self.x_min.abs();
-            let abs_x_max = self.x_max.abs();
+    /// Returns the datasets that are drawn, in order.
+    pub fn visible_datasets(&self) -> impl Iterator<Item = &CartesianDataset> {
+        self.datasets.iter().filter(|d| d.visible)
+    }
 
-            if abs_x_min > abs_x_max {
-                self.x_max = abs_x_min;
-            } else {
-                self.x_min = -abs_x_max;
-            }
+    pub fn update_range(&mut self) {
+        let transformed: Vec<(f64, f64)> = self
+            .visible_datasets()
+            .filter(|d| d.transform.is_some())
+            .flat_map(|d| d.transformed_points())
+            .collect();
+        let points: Vec<(f64, f64)> = self
+            .visible_datasets()
+            .flat_map(|d| d.error_bars.extent_points(&d.points))
+            .chain(transformed)
+            .collect();
 
-            let abs_y_min = self.y_min.abs();
-            let abs_y_max = self.y_max.abs();
+        // Ranges are only made symmetric once every dataset has points
+        let is_empty = self.visible_datasets().next().is_none()
+            || self.visible_datasets().any(|d| d.points.is_empty());
+        let effective = |mode: Autoscale| match mode {
+            Autoscale::Symmetric if is_empty => Autoscale::Expanding,
+            mode => mode,
+        };
 
-            if abs_y_min > abs_y_max {
-                self.y_max = abs_y_min;
-            } else {
-                self.y_min = -abs_y_max;
-            }
+        let xs = points.iter().map(|&(x, _)| x);
+        if let Some(range) = effective(self.x_autoscale).range(xs, (self.x_min, self.x_max)) {
+            (self.x_min, self.x_max) = range;
         }
+
+        // Within a sliding x window, y fits only the visible points
+        let windowed = matches!(self.x_autoscale, Autoscale::Window(_));
+        let (x_min, x_max) = (self.x_min, self.x_max);
+        let ys = points
+            .iter()
+            .filter(|&&(x, _)| !windowed || (x_min..=x_max).contains(&x))
+            .map(|&(_, y)| y);
+        if let Some(range) = effective(self.y_autoscale).range(ys, (self.y_min, self.y_max)) {
+            (self.y_min, self.y_max) = range;
+        }
+    }
+}
+
+impl SeriesVisibility for CartesianGraph {
+    fn series_visibility(&mut self) -> Vec<(&str, &mut bool)> {
+        self.datasets
+            .iter_mut()
+            .map(|dataset| (dataset.label.as_str(), &mut dataset.visible))
+            .collect()
+    }
+
+    fn refit_ranges(&mut self) {
+        (self.x_min, self.x_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        (self.y_min, self.y_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        self.update_range();
     }
 }
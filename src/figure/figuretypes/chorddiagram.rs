@@ -0,0 +1,414 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    utilities::{arcgeometry::angle_of, bezierpath::BezierPath},
+};
+
+/// A laid out group: the arc of one row of the flow matrix around the circle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChordGroup {
+    /// Index of the row.
+    pub index: usize,
+    /// The angle where the arc starts, clockwise on screen from the positive x-axis.
+    pub start_angle: f64,
+    /// The angle where the arc ends.
+    pub end_angle: f64,
+    /// The total outgoing flow of the row, which sets the length of the arc.
+    pub value: f64,
+}
+
+/// A laid out ribbon joining the flows between two groups, or the flow of a group to
+/// itself.
+///
+/// Each end of the ribbon spans the part of its group's arc taken by the flow leaving
+/// that group: `matrix[source][target]` at the source and `matrix[target][source]` at the
+/// target. The source is the end with the larger flow.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChordRibbon {
+    /// Index of the group with the larger outgoing flow.
+    pub source: usize,
+    /// Index of the other group.
+    pub target: usize,
+    /// The angular span of the ribbon at its source.
+    pub source_angles: (f64, f64),
+    /// The angular span of the ribbon at its target.
+    pub target_angles: (f64, f64),
+}
+
+impl ChordRibbon {
+    /// Builds the outline of the ribbon: both of its ends along the inner edge of the
+    /// arcs, joined by quadratic Bézier curves bending through the center.
+    ///
+    /// # Parameters
+    /// - `center`: The center of the circle in canvas coordinates.
+    /// - `radius`: The radius the ribbon ends touch, the inner radius of the arcs.
+    pub fn path(&self, center: (f64, f64), radius: f64) -> BezierPath {
+        let point = |angle: f64| {
+            (
+                center.0 + radius * angle.cos(),
+                center.1 + radius * angle.sin(),
+            )
+        };
+        let (s0, s1) = self.source_angles;
+        let (t0, t1) = self.target_angles;
+        let mut path = BezierPath::new(point(s0));
+        path.arc_to(center, radius, s0, s1);
+        if self.source != self.target {
+            path.quadratic_to(center, point(t0));
+            path.arc_to(center, radius, t0, t1);
+        }
+        path.quadratic_to(center, point(s0));
+        path
+    }
+}
+
+/// The groups and ribbons of a chord diagram.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChordLayout {
+    /// The groups in row order.
+    pub groups: Vec<ChordGroup>,
+    /// The ribbons, in the order they are drawn.
+    pub ribbons: Vec<ChordRibbon>,
+}
+
+/// Represents a chord diagram: the rows of a square flow matrix as arcs around a circle,
+/// with ribbons between the arcs showing the flows in both directions.
+///
+/// `matrix[i][j]` is the flow from row `i` to row `j`. The arc of row `i` is as long as
+/// its total outgoing flow, and the ribbon between rows `i` and `j` is
+/// `matrix[i][j]` wide at row `i` and `matrix[j][i]` wide at row `j`.
+#[derive(Clone)]
+pub struct ChordDiagram {
+    /// Title of the diagram.
+    pub title: String,
+    /// Labels of the rows, drawn outside their arcs.
+    pub labels: Vec<String>,
+    /// The square flow matrix.
+    pub matrix: Vec<Vec<f64>>,
+    /// Colors of the rows; rows without their own color use `GROUP_COLORS`.
+    pub colors: Vec<Option<[u8; 3]>>,
+    /// Angle in radians left empty between neighbouring arcs.
+    pub padding_angle: f64,
+    /// Width of the arcs as a fraction of the radius.
+    pub arc_width: f64,
+    /// Opacity of the ribbons (0.0 to 1.0), so crossing ribbons stay visible.
+    pub ribbon_opacity: f64,
+    /// Whether rows show their labels outside their arcs.
+    pub show_labels: bool,
+    /// The number of decimal places of values in tooltips.
+    pub precision: usize,
+    /// Configuration settings for rendering the diagram (e.g., colors, fonts).
+    pub config: FigureConfig,
+}
+
+impl ChordDiagram {
+    /// Colors assigned to rows without their own color, in order.
+    pub const GROUP_COLORS: [[u8; 3]; 8] = [
+        [31, 119, 180],
+        [255, 127, 14],
+        [44, 160, 44],
+        [214, 39, 40],
+        [148, 103, 189],
+        [140, 86, 75],
+        [227, 119, 194],
+        [23, 190, 207],
+    ];
+
+    /// Angle of the start of the first arc: the top of the circle.
+    const START_ANGLE: f64 = -FRAC_PI_2;
+
+    /// Creates a new `ChordDiagram` instance with the specified title and configuration.
+    ///
+    /// Arcs are 8% of the radius wide with 0.04 radians between them, and ribbons are
+    /// drawn at 65% opacity.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the diagram.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `ChordDiagram` instance without rows.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::chorddiagram::ChordDiagram;
+    ///
+    /// let mut chord = ChordDiagram::new("Migration", config);
+    /// chord.set_matrix(
+    ///     &["North", "East", "South"],
+    ///     vec![
+    ///         vec![10.0, 40.0, 25.0],
+    ///         vec![15.0, 5.0, 30.0],
+    ///         vec![20.0, 10.0, 0.0],
+    ///     ],
+    /// )?;
+    /// ```
+    pub fn new(title: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            labels: Vec::new(),
+            matrix: Vec::new(),
+            colors: Vec::new(),
+            padding_angle: 0.04,
+            arc_width: 0.08,
+            ribbon_opacity: 0.65,
+            show_labels: true,
+            precision: 2,
+            config,
+        }
+    }
+
+    /// Sets the rows and the flows between them, replacing any earlier ones.
+    ///
+    /// # Parameters
+    /// - `labels`: The labels of the rows.
+    /// - `matrix`: The flows, `matrix[i][j]` going from row `i` to row `j`.
+    ///
+    /// # Errors
+    /// Returns an error if the matrix is not square with one row per label, or contains a
+    /// negative or non-finite flow; the diagram is left unchanged.
+    pub fn set_matrix(&mut self, labels: &[&str], matrix: Vec<Vec<f64>>) -> Result<(), String> {
+        if matrix.len() != labels.len() {
+            return Err(format!(
+                "Expected {} matrix rows, got {}",
+                labels.len(),
+                matrix.len()
+            ));
+        }
+        for (label, row) in labels.iter().zip(&matrix) {
+            if row.len() != labels.len() {
+                return Err(format!(
+                    "Row '{label}' has {} flows, expected {}",
+                    row.len(),
+                    labels.len()
+                ));
+            }
+            if let Some(value) = row
+                .iter()
+                .find(|value| !(value.is_finite() && **value >= 0.0))
+            {
+                return Err(format!("Row '{label}' has an invalid flow {value}"));
+            }
+        }
+        self.labels = labels.iter().map(|label| label.to_string()).collect();
+        self.colors = vec![None; labels.len()];
+        self.matrix = matrix;
+        Ok(())
+    }
+
+    /// Sets the color of a row's arc and of the ribbons it is the source of.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the row.
+    /// - `color`: The RGB color.
+    pub fn set_color(&mut self, label: &str, color: [u8; 3]) {
+        if let Some(index) = self.labels.iter().position(|l| l == label) {
+            self.colors[index] = Some(color);
+        }
+    }
+
+    /// Sets the angle left empty between neighbouring arcs.
+    ///
+    /// # Parameters
+    /// - `padding_angle`: The angle in radians, at least 0.
+    pub fn set_padding_angle(&mut self, padding_angle: f64) {
+        self.padding_angle = padding_angle.max(0.0);
+    }
+
+    /// Returns the color of a row: its own or one of `GROUP_COLORS` by index.
+    pub fn group_color(&self, index: usize) -> [u8; 3] {
+        self.colors
+            .get(index)
+            .copied()
+            .flatten()
+            .unwrap_or(Self::GROUP_COLORS[index % Self::GROUP_COLORS.len()])
+    }
+
+    /// Returns the total outgoing flow of a row.
+    pub fn outgoing(&self, index: usize) -> f64 {
+        self.matrix[index].iter().sum()
+    }
+
+    /// Returns the total incoming flow of a row.
+    pub fn incoming(&self, index: usize) -> f64 {
+        self.matrix.iter().map(|row| row[index]).sum()
+    }
+
+    /// Lays out the arcs and ribbons around the circle.
+    ///
+    /// Arcs run clockwise from the top of the circle in row order, separated by
+    /// `padding_angle`. Within each arc the flows to the rows follow in row order.
+    /// Ribbons without flow in either direction are left out.
+    pub fn layout(&self) -> ChordLayout {
+        let n = self.matrix.len();
+        let total: f64 = (0..n).map(|index| self.outgoing(index)).sum();
+        if n == 0 || total <= 0.0 {
+            return ChordLayout {
+                groups: Vec::new(),
+                ribbons: Vec::new(),
+            };
+        }
+
+        // Padding takes at most half the circle
+        let padding = self.padding_angle.min(TAU / 2.0 / n as f64);
+        let scale = (TAU - padding * n as f64) / total;
+
+        let mut groups = Vec::with_capacity(n);
+        // Angular span of the flow from row i to row j within the arc of row i
+        let mut spans = vec![vec![(0.0, 0.0); n]; n];
+        let mut angle = Self::START_ANGLE;
+        for (index, row) in self.matrix.iter().enumerate() {
+            let start_angle = angle;
+            for (target, &value) in row.iter().enumerate() {
+                spans[index][target] = (angle, angle + value * scale);
+                angle += value * scale;
+            }
+            groups.push(ChordGroup {
+                index,
+                start_angle,
+                end_angle: angle,
+                value: self.outgoing(index),
+            });
+            angle += padding;
+        }
+
+        let mut ribbons = Vec::new();
+        for i in 0..n {
+            for j in i..n {
+                let (forward, backward) = (self.matrix[i][j], self.matrix[j][i]);
+                if forward <= 0.0 && backward <= 0.0 {
+                    continue;
+                }
+                let (source, target) = if forward >= backward { (i, j) } else { (j, i) };
+                ribbons.push(ChordRibbon {
+                    source,
+                    target,
+                    source_angles: spans[source][target],
+                    target_angles: spans[target][source],
+                });
+            }
+        }
+
+        ChordLayout { groups, ribbons }
+    }
+
+    /// Computes the center and the outer radius of the arcs on a canvas.
+    ///
+    /// # Parameters
+    /// - `width`, `height`, `margin`: The size and margin of the canvas in pixels.
+    pub fn geometry(width: f64, height: f64, margin: f64) -> ((f64, f64), f64) {
+        (
+            (width / 2.0, height / 2.0),
+            ((width.min(height) - 2.0 * margin) / 2.0).max(0.0),
+        )
+    }
+
+    /// Returns the inner radius of the arcs, where the ribbons end.
+    pub fn inner_radius(&self, radius: f64) -> f64 {
+        radius * (1.0 - self.arc_width.clamp(0.0, 1.0))
+    }
+
+    /// Builds the outline of the arc of a group.
+    ///
+    /// # Parameters
+    /// - `group`: The group.
+    /// - `center`: The center of the circle in canvas coordinates.
+    /// - `radius`: The outer radius of the arcs.
+    pub fn group_path(&self, group: &ChordGroup, center: (f64, f64), radius: f64) -> BezierPath {
+        BezierPath::ring_segment(
+            center,
+            (self.inner_radius(radius), radius),
+            (group.start_angle, group.end_angle),
+        )
+    }
+
+    /// Formats the tooltip of a group: its label, total outgoing and incoming flows and the
+    /// share of its outgoing flows in all flows.
+    pub fn group_tooltip(&self, index: usize) -> String {
+        let format = &self.config.format;
+        let total: f64 = self.matrix.iter().flatten().sum();
+        let share = if total > 0.0 {
+            self.outgoing(index) / total * 100.0
+        } else {
+            0.0
+        };
+        format!(
+            "{}: {} out, {} in ({} of all flows)",
+            self.labels[index],
+            format.format_number(self.outgoing(index), self.precision),
+            format.format_number(self.incoming(index), self.precision),
+            format.format_percent(share, 1)
+        )
+    }
+
+    /// Formats the tooltip of a ribbon: the flows in both directions.
+    pub fn ribbon_tooltip(&self, ribbon: &ChordRibbon) -> String {
+        let (source, target) = (ribbon.source, ribbon.target);
+        let flow = |from: usize, to: usize| {
+            self.config
+                .format
+                .format_number(self.matrix[from][to], self.precision)
+        };
+        if source == target {
+            return format!(
+                "{} → {}: {}",
+                self.labels[source],
+                self.labels[source],
+                flow(source, source)
+            );
+        }
+        format!(
+            "{} → {}: {}, {} → {}: {}",
+            self.labels[source],
+            self.labels[target],
+            flow(source, target),
+            self.labels[target],
+            self.labels[source],
+            flow(target, source)
+        )
+    }
+
+    /// Finds the arc or ribbon at a position, preferring arcs.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The position in canvas coordinates.
+    /// - `center`, `radius`: The circle, as returned by `geometry`.
+    /// - `layout`: The layout of the diagram.
+    ///
+    /// # Returns
+    /// The tooltip of the element and a point on it, or `None` if the position is empty.
+    pub fn element_at(
+        &self,
+        x: f64,
+        y: f64,
+        (center, radius): ((f64, f64), f64),
+        layout: &ChordLayout,
+    ) -> Option<((f64, f64), String)> {
+        let (dx, dy) = (x - center.0, y - center.1);
+        let distance = dx.hypot(dy);
+        if (self.inner_radius(radius)..=radius).contains(&distance) {
+            // Arcs start at the top, so angles are measured from there
+            let mut angle = angle_of(dx, dy);
+            if angle >= TAU + Self::START_ANGLE {
+                angle -= TAU;
+            }
+            return layout
+                .groups
+                .iter()
+                .find(|group| (group.start_angle..group.end_angle).contains(&angle))
+                .map(|group| ((x, y), self.group_tooltip(group.index)));
+        }
+        // Ribbons drawn later are on top
+        layout
+            .ribbons
+            .iter()
+            .rev()
+            .find(|ribbon| {
+                ribbon
+                    .path(center, self.inner_radius(radius))
+                    .contains(x, y)
+            })
+            .map(|ribbon| ((x, y), self.ribbon_tooltip(ribbon)))
+    }
+}
@@ -0,0 +1,241 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    utilities::{
+        colorbar::COLOR_BAR_SPACE,
+        colormap::{interpolate, MISSING_COLOR, SEQUENTIAL_YELLOW_BLUE},
+        geojson::{parse_features, GeoFeature},
+        projection::{fit_bounds, Projection},
+    },
+};
+
+/// Represents a choropleth map: geographic regions filled by the value associated with them.
+///
+/// Regions are loaded from GeoJSON polygons and matched with their values by name. Regions
+/// without a value are drawn in a neutral gray.
+#[derive(Clone)]
+pub struct ChoroplethMap {
+    /// Title of the map.
+    pub title: String,
+    /// The regions of the map.
+    pub features: Vec<GeoFeature>,
+    /// The value of each region, as `(region name, value)` pairs.
+    pub values: Vec<(String, f64)>,
+    /// The projection from longitude and latitude to the plane.
+    pub projection: Projection,
+    /// Color stops of the value scale, from the lowest to the highest value.
+    pub color_stops: Vec<[u8; 3]>,
+    /// Color of the region borders.
+    pub color_border: [u8; 3],
+    /// Configuration settings for rendering the map (e.g., colors, fonts).
+    pub config: FigureConfig,
+}
+
+impl ChoroplethMap {
+    /// Creates a new `ChoroplethMap` instance with the specified title, projection, and configuration.
+    ///
+    /// Values are colored from light yellow to dark blue, and regions have white borders.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the map.
+    /// - `projection`: The map projection (`Equirectangular` or `Mercator`).
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `ChoroplethMap` instance with no regions.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::choroplethmap::ChoroplethMap;
+    /// use dataviz::figure::utilities::projection::Projection;
+    ///
+    /// let mut map = ChoroplethMap::new("Population Density", Projection::Mercator, config);
+    /// map.load_geojson(&std::fs::read_to_string("europe.geojson")?, "name")?;
+    /// map.set_value("France", 119.0);
+    /// map.set_value("Germany", 233.0);
+    /// ```
+    pub fn new(title: &str, projection: Projection, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            features: Vec::new(),
+            values: Vec::new(),
+            projection,
+            color_stops: SEQUENTIAL_YELLOW_BLUE.to_vec(),
+            color_border: [255, 255, 255],
+            config,
+        }
+    }
+
+    /// Adds the polygon features of a GeoJSON document to the map.
+    ///
+    /// # Parameters
+    /// - `text`: The GeoJSON text.
+    /// - `name_property`: The feature property holding the region name, matched against the
+    ///   names passed to `set_value`.
+    ///
+    /// # Returns
+    /// An error message if the document could not be read; the map is left unchanged then.
+    pub fn load_geojson(&mut self, text: &str, name_property: &str) -> Result<(), String> {
+        self.features.extend(parse_features(text, name_property)?);
+        Ok(())
+    }
+
+    /// Adds a single region to the map.
+    ///
+    /// # Parameters
+    /// - `feature`: The region and its polygons.
+    pub fn add_feature(&mut self, feature: GeoFeature) {
+        self.features.push(feature);
+    }
+
+    /// Sets the value of a region, replacing any previous value.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the region.
+    /// - `value`: The value of the region.
+    pub fn set_value(&mut self, name: &str, value: f64) {
+        match self.values.iter_mut().find(|(region, _)| region == name) {
+            Some(entry) => entry.1 = value,
+            None => self.values.push((name.to_string(), value)),
+        }
+    }
+
+    /// Returns the value of a region, if it has a finite one.
+    pub fn value(&self, name: &str) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|(region, _)| region == name)
+            .map(|&(_, value)| value)
+            .filter(|value| value.is_finite())
+    }
+
+    /// Computes the range of the region values.
+    ///
+    /// # Returns
+    /// A tuple `(min, max)`. Defaults to `(0.0, 1.0)` when no region has a value.
+    pub fn value_range(&self) -> (f64, f64) {
+        let values = self
+            .features
+            .iter()
+            .filter_map(|feature| self.value(&feature.name));
+        let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(v), max.max(v))
+        });
+        if min > max {
+            (0.0, 1.0)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// Maps a value onto the color scale of the map.
+    ///
+    /// # Parameters
+    /// - `value`: The value to map.
+    /// - `range`: The value range, as returned by `value_range`.
+    pub fn value_color(&self, value: f64, range: (f64, f64)) -> [u8; 3] {
+        let (min, max) = range;
+        let t = if max > min {
+            (value - min) / (max - min)
+        } else {
+            0.5
+        };
+        interpolate(&self.color_stops, t)
+    }
+
+    /// Returns the fill color of a region, or `MISSING_COLOR` if it has no value.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the region.
+    /// - `range`: The value range, as returned by `value_range`.
+    pub fn fill_color(&self, name: &str, range: (f64, f64)) -> [u8; 3] {
+        match self.value(name) {
+            Some(value) => self.value_color(value, range),
+            None => MISSING_COLOR,
+        }
+    }
+
+    /// Computes how the projected map is fitted into a canvas, preserving its aspect ratio.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(scale, origin_x, origin_y)`: a projected point `(x, y)` is drawn at
+    /// `(origin_x + x * scale, origin_y - y * scale)`. `None` when the map has no points.
+    pub fn layout(&self, width: f64, height: f64, margin: f64) -> Option<(f64, f64, f64)> {
+        let (mut x_min, mut x_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        let (mut y_min, mut y_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        for &(longitude, latitude) in self.all_points() {
+            let (x, y) = self.projection.project(longitude, latitude);
+            (x_min, x_max) = (x_min.min(x), x_max.max(x));
+            (y_min, y_max) = (y_min.min(y), y_max.max(y));
+        }
+        if x_min > x_max {
+            return None;
+        }
+
+        let area_width = (width - 2.0 * margin - COLOR_BAR_SPACE).max(1.0);
+        let area_height = (height - 2.0 * margin).max(1.0);
+        Some(fit_bounds(
+            (x_min, x_max, y_min, y_max),
+            (margin, margin, area_width, area_height),
+        ))
+    }
+
+    /// Projects a geographic coordinate onto the canvas.
+    ///
+    /// # Parameters
+    /// - `point`: The `(longitude, latitude)` in degrees.
+    /// - `layout`: The layout returned by `layout`.
+    pub fn to_canvas(&self, point: (f64, f64), layout: (f64, f64, f64)) -> (f64, f64) {
+        let (scale, origin_x, origin_y) = layout;
+        let (x, y) = self.projection.project(point.0, point.1);
+        (origin_x + x * scale, origin_y - y * scale)
+    }
+
+    /// Projects the rings of a polygon onto the canvas.
+    pub fn polygon_to_canvas(
+        &self,
+        rings: &[Vec<(f64, f64)>],
+        layout: (f64, f64, f64),
+    ) -> Vec<Vec<(f64, f64)>> {
+        rings
+            .iter()
+            .map(|ring| ring.iter().map(|&p| self.to_canvas(p, layout)).collect())
+            .collect()
+    }
+
+    /// Finds the region containing a canvas point.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The point in canvas coordinates.
+    /// - `layout`: The layout returned by `layout`.
+    ///
+    /// # Returns
+    /// The index of the region in `features`, or `None` outside every region.
+    pub fn feature_at(&self, x: f64, y: f64, layout: (f64, f64, f64)) -> Option<usize> {
+        self.features.iter().position(|feature| {
+            feature.polygons.iter().any(|rings| {
+                // Even-odd rule over all rings, so holes are excluded
+                let rings = self.polygon_to_canvas(rings, layout);
+                let mut inside = false;
+                for ring in &rings {
+                    for (index, &(x1, y1)) in ring.iter().enumerate() {
+                        let (x2, y2) = ring[(index + 1) % ring.len()];
+                        if (y1 <= y) != (y2 <= y) && x < x1 + (y - y1) / (y2 - y1) * (x2 - x1) {
+                            inside = !inside;
+                        }
+                    }
+                }
+                inside
+            })
+        })
+    }
+
+    fn all_points(&self) -> impl Iterator<Item = &(f64, f64)> {
+        self.features
+            .iter()
+            .flat_map(|feature| feature.polygons.iter().flatten().flatten())
+    }
+}
@@ -0,0 +1,373 @@
+use image::RgbImage;
+
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    utilities::{
+        colorbar::{self, COLOR_BAR_SPACE},
+        colormap::{interpolate, SEQUENTIAL_YELLOW_BLUE},
+        marchingsquares::{bilinear, iso_segments},
+    },
+};
+
+/// Represents a contour plot: iso-lines of a gridded scalar field at a set of levels,
+/// optionally with the bands between the levels filled.
+///
+/// Values are stored as a matrix indexed `[row][column]`. Columns are evenly spaced over
+/// the x extent from left to right, and rows over the y extent from the bottom up, so
+/// `values[0][0]` is the bottom-left grid point. Missing or `NaN` values leave a gap.
+#[derive(Clone)]
+pub struct ContourPlot {
+    /// Title of the plot.
+    pub title: String,
+    /// Label for the X-axis.
+    pub x_label: String,
+    /// Label for the Y-axis.
+    pub y_label: String,
+    /// The field, indexed as `[row][column]`.
+    pub values: Vec<Vec<f64>>,
+    /// The x values of the first and last column, or `None` to use column indices.
+    pub x_extent: Option<(f64, f64)>,
+    /// The y values of the first and last row, or `None` to use row indices.
+    pub y_extent: Option<(f64, f64)>,
+    /// The levels of the iso-lines, or empty for `num_levels` evenly spaced levels.
+    pub levels: Vec<f64>,
+    /// Number of automatic levels, spread evenly inside the range of the values.
+    pub num_levels: usize,
+    /// Whether the bands between the levels are filled.
+    pub filled: bool,
+    /// Color stops from the lowest to the highest level.
+    pub color_stops: Vec<[u8; 3]>,
+    /// Color of the iso-lines over filled bands; without bands every line takes the color
+    /// of its level.
+    pub line_color: [u8; 3],
+    /// Number of decimals of the level labels and tooltips.
+    pub precision: usize,
+    /// Configuration settings for rendering the plot (e.g., colors, fonts).
+    pub config: FigureConfig,
+}
+
+impl ContourPlot {
+    /// Creates a new `ContourPlot` instance with the specified title, labels, and
+    /// configuration.
+    ///
+    /// The plot draws eight automatic levels as lines, colored with the yellow to blue
+    /// colormap.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the plot.
+    /// - `x_label`: The label for the X-axis.
+    /// - `y_label`: The label for the Y-axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `ContourPlot` instance without values.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::contourplot::ContourPlot;
+    ///
+    /// let mut contour = ContourPlot::new("Elevation", "Easting (km)", "Northing (km)", config);
+    /// contour.set_values(elevation);
+    /// contour.set_extent((0.0, 12.0), (0.0, 8.0));
+    /// contour.set_levels(&[100.0, 200.0, 300.0, 400.0]);
+    /// contour.set_filled(true);
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            values: Vec::new(),
+            x_extent: None,
+            y_extent: None,
+            levels: Vec::new(),
+            num_levels: 8,
+            filled: false,
+            color_stops: SEQUENTIAL_YELLOW_BLUE.to_vec(),
+            line_color: [60, 60, 60],
+            precision: 2,
+            config,
+        }
+    }
+
+    /// Sets the field.
+    ///
+    /// # Parameters
+    /// - `values`: The values, indexed as `[row][column]` with row `0` at the bottom.
+    pub fn set_values(&mut self, values: Vec<Vec<f64>>) {
+        self.values = values;
+    }
+
+    /// Places the grid in data coordinates.
+    ///
+    /// # Parameters
+    /// - `x`: The x values of the first and last column.
+    /// - `y`: The y values of the first and last row.
+    pub fn set_extent(&mut self, x: (f64, f64), y: (f64, f64)) {
+        self.x_extent = Some(x);
+        self.y_extent = Some(y);
+    }
+
+    /// Sets the levels of the iso-lines.
+    ///
+    /// # Parameters
+    /// - `levels`: The levels, in any order; non-finite levels are ignored.
+    pub fn set_levels(&mut self, levels: &[f64]) {
+        self.levels = levels.to_vec();
+    }
+
+    /// Uses evenly spaced levels instead of explicit ones.
+    ///
+    /// # Parameters
+    /// - `count`: The number of levels inside the range of the values.
+    pub fn set_num_levels(&mut self, count: usize) {
+        self.levels.clear();
+        self.num_levels = count;
+    }
+
+    /// Fills or clears the bands between the levels.
+    ///
+    /// # Parameters
+    /// - `filled`: Whether each band is filled with its color, with the iso-lines drawn in
+    ///   `line_color` on top.
+    pub fn set_filled(&mut self, filled: bool) {
+        self.filled = filled;
+    }
+
+    /// Sets the colormap.
+    ///
+    /// # Parameters
+    /// - `stops`: Evenly spaced color stops from the lowest to the highest level, e.g.
+    ///   `colormap::SEQUENTIAL_MAGMA`.
+    pub fn set_color_stops(&mut self, stops: &[[u8; 3]]) {
+        self.color_stops = stops.to_vec();
+    }
+
+    /// Returns the number of rows.
+    pub fn num_rows(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the number of columns, the length of the longest row.
+    pub fn num_columns(&self) -> usize {
+        self.values.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Returns the x values of the first and last column.
+    pub fn x_range(&self) -> (f64, f64) {
+        self.x_extent
+            .unwrap_or((0.0, self.num_columns().saturating_sub(1).max(1) as f64))
+    }
+
+    /// Returns the y values of the first and last row.
+    pub fn y_range(&self) -> (f64, f64) {
+        self.y_extent
+            .unwrap_or((0.0, self.num_rows().saturating_sub(1).max(1) as f64))
+    }
+
+    /// Returns the smallest and largest finite value, or `(0.0, 1.0)` without finite values.
+    pub fn value_range(&self) -> (f64, f64) {
+        let (min, max) = self
+            .values
+            .iter()
+            .flatten()
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            });
+        if min > max {
+            (0.0, 1.0)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// Returns the levels of the iso-lines in ascending order: the set levels, or
+    /// `num_levels` levels splitting the value range into equal bands.
+    pub fn contour_levels(&self) -> Vec<f64> {
+        if !self.levels.is_empty() {
+            let mut levels: Vec<f64> = self
+                .levels
+                .iter()
+                .copied()
+                .filter(|level| level.is_finite())
+                .collect();
+            levels.sort_by(f64::total_cmp);
+            levels.dedup();
+            return levels;
+        }
+        let (min, max) = self.value_range();
+        let count = self.num_levels;
+        (1..=count)
+            .map(|index| min + (max - min) * index as f64 / (count + 1) as f64)
+            .collect()
+    }
+
+    /// Returns the color of a level's iso-line when the bands are not filled.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the level in `contour_levels`.
+    /// - `count`: The number of levels.
+    pub fn level_color(&self, index: usize, count: usize) -> [u8; 3] {
+        let t = if count > 1 {
+            index as f64 / (count - 1) as f64
+        } else {
+            0.5
+        };
+        interpolate(&self.color_stops, t)
+    }
+
+    /// Returns the color of a band.
+    ///
+    /// # Parameters
+    /// - `band`: The band, from `0` below the lowest level to `count` above the highest.
+    /// - `count`: The number of levels.
+    pub fn band_color(&self, band: usize, count: usize) -> [u8; 3] {
+        let t = if count > 0 {
+            band as f64 / count as f64
+        } else {
+            0.5
+        };
+        interpolate(&self.color_stops, t)
+    }
+
+    /// Returns the band of a value: the number of levels at or below it.
+    ///
+    /// # Parameters
+    /// - `value`: The value.
+    /// - `levels`: The levels returned by `contour_levels`.
+    pub fn band_of(&self, value: f64, levels: &[f64]) -> usize {
+        levels.partition_point(|&level| level <= value)
+    }
+
+    /// Formats a value for level labels and tooltips.
+    ///
+    /// # Parameters
+    /// - `value`: The value.
+    pub fn format_value(&self, value: f64) -> String {
+        if value.is_nan() {
+            "n/a".to_string()
+        } else {
+            self.config.format.format_number(value, self.precision)
+        }
+    }
+
+    /// Computes the plot area on a canvas, leaving room for the color bar.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(left, top, right, bottom)` in canvas coordinates.
+    pub fn plot_area(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64, f64) {
+        colorbar::plot_area(width, height, margin, 0.0, COLOR_BAR_SPACE)
+    }
+
+    /// Converts a fractional grid position to canvas coordinates.
+    ///
+    /// # Parameters
+    /// - `column`, `row`: The grid position.
+    /// - `area`: The plot area returned by `plot_area`.
+    pub fn grid_to_canvas(&self, column: f64, row: f64, area: (f64, f64, f64, f64)) -> (f64, f64) {
+        let (left, top, right, bottom) = area;
+        let columns = self.num_columns().saturating_sub(1).max(1) as f64;
+        let rows = self.num_rows().saturating_sub(1).max(1) as f64;
+        (
+            left + column / columns * (right - left),
+            bottom - row / rows * (bottom - top),
+        )
+    }
+
+    /// Converts canvas coordinates to a fractional grid position.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas position.
+    /// - `area`: The plot area returned by `plot_area`.
+    pub fn canvas_to_grid(&self, x: f64, y: f64, area: (f64, f64, f64, f64)) -> (f64, f64) {
+        let (left, top, right, bottom) = area;
+        let columns = self.num_columns().saturating_sub(1).max(1) as f64;
+        let rows = self.num_rows().saturating_sub(1).max(1) as f64;
+        (
+            (x - left) / (right - left) * columns,
+            (bottom - y) / (bottom - top) * rows,
+        )
+    }
+
+    /// Converts a fractional grid position to data coordinates.
+    ///
+    /// # Parameters
+    /// - `column`, `row`: The grid position.
+    pub fn grid_to_data(&self, column: f64, row: f64) -> (f64, f64) {
+        let (x0, x1) = self.x_range();
+        let (y0, y1) = self.y_range();
+        let columns = self.num_columns().saturating_sub(1).max(1) as f64;
+        let rows = self.num_rows().saturating_sub(1).max(1) as f64;
+        (
+            x0 + column / columns * (x1 - x0),
+            y0 + row / rows * (y1 - y0),
+        )
+    }
+
+    /// Samples the field under a canvas position by bilinear interpolation.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas position.
+    /// - `area`: The plot area returned by `plot_area`.
+    ///
+    /// # Returns
+    /// The value, or `NaN` outside the grid or next to a missing value.
+    pub fn value_at(&self, x: f64, y: f64, area: (f64, f64, f64, f64)) -> f64 {
+        let (column, row) = self.canvas_to_grid(x, y, area);
+        bilinear(&self.values, column, row)
+    }
+
+    /// Computes the iso-lines of every level in canvas coordinates.
+    ///
+    /// # Parameters
+    /// - `area`: The plot area returned by `plot_area`.
+    ///
+    /// # Returns
+    /// The levels in ascending order, each with its segments as `(x1, y1, x2, y2)` lines.
+    #[allow(clippy::type_complexity)]
+    pub fn iso_lines(&self, area: (f64, f64, f64, f64)) -> Vec<(f64, Vec<(f64, f64, f64, f64)>)> {
+        self.contour_levels()
+            .into_iter()
+            .map(|level| {
+                let lines = iso_segments(&self.values, level)
+                    .into_iter()
+                    .map(|[(c1, r1), (c2, r2)]| {
+                        let (x1, y1) = self.grid_to_canvas(c1, r1, area);
+                        let (x2, y2) = self.grid_to_canvas(c2, r2, area);
+                        (x1, y1, x2, y2)
+                    })
+                    .collect();
+                (level, lines)
+            })
+            .collect()
+    }
+
+    /// Renders the filled bands into an image covering the plot area.
+    ///
+    /// # Parameters
+    /// - `area`: The plot area returned by `plot_area`.
+    ///
+    /// # Returns
+    /// The image; pixels without a value are left in the background color.
+    pub fn render_bands(&self, area: (f64, f64, f64, f64)) -> RgbImage {
+        let (left, top, right, bottom) = area;
+        let width = (right - left).round().max(1.0) as u32;
+        let height = (bottom - top).round().max(1.0) as u32;
+
+        let levels = self.contour_levels();
+        let mut image =
+            RgbImage::from_pixel(width, height, image::Rgb(self.config.color_background));
+        for (x, y, pixel) in image.enumerate_pixels_mut() {
+            let value = self.value_at(left + x as f64 + 0.5, top + y as f64 + 0.5, area);
+            if !value.is_nan() {
+                pixel.0 = self.band_color(self.band_of(value, &levels), levels.len());
+            }
+        }
+        image
+    }
+}
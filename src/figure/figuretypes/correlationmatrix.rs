@@ -0,0 +1,109 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    utilities::{correlation::correlation_matrix, correlationmethod::CorrelationMethod},
+};
+
+/// Represents a correlation matrix plot: the pairwise correlations of several numeric
+/// series drawn as an annotated heatmap.
+///
+/// Cells are colored on a diverging blue–white–red scale centered at zero, and each cell
+/// shows its coefficient.
+#[derive(Clone)]
+pub struct CorrelationMatrix {
+    /// Title of the plot.
+    pub title: String,
+    /// The correlated series as `(label, values)` pairs, in matrix order.
+    pub series: Vec<(String, Vec<f64>)>,
+    /// The correlation coefficient to compute.
+    pub method: CorrelationMethod,
+    /// Whether the coefficient is printed in every cell.
+    pub show_values: bool,
+    /// Number of decimals of the printed coefficients.
+    pub precision: usize,
+    /// Configuration settings for rendering the plot (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl CorrelationMatrix {
+    /// Creates a new `CorrelationMatrix` instance with the specified title, method, and configuration.
+    ///
+    /// Coefficients are printed with two decimals by default.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the plot.
+    /// - `method`: The correlation coefficient to compute (`Pearson` or `Spearman`).
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `CorrelationMatrix` instance with no series.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::correlationmatrix::CorrelationMatrix;
+    /// use dataviz::figure::utilities::correlationmethod::CorrelationMethod;
+    ///
+    /// let mut matrix = CorrelationMatrix::new("Features", CorrelationMethod::Spearman, config);
+    /// matrix.add_series("height", &heights);
+    /// matrix.add_series("weight", &weights);
+    /// matrix.add_series("age", &ages);
+    /// ```
+    pub fn new(title: &str, method: CorrelationMethod, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            series: Vec::new(),
+            method,
+            show_values: true,
+            precision: 2,
+            config,
+        }
+    }
+
+    /// Adds a series to the matrix.
+    ///
+    /// Series are compared over their common length, so they should hold one value per
+    /// observation, in the same observation order.
+    ///
+    /// # Parameters
+    /// - `label`: The name of the series, shown along both axes.
+    /// - `values`: The values of the series.
+    pub fn add_series(&mut self, label: &str, values: &[f64]) {
+        self.series.push((label.to_string(), values.to_vec()));
+    }
+
+    /// Computes the correlation matrix of the series.
+    ///
+    /// # Returns
+    /// A symmetric matrix where entry `[i][j]` is the correlation between series `i` and `j`,
+    /// or `NaN` where a series is constant.
+    pub fn matrix(&self) -> Vec<Vec<f64>> {
+        let values: Vec<Vec<f64>> = self.series.iter().map(|(_, v)| v.clone()).collect();
+        correlation_matrix(&values, self.method)
+    }
+
+    /// Formats a coefficient for display in a cell or tooltip.
+    ///
+    /// # Parameters
+    /// - `value`: The coefficient.
+    pub fn format_value(&self, value: f64) -> String {
+        if value.is_nan() {
+            "n/a".to_string()
+        } else {
+            self.config.format.format_number(value, self.precision)
+        }
+    }
+
+    /// Computes the square plot area on a canvas.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(left, top, cell_size)` in pixels. The matrix spans
+    /// `cell_size * series.len()` pixels in both directions from `(left, top)`.
+    pub fn layout(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64) {
+        let side = (width - 2.0 * margin).min(height - 2.0 * margin).max(0.0);
+        let cell_size = side / self.series.len().max(1) as f64;
+        (margin, margin, cell_size)
+    }
+}
@@ -0,0 +1,214 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    utilities::{clustering, orientation::Orientation},
+};
+
+/// Represents a dendrogram: the tree of a hierarchical clustering, with the clustered items
+/// as labelled leaves and every merge drawn at its distance.
+///
+/// With `Orientation::Vertical` the root is at the top and the leaves are labelled along
+/// the bottom; with `Orientation::Horizontal` the root is on the left and the leaves are
+/// labelled on the right.
+#[derive(Clone)]
+pub struct Dendrogram {
+    /// Title of the plot.
+    pub title: String,
+    /// Label of the distance axis.
+    pub distance_label: String,
+    /// Labels of the leaves, indexed by leaf id.
+    pub labels: Vec<String>,
+    /// The merges of the clustering.
+    pub tree: clustering::Dendrogram,
+    /// Whether the root is at the top (`Vertical`) or on the left (`Horizontal`).
+    pub orientation: Orientation,
+    /// Color of the tree lines in RGB format.
+    pub color: [u8; 3],
+    /// Configuration settings for rendering the plot (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl Dendrogram {
+    /// Creates a new `Dendrogram` instance without leaves.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the plot.
+    /// - `distance_label`: The label of the distance axis.
+    /// - `orientation`: `Orientation::Vertical` for the root at the top,
+    ///   `Orientation::Horizontal` for the root on the left.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `Dendrogram` instance.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::{figuretypes::dendrogram::Dendrogram, utilities::orientation::Orientation};
+    ///
+    /// let mut plot = Dendrogram::new("Samples", "Distance", Orientation::Vertical, config);
+    /// plot.set_linkage(&["A", "B", "C"], &[(0, 2, 0.5), (1, 3, 2.0)])?;
+    /// ```
+    pub fn new(
+        title: &str,
+        distance_label: &str,
+        orientation: Orientation,
+        config: FigureConfig,
+    ) -> Self {
+        Self {
+            title: title.to_string(),
+            distance_label: distance_label.to_string(),
+            labels: Vec::new(),
+            tree: clustering::Dendrogram {
+                leaves: 0,
+                merges: Vec::new(),
+            },
+            orientation,
+            color: [31, 119, 180],
+            config,
+        }
+    }
+
+    /// Sets the leaves and the linkage joining them, e.g. computed by another clustering
+    /// library.
+    ///
+    /// # Parameters
+    /// - `labels`: The labels of the leaves; leaf `i` is node `i`.
+    /// - `merges`: The merges as `(left, right, distance)`, in the order they were performed;
+    ///   merge `i` creates node `labels.len() + i`.
+    ///
+    /// # Errors
+    /// Returns an error if the merges do not join all leaves into one tree; the dendrogram
+    /// is left unchanged.
+    pub fn set_linkage(
+        &mut self,
+        labels: &[&str],
+        merges: &[(usize, usize, f64)],
+    ) -> Result<(), String> {
+        self.tree = clustering::Dendrogram::from_linkage(labels.len(), merges)?;
+        self.labels = labels.iter().map(|label| label.to_string()).collect();
+        Ok(())
+    }
+
+    /// Sets the leaves and a clustering computed by `clustering::cluster`.
+    ///
+    /// # Parameters
+    /// - `labels`: The labels of the clustered rows, in their original order.
+    /// - `tree`: The clustering of the rows.
+    ///
+    /// # Errors
+    /// Returns an error if the number of labels differs from the number of leaves.
+    pub fn set_tree(
+        &mut self,
+        labels: &[&str],
+        tree: clustering::Dendrogram,
+    ) -> Result<(), String> {
+        if labels.len() != tree.leaves {
+            return Err(format!(
+                "Expected {} labels, got {}",
+                tree.leaves,
+                labels.len()
+            ));
+        }
+        self.tree = tree;
+        self.labels = labels.iter().map(|label| label.to_string()).collect();
+        Ok(())
+    }
+
+    /// Sets the color of the tree lines.
+    ///
+    /// # Parameters
+    /// - `color`: The RGB color.
+    pub fn set_color(&mut self, color: [u8; 3]) {
+        self.color = color;
+    }
+
+    /// Returns the label of a leaf, or its id if it has none.
+    pub fn leaf_label(&self, leaf: usize) -> String {
+        self.labels
+            .get(leaf)
+            .cloned()
+            .unwrap_or_else(|| leaf.to_string())
+    }
+
+    /// Returns the top of the distance axis: the height of the tree, or `1.0` for a tree
+    /// without positive merge distances.
+    pub fn distance_max(&self) -> f64 {
+        let height = self.tree.height();
+        if height > 0.0 {
+            height
+        } else {
+            1.0
+        }
+    }
+
+    /// Computes the plot area on a canvas: the area inside the margins.
+    ///
+    /// # Returns
+    /// A tuple `(left, top, right, bottom)`.
+    pub fn plot_area(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64, f64) {
+        (margin, margin, width - margin, height - margin)
+    }
+
+    /// Maps a point in dendrogram units to canvas coordinates.
+    ///
+    /// # Parameters
+    /// - `position`: The position along the leaves, as in `clustering::Dendrogram::segments`.
+    /// - `distance`: The merge distance.
+    /// - `area`: The plot area, as returned by `plot_area`.
+    ///
+    /// # Returns
+    /// The `(x, y)` canvas coordinates.
+    pub fn to_canvas(
+        &self,
+        position: f64,
+        distance: f64,
+        (left, top, right, bottom): (f64, f64, f64, f64),
+    ) -> (f64, f64) {
+        let leaves = self.tree.leaves.max(1) as f64;
+        let along = position / leaves;
+        let up = distance / self.distance_max();
+        match self.orientation {
+            Orientation::Vertical => (left + along * (right - left), bottom - up * (bottom - top)),
+            Orientation::Horizontal => (right - up * (right - left), top + along * (bottom - top)),
+        }
+    }
+
+    /// Finds the node closest to a point on the canvas.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas coordinates.
+    /// - `area`: The plot area, as returned by `plot_area`.
+    /// - `max_distance`: The largest distance in pixels at which a node is found.
+    ///
+    /// # Returns
+    /// The id of the node, or `None` if no node is close enough.
+    pub fn node_at(
+        &self,
+        x: f64,
+        y: f64,
+        area: (f64, f64, f64, f64),
+        max_distance: f64,
+    ) -> Option<usize> {
+        self.tree
+            .node_positions()
+            .into_iter()
+            .map(|(position, distance)| {
+                let (px, py) = self.to_canvas(position, distance, area);
+                (px - x).hypot(py - y)
+            })
+            .enumerate()
+            .filter(|&(_, distance)| distance <= max_distance)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(node, _)| node)
+    }
+
+    /// Describes a node for tooltips: a leaf by its label, a merge by its size and distance.
+    pub fn node_tooltip(&self, node: usize) -> String {
+        match node.checked_sub(self.tree.leaves) {
+            None => self.leaf_label(node),
+            Some(index) => {
+                let merge = &self.tree.merges[index];
+                format!("{} items merged at {:.3}", merge.size, merge.distance)
+            }
+        }
+    }
+}
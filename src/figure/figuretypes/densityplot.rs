@@ -0,0 +1,186 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    datasets::densityplotdataset::DensityPlotDataset,
+    utilities::{scaling::data_bounds, seriesvisibility::SeriesVisibility},
+};
+
+/// Number of values each density curve is evaluated at.
+pub const DENSITY_POINTS: usize = 200;
+
+/// Number of bandwidths the value range extends beyond the samples, so curves reach zero.
+const TAIL_BANDWIDTHS: f64 = 3.0;
+
+/// Computes the value range shared by density curves: all samples, extended by three
+/// bandwidths on both sides so that the tails of the curves reach zero.
+///
+/// # Parameters
+/// - `datasets`: The sample sets drawn as curves.
+///
+/// # Returns
+/// A tuple `(min, max)`. Defaults to `(0.0, 1.0)` when there are no samples.
+pub fn density_value_range<'a>(
+    datasets: impl Iterator<Item = &'a DensityPlotDataset> + Clone,
+) -> (f64, f64) {
+    let values = datasets
+        .clone()
+        .flat_map(|dataset| dataset.samples.iter().copied())
+        .filter(|value| value.is_finite())
+        .map(|value| (value, 0.0));
+    let tail = datasets
+        .filter_map(|dataset| dataset.effective_bandwidth())
+        .fold(0.0, f64::max)
+        * TAIL_BANDWIDTHS;
+    match data_bounds(values) {
+        None => (0.0, 1.0),
+        Some((min, max, _, _)) if min == max && tail == 0.0 => (min - 1.0, max + 1.0),
+        Some((min, max, _, _)) => (min - tail, max + tail),
+    }
+}
+
+/// Represents a density plot: smooth curves estimating the distribution of raw samples with
+/// a Gaussian kernel, drawn as filled areas over a value axis.
+///
+/// It complements `Histogram`; to draw a density curve over the bars of a histogram instead,
+/// see `Histogram::set_density_overlay`.
+#[derive(Clone)]
+pub struct DensityPlot {
+    /// Title of the plot.
+    pub title: String,
+    /// Label of the value axis.
+    pub x_label: String,
+    /// Label of the density axis.
+    pub y_label: String,
+    /// The sample sets, one curve each.
+    pub datasets: Vec<DensityPlotDataset>,
+    /// Whether the areas under the curves are filled.
+    pub fill: bool,
+    /// Opacity of the fills (0.0 to 1.0), so overlapping curves stay visible.
+    pub fill_opacity: f64,
+    /// Configuration settings for rendering the plot (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl DensityPlot {
+    /// Creates a new `DensityPlot` instance with the specified title, axis labels, and
+    /// configuration.
+    ///
+    /// Curves are filled at 35% opacity.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the plot.
+    /// - `x_label`: The label of the value axis.
+    /// - `y_label`: The label of the density axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `DensityPlot` instance without datasets.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::densityplot::DensityPlot;
+    ///
+    /// let mut plot = DensityPlot::new("Response Times", "ms", "Density", config);
+    /// plot.add_dataset(latencies);
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            datasets: Vec::new(),
+            fill: true,
+            fill_opacity: 0.35,
+            config,
+        }
+    }
+
+    /// Adds a sample set, drawn as one curve.
+    ///
+    /// # Parameters
+    /// - `dataset`: The `DensityPlotDataset` holding the samples.
+    pub fn add_dataset(&mut self, dataset: DensityPlotDataset) {
+        self.datasets.push(dataset);
+    }
+
+    /// Returns the sample sets that are drawn, in order.
+    pub fn visible_datasets(&self) -> impl Iterator<Item = &DensityPlotDataset> + Clone {
+        self.datasets.iter().filter(|d| d.visible)
+    }
+
+    /// Computes the value range of the plot: the samples of the visible datasets, extended by
+    /// three bandwidths on both sides so that the tails of the curves reach zero.
+    ///
+    /// # Returns
+    /// A tuple `(min, max)`. Defaults to `(0.0, 1.0)` when there are no samples.
+    pub fn value_range(&self) -> (f64, f64) {
+        density_value_range(self.visible_datasets())
+    }
+
+    /// Evaluates the density curve of every dataset over the value range.
+    ///
+    /// # Returns
+    /// One curve of `(value, density)` pairs per dataset, empty for hidden datasets and
+    /// datasets whose density cannot be estimated.
+    pub fn curves(&self) -> Vec<Vec<(f64, f64)>> {
+        let range = self.value_range();
+        self.datasets
+            .iter()
+            .map(|dataset| {
+                if dataset.visible {
+                    dataset.density(range, DENSITY_POINTS)
+                } else {
+                    Vec::new()
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the top of the density axis: the highest density of any curve, padded by 5%.
+    ///
+    /// # Returns
+    /// The top, or `1.0` when no curve has a density.
+    pub fn density_max(&self) -> f64 {
+        let max = self
+            .curves()
+            .iter()
+            .flatten()
+            .map(|&(_, density)| density)
+            .fold(0.0, f64::max);
+        if max > 0.0 {
+            max * 1.05
+        } else {
+            1.0
+        }
+    }
+
+    /// Finds the curve closest to a point, measured vertically at its value.
+    ///
+    /// # Parameters
+    /// - `value`: The value on the x-axis.
+    /// - `density`: The density on the y-axis.
+    ///
+    /// # Returns
+    /// The index of the dataset and its density at `value`, or `None` if `value` lies
+    /// outside the value range or no curve has a density.
+    pub fn closest_curve(&self, value: f64, density: f64) -> Option<(usize, f64)> {
+        let (min, max) = self.value_range();
+        if !(min..=max).contains(&value) {
+            return None;
+        }
+        self.datasets
+            .iter()
+            .enumerate()
+            .filter(|(_, dataset)| dataset.visible)
+            .filter_map(|(index, dataset)| Some((index, dataset.density_at(value)?)))
+            .min_by(|a, b| (a.1 - density).abs().total_cmp(&(b.1 - density).abs()))
+    }
+}
+
+impl SeriesVisibility for DensityPlot {
+    fn series_visibility(&mut self) -> Vec<(&str, &mut bool)> {
+        self.datasets
+            .iter_mut()
+            .map(|dataset| (dataset.label.as_str(), &mut dataset.visible))
+            .collect()
+    }
+}
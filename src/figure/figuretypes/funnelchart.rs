@@ -0,0 +1,322 @@
+use crate::figure::configuration::figureconfig::FigureConfig;
+
+/// A stage of a funnel, such as a step of a conversion process.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FunnelStage {
+    /// The label of the stage, drawn on its segment.
+    pub label: String,
+    /// The value of the stage, e.g. the number of users reaching it.
+    pub value: f64,
+    /// The fill color of the stage's segment.
+    pub color: Option<[u8; 3]>,
+}
+
+/// The shape of the segments of a funnel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FunnelShape {
+    /// Each segment narrows from its own width to the width of the next stage, so the
+    /// segments join into one funnel.
+    Trapezoid,
+    /// Each segment is a bar as wide as its stage, centered on the funnel's axis.
+    Bars,
+}
+
+/// A laid out stage: a segment of the funnel, symmetric around a vertical axis.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FunnelSegment {
+    /// Index of the stage.
+    pub index: usize,
+    /// The x-coordinate of the funnel's axis, relative to the plot area.
+    pub center_x: f64,
+    /// Top edge, relative to the plot area.
+    pub y0: f64,
+    /// Bottom edge, relative to the plot area.
+    pub y1: f64,
+    /// Width of the top edge.
+    pub top_width: f64,
+    /// Width of the bottom edge.
+    pub bottom_width: f64,
+}
+
+impl FunnelSegment {
+    /// Returns the width of the segment at a height, interpolated between its edges.
+    pub fn width_at(&self, y: f64) -> f64 {
+        if self.y1 <= self.y0 {
+            return self.top_width;
+        }
+        let t = ((y - self.y0) / (self.y1 - self.y0)).clamp(0.0, 1.0);
+        self.top_width + (self.bottom_width - self.top_width) * t
+    }
+
+    /// Returns the corners of the segment offset by `(dx, dy)`, clockwise from the top left.
+    pub fn polygon(&self, dx: f64, dy: f64) -> Vec<(f64, f64)> {
+        let cx = self.center_x + dx;
+        vec![
+            (cx - self.top_width / 2.0, self.y0 + dy),
+            (cx + self.top_width / 2.0, self.y0 + dy),
+            (cx + self.bottom_width / 2.0, self.y1 + dy),
+            (cx - self.bottom_width / 2.0, self.y1 + dy),
+        ]
+    }
+
+    /// Checks whether a point relative to the plot area lies on the segment.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        (self.y0..=self.y1).contains(&y) && (x - self.center_x).abs() <= self.width_at(y) / 2.0
+    }
+}
+
+/// Represents a funnel chart: ordered stages drawn top to bottom as segments whose widths
+/// are proportional to their values, annotated with each value as a percentage of the
+/// first stage.
+///
+/// The widest stage spans the plot area, minus the column of percentage annotations on
+/// the right.
+#[derive(Clone)]
+pub struct FunnelChart {
+    /// Title of the chart.
+    pub title: String,
+    /// The stages, from the top of the funnel.
+    pub stages: Vec<FunnelStage>,
+    /// The shape of the segments.
+    pub shape: FunnelShape,
+    /// Vertical space between segments, in pixels.
+    pub stage_gap: f64,
+    /// Width of the column of percentage annotations right of the funnel, in pixels.
+    pub annotation_width: f64,
+    /// Whether segments show their stage labels and values.
+    pub show_labels: bool,
+    /// Whether stages are annotated with their value as a percentage of the first stage.
+    pub show_percentages: bool,
+    /// The number of decimal places of values in labels and tooltips.
+    pub precision: usize,
+    /// Configuration settings for rendering the chart (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl FunnelChart {
+    /// Colors assigned to stages without their own color, in order.
+    pub const STAGE_COLORS: [[u8; 3]; 8] = [
+        [31, 119, 180],
+        [255, 127, 14],
+        [44, 160, 44],
+        [214, 39, 40],
+        [148, 103, 189],
+        [140, 86, 75],
+        [227, 119, 194],
+        [23, 190, 207],
+    ];
+
+    /// Creates a new `FunnelChart` instance with the specified title and configuration.
+    ///
+    /// Segments are trapezoids 4 pixels apart, with a 60 pixel column of percentages.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the chart.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `FunnelChart` instance without stages.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::funnelchart::FunnelChart;
+    ///
+    /// let mut funnel = FunnelChart::new("Signups", config);
+    /// funnel.add_stage("Visits", 12000.0)?;
+    /// funnel.add_stage("Signups", 3100.0)?;
+    /// funnel.add_stage("Activated", 1800.0)?;
+    /// funnel.add_stage("Paying", 420.0)?;
+    /// ```
+    pub fn new(title: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            stages: Vec::new(),
+            shape: FunnelShape::Trapezoid,
+            stage_gap: 4.0,
+            annotation_width: 60.0,
+            show_labels: true,
+            show_percentages: true,
+            precision: 0,
+            config,
+        }
+    }
+
+    /// Adds a stage below the existing ones.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the stage.
+    /// - `value`: The value of the stage.
+    ///
+    /// # Errors
+    /// Returns an error, without adding the stage, if the value is negative or not finite.
+    pub fn add_stage(&mut self, label: &str, value: f64) -> Result<(), String> {
+        if !value.is_finite() || value < 0.0 {
+            return Err(format!(
+                "Value of stage '{label}' must be non-negative, got {value}"
+            ));
+        }
+        self.stages.push(FunnelStage {
+            label: label.to_string(),
+            value,
+            color: None,
+        });
+        Ok(())
+    }
+
+    /// Sets the fill color of a stage.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the stage.
+    /// - `color`: The RGB color.
+    ///
+    /// # Errors
+    /// Returns an error if no stage has the label.
+    pub fn set_stage_color(&mut self, label: &str, color: [u8; 3]) -> Result<(), String> {
+        let stage = self
+            .stages
+            .iter_mut()
+            .find(|stage| stage.label == label)
+            .ok_or_else(|| format!("No stage labelled '{label}'"))?;
+        stage.color = Some(color);
+        Ok(())
+    }
+
+    /// Returns the color of a stage: its own or one of `STAGE_COLORS` by index.
+    pub fn stage_color(&self, index: usize) -> [u8; 3] {
+        self.stages[index]
+            .color
+            .unwrap_or(Self::STAGE_COLORS[index % Self::STAGE_COLORS.len()])
+    }
+
+    /// Computes the value of a stage as a percentage of the first stage.
+    ///
+    /// # Returns
+    /// The percentage, or `None` if the first stage is zero.
+    pub fn percent_of_first(&self, index: usize) -> Option<f64> {
+        let first = self.stages.first()?.value;
+        (first > 0.0).then(|| self.stages[index].value / first * 100.0)
+    }
+
+    /// Computes the value of a stage as a percentage of the stage above it.
+    ///
+    /// # Returns
+    /// The percentage, or `None` for the first stage or if the stage above is zero.
+    pub fn percent_of_previous(&self, index: usize) -> Option<f64> {
+        let previous = self.stages.get(index.checked_sub(1)?)?.value;
+        (previous > 0.0).then(|| self.stages[index].value / previous * 100.0)
+    }
+
+    /// Formats the label drawn on a stage's segment: its label and value.
+    pub fn stage_label(&self, index: usize) -> String {
+        let stage = &self.stages[index];
+        format!(
+            "{}: {}",
+            stage.label,
+            self.config
+                .format
+                .format_number(stage.value, self.precision)
+        )
+    }
+
+    /// Formats the percentage annotation of a stage, or `None` if the first stage is zero.
+    pub fn percentage_label(&self, index: usize) -> Option<String> {
+        self.percent_of_first(index)
+            .map(|percent| self.config.format.format_percent(percent, 1))
+    }
+
+    /// Formats the tooltip of a stage: its label and value with its share of the first and
+    /// of the previous stage.
+    pub fn stage_tooltip(&self, index: usize) -> String {
+        let shares: Vec<String> = [
+            self.percent_of_first(index)
+                .filter(|_| index > 0)
+                .map(|percent| {
+                    format!("{} of first", self.config.format.format_percent(percent, 1))
+                }),
+            self.percent_of_previous(index).map(|percent| {
+                format!(
+                    "{} of previous",
+                    self.config.format.format_percent(percent, 1)
+                )
+            }),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if shares.is_empty() {
+            self.stage_label(index)
+        } else {
+            format!("{} ({})", self.stage_label(index), shares.join(", "))
+        }
+    }
+
+    /// Lays out the segments of the funnel.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The size of the plot area in pixels.
+    ///
+    /// # Returns
+    /// The segments relative to the plot area, from the top, or none if no stage has a
+    /// positive value.
+    pub fn layout(&self, width: f64, height: f64) -> Vec<FunnelSegment> {
+        let max_value = self
+            .stages
+            .iter()
+            .map(|stage| stage.value)
+            .fold(0.0, f64::max);
+        if max_value <= 0.0 {
+            return Vec::new();
+        }
+
+        let count = self.stages.len() as f64;
+        let funnel_width = if self.show_percentages {
+            (width - self.annotation_width).max(0.0)
+        } else {
+            width
+        };
+        let segment_height = ((height - self.stage_gap * (count - 1.0)) / count).max(0.0);
+        let widths: Vec<f64> = self
+            .stages
+            .iter()
+            .map(|stage| stage.value / max_value * funnel_width)
+            .collect();
+
+        widths
+            .iter()
+            .enumerate()
+            .map(|(index, &top_width)| {
+                let y0 = index as f64 * (segment_height + self.stage_gap);
+                let bottom_width = match self.shape {
+                    // The last segment has no stage below it to narrow to
+                    FunnelShape::Trapezoid => *widths.get(index + 1).unwrap_or(&top_width),
+                    FunnelShape::Bars => top_width,
+                };
+                FunnelSegment {
+                    index,
+                    center_x: funnel_width / 2.0,
+                    y0,
+                    y1: y0 + segment_height,
+                    top_width,
+                    bottom_width,
+                }
+            })
+            .collect()
+    }
+
+    /// Finds the segment at a position.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The position relative to the plot area.
+    /// - `segments`: The layout of the chart.
+    ///
+    /// # Returns
+    /// The segment under the position, or `None` if the position is empty.
+    pub fn segment_at<'a>(
+        &self,
+        x: f64,
+        y: f64,
+        segments: &'a [FunnelSegment],
+    ) -> Option<&'a FunnelSegment> {
+        segments.iter().find(|segment| segment.contains(x, y))
+    }
+}
@@ -0,0 +1,236 @@
+use crate::figure::{configuration::figureconfig::FigureConfig, utilities::axistype::AxisType};
+
+/// A task of a Gantt chart: a named time span, optionally belonging to a group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GanttTask {
+    /// The name of the task, shown on the y-axis.
+    pub name: String,
+    /// The start of the task as a Unix timestamp in seconds.
+    pub start: f64,
+    /// The end of the task as a Unix timestamp in seconds.
+    pub end: f64,
+    /// The group of the task (e.g. a phase or team), colored and listed in the legend.
+    pub group: Option<String>,
+    /// The color of the task's bar, overriding its group color.
+    pub color: Option<[u8; 3]>,
+}
+
+impl GanttTask {
+    /// Creates a task without group or color.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the task.
+    /// - `start`, `end`: The time span as Unix timestamps in seconds.
+    pub fn new(name: &str, start: f64, end: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            start,
+            end,
+            group: None,
+            color: None,
+        }
+    }
+
+    /// Assigns the task to a group.
+    ///
+    /// # Parameters
+    /// - `group`: The name of the group.
+    pub fn set_group(&mut self, group: &str) {
+        self.group = Some(group.to_string());
+    }
+
+    /// Sets the color of the task's bar.
+    ///
+    /// # Parameters
+    /// - `color`: The RGB color, used instead of the group color.
+    pub fn set_color(&mut self, color: [u8; 3]) {
+        self.color = Some(color);
+    }
+
+    /// Returns the duration of the task in seconds.
+    pub fn duration(&self) -> f64 {
+        self.end - self.start
+    }
+}
+
+/// Represents a Gantt chart: one horizontal bar per task on a time axis, with the task
+/// names listed from top to bottom on a categorical y-axis.
+///
+/// Times are Unix timestamps in seconds, shown in the configured time zone with the
+/// pattern of `time_pattern` unless a datetime format is set for the x-axis.
+#[derive(Clone)]
+pub struct GanttChart {
+    /// Title of the chart.
+    pub title: String,
+    /// Label for the X-axis (the time axis).
+    pub x_label: String,
+    /// Label for the Y-axis (the task axis).
+    pub y_label: String,
+    /// The tasks, drawn as rows from top to bottom.
+    pub tasks: Vec<GanttTask>,
+    /// Color of tasks without a group or color of their own.
+    pub default_color: [u8; 3],
+    /// Pattern of the time tick labels (see `TimeZone::format`); `None` uses the date format
+    /// of the format context.
+    pub time_format: Option<String>,
+    /// Configuration settings for rendering the chart (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl GanttChart {
+    /// Colors assigned to the groups in order of their first task.
+    pub const GROUP_COLORS: [[u8; 3]; 8] = [
+        [31, 119, 180],
+        [255, 127, 14],
+        [44, 160, 44],
+        [214, 39, 40],
+        [148, 103, 189],
+        [140, 86, 75],
+        [227, 119, 194],
+        [23, 190, 207],
+    ];
+
+    /// Height of a bar as a fraction of its row.
+    pub const BAR_HEIGHT: f64 = 0.6;
+
+    /// Creates a new `GanttChart` instance with the specified title, labels, and
+    /// configuration.
+    ///
+    /// Time ticks are labelled with the date format of the configuration's format context,
+    /// or as `"%d %b"` without one.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the chart.
+    /// - `x_label`: The label for the time axis.
+    /// - `y_label`: The label for the task axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `GanttChart` instance without tasks.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::ganttchart::{GanttChart, GanttTask};
+    ///
+    /// let day = 86_400.0;
+    /// let mut chart = GanttChart::new("Release Plan", "Date", "", config);
+    /// let mut design = GanttTask::new("Design", start, start + 5.0 * day);
+    /// design.set_group("Phase 1");
+    /// chart.add_task(design);
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            tasks: Vec::new(),
+            default_color: [31, 119, 180],
+            time_format: None,
+            config,
+        }
+    }
+
+    /// Adds a task as the next row.
+    ///
+    /// # Parameters
+    /// - `task`: The task; tasks with an end before their start are drawn reversed.
+    pub fn add_task(&mut self, task: GanttTask) {
+        self.tasks.push(task);
+    }
+
+    /// Sets the pattern of the time tick labels.
+    ///
+    /// # Parameters
+    /// - `pattern`: The format, e.g. `"%d %b"` or `"%H:%M"` (see `TimeZone::format`).
+    pub fn set_time_format(&mut self, pattern: &str) {
+        self.time_format = Some(pattern.to_string());
+    }
+
+    /// Returns the pattern of the time tick labels: the chart's time format, the date format
+    /// of the format context, or `"%d %b"`.
+    pub fn time_pattern(&self) -> &str {
+        self.time_format
+            .as_deref()
+            .or(self.config.format.date_format.as_deref())
+            .unwrap_or("%d %b")
+    }
+
+    /// Returns the groups in order of their first task.
+    pub fn groups(&self) -> Vec<&str> {
+        let mut groups: Vec<&str> = Vec::new();
+        for group in self.tasks.iter().filter_map(|task| task.group.as_deref()) {
+            if !groups.contains(&group) {
+                groups.push(group);
+            }
+        }
+        groups
+    }
+
+    /// Returns the color of a group, or the default color for unknown groups.
+    pub fn group_color(&self, group: &str) -> [u8; 3] {
+        self.groups()
+            .iter()
+            .position(|&known| known == group)
+            .map_or(self.default_color, |index| {
+                Self::GROUP_COLORS[index % Self::GROUP_COLORS.len()]
+            })
+    }
+
+    /// Returns the color of a task's bar: its own color, its group color, or the default.
+    pub fn task_color(&self, task: &GanttTask) -> [u8; 3] {
+        task.color.unwrap_or_else(|| match &task.group {
+            Some(group) => self.group_color(group),
+            None => self.default_color,
+        })
+    }
+
+    /// Computes the time range of the x-axis, covering all tasks with finite times.
+    pub fn time_range(&self) -> (f64, f64) {
+        let (min, max) = self
+            .tasks
+            .iter()
+            .flat_map(|task| [task.start, task.end])
+            .filter(|time| time.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), time| {
+                (min.min(time), max.max(time))
+            });
+        if !min.is_finite() {
+            (0.0, 1.0)
+        } else if max > min {
+            (min, max)
+        } else {
+            (min, min + 1.0)
+        }
+    }
+
+    /// Returns the top and bottom edge of a task's bar, as fractions of the plot height
+    /// measured downwards from the top.
+    pub fn bar_edges(&self, index: usize) -> (f64, f64) {
+        let row = 1.0 / self.tasks.len().max(1) as f64;
+        let center = (index as f64 + 0.5) * row;
+        (
+            center - row * Self::BAR_HEIGHT / 2.0,
+            center + row * Self::BAR_HEIGHT / 2.0,
+        )
+    }
+
+    /// Finds the task whose row contains a position.
+    ///
+    /// # Parameters
+    /// - `y`: The position as a fraction of the plot height, measured downwards from the
+    ///   top.
+    pub fn task_at(&self, y: f64) -> Option<usize> {
+        if !(0.0..1.0).contains(&y) || self.tasks.is_empty() {
+            return None;
+        }
+        Some(((y * self.tasks.len() as f64) as usize).min(self.tasks.len() - 1))
+    }
+
+    /// Formats a time in the configured time zone, with the x-axis datetime format if one
+    /// is set and `time_pattern` otherwise.
+    pub fn format_time(&self, time: f64) -> String {
+        self.config
+            .format_datetime_tick(AxisType::AxisX, time)
+            .unwrap_or_else(|| self.config.time_zone.format(time, self.time_pattern()))
+    }
+}
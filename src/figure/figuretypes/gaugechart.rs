@@ -0,0 +1,304 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    utilities::{arcgeometry::angle_of, bezierpath::BezierPath},
+};
+
+/// A colored range of a gauge's dial, e.g. a warning or critical range.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GaugeZone {
+    /// Name of the zone, shown in tooltips.
+    pub label: String,
+    /// The start of the zone, in the units of the gauge.
+    pub from: f64,
+    /// The end of the zone.
+    pub to: f64,
+    /// The RGB color of the zone.
+    pub color: [u8; 3],
+}
+
+/// Placement of a gauge's dial on a canvas.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GaugeLayout {
+    /// Center of the dial.
+    pub center: (f64, f64),
+    /// Outer radius of the dial.
+    pub radius: f64,
+    /// Inner radius of the dial's band.
+    pub inner: f64,
+}
+
+/// Represents a gauge chart: a single value shown by a needle on a circular dial, with
+/// colored zones and the range's minimum and maximum labelled at the dial's ends.
+///
+/// The dial opens downwards, sweeping `sweep_angle` clockwise from the minimum at its
+/// lower left to the maximum at its lower right. Values outside the range pin the needle
+/// to the nearest end.
+#[derive(Clone)]
+pub struct GaugeChart {
+    /// Title of the chart.
+    pub title: String,
+    /// The value the needle points at.
+    pub value: f64,
+    /// The value at the start of the dial.
+    pub min: f64,
+    /// The value at the end of the dial.
+    pub max: f64,
+    /// Units appended to the value below the needle, e.g. `"km/h"`.
+    pub units: String,
+    /// Colored ranges of the dial, drawn in order over the track.
+    pub zones: Vec<GaugeZone>,
+    /// Angle in radians the dial sweeps from its minimum to its maximum.
+    pub sweep_angle: f64,
+    /// Width of the dial's band as a fraction of its radius.
+    pub band_width: f64,
+    /// Color of the dial where no zone is set.
+    pub track_color: [u8; 3],
+    /// Color of the needle and its hub.
+    pub needle_color: [u8; 3],
+    /// The number of decimal places of the value.
+    pub precision: usize,
+    /// Configuration settings for rendering the chart (e.g., colors, fonts).
+    pub config: FigureConfig,
+}
+
+impl GaugeChart {
+    /// Length of the needle as a fraction of the inner radius of the band.
+    const NEEDLE_LENGTH: f64 = 0.9;
+
+    /// Distance of the value text below the center, as a fraction of the radius.
+    pub const VALUE_DEPTH: f64 = 0.35;
+
+    /// Room below the dial for the min/max labels, as a fraction of the radius.
+    const LABEL_ROOM: f64 = 0.15;
+
+    /// Creates a new `GaugeChart` instance with the specified title and configuration.
+    ///
+    /// The dial ranges from 0 to 100 over 240 degrees, with a gray track and a dark needle
+    /// at 0.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the chart.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `GaugeChart` instance without zones.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::gaugechart::GaugeChart;
+    ///
+    /// let mut gauge = GaugeChart::new("CPU Load", config);
+    /// gauge.set_units("%");
+    /// gauge.add_zone("Normal", 0.0, 60.0, [44, 160, 44])?;
+    /// gauge.add_zone("High", 60.0, 85.0, [255, 127, 14])?;
+    /// gauge.add_zone("Critical", 85.0, 100.0, [214, 39, 40])?;
+    /// gauge.set_value(72.5);
+    /// ```
+    pub fn new(title: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            value: 0.0,
+            min: 0.0,
+            max: 100.0,
+            units: String::new(),
+            zones: Vec::new(),
+            sweep_angle: 240f64.to_radians(),
+            band_width: 0.2,
+            track_color: [220, 220, 220],
+            needle_color: [60, 60, 60],
+            precision: 1,
+            config,
+        }
+    }
+
+    /// Sets the value the needle points at.
+    pub fn set_value(&mut self, value: f64) {
+        self.value = value;
+    }
+
+    /// Sets the range of the dial.
+    ///
+    /// # Parameters
+    /// - `min`: The value at the start of the dial.
+    /// - `max`: The value at the end of the dial.
+    ///
+    /// # Errors
+    /// Returns an error if the bounds are not finite or `min` is not below `max`; the
+    /// range is left unchanged.
+    pub fn set_range(&mut self, min: f64, max: f64) -> Result<(), String> {
+        if !(min.is_finite() && max.is_finite() && min < max) {
+            return Err(format!("Invalid gauge range {min} to {max}"));
+        }
+        self.min = min;
+        self.max = max;
+        Ok(())
+    }
+
+    /// Sets the units appended to the value.
+    pub fn set_units(&mut self, units: &str) {
+        self.units = units.to_string();
+    }
+
+    /// Sets the angle the dial sweeps.
+    ///
+    /// # Parameters
+    /// - `degrees`: The sweep in degrees, clamped to between 30 and 360.
+    pub fn set_sweep_degrees(&mut self, degrees: f64) {
+        self.sweep_angle = degrees.clamp(30.0, 360.0).to_radians();
+    }
+
+    /// Adds a colored zone to the dial. Zones added later are drawn over earlier ones.
+    ///
+    /// # Parameters
+    /// - `label`: The name of the zone.
+    /// - `from`, `to`: The range of the zone; parts outside the dial's range are not drawn.
+    /// - `color`: The RGB color of the zone.
+    ///
+    /// # Errors
+    /// Returns an error if the bounds are not finite or `from` is not below `to`.
+    pub fn add_zone(
+        &mut self,
+        label: &str,
+        from: f64,
+        to: f64,
+        color: [u8; 3],
+    ) -> Result<(), String> {
+        if !(from.is_finite() && to.is_finite() && from < to) {
+            return Err(format!("Invalid range {from} to {to} of zone '{label}'"));
+        }
+        self.zones.push(GaugeZone {
+            label: label.to_string(),
+            from,
+            to,
+            color,
+        });
+        Ok(())
+    }
+
+    /// Returns the angle of the start of the dial, clockwise on screen from the positive
+    /// x-axis, so the dial opens symmetrically downwards.
+    pub fn start_angle(&self) -> f64 {
+        FRAC_PI_2 + (TAU - self.sweep_angle) / 2.0
+    }
+
+    /// Maps a value to its angle on the dial, clamping it to the dial's range.
+    pub fn value_angle(&self, value: f64) -> f64 {
+        let t = if self.max > self.min {
+            ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        self.start_angle() + t * self.sweep_angle
+    }
+
+    /// Maps an angle on the dial back to its value.
+    ///
+    /// # Returns
+    /// The value, or `None` if the angle lies in the dial's opening.
+    pub fn angle_value(&self, angle: f64) -> Option<f64> {
+        let offset = (angle - self.start_angle()).rem_euclid(TAU);
+        (offset <= self.sweep_angle)
+            .then(|| self.min + offset / self.sweep_angle * (self.max - self.min))
+    }
+
+    /// Places the dial on a canvas: as large as fits between the margins, with room below
+    /// the center for the value and below the ends of the dial for the min/max labels.
+    ///
+    /// # Parameters
+    /// - `width`, `height`, `margin`: The size and margin of the canvas in pixels.
+    pub fn layout(&self, width: f64, height: f64, margin: f64) -> GaugeLayout {
+        // Depth of the ends of the dial below its center, as a fraction of the radius,
+        // and at least the depth of the value below the hub
+        let depth = ((TAU - self.sweep_angle) / 2.0)
+            .cos()
+            .max(Self::VALUE_DEPTH);
+        let extent = 1.0 + depth + Self::LABEL_ROOM;
+        let plot_w = (width - 2.0 * margin).max(0.0);
+        let plot_h = (height - 2.0 * margin).max(0.0);
+        let radius = (plot_w / 2.0).min(plot_h / extent);
+        let center = (
+            width / 2.0,
+            margin + radius + (plot_h - radius * extent) / 2.0,
+        );
+        GaugeLayout {
+            center,
+            radius,
+            inner: radius * (1.0 - self.band_width.clamp(0.0, 1.0)),
+        }
+    }
+
+    /// Builds the band of the dial between two values.
+    pub fn band_path(&self, from: f64, to: f64, layout: &GaugeLayout) -> BezierPath {
+        BezierPath::ring_segment(
+            layout.center,
+            (layout.inner, layout.radius),
+            (self.value_angle(from), self.value_angle(to)),
+        )
+    }
+
+    /// Builds the needle: a narrow triangle from the hub to the value.
+    pub fn needle_path(&self, layout: &GaugeLayout) -> BezierPath {
+        let angle = self.value_angle(self.value);
+        let (cx, cy) = layout.center;
+        let length = layout.inner * Self::NEEDLE_LENGTH;
+        let half_width = (layout.radius * 0.04).max(2.0);
+        let (dx, dy) = (angle.cos(), angle.sin());
+        let mut path = BezierPath::new((cx - dy * half_width, cy + dx * half_width));
+        path.line_to((cx + dx * length, cy + dy * length));
+        path.line_to((cx + dy * half_width, cy - dx * half_width));
+        path
+    }
+
+    /// Returns the radius of the needle's hub.
+    pub fn hub_radius(&self, layout: &GaugeLayout) -> f64 {
+        (layout.radius * 0.07).max(4.0)
+    }
+
+    /// Formats a value with the chart's precision and units, in the format context of its
+    /// configuration.
+    pub fn format_value(&self, value: f64) -> String {
+        self.config
+            .format
+            .format_with_units(value, self.precision, &self.units)
+    }
+
+    /// Returns the zone drawn at a value: the last added zone containing it.
+    pub fn zone_at(&self, value: f64) -> Option<&GaugeZone> {
+        self.zones
+            .iter()
+            .rev()
+            .find(|zone| (zone.from..=zone.to).contains(&value))
+    }
+
+    /// Finds the element of the gauge at a position: the band of the dial, or the needle
+    /// and its hub inside the dial.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The position in canvas coordinates.
+    /// - `layout`: The placement of the dial.
+    ///
+    /// # Returns
+    /// The tooltip of the element and a point on it, or `None` if the position is empty.
+    pub fn element_at(&self, x: f64, y: f64, layout: &GaugeLayout) -> Option<((f64, f64), String)> {
+        let (dx, dy) = (x - layout.center.0, y - layout.center.1);
+        let distance = dx.hypot(dy);
+        if (layout.inner..=layout.radius).contains(&distance) {
+            let value = self.angle_value(angle_of(dx, dy))?;
+            let tooltip = match self.zone_at(value) {
+                Some(zone) => format!(
+                    "{}: {} to {}",
+                    zone.label,
+                    self.format_value(zone.from),
+                    self.format_value(zone.to)
+                ),
+                None => self.format_value(value),
+            };
+            return Some(((x, y), tooltip));
+        }
+        let on_needle = self.needle_path(layout).contains(x, y);
+        (on_needle || distance <= self.hub_radius(layout))
+            .then(|| (layout.center, self.format_value(self.value)))
+    }
+}
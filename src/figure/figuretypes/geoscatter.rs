@@ -0,0 +1,264 @@
+use image::RgbImage;
+
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    datasets::scattergraphdataset::ScatterGraphDataset,
+    utilities::{
+        basemap::Basemap,
+        projection::{fit_bounds, Projection},
+        scaling::clip_segment,
+    },
+};
+
+/// Represents a geographic scatter plot: points given as longitude and latitude, projected
+/// onto a map with an optional basemap image and coastline layer.
+///
+/// Dataset points are `(longitude, latitude)` pairs in degrees. Points outside the map
+/// extent are not drawn.
+#[derive(Clone)]
+pub struct GeoScatter {
+    /// Title of the map.
+    pub title: String,
+    /// The point datasets, with `(longitude, latitude)` points.
+    pub datasets: Vec<ScatterGraphDataset>,
+    /// The projection from longitude and latitude to the plane.
+    pub projection: Projection,
+    /// Coastlines or other reference polylines, as lists of `(longitude, latitude)` points.
+    pub coastlines: Vec<Vec<(f64, f64)>>,
+    /// Color of the coastlines.
+    pub color_coastline: [u8; 3],
+    /// Optional raster image drawn below everything else.
+    pub basemap: Option<Basemap>,
+    /// Explicit map extent as `(lon_min, lon_max, lat_min, lat_max)`, overriding the
+    /// automatic extent.
+    pub extent: Option<(f64, f64, f64, f64)>,
+    /// Configuration settings for rendering the map (e.g., colors, fonts).
+    pub config: FigureConfig,
+}
+
+impl GeoScatter {
+    /// Creates a new `GeoScatter` instance with the specified title, projection, and configuration.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the map.
+    /// - `projection`: The map projection (`Equirectangular` or `Mercator`).
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `GeoScatter` instance with no layers.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::datasets::scattergraphdataset::ScatterGraphDataset;
+    /// use dataviz::figure::figuretypes::geoscatter::GeoScatter;
+    /// use dataviz::figure::utilities::{projection::Projection, scatterdottype::ScatterDotType};
+    ///
+    /// let mut map = GeoScatter::new("Earthquakes", Projection::Mercator, config);
+    /// let mut quakes = ScatterGraphDataset::new([200, 0, 0], "M5+", ScatterDotType::Circle(3));
+    /// quakes.points.push((142.4, 38.3));
+    /// map.add_dataset(quakes);
+    /// map.add_coastline(&coastline);
+    /// ```
+    pub fn new(title: &str, projection: Projection, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            datasets: Vec::new(),
+            projection,
+            coastlines: Vec::new(),
+            color_coastline: [90, 90, 90],
+            basemap: None,
+            extent: None,
+            config,
+        }
+    }
+
+    /// Adds a point dataset to the map.
+    ///
+    /// # Parameters
+    /// - `dataset`: The `ScatterGraphDataset` holding `(longitude, latitude)` points.
+    pub fn add_dataset(&mut self, dataset: ScatterGraphDataset) {
+        self.datasets.push(dataset);
+    }
+
+    /// Adds a coastline, border or other reference polyline.
+    ///
+    /// # Parameters
+    /// - `points`: The polyline as `(longitude, latitude)` points.
+    pub fn add_coastline(&mut self, points: &[(f64, f64)]) {
+        self.coastlines.push(points.to_vec());
+    }
+
+    /// Sets the basemap image drawn below the other layers.
+    ///
+    /// Unless an explicit extent is set, the map then shows the whole basemap.
+    ///
+    /// # Parameters
+    /// - `basemap`: The equirectangular basemap.
+    pub fn set_basemap(&mut self, basemap: Basemap) {
+        self.basemap = Some(basemap);
+    }
+
+    /// Sets the geographic extent shown by the map.
+    ///
+    /// # Parameters
+    /// - `lon_min`, `lon_max`: The longitude range in degrees.
+    /// - `lat_min`, `lat_max`: The latitude range in degrees.
+    pub fn set_extent(&mut self, lon_min: f64, lon_max: f64, lat_min: f64, lat_max: f64) {
+        self.extent = Some((lon_min, lon_max, lat_min, lat_max));
+    }
+
+    /// Computes the geographic extent shown by the map.
+    ///
+    /// The explicit extent is used when set, then the basemap extent, then the extent of the
+    /// points and coastlines padded by 5%. An empty map shows the whole world.
+    ///
+    /// # Returns
+    /// A tuple `(lon_min, lon_max, lat_min, lat_max)` in degrees.
+    pub fn geographic_extent(&self) -> (f64, f64, f64, f64) {
+        if let Some(extent) = self.extent {
+            return extent;
+        }
+        if let Some(basemap) = &self.basemap {
+            return basemap.bounds;
+        }
+
+        let points = self
+            .datasets
+            .iter()
+            .flat_map(|dataset| dataset.points.iter())
+            .chain(self.coastlines.iter().flatten());
+        let (mut lon_min, mut lon_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        let (mut lat_min, mut lat_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        for &(longitude, latitude) in points {
+            (lon_min, lon_max) = (lon_min.min(longitude), lon_max.max(longitude));
+            (lat_min, lat_max) = (lat_min.min(latitude), lat_max.max(latitude));
+        }
+        if lon_min > lon_max {
+            return (-180.0, 180.0, -85.0, 85.0);
+        }
+
+        let lon_padding = ((lon_max - lon_min) * 0.05).max(0.5);
+        let lat_padding = ((lat_max - lat_min) * 0.05).max(0.5);
+        (
+            (lon_min - lon_padding).max(-180.0),
+            (lon_max + lon_padding).min(180.0),
+            (lat_min - lat_padding).max(-90.0),
+            (lat_max + lat_padding).min(90.0),
+        )
+    }
+
+    /// Computes how the map extent is fitted into a canvas, preserving its aspect ratio.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(scale, origin_x, origin_y)`: a projected point `(x, y)` is drawn at
+    /// `(origin_x + x * scale, origin_y - y * scale)`.
+    pub fn layout(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64) {
+        let (lon_min, lon_max, lat_min, lat_max) = self.geographic_extent();
+        let (x_min, y_min) = self.projection.project(lon_min, lat_min);
+        let (x_max, y_max) = self.projection.project(lon_max, lat_max);
+        fit_bounds(
+            (x_min, x_max, y_min, y_max),
+            (
+                margin,
+                margin,
+                (width - 2.0 * margin).max(1.0),
+                (height - 2.0 * margin).max(1.0),
+            ),
+        )
+    }
+
+    /// Computes the canvas rectangle covered by the map extent.
+    ///
+    /// # Parameters
+    /// - `layout`: The layout returned by `layout`.
+    ///
+    /// # Returns
+    /// A tuple `(left, top, right, bottom)` in canvas coordinates.
+    pub fn map_area(&self, layout: (f64, f64, f64)) -> (f64, f64, f64, f64) {
+        let (lon_min, lon_max, lat_min, lat_max) = self.geographic_extent();
+        let (left, bottom) = self.to_canvas((lon_min, lat_min), layout);
+        let (right, top) = self.to_canvas((lon_max, lat_max), layout);
+        (left, top, right, bottom)
+    }
+
+    /// Projects a geographic coordinate onto the canvas.
+    ///
+    /// # Parameters
+    /// - `point`: The `(longitude, latitude)` in degrees.
+    /// - `layout`: The layout returned by `layout`.
+    pub fn to_canvas(&self, point: (f64, f64), layout: (f64, f64, f64)) -> (f64, f64) {
+        let (scale, origin_x, origin_y) = layout;
+        let (x, y) = self.projection.project(point.0, point.1);
+        (origin_x + x * scale, origin_y - y * scale)
+    }
+
+    /// Converts a canvas coordinate back to longitude and latitude.
+    ///
+    /// # Parameters
+    /// - `point`: The canvas coordinate.
+    /// - `layout`: The layout returned by `layout`.
+    pub fn to_geographic(&self, point: (f64, f64), layout: (f64, f64, f64)) -> (f64, f64) {
+        let (scale, origin_x, origin_y) = layout;
+        self.projection
+            .unproject((point.0 - origin_x) / scale, (origin_y - point.1) / scale)
+    }
+
+    /// Checks whether a geographic coordinate lies within the map extent.
+    pub fn contains(&self, longitude: f64, latitude: f64) -> bool {
+        let (lon_min, lon_max, lat_min, lat_max) = self.geographic_extent();
+        (lon_min..=lon_max).contains(&longitude) && (lat_min..=lat_max).contains(&latitude)
+    }
+
+    /// Reprojects the basemap onto the map area of a canvas.
+    ///
+    /// # Parameters
+    /// - `layout`: The layout returned by `layout`.
+    ///
+    /// # Returns
+    /// The top-left corner of the map area and the rendered image covering it, or `None`
+    /// without a basemap. Pixels outside the basemap are left in the background color.
+    pub fn render_basemap(&self, layout: (f64, f64, f64)) -> Option<((f64, f64), RgbImage)> {
+        let basemap = self.basemap.as_ref()?;
+        let (left, top, right, bottom) = self.map_area(layout);
+        let width = (right - left).round().max(1.0) as u32;
+        let height = (bottom - top).round().max(1.0) as u32;
+
+        let mut image =
+            RgbImage::from_pixel(width, height, image::Rgb(self.config.color_background));
+        for (x, y, pixel) in image.enumerate_pixels_mut() {
+            let center = (left + x as f64 + 0.5, top + y as f64 + 0.5);
+            let (longitude, latitude) = self.to_geographic(center, layout);
+            if let Some(color) = basemap.sample(longitude, latitude) {
+                pixel.0 = color;
+            }
+        }
+        Some(((left, top), image))
+    }
+
+    /// Projects the coastlines onto the canvas, clipped to the map area.
+    ///
+    /// # Parameters
+    /// - `layout`: The layout returned by `layout`.
+    ///
+    /// # Returns
+    /// The visible line segments in canvas coordinates.
+    pub fn coastline_segments(&self, layout: (f64, f64, f64)) -> Vec<((f64, f64), (f64, f64))> {
+        let area = self.map_area(layout);
+        self.coastlines
+            .iter()
+            .flat_map(|line| {
+                line.windows(2).filter_map(move |pair| {
+                    clip_segment(
+                        self.to_canvas(pair[0], layout),
+                        self.to_canvas(pair[1], layout),
+                        area,
+                    )
+                })
+            })
+            .collect()
+    }
+}
@@ -1,12 +1,28 @@
 use crate::figure::{
-    canvas::pixelcanvas::PixelCanvas,
+    canvas::{pixelcanvas::PixelCanvas, rendertarget::RenderTarget},
     configuration::figureconfig::FigureConfig,
     datasets::bardataset::BarDataset,
     drawers::drawer::Drawer,
-    utilities::{axistype::AxisType, orientation::Orientation},
+    utilities::{
+        axistype::AxisType,
+        barcolorrule::BarColorRule,
+        barlabelposition::BarLabelPosition,
+        barstacking::BarStacking,
+        colormap::text_color_for,
+        divergingstyle::DivergingStyle,
+        orientation::Orientation,
+        scaling::{scale_factor, tick_values, to_pixel_x, to_pixel_y},
+        seriesvisibility::SeriesVisibility,
+        textmetrics::measure_text_with_fallbacks,
+    },
 };
 
+/// Space in pixels each category needs along the category axis for readable bars, used to
+/// decide how many categories fit on a page.
+pub const MIN_CATEGORY_SPACE: f64 = 24.0;
+
 /// A grouped bar chart representation, supporting horizontal and vertical orientations.
+#[derive(Clone)]
 pub struct GroupBarChart {
     /// A collection of datasets to be visualized in the grouped bar chart.
     pub datasets: Vec<BarDataset>,
@@ -18,6 +34,27 @@ pub struct GroupBarChart {
     pub y_label: String,
     /// Orientation of the bar chart (`Horizontal` or `Vertical`).
     pub orientation: Orientation,
+    /// Whether the datasets of a category are drawn side by side or stacked. Ignored in
+    /// diverging mode.
+    pub stacking: BarStacking,
+    /// Diverging mode: when set, bars grow from the style's baseline, colored by direction,
+    /// with their values printed outside their tips.
+    pub diverging: Option<DivergingStyle>,
+    /// Colors bars by their value, overriding the dataset and diverging colors.
+    pub color_rule: Option<BarColorRule>,
+    /// Whether the legend shows the entries of `color_rule` instead of the datasets.
+    pub rule_legend: bool,
+    /// Placement of the value label drawn on every bar, or `None` for no value labels.
+    /// Diverging charts default to `Outside`.
+    pub value_labels: Option<BarLabelPosition>,
+    /// Whether value labels are rotated by 90° to read along vertical bars.
+    pub rotate_value_labels: bool,
+    /// Fixed range of the value axis, or `None` to fit the data. Pages of a chart share the
+    /// range of the whole chart.
+    pub value_limits: Option<(f64, f64)>,
+    /// Whether horizontal charts draw the value axis above the plot, with the category
+    /// labels left-aligned in a column left of it. Ignored by vertical charts.
+    pub value_axis_top: bool,
     /// Configuration settings for rendering the chart (e.g., colors, fonts, grid).
     pub config: FigureConfig,
 }
@@ -57,6 +94,14 @@ impl GroupBarChart {
             x_label: x_label.to_string(),
             y_label: y_label.to_string(),
             orientation,
+            stacking: BarStacking::Grouped,
+            diverging: None,
+            color_rule: None,
+            rule_legend: false,
+            value_labels: None,
+            rotate_value_labels: false,
+            value_limits: None,
+            value_axis_top: false,
             config,
         }
     }
@@ -77,6 +122,518 @@ impl GroupBarChart {
         self.datasets.push(dataset);
     }
 
+    /// Returns the datasets that are drawn with their indices in `datasets`, in order.
+    pub fn visible_datasets(&self) -> impl Iterator<Item = (usize, &BarDataset)> {
+        self.datasets.iter().enumerate().filter(|(_, d)| d.visible)
+    }
+
+    /// Switches the chart to diverging mode.
+    ///
+    /// Bars grow from `style.baseline` instead of zero: values above it use the positive
+    /// color and extend right (horizontal) or up (vertical), values below it use the
+    /// negative color and extend left or down. Each bar's value is printed just outside its
+    /// tip, and the legend shows the two directions instead of the datasets.
+    ///
+    /// # Parameters
+    /// - `style`: The baseline, colors and legend labels of the two directions.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::divergingstyle::DivergingStyle;
+    ///
+    /// bar_chart.set_diverging(DivergingStyle::new(0.0, [0, 150, 0], [200, 0, 0]));
+    /// ```
+    pub fn set_diverging(&mut self, style: DivergingStyle) {
+        self.diverging = Some(style);
+    }
+
+    /// Colors bars by their value with a rule, e.g. red below a target and green above it.
+    ///
+    /// The rule is applied at render time to every bar, taking precedence over the dataset
+    /// colors and the diverging style colors.
+    ///
+    /// # Parameters
+    /// - `rule`: The threshold or closure rule.
+    /// - `legend`: Whether the legend shows the rule's entries instead of the datasets (or
+    ///   the diverging directions). Ignored if the rule has no legend entries.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::barcolorrule::BarColorRule;
+    ///
+    /// bar_chart.set_color_rule(BarColorRule::target(100.0, [200, 0, 0], [0, 150, 0]), true);
+    /// ```
+    pub fn set_color_rule(&mut self, rule: BarColorRule, legend: bool) {
+        self.color_rule = Some(rule);
+        self.rule_legend = legend;
+    }
+
+    /// Sets whether the datasets of a category are drawn side by side or stacked.
+    ///
+    /// Stacked bars show one segment per dataset, in dataset order from the baseline
+    /// outwards, and the legend keeps one entry per dataset. With `BarStacking::Normalized`,
+    /// every category spans 100 (split between the positive and negative side by the
+    /// absolute values) and value labels show each segment's percentage.
+    ///
+    /// # Parameters
+    /// - `stacking`: The stacking mode.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::barstacking::BarStacking;
+    ///
+    /// bar_chart.set_stacking(BarStacking::Normalized);
+    /// bar_chart.config.set_unit(AxisType::AxisY, "%");
+    /// ```
+    pub fn set_stacking(&mut self, stacking: BarStacking) {
+        self.stacking = stacking;
+    }
+
+    /// Returns whether bars are stacked, i.e. a stacking mode is set and the chart is not
+    /// diverging.
+    pub fn is_stacked(&self) -> bool {
+        self.diverging.is_none() && self.stacking != BarStacking::Grouped
+    }
+
+    /// Returns the number of bars side by side in each category.
+    pub fn bar_slots(&self) -> usize {
+        if self.is_stacked() {
+            1
+        } else {
+            self.visible_datasets().count()
+        }
+    }
+
+    /// Returns the position of a dataset's bar within its category, from `0` to
+    /// `bar_slots() - 1`. Hidden datasets take no position.
+    pub fn bar_slot(&self, dataset_index: usize) -> usize {
+        if self.is_stacked() {
+            0
+        } else {
+            self.visible_datasets()
+                .take_while(|&(index, _)| index < dataset_index)
+                .count()
+        }
+    }
+
+    /// Computes where a bar starts and ends along the value axis.
+    ///
+    /// # Parameters
+    /// - `dataset_index`: The index of the dataset the bar belongs to.
+    /// - `category`: The category of the bar.
+    /// - `value`: The value of the bar.
+    ///
+    /// # Returns
+    /// `(start, end)` in axis units: from the baseline to the value for grouped bars, or
+    /// the segment above (below, for negative values) the earlier visible datasets for
+    /// stacked bars.
+    pub fn bar_span(&self, dataset_index: usize, category: u32, value: f64) -> (f64, f64) {
+        if !self.is_stacked() {
+            return (self.baseline(), value);
+        }
+        let value_in = |(_, dataset): (usize, &BarDataset)| {
+            dataset
+                .data
+                .iter()
+                .find(|(x, _)| *x as u32 == category)
+                .map(|&(_, value)| value)
+        };
+        let scale = match self.stacking {
+            BarStacking::Normalized => {
+                let total: f64 = self
+                    .visible_datasets()
+                    .filter_map(value_in)
+                    .map(f64::abs)
+                    .sum();
+                if total > 0.0 {
+                    100.0 / total
+                } else {
+                    0.0
+                }
+            }
+            _ => 1.0,
+        };
+        let offset: f64 = self
+            .visible_datasets()
+            .take_while(|&(index, _)| index < dataset_index)
+            .filter_map(value_in)
+            .filter(|&below| (below < 0.0) == (value < 0.0))
+            .sum();
+        (offset * scale, (offset + value) * scale)
+    }
+
+    /// Returns the value shown in a bar's label: the bar's value, or the segment's size
+    /// (its percentage when normalized) for stacked bars.
+    ///
+    /// # Parameters
+    /// - `(start, end)`: The span of the bar returned by `bar_span`.
+    pub fn segment_value(&self, (start, end): (f64, f64)) -> f64 {
+        if self.is_stacked() {
+            end - start
+        } else {
+            end
+        }
+    }
+
+    /// Sets whether a horizontal chart draws its value axis above the plot.
+    ///
+    /// This is the layout of ranked lists: the scale reads first, and the category labels
+    /// are left-aligned in a column left of the plot instead of ending at the axis. Vertical
+    /// charts ignore the setting.
+    ///
+    /// # Parameters
+    /// - `top`: Whether the value axis and its tick labels are drawn above the plot.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut ranking = GroupBarChart::new("Top Sellers", "Units", "Rank", Orientation::Horizontal, config);
+    /// ranking.set_value_axis_top(true);
+    /// ```
+    pub fn set_value_axis_top(&mut self, top: bool) {
+        self.value_axis_top = top;
+    }
+
+    /// Returns the value bars grow from: the diverging baseline, or `0.0`.
+    pub fn baseline(&self) -> f64 {
+        self.diverging.as_ref().map_or(0.0, |style| style.baseline)
+    }
+
+    /// Computes the range of the value axis.
+    ///
+    /// The range is `value_limits` if set. Otherwise it always includes the baseline, and
+    /// for stacked bars the cumulative totals of each category. In diverging mode it is
+    /// padded by 10% on both sides to leave room for the value labels.
+    ///
+    /// # Returns
+    /// A tuple `(min, max)` with `min < max`.
+    pub fn value_range(&self) -> (f64, f64) {
+        if let Some((min, max)) = self.value_limits.filter(|(min, max)| min < max) {
+            return (min, max);
+        }
+        let baseline = self.baseline();
+        let (min, max) = self
+            .visible_datasets()
+            .flat_map(|(index, dataset)| {
+                dataset
+                    .data
+                    .iter()
+                    .map(move |&(category, value)| self.bar_span(index, category as u32, value))
+            })
+            .fold((baseline, baseline), |(min, max), (start, end)| {
+                (min.min(start).min(end), max.max(start).max(end))
+            });
+
+        if min == max {
+            return (min, min + 1.0);
+        }
+        match self.diverging {
+            Some(_) => {
+                let padding = (max - min) * 0.1;
+                let min = if min < baseline { min - padding } else { min };
+                let max = if max > baseline { max + padding } else { max };
+                (min, max)
+            }
+            None => (min, max),
+        }
+    }
+
+    /// Returns the categories of all visible datasets in ascending order, without
+    /// duplicates.
+    pub fn categories(&self) -> Vec<u32> {
+        self.visible_datasets()
+            .flat_map(|(_, dataset)| dataset.data.iter().map(|&(x, _)| x as u32))
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Computes how many categories fit on a canvas while keeping `MIN_CATEGORY_SPACE`
+    /// pixels per category along the category axis.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// The number of categories per page, at least `1`.
+    pub fn categories_per_page(&self, width: u32, height: u32, margin: u32) -> usize {
+        let length = match self.orientation {
+            Orientation::Vertical => width,
+            Orientation::Horizontal => height,
+        }
+        .saturating_sub(2 * margin) as f64;
+        ((length / MIN_CATEGORY_SPACE) as usize).max(1)
+    }
+
+    /// Splits the chart into pages of consecutive categories, so charts with dozens of
+    /// categories stay readable instead of rendering hairline bars.
+    ///
+    /// Categories are spread evenly over the fewest pages possible. Every page keeps the
+    /// styling and datasets of the chart, restricted to its categories, and the value axis
+    /// of the whole chart so pages compare directly. Titles are numbered, e.g.
+    /// `Sales (2/3)`.
+    ///
+    /// # Parameters
+    /// - `max_categories`: The most categories on one page.
+    ///
+    /// # Returns
+    /// The pages in category order; a single unnumbered copy of the chart if all
+    /// categories fit on one page.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// for (index, mut page) in bar_chart.pages(20).into_iter().enumerate() {
+    ///     let mut canvas = PixelCanvas::new(800, 600, [255, 255, 255], 80);
+    ///     page.draw(&mut canvas);
+    ///     canvas.save_as_image(&format!("sales_{}.png", index + 1));
+    /// }
+    /// ```
+    pub fn pages(&self, max_categories: usize) -> Vec<GroupBarChart> {
+        let categories = self.categories();
+        let max_categories = max_categories.max(1);
+        if categories.len() <= max_categories {
+            return vec![self.clone()];
+        }
+
+        // Spread the categories evenly, so the last page is not nearly empty
+        let page_count = categories.len().div_ceil(max_categories);
+        let per_page = categories.len().div_ceil(page_count);
+        let value_range = self.value_range();
+        let chunks: Vec<&[u32]> = categories.chunks(per_page).collect();
+        chunks
+            .iter()
+            .enumerate()
+            .map(|(index, chunk)| {
+                let on_page = |x: f64| chunk.contains(&(x as u32));
+                let mut page = self.clone();
+                page.title = format!("{} ({}/{})", self.title, index + 1, chunks.len());
+                page.value_limits = Some(value_range);
+                for dataset in &mut page.datasets {
+                    dataset.data.retain(|&(x, _)| on_page(x));
+                    dataset.links.retain(|(x, _)| on_page(*x));
+                }
+                page
+            })
+            .collect()
+    }
+
+    /// Splits the chart into as many pages as needed to fit a canvas, see
+    /// `categories_per_page` and `pages`.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    pub fn pages_for_canvas(&self, width: u32, height: u32, margin: u32) -> Vec<GroupBarChart> {
+        self.pages(self.categories_per_page(width, height, margin))
+    }
+
+    /// Returns the fill color of a bar.
+    ///
+    /// # Parameters
+    /// - `dataset`: The dataset the bar belongs to.
+    /// - `value`: The value of the bar.
+    ///
+    /// # Returns
+    /// The color rule's color if one is set, the direction color in diverging mode,
+    /// otherwise the dataset color.
+    pub fn bar_color(&self, dataset: &BarDataset, value: f64) -> [u8; 3] {
+        if let Some(rule) = &self.color_rule {
+            return rule.color(value);
+        }
+        match &self.diverging {
+            Some(style) if value < style.baseline => style.negative_color,
+            Some(style) => style.positive_color,
+            None => dataset.color,
+        }
+    }
+
+    /// Returns the entries shown in the legend as `(label, color)` pairs.
+    ///
+    /// These are the color rule's entries when the rule legend is enabled, the two
+    /// directions in diverging mode, otherwise one entry per dataset.
+    pub fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        if let Some(rule) = self.color_rule.as_ref().filter(|_| self.rule_legend) {
+            let entries = rule.legend_entries();
+            if !entries.is_empty() {
+                return entries;
+            }
+        }
+        match &self.diverging {
+            Some(style) => vec![
+                (style.positive_label.clone(), style.positive_color),
+                (style.negative_label.clone(), style.negative_color),
+            ],
+            None => self
+                .visible_datasets()
+                .map(|(_, dataset)| (dataset.label.clone(), dataset.color))
+                .collect(),
+        }
+    }
+
+    /// Enables a value label on every bar.
+    ///
+    /// Labels use the tick formatter and unit of the value axis. Labels drawn inside a bar
+    /// are black or white, whichever contrasts best with the bar color; labels outside use
+    /// the axis color.
+    ///
+    /// # Parameters
+    /// - `position`: Inside or outside the bar. Inside labels that do not fit in their bar
+    ///   are drawn outside.
+    /// - `rotated`: Whether labels are rotated by 90°, which suits narrow vertical bars.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::barlabelposition::BarLabelPosition;
+    ///
+    /// bar_chart.set_value_labels(BarLabelPosition::Inside, true);
+    /// ```
+    pub fn set_value_labels(&mut self, position: BarLabelPosition, rotated: bool) {
+        self.value_labels = Some(position);
+        self.rotate_value_labels = rotated;
+    }
+
+    /// Returns where value labels are drawn, taking the diverging default into account.
+    pub fn value_label_position(&self) -> Option<BarLabelPosition> {
+        self.value_labels
+            .or(self.diverging.as_ref().map(|_| BarLabelPosition::Outside))
+    }
+
+    /// Formats the value label of a bar with the value axis's tick formatter.
+    ///
+    /// # Parameters
+    /// - `value`: The value of the bar.
+    ///
+    /// # Returns
+    /// The first line of the formatted value.
+    pub fn value_label_text(&self, value: f64) -> String {
+        let axis = match self.orientation {
+            Orientation::Vertical => AxisType::AxisY,
+            Orientation::Horizontal => AxisType::AxisX,
+        };
+        let label = self.config.tick_label(axis, value, |v| format!("{v:.1}"));
+        label.lines().next().unwrap_or_default().to_string()
+    }
+
+    /// Places a value label relative to its bar.
+    ///
+    /// # Parameters
+    /// - `bar`: The bar's bounds as `(left, top, right, bottom)` in pixels.
+    /// - `value`: The value of the bar, which decides the direction the bar grows in.
+    /// - `label_size`: The `(width, height)` of the label as drawn, i.e. after rotation.
+    ///
+    /// # Returns
+    /// The top-left corner of the label, and whether it is drawn inside the bar.
+    pub fn value_label_placement(
+        &self,
+        (left, top, right, bottom): (f64, f64, f64, f64),
+        value: f64,
+        (label_width, label_height): (f64, f64),
+    ) -> ((f64, f64), bool) {
+        let gap = 4.0;
+        let positive = value >= self.baseline();
+        let fits = match self.orientation {
+            Orientation::Vertical => {
+                bottom - top >= label_height + 2.0 * gap && right - left >= label_width
+            }
+            Orientation::Horizontal => {
+                right - left >= label_width + 2.0 * gap && bottom - top >= label_height
+            }
+        };
+        let inside = self.value_label_position() == Some(BarLabelPosition::Inside) && fits;
+
+        let position = match self.orientation {
+            Orientation::Vertical => {
+                let x = (left + right - label_width) / 2.0;
+                let y = match (positive, inside) {
+                    (true, false) => top - gap - label_height,
+                    (true, true) => top + gap,
+                    (false, false) => bottom + gap,
+                    (false, true) => bottom - gap - label_height,
+                };
+                (x, y)
+            }
+            Orientation::Horizontal => {
+                let y = (top + bottom - label_height) / 2.0;
+                let x = match (positive, inside) {
+                    (true, false) => right + gap,
+                    (true, true) => right - gap - label_width,
+                    (false, false) => left - gap - label_width,
+                    (false, true) => left + gap,
+                };
+                (x, y)
+            }
+        };
+        (position, inside)
+    }
+
+    /// Draws a bar's value label, if value labels are enabled.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the label on.
+    /// - `bar`: The bar's bounds as `(left, top, right, bottom)` in pixels.
+    /// - `value`: The value of the bar.
+    /// - `bar_color`: The fill color of the bar.
+    fn draw_value_label(
+        &self,
+        canvas: &mut PixelCanvas,
+        bar: (u32, u32, u32, u32),
+        value: f64,
+        bar_color: [u8; 3],
+    ) {
+        if self.value_label_position().is_none() {
+            return;
+        }
+        let cfg = &self.config;
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let scale = ab_glyph::PxScale {
+            x: cfg.font_size_axis,
+            y: cfg.font_size_axis,
+        };
+        let text = self.value_label_text(value);
+
+        let (w, h) =
+            measure_text_with_fallbacks(&text, font_path, &cfg.font_fallbacks, cfg.font_size_axis);
+        let label_size = if self.rotate_value_labels {
+            (h as f64, w as f64)
+        } else {
+            (w as f64, h as f64)
+        };
+        let bar = (bar.0 as f64, bar.1 as f64, bar.2 as f64, bar.3 as f64);
+        let ((x, y), inside) = self.value_label_placement(bar, value, label_size);
+        let color = if inside {
+            text_color_for(cfg.text_halo.map_or(bar_color, |halo| halo.color()))
+        } else {
+            cfg.color_axis
+        };
+        let (x, y) = (x.max(0.0) as u32, y.max(0.0) as u32);
+
+        if let Some(halo) = &cfg.text_halo {
+            canvas.draw_text_halo(
+                x,
+                y,
+                &text,
+                font_path,
+                &cfg.font_fallbacks,
+                scale,
+                self.rotate_value_labels,
+                halo,
+            );
+        }
+        if self.rotate_value_labels {
+            canvas.draw_text_rotated(x, y, &text, color, font_path, &cfg.font_fallbacks, scale);
+        } else {
+            canvas.draw_text_with_fallbacks(
+                x,
+                y,
+                &text,
+                color,
+                font_path,
+                &cfg.font_fallbacks,
+                scale,
+            );
+        }
+    }
+
     /// Draws a horizontal grouped bar chart.
     ///
     /// # Parameters
@@ -92,6 +649,7 @@ impl GroupBarChart {
     /// ```
     pub fn draw_horizontal(&self, canvas: &mut PixelCanvas) {
         canvas.clear();
+        canvas.set_transparent(self.config.transparent_background);
         self.fill_background(canvas, &self.config);
 
         let margin = canvas.margin;
@@ -104,27 +662,20 @@ impl GroupBarChart {
 
         // Get unique y-axis values
         let unique_y_values: Vec<u32> = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.data.iter().map(|(y, _)| *y as u32))
+            .visible_datasets()
+            .flat_map(|(_, dataset)| dataset.data.iter().map(|(y, _)| *y as u32))
             .collect::<std::collections::BTreeSet<_>>()
             .into_iter()
             .collect();
 
         let y_count = unique_y_values.len();
 
-        let (x_min, x_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.data.iter().map(|&(_, y)| y))
-            .fold((0.0_f64, 0.0_f64), |(min, max), y| (min.min(y), max.max(y)));
-
-        // Adjust limits to include (0, 0)
-        let x_min = x_min.min(0.0);
+        let (x_min, x_max) = self.value_range();
 
         // Calculate scales
         let scale_y = (height - 2 * margin) as f64 / y_count as f64;
-        let scale_x = (width - 2 * margin) as f64 / x_max;
+        let scale_x = scale_factor(x_min, x_max, (width - 2 * margin) as f64);
+        let base_x = to_pixel_x(self.baseline(), x_min, scale_x, margin as f64) as u32;
 
         // Draw grids
         self.draw_grid(canvas, cfg);
@@ -136,58 +687,148 @@ impl GroupBarChart {
         self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.y_label);
         self.draw_label(canvas, cfg, margin, margin / 2, &self.x_label);
 
-        // X-axis ticks
-        let x_tick_step = (x_max - x_min) / cfg.num_axis_ticks as f64;
-        for i in 0..=cfg.num_axis_ticks {
-            let value_x = x_min + i as f64 * x_tick_step;
-            let tick_x = origin_x + ((value_x - x_min) * scale_x) as u32;
-
-            let value_label = format!("{value_x:.1}");
+        // X-axis ticks, below the plot or above it, ending a few pixels above its top edge
+        let tick_label_y = if self.value_axis_top {
+            let block_height =
+                2.0 * cfg.font_size_axis + cfg.x_tick_label_overflow(cfg.font_size_axis);
+            margin.saturating_sub(block_height as u32 + 4)
+        } else {
+            origin_y
+        };
+        let x_values = tick_values(x_min, x_max, cfg.num_axis_ticks);
+        let x_labels = cfg.tick_labels(AxisType::AxisX, &x_values, |v| format!("{v:.1}"));
+        let x_labels = cfg.thin_tick_labels(
+            AxisType::AxisX,
+            x_labels,
+            (width - 2 * margin) as f64,
+            cfg.font_size_axis,
+        );
+        for (&value_x, value_label) in x_values.iter().zip(&x_labels) {
+            let tick_x = to_pixel_x(value_x, x_min, scale_x, margin as f64) as u32;
 
-            self.draw_axis_value(canvas, cfg, tick_x, origin_y, &value_label, AxisType::AxisX);
+            self.draw_axis_value(
+                canvas,
+                cfg,
+                tick_x,
+                tick_label_y,
+                value_label,
+                AxisType::AxisX,
+            );
         }
 
-        // Draw grouped horizontal bars
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw grouped horizontal bars; value labels are drawn on top of all bars
+        let mut value_labels = Vec::new();
         let group_height = scale_y * 0.8; // Height of each group
-        let bar_height = group_height / self.datasets.len() as f64; // Height of each bar
+        let bar_height = group_height / self.bar_slots() as f64; // Height of each bar
+        let font_path = cfg.font_label.as_ref().expect("Font path is not set");
+        let label_scale = ab_glyph::PxScale {
+            x: cfg.font_size_axis,
+            y: cfg.font_size_axis,
+        };
+        let label_column_x = (origin_x - 10).saturating_sub(
+            unique_y_values
+                .iter()
+                .map(|y_label| {
+                    measure_text_with_fallbacks(
+                        &y_label.to_string(),
+                        font_path,
+                        &cfg.font_fallbacks,
+                        cfg.font_size_axis,
+                    )
+                    .0
+                })
+                .max()
+                .unwrap_or(0),
+        );
 
         for (group_index, y_label) in unique_y_values.iter().enumerate() {
             let group_center_y = origin_y - ((group_index as f64 + 0.5) * scale_y) as u32;
 
-            self.draw_axis_value(
-                canvas,
-                cfg,
-                origin_x - 10,
-                group_center_y,
-                &y_label.to_string(),
-                AxisType::AxisY,
-            );
+            if self.value_axis_top {
+                // Left-aligned in a column ending 10 pixels left of the plot
+                let text = y_label.to_string();
+                let (_, h) = measure_text_with_fallbacks(
+                    &text,
+                    font_path,
+                    &cfg.font_fallbacks,
+                    cfg.font_size_axis,
+                );
+                self.draw_text_with_halo(
+                    canvas,
+                    cfg,
+                    (label_column_x, group_center_y.saturating_sub(h / 2)),
+                    &text,
+                    cfg.color_axis,
+                    label_scale,
+                );
+            } else {
+                self.draw_axis_value(
+                    canvas,
+                    cfg,
+                    origin_x - 10,
+                    group_center_y,
+                    &y_label.to_string(),
+                    AxisType::AxisY,
+                );
+            }
 
             // Draw bars for each company in the group
-            for (company_index, dataset) in self.datasets.iter().enumerate() {
+            for (company_index, dataset) in self.visible_datasets() {
                 if let Some(&(_, value)) = dataset
                     .data
                     .iter()
                     .find(|(y, _)| (*y as u32).to_string() == y_label.to_string())
                 {
-                    let bar_length = (value * scale_x) as u32;
+                    let span = self.bar_span(company_index, *y_label, value);
+                    let start_x = to_pixel_x(span.0, x_min, scale_x, margin as f64) as u32;
+                    let value_x = to_pixel_x(span.1, x_min, scale_x, margin as f64) as u32;
                     let bar_top = group_center_y - (group_height / 2.0) as u32
-                        + (company_index as f64 * bar_height) as u32;
+                        + (self.bar_slot(company_index) as f64 * bar_height) as u32;
                     let bar_bottom = bar_top + bar_height as u32;
+                    let color = self.bar_color(dataset, value);
 
-                    for x in origin_x..(origin_x + bar_length) {
+                    for x in start_x.min(value_x)..start_x.max(value_x) {
                         for y in bar_top..bar_bottom {
-                            canvas.draw_pixel(x, y, dataset.color);
+                            canvas.draw_pixel(x, y, color);
                         }
                     }
+
+                    let bar = (
+                        start_x.min(value_x),
+                        bar_top,
+                        start_x.max(value_x),
+                        bar_bottom,
+                    );
+                    value_labels.push((bar, self.segment_value(span), color));
                 }
             }
         }
 
+        for (bar, value, color) in value_labels {
+            self.draw_value_label(canvas, bar, value, color);
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
         canvas.draw_vertical_line(margin, cfg.color_axis);
-        canvas.draw_horizontal_line(height - margin, cfg.color_axis);
+        let value_axis_y = if self.value_axis_top {
+            margin
+        } else {
+            height - margin
+        };
+        canvas.draw_horizontal_line(value_axis_y, cfg.color_axis);
+        if self.diverging.is_some() {
+            canvas.draw_vertical_line(base_x, cfg.color_axis);
+        }
+        self.draw_axis_units(canvas, &self.config);
+
         // Draw legend
-        self.draw_legend(canvas);
+        if self.config.show_legend {
+            self.draw_legend(canvas);
+        }
     }
 
     /// Draws a vertical grouped bar chart.
@@ -205,6 +846,7 @@ impl GroupBarChart {
     /// ```
     pub fn draw_vertical(&self, canvas: &mut PixelCanvas) {
         canvas.clear();
+        canvas.set_transparent(self.config.transparent_background);
         self.fill_background(canvas, &self.config);
 
         let margin = canvas.margin;
@@ -217,23 +859,20 @@ impl GroupBarChart {
 
         // Get unique x-axis values
         let unique_x_values: Vec<u32> = self
-            .datasets
-            .iter()
-            .flat_map(|d| d.data.iter().map(|(x, _)| *x as u32))
+            .visible_datasets()
+            .flat_map(|(_, d)| d.data.iter().map(|(x, _)| *x as u32))
             .collect::<std::collections::BTreeSet<_>>()
             .into_iter()
             .collect();
 
         let x_count = unique_x_values.len();
-        let y_max = self
-            .datasets
-            .iter()
-            .flat_map(|d| d.data.iter().map(|(_, y)| *y))
-            .fold(0.0_f64, |max, y| max.max(y));
+        let (y_min, y_max) = self.value_range();
 
         // Calculate scales
         let scale_x = (width - 2 * margin) as f64 / x_count as f64;
-        let scale_y = (height - 2 * margin) as f64 / y_max;
+        let scale_y = scale_factor(y_min, y_max, (height - 2 * margin) as f64);
+        let to_y = |value| to_pixel_y(value, y_min, scale_y, height as f64, margin as f64) as u32;
+        let base_y = to_y(self.baseline());
         let origin_x = margin;
         let origin_y = height - margin;
 
@@ -251,26 +890,34 @@ impl GroupBarChart {
         self.draw_label(canvas, cfg, margin, margin / 2, &self.y_label);
 
         // Y-axis ticks
-        let y_tick_step = y_max / cfg.num_axis_ticks as f64;
-        for i in 0..=cfg.num_axis_ticks {
-            let value_y = i as f64 * y_tick_step;
-            let tick_y = origin_y - (value_y * scale_y) as u32;
-
-            let value_label = format!("{value_y:.2}");
+        let y_values = tick_values(y_min, y_max, cfg.num_axis_ticks);
+        let y_labels = cfg.tick_labels(AxisType::AxisY, &y_values, |v| format!("{v:.2}"));
+        let y_labels = cfg.thin_tick_labels(
+            AxisType::AxisY,
+            y_labels,
+            (height - 2 * margin) as f64,
+            cfg.font_size_axis,
+        );
+        for (&value_y, value_label) in y_values.iter().zip(&y_labels) {
+            let tick_y = to_y(value_y);
 
             self.draw_axis_value(
                 canvas,
                 cfg,
                 origin_x - 5,
                 tick_y,
-                &value_label,
+                value_label,
                 AxisType::AxisY,
             );
         }
 
-        // Draw x-axis labels and grouped bars
+        self.config
+            .run_before_data_hook(RenderTarget::Pixel(canvas));
+
+        // Draw x-axis labels and grouped bars; value labels are drawn on top of all bars
+        let mut value_labels = Vec::new();
         let group_width = scale_x * 0.8; // Width of each group of bars
-        let bar_width = group_width / self.datasets.len() as f64; // Width of each bar
+        let bar_width = group_width / self.bar_slots() as f64; // Width of each bar
 
         for (group_index, x_label) in unique_x_values.iter().enumerate() {
             let group_center_x = origin_x + (((group_index as f64 + 0.5) * scale_x) as u32);
@@ -285,27 +932,59 @@ impl GroupBarChart {
             );
 
             // Draw bars for each company in the group
-            for (company_index, dataset) in self.datasets.iter().enumerate() {
+            for (company_index, dataset) in self.visible_datasets() {
                 if let Some(&(_, income)) = dataset
                     .data
                     .iter()
                     .find(|(x, _)| (*x as u32).to_string() == x_label.to_string())
                 {
-                    let bar_height = (income * scale_y) as u32;
+                    let span = self.bar_span(company_index, *x_label, income);
+                    let (start_y, value_y) = (to_y(span.0), to_y(span.1));
                     let bar_left = group_center_x - (group_width / 2.0) as u32
-                        + (company_index as f64 * bar_width) as u32;
+                        + (self.bar_slot(company_index) as f64 * bar_width) as u32;
                     let bar_right = bar_left + bar_width as u32;
+                    let color = self.bar_color(dataset, income);
 
                     for x in bar_left..=bar_right {
-                        for y in (origin_y - bar_height)..origin_y {
-                            canvas.draw_pixel(x, y, dataset.color);
+                        for y in start_y.min(value_y)..start_y.max(value_y) {
+                            canvas.draw_pixel(x, y, color);
                         }
                     }
+
+                    let bar = (
+                        bar_left,
+                        start_y.min(value_y),
+                        bar_right,
+                        start_y.max(value_y),
+                    );
+                    value_labels.push((bar, self.segment_value(span), color));
                 }
             }
         }
 
+        for (bar, value, color) in value_labels {
+            self.draw_value_label(canvas, bar, value, color);
+        }
+
+        self.config.run_after_data_hook(RenderTarget::Pixel(canvas));
+
+        if self.diverging.is_some() {
+            canvas.draw_horizontal_line(base_y, cfg.color_axis);
+        }
+        self.draw_axis_units(canvas, &self.config);
+
         // Draw legend
-        self.draw_legend(canvas);
+        if self.config.show_legend {
+            self.draw_legend(canvas);
+        }
+    }
+}
+
+impl SeriesVisibility for GroupBarChart {
+    fn series_visibility(&mut self) -> Vec<(&str, &mut bool)> {
+        self.datasets
+            .iter_mut()
+            .map(|dataset| (dataset.label.as_str(), &mut dataset.visible))
+            .collect()
     }
 }
@@ -0,0 +1,370 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    utilities::{
+        clustering::{cluster, transpose, Dendrogram},
+        colorbar::{self, COLOR_BAR_SPACE},
+        colormap::{interpolate, SEQUENTIAL_YELLOW_BLUE},
+        linkage::Linkage,
+    },
+};
+
+/// Space in pixels kept above and right of the grid for the dendrograms of a clustered
+/// heatmap.
+pub const DENDROGRAM_SPACE: f64 = 50.0;
+
+/// Represents a heatmap: a matrix of values drawn as a grid of cells colored through a
+/// colormap.
+///
+/// Values are stored as a matrix indexed `[row][column]`. Row `0` is drawn at the top and
+/// column `0` at the left; rows may differ in length, and missing or `NaN` cells are drawn
+/// in the missing color.
+///
+/// With `set_clustering`, the rows and columns are instead drawn in the order of a
+/// hierarchical clustering, with the column dendrogram above the grid and the row
+/// dendrogram right of it.
+#[derive(Clone)]
+pub struct Heatmap {
+    /// Title of the heatmap.
+    pub title: String,
+    /// Label for the X-axis (the columns).
+    pub x_label: String,
+    /// Label for the Y-axis (the rows).
+    pub y_label: String,
+    /// The values, indexed as `[row][column]`.
+    pub values: Vec<Vec<f64>>,
+    /// Names of the rows, shown left of the grid. Rows without a name are numbered.
+    pub row_labels: Vec<String>,
+    /// Names of the columns, shown below the grid. Columns without a name are numbered.
+    pub column_labels: Vec<String>,
+    /// The values mapped to the first and last color stop. Computed from the finite values
+    /// when `None`; values outside the range are clamped.
+    pub value_range: Option<(f64, f64)>,
+    /// Color stops from the lowest to the highest value.
+    pub color_stops: Vec<[u8; 3]>,
+    /// Whether the value is printed in every cell.
+    pub show_values: bool,
+    /// Number of decimals of the printed values and the color bar labels.
+    pub precision: usize,
+    /// The linkage used to cluster rows and columns, or `None` to draw them in matrix order.
+    pub clustering: Option<Linkage>,
+    /// Configuration settings for rendering the heatmap (e.g., colors, fonts).
+    pub config: FigureConfig,
+}
+
+impl Heatmap {
+    /// Creates a new `Heatmap` instance with the specified title, labels, and configuration.
+    ///
+    /// The heatmap uses the yellow to blue colormap and prints no values by default.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the heatmap.
+    /// - `x_label`: The label for the column axis.
+    /// - `y_label`: The label for the row axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `Heatmap` instance without values.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::heatmap::Heatmap;
+    ///
+    /// let mut heatmap = Heatmap::new("Occupancy", "Hour", "Weekday", config);
+    /// heatmap.set_values(occupancy, Some((0.0, 100.0)));
+    /// heatmap.set_labels(&["Mon", "Tue", "Wed", "Thu", "Fri"], &hours);
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            values: Vec::new(),
+            row_labels: Vec::new(),
+            column_labels: Vec::new(),
+            value_range: None,
+            color_stops: SEQUENTIAL_YELLOW_BLUE.to_vec(),
+            show_values: false,
+            precision: 2,
+            clustering: None,
+            config,
+        }
+    }
+
+    /// Sets the matrix and the values mapped to the ends of the colormap.
+    ///
+    /// # Parameters
+    /// - `values`: The values, indexed as `[row][column]`.
+    /// - `range`: The `(min, max)` values mapped to the first and last color stop, or `None`
+    ///   to use the smallest and largest finite value.
+    pub fn set_values(&mut self, values: Vec<Vec<f64>>, range: Option<(f64, f64)>) {
+        self.values = values;
+        self.value_range = range;
+    }
+
+    /// Sets the names of the rows and columns.
+    ///
+    /// # Parameters
+    /// - `rows`: The row names, from top to bottom.
+    /// - `columns`: The column names, from left to right.
+    pub fn set_labels(&mut self, rows: &[&str], columns: &[&str]) {
+        self.row_labels = rows.iter().map(|label| label.to_string()).collect();
+        self.column_labels = columns.iter().map(|label| label.to_string()).collect();
+    }
+
+    /// Sets the colormap.
+    ///
+    /// # Parameters
+    /// - `stops`: Evenly spaced color stops from the lowest to the highest value, e.g.
+    ///   `colormap::SEQUENTIAL_MAGMA` or `colormap::DIVERGING_BLUE_RED`.
+    pub fn set_color_stops(&mut self, stops: &[[u8; 3]]) {
+        self.color_stops = stops.to_vec();
+    }
+
+    /// Reorders the rows and columns by hierarchical clustering and draws their
+    /// dendrograms in the margins of the grid.
+    ///
+    /// Rows are compared by the Euclidean distance of their values, and columns likewise;
+    /// missing and non-finite cells count as `0.0`. The matrix itself is left in its order.
+    ///
+    /// # Parameters
+    /// - `linkage`: The rule measuring the distance between clusters, or `None` to draw the
+    ///   rows and columns in matrix order without dendrograms.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::linkage::Linkage;
+    ///
+    /// heatmap.set_clustering(Some(Linkage::Average));
+    /// ```
+    pub fn set_clustering(&mut self, linkage: Option<Linkage>) {
+        self.clustering = linkage;
+    }
+
+    /// Clusters the rows and the columns of the matrix.
+    ///
+    /// # Returns
+    /// The row and column dendrograms, or `None` without clustering.
+    pub fn dendrograms(&self) -> Option<(Dendrogram, Dendrogram)> {
+        let linkage = self.clustering?;
+        let columns = self.num_columns();
+        let matrix: Vec<Vec<f64>> = self
+            .values
+            .iter()
+            .map(|row| {
+                (0..columns)
+                    .map(|column| row.get(column).copied().filter(|v| v.is_finite()))
+                    .map(|value| value.unwrap_or(0.0))
+                    .collect()
+            })
+            .collect();
+        Some((
+            cluster(&matrix, linkage),
+            cluster(&transpose(&matrix), linkage),
+        ))
+    }
+
+    /// Returns the order in which rows and columns are drawn.
+    ///
+    /// # Returns
+    /// The matrix index of each drawn row, from top to bottom, and of each drawn column,
+    /// from left to right.
+    pub fn cell_order(&self) -> (Vec<usize>, Vec<usize>) {
+        match self.dendrograms() {
+            Some((rows, columns)) => (rows.leaf_order(), columns.leaf_order()),
+            None => (
+                (0..self.num_rows()).collect(),
+                (0..self.num_columns()).collect(),
+            ),
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn num_rows(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the number of columns, the length of the longest row.
+    pub fn num_columns(&self) -> usize {
+        self.values.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Returns the name of a row, or its one-based number if it has none.
+    pub fn row_label(&self, row: usize) -> String {
+        self.row_labels
+            .get(row)
+            .cloned()
+            .unwrap_or_else(|| (row + 1).to_string())
+    }
+
+    /// Returns the name of a column, or its one-based number if it has none.
+    pub fn column_label(&self, column: usize) -> String {
+        self.column_labels
+            .get(column)
+            .cloned()
+            .unwrap_or_else(|| (column + 1).to_string())
+    }
+
+    /// Returns the values mapped to the first and last color stop.
+    ///
+    /// # Returns
+    /// The set range, or the smallest and largest finite value. Defaults to `(0.0, 1.0)`
+    /// without finite values.
+    pub fn range(&self) -> (f64, f64) {
+        if let Some(range) = self.value_range {
+            return range;
+        }
+        let (min, max) = self
+            .values
+            .iter()
+            .flatten()
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            });
+        if min > max {
+            (0.0, 1.0)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// Maps a value to its color, clamping values outside the range.
+    ///
+    /// # Returns
+    /// The color of `value`; the middle color stop if the range is empty, and
+    /// `colormap::MISSING_COLOR` for `NaN`.
+    pub fn value_color(&self, value: f64) -> [u8; 3] {
+        let (min, max) = self.range();
+        let t = if max > min {
+            (value - min) / (max - min)
+        } else if value.is_nan() {
+            f64::NAN
+        } else {
+            0.5
+        };
+        interpolate(&self.color_stops, t)
+    }
+
+    /// Formats a value for display in a cell, tooltip or on the color bar.
+    ///
+    /// # Parameters
+    /// - `value`: The value.
+    pub fn format_value(&self, value: f64) -> String {
+        if value.is_nan() {
+            "n/a".to_string()
+        } else {
+            self.config.format.format_number(value, self.precision)
+        }
+    }
+
+    /// Returns the space kept above and right of the grid for dendrograms.
+    pub fn dendrogram_space(&self) -> f64 {
+        if self.clustering.is_some() {
+            DENDROGRAM_SPACE
+        } else {
+            0.0
+        }
+    }
+
+    /// Computes the plot area on a canvas, leaving room for the color bar and the
+    /// dendrograms.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(left, top, right, bottom)` in canvas coordinates.
+    pub fn plot_area(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64, f64) {
+        let space = self.dendrogram_space();
+        colorbar::plot_area(width, height, margin, space, COLOR_BAR_SPACE + space)
+    }
+
+    /// Computes the dendrogram lines of a clustered heatmap: the column tree above the grid
+    /// and the row tree right of it, with their leaves at the edge of the grid.
+    ///
+    /// # Parameters
+    /// - `area`: The plot area returned by `plot_area`.
+    ///
+    /// # Returns
+    /// The lines as `((x1, y1), (x2, y2))` in canvas coordinates; empty without clustering.
+    pub fn dendrogram_lines(&self, area: (f64, f64, f64, f64)) -> Vec<((f64, f64), (f64, f64))> {
+        let Some((rows, columns)) = self.dendrograms() else {
+            return Vec::new();
+        };
+        let (left, top, right, bottom) = area;
+        let cell_width = (right - left) / self.num_columns().max(1) as f64;
+        let cell_height = (bottom - top) / self.num_rows().max(1) as f64;
+        let depth = DENDROGRAM_SPACE - 5.0;
+        let scale = |tree: &Dendrogram| {
+            let height = tree.height();
+            move |distance: f64| {
+                if height > 0.0 {
+                    distance / height * depth
+                } else {
+                    0.0
+                }
+            }
+        };
+
+        let column_scale = scale(&columns);
+        let column_lines = columns.segments().into_iter().map(|((p1, d1), (p2, d2))| {
+            (
+                (left + p1 * cell_width, top - column_scale(d1)),
+                (left + p2 * cell_width, top - column_scale(d2)),
+            )
+        });
+        let row_scale = scale(&rows);
+        let row_lines = rows.segments().into_iter().map(|((p1, d1), (p2, d2))| {
+            (
+                (right + row_scale(d1), top + p1 * cell_height),
+                (right + row_scale(d2), top + p2 * cell_height),
+            )
+        });
+        column_lines.chain(row_lines).collect()
+    }
+
+    /// Finds the grid cell under a canvas position, as drawn.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas position.
+    /// - `area`: The plot area returned by `plot_area`.
+    ///
+    /// # Returns
+    /// The `(row, column)` position in the grid, which is the matrix index only without
+    /// clustering (see `cell_order`), or `None` outside the plot area.
+    pub fn grid_cell_at(
+        &self,
+        x: f64,
+        y: f64,
+        area: (f64, f64, f64, f64),
+    ) -> Option<(usize, usize)> {
+        let (left, top, right, bottom) = area;
+        if !(left..right).contains(&x) || !(top..bottom).contains(&y) {
+            return None;
+        }
+        let column = ((x - left) / (right - left) * self.num_columns() as f64) as usize;
+        let row = ((y - top) / (bottom - top) * self.num_rows() as f64) as usize;
+        Some((row, column))
+    }
+
+    /// Finds the matrix cell under a canvas position.
+    ///
+    /// Clusters the matrix on every call when clustering is set; use `cell_order` and
+    /// `grid_cell_at` to look up many positions.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas position.
+    /// - `area`: The plot area returned by `plot_area`.
+    ///
+    /// # Returns
+    /// The `(row, column)` indices into `values`, or `None` outside the plot area or the
+    /// matrix.
+    pub fn cell_at(&self, x: f64, y: f64, area: (f64, f64, f64, f64)) -> Option<(usize, usize)> {
+        let (row, column) = self.grid_cell_at(x, y, area)?;
+        let (rows, columns) = self.cell_order();
+        let (row, column) = (*rows.get(row)?, *columns.get(column)?);
+        self.values.get(row)?.get(column)?;
+        Some((row, column))
+    }
+}
@@ -0,0 +1,498 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    utilities::{
+        colorbar::{self, COLOR_BAR_SPACE},
+        colormap::{interpolate, SEQUENTIAL_YELLOW_BLUE},
+        scaling::data_bounds,
+    },
+};
+
+/// The binned `(x_range, y_range)` of a `Hist2D`.
+type Ranges = ((f64, f64), (f64, f64));
+
+/// The shape of the bins of a `Hist2D`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hist2DShape {
+    /// A grid of rectangles.
+    Rectangular,
+    /// Hexagons in offset rows, which follow the density of round clusters more closely
+    /// than rectangles.
+    Hexagonal,
+}
+
+/// A bin of a `Hist2D` holding points.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hist2DCell {
+    /// The center of the bin in data coordinates.
+    pub center: (f64, f64),
+    /// The outline of the bin in data coordinates, clipped to the binned ranges.
+    pub outline: Vec<(f64, f64)>,
+    /// The number of points in the bin.
+    pub count: f64,
+}
+
+/// Represents a two-dimensional histogram: a point cloud binned into rectangles or hexagons
+/// that are colored by the number of points they hold.
+///
+/// Unlike a scatter graph, the drawing cost does not grow with the number of points, and
+/// dense regions stay distinguishable where markers would saturate.
+#[derive(Clone)]
+pub struct Hist2D {
+    /// Title of the histogram.
+    pub title: String,
+    /// Label for the X-axis.
+    pub x_label: String,
+    /// Label for the Y-axis.
+    pub y_label: String,
+    /// The points as `(x, y)` pairs.
+    pub points: Vec<(f64, f64)>,
+    /// The shape of the bins.
+    pub shape: Hist2DShape,
+    /// Number of bins along the x-axis.
+    pub bins_x: usize,
+    /// Number of bins along the y-axis; `None` uses as many as along the x-axis for
+    /// rectangles, and `bins_x / √3` for hexagons so they are regular on a square plot.
+    pub bins_y: Option<usize>,
+    /// The binned x range; computed from the points when `None`. Points outside are ignored.
+    pub x_range: Option<(f64, f64)>,
+    /// The binned y range; computed from the points when `None`. Points outside are ignored.
+    pub y_range: Option<(f64, f64)>,
+    /// Bins holding fewer points are left empty.
+    pub min_count: f64,
+    /// Whether counts are mapped to colors on a logarithmic scale, so sparse regions stay
+    /// visible next to dense cores.
+    pub log_scale: bool,
+    /// Color stops from the lowest to the highest count.
+    pub color_stops: Vec<[u8; 3]>,
+    /// Configuration settings for rendering the histogram (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl Hist2D {
+    /// Creates a new `Hist2D` instance with the specified title, labels, and configuration.
+    ///
+    /// The histogram uses 40 rectangular bins along the x-axis, a linear count scale and
+    /// the yellow to blue colormap by default.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the histogram.
+    /// - `x_label`: The label for the X-axis.
+    /// - `y_label`: The label for the Y-axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `Hist2D` instance without points.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::hist2d::{Hist2D, Hist2DShape};
+    ///
+    /// let mut hist = Hist2D::new("Pickup Locations", "Longitude", "Latitude", config);
+    /// hist.add_points(&pickups);
+    /// hist.set_shape(Hist2DShape::Hexagonal);
+    /// hist.set_log_scale(true);
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            points: Vec::new(),
+            shape: Hist2DShape::Rectangular,
+            bins_x: 40,
+            bins_y: None,
+            x_range: None,
+            y_range: None,
+            min_count: 1.0,
+            log_scale: false,
+            color_stops: SEQUENTIAL_YELLOW_BLUE.to_vec(),
+            config,
+        }
+    }
+
+    /// Adds a single point.
+    pub fn add_point(&mut self, x: f64, y: f64) {
+        self.points.push((x, y));
+    }
+
+    /// Adds several points.
+    ///
+    /// # Parameters
+    /// - `points`: The points as `(x, y)` pairs.
+    pub fn add_points(&mut self, points: &[(f64, f64)]) {
+        self.points.extend_from_slice(points);
+    }
+
+    /// Sets the shape of the bins.
+    pub fn set_shape(&mut self, shape: Hist2DShape) {
+        self.shape = shape;
+    }
+
+    /// Sets the number of bins.
+    ///
+    /// # Parameters
+    /// - `bins_x`: The number of bins along the x-axis, at least one.
+    /// - `bins_y`: The number of bins along the y-axis, or `None` to derive it from
+    ///   `bins_x`.
+    pub fn set_bins(&mut self, bins_x: usize, bins_y: Option<usize>) {
+        self.bins_x = bins_x.max(1);
+        self.bins_y = bins_y.map(|bins| bins.max(1));
+    }
+
+    /// Sets the binned area, e.g. to compare histograms of different point sets or to
+    /// leave out outliers.
+    ///
+    /// # Parameters
+    /// - `x_range`: The `(min, max)` x values.
+    /// - `y_range`: The `(min, max)` y values.
+    pub fn set_range(&mut self, x_range: (f64, f64), y_range: (f64, f64)) {
+        self.x_range = Some(x_range);
+        self.y_range = Some(y_range);
+    }
+
+    /// Sets whether counts are mapped to colors on a logarithmic scale.
+    pub fn set_log_scale(&mut self, log_scale: bool) {
+        self.log_scale = log_scale;
+    }
+
+    /// Sets the fewest points a bin needs to be drawn.
+    pub fn set_min_count(&mut self, min_count: f64) {
+        self.min_count = min_count;
+    }
+
+    /// Sets the colormap.
+    ///
+    /// # Parameters
+    /// - `stops`: Evenly spaced color stops from the lowest to the highest count, e.g.
+    ///   `colormap::SEQUENTIAL_MAGMA`.
+    pub fn set_color_stops(&mut self, stops: &[[u8; 3]]) {
+        self.color_stops = stops.to_vec();
+    }
+
+    /// Returns the binned `(x_range, y_range)`: the set ranges, or the bounds of the finite
+    /// points. Empty ranges are widened by `0.5` on both sides; without points the ranges
+    /// are `(0.0, 1.0)`.
+    pub fn ranges(&self) -> ((f64, f64), (f64, f64)) {
+        let bounds = data_bounds(
+            self.points
+                .iter()
+                .copied()
+                .filter(|(x, y)| x.is_finite() && y.is_finite()),
+        );
+        let widen = |(min, max): (f64, f64)| {
+            if max > min {
+                (min, max)
+            } else {
+                (min - 0.5, max + 0.5)
+            }
+        };
+        let (x_range, y_range) = match bounds {
+            Some((x_min, x_max, y_min, y_max)) => ((x_min, x_max), (y_min, y_max)),
+            None => ((0.0, 1.0), (0.0, 1.0)),
+        };
+        (
+            widen(self.x_range.unwrap_or(x_range)),
+            widen(self.y_range.unwrap_or(y_range)),
+        )
+    }
+
+    /// Returns the number of bins along the y-axis.
+    pub fn rows(&self) -> usize {
+        match (self.bins_y, self.shape) {
+            (Some(rows), _) => rows.max(1),
+            (None, Hist2DShape::Rectangular) => self.bins_x.max(1),
+            (None, Hist2DShape::Hexagonal) => {
+                ((self.bins_x as f64 / 3f64.sqrt()).round() as usize).max(1)
+            }
+        }
+    }
+
+    /// Returns the size of a bin in data units: the width and height of a rectangle, or the
+    /// horizontal and vertical spacing of hexagons in the same row.
+    pub fn cell_size(&self) -> (f64, f64) {
+        self.bin_size(self.ranges())
+    }
+
+    /// Returns the size of a bin within the given ranges.
+    fn bin_size(&self, ((x0, x1), (y0, y1)): Ranges) -> (f64, f64) {
+        (
+            (x1 - x0) / self.bins_x.max(1) as f64,
+            (y1 - y0) / self.rows() as f64,
+        )
+    }
+
+    /// Returns the number of bins, including empty ones.
+    fn num_bins(&self) -> usize {
+        let (columns, rows) = (self.bins_x.max(1), self.rows());
+        match self.shape {
+            Hist2DShape::Rectangular => columns * rows,
+            Hist2DShape::Hexagonal => (columns + 1) * (rows + 1) + columns * rows,
+        }
+    }
+
+    /// Finds the bin of a point.
+    ///
+    /// Hexagons lie on two interleaved lattices, one centered on the grid corners and one on
+    /// the centers of the grid cells; a point belongs to the nearer of the two candidate
+    /// centers.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The point.
+    /// - `ranges`: The binned ranges returned by `ranges`, computed once per binning.
+    ///
+    /// # Returns
+    /// The index of the bin, or `None` for points outside the ranges.
+    fn bin_index(&self, x: f64, y: f64, ranges: Ranges) -> Option<usize> {
+        let ((x0, x1), (y0, y1)) = ranges;
+        if !(x0..=x1).contains(&x) || !(y0..=y1).contains(&y) {
+            return None;
+        }
+        let (columns, rows) = (self.bins_x.max(1), self.rows());
+        let (sx, sy) = self.bin_size(ranges);
+        let (u, v) = ((x - x0) / sx, (y - y0) / sy);
+        match self.shape {
+            Hist2DShape::Rectangular => {
+                let column = (u as usize).min(columns - 1);
+                let row = (v as usize).min(rows - 1);
+                Some(row * columns + column)
+            }
+            Hist2DShape::Hexagonal => {
+                let (u1, v1) = (u.round(), v.round());
+                let (u2, v2) = (u.floor(), v.floor());
+                let corner = (u - u1).powi(2) + 3.0 * (v - v1).powi(2);
+                let center = (u - u2 - 0.5).powi(2) + 3.0 * (v - v2 - 0.5).powi(2);
+                if corner <= center {
+                    Some(v1 as usize * (columns + 1) + u1 as usize)
+                } else {
+                    let column = (u2 as usize).min(columns - 1);
+                    let row = (v2 as usize).min(rows - 1);
+                    Some((columns + 1) * (rows + 1) + row * columns + column)
+                }
+            }
+        }
+    }
+
+    /// Returns the center of a bin in data coordinates.
+    fn bin_center(&self, index: usize, ranges: Ranges) -> (f64, f64) {
+        let ((x0, _), (y0, _)) = ranges;
+        let (columns, rows) = (self.bins_x.max(1), self.rows());
+        let (sx, sy) = self.bin_size(ranges);
+        let (column, row, offset) = match self.shape {
+            Hist2DShape::Rectangular => (index % columns, index / columns, 0.5),
+            Hist2DShape::Hexagonal if index < (columns + 1) * (rows + 1) => {
+                (index % (columns + 1), index / (columns + 1), 0.0)
+            }
+            Hist2DShape::Hexagonal => {
+                let index = index - (columns + 1) * (rows + 1);
+                (index % columns, index / columns, 0.5)
+            }
+        };
+        (
+            x0 + (column as f64 + offset) * sx,
+            y0 + (row as f64 + offset) * sy,
+        )
+    }
+
+    /// Bins the points.
+    ///
+    /// # Returns
+    /// The bins holding at least `min_count` points, in no particular order.
+    pub fn cells(&self) -> Vec<Hist2DCell> {
+        let ranges = self.ranges();
+        let mut counts = vec![0.0; self.num_bins()];
+        for &(x, y) in &self.points {
+            if let Some(index) = self.bin_index(x, y, ranges) {
+                counts[index] += 1.0;
+            }
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, count)| count > 0.0 && count >= self.min_count)
+            .map(|(index, count)| self.cell(index, count, ranges))
+            .collect()
+    }
+
+    /// Finds the bin containing a position, counting only the points in that bin.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The position in data coordinates.
+    ///
+    /// # Returns
+    /// The bin, or `None` outside the ranges or if the bin is not drawn.
+    pub fn cell_at(&self, x: f64, y: f64) -> Option<Hist2DCell> {
+        let ranges = self.ranges();
+        let index = self.bin_index(x, y, ranges)?;
+        let count = self
+            .points
+            .iter()
+            .filter(|&&(px, py)| self.bin_index(px, py, ranges) == Some(index))
+            .count() as f64;
+        (count > 0.0 && count >= self.min_count).then(|| self.cell(index, count, ranges))
+    }
+
+    /// Builds the cell of a bin, with its center and clipped outline.
+    fn cell(&self, index: usize, count: f64, ranges: Ranges) -> Hist2DCell {
+        let (sx, sy) = self.bin_size(ranges);
+        let (cx, cy) = self.bin_center(index, ranges);
+        let outline = match self.shape {
+            Hist2DShape::Rectangular => vec![
+                (cx - sx / 2.0, cy - sy / 2.0),
+                (cx + sx / 2.0, cy - sy / 2.0),
+                (cx + sx / 2.0, cy + sy / 2.0),
+                (cx - sx / 2.0, cy + sy / 2.0),
+            ],
+            Hist2DShape::Hexagonal => vec![
+                (cx, cy - sy / 3.0),
+                (cx + sx / 2.0, cy - sy / 6.0),
+                (cx + sx / 2.0, cy + sy / 6.0),
+                (cx, cy + sy / 3.0),
+                (cx - sx / 2.0, cy + sy / 6.0),
+                (cx - sx / 2.0, cy - sy / 6.0),
+            ],
+        };
+        let (x_range, y_range) = ranges;
+        Hist2DCell {
+            center: (cx, cy),
+            outline: clip_to_rect(outline, x_range, y_range),
+            count,
+        }
+    }
+
+    /// Returns the lowest and highest count of the drawn bins, `(1.0, 1.0)` without bins.
+    pub fn count_range(&self, cells: &[Hist2DCell]) -> (f64, f64) {
+        let (min, max) = cells
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), cell| {
+                (min.min(cell.count), max.max(cell.count))
+            });
+        if min > max {
+            (1.0, 1.0)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// Maps a count to its color within the count range, linearly or logarithmically.
+    ///
+    /// # Parameters
+    /// - `count`: The count.
+    /// - `range`: The count range returned by `count_range`.
+    pub fn count_color(&self, count: f64, range: (f64, f64)) -> [u8; 3] {
+        interpolate(&self.color_stops, self.count_fraction(count, range))
+    }
+
+    /// Returns the position of a count within the count range, from `0.0` to `1.0`.
+    pub fn count_fraction(&self, count: f64, (min, max): (f64, f64)) -> f64 {
+        if max <= min {
+            return 1.0;
+        }
+        if self.log_scale && min > 0.0 {
+            (count.max(min) / min).ln() / (max / min).ln()
+        } else {
+            (count - min) / (max - min)
+        }
+    }
+
+    /// Returns the count at a position within the count range; the inverse of
+    /// `count_fraction`.
+    pub fn count_at_fraction(&self, fraction: f64, (min, max): (f64, f64)) -> f64 {
+        if self.log_scale && min > 0.0 {
+            min * (max / min).powf(fraction)
+        } else {
+            min + fraction * (max - min)
+        }
+    }
+
+    /// Formats a count for the color bar and tooltips.
+    pub fn format_count(&self, count: f64) -> String {
+        self.config.format.format_number(count, 0)
+    }
+
+    /// Computes the plot area on a canvas, leaving room for the color bar.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(left, top, right, bottom)` in canvas coordinates.
+    pub fn plot_area(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64, f64) {
+        colorbar::plot_area(width, height, margin, 0.0, COLOR_BAR_SPACE)
+    }
+
+    /// Converts data coordinates to canvas coordinates.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The position in data coordinates.
+    /// - `ranges`: The binned ranges returned by `ranges`.
+    /// - `area`: The plot area returned by `plot_area`.
+    pub fn to_canvas(
+        &self,
+        x: f64,
+        y: f64,
+        ranges: ((f64, f64), (f64, f64)),
+        area: (f64, f64, f64, f64),
+    ) -> (f64, f64) {
+        let (left, top, right, bottom) = area;
+        let ((x0, x1), (y0, y1)) = ranges;
+        (
+            left + (x - x0) / (x1 - x0) * (right - left),
+            bottom - (y - y0) / (y1 - y0) * (bottom - top),
+        )
+    }
+
+    /// Converts canvas coordinates to data coordinates.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas position.
+    /// - `ranges`: The binned ranges returned by `ranges`.
+    /// - `area`: The plot area returned by `plot_area`.
+    pub fn to_data(
+        &self,
+        x: f64,
+        y: f64,
+        ranges: ((f64, f64), (f64, f64)),
+        area: (f64, f64, f64, f64),
+    ) -> (f64, f64) {
+        let (left, top, right, bottom) = area;
+        let ((x0, x1), (y0, y1)) = ranges;
+        (
+            x0 + (x - left) / (right - left) * (x1 - x0),
+            y0 + (bottom - y) / (bottom - top) * (y1 - y0),
+        )
+    }
+}
+
+/// Clips a convex polygon to a rectangle (Sutherland–Hodgman).
+fn clip_to_rect(
+    polygon: Vec<(f64, f64)>,
+    (x0, x1): (f64, f64),
+    (y0, y1): (f64, f64),
+) -> Vec<(f64, f64)> {
+    // Each edge keeps the points with a non-negative distance inside it
+    let edges: [&dyn Fn((f64, f64)) -> f64; 4] = [
+        &|(x, _)| x - x0,
+        &|(x, _)| x1 - x,
+        &|(_, y)| y - y0,
+        &|(_, y)| y1 - y,
+    ];
+    edges.iter().fold(polygon, |polygon, inside| {
+        let mut clipped = Vec::with_capacity(polygon.len() + 1);
+        for (index, &current) in polygon.iter().enumerate() {
+            let previous = polygon[(index + polygon.len() - 1) % polygon.len()];
+            let (d_previous, d_current) = (inside(previous), inside(current));
+            if (d_previous >= 0.0) != (d_current >= 0.0) {
+                let t = d_previous / (d_previous - d_current);
+                clipped.push((
+                    previous.0 + t * (current.0 - previous.0),
+                    previous.1 + t * (current.1 - previous.1),
+                ));
+            }
+            if d_current >= 0.0 {
+                clipped.push(current);
+            }
+        }
+        clipped
+    })
+}
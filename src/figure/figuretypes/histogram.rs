@@ -1,6 +1,43 @@
-use crate::figure::configuration::figureconfig::FigureConfig;
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    utilities::{
+        histogramcomparison::HistogramComparison, histogramnormalization::HistogramNormalization,
+        statistics::kernel_density_between,
+    },
+};
+
+/// Number of values the density overlay is evaluated at.
+const OVERLAY_POINTS: usize = 200;
+
+/// A kernel density estimate of a histogram's own data, drawn as a curve over its bars.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistogramDensityOverlay {
+    /// The bandwidth of the Gaussian kernel, in data units. Silverman's rule of thumb is used
+    /// when `None`.
+    pub bandwidth: Option<f64>,
+    /// Color of the curve in RGB format.
+    pub color: [u8; 3],
+}
+
+/// An additional sample set drawn in the bins of a histogram, for comparison with its data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistogramSeries {
+    /// Legend label of the set.
+    pub label: String,
+    /// Raw data values of the set.
+    pub data: Vec<f64>,
+    /// Weight of each data value, in the order of `data`.
+    pub weights: Vec<f64>,
+    /// Color of the set's bars in RGB format.
+    pub color: [u8; 3],
+    /// Cached frequencies for each bin, before normalization.
+    pub bin_counts: Vec<f64>,
+    /// Cached summed weights of the values below and above the fixed range.
+    pub outside_counts: (f64, f64),
+}
 
 /// Represents a histogram, including title, axis labels, bin configuration, and cached data.
+#[derive(Clone)]
 pub struct Histogram {
     /// Title of the histogram.
     pub title: String,
@@ -12,16 +49,41 @@ pub struct Histogram {
     pub bins: usize,
     /// Raw data values to be represented in the histogram.
     pub data: Vec<f64>,
+    /// Weight of each data value, in the order of `data`.
+    pub weights: Vec<f64>,
     /// Color of the histogram bars in RGB format.
     pub color: [u8; 3],
     /// Cached minimum value in the dataset.
     pub min: f64,
     /// Cached maximum value in the dataset.
     pub max: f64,
-    /// Cached frequencies for each bin.
+    /// Cached frequencies for each bin: the summed weights of the values in the bin, before
+    /// normalization.
     pub bin_counts: Vec<f64>,
+    /// Fixed range of the bins, set by `set_range`. Values outside it are not counted in any
+    /// bin. The bins span the data when `None`.
+    pub range: Option<(f64, f64)>,
+    /// Whether the values outside `range` are collected in an underflow and an overflow bin,
+    /// drawn as hatched bars beyond the edges of the range.
+    pub overflow_bins: bool,
+    /// Cached summed weights of the values below and above `range`.
+    pub outside_counts: (f64, f64),
     /// Cached width of each bin.
     pub bin_width: f64,
+    /// What the bar heights show: counts, relative frequencies or densities.
+    pub normalization: HistogramNormalization,
+    /// Legend label of the histogram's own data, shown when sets are compared. Defaults to
+    /// the title when empty.
+    pub label: String,
+    /// Additional sample sets compared with the histogram's data. The bins span the values
+    /// of all sets.
+    pub series: Vec<HistogramSeries>,
+    /// Whether compared sets are overlaid or dodged.
+    pub comparison: HistogramComparison,
+    /// Opacity of the bars when sets are overlaid (0.0 to 1.0).
+    pub overlay_alpha: f64,
+    /// A density curve drawn over the bars, scaled to the bar heights.
+    pub density_overlay: Option<HistogramDensityOverlay>,
     /// Configuration settings for rendering the histogram.
     pub config: FigureConfig,
 }
@@ -62,11 +124,21 @@ impl Histogram {
             y_label: y_label.to_string(),
             bins,
             data: Vec::new(),
+            weights: Vec::new(),
             color,
             min: f64::INFINITY,
             max: f64::NEG_INFINITY,
             bin_counts: vec![0.0; bins],
+            range: None,
+            overflow_bins: false,
+            outside_counts: (0.0, 0.0),
             bin_width: 0.0,
+            normalization: HistogramNormalization::Count,
+            label: String::new(),
+            series: Vec::new(),
+            comparison: HistogramComparison::Overlay,
+            overlay_alpha: 0.5,
+            density_overlay: None,
             config,
         }
     }
@@ -101,9 +173,51 @@ impl Histogram {
     /// histogram.add_data(3.5);
     /// ```
     pub fn add_data(&mut self, value: f64) {
+        self.add_weighted_data(value, 1.0);
+    }
+
+    /// Adds multiple weighted data values to the histogram.
+    ///
+    /// # Parameters
+    /// - `samples`: `(value, weight)` pairs to be added to the histogram.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// histogram.add_weighted_data_vec(vec![(1.2, 0.5), (2.5, 2.0)]);
+    /// ```
+    pub fn add_weighted_data_vec(&mut self, samples: Vec<(f64, f64)>) {
+        for (value, weight) in samples {
+            self.add_weighted_data(value, weight);
+        }
+    }
+
+    /// Adds a single data value that counts `weight` times towards its bin.
+    ///
+    /// # Parameters
+    /// - `value`: An `f64` value to be added to the histogram.
+    /// - `weight`: The non-negative weight of the value; `add_data` uses 1.
+    ///
+    /// # Details
+    /// - Updates the cached minimum and maximum values, unless the range is fixed by
+    ///   `set_range`.
+    /// - Recalculates the bin width, and the bin counts of all values if the range changed.
+    /// - The maximum value falls into the last bin.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// histogram.add_weighted_data(3.5, 0.25);
+    /// ```
+    pub fn add_weighted_data(&mut self, value: f64, weight: f64) {
         self.data.push(value);
+        self.weights.push(weight);
+
+        if self.range.is_some() {
+            self.count(value, weight);
+            return;
+        }
 
         // Update min and max
+        let range_changed = value < self.min || value > self.max;
         if value < self.min {
             self.min = value;
         }
@@ -113,12 +227,250 @@ impl Histogram {
 
         // Recalculate bin width and update bin counts
         self.bin_width = (self.max - self.min) / self.bins as f64;
-        if self.bin_width > 0.0 {
+        if range_changed {
+            self.recount();
+        } else {
+            self.count(value, weight);
+        }
+    }
+
+    /// Adds a sample set to compare with the histogram's data, binned with the same edges.
+    ///
+    /// The bins are widened to span the new values if needed, unless the range is fixed by
+    /// `set_range`, and every set is counted again. Each set is normalized on its own, so
+    /// that sets of different sizes can be compared with
+    /// `HistogramNormalization::RelativeFrequency` or `Density`.
+    ///
+    /// # Parameters
+    /// - `label`: The legend label of the set.
+    /// - `values`: The values of the set.
+    /// - `color`: The RGB color of the set's bars.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::histogramcomparison::HistogramComparison;
+    ///
+    /// histogram.label = "Before".to_string();
+    /// histogram.add_series("After", after, [0, 90, 200]);
+    /// histogram.set_comparison(HistogramComparison::Dodge);
+    /// ```
+    pub fn add_series(&mut self, label: &str, values: Vec<f64>, color: [u8; 3]) {
+        if self.range.is_none() {
+            for &value in &values {
+                self.min = self.min.min(value);
+                self.max = self.max.max(value);
+            }
+        }
+        self.series.push(HistogramSeries {
+            label: label.to_string(),
+            weights: vec![1.0; values.len()],
+            data: values,
+            color,
+            bin_counts: Vec::new(),
+            outside_counts: (0.0, 0.0),
+        });
+        self.bin_width = (self.max - self.min) / self.bins as f64;
+        self.recount();
+    }
+
+    /// Sets how compared sample sets are drawn.
+    ///
+    /// # Parameters
+    /// - `comparison`: Overlaid semi-transparent bars or dodged bars per bin.
+    pub fn set_comparison(&mut self, comparison: HistogramComparison) {
+        self.comparison = comparison;
+    }
+
+    /// Fixes the range of the bins instead of spanning the data, e.g. to compare histograms
+    /// of different data with the same bins. Values outside the range are not counted in
+    /// any bin; see `set_overflow_bins` to show them.
+    ///
+    /// # Parameters
+    /// - `min`: The start of the first bin.
+    /// - `max`: The end of the last bin, which includes `max` itself.
+    ///
+    /// # Errors
+    /// Returns an error unless `min` and `max` are finite and `min < max`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// histogram.set_range(0.0, 100.0)?;
+    /// histogram.set_overflow_bins(true);
+    /// ```
+    pub fn set_range(&mut self, min: f64, max: f64) -> Result<(), String> {
+        if !(min.is_finite() && max.is_finite() && min < max) {
+            return Err(format!("Invalid histogram range [{min}, {max}]"));
+        }
+        self.range = Some((min, max));
+        self.min = min;
+        self.max = max;
+        self.bin_width = (max - min) / self.bins as f64;
+        self.recount();
+        Ok(())
+    }
+
+    /// Sets whether the values outside the range set by `set_range` are collected in an
+    /// underflow and an overflow bin. The two bins are drawn as hatched bars one bin width
+    /// beyond the edges of the range, so out-of-range data stays visible.
+    ///
+    /// When shown, their counts are part of the totals the bars are normalized by.
+    ///
+    /// # Parameters
+    /// - `enabled`: `true` to show the bins.
+    pub fn set_overflow_bins(&mut self, enabled: bool) {
+        self.overflow_bins = enabled;
+    }
+
+    /// Returns `true` if the underflow and overflow bins are drawn: they are enabled and the
+    /// range is fixed.
+    pub fn shows_overflow_bins(&self) -> bool {
+        self.overflow_bins && self.range.is_some()
+    }
+
+    /// Returns the range of the x-axis: the range of the bins, extended by one bin width on
+    /// both sides when the underflow and overflow bins are drawn.
+    pub fn x_range(&self) -> (f64, f64) {
+        if self.shows_overflow_bins() {
+            (self.min - self.bin_width, self.max + self.bin_width)
+        } else {
+            (self.min, self.max)
+        }
+    }
+
+    /// Adds the weight of a value to the count of its bin.
+    fn count(&mut self, value: f64, weight: f64) {
+        if let Some(bin_index) = self.bin_index(value) {
+            self.bin_counts[bin_index] += weight;
+        } else if value < self.min {
+            self.outside_counts.0 += weight;
+        } else if value > self.max {
+            self.outside_counts.1 += weight;
+        }
+    }
+
+    /// Returns the bin of a value, or `None` while the bins have no width or if the value
+    /// lies outside the range of the bins.
+    fn bin_index(&self, value: f64) -> Option<usize> {
+        if value < self.min || value > self.max {
+            None
+        } else if self.bin_width > 0.0 {
             let bin_index = ((value - self.min) / self.bin_width).floor() as usize;
-            if bin_index < self.bins {
-                self.bin_counts[bin_index] += 1.0;
+            Some(bin_index.min(self.bins - 1))
+        } else {
+            None
+        }
+    }
+
+    /// Sums the weights of weighted values per bin.
+    fn count_all(&self, data: &[f64], weights: &[f64]) -> Vec<f64> {
+        let mut counts = vec![0.0; self.bins];
+        for (&value, &weight) in data.iter().zip(weights) {
+            if let Some(bin_index) = self.bin_index(value) {
+                counts[bin_index] += weight;
+            }
+        }
+        counts
+    }
+
+    /// Sums the weights of the values below and above the range of the bins.
+    fn count_outside(&self, data: &[f64], weights: &[f64]) -> (f64, f64) {
+        let mut counts = (0.0, 0.0);
+        for (&value, &weight) in data.iter().zip(weights) {
+            if value < self.min {
+                counts.0 += weight;
+            } else if value > self.max {
+                counts.1 += weight;
             }
         }
+        counts
+    }
+
+    /// Counts the values of every set again, after the bins changed.
+    fn recount(&mut self) {
+        self.bin_counts = self.count_all(&self.data, &self.weights);
+        self.outside_counts = self.count_outside(&self.data, &self.weights);
+        let series_counts: Vec<(Vec<f64>, (f64, f64))> = self
+            .series
+            .iter()
+            .map(|series| {
+                (
+                    self.count_all(&series.data, &series.weights),
+                    self.count_outside(&series.data, &series.weights),
+                )
+            })
+            .collect();
+        for (series, (counts, outside)) in self.series.iter_mut().zip(series_counts) {
+            series.bin_counts = counts;
+            series.outside_counts = outside;
+        }
+    }
+
+    /// Sets what the bar heights show.
+    ///
+    /// # Parameters
+    /// - `normalization`: Counts, relative frequencies or densities.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::histogramnormalization::HistogramNormalization;
+    ///
+    /// histogram.set_normalization(HistogramNormalization::Density);
+    /// ```
+    pub fn set_normalization(&mut self, normalization: HistogramNormalization) {
+        self.normalization = normalization;
+    }
+
+    /// Draws a kernel density estimate of the histogram's own data as a curve over the bars.
+    ///
+    /// The curve is scaled to the bars as set by `set_normalization`, so its area equals the
+    /// total area of the bars. Weights are ignored by the estimate.
+    ///
+    /// # Parameters
+    /// - `bandwidth`: The bandwidth of the Gaussian kernel, or `None` for Silverman's rule of
+    ///   thumb.
+    /// - `color`: The RGB color of the curve.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// histogram.set_density_overlay(None, [200, 30, 30]);
+    /// ```
+    pub fn set_density_overlay(&mut self, bandwidth: Option<f64>, color: [u8; 3]) {
+        self.density_overlay = Some(HistogramDensityOverlay { bandwidth, color });
+    }
+
+    /// Evaluates the density overlay over the range of the bins.
+    ///
+    /// # Returns
+    /// Pairs of `(value, height)` in the units of the bar heights, or an empty vector without
+    /// an overlay or when the density cannot be estimated.
+    pub fn density_curve(&self) -> Vec<(f64, f64)> {
+        let Some(overlay) = &self.density_overlay else {
+            return Vec::new();
+        };
+        let total: f64 = self.bin_counts.iter().sum();
+        let scale = match self.normalization {
+            HistogramNormalization::Count => total * self.bin_width,
+            HistogramNormalization::RelativeFrequency => self.bin_width,
+            HistogramNormalization::Density => 1.0,
+        };
+        kernel_density_between(
+            &self.data,
+            overlay.bandwidth,
+            (self.min, self.max),
+            OVERLAY_POINTS,
+        )
+        .into_iter()
+        .map(|(value, density)| (value, density * scale))
+        .collect()
+    }
+
+    /// Returns the number of decimals of the default y tick labels: fractions need more than
+    /// counts.
+    pub(crate) fn y_decimals(&self) -> usize {
+        match self.normalization {
+            HistogramNormalization::Count => 1,
+            HistogramNormalization::RelativeFrequency | HistogramNormalization::Density => 3,
+        }
     }
 
     /// Calculates the bin ranges and frequencies for the histogram.
@@ -126,7 +478,7 @@ impl Histogram {
     /// # Returns
     /// A vector of tuples where each tuple contains:
     /// - The starting value of the bin.
-    /// - The frequency count for that bin.
+    /// - The bar height of that bin, normalized as set by `set_normalization`.
     ///
     /// # Example
     /// ```rust,ignore
@@ -136,10 +488,128 @@ impl Histogram {
     /// }
     /// ```
     pub fn calculate_bins(&self) -> Vec<(f64, f64)> {
-        self.bin_counts
+        self.normalize(&self.bin_counts, self.outside_counts)
+    }
+
+    /// Calculates the bins of every sample set, the histogram's own data first.
+    ///
+    /// # Returns
+    /// One vector per set, in the format of `calculate_bins`.
+    pub fn calculate_all_bins(&self) -> Vec<Vec<(f64, f64)>> {
+        std::iter::once(self.calculate_bins())
+            .chain(
+                self.series
+                    .iter()
+                    .map(|series| self.normalize(&series.bin_counts, series.outside_counts)),
+            )
+            .collect()
+    }
+
+    /// Calculates the underflow and overflow bars of every sample set, the histogram's own
+    /// data first.
+    ///
+    /// # Returns
+    /// One `[underflow, overflow]` pair per set, each bar as `(start, height)` in the format
+    /// of `calculate_bins`, or an empty vector unless the bars are drawn (see
+    /// `set_overflow_bins`).
+    pub fn calculate_overflow_bins(&self) -> Vec<[(f64, f64); 2]> {
+        if !self.shows_overflow_bins() {
+            return Vec::new();
+        }
+        std::iter::once((&self.bin_counts, self.outside_counts))
+            .chain(
+                self.series
+                    .iter()
+                    .map(|series| (&series.bin_counts, series.outside_counts)),
+            )
+            .map(|(bin_counts, outside)| {
+                let scale = self.normalization_scale(bin_counts, outside);
+                [
+                    (self.min - self.bin_width, outside.0 * scale),
+                    (self.max, outside.1 * scale),
+                ]
+            })
+            .collect()
+    }
+
+    /// Returns the tallest bar height of all sets, including underflow and overflow bars, or
+    /// the top of the density overlay if it is higher.
+    pub fn y_max(&self) -> f64 {
+        self.calculate_all_bins()
+            .iter()
+            .flatten()
+            .chain(self.calculate_overflow_bins().iter().flatten())
+            .map(|&(_, freq)| freq)
+            .chain(self.density_curve().into_iter().map(|(_, height)| height))
+            .fold(0.0, f64::max)
+    }
+
+    /// Returns the `(label, color)` of every sample set, the histogram's own data first.
+    pub fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        let label = if self.label.is_empty() {
+            &self.title
+        } else {
+            &self.label
+        };
+        std::iter::once((label.clone(), self.color))
+            .chain(
+                self.series
+                    .iter()
+                    .map(|series| (series.label.clone(), series.color)),
+            )
+            .collect()
+    }
+
+    /// Returns the horizontal extent of a set's bars within their bins.
+    ///
+    /// # Parameters
+    /// - `set_index`: The index of the set, `0` for the histogram's own data.
+    ///
+    /// # Returns
+    /// `(start, end)` as fractions of the bin width: the whole bin unless sets are dodged.
+    pub fn bar_fraction(&self, set_index: usize) -> (f64, f64) {
+        let sets = self.series.len() + 1;
+        match self.comparison {
+            HistogramComparison::Dodge if sets > 1 => (
+                set_index as f64 / sets as f64,
+                (set_index + 1) as f64 / sets as f64,
+            ),
+            _ => (0.0, 1.0),
+        }
+    }
+
+    /// Returns the opacity of the bars: `overlay_alpha` when sets are overlaid, otherwise
+    /// opaque.
+    pub fn bar_alpha(&self) -> f64 {
+        if self.comparison == HistogramComparison::Overlay && !self.series.is_empty() {
+            self.overlay_alpha.clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns the factor bin counts are scaled by, as set by `set_normalization`. The
+    /// total includes the values outside the range while the overflow bins are drawn.
+    fn normalization_scale(&self, bin_counts: &[f64], outside: (f64, f64)) -> f64 {
+        let mut total: f64 = bin_counts.iter().sum();
+        if self.shows_overflow_bins() {
+            total += outside.0 + outside.1;
+        }
+        match self.normalization {
+            HistogramNormalization::Count => 1.0,
+            _ if total == 0.0 => 0.0,
+            HistogramNormalization::RelativeFrequency => 1.0 / total,
+            HistogramNormalization::Density => 1.0 / (total * self.bin_width),
+        }
+    }
+
+    /// Scales bin counts as set by `set_normalization`.
+    fn normalize(&self, bin_counts: &[f64], outside: (f64, f64)) -> Vec<(f64, f64)> {
+        let scale = self.normalization_scale(bin_counts, outside);
+        bin_counts
             .iter()
             .enumerate()
-            .map(|(i, &freq)| (self.min + i as f64 * self.bin_width, freq))
+            .map(|(i, &freq)| (self.min + i as f64 * self.bin_width, freq * scale))
             .collect()
     }
 }
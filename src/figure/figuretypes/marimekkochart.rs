@@ -0,0 +1,192 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig, datasets::segmentdataset::SegmentDataset,
+};
+
+/// A single cell of a Marimekko chart: one segment within one category.
+///
+/// Coordinates are fractions of the plot area, with `y` measured upwards from the x-axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MarimekkoCell {
+    /// Index of the category (column).
+    pub category: usize,
+    /// Index of the segment (dataset).
+    pub segment: usize,
+    /// The value of the segment in the category.
+    pub value: f64,
+    /// Left edge of the cell.
+    pub x0: f64,
+    /// Right edge of the cell.
+    pub x1: f64,
+    /// Bottom edge of the cell.
+    pub y0: f64,
+    /// Top edge of the cell.
+    pub y1: f64,
+}
+
+impl MarimekkoCell {
+    /// Returns the share of the cell within its category, between 0 and 1.
+    pub fn share(&self) -> f64 {
+        self.y1 - self.y0
+    }
+}
+
+/// Represents a Marimekko (mosaic) chart: a stacked 100% bar chart whose bar widths are
+/// proportional to the category totals.
+///
+/// Both dimensions of a cell encode values: its width is the category's share of the grand
+/// total (e.g., market size per region) and its height is the segment's share within the
+/// category (e.g., market share per brand), so cell areas are proportional to the values.
+#[derive(Clone)]
+pub struct MarimekkoChart {
+    /// Title of the chart.
+    pub title: String,
+    /// Label for the X-axis (the category axis).
+    pub x_label: String,
+    /// Label for the Y-axis (the share axis).
+    pub y_label: String,
+    /// Names of the categories, drawn as columns from left to right.
+    pub categories: Vec<String>,
+    /// One dataset per segment, stacked from the bottom of each column.
+    pub datasets: Vec<SegmentDataset>,
+    /// Whether cells large enough to hold it show their share as a label.
+    pub show_labels: bool,
+    /// Configuration settings for rendering the chart (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl MarimekkoChart {
+    /// Creates a new `MarimekkoChart` instance with the specified title, labels, and configuration.
+    ///
+    /// Cell labels are shown by default.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the chart.
+    /// - `x_label`: The label for the category axis.
+    /// - `y_label`: The label for the share axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `MarimekkoChart` instance with no categories or segments.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::datasets::segmentdataset::SegmentDataset;
+    /// use dataviz::figure::figuretypes::marimekkochart::MarimekkoChart;
+    ///
+    /// let mut chart = MarimekkoChart::new("Market Share", "Region", "Share", config);
+    /// chart.add_category("Europe");
+    /// chart.add_category("Asia");
+    ///
+    /// let mut premium = SegmentDataset::new("Premium", [120, 40, 140]);
+    /// premium.add_values(&[12.0, 30.0]);
+    /// chart.add_dataset(premium);
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            categories: Vec::new(),
+            datasets: Vec::new(),
+            show_labels: true,
+            config,
+        }
+    }
+
+    /// Adds a category (column) to the chart.
+    ///
+    /// # Parameters
+    /// - `label`: The name of the category, shown below its column.
+    pub fn add_category(&mut self, label: &str) {
+        self.categories.push(label.to_string());
+    }
+
+    /// Adds a segment to the chart.
+    ///
+    /// # Parameters
+    /// - `dataset`: The `SegmentDataset` holding the segment's value in each category.
+    pub fn add_dataset(&mut self, dataset: SegmentDataset) {
+        self.datasets.push(dataset);
+    }
+
+    /// Returns the value of a segment in a category.
+    ///
+    /// Missing and negative values count as zero, as they have no area.
+    pub fn value(&self, category: usize, segment: usize) -> f64 {
+        self.datasets[segment]
+            .values
+            .get(category)
+            .copied()
+            .filter(|value| value.is_finite())
+            .unwrap_or(0.0)
+            .max(0.0)
+    }
+
+    /// Computes the total of every category.
+    pub fn category_totals(&self) -> Vec<f64> {
+        (0..self.categories.len())
+            .map(|category| {
+                (0..self.datasets.len())
+                    .map(|segment| self.value(category, segment))
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Lays out the cells of the chart.
+    ///
+    /// Categories with a zero total have no width and produce no cells.
+    ///
+    /// # Returns
+    /// The cells column by column, each column stacked from the bottom.
+    pub fn cells(&self) -> Vec<MarimekkoCell> {
+        let totals = self.category_totals();
+        let grand_total: f64 = totals.iter().sum();
+        if grand_total <= 0.0 {
+            return Vec::new();
+        }
+
+        let mut cells = Vec::new();
+        let mut x0 = 0.0;
+        for (category, &total) in totals.iter().enumerate() {
+            if total <= 0.0 {
+                continue;
+            }
+            let x1 = x0 + total / grand_total;
+            let mut y0 = 0.0;
+            for segment in 0..self.datasets.len() {
+                let value = self.value(category, segment);
+                let y1 = y0 + value / total;
+                if value > 0.0 {
+                    cells.push(MarimekkoCell {
+                        category,
+                        segment,
+                        value,
+                        x0,
+                        x1,
+                        y0,
+                        y1,
+                    });
+                }
+                y0 = y1;
+            }
+            x0 = x1;
+        }
+        cells
+    }
+
+    /// Finds the cell containing a point of the plot area.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The point as fractions of the plot area, with `y` measured upwards.
+    pub fn cell_at(&self, x: f64, y: f64) -> Option<MarimekkoCell> {
+        self.cells()
+            .into_iter()
+            .find(|cell| (cell.x0..cell.x1).contains(&x) && (cell.y0..cell.y1).contains(&y))
+    }
+
+    /// Formats the label of a cell: its share within the category, in percent.
+    pub fn cell_label(&self, cell: &MarimekkoCell) -> String {
+        self.config.format.format_percent(cell.share() * 100.0, 0)
+    }
+}
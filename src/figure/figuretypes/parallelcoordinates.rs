@@ -0,0 +1,398 @@
+use crate::figure::configuration::figureconfig::FigureConfig;
+
+/// Space in pixels kept above the axes for their names.
+pub const AXIS_NAME_SPACE: f64 = 30.0;
+
+/// A record of a `ParallelCoordinates` plot: one value per axis.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParallelRecord {
+    /// The values, in axis order. Non-finite values break the polyline.
+    pub values: Vec<f64>,
+    /// The category coloring the polyline, or `None` for the default color.
+    pub category: Option<String>,
+}
+
+/// Represents a parallel coordinates plot: vertical axes side by side, each scaled to its
+/// own range, with every record drawn as a polyline through its value on each axis.
+///
+/// Lines are colored by category and drawn translucent, so dense bundles of records show
+/// up darker than isolated ones.
+#[derive(Clone)]
+pub struct ParallelCoordinates {
+    /// Title of the plot.
+    pub title: String,
+    /// Names of the axes, from left to right.
+    pub axes: Vec<String>,
+    /// The records, one polyline each.
+    pub records: Vec<ParallelRecord>,
+    /// Fixed range of each axis, or `None` to fit the values of the records.
+    pub axis_ranges: Vec<Option<(f64, f64)>>,
+    /// Colors set for categories, overriding the palette.
+    pub category_colors: Vec<(String, [u8; 3])>,
+    /// Colors assigned to the other categories, in order of first appearance.
+    pub palette: Vec<[u8; 3]>,
+    /// Color of the records without a category.
+    pub color: [u8; 3],
+    /// Opacity of the lines (0.0 to 1.0).
+    pub line_alpha: f64,
+    /// Configuration settings for rendering the plot (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl ParallelCoordinates {
+    /// Colors of the default palette.
+    pub const CATEGORY_COLORS: [[u8; 3]; 8] = [
+        [31, 119, 180],
+        [255, 127, 14],
+        [44, 160, 44],
+        [214, 39, 40],
+        [148, 103, 189],
+        [140, 86, 75],
+        [227, 119, 194],
+        [23, 190, 207],
+    ];
+
+    /// Creates a new `ParallelCoordinates` instance with the specified axes and
+    /// configuration.
+    ///
+    /// Lines are drawn with an opacity of `0.5`; records without a category are steel blue.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the plot.
+    /// - `axes`: The names of the axes, from left to right.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `ParallelCoordinates` instance without records.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::parallelcoordinates::ParallelCoordinates;
+    ///
+    /// let mut plot = ParallelCoordinates::new(
+    ///     "Iris",
+    ///     &["Sepal length", "Sepal width", "Petal length", "Petal width"],
+    ///     config,
+    /// );
+    /// plot.add_record(&[5.1, 3.5, 1.4, 0.2], Some("setosa"))?;
+    /// plot.add_record(&[7.0, 3.2, 4.7, 1.4], Some("versicolor"))?;
+    /// plot.set_line_alpha(0.3);
+    /// ```
+    pub fn new(title: &str, axes: &[&str], config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            axes: axes.iter().map(|axis| axis.to_string()).collect(),
+            records: Vec::new(),
+            axis_ranges: vec![None; axes.len()],
+            category_colors: Vec::new(),
+            palette: Self::CATEGORY_COLORS.to_vec(),
+            color: [70, 130, 180],
+            line_alpha: 0.5,
+            config,
+        }
+    }
+
+    /// Adds a record.
+    ///
+    /// # Parameters
+    /// - `values`: One value per axis, in axis order.
+    /// - `category`: The category coloring the line, or `None` for the default color.
+    ///
+    /// # Errors
+    /// Returns an error, without adding the record, if the number of values differs from
+    /// the number of axes.
+    pub fn add_record(&mut self, values: &[f64], category: Option<&str>) -> Result<(), String> {
+        if values.len() != self.axes.len() {
+            return Err(format!(
+                "Expected {} values, one per axis, got {}",
+                self.axes.len(),
+                values.len()
+            ));
+        }
+        self.records.push(ParallelRecord {
+            values: values.to_vec(),
+            category: category.map(str::to_string),
+        });
+        Ok(())
+    }
+
+    /// Fixes the range of an axis, e.g. to show a score on its full scale.
+    ///
+    /// # Parameters
+    /// - `axis`: The name of the axis.
+    /// - `min`, `max`: The values at the bottom and top of the axis. Values outside are
+    ///   drawn beyond the axis ends.
+    ///
+    /// # Errors
+    /// Returns an error if no axis has the name, or if the range is empty or not finite.
+    pub fn set_axis_range(&mut self, axis: &str, min: f64, max: f64) -> Result<(), String> {
+        let index = self
+            .axes
+            .iter()
+            .position(|name| name == axis)
+            .ok_or_else(|| format!("No axis named '{axis}'"))?;
+        if !(min.is_finite() && max.is_finite() && min < max) {
+            return Err(format!("Invalid range {min}..{max} for axis '{axis}'"));
+        }
+        self.axis_ranges.resize(self.axes.len(), None);
+        self.axis_ranges[index] = Some((min, max));
+        Ok(())
+    }
+
+    /// Sets the line color of a category.
+    ///
+    /// # Parameters
+    /// - `category`: The category.
+    /// - `color`: The RGB color.
+    pub fn set_category_color(&mut self, category: &str, color: [u8; 3]) {
+        match self
+            .category_colors
+            .iter_mut()
+            .find(|(name, _)| name == category)
+        {
+            Some((_, existing)) => *existing = color,
+            None => self.category_colors.push((category.to_string(), color)),
+        }
+    }
+
+    /// Sets the colors assigned to categories without their own color.
+    ///
+    /// # Parameters
+    /// - `palette`: The colors, repeated when there are more categories than colors. An
+    ///   empty palette restores `CATEGORY_COLORS`.
+    pub fn set_palette(&mut self, palette: &[[u8; 3]]) {
+        self.palette = if palette.is_empty() {
+            Self::CATEGORY_COLORS.to_vec()
+        } else {
+            palette.to_vec()
+        };
+    }
+
+    /// Sets the opacity of the lines.
+    ///
+    /// # Parameters
+    /// - `alpha`: The opacity, clamped to `0.0..=1.0`; lower values keep dense data
+    ///   readable.
+    pub fn set_line_alpha(&mut self, alpha: f64) {
+        self.line_alpha = alpha.clamp(0.0, 1.0);
+    }
+
+    /// Returns the categories of the records in order of first appearance.
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories: Vec<&str> = Vec::new();
+        for category in self.records.iter().filter_map(|r| r.category.as_deref()) {
+            if !categories.contains(&category) {
+                categories.push(category);
+            }
+        }
+        categories
+    }
+
+    /// Returns the color of a category: its own or one of the palette by order of first
+    /// appearance.
+    pub fn category_color(&self, category: &str) -> [u8; 3] {
+        if let Some(&(_, color)) = self.category_colors.iter().find(|(c, _)| c == category) {
+            return color;
+        }
+        let palette: &[[u8; 3]] = if self.palette.is_empty() {
+            &Self::CATEGORY_COLORS
+        } else {
+            &self.palette
+        };
+        let index = self
+            .categories()
+            .iter()
+            .position(|&c| c == category)
+            .unwrap_or(0);
+        palette[index % palette.len()]
+    }
+
+    /// Returns the line color of a record.
+    pub fn record_color(&self, record: usize) -> [u8; 3] {
+        match &self.records[record].category {
+            Some(category) => self.category_color(category),
+            None => self.color,
+        }
+    }
+
+    /// Returns the indices of the records in drawing order: records without a category
+    /// first, then the records of each category in order of first appearance.
+    pub fn draw_order(&self) -> Vec<usize> {
+        let uncategorized = (0..self.records.len()).filter(|&i| self.records[i].category.is_none());
+        let categorized = self.categories().into_iter().flat_map(|category| {
+            (0..self.records.len())
+                .filter(move |&i| self.records[i].category.as_deref() == Some(category))
+        });
+        uncategorized.chain(categorized).collect()
+    }
+
+    /// Returns the range of an axis: its fixed range, or the range of the finite values of
+    /// the records. Without values, or if all values are equal, the range is widened by 1
+    /// on both sides.
+    pub fn axis_range(&self, axis: usize) -> (f64, f64) {
+        if let Some(Some(range)) = self.axis_ranges.get(axis) {
+            return *range;
+        }
+        let (min, max) = self
+            .records
+            .iter()
+            .filter_map(|record| record.values.get(axis).copied())
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        if min > max {
+            (0.0, 1.0)
+        } else if min == max {
+            (min - 1.0, max + 1.0)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// Computes the plot area on a canvas, leaving room above the axes for their names.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(left, top, right, bottom)` in canvas coordinates. The first axis lies on
+    /// `left`, the last one on `right`.
+    pub fn plot_area(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64, f64) {
+        (
+            margin,
+            margin + AXIS_NAME_SPACE,
+            (width - margin).max(margin + 1.0),
+            (height - margin).max(margin + AXIS_NAME_SPACE + 1.0),
+        )
+    }
+
+    /// Returns the x-coordinate of an axis. A single axis is centered.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis index.
+    /// - `area`: The plot area returned by `plot_area`.
+    pub fn axis_x(&self, axis: usize, area: (f64, f64, f64, f64)) -> f64 {
+        let (left, _, right, _) = area;
+        if self.axes.len() < 2 {
+            return (left + right) / 2.0;
+        }
+        left + axis as f64 * (right - left) / (self.axes.len() - 1) as f64
+    }
+
+    /// Converts a value on an axis to canvas coordinates.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis index.
+    /// - `value`: The value.
+    /// - `area`: The plot area returned by `plot_area`.
+    pub fn to_canvas(&self, axis: usize, value: f64, area: (f64, f64, f64, f64)) -> (f64, f64) {
+        let (_, top, _, bottom) = area;
+        let (min, max) = self.axis_range(axis);
+        (
+            self.axis_x(axis, area),
+            bottom - (value - min) / (max - min) * (bottom - top),
+        )
+    }
+
+    /// Computes the polylines of a record on a canvas.
+    ///
+    /// # Parameters
+    /// - `record`: The record index.
+    /// - `area`: The plot area returned by `plot_area`.
+    ///
+    /// # Returns
+    /// The vertices of each polyline; the line is split at non-finite values.
+    pub fn record_lines(&self, record: usize, area: (f64, f64, f64, f64)) -> Vec<Vec<(f64, f64)>> {
+        let mut lines = vec![Vec::new()];
+        for (axis, &value) in self.records[record].values.iter().enumerate() {
+            if value.is_finite() {
+                lines
+                    .last_mut()
+                    .unwrap()
+                    .push(self.to_canvas(axis, value, area));
+            } else if !lines.last().unwrap().is_empty() {
+                lines.push(Vec::new());
+            }
+        }
+        lines.retain(|line| !line.is_empty());
+        lines
+    }
+
+    /// Finds the record whose polyline passes closest to a canvas position. Of records at
+    /// the same distance, the one drawn last (on top) wins.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas position.
+    /// - `area`: The plot area returned by `plot_area`.
+    /// - `max_distance`: The largest distance in pixels.
+    ///
+    /// # Returns
+    /// The record index and the axis whose vertex is closest to the position, or `None`
+    /// if no line is close enough.
+    pub fn record_at(
+        &self,
+        x: f64,
+        y: f64,
+        area: (f64, f64, f64, f64),
+        max_distance: f64,
+    ) -> Option<(usize, usize)> {
+        let mut closest: Option<(usize, f64)> = None;
+        for record in self.draw_order() {
+            let distance = self
+                .record_lines(record, area)
+                .iter()
+                .flat_map(|line| {
+                    let single = (line.len() == 1).then(|| (line[0], line[0]));
+                    line.windows(2).map(|w| (w[0], w[1])).chain(single)
+                })
+                .map(|(a, b)| distance_to_segment((x, y), a, b))
+                .fold(f64::INFINITY, f64::min);
+            if distance <= max_distance && closest.is_none_or(|(_, best)| distance <= best) {
+                closest = Some((record, distance));
+            }
+        }
+        let (record, _) = closest?;
+        let axis = (0..self.axes.len())
+            .filter(|&axis| self.records[record].values[axis].is_finite())
+            .min_by(|&a, &b| {
+                (self.axis_x(a, area) - x)
+                    .abs()
+                    .total_cmp(&(self.axis_x(b, area) - x).abs())
+            })?;
+        Some((record, axis))
+    }
+
+    /// Formats a value for tick labels and tooltips.
+    pub fn format_value(&self, value: f64) -> String {
+        self.config.format.format_number(value, 2)
+    }
+
+    /// Returns the tooltip text of a record: its category and its value on every axis.
+    pub fn record_tooltip(&self, record: usize) -> String {
+        let record = &self.records[record];
+        let values: Vec<String> = self
+            .axes
+            .iter()
+            .zip(&record.values)
+            .map(|(axis, &value)| format!("{axis}: {}", self.format_value(value)))
+            .collect();
+        match &record.category {
+            Some(category) => format!("{category}, {}", values.join(", ")),
+            None => values.join(", "),
+        }
+    }
+}
+
+/// Returns the distance from a point to a line segment.
+fn distance_to_segment(point: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared > 0.0 {
+        (((point.0 - a.0) * dx + (point.1 - a.1) * dy) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (point.0 - (a.0 + t * dx)).hypot(point.1 - (a.1 + t * dy))
+}
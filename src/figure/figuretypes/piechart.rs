@@ -1,6 +1,49 @@
-use crate::figure::{canvas::pixelcanvas::PixelCanvas, configuration::figureconfig::FigureConfig};
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas,
+    configuration::figureconfig::FigureConfig,
+    utilities::{
+        arcgeometry::fill_ring_segment, colormap::MISSING_COLOR, legendposition::LegendPosition,
+        textmetrics::measure_text_with_fallbacks,
+    },
+};
+
+/// Space in pixels between legend entries, and between the legend and the pie.
+pub const LEGEND_SPACING: f64 = 10.0;
+
+/// Placement of a pie and its legend on a canvas.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PieLayout {
+    /// Center of the pie.
+    pub center: (f64, f64),
+    /// Radius of the pie.
+    pub radius: f64,
+    /// Top-left corner of each legend entry, in slice order. Empty without legend mode.
+    pub entries: Vec<(f64, f64)>,
+}
+
+/// Merging of small slices into a single slice, so that slivers don't clutter the pie.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OtherSlice {
+    /// Share of the total, in percent, below which a slice is merged.
+    pub threshold: f64,
+    /// Label of the merged slice.
+    pub label: String,
+    /// RGB color of the merged slice.
+    pub color: [u8; 3],
+}
+
+/// A ring of values drawn inside the slices of a pie chart, e.g. last year's values to
+/// compare with this year's.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PieRing {
+    /// Name of the ring, shown in tooltips, e.g. `"2023"`.
+    pub label: String,
+    /// Values by category label, matched to the chart's slices by label.
+    pub values: Vec<(String, f64)>,
+}
 
 /// Represents a pie chart with title, datasets, and configuration settings.
+#[derive(Clone)]
 pub struct PieChart {
     /// Title of the pie chart.
     pub title: String,
@@ -9,6 +52,19 @@ pub struct PieChart {
     /// - A value (`f64`).
     /// - A color in RGB format (`[u8; 3]`).
     pub datasets: Vec<(String, f64, [u8; 3])>,
+    /// Whether slices are identified by a legend of swatches, labels, values and percentages,
+    /// placed according to `config.legend_position`, instead of percentages on the slices.
+    pub legend: bool,
+    /// Merging of small slices into one slice, or `None` to draw every slice.
+    pub other: Option<OtherSlice>,
+    /// Radius of the hollow center as a fraction of the radius, from `0.0` (a pie) to below
+    /// `1.0` (a thin ring).
+    pub inner_radius: f64,
+    /// Text shown in the hollow center of a donut chart, e.g. the total.
+    pub center_text: Option<String>,
+    /// Rings drawn inside the slices, outermost first, sharing the colors and legend of
+    /// the slices.
+    pub rings: Vec<PieRing>,
     /// Configuration settings for rendering the chart (e.g., fonts, colors, grid).
     pub config: FigureConfig,
 }
@@ -35,6 +91,11 @@ impl PieChart {
         Self {
             title: title.to_string(),
             datasets: Vec::new(),
+            legend: false,
+            other: None,
+            inner_radius: 0.0,
+            center_text: None,
+            rings: Vec::new(),
             config,
         }
     }
@@ -56,6 +117,423 @@ impl PieChart {
         self.datasets.push((label.to_string(), value, color));
     }
 
+    /// Enables or disables legend mode.
+    ///
+    /// Legend mode keeps small slices readable: instead of a percentage on every slice,
+    /// each slice gets a legend entry with a color swatch, its label, value and percentage.
+    ///
+    /// # Parameters
+    /// - `legend`: `true` to show the legend instead of the slice labels.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pie_chart.config.legend_position = LegendPosition::Right;
+    /// pie_chart.set_legend(true);
+    /// ```
+    pub fn set_legend(&mut self, legend: bool) {
+        self.legend = legend;
+    }
+
+    /// Merges slices below a share of the total into one slice drawn last.
+    ///
+    /// Nothing is merged unless at least two slices fall below the threshold, as a single
+    /// merged slice would only lose its label.
+    ///
+    /// # Parameters
+    /// - `threshold`: Share of the total, in percent, below which a slice is merged.
+    /// - `label`: The label of the merged slice, e.g. `"Other"`.
+    /// - `color`: The RGB color of the merged slice.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pie_chart.set_other_slice(3.0, "Other", [160, 160, 160]);
+    /// ```
+    pub fn set_other_slice(&mut self, threshold: f64, label: &str, color: [u8; 3]) {
+        self.other = Some(OtherSlice {
+            threshold,
+            label: label.to_string(),
+            color,
+        });
+    }
+
+    /// Turns the pie into a donut chart with a hollow center.
+    ///
+    /// Slice percentages are drawn in the middle of the ring.
+    ///
+    /// # Parameters
+    /// - `inner_radius`: Radius of the hollow center as a fraction of the radius, clamped
+    ///   to `0.0..=0.95`. `0.0` draws a pie again.
+    /// - `center_text`: Text shown in the center, e.g. the total, or `None`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pie_chart.set_donut(0.5, Some("Total: 100"));
+    /// ```
+    pub fn set_donut(&mut self, inner_radius: f64, center_text: Option<&str>) {
+        self.inner_radius = inner_radius.clamp(0.0, 0.95);
+        self.center_text = center_text.map(str::to_string);
+    }
+
+    /// Adds a ring inside the slices, to compare the composition of another set of values,
+    /// e.g. last year's, with the slices.
+    ///
+    /// Rings split the space between the hollow center and the radius evenly, the slices
+    /// taking the outermost ring. Values are matched to the slices by category label and
+    /// drawn in their colors and order, so the chart keeps a single legend. Categories
+    /// without a slice are drawn last in `MISSING_COLOR`; add them as slices with a zero
+    /// value to give them a color.
+    ///
+    /// # Parameters
+    /// - `label`: The name of the ring, shown in tooltips.
+    /// - `values`: The `(category label, value)` pairs of the ring.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pie_chart.add_slice("Product A", 30.0, [255, 0, 0]);
+    /// pie_chart.add_slice("Product B", 50.0, [0, 255, 0]);
+    /// pie_chart.add_ring("Last year", &[("Product A", 45.0), ("Product B", 40.0)]);
+    /// pie_chart.set_donut(0.3, None);
+    /// ```
+    pub fn add_ring(&mut self, label: &str, values: &[(&str, f64)]) {
+        self.rings.push(PieRing {
+            label: label.to_string(),
+            values: values
+                .iter()
+                .map(|&(category, value)| (category.to_string(), value))
+                .collect(),
+        });
+    }
+
+    /// Returns the number of rings, the slices included.
+    pub fn ring_count(&self) -> usize {
+        1 + self.rings.len()
+    }
+
+    /// Returns the inner and outer radius of a ring as fractions of the radius.
+    ///
+    /// # Parameters
+    /// - `ring`: The ring, `0` for the slices and `1..` for the rings added with
+    ///   `add_ring`.
+    pub fn ring_radii(&self, ring: usize) -> (f64, f64) {
+        let width = (1.0 - self.inner_radius) / self.ring_count() as f64;
+        let outer = 1.0 - ring as f64 * width;
+        (outer - width, outer)
+    }
+
+    /// Returns the ring at a distance from the center, as a fraction of the radius, or
+    /// `None` in the hollow center or outside the chart.
+    pub fn ring_at(&self, distance: f64) -> Option<usize> {
+        (0..self.ring_count()).find(|&ring| {
+            let (inner, outer) = self.ring_radii(ring);
+            distance >= inner && distance <= outer
+        })
+    }
+
+    /// Returns the distance of the slice labels of a ring from the center as a fraction of
+    /// the radius: the middle of the ring, or `0.6` of the outer radius for a ring without
+    /// a hollow center.
+    pub fn ring_label_radius(&self, ring: usize) -> f64 {
+        match self.ring_radii(ring) {
+            (inner, outer) if inner > 0.0 => (inner + outer) / 2.0,
+            (_, outer) => outer * 0.6,
+        }
+    }
+
+    /// Returns the distance of the slice labels from the center as a fraction of the
+    /// radius: the middle of the ring for donuts, otherwise `0.6`.
+    pub fn label_radius(&self) -> f64 {
+        self.ring_label_radius(0)
+    }
+
+    /// Returns the indices of the datasets merged into the other slice, in insertion order.
+    fn merged_indices(&self) -> Vec<usize> {
+        let Some(other) = &self.other else {
+            return Vec::new();
+        };
+        let total: f64 = self.datasets.iter().map(|(_, value, _)| value).sum();
+        if total <= 0.0 {
+            return Vec::new();
+        }
+        let merged: Vec<usize> = (0..self.datasets.len())
+            .filter(|&index| self.datasets[index].1 / total * 100.0 < other.threshold)
+            .collect();
+        if merged.len() < 2 {
+            return Vec::new();
+        }
+        merged
+    }
+
+    /// Returns the labels of the datasets merged into the other slice, for legends and
+    /// tooltips. Empty when no slice is merged.
+    pub fn merged_labels(&self) -> Vec<&str> {
+        self.merged_indices()
+            .into_iter()
+            .map(|index| self.datasets[index].0.as_str())
+            .collect()
+    }
+
+    /// Returns the slices as drawn: the datasets above the merge threshold in insertion
+    /// order, followed by the other slice if any datasets were merged into it.
+    ///
+    /// # Returns
+    /// `(label, value, color)` of each drawn slice.
+    pub fn slices(&self) -> Vec<(String, f64, [u8; 3])> {
+        let merged = self.merged_indices();
+        let mut slices: Vec<(String, f64, [u8; 3])> = self
+            .datasets
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !merged.contains(index))
+            .map(|(_, slice)| slice.clone())
+            .collect();
+        if let (Some(other), false) = (&self.other, merged.is_empty()) {
+            let value = merged.iter().map(|&index| self.datasets[index].1).sum();
+            slices.push((other.label.clone(), value, other.color));
+        }
+        slices
+    }
+
+    /// Returns the slices of a ring as drawn, in the order and colors of the chart's slices.
+    ///
+    /// Values of categories merged into the other slice are merged in every ring.
+    ///
+    /// # Parameters
+    /// - `ring`: The ring, `0` for the slices and `1..` for the rings added with
+    ///   `add_ring`.
+    ///
+    /// # Returns
+    /// `(label, value, color)` of each drawn slice of the ring.
+    pub fn ring_slices(&self, ring: usize) -> Vec<(String, f64, [u8; 3])> {
+        let slices = self.slices();
+        let Some(ring) = ring.checked_sub(1).and_then(|index| self.rings.get(index)) else {
+            return slices;
+        };
+        let merged = self.merged_labels();
+        let value_of = |matches: &dyn Fn(&str) -> bool| -> Option<f64> {
+            let values: Vec<f64> = ring
+                .values
+                .iter()
+                .filter(|(category, _)| matches(category))
+                .map(|&(_, value)| value)
+                .collect();
+            (!values.is_empty()).then(|| values.iter().sum())
+        };
+
+        let other_index = (!merged.is_empty()).then(|| slices.len() - 1);
+        let mut ring_slices: Vec<(String, f64, [u8; 3])> = slices
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (label, _, color))| {
+                let value = if Some(index) == other_index {
+                    value_of(&|category| merged.contains(&category))
+                } else {
+                    value_of(&|category| category == label)
+                }?;
+                Some((label.clone(), value, *color))
+            })
+            .collect();
+        for (category, value) in &ring.values {
+            let known = merged.contains(&category.as_str())
+                || self.datasets.iter().any(|(label, _, _)| label == category);
+            if !known {
+                ring_slices.push((category.clone(), *value, MISSING_COLOR));
+            }
+        }
+        ring_slices
+    }
+
+    /// Returns the tooltip of a drawn slice of a ring: the tooltip of a slice, or the ring
+    /// name followed by the legend text for the rings added with `add_ring`, e.g.
+    /// `"Last year: Product A: 45 (52.9%)"`.
+    ///
+    /// # Parameters
+    /// - `ring`: The ring, `0` for the slices.
+    /// - `index`: The index of the slice in `ring_slices(ring)`.
+    pub fn ring_tooltip_text(&self, ring: usize, index: usize) -> String {
+        match ring.checked_sub(1).and_then(|index| self.rings.get(index)) {
+            Some(pie_ring) => format!(
+                "{}: {}",
+                pie_ring.label,
+                self.slice_text(&self.ring_slices(ring), index)
+            ),
+            None => self.tooltip_text(index),
+        }
+    }
+
+    /// Returns the tooltip of a drawn slice: its legend text, followed by the merged labels
+    /// for the other slice, e.g. `"Other: 7 (3.5%); Product D, Product E"`.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the slice in `slices`.
+    pub fn tooltip_text(&self, index: usize) -> String {
+        let text = self.legend_text(index);
+        let merged = self.merged_labels();
+        if merged.is_empty() || index + 1 != self.slices().len() {
+            return text;
+        }
+        format!("{text}; {}", merged.join(", "))
+    }
+
+    /// Returns the legend text of a drawn slice, e.g. `"Product A: 30 (15.0%)"`.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the slice in `slices`.
+    pub fn legend_text(&self, index: usize) -> String {
+        self.slice_text(&self.slices(), index)
+    }
+
+    /// Returns the label, value and percentage of the total of a slice among slices.
+    fn slice_text(&self, slices: &[(String, f64, [u8; 3])], index: usize) -> String {
+        let total: f64 = slices.iter().map(|(_, value, _)| value).sum();
+        let (label, value, _) = &slices[index];
+        let percentage = if total > 0.0 {
+            value / total * 100.0
+        } else {
+            0.0
+        };
+        let format = &self.config.format;
+        format!(
+            "{label}: {} ({})",
+            format.localize(&value.to_string()),
+            format.format_percent(percentage, 1)
+        )
+    }
+
+    /// Places the pie and, in legend mode, the legend entries on a canvas.
+    ///
+    /// Without legend mode the pie is centered on the canvas. In legend mode the legend
+    /// takes a column left or right of the pie, or wrapped rows above or below it, and the
+    /// pie is centered in the remaining space.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    /// - `entry_sizes`: The `(width, height)` of each legend entry, swatch included.
+    ///
+    /// # Returns
+    /// The `PieLayout` of the chart.
+    pub fn layout(
+        &self,
+        width: f64,
+        height: f64,
+        margin: f64,
+        entry_sizes: &[(f64, f64)],
+    ) -> PieLayout {
+        let (mut left, mut top) = (margin, margin);
+        let (mut right, mut bottom) = (width - margin, height - margin);
+        let mut entries = Vec::new();
+
+        if self.legend && !entry_sizes.is_empty() {
+            let row_height = entry_sizes.iter().map(|&(_, h)| h).fold(0.0, f64::max);
+            let column_width = entry_sizes.iter().map(|&(w, _)| w).fold(0.0, f64::max);
+
+            match self.config.legend_position {
+                LegendPosition::Left | LegendPosition::Right => {
+                    let column_height =
+                        entry_sizes.len() as f64 * (row_height + LEGEND_SPACING) - LEGEND_SPACING;
+                    let x = if self.config.legend_position == LegendPosition::Left {
+                        left += column_width + LEGEND_SPACING;
+                        margin
+                    } else {
+                        right -= column_width + LEGEND_SPACING;
+                        width - margin - column_width
+                    };
+                    let y = ((height - column_height) / 2.0).max(margin);
+                    for index in 0..entry_sizes.len() {
+                        entries.push((x, y + index as f64 * (row_height + LEGEND_SPACING)));
+                    }
+                }
+                LegendPosition::Top | LegendPosition::Bottom => {
+                    // Wrap entries into rows no wider than the plot area
+                    let mut rows: Vec<Vec<usize>> = vec![Vec::new()];
+                    let mut row_width = 0.0;
+                    for (index, &(w, _)) in entry_sizes.iter().enumerate() {
+                        let row = rows.last_mut().unwrap();
+                        if !row.is_empty() && row_width + LEGEND_SPACING + w > right - left {
+                            rows.push(vec![index]);
+                            row_width = w;
+                        } else {
+                            if !row.is_empty() {
+                                row_width += LEGEND_SPACING;
+                            }
+                            row.push(index);
+                            row_width += w;
+                        }
+                    }
+
+                    let legend_height =
+                        rows.len() as f64 * (row_height + LEGEND_SPACING) - LEGEND_SPACING;
+                    let y = if self.config.legend_position == LegendPosition::Top {
+                        top += legend_height + LEGEND_SPACING;
+                        margin
+                    } else {
+                        bottom -= legend_height + LEGEND_SPACING;
+                        height - margin - legend_height
+                    };
+
+                    entries = vec![(0.0, 0.0); entry_sizes.len()];
+                    for (row_index, row) in rows.iter().enumerate() {
+                        let row_width = row.iter().map(|&i| entry_sizes[i].0).sum::<f64>()
+                            + (row.len() - 1) as f64 * LEGEND_SPACING;
+                        let mut x = (width - row_width) / 2.0;
+                        for &index in row {
+                            entries[index] =
+                                (x, y + row_index as f64 * (row_height + LEGEND_SPACING));
+                            x += entry_sizes[index].0 + LEGEND_SPACING;
+                        }
+                    }
+                }
+            }
+        }
+
+        PieLayout {
+            center: ((left + right) / 2.0, (top + bottom) / 2.0),
+            radius: ((right - left).min(bottom - top) / 2.0).max(0.0),
+            entries,
+        }
+    }
+
+    /// Measures the legend entries with the legend font and places the chart on a pixel canvas.
+    ///
+    /// Each entry is a square swatch the size of the legend font, followed by its text.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` the chart is drawn on.
+    ///
+    /// # Returns
+    /// The `PieLayout` of the chart.
+    pub fn pixel_layout(&self, canvas: &PixelCanvas) -> PieLayout {
+        let entry_sizes: Vec<(f64, f64)> = if self.legend {
+            let font_path = self
+                .config
+                .font_label
+                .as_ref()
+                .expect("Font path is not set");
+            let font_size = self.config.font_size_legend;
+            let square_size = font_size as f64;
+            (0..self.slices().len())
+                .map(|index| {
+                    let (w, h) = measure_text_with_fallbacks(
+                        &self.legend_text(index),
+                        font_path,
+                        &self.config.font_fallbacks,
+                        font_size,
+                    );
+                    (square_size * 1.5 + w as f64, square_size.max(h as f64))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        self.layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+            &entry_sizes,
+        )
+    }
+
     /// Draws a slice of the pie chart on the canvas.
     ///
     /// # Parameters
@@ -69,7 +547,8 @@ impl PieChart {
     ///
     /// # Details
     /// This method fills a portion of the circle defined by `start_angle` and `end_angle`.
-    /// It ensures that only points within the slice and the circle are drawn.
+    /// It ensures that only points within the slice and the circle, and outside the hollow
+    /// center of a donut chart and the rings added with `add_ring`, are drawn.
     ///
     /// # Example
     /// ```rust,ignore
@@ -86,28 +565,12 @@ impl PieChart {
         end_angle: f64,
         color: [u8; 3],
     ) {
-        let start_angle_rad = start_angle;
-        let end_angle_rad = end_angle;
-
-        for y in -radius..=radius {
-            for x in -radius..=radius {
-                // Check if the point is within the circle
-                let distance = (x * x + y * y) as f64;
-                if distance <= (radius * radius) as f64 {
-                    // Calculate the angle of the point
-                    let angle = (y as f64).atan2(x as f64);
-                    let normalized_angle = if angle < 0.0 {
-                        angle + 2.0 * std::f64::consts::PI
-                    } else {
-                        angle
-                    };
-
-                    // Check if the angle is within the slice range
-                    if normalized_angle >= start_angle_rad && normalized_angle < end_angle_rad {
-                        canvas.draw_pixel((center_x + x) as u32, (center_y - y) as u32, color);
-                    }
-                }
-            }
-        }
+        fill_ring_segment(
+            canvas,
+            (center_x, center_y),
+            (radius as f64 * self.ring_radii(0).0, radius),
+            (start_angle, end_angle),
+            color,
+        );
     }
 }
@@ -0,0 +1,259 @@
+use std::f64::consts::PI;
+
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    datasets::cartesiangraphdataset::CartesianDataset,
+    utilities::{angleunit::AngleUnit, axistype::AxisType, seriesvisibility::SeriesVisibility},
+};
+
+/// Space in pixels kept around the plot for the angle labels.
+pub const ANGLE_LABEL_SPACE: f64 = 30.0;
+
+/// Distance in pixels within which a point counts as hovered.
+const POINT_PICK_RADIUS: f64 = 8.0;
+
+/// Formats a radius with 2 decimals, without trailing zeros.
+fn trim_zeros(value: f64) -> String {
+    let text = format!("{value:.2}");
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Represents a polar graph: series of `(theta, r)` points drawn over concentric grid circles
+/// and angular spokes.
+///
+/// Angles are in radians, with `0` pointing right and positive angles turning
+/// counterclockwise. Radii share one scale, from `0` at the center to `r_max` at the outer
+/// circle; negative radii are drawn at the center.
+#[derive(Clone)]
+pub struct PolarGraph {
+    /// Title of the graph.
+    pub title: String,
+    /// The series, with `points` holding `(theta, r)` pairs.
+    pub datasets: Vec<CartesianDataset>,
+    /// The radius at the outer circle, or `None` to use the largest radius of all series.
+    pub r_max: Option<f64>,
+    /// Number of grid circles between the center and the outer circle.
+    pub num_rings: usize,
+    /// Number of angular spokes, evenly spaced from angle `0`.
+    pub num_spokes: usize,
+    /// Unit of the angle labels.
+    pub angle_unit: AngleUnit,
+    /// Configuration settings for rendering the graph (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl PolarGraph {
+    /// Creates a new `PolarGraph` instance with the specified title and configuration.
+    ///
+    /// Angle labels use the x-axis tick formatter of the configuration if one is set, and
+    /// radius labels the y-axis one.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the graph.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `PolarGraph` instance with no series, four grid circles and twelve spokes
+    /// labelled in degrees.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::polargraph::PolarGraph;
+    ///
+    /// let mut polar = PolarGraph::new("Antenna Pattern", config);
+    /// let mut gain = CartesianDataset::new([0, 90, 200], "Gain", LineType::Solid);
+    /// gain.points = (0..=360)
+    ///     .map(|deg| (f64::from(deg).to_radians(), f64::from(deg).to_radians().cos().abs()))
+    ///     .collect();
+    /// polar.add_dataset(gain);
+    /// polar.set_angle_unit(AngleUnit::Radians);
+    /// ```
+    pub fn new(title: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            datasets: Vec::new(),
+            r_max: None,
+            num_rings: 4,
+            num_spokes: 12,
+            angle_unit: AngleUnit::Degrees,
+            config,
+        }
+    }
+
+    /// Adds a series to the graph.
+    ///
+    /// # Parameters
+    /// - `dataset`: The series, with `(theta, r)` points.
+    pub fn add_dataset(&mut self, dataset: CartesianDataset) {
+        self.datasets.push(dataset);
+    }
+
+    /// Returns the series that are drawn, in order.
+    pub fn visible_datasets(&self) -> impl Iterator<Item = &CartesianDataset> {
+        self.datasets.iter().filter(|d| d.visible)
+    }
+
+    /// Sets the radius at the outer circle.
+    ///
+    /// # Parameters
+    /// - `r_max`: The radius at the outer circle; larger radii are drawn beyond it.
+    pub fn set_r_max(&mut self, r_max: f64) {
+        self.r_max = Some(r_max);
+    }
+
+    /// Sets the unit of the angle labels.
+    ///
+    /// # Parameters
+    /// - `unit`: `AngleUnit::Degrees` for labels like `90°`, or `AngleUnit::Radians` for
+    ///   labels like `π/2`. Data is given in radians either way.
+    pub fn set_angle_unit(&mut self, unit: AngleUnit) {
+        self.angle_unit = unit;
+    }
+
+    /// Returns the radius at the outer circle: `r_max`, or the largest radius of the visible
+    /// series.
+    /// Defaults to `1.0` without positive radii.
+    pub fn scale_max(&self) -> f64 {
+        if let Some(r_max) = self.r_max.filter(|&max| max > 0.0) {
+            return r_max;
+        }
+        let max = self
+            .visible_datasets()
+            .flat_map(|dataset| dataset.points.iter().map(|&(_, r)| r))
+            .filter(|r| r.is_finite())
+            .fold(0.0, f64::max);
+        if max > 0.0 {
+            max
+        } else {
+            1.0
+        }
+    }
+
+    /// Computes the radii of the grid circles, the last one being the outer circle.
+    pub fn ring_values(&self) -> Vec<f64> {
+        let num_rings = self.num_rings.max(1);
+        let max = self.scale_max();
+        (1..=num_rings)
+            .map(|ring| max * ring as f64 / num_rings as f64)
+            .collect()
+    }
+
+    /// Computes the angles of the spokes in radians, counterclockwise from `0`.
+    pub fn spoke_angles(&self) -> Vec<f64> {
+        let num_spokes = self.num_spokes.max(1);
+        (0..num_spokes)
+            .map(|spoke| spoke as f64 * 2.0 * PI / num_spokes as f64)
+            .collect()
+    }
+
+    /// Builds the labels of the spokes, through the x-axis tick formatter of the
+    /// configuration or in `angle_unit`.
+    pub fn angle_labels(&self) -> Vec<String> {
+        self.config
+            .tick_labels(AxisType::AxisX, &self.spoke_angles(), |angle| {
+                self.angle_unit.format(angle)
+            })
+    }
+
+    /// Builds the labels of the grid circles, through the y-axis tick formatter of the
+    /// configuration or without trailing zeros.
+    pub fn ring_labels(&self) -> Vec<String> {
+        self.config
+            .tick_labels(AxisType::AxisY, &self.ring_values(), trim_zeros)
+    }
+
+    /// Computes the center and radius of the graph on a canvas.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(center_x, center_y, radius)`.
+    pub fn layout(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64) {
+        let radius = (width.min(height) / 2.0 - margin - ANGLE_LABEL_SPACE).max(0.0);
+        (width / 2.0, height / 2.0, radius)
+    }
+
+    /// Converts an angle and a distance from the center to canvas coordinates.
+    ///
+    /// # Parameters
+    /// - `theta`: The angle in radians, counterclockwise from the right.
+    /// - `distance`: The distance from the center in pixels.
+    /// - `layout`: The layout returned by `layout`.
+    pub fn to_canvas(&self, theta: f64, distance: f64, layout: (f64, f64, f64)) -> (f64, f64) {
+        let (center_x, center_y, _) = layout;
+        (
+            center_x + distance * theta.cos(),
+            center_y - distance * theta.sin(),
+        )
+    }
+
+    /// Computes the canvas position of a `(theta, r)` point.
+    ///
+    /// # Parameters
+    /// - `theta`: The angle in radians.
+    /// - `r`: The radius; negative and non-finite radii are drawn at the center.
+    /// - `layout`: The layout returned by `layout`.
+    pub fn point_position(&self, theta: f64, r: f64, layout: (f64, f64, f64)) -> (f64, f64) {
+        let r = if r.is_finite() { r.max(0.0) } else { 0.0 };
+        let distance = r / self.scale_max() * layout.2;
+        self.to_canvas(theta, distance, layout)
+    }
+
+    /// Finds the point under a canvas position, preferring the series drawn last.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas position.
+    /// - `layout`: The layout returned by `layout`.
+    ///
+    /// # Returns
+    /// The indices `(dataset, point)` of the closest point within a few pixels, or `None`.
+    pub fn point_at(&self, x: f64, y: f64, layout: (f64, f64, f64)) -> Option<(usize, usize)> {
+        let mut closest = None;
+        let mut min_distance = POINT_PICK_RADIUS;
+        let visible = self.datasets.iter().enumerate().filter(|(_, d)| d.visible);
+        for (index, dataset) in visible.rev() {
+            for (point, &(theta, r)) in dataset.points.iter().enumerate() {
+                let (px, py) = self.point_position(theta, r, layout);
+                let distance = (px - x).hypot(py - y);
+                if distance < min_distance {
+                    min_distance = distance;
+                    closest = Some((index, point));
+                }
+            }
+        }
+        closest
+    }
+
+    /// Formats a radius for labels and tooltips, without trailing zeros.
+    ///
+    /// # Parameters
+    /// - `value`: The radius.
+    pub fn format_value(&self, value: f64) -> String {
+        self.config.format.localize(&trim_zeros(value))
+    }
+
+    /// Builds the tooltip of a point, e.g. `Gain: θ = π/4, r = 0.71`.
+    ///
+    /// # Parameters
+    /// - `dataset`: The series of the point.
+    /// - `theta`, `r`: The point.
+    pub fn point_tooltip(&self, dataset: &CartesianDataset, theta: f64, r: f64) -> String {
+        format!(
+            "{}: θ = {}, r = {}",
+            dataset.label,
+            self.angle_unit.format(theta),
+            self.format_value(r)
+        )
+    }
+}
+
+impl SeriesVisibility for PolarGraph {
+    fn series_visibility(&mut self) -> Vec<(&str, &mut bool)> {
+        self.datasets
+            .iter_mut()
+            .map(|dataset| (dataset.label.as_str(), &mut dataset.visible))
+            .collect()
+    }
+}
@@ -1,9 +1,15 @@
 use crate::figure::{
-    configuration::figureconfig::FigureConfig, datasets::cartesiangraphdataset::CartesianDataset,
+    configuration::figureconfig::FigureConfig,
+    datasets::cartesiangraphdataset::CartesianDataset,
+    utilities::{
+        scaling::{data_bounds, symmetric_range},
+        seriesvisibility::SeriesVisibility,
+    },
 };
 
 /// Represents a graph limited to the first quadrant (x >= 0, y >= 0),
 /// including datasets and configuration settings.
+#[derive(Clone)]
 pub struct Quadrant1Graph {
     /// A collection of datasets to be visualized in the graph.
     pub datasets: Vec<CartesianDataset>,
@@ -66,7 +72,9 @@ impl Quadrant1Graph {
     /// # Example
     /// ```rust,ignore
     /// use dataviz::figure::datasets::cartesiangraphdataset::CartesianDataset;
-    /// use dataviz::figure::utilities::linetype::LineType;
+    /// use dataviz::figure::utilities::{
+    ///     errorbars::ErrorBars, interpolation::Interpolation, linetype::LineType,
+    /// };
     ///
     /// let mut graph = Quadrant1Graph::new("Example Graph", "X Axis", "Y Axis", config);
     /// let dataset = CartesianDataset {
@@ -74,68 +82,71 @@ impl Quadrant1Graph {
     ///     color: [255, 0, 0],
     ///     label: "Dataset 1".to_string(),
     ///     line_type: LineType::Solid,
+    ///     legend_format: None,
+    ///     reference_line: None,
+    ///     error_bars: ErrorBars::default(),
+    ///     segment_starts: Vec::new(),
+    ///     interpolation: Interpolation::Linear,
     /// };
     /// graph.add_dataset(dataset);
     /// ```
     pub fn add_dataset(&mut self, dataset: CartesianDataset) {
-        let filtered_dataset = CartesianDataset {
-            points: dataset
-                .points
-                .into_iter()
-                .filter(|&(x, y)| x >= 0.0 && y >= 0.0)
-                .collect(),
-            color: dataset.color,
-            label: dataset.label.clone(),
-            line_type: dataset.line_type,
-        };
+        let mut filtered_dataset = dataset.clone();
+        filtered_dataset.points.clear();
+        filtered_dataset.segment_starts.clear();
+        // Keep the gaps between segments and the error bars of the kept points
+        let mut kept = Vec::new();
+        for (index, &(x, y)) in dataset.points.iter().enumerate() {
+            if dataset.segment_starts.contains(&index) {
+                filtered_dataset.start_new_segment();
+            }
+            if x >= 0.0 && y >= 0.0 {
+                filtered_dataset.points.push((x, y));
+                kept.push(index);
+            }
+        }
+        filtered_dataset.error_bars = dataset.error_bars.select(&kept);
         self.datasets.push(filtered_dataset);
         self.update_range();
     }
 
+    /// Returns the datasets that are drawn, in order.
+    pub fn visible_datasets(&self) -> impl Iterator<Item = &CartesianDataset> {
+        self.datasets.iter().filter(|d| d.visible)
+    }
+
     pub fn update_range(&mut self) {
-        for dataset in &self.datasets {
-            for &(x, y) in &dataset.points {
-                if x < self.x_min {
-                    self.x_min = x;
-                }
-                if x > self.x_max {
-                    self.x_max = x;
-                }
-                if y < self.y_min {
-                    self.y_min = y;
-                }
-                if y > self.y_max {
-                    self.y_max = y;
-                }
-            }
+        let points = self
+            .visible_datasets()
+            .flat_map(|d| d.points.iter().copied());
+        if let Some((x_min, x_max, y_min, y_max)) = data_bounds(points) {
+            self.x_min = self.x_min.min(x_min);
+            self.x_max = self.x_max.max(x_max);
+            self.y_min = self.y_min.min(y_min);
+            self.y_max = self.y_max.max(y_max);
         }
-        let mut is_empty = self.datasets.is_empty();
 
-        for dataset in &self.datasets {
-            if dataset.points.is_empty() {
-                is_empty = true;
-                break;
-            }
-        }
+        let is_empty = self.visible_datasets().next().is_none()
+            || self.visible_datasets().any(|d| d.points.is_empty());
 
         if !is_empty {
-            let abs_x_min = self.x_min.abs();
-            let abs_x_max = self.x_max.abs();
-
-            if abs_x_min > abs_x_max {
-                self.x_max = abs_x_min;
-            } else {
-                self.x_min = -abs_x_max;
-            }
+            (self.x_min, self.x_max) = symmetric_range(self.x_min, self.x_max);
+            (self.y_min, self.y_max) = symmetric_range(self.y_min, self.y_max);
+        }
+    }
+}
 
-            let abs_y_min = self.y_min.abs();
-            let abs_y_max = self.y_max.abs();
+impl SeriesVisibility for Quadrant1Graph {
+    fn series_visibility(&mut self) -> Vec<(&str, &mut bool)> {
+        self.datasets
+            .iter_mut()
+            .map(|dataset| (dataset.label.as_str(), &mut dataset.visible))
+            .collect()
+    }
 
-            if abs_y_min > abs_y_max {
-                self.y_max = abs_y_min;
-            } else {
-                self.y_min = -abs_y_max;
-            }
-        }
+    fn refit_ranges(&mut self) {
+        (self.x_min, self.x_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        (self.y_min, self.y_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        self.update_range();
     }
 }
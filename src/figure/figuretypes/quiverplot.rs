@@ -0,0 +1,346 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    utilities::{
+        colorbar::{self, COLOR_BAR_SPACE},
+        colormap::{interpolate, SEQUENTIAL_YELLOW_BLUE},
+        scaling::data_bounds,
+    },
+};
+
+/// Length of the longest arrow relative to the typical spacing between arrows when arrows
+/// are scaled automatically.
+const AUTO_ARROW_FILL: f64 = 0.9;
+
+/// A vector with the tail and tip of its arrow on a canvas.
+type Arrow = (QuiverVector, (f64, f64), (f64, f64));
+
+/// A vector of a `QuiverPlot`: a direction and magnitude at a position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuiverVector {
+    /// The x position of the tail.
+    pub x: f64,
+    /// The y position of the tail.
+    pub y: f64,
+    /// The x component.
+    pub u: f64,
+    /// The y component.
+    pub v: f64,
+}
+
+impl QuiverVector {
+    /// Returns the magnitude of the vector.
+    pub fn magnitude(&self) -> f64 {
+        self.u.hypot(self.v)
+    }
+}
+
+/// Represents a quiver plot: a vector field drawn as arrows starting at `(x, y)`, pointing
+/// along `(u, v)` and as long as the magnitude of the vector.
+///
+/// Arrow lengths are measured on the canvas, so directions keep their angle whatever the
+/// ranges of the axes. By default arrows are scaled so the longest one spans 90% of the
+/// typical spacing between arrows.
+#[derive(Clone)]
+pub struct QuiverPlot {
+    /// Title of the plot.
+    pub title: String,
+    /// Label for the X-axis.
+    pub x_label: String,
+    /// Label for the Y-axis.
+    pub y_label: String,
+    /// The vectors.
+    pub vectors: Vec<QuiverVector>,
+    /// Arrow length in pixels per unit of magnitude, or `None` to scale arrows to the
+    /// spacing between them.
+    pub arrow_scale: Option<f64>,
+    /// Length of the arrowheads in pixels.
+    pub head_length: f64,
+    /// Color of the arrows when they are not colored by magnitude.
+    pub color: [u8; 3],
+    /// Whether arrows are colored by their magnitude, with a color bar.
+    pub color_by_magnitude: bool,
+    /// Color stops from the lowest to the highest magnitude.
+    pub color_stops: Vec<[u8; 3]>,
+    /// Configuration settings for rendering the plot (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl QuiverPlot {
+    /// Creates a new `QuiverPlot` instance with the specified title, labels, and
+    /// configuration.
+    ///
+    /// Arrows are scaled automatically, steel blue, with 7 pixel heads.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the plot.
+    /// - `x_label`: The label for the X-axis.
+    /// - `y_label`: The label for the Y-axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `QuiverPlot` instance without vectors.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::quiverplot::QuiverPlot;
+    ///
+    /// let mut plot = QuiverPlot::new("Wind Field", "Longitude", "Latitude", config);
+    /// for &(x, y) in &stations {
+    ///     let (u, v) = wind_at(x, y);
+    ///     plot.add_vector(x, y, u, v);
+    /// }
+    /// plot.set_color_by_magnitude(true);
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            vectors: Vec::new(),
+            arrow_scale: None,
+            head_length: 7.0,
+            color: [70, 130, 180],
+            color_by_magnitude: false,
+            color_stops: SEQUENTIAL_YELLOW_BLUE.to_vec(),
+            config,
+        }
+    }
+
+    /// Adds a vector.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The position of the arrow's tail.
+    /// - `u`, `v`: The x and y components of the vector.
+    pub fn add_vector(&mut self, x: f64, y: f64, u: f64, v: f64) {
+        self.vectors.push(QuiverVector { x, y, u, v });
+    }
+
+    /// Adds the vectors of a field sampled on a grid.
+    ///
+    /// # Parameters
+    /// - `xs`, `ys`: The x and y positions of the grid; one vector is added per pair.
+    /// - `field`: Returns the `(u, v)` components at a position.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let steps: Vec<f64> = (-5..=5).map(f64::from).collect();
+    /// // A vortex around the origin
+    /// plot.add_field(&steps, &steps, |x, y| (-y, x));
+    /// ```
+    pub fn add_field(&mut self, xs: &[f64], ys: &[f64], field: impl Fn(f64, f64) -> (f64, f64)) {
+        for &y in ys {
+            for &x in xs {
+                let (u, v) = field(x, y);
+                self.add_vector(x, y, u, v);
+            }
+        }
+    }
+
+    /// Sets a fixed arrow length per unit of magnitude, e.g. to compare plots.
+    ///
+    /// # Parameters
+    /// - `pixels_per_unit`: The arrow length in pixels of a vector of magnitude 1, or
+    ///   `None` to scale arrows to the spacing between them.
+    pub fn set_arrow_scale(&mut self, pixels_per_unit: Option<f64>) {
+        self.arrow_scale = pixels_per_unit;
+    }
+
+    /// Sets whether arrows are colored by their magnitude.
+    ///
+    /// # Parameters
+    /// - `enabled`: Whether to color by magnitude; a color bar is drawn right of the plot.
+    pub fn set_color_by_magnitude(&mut self, enabled: bool) {
+        self.color_by_magnitude = enabled;
+    }
+
+    /// Sets the colormap of magnitude coloring.
+    ///
+    /// # Parameters
+    /// - `stops`: Evenly spaced color stops from the lowest to the highest magnitude.
+    pub fn set_color_stops(&mut self, stops: &[[u8; 3]]) {
+        self.color_stops = stops.to_vec();
+    }
+
+    /// Returns the vectors with finite positions and components.
+    fn finite_vectors(&self) -> impl Iterator<Item = &QuiverVector> {
+        self.vectors.iter().filter(|vector| {
+            [vector.x, vector.y, vector.u, vector.v]
+                .iter()
+                .all(|c| c.is_finite())
+        })
+    }
+
+    /// Returns the typical distance between neighbouring arrows in data units along x and
+    /// y, assuming they lie on a roughly square grid.
+    fn spacing(&self, ((x0, x1), (y0, y1)): ((f64, f64), (f64, f64))) -> (f64, f64) {
+        let per_side = (self.finite_vectors().count() as f64).sqrt().max(1.0);
+        ((x1 - x0) / (per_side + 1.0), (y1 - y0) / (per_side + 1.0))
+    }
+
+    /// Returns the `(x_range, y_range)` of the plot: the positions of the vectors, padded by
+    /// one spacing between arrows so the arrows at the edges stay inside. Without vectors
+    /// the ranges are `(0.0, 1.0)`.
+    pub fn ranges(&self) -> ((f64, f64), (f64, f64)) {
+        let Some((x_min, x_max, y_min, y_max)) =
+            data_bounds(self.finite_vectors().map(|vector| (vector.x, vector.y)))
+        else {
+            return ((0.0, 1.0), (0.0, 1.0));
+        };
+        let widen = |min: f64, max: f64| {
+            if max > min {
+                (min, max)
+            } else {
+                (min - 1.0, max + 1.0)
+            }
+        };
+        let (x_range, y_range) = (widen(x_min, x_max), widen(y_min, y_max));
+        let per_side = (self.finite_vectors().count() as f64).sqrt().max(2.0);
+        let pad_x = (x_range.1 - x_range.0) / (per_side - 1.0);
+        let pad_y = (y_range.1 - y_range.0) / (per_side - 1.0);
+        (
+            (x_range.0 - pad_x, x_range.1 + pad_x),
+            (y_range.0 - pad_y, y_range.1 + pad_y),
+        )
+    }
+
+    /// Returns the lowest and highest magnitude, `(0.0, 1.0)` without vectors.
+    pub fn magnitude_range(&self) -> (f64, f64) {
+        let (min, max) = self.finite_vectors().map(QuiverVector::magnitude).fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(min, max), magnitude| (min.min(magnitude), max.max(magnitude)),
+        );
+        if min > max {
+            (0.0, 1.0)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// Returns the arrow length in pixels per unit of magnitude in a plot area.
+    ///
+    /// # Parameters
+    /// - `area`: The plot area returned by `plot_area`.
+    pub fn pixels_per_unit(&self, area: (f64, f64, f64, f64)) -> f64 {
+        if let Some(scale) = self.arrow_scale {
+            return scale;
+        }
+        let (left, top, right, bottom) = area;
+        let ranges = self.ranges();
+        let ((x0, x1), (y0, y1)) = ranges;
+        let (step_x, step_y) = self.spacing(ranges);
+        let spacing =
+            (step_x / (x1 - x0) * (right - left)).min(step_y / (y1 - y0) * (bottom - top));
+        let (_, max) = self.magnitude_range();
+        if max > 0.0 {
+            AUTO_ARROW_FILL * spacing / max
+        } else {
+            0.0
+        }
+    }
+
+    /// Computes the arrows on a canvas.
+    ///
+    /// # Parameters
+    /// - `area`: The plot area returned by `plot_area`.
+    ///
+    /// # Returns
+    /// One `(vector, tail, tip)` triple per finite vector, in canvas coordinates.
+    pub fn arrows(&self, area: (f64, f64, f64, f64)) -> Vec<Arrow> {
+        let ranges = self.ranges();
+        let scale = self.pixels_per_unit(area);
+        self.finite_vectors()
+            .map(|&vector| {
+                let tail = self.to_canvas(vector.x, vector.y, ranges, area);
+                // Canvas y grows downwards
+                let tip = (tail.0 + vector.u * scale, tail.1 - vector.v * scale);
+                (vector, tail, tip)
+            })
+            .collect()
+    }
+
+    /// Returns the color of an arrow: by magnitude if enabled, otherwise `color`.
+    ///
+    /// # Parameters
+    /// - `vector`: The vector.
+    /// - `range`: The magnitude range returned by `magnitude_range`.
+    pub fn arrow_color(&self, vector: &QuiverVector, (min, max): (f64, f64)) -> [u8; 3] {
+        if !self.color_by_magnitude {
+            return self.color;
+        }
+        let fraction = if max > min {
+            (vector.magnitude() - min) / (max - min)
+        } else {
+            1.0
+        };
+        interpolate(&self.color_stops, fraction)
+    }
+
+    /// Finds the vector whose tail is closest to a canvas position.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas position.
+    /// - `area`: The plot area returned by `plot_area`.
+    /// - `max_distance`: The largest distance in pixels.
+    ///
+    /// # Returns
+    /// The vector and its tail in canvas coordinates, or `None` if no tail is close enough.
+    pub fn closest_vector(
+        &self,
+        x: f64,
+        y: f64,
+        area: (f64, f64, f64, f64),
+        max_distance: f64,
+    ) -> Option<(QuiverVector, (f64, f64))> {
+        let ranges = self.ranges();
+        self.finite_vectors()
+            .map(|&vector| (vector, self.to_canvas(vector.x, vector.y, ranges, area)))
+            .map(|(vector, tail)| (vector, tail, (tail.0 - x).hypot(tail.1 - y)))
+            .filter(|&(_, _, distance)| distance <= max_distance)
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+            .map(|(vector, tail, _)| (vector, tail))
+    }
+
+    /// Formats a component or magnitude for tooltips and the color bar.
+    pub fn format_value(&self, value: f64) -> String {
+        self.config.format.format_number(value, 2)
+    }
+
+    /// Computes the plot area on a canvas, leaving room for the color bar when arrows are
+    /// colored by magnitude.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(left, top, right, bottom)` in canvas coordinates.
+    pub fn plot_area(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64, f64) {
+        let color_bar = if self.color_by_magnitude {
+            COLOR_BAR_SPACE
+        } else {
+            0.0
+        };
+        colorbar::plot_area(width, height, margin, 0.0, color_bar)
+    }
+
+    /// Converts data coordinates to canvas coordinates.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The position in data coordinates.
+    /// - `ranges`: The ranges returned by `ranges`.
+    /// - `area`: The plot area returned by `plot_area`.
+    pub fn to_canvas(
+        &self,
+        x: f64,
+        y: f64,
+        ranges: ((f64, f64), (f64, f64)),
+        area: (f64, f64, f64, f64),
+    ) -> (f64, f64) {
+        let (left, top, right, bottom) = area;
+        let ((x0, x1), (y0, y1)) = ranges;
+        (
+            left + (x - x0) / (x1 - x0) * (right - left),
+            bottom - (y - y0) / (y1 - y0) * (bottom - top),
+        )
+    }
+}
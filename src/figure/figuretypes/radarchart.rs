@@ -0,0 +1,237 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig, datasets::radardataset::RadarDataset,
+    utilities::seriesvisibility::SeriesVisibility,
+};
+
+/// Space in pixels kept around the chart for the axis labels.
+pub const AXIS_LABEL_SPACE: f64 = 40.0;
+
+/// Distance in pixels within which a vertex counts as hovered.
+const VERTEX_PICK_RADIUS: f64 = 8.0;
+
+/// Represents a radar (spider) chart: named axes spread evenly around a center, with one
+/// polygon per series connecting its value on every axis.
+///
+/// The first axis points up and the others follow clockwise. All axes share one scale,
+/// from `0` at the center to `max_value` at the outer ring.
+#[derive(Clone)]
+pub struct RadarChart {
+    /// Title of the chart.
+    pub title: String,
+    /// Names of the axes, clockwise from the top.
+    pub axes: Vec<String>,
+    /// The series, one polygon each.
+    pub datasets: Vec<RadarDataset>,
+    /// The value at the outer ring, or `None` to use the largest value of all series.
+    pub max_value: Option<f64>,
+    /// Number of gridline rings between the center and the outer ring.
+    pub num_rings: usize,
+    /// Opacity of the polygon fills (0.0 to 1.0), or `None` to draw outlines only.
+    pub fill_alpha: Option<f64>,
+    /// Configuration settings for rendering the chart (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl RadarChart {
+    /// Creates a new `RadarChart` instance with the specified axes and configuration.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the chart.
+    /// - `axes`: The names of the axes, clockwise from the top.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `RadarChart` instance with no series, five rings and unfilled polygons.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::radarchart::RadarChart;
+    ///
+    /// let mut radar = RadarChart::new("Skills", &["Speed", "Power", "Range", "Defense", "Stamina"], config);
+    /// radar.add_dataset(RadarDataset::new("Player A", &[7.0, 9.0, 4.0, 6.0, 8.0], [200, 50, 50]));
+    /// radar.set_fill(0.3);
+    /// ```
+    pub fn new(title: &str, axes: &[&str], config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            axes: axes.iter().map(|axis| axis.to_string()).collect(),
+            datasets: Vec::new(),
+            max_value: None,
+            num_rings: 5,
+            fill_alpha: None,
+            config,
+        }
+    }
+
+    /// Adds a series to the chart.
+    ///
+    /// # Parameters
+    /// - `dataset`: The series, with one value per axis.
+    pub fn add_dataset(&mut self, dataset: RadarDataset) {
+        self.datasets.push(dataset);
+    }
+
+    /// Returns the series that are drawn, in order.
+    pub fn visible_datasets(&self) -> impl Iterator<Item = &RadarDataset> {
+        self.datasets.iter().filter(|d| d.visible)
+    }
+
+    /// Sets the value at the outer ring, e.g. the maximum score of a rating scale.
+    ///
+    /// # Parameters
+    /// - `max_value`: The value at the outer ring; larger values are drawn beyond it.
+    pub fn set_max_value(&mut self, max_value: f64) {
+        self.max_value = Some(max_value);
+    }
+
+    /// Fills the polygons with their series color.
+    ///
+    /// # Parameters
+    /// - `alpha`: The opacity of the fills, clamped to `0.0..=1.0`; overlapping series stay
+    ///   visible below `1.0`.
+    pub fn set_fill(&mut self, alpha: f64) {
+        self.fill_alpha = Some(alpha.clamp(0.0, 1.0));
+    }
+
+    /// Returns the value at the outer ring: `max_value`, or the largest value of the visible
+    /// series.
+    /// Defaults to `1.0` without positive values.
+    pub fn scale_max(&self) -> f64 {
+        if let Some(max_value) = self.max_value.filter(|&max| max > 0.0) {
+            return max_value;
+        }
+        let max = self
+            .visible_datasets()
+            .flat_map(|dataset| dataset.values.iter().copied())
+            .filter(|value| value.is_finite())
+            .fold(0.0, f64::max);
+        if max > 0.0 {
+            max
+        } else {
+            1.0
+        }
+    }
+
+    /// Computes the values of the gridline rings, the last one being the outer ring.
+    pub fn ring_values(&self) -> Vec<f64> {
+        let num_rings = self.num_rings.max(1);
+        let max = self.scale_max();
+        (1..=num_rings)
+            .map(|ring| max * ring as f64 / num_rings as f64)
+            .collect()
+    }
+
+    /// Returns the bearing of an axis in degrees, clockwise from the top.
+    pub fn axis_bearing(&self, axis: usize) -> f64 {
+        axis as f64 * 360.0 / self.axes.len().max(1) as f64
+    }
+
+    /// Computes the center and radius of the chart on a canvas.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(center_x, center_y, radius)`.
+    pub fn layout(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64) {
+        let radius = (width.min(height) / 2.0 - margin - AXIS_LABEL_SPACE).max(0.0);
+        (width / 2.0, height / 2.0, radius)
+    }
+
+    /// Converts a bearing and a distance from the center to canvas coordinates.
+    ///
+    /// # Parameters
+    /// - `bearing`: The bearing in degrees, clockwise from the top.
+    /// - `distance`: The distance from the center in pixels.
+    /// - `layout`: The layout returned by `layout`.
+    pub fn to_canvas(&self, bearing: f64, distance: f64, layout: (f64, f64, f64)) -> (f64, f64) {
+        let (center_x, center_y, _) = layout;
+        let angle = bearing.to_radians();
+        (
+            center_x + distance * angle.sin(),
+            center_y - distance * angle.cos(),
+        )
+    }
+
+    /// Computes the position of a value on an axis.
+    ///
+    /// # Parameters
+    /// - `axis`: The index of the axis.
+    /// - `value`: The value; negative and non-finite values are drawn at the center.
+    /// - `layout`: The layout returned by `layout`.
+    pub fn value_position(&self, axis: usize, value: f64, layout: (f64, f64, f64)) -> (f64, f64) {
+        let value = if value.is_finite() {
+            value.max(0.0)
+        } else {
+            0.0
+        };
+        let distance = value / self.scale_max() * layout.2;
+        self.to_canvas(self.axis_bearing(axis), distance, layout)
+    }
+
+    /// Computes the polygon of a series in canvas coordinates, one vertex per axis.
+    ///
+    /// # Parameters
+    /// - `dataset`: The series.
+    /// - `layout`: The layout returned by `layout`.
+    pub fn polygon(&self, dataset: &RadarDataset, layout: (f64, f64, f64)) -> Vec<(f64, f64)> {
+        (0..self.axes.len())
+            .map(|axis| self.value_position(axis, dataset.value(axis), layout))
+            .collect()
+    }
+
+    /// Computes a gridline ring as a polygon through every axis.
+    ///
+    /// # Parameters
+    /// - `value`: The value of the ring.
+    /// - `layout`: The layout returned by `layout`.
+    pub fn ring_polygon(&self, value: f64, layout: (f64, f64, f64)) -> Vec<(f64, f64)> {
+        (0..self.axes.len())
+            .map(|axis| self.value_position(axis, value, layout))
+            .collect()
+    }
+
+    /// Finds the vertex under a canvas position, preferring the series drawn last.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas position.
+    /// - `layout`: The layout returned by `layout`.
+    ///
+    /// # Returns
+    /// The indices `(dataset, axis)` of the closest vertex within a few pixels, or `None`.
+    pub fn vertex_at(&self, x: f64, y: f64, layout: (f64, f64, f64)) -> Option<(usize, usize)> {
+        let mut closest = None;
+        let mut min_distance = VERTEX_PICK_RADIUS;
+        let visible = self.datasets.iter().enumerate().filter(|(_, d)| d.visible);
+        for (index, dataset) in visible.rev() {
+            for (axis, (vx, vy)) in self.polygon(dataset, layout).into_iter().enumerate() {
+                let distance = (vx - x).hypot(vy - y);
+                if distance < min_distance {
+                    min_distance = distance;
+                    closest = Some((index, axis));
+                }
+            }
+        }
+        closest
+    }
+
+    /// Formats a value for ring labels and tooltips, without trailing zeros.
+    ///
+    /// # Parameters
+    /// - `value`: The value.
+    pub fn format_value(&self, value: f64) -> String {
+        let text = format!("{value:.2}");
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        self.config.format.localize(text)
+    }
+}
+
+impl SeriesVisibility for RadarChart {
+    fn series_visibility(&mut self) -> Vec<(&str, &mut bool)> {
+        self.datasets
+            .iter_mut()
+            .map(|dataset| (dataset.label.as_str(), &mut dataset.visible))
+            .collect()
+    }
+}
@@ -0,0 +1,181 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    datasets::densityplotdataset::DensityPlotDataset,
+    figuretypes::densityplot::{density_value_range, DENSITY_POINTS},
+};
+
+/// Represents a ridgeline plot (joyplot): the density curves of several sample sets
+/// stacked vertically with a slight overlap, one row per category, over a shared value axis.
+///
+/// All curves are scaled by the same peak density, so the heights of the ridges can be
+/// compared across rows. Rows are drawn from the top down, each one in front of the ridge
+/// above it.
+#[derive(Clone)]
+pub struct RidgelinePlot {
+    /// Title of the plot.
+    pub title: String,
+    /// Label of the value axis.
+    pub x_label: String,
+    /// The sample sets, one row each from top to bottom, labelled by their labels.
+    pub datasets: Vec<DensityPlotDataset>,
+    /// How far the highest ridge rises into the rows above, in row heights (e.g. `0.5` for
+    /// half a row). `0.0` keeps every ridge within its own row.
+    pub overlap: f64,
+    /// Opacity of the fills (0.0 to 1.0).
+    pub fill_opacity: f64,
+    /// Configuration settings for rendering the plot (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl RidgelinePlot {
+    /// Creates a new `RidgelinePlot` instance with the specified title, value axis label,
+    /// and configuration.
+    ///
+    /// Ridges overlap the row above by one row height and are filled at 80% opacity.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the plot.
+    /// - `x_label`: The label of the value axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `RidgelinePlot` instance without datasets.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::ridgelineplot::RidgelinePlot;
+    ///
+    /// let mut plot = RidgelinePlot::new("Temperatures by Month", "°C", config);
+    /// plot.add_dataset(january);
+    /// plot.add_dataset(february);
+    /// ```
+    pub fn new(title: &str, x_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            datasets: Vec::new(),
+            overlap: 1.0,
+            fill_opacity: 0.8,
+            config,
+        }
+    }
+
+    /// Adds a sample set, drawn as the next row below the existing ones.
+    ///
+    /// # Parameters
+    /// - `dataset`: The `DensityPlotDataset` holding the samples; its label names the row.
+    pub fn add_dataset(&mut self, dataset: DensityPlotDataset) {
+        self.datasets.push(dataset);
+    }
+
+    /// Sets how far the highest ridge rises into the rows above.
+    ///
+    /// # Parameters
+    /// - `overlap`: The overlap in row heights; negative values are treated as `0.0`.
+    pub fn set_overlap(&mut self, overlap: f64) {
+        self.overlap = overlap.max(0.0);
+    }
+
+    /// Computes the value range of the plot: all samples, extended by three bandwidths on
+    /// both sides so that the tails of the ridges reach zero.
+    ///
+    /// # Returns
+    /// A tuple `(min, max)`. Defaults to `(0.0, 1.0)` when there are no samples.
+    pub fn value_range(&self) -> (f64, f64) {
+        density_value_range(self.datasets.iter())
+    }
+
+    /// Evaluates the density curve of every dataset over the value range.
+    ///
+    /// # Returns
+    /// One curve of `(value, density)` pairs per dataset, empty for datasets whose density
+    /// cannot be estimated.
+    pub fn curves(&self) -> Vec<Vec<(f64, f64)>> {
+        let range = self.value_range();
+        self.datasets
+            .iter()
+            .map(|dataset| dataset.density(range, DENSITY_POINTS))
+            .collect()
+    }
+
+    /// Returns the highest density of any curve, drawn as a full ridge height.
+    ///
+    /// # Returns
+    /// The peak density, or `1.0` when no curve has a density.
+    pub fn density_max(&self) -> f64 {
+        let max = self
+            .curves()
+            .iter()
+            .flatten()
+            .map(|&(_, density)| density)
+            .fold(0.0, f64::max);
+        if max > 0.0 {
+            max
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns the height of one row, chosen so that the highest ridge of the top row
+    /// reaches the top of the plot area.
+    fn row_height(&self, height: f64, margin: f64) -> f64 {
+        (height - 2.0 * margin) / (self.datasets.len().max(1) as f64 + self.overlap)
+    }
+
+    /// Returns the y-coordinate of the baseline of a row, where its density is zero.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the row's dataset.
+    /// - `height`: The height of the canvas.
+    /// - `margin`: The margin around the plot area.
+    pub fn baseline(&self, index: usize, height: f64, margin: f64) -> f64 {
+        margin + (index as f64 + 1.0 + self.overlap) * self.row_height(height, margin)
+    }
+
+    /// Maps a density of a row to its y-coordinate on the canvas.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the row's dataset.
+    /// - `density`: The density.
+    /// - `density_max`: The peak density, as returned by `density_max`.
+    /// - `height`: The height of the canvas.
+    /// - `margin`: The margin around the plot area.
+    pub fn to_ridge_y(
+        &self,
+        index: usize,
+        density: f64,
+        density_max: f64,
+        height: f64,
+        margin: f64,
+    ) -> f64 {
+        let ridge_height = (1.0 + self.overlap) * self.row_height(height, margin);
+        self.baseline(index, height, margin) - density / density_max * ridge_height
+    }
+
+    /// Finds the frontmost ridge covering a point at a value.
+    ///
+    /// # Parameters
+    /// - `value`: The value on the x-axis.
+    /// - `y`: The y-coordinate on the canvas.
+    /// - `height`: The height of the canvas.
+    /// - `margin`: The margin around the plot area.
+    ///
+    /// # Returns
+    /// The index of the dataset and its density at `value`, or `None` if `value` lies
+    /// outside the value range or no ridge covers the point.
+    pub fn ridge_at(&self, value: f64, y: f64, height: f64, margin: f64) -> Option<(usize, f64)> {
+        let (min, max) = self.value_range();
+        if !(min..=max).contains(&value) {
+            return None;
+        }
+        let density_max = self.density_max();
+        // Lower rows are drawn in front of the rows above them
+        (0..self.datasets.len()).rev().find_map(|index| {
+            let density = self.datasets[index].density_at(value)?;
+            let top = self.to_ridge_y(index, density, density_max, height, margin);
+            (top..=self.baseline(index, height, margin))
+                .contains(&y)
+                .then_some((index, density))
+        })
+    }
+}
@@ -0,0 +1,589 @@
+use crate::figure::configuration::figureconfig::FigureConfig;
+
+/// A node of a Sankey diagram, through which flows pass.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SankeyNode {
+    /// The label of the node, drawn beside its bar.
+    pub label: String,
+    /// The color of the node's bar and of the ribbons leaving it.
+    pub color: Option<[u8; 3]>,
+}
+
+/// A weighted flow from one node to another.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SankeyLink {
+    /// Index of the node the flow leaves.
+    pub source: usize,
+    /// Index of the node the flow enters.
+    pub target: usize,
+    /// The amount of the flow, which sets the width of its ribbon.
+    pub value: f64,
+}
+
+/// A laid out node: the bar of a node in its column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SankeyNodeBox {
+    /// Index of the node.
+    pub index: usize,
+    /// The column of the node, 0 for the leftmost.
+    pub column: usize,
+    /// The throughput of the node: the larger of its incoming and outgoing totals.
+    pub value: f64,
+    /// Left edge, relative to the plot area.
+    pub x0: f64,
+    /// Top edge, relative to the plot area.
+    pub y0: f64,
+    /// Right edge, relative to the plot area.
+    pub x1: f64,
+    /// Bottom edge, relative to the plot area.
+    pub y1: f64,
+}
+
+impl SankeyNodeBox {
+    /// Checks whether a point relative to the plot area lies on the bar.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        (self.x0..=self.x1).contains(&x) && (self.y0..=self.y1).contains(&y)
+    }
+}
+
+/// A laid out link: a ribbon of constant width curving from the right edge of its source
+/// bar to the left edge of its target bar.
+///
+/// Both edges of the ribbon are cubic Bézier curves whose control points lie halfway
+/// between the bars, so the ribbon leaves and enters the bars horizontally.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SankeyRibbon {
+    /// Index of the link.
+    pub link: usize,
+    /// The x-coordinate where the ribbon leaves its source, relative to the plot area.
+    pub x0: f64,
+    /// The top of the ribbon at its source.
+    pub y0: f64,
+    /// The x-coordinate where the ribbon enters its target.
+    pub x1: f64,
+    /// The top of the ribbon at its target.
+    pub y1: f64,
+    /// The width of the ribbon, proportional to the flow.
+    pub width: f64,
+}
+
+impl SankeyRibbon {
+    /// Returns the point of the top edge at curve parameter `t` in `[0, 1]`.
+    fn top_at(&self, t: f64) -> (f64, f64) {
+        // The control points lie halfway between the bars at the heights of the end points
+        let x = self.x0 + (self.x1 - self.x0) * (1.5 * t * (1.0 - t) + t * t * t);
+        let y = self.y0 + (self.y1 - self.y0) * (3.0 * t * t - 2.0 * t * t * t);
+        (x, y)
+    }
+
+    /// Builds the SVG path of the ribbon, offset by `(dx, dy)`.
+    ///
+    /// # Returns
+    /// The `d` attribute of the path, with a cubic Bézier curve along each edge.
+    pub fn svg_path(&self, dx: f64, dy: f64) -> String {
+        let (x0, x1) = (self.x0 + dx, self.x1 + dx);
+        let (y0, y1) = (self.y0 + dy, self.y1 + dy);
+        let xm = (x0 + x1) / 2.0;
+        let w = self.width;
+        format!(
+            "M {x0:.2} {y0:.2} C {xm:.2} {y0:.2} {xm:.2} {y1:.2} {x1:.2} {y1:.2} L {x1:.2} {:.2} C {xm:.2} {:.2} {xm:.2} {:.2} {x0:.2} {:.2} Z",
+            y1 + w,
+            y1 + w,
+            y0 + w,
+            y0 + w
+        )
+    }
+
+    /// Approximates the outline of the ribbon with a polygon, for raster output.
+    ///
+    /// # Parameters
+    /// - `samples`: The number of segments along each edge, at least 1.
+    ///
+    /// # Returns
+    /// The top edge from source to target followed by the bottom edge back.
+    pub fn outline(&self, samples: usize) -> Vec<(f64, f64)> {
+        let samples = samples.max(1);
+        let top: Vec<(f64, f64)> = (0..=samples)
+            .map(|i| self.top_at(i as f64 / samples as f64))
+            .collect();
+        let bottom = top.iter().rev().map(|&(x, y)| (x, y + self.width));
+        top.iter().copied().chain(bottom).collect()
+    }
+
+    /// Checks whether a point relative to the plot area lies on the ribbon.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        if x < self.x0 || x > self.x1 {
+            return false;
+        }
+        // The x-coordinate grows with t, so the curve parameter at x is found by bisection
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..32 {
+            let t = (low + high) / 2.0;
+            if self.top_at(t).0 < x {
+                low = t;
+            } else {
+                high = t;
+            }
+        }
+        let top = self.top_at((low + high) / 2.0).1;
+        (top..=top + self.width).contains(&y)
+    }
+}
+
+/// The node bars and link ribbons of a Sankey diagram, relative to the plot area.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SankeyLayout {
+    /// The bars of the nodes with flow, in node order.
+    pub nodes: Vec<SankeyNodeBox>,
+    /// The ribbons of the links, in link order.
+    pub ribbons: Vec<SankeyRibbon>,
+}
+
+/// Represents a Sankey diagram: nodes arranged in columns, connected by ribbons whose width
+/// is proportional to the flow between them.
+///
+/// Every node is placed one column right of its furthest upstream source, and nodes
+/// without outgoing flows move to the last column. Within a column, bars are stacked with
+/// `node_padding` between them and shifted toward the nodes they are linked to, so
+/// ribbons cross as little as possible.
+#[derive(Clone)]
+pub struct SankeyDiagram {
+    /// Title of the diagram.
+    pub title: String,
+    /// The nodes, in the order they were added.
+    pub nodes: Vec<SankeyNode>,
+    /// The flows between nodes. They never form a cycle.
+    pub links: Vec<SankeyLink>,
+    /// Width of the node bars in pixels.
+    pub node_width: f64,
+    /// Vertical space between bars in the same column, in pixels.
+    pub node_padding: f64,
+    /// Opacity of the ribbons (0.0 to 1.0), so crossing ribbons stay visible.
+    pub link_opacity: f64,
+    /// Whether nodes show their labels beside their bars.
+    pub show_labels: bool,
+    /// The number of decimal places of values in tooltips.
+    pub precision: usize,
+    /// Configuration settings for rendering the diagram (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl SankeyDiagram {
+    /// Colors assigned to nodes without their own color, in order.
+    pub const NODE_COLORS: [[u8; 3]; 8] = [
+        [31, 119, 180],
+        [255, 127, 14],
+        [44, 160, 44],
+        [214, 39, 40],
+        [148, 103, 189],
+        [140, 86, 75],
+        [227, 119, 194],
+        [23, 190, 207],
+    ];
+
+    /// Number of passes that move bars toward the nodes they are linked to.
+    const RELAXATION_PASSES: usize = 32;
+
+    /// Creates a new `SankeyDiagram` instance with the specified title and configuration.
+    ///
+    /// Bars are 15 pixels wide with 10 pixels between them, and ribbons are drawn at 45%
+    /// opacity.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the diagram.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `SankeyDiagram` instance without nodes.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::sankeydiagram::SankeyDiagram;
+    ///
+    /// let mut sankey = SankeyDiagram::new("Energy", config);
+    /// sankey.add_link("Coal", "Electricity", 120.0)?;
+    /// sankey.add_link("Solar", "Electricity", 40.0)?;
+    /// sankey.add_link("Electricity", "Homes", 90.0)?;
+    /// sankey.add_link("Electricity", "Industry", 70.0)?;
+    /// ```
+    pub fn new(title: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            nodes: Vec::new(),
+            links: Vec::new(),
+            node_width: 15.0,
+            node_padding: 10.0,
+            link_opacity: 0.45,
+            show_labels: true,
+            precision: 2,
+            config,
+        }
+    }
+
+    /// Adds a node, unless a node with the same label exists.
+    ///
+    /// Nodes are also added by `add_link`, so this is only needed to fix the order of
+    /// nodes or to show nodes without flows.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the node.
+    ///
+    /// # Returns
+    /// The index of the node with the label.
+    pub fn add_node(&mut self, label: &str) -> usize {
+        if let Some(index) = self.node_index(label) {
+            return index;
+        }
+        self.nodes.push(SankeyNode {
+            label: label.to_string(),
+            color: None,
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Returns the index of the node with a label.
+    pub fn node_index(&self, label: &str) -> Option<usize> {
+        self.nodes.iter().position(|node| node.label == label)
+    }
+
+    /// Sets the color of a node's bar and of the ribbons leaving it.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the node, which is added if it does not exist.
+    /// - `color`: The RGB color.
+    pub fn set_node_color(&mut self, label: &str, color: [u8; 3]) {
+        let index = self.add_node(label);
+        self.nodes[index].color = Some(color);
+    }
+
+    /// Adds a flow between two nodes, adding the nodes if they do not exist.
+    ///
+    /// # Parameters
+    /// - `source`: The label of the node the flow leaves.
+    /// - `target`: The label of the node the flow enters.
+    /// - `value`: The amount of the flow.
+    ///
+    /// # Errors
+    /// Returns an error, without adding anything, if the value is not positive and finite,
+    /// if source and target are the same node, or if the flow would close a cycle.
+    pub fn add_link(&mut self, source: &str, target: &str, value: f64) -> Result<(), String> {
+        if !value.is_finite() || value <= 0.0 {
+            return Err(format!(
+                "Flow from '{source}' to '{target}' must be positive, got {value}"
+            ));
+        }
+        if source == target {
+            return Err(format!("Flow from '{source}' to itself is not allowed"));
+        }
+        if let (Some(source_index), Some(target_index)) =
+            (self.node_index(source), self.node_index(target))
+        {
+            if self.reaches(target_index, source_index) {
+                return Err(format!(
+                    "Flow from '{source}' to '{target}' would close a cycle"
+                ));
+            }
+        }
+
+        let source = self.add_node(source);
+        let target = self.add_node(target);
+        self.links.push(SankeyLink {
+            source,
+            target,
+            value,
+        });
+        Ok(())
+    }
+
+    /// Checks whether flows lead from one node to another.
+    fn reaches(&self, from: usize, to: usize) -> bool {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut stack = vec![from];
+        while let Some(node) = stack.pop() {
+            if node == to {
+                return true;
+            }
+            if std::mem::replace(&mut visited[node], true) {
+                continue;
+            }
+            stack.extend(
+                self.links
+                    .iter()
+                    .filter(|link| link.source == node)
+                    .map(|link| link.target),
+            );
+        }
+        false
+    }
+
+    /// Returns the throughput of a node: the larger of its incoming and outgoing totals.
+    pub fn node_value(&self, index: usize) -> f64 {
+        let incoming: f64 = self
+            .links
+            .iter()
+            .filter(|link| link.target == index)
+            .map(|link| link.value)
+            .sum();
+        let outgoing: f64 = self
+            .links
+            .iter()
+            .filter(|link| link.source == index)
+            .map(|link| link.value)
+            .sum();
+        incoming.max(outgoing)
+    }
+
+    /// Assigns every node to a column.
+    ///
+    /// A node is placed one column right of its furthest upstream source, and nodes without
+    /// outgoing flows are moved to the last column, so all flows end at the right edge.
+    ///
+    /// # Returns
+    /// The column of each node, in node order.
+    pub fn columns(&self) -> Vec<usize> {
+        let mut columns = vec![0; self.nodes.len()];
+        // Flows never form a cycle, so columns settle after at most one pass per node
+        for _ in 0..self.nodes.len() {
+            let mut changed = false;
+            for link in &self.links {
+                if columns[link.target] < columns[link.source] + 1 {
+                    columns[link.target] = columns[link.source] + 1;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let last = columns.iter().copied().max().unwrap_or(0);
+        for (index, column) in columns.iter_mut().enumerate() {
+            let has_inflow = self.links.iter().any(|link| link.target == index);
+            let has_outflow = self.links.iter().any(|link| link.source == index);
+            if has_inflow && !has_outflow {
+                *column = last;
+            }
+        }
+        columns
+    }
+
+    /// Lays out the node bars and link ribbons.
+    ///
+    /// Columns are spread evenly across the width. Bar heights share one scale, chosen so
+    /// the fullest column fits the height, and bars are then moved toward the weighted
+    /// center of the nodes they are linked to over a number of passes. Ribbons leave and
+    /// enter the bars sorted by the position of the node at their other end.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The size of the plot area in pixels.
+    ///
+    /// # Returns
+    /// The layout, relative to the plot area. Nodes without flow have no bar.
+    pub fn layout(&self, width: f64, height: f64) -> SankeyLayout {
+        let columns = self.columns();
+        let values: Vec<f64> = (0..self.nodes.len())
+            .map(|index| self.node_value(index))
+            .collect();
+        let column_count = columns.iter().copied().max().map_or(0, |last| last + 1);
+        let mut by_column: Vec<Vec<usize>> = vec![Vec::new(); column_count];
+        for (index, &column) in columns.iter().enumerate() {
+            if values[index] > 0.0 {
+                by_column[column].push(index);
+            }
+        }
+
+        // One scale for all bars, fitting the column with the most flow and bars
+        let scale = by_column
+            .iter()
+            .filter(|nodes| !nodes.is_empty())
+            .map(|nodes| {
+                let total: f64 = nodes.iter().map(|&index| values[index]).sum();
+                (height - (nodes.len() - 1) as f64 * self.node_padding).max(0.0) / total
+            })
+            .fold(f64::INFINITY, f64::min);
+        if !scale.is_finite() {
+            return SankeyLayout::default();
+        }
+
+        let column_step = (width - self.node_width).max(0.0) / (column_count.max(2) - 1) as f64;
+        let mut y0 = vec![0.0; self.nodes.len()];
+        let heights: Vec<f64> = values.iter().map(|value| value * scale).collect();
+        for nodes in &by_column {
+            let mut y = 0.0;
+            for &index in nodes {
+                y0[index] = y;
+                y += heights[index] + self.node_padding;
+            }
+        }
+
+        // Move bars toward the nodes they are linked to, alternating the direction of travel
+        for pass in 0..Self::RELAXATION_PASSES {
+            let alpha = 0.99_f64.powi(pass as i32);
+            let order: Vec<&Vec<usize>> = if pass % 2 == 0 {
+                by_column.iter().collect()
+            } else {
+                by_column.iter().rev().collect()
+            };
+            for nodes in order {
+                for &index in nodes {
+                    let (mut weighted, mut weight) = (0.0, 0.0);
+                    for link in &self.links {
+                        let other = if link.source == index {
+                            link.target
+                        } else if link.target == index {
+                            link.source
+                        } else {
+                            continue;
+                        };
+                        weighted += (y0[other] + heights[other] / 2.0) * link.value;
+                        weight += link.value;
+                    }
+                    if weight > 0.0 {
+                        let center = y0[index] + heights[index] / 2.0;
+                        y0[index] += (weighted / weight - center) * alpha;
+                    }
+                }
+                self.resolve_collisions(nodes, &mut y0, &heights, height);
+            }
+        }
+
+        let mut nodes: Vec<SankeyNodeBox> = by_column
+            .iter()
+            .flatten()
+            .map(|&index| {
+                let x0 = columns[index] as f64 * column_step;
+                SankeyNodeBox {
+                    index,
+                    column: columns[index],
+                    value: values[index],
+                    x0,
+                    y0: y0[index],
+                    x1: x0 + self.node_width,
+                    y1: y0[index] + heights[index],
+                }
+            })
+            .collect();
+        nodes.sort_by_key(|node| node.index);
+
+        // Stack ribbons on each bar, ordered by the position of the node at their other end
+        let center = |index: usize| y0[index] + heights[index] / 2.0;
+        let mut source_offsets = vec![0.0; self.links.len()];
+        let mut target_offsets = vec![0.0; self.links.len()];
+        for node in 0..self.nodes.len() {
+            let mut outgoing: Vec<usize> = (0..self.links.len())
+                .filter(|&link| self.links[link].source == node)
+                .collect();
+            outgoing.sort_by(|&a, &b| {
+                center(self.links[a].target).total_cmp(&center(self.links[b].target))
+            });
+            let mut offset = 0.0;
+            for link in outgoing {
+                source_offsets[link] = offset;
+                offset += self.links[link].value * scale;
+            }
+
+            let mut incoming: Vec<usize> = (0..self.links.len())
+                .filter(|&link| self.links[link].target == node)
+                .collect();
+            incoming.sort_by(|&a, &b| {
+                center(self.links[a].source).total_cmp(&center(self.links[b].source))
+            });
+            let mut offset = 0.0;
+            for link in incoming {
+                target_offsets[link] = offset;
+                offset += self.links[link].value * scale;
+            }
+        }
+
+        let ribbons = self
+            .links
+            .iter()
+            .enumerate()
+            .map(|(index, link)| SankeyRibbon {
+                link: index,
+                x0: columns[link.source] as f64 * column_step + self.node_width,
+                y0: y0[link.source] + source_offsets[index],
+                x1: columns[link.target] as f64 * column_step,
+                y1: y0[link.target] + target_offsets[index],
+                width: link.value * scale,
+            })
+            .collect();
+
+        SankeyLayout { nodes, ribbons }
+    }
+
+    /// Removes overlaps between the bars of a column, pushing bars down and then, where
+    /// they leave the plot area, back up.
+    fn resolve_collisions(&self, nodes: &[usize], y0: &mut [f64], heights: &[f64], height: f64) {
+        let mut sorted = nodes.to_vec();
+        sorted.sort_by(|&a, &b| y0[a].total_cmp(&y0[b]));
+
+        let mut y = 0.0;
+        for &index in &sorted {
+            y0[index] = y0[index].max(y);
+            y = y0[index] + heights[index] + self.node_padding;
+        }
+
+        let mut y = height;
+        for &index in sorted.iter().rev() {
+            y0[index] = y0[index].min(y - heights[index]);
+            y = y0[index] - self.node_padding;
+        }
+    }
+
+    /// Returns the color of a node: its own or one of `NODE_COLORS` by index.
+    pub fn node_color(&self, index: usize) -> [u8; 3] {
+        self.nodes[index]
+            .color
+            .unwrap_or(Self::NODE_COLORS[index % Self::NODE_COLORS.len()])
+    }
+
+    /// Formats the tooltip of a node: its label and throughput.
+    pub fn node_tooltip(&self, node: &SankeyNodeBox) -> String {
+        format!(
+            "{}: {}",
+            self.nodes[node.index].label,
+            self.config.format.format_number(node.value, self.precision)
+        )
+    }
+
+    /// Formats the tooltip of a link: its source, target and value.
+    pub fn link_tooltip(&self, index: usize) -> String {
+        let link = &self.links[index];
+        format!(
+            "{} → {}: {}",
+            self.nodes[link.source].label,
+            self.nodes[link.target].label,
+            self.config.format.format_number(link.value, self.precision)
+        )
+    }
+
+    /// Finds the bar or ribbon at a position, preferring bars, which are drawn on top.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The position relative to the plot area.
+    /// - `layout`: The layout of the diagram.
+    ///
+    /// # Returns
+    /// The tooltip of the element and a point on it, or `None` if the position is empty.
+    pub fn element_at(
+        &self,
+        x: f64,
+        y: f64,
+        layout: &SankeyLayout,
+    ) -> Option<((f64, f64), String)> {
+        if let Some(node) = layout.nodes.iter().find(|node| node.contains(x, y)) {
+            return Some((
+                ((node.x0 + node.x1) / 2.0, (node.y0 + node.y1) / 2.0),
+                self.node_tooltip(node),
+            ));
+        }
+        // Ribbons drawn later are on top
+        layout
+            .ribbons
+            .iter()
+            .rev()
+            .find(|ribbon| ribbon.contains(x, y))
+            .map(|ribbon| ((x, y), self.link_tooltip(ribbon.link)))
+    }
+}
@@ -1,9 +1,18 @@
 use crate::figure::{
-    canvas::pixelcanvas::PixelCanvas, configuration::figureconfig::FigureConfig,
-    datasets::scattergraphdataset::ScatterGraphDataset, utilities::scatterdottype::ScatterDotType,
+    canvas::pixelcanvas::PixelCanvas,
+    configuration::figureconfig::FigureConfig,
+    datasets::scattergraphdataset::ScatterGraphDataset,
+    utilities::{
+        densitythinning::DensityThinning, scatterdottype::ScatterDotType,
+        seriesvisibility::SeriesVisibility,
+    },
 };
 
+/// Opacity of bubbles, so overlapping ones stay visible.
+pub const BUBBLE_OPACITY: f64 = 0.6;
+
 /// Represents a scatter graph, including title, axis labels, datasets, and configuration settings.
+#[derive(Clone)]
 pub struct ScatterGraph {
     /// Title of the scatter graph.
     pub title: String,
@@ -13,6 +22,12 @@ pub struct ScatterGraph {
     pub y_label: String,
     /// A collection of datasets to be visualized on the scatter graph.
     pub datasets: Vec<ScatterGraphDataset>,
+    /// Thinning of dense regions at render time, or `None` to draw every point.
+    pub thinning: Option<DensityThinning>,
+    /// Radii in pixels of the smallest and largest bubble, for datasets with sizes.
+    pub bubble_radius: (f64, f64),
+    /// Whether a legend of bubble sizes is drawn in the top-right corner of the plot.
+    pub size_legend: bool,
     /// Configuration settings for rendering the graph (e.g., colors, fonts, grid).
     pub config: FigureConfig,
 }
@@ -43,6 +58,9 @@ impl ScatterGraph {
             x_label: x_label.to_string(),
             y_label: y_label.to_string(),
             datasets: Vec::new(),
+            thinning: None,
+            bubble_radius: (3.0, 20.0),
+            size_legend: false,
             config,
         }
     }
@@ -55,13 +73,18 @@ impl ScatterGraph {
     /// # Example
     /// ```rust,ignore
     /// use dataviz::figure::datasets::scattergraphdataset::ScatterGraphDataset;
-    /// use dataviz::figure::utilities::scatterdottype::ScatterDotType;
+    /// use dataviz::figure::utilities::{errorbars::ErrorBars, scatterdottype::ScatterDotType};
     ///
     /// let dataset = ScatterGraphDataset {
     ///     points: vec![(1.0, 2.0), (3.0, 4.0)],
     ///     color: [255, 0, 0],
     ///     label: "Dataset 1".to_string(),
     ///     dot_type: ScatterDotType::Circle(5),
+    ///     legend_format: None,
+    ///     reference_line: None,
+    ///     error_bars: ErrorBars::default(),
+    ///     sizes: Vec::new(),
+    ///     visible: true,
     /// };
     /// scatter_graph.add_dataset(dataset);
     /// ```
@@ -69,6 +92,158 @@ impl ScatterGraph {
         self.datasets.push(dataset);
     }
 
+    /// Returns the datasets that are drawn, in order.
+    pub fn visible_datasets(&self) -> impl Iterator<Item = &ScatterGraphDataset> {
+        self.datasets.iter().filter(|d| d.visible)
+    }
+
+    /// Thins dense clusters when rendering, so huge point clouds stay readable.
+    ///
+    /// Each dataset is thinned on its own, so a small series inside a dense cluster of
+    /// another one stays visible. The data itself is not changed.
+    ///
+    /// # Parameters
+    /// - `cell_size`: Side length in pixels of the cells point density is measured in.
+    /// - `max_per_cell`: Number of points kept per cell; sparser cells keep all of theirs.
+    /// - `seed`: Seed choosing which points of a dense cell are drawn.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// scatter_graph.set_density_thinning(4.0, 3, 0);
+    /// ```
+    pub fn set_density_thinning(&mut self, cell_size: f64, max_per_cell: usize, seed: u64) {
+        self.thinning = Some(DensityThinning::new(cell_size, max_per_cell, seed));
+    }
+
+    /// Sets the radii of the smallest and largest bubble.
+    ///
+    /// # Parameters
+    /// - `min`: The radius in pixels of the bubble with the smallest size.
+    /// - `max`: The radius in pixels of the bubble with the largest size.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// scatter_graph.set_bubble_radius(2.0, 30.0);
+    /// ```
+    pub fn set_bubble_radius(&mut self, min: f64, max: f64) {
+        self.bubble_radius = (min.max(0.0), max.max(0.0));
+    }
+
+    /// Shows or hides the legend of bubble sizes, listing the smallest, middle and largest
+    /// size with their bubbles.
+    ///
+    /// # Parameters
+    /// - `visible`: Whether the legend is drawn.
+    pub fn set_size_legend(&mut self, visible: bool) {
+        self.size_legend = visible;
+    }
+
+    /// Computes the range of the point sizes over all visible datasets.
+    ///
+    /// # Returns
+    /// A tuple `(min, max)`, or `None` if no point has a size.
+    pub fn size_range(&self) -> Option<(f64, f64)> {
+        self.visible_datasets()
+            .flat_map(|dataset| dataset.sizes.iter().copied())
+            .filter(|size| size.is_finite())
+            .fold(None, |range, size| match range {
+                Some((min, max)) => Some((f64::min(min, size), f64::max(max, size))),
+                None => Some((size, size)),
+            })
+    }
+
+    /// Maps a point size to a bubble radius, so that the bubble area grows linearly from
+    /// the smallest to the largest size.
+    ///
+    /// # Parameters
+    /// - `size`: The point size.
+    /// - `range`: The range returned by `size_range`.
+    ///
+    /// # Returns
+    /// The radius in pixels; the middle radius if all sizes are equal.
+    pub fn bubble_radius_of(&self, size: f64, range: (f64, f64)) -> f64 {
+        let (min_radius, max_radius) = self.bubble_radius;
+        let (min, max) = range;
+        let t = if max > min {
+            ((size - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        (min_radius.powi(2) + t * (max_radius.powi(2) - min_radius.powi(2))).sqrt()
+    }
+
+    /// Returns the sizes listed in the size legend: the smallest, middle and largest size,
+    /// without duplicates.
+    pub fn size_legend_values(&self) -> Vec<f64> {
+        let Some((min, max)) = self.size_range() else {
+            return Vec::new();
+        };
+        let mut values = vec![max, (min + max) / 2.0, min];
+        values.dedup();
+        values
+    }
+
+    /// Computes the range of the data over all visible datasets, including error bars.
+    ///
+    /// # Returns
+    /// A tuple `((x_min, x_max), (y_min, y_max))`; infinite without points.
+    pub fn data_range(&self) -> ((f64, f64), (f64, f64)) {
+        self.visible_datasets()
+            .flat_map(|dataset| dataset.error_bars.extent_points(&dataset.points))
+            .fold(
+                (
+                    (f64::INFINITY, f64::NEG_INFINITY),
+                    (f64::INFINITY, f64::NEG_INFINITY),
+                ),
+                |((x_min, x_max), (y_min, y_max)), (x, y)| {
+                    ((x_min.min(x), x_max.max(x)), (y_min.min(y), y_max.max(y)))
+                },
+            )
+    }
+
+    /// Returns the indices of the points of a dataset that are drawn.
+    ///
+    /// # Parameters
+    /// - `dataset`: The dataset.
+    /// - `to_pixel`: Maps a data point to its canvas position.
+    ///
+    /// # Returns
+    /// All indices without thinning, otherwise the kept ones, in ascending order.
+    pub fn visible_indices(
+        &self,
+        dataset: &ScatterGraphDataset,
+        to_pixel: impl Fn(f64, f64) -> (f64, f64),
+    ) -> Vec<usize> {
+        let Some(thinning) = &self.thinning else {
+            return (0..dataset.points.len()).collect();
+        };
+        let pixels: Vec<(f64, f64)> = dataset
+            .points
+            .iter()
+            .map(|&(x, y)| to_pixel(x, y))
+            .collect();
+        thinning.keep(&pixels)
+    }
+
+    /// Returns the points of a dataset that are drawn.
+    ///
+    /// # Parameters
+    /// - `dataset`: The dataset.
+    /// - `to_pixel`: Maps a data point to its canvas position.
+    ///
+    /// # Returns
+    /// All points without thinning, otherwise the kept ones, in dataset order.
+    pub fn visible_points(
+        &self,
+        dataset: &ScatterGraphDataset,
+        to_pixel: impl Fn(f64, f64) -> (f64, f64),
+    ) -> Vec<(f64, f64)> {
+        self.visible_indices(dataset, to_pixel)
+            .into_iter()
+            .map(|index| dataset.points[index])
+            .collect()
+    }
+
     /// Draws a single dot on the canvas using the specified dot type and color.
     ///
     /// # Parameters
@@ -97,37 +272,29 @@ impl ScatterGraph {
         dot_type: ScatterDotType,
         color: [u8; 3],
     ) {
-        match dot_type {
-            ScatterDotType::Circle(radius) => {
-                for dy in -(radius as i32)..=radius as i32 {
-                    for dx in -(radius as i32)..=radius as i32 {
-                        if dx * dx + dy * dy <= (radius * radius) as i32 {
-                            canvas.draw_pixel((x + dx) as u32, (y + dy) as u32, color);
-                        }
-                    }
-                }
-            }
-            ScatterDotType::Square(size) => {
-                for dy in -(size as i32) / 2..=(size as i32) / 2 {
-                    for dx in -(size as i32) / 2..=(size as i32) / 2 {
-                        canvas.draw_pixel((x + dx) as u32, (y + dy) as u32, color);
-                    }
-                }
-            }
-            ScatterDotType::Cross(thickness) => {
-                for i in -(thickness as i32)..=(thickness as i32) {
-                    canvas.draw_pixel((x + i) as u32, y as u32, color); // Horizontal line
-                    canvas.draw_pixel(x as u32, (y + i) as u32, color); // Vertical line
-                }
-            }
-            ScatterDotType::Triangle(base) => {
-                for dy in 0..=base as i32 {
-                    let dx = (base as f64 * (1.0 - dy as f64 / base as f64)) as i32;
-                    for x_offset in -dx..=dx {
-                        canvas.draw_pixel((x + x_offset) as u32, (y - dy) as u32, color);
-                    }
-                }
-            }
+        dot_type.draw(canvas, x, y, color);
+    }
+
+    /// Builds the tooltip of a point, with its size for bubbles, e.g.
+    /// `Cities: (1.20, 3.40), size 250.00`.
+    ///
+    /// # Parameters
+    /// - `dataset`: The dataset of the point.
+    /// - `index`: The index of the point.
+    pub fn point_tooltip(&self, dataset: &ScatterGraphDataset, index: usize) -> String {
+        let (x, y) = dataset.points[index];
+        match dataset.size(index) {
+            Some(size) => format!("{}: ({x:.2}, {y:.2}), size {size:.2}", dataset.label),
+            None => format!("{}: ({x:.2}, {y:.2})", dataset.label),
         }
     }
 }
+
+impl SeriesVisibility for ScatterGraph {
+    fn series_visibility(&mut self) -> Vec<(&str, &mut bool)> {
+        self.datasets
+            .iter_mut()
+            .map(|dataset| (dataset.label.as_str(), &mut dataset.visible))
+            .collect()
+    }
+}
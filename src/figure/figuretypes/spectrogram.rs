@@ -0,0 +1,239 @@
+use image::RgbImage;
+
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    utilities::{
+        colorbar::{self, COLOR_BAR_SPACE},
+        colormap::{interpolate, SEQUENTIAL_MAGMA},
+    },
+};
+
+/// Represents a spectrogram: the magnitude of a signal over time and frequency, drawn as a
+/// heatmap with a decibel color scale.
+///
+/// Magnitudes are stored as a matrix indexed `[time][frequency]`. Column `i` covers the
+/// times from `i * time_step` to `(i + 1) * time_step`, row `k` the frequencies around
+/// `k * frequency_step`.
+#[derive(Clone)]
+pub struct Spectrogram {
+    /// Title of the spectrogram.
+    pub title: String,
+    /// Label for the X-axis (time).
+    pub x_label: String,
+    /// Label for the Y-axis (frequency).
+    pub y_label: String,
+    /// The magnitudes, indexed as `[time][frequency]`.
+    pub magnitudes: Vec<Vec<f64>>,
+    /// Duration of a column in seconds.
+    pub time_step: f64,
+    /// Frequency spacing of the rows in Hz.
+    pub frequency_step: f64,
+    /// Range in dB shown below the strongest magnitude; weaker magnitudes are clipped.
+    pub dynamic_range: f64,
+    /// Color stops from the weakest to the strongest magnitude.
+    pub color_stops: Vec<[u8; 3]>,
+    /// Configuration settings for rendering the spectrogram (e.g., colors, fonts).
+    pub config: FigureConfig,
+}
+
+impl Spectrogram {
+    /// Creates a new `Spectrogram` instance with the specified title, labels, and configuration.
+    ///
+    /// The dynamic range defaults to 80 dB.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the spectrogram.
+    /// - `x_label`: The label for the time axis.
+    /// - `y_label`: The label for the frequency axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `Spectrogram` instance without magnitudes.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::spectrogram::Spectrogram;
+    ///
+    /// let mut spectrogram = Spectrogram::new("Recording", "Time (s)", "Frequency (Hz)", config);
+    /// spectrogram.set_magnitudes(magnitudes, 0.01, 43.0);
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            magnitudes: Vec::new(),
+            time_step: 1.0,
+            frequency_step: 1.0,
+            dynamic_range: 80.0,
+            color_stops: SEQUENTIAL_MAGMA.to_vec(),
+            config,
+        }
+    }
+
+    /// Computes a spectrogram from raw samples with a short-time Fourier transform.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the spectrogram.
+    /// - `samples`: The signal.
+    /// - `sample_rate`: The sample rate in Hz.
+    /// - `window_size`: The frame length, a power of two.
+    /// - `hop`: The distance between frame starts in samples.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// The spectrogram with time and frequency axes in seconds and Hz, or a message if the
+    /// transform parameters are invalid.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let spectrogram = Spectrogram::from_samples("Chirp", &samples, 44100.0, 1024, 256, config)?;
+    /// ```
+    #[cfg(feature = "stft")]
+    pub fn from_samples(
+        title: &str,
+        samples: &[f64],
+        sample_rate: f64,
+        window_size: usize,
+        hop: usize,
+        config: FigureConfig,
+    ) -> Result<Self, String> {
+        let magnitudes = crate::figure::utilities::stft::stft(samples, window_size, hop)?;
+        let mut spectrogram = Self::new(title, "Time (s)", "Frequency (Hz)", config);
+        spectrogram.set_magnitudes(
+            magnitudes,
+            hop as f64 / sample_rate,
+            sample_rate / window_size as f64,
+        );
+        Ok(spectrogram)
+    }
+
+    /// Sets the magnitude matrix and its time and frequency resolution.
+    ///
+    /// # Parameters
+    /// - `magnitudes`: The magnitudes, indexed as `[time][frequency]`.
+    /// - `time_step`: Duration of a column in seconds.
+    /// - `frequency_step`: Frequency spacing of the rows in Hz.
+    pub fn set_magnitudes(
+        &mut self,
+        magnitudes: Vec<Vec<f64>>,
+        time_step: f64,
+        frequency_step: f64,
+    ) {
+        self.magnitudes = magnitudes;
+        self.time_step = time_step;
+        self.frequency_step = frequency_step;
+    }
+
+    /// Returns the number of frequency rows, the length of the longest column.
+    pub fn num_frequencies(&self) -> usize {
+        self.magnitudes.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Returns the time range `(start, end)` covered by the columns, in seconds.
+    pub fn time_extent(&self) -> (f64, f64) {
+        (0.0, self.magnitudes.len() as f64 * self.time_step)
+    }
+
+    /// Returns the frequency range `(lowest, highest)` covered by the rows, in Hz.
+    pub fn frequency_extent(&self) -> (f64, f64) {
+        let rows = self.num_frequencies().saturating_sub(1);
+        (0.0, rows as f64 * self.frequency_step)
+    }
+
+    /// Converts the magnitudes to decibels relative to the strongest magnitude.
+    ///
+    /// # Returns
+    /// The levels in dB, at most 0 and indexed as `[time][frequency]`; zero magnitudes give
+    /// negative infinity.
+    pub fn levels(&self) -> Vec<Vec<f64>> {
+        let reference = self
+            .magnitudes
+            .iter()
+            .flatten()
+            .copied()
+            .fold(0.0, f64::max);
+        self.magnitudes
+            .iter()
+            .map(|column| {
+                column
+                    .iter()
+                    .map(|&magnitude| {
+                        if reference > 0.0 {
+                            20.0 * (magnitude / reference).log10()
+                        } else {
+                            f64::NEG_INFINITY
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Maps a level in dB to its color, clipping levels below the dynamic range.
+    pub fn level_color(&self, decibels: f64) -> [u8; 3] {
+        let t = (decibels + self.dynamic_range) / self.dynamic_range;
+        interpolate(&self.color_stops, t.clamp(0.0, 1.0))
+    }
+
+    /// Computes the plot area on a canvas, leaving room for the color bar.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(left, top, right, bottom)` in canvas coordinates.
+    pub fn plot_area(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64, f64) {
+        colorbar::plot_area(width, height, margin, 0.0, COLOR_BAR_SPACE)
+    }
+
+    /// Finds the matrix cell under a canvas position.
+    ///
+    /// Frequency rows are centered on their frequency, so the lowest and highest rows are
+    /// half as tall as the others.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas position.
+    /// - `area`: The plot area returned by `plot_area`.
+    ///
+    /// # Returns
+    /// The `(time, frequency)` indices, or `None` outside the plot area or the matrix.
+    pub fn cell_at(&self, x: f64, y: f64, area: (f64, f64, f64, f64)) -> Option<(usize, usize)> {
+        let (left, top, right, bottom) = area;
+        let rows = self.num_frequencies();
+        if !(left..right).contains(&x) || !(top..=bottom).contains(&y) || rows == 0 {
+            return None;
+        }
+
+        let column = ((x - left) / (right - left) * self.magnitudes.len() as f64) as usize;
+        let fraction = (bottom - y) / (bottom - top);
+        let row = (fraction * rows.saturating_sub(1) as f64).round() as usize;
+        let magnitude = self.magnitudes.get(column)?.get(row)?;
+        magnitude.is_finite().then_some((column, row))
+    }
+
+    /// Renders the matrix into an image covering the plot area.
+    ///
+    /// # Parameters
+    /// - `area`: The plot area returned by `plot_area`.
+    ///
+    /// # Returns
+    /// The image; cells missing from shorter columns are left in the background color.
+    pub fn render(&self, area: (f64, f64, f64, f64)) -> RgbImage {
+        let (left, top, right, bottom) = area;
+        let width = (right - left).round().max(1.0) as u32;
+        let height = (bottom - top).round().max(1.0) as u32;
+
+        let levels = self.levels();
+        let mut image =
+            RgbImage::from_pixel(width, height, image::Rgb(self.config.color_background));
+        for (x, y, pixel) in image.enumerate_pixels_mut() {
+            let center = (left + x as f64 + 0.5, top + y as f64 + 0.5);
+            if let Some((column, row)) = self.cell_at(center.0, center.1, area) {
+                pixel.0 = self.level_color(levels[column][row]);
+            }
+        }
+        image
+    }
+}
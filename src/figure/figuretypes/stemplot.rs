@@ -0,0 +1,93 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    datasets::cartesiangraphdataset::CartesianDataset,
+    figuretypes::cartesiangraph::CartesianGraph,
+    utilities::{scatterdottype::ScatterDotType, seriesvisibility::SeriesVisibility},
+};
+
+/// A stem plot, which draws a vertical line from a baseline to each `(x, y)` value, topped
+/// by a marker, as used for discrete signals and impulse responses.
+///
+/// The datasets, axes, grid, legend and reference lines are those of a `CartesianGraph`;
+/// each dataset's `line_type` styles its stems.
+#[derive(Clone)]
+pub struct StemPlot {
+    /// The graph holding the datasets and drawing the axes, grid and legend.
+    pub graph: CartesianGraph,
+    /// The y value the stems start from.
+    pub baseline: f64,
+    /// The marker drawn at the top of each stem.
+    pub marker: ScatterDotType,
+}
+
+impl StemPlot {
+    /// Creates a new `StemPlot` with stems from zero topped by circles.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the plot.
+    /// - `x_label`: The label of the x-axis.
+    /// - `y_label`: The label of the y-axis.
+    /// - `config`: The figure configuration.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut plot = StemPlot::new("Impulse Response", "n", "h[n]", &config);
+    /// let mut dataset = CartesianDataset::new([0, 90, 200], "h", LineType::Solid);
+    /// dataset.points = (0..16).map(|n| (n as f64, 0.8f64.powi(n))).collect();
+    /// plot.add_dataset(dataset);
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: &FigureConfig) -> Self {
+        Self {
+            graph: CartesianGraph::new(title, x_label, y_label, config),
+            baseline: 0.0,
+            marker: ScatterDotType::Circle(4),
+        }
+    }
+
+    /// Adds a dataset whose points are drawn as stems.
+    ///
+    /// # Parameters
+    /// - `dataset`: The dataset; its color, label and line type style the stems.
+    pub fn add_dataset(&mut self, dataset: CartesianDataset) {
+        self.graph.add_dataset(dataset);
+        self.include_baseline();
+    }
+
+    /// Sets the y value the stems start from.
+    ///
+    /// # Parameters
+    /// - `baseline`: The baseline; `0.0` by default.
+    pub fn set_baseline(&mut self, baseline: f64) {
+        self.baseline = baseline;
+        self.include_baseline();
+    }
+
+    /// Sets the marker drawn at the top of each stem.
+    ///
+    /// # Parameters
+    /// - `marker`: The marker shape and size.
+    pub fn set_marker(&mut self, marker: ScatterDotType) {
+        self.marker = marker;
+    }
+
+    /// Widens the y range of the graph so that it contains the baseline.
+    fn include_baseline(&mut self) {
+        if self.graph.datasets.iter().all(|d| d.points.is_empty()) {
+            return;
+        }
+        self.graph.y_min = self.graph.y_min.min(self.baseline);
+        self.graph.y_max = self.graph.y_max.max(self.baseline);
+        self.graph.update_range();
+    }
+}
+
+impl SeriesVisibility for StemPlot {
+    fn series_visibility(&mut self) -> Vec<(&str, &mut bool)> {
+        self.graph.series_visibility()
+    }
+
+    fn refit_ranges(&mut self) {
+        self.graph.refit_ranges();
+        self.include_baseline();
+    }
+}
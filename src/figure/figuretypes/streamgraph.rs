@@ -0,0 +1,379 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig, utilities::interpolation::monotone_cubic,
+};
+
+/// Number of vertices per interval between x values when streams are smoothed.
+const SMOOTH_SAMPLES: usize = 12;
+
+/// The baseline a `Streamgraph` stacks its series on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StreamBaseline {
+    /// Series stacked on zero, as in a stacked area chart.
+    Zero,
+    /// Series stacked symmetrically around zero (ThemeRiver).
+    Silhouette,
+    /// Series stacked on a baseline minimizing the weighted slopes of the layers (after
+    /// Byron and Wattenberg), so that thick layers stay as flat as possible.
+    #[default]
+    Wiggle,
+}
+
+/// A series of a `Streamgraph`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StreamSeries {
+    /// The label of the series, shown in the legend and tooltips.
+    pub label: String,
+    /// The values at the x values of the graph. Missing values count as zero.
+    pub values: Vec<f64>,
+    /// The fill color, or `None` for a color of the palette.
+    pub color: Option<[u8; 3]>,
+}
+
+/// Represents a streamgraph: series over shared x values, e.g. time, stacked as flowing
+/// layers around a moving baseline.
+#[derive(Clone)]
+pub struct Streamgraph {
+    /// Title of the graph.
+    pub title: String,
+    /// Label for the X-axis.
+    pub x_label: String,
+    /// Label for the Y-axis.
+    pub y_label: String,
+    /// The x values shared by all series, in increasing order.
+    pub x_values: Vec<f64>,
+    /// The series, stacked from the bottom up.
+    pub series: Vec<StreamSeries>,
+    /// The baseline the series are stacked on.
+    pub baseline: StreamBaseline,
+    /// Whether layers are drawn as smooth curves instead of straight segments.
+    pub smooth: bool,
+    /// Colors assigned to series without their own color, in order.
+    pub palette: Vec<[u8; 3]>,
+    /// Configuration settings for rendering the graph (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl Streamgraph {
+    /// Colors of the default palette.
+    pub const SERIES_COLORS: [[u8; 3]; 8] = [
+        [31, 119, 180],
+        [255, 127, 14],
+        [44, 160, 44],
+        [214, 39, 40],
+        [148, 103, 189],
+        [140, 86, 75],
+        [227, 119, 194],
+        [23, 190, 207],
+    ];
+
+    /// Creates a new `Streamgraph` instance with the specified title, labels, and
+    /// configuration.
+    ///
+    /// Series are stacked on the wiggle baseline and smoothed.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the graph.
+    /// - `x_label`: The label for the X-axis.
+    /// - `y_label`: The label for the Y-axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `Streamgraph` instance without series.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::streamgraph::Streamgraph;
+    ///
+    /// let mut graph = Streamgraph::new("Listening Time", "Week", "Hours", config);
+    /// graph.set_x_values(&[1.0, 2.0, 3.0, 4.0]);
+    /// graph.add_series("Rock", &[12.0, 15.0, 9.0, 7.0])?;
+    /// graph.add_series("Jazz", &[3.0, 4.0, 8.0, 11.0])?;
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            x_values: Vec::new(),
+            series: Vec::new(),
+            baseline: StreamBaseline::Wiggle,
+            smooth: true,
+            palette: Self::SERIES_COLORS.to_vec(),
+            config,
+        }
+    }
+
+    /// Sets the x values shared by all series.
+    ///
+    /// # Parameters
+    /// - `x_values`: The x values in increasing order, e.g. timestamps.
+    pub fn set_x_values(&mut self, x_values: &[f64]) {
+        self.x_values = x_values.to_vec();
+    }
+
+    /// Adds a series on top of the existing ones.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the series.
+    /// - `values`: The values at the x values. Missing values count as zero and extra
+    ///   values are ignored.
+    ///
+    /// # Errors
+    /// Returns an error, without adding the series, if a value is negative or not finite.
+    pub fn add_series(&mut self, label: &str, values: &[f64]) -> Result<(), String> {
+        if let Some(value) = values.iter().find(|v| !v.is_finite() || **v < 0.0) {
+            return Err(format!(
+                "Values of series '{label}' must be non-negative, got {value}"
+            ));
+        }
+        self.series.push(StreamSeries {
+            label: label.to_string(),
+            values: values.to_vec(),
+            color: None,
+        });
+        Ok(())
+    }
+
+    /// Sets the fill color of a series.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the series.
+    /// - `color`: The RGB color.
+    ///
+    /// # Errors
+    /// Returns an error if no series has the label.
+    pub fn set_series_color(&mut self, label: &str, color: [u8; 3]) -> Result<(), String> {
+        let series = self
+            .series
+            .iter_mut()
+            .find(|series| series.label == label)
+            .ok_or_else(|| format!("No series labelled '{label}'"))?;
+        series.color = Some(color);
+        Ok(())
+    }
+
+    /// Sets the colors assigned to series without their own color.
+    ///
+    /// # Parameters
+    /// - `palette`: The colors, repeated when there are more series than colors. An empty
+    ///   palette restores `SERIES_COLORS`.
+    pub fn set_palette(&mut self, palette: &[[u8; 3]]) {
+        self.palette = if palette.is_empty() {
+            Self::SERIES_COLORS.to_vec()
+        } else {
+            palette.to_vec()
+        };
+    }
+
+    /// Sets the baseline the series are stacked on.
+    pub fn set_baseline(&mut self, baseline: StreamBaseline) {
+        self.baseline = baseline;
+    }
+
+    /// Sets whether layers are drawn as smooth curves.
+    ///
+    /// Smoothing uses monotone cubic curves, which pass through every value without
+    /// overshooting, so layers never get a negative thickness.
+    pub fn set_smooth(&mut self, smooth: bool) {
+        self.smooth = smooth;
+    }
+
+    /// Returns the color of a series: its own or one of the palette by index.
+    pub fn series_color(&self, index: usize) -> [u8; 3] {
+        self.series[index].color.unwrap_or_else(|| {
+            let palette: &[[u8; 3]] = if self.palette.is_empty() {
+                &Self::SERIES_COLORS
+            } else {
+                &self.palette
+            };
+            palette[index % palette.len()]
+        })
+    }
+
+    /// Returns the value of a series at an x value index, zero if missing.
+    pub fn value(&self, series: usize, index: usize) -> f64 {
+        self.series[series]
+            .values
+            .get(index)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the x positions of the layer vertices and the thickness of every series at
+    /// them: the x values themselves, or smooth samples between them.
+    fn samples(&self) -> (Vec<f64>, Vec<Vec<f64>>) {
+        let xs = self.x_values.clone();
+        let thicknesses: Vec<Vec<f64>> = (0..self.series.len())
+            .map(|series| {
+                (0..xs.len())
+                    .map(|index| self.value(series, index))
+                    .collect()
+            })
+            .collect();
+        if !self.smooth || xs.len() < 3 {
+            return (xs, thicknesses);
+        }
+        let smooth = |values: &[f64]| -> Vec<(f64, f64)> {
+            let points: Vec<(f64, f64)> = xs.iter().copied().zip(values.iter().copied()).collect();
+            monotone_cubic(&points, SMOOTH_SAMPLES)
+        };
+        let sample_xs = smooth(&vec![0.0; xs.len()])
+            .into_iter()
+            .map(|(x, _)| x)
+            .collect();
+        let sampled = thicknesses
+            .iter()
+            .map(|values| {
+                smooth(values)
+                    .into_iter()
+                    .map(|(_, value)| value.max(0.0))
+                    .collect()
+            })
+            .collect();
+        (sample_xs, sampled)
+    }
+
+    /// Computes the baseline at every sample.
+    ///
+    /// # Parameters
+    /// - `thicknesses`: The thickness of every series at every sample.
+    /// - `samples`: The number of samples.
+    fn baseline_offsets(&self, thicknesses: &[Vec<f64>], samples: usize) -> Vec<f64> {
+        let total = |index: usize| -> f64 { thicknesses.iter().map(|values| values[index]).sum() };
+        match self.baseline {
+            StreamBaseline::Zero => vec![0.0; samples],
+            StreamBaseline::Silhouette => (0..samples).map(|index| -total(index) / 2.0).collect(),
+            StreamBaseline::Wiggle => {
+                // Each step moves the baseline against the slopes of the layers, weighted by
+                // their thickness
+                let mut offsets = vec![0.0; samples];
+                for index in 1..samples {
+                    let (mut weight, mut weighted_slope) = (0.0, 0.0);
+                    let mut below = 0.0;
+                    for values in thicknesses {
+                        let change = values[index] - values[index - 1];
+                        weight += values[index];
+                        weighted_slope += values[index] * (below + change / 2.0);
+                        below += change;
+                    }
+                    offsets[index] = offsets[index - 1]
+                        - if weight > 0.0 {
+                            weighted_slope / weight
+                        } else {
+                            0.0
+                        };
+                }
+                // Center the stream around zero
+                let middle = (0..samples)
+                    .map(|index| offsets[index] + total(index) / 2.0)
+                    .sum::<f64>()
+                    / samples.max(1) as f64;
+                offsets.iter().map(|offset| offset - middle).collect()
+            }
+        }
+    }
+
+    /// Computes the layers of the series, bottom first.
+    ///
+    /// # Returns
+    /// For every series, its `(x, bottom, top)` vertices in data coordinates.
+    pub fn layers(&self) -> Vec<Vec<(f64, f64, f64)>> {
+        let (xs, thicknesses) = self.samples();
+        let offsets = self.baseline_offsets(&thicknesses, xs.len());
+        let mut bottoms = offsets;
+        thicknesses
+            .iter()
+            .map(|values| {
+                xs.iter()
+                    .zip(values)
+                    .zip(bottoms.iter_mut())
+                    .map(|((&x, &thickness), bottom)| {
+                        let layer = (x, *bottom, *bottom + thickness);
+                        *bottom += thickness;
+                        layer
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the `(x_range, y_range)` of the layers, `(0.0, 1.0)` along an axis without
+    /// extent.
+    pub fn ranges(&self, layers: &[Vec<(f64, f64, f64)>]) -> ((f64, f64), (f64, f64)) {
+        let widen = |(min, max): (f64, f64)| {
+            if max > min {
+                (min, max)
+            } else if min.is_finite() {
+                (min - 0.5, max + 0.5)
+            } else {
+                (0.0, 1.0)
+            }
+        };
+        let x_range = self
+            .x_values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &x| {
+                (min.min(x), max.max(x))
+            });
+        let y_range = layers.iter().flatten().fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(min, max), &(_, bottom, top)| (min.min(bottom), max.max(top)),
+        );
+        (widen(x_range), widen(y_range))
+    }
+
+    /// Finds the series whose layer covers a point.
+    ///
+    /// # Parameters
+    /// - `layers`: The layers returned by `layers`.
+    /// - `x`, `y`: The point in data coordinates.
+    ///
+    /// # Returns
+    /// The index of the series with the bottom and top of its layer at `x`, or `None`
+    /// outside the stream.
+    pub fn series_at(
+        &self,
+        layers: &[Vec<(f64, f64, f64)>],
+        x: f64,
+        y: f64,
+    ) -> Option<(usize, f64, f64)> {
+        layers.iter().enumerate().find_map(|(index, layer)| {
+            let (bottom, top) = bounds_at(layer, x)?;
+            (top > bottom && y >= bottom && y <= top).then_some((index, bottom, top))
+        })
+    }
+
+    /// Returns the index of the x value closest to `x`, or `None` without x values.
+    pub fn closest_index(&self, x: f64) -> Option<usize> {
+        (0..self.x_values.len()).min_by(|&a, &b| {
+            (self.x_values[a] - x)
+                .abs()
+                .total_cmp(&(self.x_values[b] - x).abs())
+        })
+    }
+
+    /// Returns the tooltip of a series: its label and total, e.g. `"Rock: 43"`.
+    pub fn series_tooltip(&self, series: usize) -> String {
+        let total: f64 = self.series[series].values.iter().sum();
+        format!(
+            "{}: {}",
+            self.series[series].label,
+            self.config.format.localize(&total.to_string())
+        )
+    }
+}
+
+/// Interpolates the bottom and top of a layer at `x`, or `None` outside the layer.
+fn bounds_at(layer: &[(f64, f64, f64)], x: f64) -> Option<(f64, f64)> {
+    let (first, last) = (layer.first()?, layer.last()?);
+    if x < first.0 || x > last.0 {
+        return None;
+    }
+    let after = layer.partition_point(|&(vertex_x, _, _)| vertex_x < x);
+    if after == 0 {
+        return Some((first.1, first.2));
+    }
+    let ((x0, bottom0, top0), (x1, bottom1, top1)) = (layer[after - 1], layer[after]);
+    let t = if x1 > x0 { (x - x0) / (x1 - x0) } else { 0.0 };
+    Some((bottom0 + t * (bottom1 - bottom0), top0 + t * (top1 - top0)))
+}
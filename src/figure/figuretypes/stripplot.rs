@@ -0,0 +1,131 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    datasets::categorydataset::CategoryDataset,
+    utilities::{random::SeededRng, scaling::data_bounds},
+};
+
+/// Represents a strip plot: the raw observations of each category drawn as points,
+/// spread horizontally with random jitter so overlapping values stay visible.
+///
+/// The jitter is drawn from a seeded generator, so a figure always renders the same way.
+#[derive(Clone)]
+pub struct StripPlot {
+    /// Title of the strip plot.
+    pub title: String,
+    /// Label for the X-axis (the category axis).
+    pub x_label: String,
+    /// Label for the Y-axis (the value axis).
+    pub y_label: String,
+    /// One dataset per category, drawn from left to right.
+    pub datasets: Vec<CategoryDataset>,
+    /// Width of the jitter band as a fraction of the category width (`0.0` to `1.0`).
+    pub jitter: f64,
+    /// Seed of the jitter generator.
+    pub seed: u64,
+    /// Whether a box plot summary is drawn over the points of each category.
+    pub show_box: bool,
+    /// Radius of the sample points in pixels.
+    pub point_radius: u32,
+    /// Configuration settings for rendering the plot (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl StripPlot {
+    /// Creates a new `StripPlot` instance with the specified title, labels, and configuration.
+    ///
+    /// The plot starts with a jitter of `0.6`, seed `0`, no box overlay and 3 pixel points.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the strip plot.
+    /// - `x_label`: The label for the category axis.
+    /// - `y_label`: The label for the value axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `StripPlot` instance with no categories.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::stripplot::StripPlot;
+    ///
+    /// let mut plot = StripPlot::new("Reaction Times", "Group", "ms", FigureConfig::default());
+    /// plot.show_box = true;
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            datasets: Vec::new(),
+            jitter: 0.6,
+            seed: 0,
+            show_box: false,
+            point_radius: 3,
+            config,
+        }
+    }
+
+    /// Adds a category to the strip plot.
+    ///
+    /// # Parameters
+    /// - `dataset`: The `CategoryDataset` holding the category's observations.
+    pub fn add_dataset(&mut self, dataset: CategoryDataset) {
+        self.datasets.push(dataset);
+    }
+
+    /// Sets the width of the jitter band and the seed used to generate it.
+    ///
+    /// # Parameters
+    /// - `jitter`: Width of the band as a fraction of the category width, clamped to `0.0..=1.0`.
+    ///   A jitter of `0.0` draws every category as a single vertical strip.
+    /// - `seed`: Seed of the jitter generator.
+    pub fn set_jitter(&mut self, jitter: f64, seed: u64) {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self.seed = seed;
+    }
+
+    /// Computes the value range of the plot, padded by 5% on both sides.
+    ///
+    /// # Returns
+    /// A tuple `(min, max)`. Defaults to `(0.0, 1.0)` when there are no samples.
+    pub fn value_range(&self) -> (f64, f64) {
+        let values = self
+            .datasets
+            .iter()
+            .flat_map(|dataset| dataset.samples.iter().map(|&value| (0.0, value)));
+        match data_bounds(values) {
+            None => (0.0, 1.0),
+            Some((_, _, min, max)) if min == max => (min - 1.0, max + 1.0),
+            Some((_, _, min, max)) => {
+                let padding = (max - min) * 0.05;
+                (min - padding, max + padding)
+            }
+        }
+    }
+
+    /// Computes the jittered position of every sample.
+    ///
+    /// Category `i` is centered on `i + 0.5`, so the category axis spans `0.0` to the
+    /// number of categories.
+    ///
+    /// # Returns
+    /// For each dataset, its samples as `(category_position, value)` points, in sample order.
+    pub fn jittered_points(&self) -> Vec<Vec<(f64, f64)>> {
+        let mut rng = SeededRng::new(self.seed);
+        let half_band = self.jitter / 2.0;
+
+        self.datasets
+            .iter()
+            .enumerate()
+            .map(|(index, dataset)| {
+                let center = index as f64 + 0.5;
+                dataset
+                    .samples
+                    .iter()
+                    .map(|&value| (center + rng.range(-half_band, half_band), value))
+                    .collect()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,250 @@
+use std::f64::consts::TAU;
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas, configuration::figureconfig::FigureConfig,
+    figuretypes::treemap::TreemapNode, utilities::arcgeometry::angle_of,
+};
+
+/// A laid out segment of a sunburst chart: one node drawn as a section of its ring.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SunburstSegment {
+    /// Indices of the node and its ancestors, starting at the root node.
+    pub path: Vec<usize>,
+    /// The total of the node.
+    pub value: f64,
+    /// The angle at which the segment starts, in radians counterclockwise from the
+    /// positive x-axis.
+    pub start_angle: f64,
+    /// The angle at which the segment ends.
+    pub end_angle: f64,
+}
+
+impl SunburstSegment {
+    /// Returns the ring of the segment, 0 for root nodes.
+    pub fn depth(&self) -> usize {
+        self.path.len() - 1
+    }
+
+    /// Returns the angle at the middle of the segment.
+    pub fn mid_angle(&self) -> f64 {
+        (self.start_angle + self.end_angle) / 2.0
+    }
+}
+
+/// Represents a sunburst chart: hierarchical data drawn as concentric rings, with the root
+/// nodes in the innermost ring and every node's children in the next ring, spanning the
+/// node's angle.
+///
+/// Nodes are the same `TreemapNode`s as in a `Treemap`. Each root node gets a color of its
+/// own, shaded lighter for every ring further out, and labels follow the arcs of the
+/// segments they fit in.
+#[derive(Clone)]
+pub struct SunburstChart {
+    /// Title of the chart.
+    pub title: String,
+    /// The root nodes, sharing the innermost ring.
+    pub roots: Vec<TreemapNode>,
+    /// Radius of the empty center as a fraction of the chart radius, between 0 and 1.
+    pub inner_radius: f64,
+    /// Whether segments show their labels along their arcs.
+    pub show_labels: bool,
+    /// The number of decimal places of values in tooltips.
+    pub precision: usize,
+    /// Configuration settings for rendering the chart (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl SunburstChart {
+    /// Creates a new `SunburstChart` instance with the specified title and configuration.
+    ///
+    /// Labels are shown, and the empty center takes a fifth of the radius.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the chart.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `SunburstChart` instance without nodes.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::{sunburstchart::SunburstChart, treemap::TreemapNode};
+    ///
+    /// let mut chart = SunburstChart::new("Budget", config);
+    /// let mut staff = TreemapNode::new("Staff", 0.0);
+    /// staff.add_child(TreemapNode::new("Salaries", 420.0));
+    /// staff.add_child(TreemapNode::new("Training", 35.0));
+    /// chart.add_root(staff);
+    /// chart.add_root(TreemapNode::new("Rent", 120.0));
+    /// ```
+    pub fn new(title: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            roots: Vec::new(),
+            inner_radius: 0.2,
+            show_labels: true,
+            precision: 2,
+            config,
+        }
+    }
+
+    /// Adds a root node.
+    ///
+    /// # Parameters
+    /// - `node`: The node, with its descendants.
+    pub fn add_root(&mut self, node: TreemapNode) {
+        self.roots.push(node);
+    }
+
+    /// Sets the radius of the empty center.
+    ///
+    /// # Parameters
+    /// - `inner_radius`: The radius as a fraction of the chart radius, clamped to `[0, 0.9]`.
+    pub fn set_inner_radius(&mut self, inner_radius: f64) {
+        self.inner_radius = inner_radius.clamp(0.0, 0.9);
+    }
+
+    /// Returns the sum of the root totals.
+    pub fn total(&self) -> f64 {
+        self.roots.iter().map(TreemapNode::total).sum()
+    }
+
+    /// Returns the node at a path of child indices, starting at a root index.
+    ///
+    /// # Panics
+    /// Panics if the path is empty or an index is out of bounds.
+    pub fn node(&self, path: &[usize]) -> &TreemapNode {
+        TreemapNode::at_path(&self.roots, path)
+    }
+
+    /// Lays out the segments of the chart.
+    ///
+    /// Nodes without area are skipped. Root nodes share the full circle and every node's
+    /// children share its angle, in the order they were added.
+    ///
+    /// # Returns
+    /// The segments, every parent before its children.
+    pub fn segments(&self) -> Vec<SunburstSegment> {
+        let mut segments = Vec::new();
+        Self::layout_nodes(&self.roots, &[], (0.0, TAU), &mut segments);
+        segments
+    }
+
+    /// Lays out sibling nodes within an angle, recursing into their children.
+    fn layout_nodes(
+        nodes: &[TreemapNode],
+        parent: &[usize],
+        (start_angle, end_angle): (f64, f64),
+        segments: &mut Vec<SunburstSegment>,
+    ) {
+        let total: f64 = nodes.iter().map(TreemapNode::total).sum();
+        if total <= 0.0 {
+            return;
+        }
+
+        let mut angle = start_angle;
+        for (index, node) in nodes.iter().enumerate() {
+            let value = node.total();
+            if value <= 0.0 {
+                continue;
+            }
+            let sweep_angle = value / total * (end_angle - start_angle);
+            let mut path = parent.to_vec();
+            path.push(index);
+            segments.push(SunburstSegment {
+                path: path.clone(),
+                value,
+                start_angle: angle,
+                end_angle: angle + sweep_angle,
+            });
+            Self::layout_nodes(
+                &node.children,
+                &path,
+                (angle, angle + sweep_angle),
+                segments,
+            );
+            angle += sweep_angle;
+        }
+    }
+
+    /// Returns the number of rings, one per level of nodes with area.
+    pub fn ring_count(&self) -> usize {
+        self.segments()
+            .iter()
+            .map(|segment| segment.depth() + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the inner and outer radius of a ring as fractions of the chart radius.
+    pub fn ring_radii(&self, depth: usize) -> (f64, f64) {
+        let rings = self.ring_count().max(1) as f64;
+        let width = (1.0 - self.inner_radius) / rings;
+        (
+            self.inner_radius + depth as f64 * width,
+            self.inner_radius + (depth + 1) as f64 * width,
+        )
+    }
+
+    /// Computes the center and radius of the chart, filling the plot area of a canvas.
+    ///
+    /// # Parameters
+    /// - `width`, `height`, `margin`: The size and margin of the canvas in pixels.
+    ///
+    /// # Returns
+    /// The center and the radius in pixels.
+    pub fn layout(width: f64, height: f64, margin: f64) -> ((f64, f64), f64) {
+        (
+            (width / 2.0, height / 2.0),
+            ((width.min(height) - 2.0 * margin) / 2.0).max(0.0),
+        )
+    }
+
+    /// Finds the segment at a position on a pixel canvas.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The position in canvas space.
+    /// - `canvas`: The canvas the chart was drawn on.
+    pub fn segment_at(&self, x: f64, y: f64, canvas: &PixelCanvas) -> Option<SunburstSegment> {
+        let ((center_x, center_y), radius) = Self::layout(
+            canvas.width as f64,
+            canvas.height as f64,
+            canvas.margin as f64,
+        );
+        let (dx, dy) = (x - center_x, center_y - y);
+        if radius <= 0.0 {
+            return None;
+        }
+        let distance = dx.hypot(dy) / radius;
+        let angle = angle_of(dx, dy);
+        self.segments().into_iter().find(|segment| {
+            let (inner, outer) = self.ring_radii(segment.depth());
+            (inner..outer).contains(&distance)
+                && (segment.start_angle..segment.end_angle).contains(&angle)
+        })
+    }
+
+    /// Returns the fill color of a segment, shaded by its ring (see
+    /// `TreemapNode::shaded_color`).
+    pub fn segment_color(&self, segment: &SunburstSegment) -> [u8; 3] {
+        TreemapNode::shaded_color(&self.roots, &segment.path)
+    }
+
+    /// Formats the tooltip of a segment: its path, value and share of the total.
+    pub fn segment_tooltip(&self, segment: &SunburstSegment) -> String {
+        let total = self.total();
+        let share = if total > 0.0 {
+            segment.value / total * 100.0
+        } else {
+            0.0
+        };
+        format!(
+            "{}: {} ({})",
+            TreemapNode::path_label(&self.roots, &segment.path),
+            self.config
+                .format
+                .format_number(segment.value, self.precision),
+            self.config.format.format_percent(share, 1)
+        )
+    }
+}
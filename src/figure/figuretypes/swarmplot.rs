@@ -0,0 +1,116 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    datasets::categorydataset::CategoryDataset,
+    utilities::{packing::beeswarm_offsets, scaling::data_bounds},
+};
+
+/// Represents a beeswarm plot: the raw observations of each category drawn as points that
+/// are packed side by side so that no two points overlap.
+///
+/// Unlike a strip plot, the layout is deterministic and shows the shape of each
+/// distribution, which works best for small to medium samples. Very large samples can
+/// spread a swarm wider than its category.
+#[derive(Clone)]
+pub struct SwarmPlot {
+    /// Title of the swarm plot.
+    pub title: String,
+    /// Label for the X-axis (the category axis).
+    pub x_label: String,
+    /// Label for the Y-axis (the value axis).
+    pub y_label: String,
+    /// One dataset per category, drawn from left to right.
+    pub datasets: Vec<CategoryDataset>,
+    /// Radius of the sample points in pixels.
+    pub point_radius: u32,
+    /// Gap between neighbouring points in pixels.
+    pub point_spacing: f64,
+    /// Configuration settings for rendering the plot (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl SwarmPlot {
+    /// Creates a new `SwarmPlot` instance with the specified title, labels, and configuration.
+    ///
+    /// The plot starts with 3 pixel points separated by 1 pixel.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the swarm plot.
+    /// - `x_label`: The label for the category axis.
+    /// - `y_label`: The label for the value axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `SwarmPlot` instance with no categories.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::swarmplot::SwarmPlot;
+    ///
+    /// let plot = SwarmPlot::new("Petal Length", "Species", "cm", FigureConfig::default());
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            datasets: Vec::new(),
+            point_radius: 3,
+            point_spacing: 1.0,
+            config,
+        }
+    }
+
+    /// Adds a category to the swarm plot.
+    ///
+    /// # Parameters
+    /// - `dataset`: The `CategoryDataset` holding the category's observations.
+    pub fn add_dataset(&mut self, dataset: CategoryDataset) {
+        self.datasets.push(dataset);
+    }
+
+    /// Computes the value range of the plot, padded by 5% on both sides.
+    ///
+    /// # Returns
+    /// A tuple `(min, max)`. Defaults to `(0.0, 1.0)` when there are no samples.
+    pub fn value_range(&self) -> (f64, f64) {
+        let values = self
+            .datasets
+            .iter()
+            .flat_map(|dataset| dataset.samples.iter().map(|&value| (0.0, value)));
+        match data_bounds(values) {
+            None => (0.0, 1.0),
+            Some((_, _, min, max)) if min == max => (min - 1.0, max + 1.0),
+            Some((_, _, min, max)) => {
+                let padding = (max - min) * 0.05;
+                (min - padding, max + padding)
+            }
+        }
+    }
+
+    /// Packs the samples of every category.
+    ///
+    /// Packing happens in pixel space, so the result depends on the vertical scale of the
+    /// canvas the plot is drawn on.
+    ///
+    /// # Parameters
+    /// - `scale_y`: Pixels per data unit on the value axis.
+    ///
+    /// # Returns
+    /// For each dataset, its samples as `(offset, value)` pairs in sample order, where
+    /// `offset` is the horizontal distance from the category center in pixels.
+    pub fn swarm_points(&self, scale_y: f64) -> Vec<Vec<(f64, f64)>> {
+        let diameter = 2.0 * self.point_radius as f64 + self.point_spacing;
+
+        self.datasets
+            .iter()
+            .map(|dataset| {
+                let positions: Vec<f64> = dataset.samples.iter().map(|v| v * scale_y).collect();
+                beeswarm_offsets(&positions, diameter)
+                    .into_iter()
+                    .zip(dataset.samples.iter().copied())
+                    .collect()
+            })
+            .collect()
+    }
+}
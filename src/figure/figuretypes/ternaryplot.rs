@@ -0,0 +1,174 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig, datasets::ternarydataset::TernaryDataset,
+};
+
+/// Height of an equilateral triangle with unit side.
+pub const TRIANGLE_HEIGHT: f64 = 0.866_025_403_784_438_6;
+
+/// Space in pixels kept around the triangle for tick and vertex labels.
+pub const LABEL_SPACE: f64 = 40.0;
+
+/// Represents a ternary plot: three-component compositions drawn inside an equilateral
+/// triangle, where each vertex stands for a pure component.
+///
+/// Component A is at the bottom-left vertex, B at the bottom-right vertex and C at the top.
+/// Grid lines mark constant fractions of each component.
+#[derive(Clone)]
+pub struct TernaryPlot {
+    /// Title of the plot.
+    pub title: String,
+    /// Name of component A (bottom-left vertex).
+    pub a_label: String,
+    /// Name of component B (bottom-right vertex).
+    pub b_label: String,
+    /// Name of component C (top vertex).
+    pub c_label: String,
+    /// The datasets of the plot.
+    pub datasets: Vec<TernaryDataset>,
+    /// Configuration settings for rendering the plot (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl TernaryPlot {
+    /// Creates a new `TernaryPlot` instance with the specified title, component names, and configuration.
+    ///
+    /// The grid has `config.num_axis_ticks` divisions per component.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the plot.
+    /// - `a_label`, `b_label`, `c_label`: The names of the three components.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `TernaryPlot` instance with no datasets.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::ternaryplot::TernaryPlot;
+    ///
+    /// let mut plot = TernaryPlot::new("Soil Texture", "Sand", "Silt", "Clay", config);
+    /// plot.add_dataset(soils);
+    /// ```
+    pub fn new(
+        title: &str,
+        a_label: &str,
+        b_label: &str,
+        c_label: &str,
+        config: FigureConfig,
+    ) -> Self {
+        Self {
+            title: title.to_string(),
+            a_label: a_label.to_string(),
+            b_label: b_label.to_string(),
+            c_label: c_label.to_string(),
+            datasets: Vec::new(),
+            config,
+        }
+    }
+
+    /// Adds a dataset to the plot.
+    ///
+    /// # Parameters
+    /// - `dataset`: The `TernaryDataset` to add.
+    pub fn add_dataset(&mut self, dataset: TernaryDataset) {
+        self.datasets.push(dataset);
+    }
+
+    /// Computes the position and size of the triangle on a canvas.
+    ///
+    /// The triangle is the largest equilateral triangle fitting inside the margins and the
+    /// label space around it, centered in the remaining area.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(left, bottom, side)`: the canvas position of vertex A and the side length.
+    pub fn layout(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64) {
+        let inset = margin + LABEL_SPACE;
+        let side = (width - 2.0 * inset)
+            .min((height - 2.0 * inset) / TRIANGLE_HEIGHT)
+            .max(0.0);
+        let left = (width - side) / 2.0;
+        let bottom = (height + side * TRIANGLE_HEIGHT) / 2.0;
+        (left, bottom, side)
+    }
+
+    /// Converts a composition to canvas coordinates.
+    ///
+    /// # Parameters
+    /// - `point`: The composition `[a, b, c]`, summing to 1.
+    /// - `layout`: The layout returned by `layout`.
+    pub fn to_canvas(&self, point: [f64; 3], layout: (f64, f64, f64)) -> (f64, f64) {
+        let (left, bottom, side) = layout;
+        let [_, b, c] = point;
+        (
+            left + (b + c / 2.0) * side,
+            bottom - c * TRIANGLE_HEIGHT * side,
+        )
+    }
+
+    /// Computes the grid lines of the plot.
+    ///
+    /// # Returns
+    /// For each of the components A, B and C, the lines of constant fraction `k / n` for
+    /// `k = 1..n`, as `(fraction, start, end)` compositions. Each line starts on the edge
+    /// where the tick labels of its component are drawn.
+    #[allow(clippy::type_complexity)]
+    pub fn grid_lines(&self) -> [Vec<(f64, [f64; 3], [f64; 3])>; 3] {
+        let divisions = self.config.num_axis_ticks.max(1);
+        let fractions: Vec<f64> = (1..divisions)
+            .map(|k| k as f64 / divisions as f64)
+            .collect();
+
+        [
+            // A is labelled along the bottom edge (c = 0)
+            fractions
+                .iter()
+                .map(|&a| (a, [a, 1.0 - a, 0.0], [a, 0.0, 1.0 - a]))
+                .collect(),
+            // B is labelled along the right edge (a = 0)
+            fractions
+                .iter()
+                .map(|&b| (b, [0.0, b, 1.0 - b], [1.0 - b, b, 0.0]))
+                .collect(),
+            // C is labelled along the left edge (b = 0)
+            fractions
+                .iter()
+                .map(|&c| (c, [1.0 - c, 0.0, c], [0.0, 1.0 - c, c]))
+                .collect(),
+        ]
+    }
+
+    /// Formats a grid fraction as a tick label, e.g. `0.3` as `30%`.
+    pub fn tick_label(&self, fraction: f64) -> String {
+        self.config.format.format_percent(fraction * 100.0, 0)
+    }
+
+    /// Finds the data point closest to a canvas position.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas position.
+    /// - `layout`: The layout returned by `layout`.
+    ///
+    /// # Returns
+    /// The index of the dataset, the composition, and its distance in pixels, or `None`
+    /// without points.
+    pub fn closest_point(
+        &self,
+        x: f64,
+        y: f64,
+        layout: (f64, f64, f64),
+    ) -> Option<(usize, [f64; 3], f64)> {
+        self.datasets
+            .iter()
+            .enumerate()
+            .flat_map(|(index, dataset)| dataset.points.iter().map(move |&p| (index, p)))
+            .map(|(index, point)| {
+                let (px, py) = self.to_canvas(point, layout);
+                (index, point, (x - px).hypot(y - py))
+            })
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+    }
+}
@@ -0,0 +1,427 @@
+use crate::figure::{configuration::figureconfig::FigureConfig, utilities::colormap::tint};
+
+/// A node of a treemap: a labelled value, optionally split into child nodes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreemapNode {
+    /// The label of the node, drawn in its cell.
+    pub label: String,
+    /// The value of a leaf; nodes with children use the sum of their children instead.
+    pub value: f64,
+    /// The color of the node's cell, shared by its descendants.
+    pub color: Option<[u8; 3]>,
+    /// The child nodes, laid out inside the node's cell.
+    pub children: Vec<TreemapNode>,
+}
+
+impl TreemapNode {
+    /// Creates a node without children or color.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the node.
+    /// - `value`: The value of the node, used while it has no children.
+    pub fn new(label: &str, value: f64) -> Self {
+        Self {
+            label: label.to_string(),
+            value,
+            color: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Adds a child node.
+    ///
+    /// # Parameters
+    /// - `child`: The child, laid out inside this node's cell.
+    pub fn add_child(&mut self, child: TreemapNode) {
+        self.children.push(child);
+    }
+
+    /// Sets the color of the node and, unless they have their own, its descendants.
+    ///
+    /// # Parameters
+    /// - `color`: The RGB color of the cell.
+    pub fn set_color(&mut self, color: [u8; 3]) {
+        self.color = Some(color);
+    }
+
+    /// Returns the total of the node: its value for leaves and the sum of its children's
+    /// totals otherwise.
+    ///
+    /// Negative and non-finite values count as zero, as they have no area.
+    pub fn total(&self) -> f64 {
+        if self.children.is_empty() {
+            Some(self.value)
+                .filter(|value| value.is_finite())
+                .unwrap_or(0.0)
+                .max(0.0)
+        } else {
+            self.children.iter().map(TreemapNode::total).sum()
+        }
+    }
+
+    /// Returns the node at a path of child indices, starting at a root index.
+    ///
+    /// # Parameters
+    /// - `roots`: The root nodes of the hierarchy.
+    /// - `path`: The index of the root, followed by the child index at every level.
+    ///
+    /// # Panics
+    /// Panics if the path is empty or an index is out of bounds.
+    pub fn at_path<'a>(roots: &'a [TreemapNode], path: &[usize]) -> &'a TreemapNode {
+        path[1..]
+            .iter()
+            .fold(&roots[path[0]], |node, &index| &node.children[index])
+    }
+
+    /// Joins the labels of a node and its ancestors, e.g. `"home / photos"`.
+    ///
+    /// # Parameters
+    /// - `roots`: The root nodes of the hierarchy.
+    /// - `path`: The path of the node, as for `at_path`.
+    pub fn path_label(roots: &[TreemapNode], path: &[usize]) -> String {
+        (1..=path.len())
+            .map(|len| Self::at_path(roots, &path[..len]).label.as_str())
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+
+    /// Returns the color of a node, shaded by its depth.
+    ///
+    /// A node takes the color of its nearest ancestor (or itself) with a color set, or of
+    /// its root's position in `Treemap::ROOT_COLORS`, mixed with white by 15% per level
+    /// below it.
+    ///
+    /// # Parameters
+    /// - `roots`: The root nodes of the hierarchy.
+    /// - `path`: The path of the node, as for `at_path`.
+    pub fn shaded_color(roots: &[TreemapNode], path: &[usize]) -> [u8; 3] {
+        let mut base = Treemap::ROOT_COLORS[path[0] % Treemap::ROOT_COLORS.len()];
+        let mut base_depth = 0;
+        for depth in 0..path.len() {
+            if let Some(color) = Self::at_path(roots, &path[..=depth]).color {
+                base = color;
+                base_depth = depth;
+            }
+        }
+        tint(base, (0.15 * (path.len() - 1 - base_depth) as f64).min(0.6))
+    }
+}
+
+/// A laid out cell of a treemap.
+///
+/// Coordinates are pixels relative to the top-left corner of the plot area.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreemapCell {
+    /// Indices of the node and its ancestors, starting at the root node.
+    pub path: Vec<usize>,
+    /// The total of the node.
+    pub value: f64,
+    /// Whether the node has no children.
+    pub is_leaf: bool,
+    /// Left edge of the cell.
+    pub x0: f64,
+    /// Top edge of the cell.
+    pub y0: f64,
+    /// Right edge of the cell.
+    pub x1: f64,
+    /// Bottom edge of the cell.
+    pub y1: f64,
+}
+
+impl TreemapCell {
+    /// Returns the nesting depth of the cell, 0 for root nodes.
+    pub fn depth(&self) -> usize {
+        self.path.len() - 1
+    }
+
+    /// Returns the width of the cell.
+    pub fn width(&self) -> f64 {
+        self.x1 - self.x0
+    }
+
+    /// Returns the height of the cell.
+    pub fn height(&self) -> f64 {
+        self.y1 - self.y0
+    }
+
+    /// Returns whether a point lies inside the cell.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        (self.x0..self.x1).contains(&x) && (self.y0..self.y1).contains(&y)
+    }
+}
+
+/// Represents a treemap: hierarchical data drawn as nested rectangles whose areas are
+/// proportional to the node totals.
+///
+/// Cells are laid out with the squarified algorithm, which keeps them close to squares so
+/// their areas are easy to compare. Each root node gets a color of its own, and nested
+/// cells are drawn lighter than their parent inside a padded frame whose header holds the
+/// parent's label. Labels that do not fit their cell are shortened with an ellipsis.
+#[derive(Clone)]
+pub struct Treemap {
+    /// Title of the chart.
+    pub title: String,
+    /// The root nodes, sharing the plot area.
+    pub roots: Vec<TreemapNode>,
+    /// Height of the header holding the label of a parent cell, in pixels.
+    pub header_height: f64,
+    /// Whether cells show their labels.
+    pub show_labels: bool,
+    /// The number of decimal places of values in tooltips.
+    pub precision: usize,
+    /// Configuration settings for rendering the chart (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl Treemap {
+    /// Colors assigned to the root nodes in order.
+    pub const ROOT_COLORS: [[u8; 3]; 8] = [
+        [31, 119, 180],
+        [255, 127, 14],
+        [44, 160, 44],
+        [214, 39, 40],
+        [148, 103, 189],
+        [140, 86, 75],
+        [227, 119, 194],
+        [23, 190, 207],
+    ];
+
+    /// Space between a parent cell's edges and its children, in pixels.
+    pub const NEST_PADDING: f64 = 3.0;
+
+    /// Creates a new `Treemap` instance with the specified title and configuration.
+    ///
+    /// Labels are shown, with a header of 18 pixels for parent labels.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the chart.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `Treemap` instance without nodes.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::treemap::{Treemap, TreemapNode};
+    ///
+    /// let mut treemap = Treemap::new("Disk Usage", config);
+    /// let mut home = TreemapNode::new("home", 0.0);
+    /// home.add_child(TreemapNode::new("photos", 120.0));
+    /// home.add_child(TreemapNode::new("music", 45.0));
+    /// treemap.add_root(home);
+    /// treemap.add_root(TreemapNode::new("usr", 80.0));
+    /// ```
+    pub fn new(title: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            roots: Vec::new(),
+            header_height: 18.0,
+            show_labels: true,
+            precision: 2,
+            config,
+        }
+    }
+
+    /// Adds a root node.
+    ///
+    /// # Parameters
+    /// - `node`: The node, with its descendants.
+    pub fn add_root(&mut self, node: TreemapNode) {
+        self.roots.push(node);
+    }
+
+    /// Returns the sum of the root totals.
+    pub fn total(&self) -> f64 {
+        self.roots.iter().map(TreemapNode::total).sum()
+    }
+
+    /// Returns the node at a path of child indices, starting at a root index.
+    ///
+    /// # Panics
+    /// Panics if the path is empty or an index is out of bounds.
+    pub fn node(&self, path: &[usize]) -> &TreemapNode {
+        TreemapNode::at_path(&self.roots, path)
+    }
+
+    /// Joins the labels of a node and its ancestors, e.g. `"home / photos"`.
+    pub fn path_label(&self, path: &[usize]) -> String {
+        TreemapNode::path_label(&self.roots, path)
+    }
+
+    /// Lays out the cells of the treemap.
+    ///
+    /// Nodes without area are skipped. Children are laid out inside their parent, inset by
+    /// `NEST_PADDING` and, if the parent is tall enough, below a header of
+    /// `header_height`.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The size of the plot area in pixels.
+    ///
+    /// # Returns
+    /// The cells, every parent before its children.
+    pub fn cells(&self, width: f64, height: f64) -> Vec<TreemapCell> {
+        let mut cells = Vec::new();
+        self.layout_nodes(&self.roots, &[], (0.0, 0.0, width, height), &mut cells);
+        cells
+    }
+
+    /// Lays out sibling nodes inside a rectangle, recursing into their children.
+    fn layout_nodes(
+        &self,
+        nodes: &[TreemapNode],
+        parent: &[usize],
+        rect: (f64, f64, f64, f64),
+        cells: &mut Vec<TreemapCell>,
+    ) {
+        // The squarified layout places the largest nodes first
+        let mut order: Vec<(usize, f64)> = nodes
+            .iter()
+            .map(TreemapNode::total)
+            .enumerate()
+            .filter(|&(_, total)| total > 0.0)
+            .collect();
+        order.sort_by(|a, b| b.1.total_cmp(&a.1));
+        let totals: Vec<f64> = order.iter().map(|&(_, total)| total).collect();
+
+        for (&(index, total), (x0, y0, x1, y1)) in order.iter().zip(squarify(&totals, rect)) {
+            let node = &nodes[index];
+            let mut path = parent.to_vec();
+            path.push(index);
+            cells.push(TreemapCell {
+                path: path.clone(),
+                value: total,
+                is_leaf: node.children.is_empty(),
+                x0,
+                y0,
+                x1,
+                y1,
+            });
+
+            if node.children.is_empty() {
+                continue;
+            }
+            let header = if y1 - y0 >= 2.0 * self.header_height {
+                self.header_height
+            } else {
+                Self::NEST_PADDING
+            };
+            let inner = (
+                x0 + Self::NEST_PADDING,
+                y0 + header,
+                x1 - Self::NEST_PADDING,
+                y1 - Self::NEST_PADDING,
+            );
+            if inner.2 > inner.0 && inner.3 > inner.1 {
+                self.layout_nodes(&node.children, &path, inner, cells);
+            }
+        }
+    }
+
+    /// Finds the innermost cell containing a point.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The point in pixels relative to the top-left corner of the plot area.
+    /// - `width`, `height`: The size of the plot area in pixels.
+    pub fn cell_at(&self, x: f64, y: f64, width: f64, height: f64) -> Option<TreemapCell> {
+        self.cells(width, height)
+            .into_iter()
+            .filter(|cell| cell.contains(x, y))
+            .max_by_key(TreemapCell::depth)
+    }
+
+    /// Returns whether a cell shows a header with its label above its children.
+    pub fn has_header(&self, cell: &TreemapCell) -> bool {
+        !cell.is_leaf && cell.height() >= 2.0 * self.header_height
+    }
+
+    /// Returns the fill color of a cell, shaded by its depth (see
+    /// `TreemapNode::shaded_color`).
+    pub fn cell_color(&self, cell: &TreemapCell) -> [u8; 3] {
+        TreemapNode::shaded_color(&self.roots, &cell.path)
+    }
+
+    /// Formats the tooltip of a cell: its path, value and share of the total.
+    pub fn cell_tooltip(&self, cell: &TreemapCell) -> String {
+        let total = self.total();
+        let share = if total > 0.0 {
+            cell.value / total * 100.0
+        } else {
+            0.0
+        };
+        format!(
+            "{}: {} ({})",
+            self.path_label(&cell.path),
+            self.config.format.format_number(cell.value, self.precision),
+            self.config.format.format_percent(share, 1)
+        )
+    }
+}
+
+/// Squarified treemap layout (Bruls, Huizing and van Wijk).
+///
+/// Values are placed in rows along the shorter side of the remaining rectangle; a row
+/// grows while that does not worsen its most elongated cell.
+///
+/// # Parameters
+/// - `values`: Positive values, largest first.
+/// - `rect`: The rectangle to fill, as `(x0, y0, x1, y1)`.
+///
+/// # Returns
+/// One rectangle per value, in the order of the values.
+fn squarify(values: &[f64], rect: (f64, f64, f64, f64)) -> Vec<(f64, f64, f64, f64)> {
+    let (mut x0, mut y0, x1, y1) = rect;
+    let total: f64 = values.iter().sum();
+    if total <= 0.0 || x1 <= x0 || y1 <= y0 {
+        return vec![(x0, y0, x0, y0); values.len()];
+    }
+    let areas: Vec<f64> = values
+        .iter()
+        .map(|value| value / total * (x1 - x0) * (y1 - y0))
+        .collect();
+
+    // The largest aspect ratio of a row laid along a side
+    let worst = |row: &[f64], side: f64| {
+        let sum: f64 = row.iter().sum();
+        row.iter()
+            .map(|&area| (side * side * area / (sum * sum)).max(sum * sum / (side * side * area)))
+            .fold(0.0, f64::max)
+    };
+
+    let mut rects = Vec::with_capacity(areas.len());
+    let mut start = 0;
+    while start < areas.len() {
+        let (width, height) = (x1 - x0, y1 - y0);
+        let side = width.min(height);
+        let mut end = start + 1;
+        while end < areas.len()
+            && worst(&areas[start..=end], side) <= worst(&areas[start..end], side)
+        {
+            end += 1;
+        }
+
+        let row_area: f64 = areas[start..end].iter().sum();
+        if width >= height {
+            // A column along the left edge
+            let column_width = row_area / height;
+            let mut y = y0;
+            for area in &areas[start..end] {
+                let cell_height = area / column_width;
+                rects.push((x0, y, x0 + column_width, y + cell_height));
+                y += cell_height;
+            }
+            x0 += column_width;
+        } else {
+            // A row along the top edge
+            let row_height = row_area / width;
+            let mut x = x0;
+            for area in &areas[start..end] {
+                let cell_width = area / row_height;
+                rects.push((x, y0, x + cell_width, y0 + row_height));
+                x += cell_width;
+            }
+            y0 += row_height;
+        }
+        start = end;
+    }
+    rects
+}
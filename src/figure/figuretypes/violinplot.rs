@@ -0,0 +1,107 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig, datasets::violinplotdataset::ViolinPlotDataset,
+    utilities::scaling::data_bounds,
+};
+
+/// Number of values the density of each violin is evaluated at.
+pub const DENSITY_POINTS: usize = 100;
+
+/// Represents a violin plot: the mirrored kernel density estimate of each category, with an
+/// optional quartile and median overlay.
+#[derive(Clone)]
+pub struct ViolinPlot {
+    /// Title of the violin plot.
+    pub title: String,
+    /// Label for the X-axis (the category axis).
+    pub x_label: String,
+    /// Label for the Y-axis (the value axis).
+    pub y_label: String,
+    /// One dataset per category, drawn from left to right.
+    pub datasets: Vec<ViolinPlotDataset>,
+    /// Width of the widest part of each violin as a fraction of the category width
+    /// (`0.0` to `1.0`).
+    pub violin_width: f64,
+    /// Whether a thin box from the first to the third quartile, the whiskers and a median
+    /// marker are drawn inside each violin.
+    pub show_quartiles: bool,
+    /// Configuration settings for rendering the plot (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl ViolinPlot {
+    /// Creates a new `ViolinPlot` instance with the specified title, labels, and configuration.
+    ///
+    /// The plot starts with violins 80% as wide as a category and the quartile overlay shown.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the violin plot.
+    /// - `x_label`: The label for the category axis.
+    /// - `y_label`: The label for the value axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `ViolinPlot` instance with no categories.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::violinplot::ViolinPlot;
+    ///
+    /// let mut plot = ViolinPlot::new("Reaction Times", "Group", "ms", FigureConfig::default());
+    /// plot.show_quartiles = false;
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            datasets: Vec::new(),
+            violin_width: 0.8,
+            show_quartiles: true,
+            config,
+        }
+    }
+
+    /// Adds a category to the violin plot.
+    ///
+    /// # Parameters
+    /// - `dataset`: The `ViolinPlotDataset` holding the category's samples.
+    pub fn add_dataset(&mut self, dataset: ViolinPlotDataset) {
+        self.datasets.push(dataset);
+    }
+
+    /// Returns the largest density of any violin, so all violins share one width scale and
+    /// their areas stay comparable.
+    ///
+    /// # Returns
+    /// The largest density, or `0.0` when no violin has a density.
+    pub fn max_density(&self) -> f64 {
+        self.datasets
+            .iter()
+            .flat_map(|dataset| dataset.density(DENSITY_POINTS))
+            .map(|(_, density)| density)
+            .fold(0.0, f64::max)
+    }
+
+    /// Computes the value range of the plot, covering all samples, padded by 5% on both
+    /// sides.
+    ///
+    /// # Returns
+    /// A tuple `(min, max)`. Defaults to `(0.0, 1.0)` when there are no samples.
+    pub fn value_range(&self) -> (f64, f64) {
+        let values = self
+            .datasets
+            .iter()
+            .flat_map(|dataset| dataset.samples.iter().copied())
+            .filter(|value| value.is_finite())
+            .map(|value| (0.0, value));
+        match data_bounds(values) {
+            None => (0.0, 1.0),
+            Some((_, _, min, max)) if min == max => (min - 1.0, max + 1.0),
+            Some((_, _, min, max)) => {
+                let padding = (max - min) * 0.05;
+                (min - padding, max + padding)
+            }
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use crate::figure::{configuration::figureconfig::FigureConfig, utilities::axistype::AxisType};
+
+/// One step of a waterfall chart: a change of the running total, or a bar showing it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaterfallStep {
+    /// The label of the step, shown below its bar.
+    pub label: String,
+    /// The change of the running total; ignored for totals.
+    pub value: f64,
+    /// Whether the step shows the running total instead of changing it.
+    pub total: bool,
+}
+
+/// How a waterfall bar is colored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WaterfallBarKind {
+    /// A change that raises the running total.
+    Increase,
+    /// A change that lowers the running total.
+    Decrease,
+    /// The running total, drawn from zero.
+    Total,
+}
+
+/// A laid out bar of a waterfall chart.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WaterfallBar {
+    /// Index of the step.
+    pub index: usize,
+    /// The running total before the step (zero for totals).
+    pub start: f64,
+    /// The running total after the step.
+    pub end: f64,
+    /// The color class of the bar.
+    pub kind: WaterfallBarKind,
+}
+
+impl WaterfallBar {
+    /// Returns the signed change of the bar, or the total it shows.
+    pub fn value(&self) -> f64 {
+        self.end - self.start
+    }
+
+    /// Returns the lower and upper value of the bar.
+    pub fn span(&self) -> (f64, f64) {
+        (self.start.min(self.end), self.start.max(self.end))
+    }
+}
+
+/// Represents a waterfall chart: bars that each start where the previous one ended, so a
+/// starting value is broken down into its increases and decreases (e.g. revenue to profit).
+///
+/// Increases, decreases and totals have distinct colors, and connector lines carry the
+/// running total from each bar to the next.
+#[derive(Clone)]
+pub struct WaterfallChart {
+    /// Title of the chart.
+    pub title: String,
+    /// Label for the X-axis (the step axis).
+    pub x_label: String,
+    /// Label for the Y-axis (the value axis).
+    pub y_label: String,
+    /// The steps, drawn as bars from left to right.
+    pub steps: Vec<WaterfallStep>,
+    /// Color of bars raising the running total.
+    pub increase_color: [u8; 3],
+    /// Color of bars lowering the running total.
+    pub decrease_color: [u8; 3],
+    /// Color of total bars.
+    pub total_color: [u8; 3],
+    /// Color of the connector lines between bars.
+    pub connector_color: [u8; 3],
+    /// Whether each bar shows its change or total as a label.
+    pub show_values: bool,
+    /// Number of decimals of value labels and tooltips.
+    pub precision: usize,
+    /// Configuration settings for rendering the chart (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl WaterfallChart {
+    /// Width of a bar as a fraction of its slot.
+    pub const BAR_WIDTH: f64 = 0.6;
+
+    /// Creates a new `WaterfallChart` instance with the specified title, labels, and
+    /// configuration.
+    ///
+    /// Increases are green, decreases red and totals blue; value labels are shown.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the chart.
+    /// - `x_label`: The label for the step axis.
+    /// - `y_label`: The label for the value axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `WaterfallChart` instance without steps.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::waterfallchart::WaterfallChart;
+    ///
+    /// let mut chart = WaterfallChart::new("Profit Bridge", "", "EUR (k)", config);
+    /// chart.add_step("Revenue", 420.0);
+    /// chart.add_step("Costs", -260.0);
+    /// chart.add_step("Taxes", -45.0);
+    /// chart.add_total("Profit");
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            steps: Vec::new(),
+            increase_color: [46, 160, 67],
+            decrease_color: [214, 39, 40],
+            total_color: [31, 119, 180],
+            connector_color: [120, 120, 120],
+            show_values: true,
+            precision: 2,
+            config,
+        }
+    }
+
+    /// Adds a step changing the running total.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the step.
+    /// - `value`: The change; positive values are increases, negative ones decreases.
+    pub fn add_step(&mut self, label: &str, value: f64) {
+        self.steps.push(WaterfallStep {
+            label: label.to_string(),
+            value,
+            total: false,
+        });
+    }
+
+    /// Adds a bar showing the running total of the steps before it, e.g. a subtotal or the
+    /// final result.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the total.
+    pub fn add_total(&mut self, label: &str) {
+        self.steps.push(WaterfallStep {
+            label: label.to_string(),
+            value: 0.0,
+            total: true,
+        });
+    }
+
+    /// Sets the colors of increases, decreases and totals.
+    ///
+    /// # Parameters
+    /// - `increase`: The RGB color of bars raising the running total.
+    /// - `decrease`: The RGB color of bars lowering the running total.
+    /// - `total`: The RGB color of total bars.
+    pub fn set_colors(&mut self, increase: [u8; 3], decrease: [u8; 3], total: [u8; 3]) {
+        self.increase_color = increase;
+        self.decrease_color = decrease;
+        self.total_color = total;
+    }
+
+    /// Lays out the bars of the chart.
+    ///
+    /// Non-finite changes count as zero.
+    ///
+    /// # Returns
+    /// One bar per step, in step order.
+    pub fn bars(&self) -> Vec<WaterfallBar> {
+        let mut running = 0.0;
+        self.steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| {
+                if step.total {
+                    return WaterfallBar {
+                        index,
+                        start: 0.0,
+                        end: running,
+                        kind: WaterfallBarKind::Total,
+                    };
+                }
+                let value = if step.value.is_finite() {
+                    step.value
+                } else {
+                    0.0
+                };
+                let start = running;
+                running += value;
+                WaterfallBar {
+                    index,
+                    start,
+                    end: running,
+                    kind: if value < 0.0 {
+                        WaterfallBarKind::Decrease
+                    } else {
+                        WaterfallBarKind::Increase
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Computes the value range of the y-axis: all bar spans and zero.
+    pub fn value_range(&self) -> (f64, f64) {
+        let (min, max) = self
+            .bars()
+            .iter()
+            .fold((0.0f64, 0.0f64), |(min, max), bar| {
+                let (low, high) = bar.span();
+                (min.min(low), max.max(high))
+            });
+        if max > min {
+            (min, max)
+        } else {
+            (min, min + 1.0)
+        }
+    }
+
+    /// Returns the color of a bar kind.
+    pub fn bar_color(&self, kind: WaterfallBarKind) -> [u8; 3] {
+        match kind {
+            WaterfallBarKind::Increase => self.increase_color,
+            WaterfallBarKind::Decrease => self.decrease_color,
+            WaterfallBarKind::Total => self.total_color,
+        }
+    }
+
+    /// Formats the label of a bar: the signed change, or the total, localized like the
+    /// values of the y-axis.
+    pub fn bar_label(&self, bar: &WaterfallBar) -> String {
+        let number = match bar.kind {
+            WaterfallBarKind::Total => format!("{:.*}", self.precision, bar.value()),
+            _ => format!("{:+.*}", self.precision, bar.value()),
+        };
+        self.config.localize_value(AxisType::AxisY, &number)
+    }
+
+    /// Returns the left and right edge of a step's bar, as fractions of the plot width.
+    pub fn bar_edges(&self, index: usize) -> (f64, f64) {
+        let slot = 1.0 / self.steps.len().max(1) as f64;
+        let center = (index as f64 + 0.5) * slot;
+        (
+            center - slot * Self::BAR_WIDTH / 2.0,
+            center + slot * Self::BAR_WIDTH / 2.0,
+        )
+    }
+
+    /// Finds the step whose slot contains a position.
+    ///
+    /// # Parameters
+    /// - `x`: The position as a fraction of the plot width.
+    pub fn step_at(&self, x: f64) -> Option<usize> {
+        if !(0.0..1.0).contains(&x) || self.steps.is_empty() {
+            return None;
+        }
+        Some(((x * self.steps.len() as f64) as usize).min(self.steps.len() - 1))
+    }
+}
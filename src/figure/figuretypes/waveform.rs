@@ -0,0 +1,171 @@
+use crate::figure::configuration::figureconfig::FigureConfig;
+
+/// Represents an audio waveform: a long PCM buffer drawn as its min/max envelope.
+///
+/// Instead of a polyline through every sample, each pixel column shows the range of the
+/// samples falling into it, so drawing cost depends on the figure width rather than the
+/// buffer length and no peaks are lost to decimation.
+#[derive(Clone)]
+pub struct Waveform {
+    /// Title of the waveform.
+    pub title: String,
+    /// The samples, typically between -1 and 1.
+    pub samples: Vec<f64>,
+    /// The sample rate in Hz.
+    pub sample_rate: f64,
+    /// Color of the envelope.
+    pub color: [u8; 3],
+    /// Configuration settings for rendering the waveform (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl Waveform {
+    /// Creates a new `Waveform` instance with the specified title, sample rate, and configuration.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the waveform.
+    /// - `sample_rate`: The sample rate in Hz.
+    /// - `color`: The RGB color of the envelope.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `Waveform` instance without samples.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::waveform::Waveform;
+    ///
+    /// let mut waveform = Waveform::new("Take 3", 44100.0, [40, 90, 160], config);
+    /// waveform.set_samples(samples);
+    /// ```
+    pub fn new(title: &str, sample_rate: f64, color: [u8; 3], config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            samples: Vec::new(),
+            sample_rate,
+            color,
+            config,
+        }
+    }
+
+    /// Replaces the samples.
+    ///
+    /// # Parameters
+    /// - `samples`: The PCM samples.
+    pub fn set_samples(&mut self, samples: Vec<f64>) {
+        self.samples = samples;
+    }
+
+    /// Returns the duration of the buffer in seconds.
+    pub fn duration(&self) -> f64 {
+        self.samples.len() as f64 / self.sample_rate
+    }
+
+    /// Returns the amplitude shown at the top of the plot: the peak absolute sample, or 1
+    /// for a silent buffer.
+    pub fn peak(&self) -> f64 {
+        let peak = self
+            .samples
+            .iter()
+            .fold(0.0, |peak: f64, s| peak.max(s.abs()));
+        if peak > 0.0 {
+            peak
+        } else {
+            1.0
+        }
+    }
+
+    /// Computes the min/max envelope of the samples.
+    ///
+    /// The buffer is split into `columns` consecutive chunks of (nearly) equal length. With
+    /// fewer samples than columns, chunks repeat the nearest sample.
+    ///
+    /// # Parameters
+    /// - `columns`: The number of envelope columns, usually the plot width in pixels.
+    ///
+    /// # Returns
+    /// The `(min, max)` of each column, or an empty list without samples.
+    pub fn envelope(&self, columns: usize) -> Vec<(f64, f64)> {
+        (0..columns)
+            .map_while(|column| self.envelope_column(column, columns))
+            .collect()
+    }
+
+    /// Computes a single column of the min/max envelope.
+    ///
+    /// # Parameters
+    /// - `column`: The index of the column.
+    /// - `columns`: The total number of envelope columns.
+    ///
+    /// # Returns
+    /// The `(min, max)` of the column, or `None` without samples or past the last column.
+    pub fn envelope_column(&self, column: usize, columns: usize) -> Option<(f64, f64)> {
+        let len = self.samples.len();
+        if len == 0 || column >= columns {
+            return None;
+        }
+
+        let start = (column * len / columns).min(len - 1);
+        let end = ((column + 1) * len / columns).clamp(start + 1, len);
+        Some(
+            self.samples[start..end]
+                .iter()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &s| {
+                    (min.min(s), max.max(s))
+                }),
+        )
+    }
+
+    /// Computes the plot area on a canvas.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(left, top, right, bottom)` in canvas coordinates.
+    pub fn plot_area(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64, f64) {
+        (
+            margin,
+            margin,
+            (width - margin).max(margin + 1.0),
+            (height - margin).max(margin + 1.0),
+        )
+    }
+
+    /// Converts an amplitude to a canvas y-coordinate.
+    ///
+    /// # Parameters
+    /// - `amplitude`: The sample value.
+    /// - `peak`: The amplitude at the top of the plot, as returned by `peak`.
+    /// - `area`: The plot area returned by `plot_area`.
+    pub fn to_canvas_y(&self, amplitude: f64, peak: f64, area: (f64, f64, f64, f64)) -> f64 {
+        let (_, top, _, bottom) = area;
+        let middle = (top + bottom) / 2.0;
+        middle - amplitude / peak * (bottom - top) / 2.0
+    }
+}
+
+/// Formats a time as minutes and seconds, e.g. `83.5` as `1:23` or, with one decimal,
+/// `1:23.5`.
+///
+/// # Parameters
+/// - `seconds`: The time in seconds.
+/// - `decimals`: The number of decimals of the seconds.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::figuretypes::waveform::format_mm_ss;
+///
+/// assert_eq!(format_mm_ss(83.5, 0), "1:24");
+/// assert_eq!(format_mm_ss(83.5, 1), "1:23.5");
+/// ```
+pub fn format_mm_ss(seconds: f64, decimals: usize) -> String {
+    let sign = if seconds < 0.0 { "-" } else { "" };
+    let scale = 10f64.powi(decimals as i32);
+    let total = (seconds.abs() * scale).round() / scale;
+    let minutes = (total / 60.0).floor();
+    let rest = total - minutes * 60.0;
+    let width = if decimals > 0 { decimals + 3 } else { 2 };
+    format!("{sign}{minutes:.0}:{rest:0width$.decimals$}")
+}
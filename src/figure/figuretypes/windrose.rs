@@ -0,0 +1,287 @@
+use crate::figure::{
+    configuration::figureconfig::FigureConfig,
+    utilities::colormap::{interpolate, SEQUENTIAL_YELLOW_BLUE},
+};
+
+/// Space in pixels kept around the rose for the compass labels.
+pub const COMPASS_SPACE: f64 = 25.0;
+
+/// Names of the 16 compass points, clockwise from north.
+const COMPASS_POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
+
+/// Represents a wind rose: directional observations binned into angular sectors, with the
+/// sector bars stacked by magnitude class.
+///
+/// Directions are compass bearings in degrees (0 = north, clockwise) giving where the wind
+/// comes from. Bar lengths are the percentage of all observations in the sector, split
+/// into the magnitude classes from the center outwards.
+#[derive(Clone)]
+pub struct WindRose {
+    /// Title of the chart.
+    pub title: String,
+    /// Number of sectors, the first centered on north.
+    pub num_sectors: usize,
+    /// Lower edges of the magnitude classes, ascending; the last class is open-ended.
+    pub class_edges: Vec<f64>,
+    /// Unit of the magnitudes, shown in the legend (e.g., `m/s`).
+    pub unit: String,
+    /// Colors of the magnitude classes, from the lowest class.
+    pub class_colors: Vec<[u8; 3]>,
+    /// The observations as `(direction, magnitude)` pairs.
+    pub observations: Vec<(f64, f64)>,
+    /// Configuration settings for rendering the chart (e.g., colors, fonts, grid).
+    pub config: FigureConfig,
+}
+
+impl WindRose {
+    /// Creates a new `WindRose` instance with the specified sectors, classes, and configuration.
+    ///
+    /// Class colors are sampled from a light yellow to dark blue scale; replace
+    /// `class_colors` to change them.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the chart.
+    /// - `num_sectors`: The number of direction sectors (commonly 8, 16 or 36).
+    /// - `class_edges`: The ascending lower edges of the magnitude classes.
+    /// - `unit`: The unit of the magnitudes.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `WindRose` instance with no observations.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::figuretypes::windrose::WindRose;
+    ///
+    /// let mut rose = WindRose::new("Station 12", 16, &[0.0, 2.0, 4.0, 6.0, 8.0], "m/s", config);
+    /// rose.add_observation(225.0, 5.3);
+    /// ```
+    pub fn new(
+        title: &str,
+        num_sectors: usize,
+        class_edges: &[f64],
+        unit: &str,
+        config: FigureConfig,
+    ) -> Self {
+        let num_classes = class_edges.len().max(1);
+        let class_colors = (0..num_classes)
+            .map(|class| {
+                let t = class as f64 / (num_classes - 1).max(1) as f64;
+                interpolate(&SEQUENTIAL_YELLOW_BLUE, 0.2 + 0.8 * t)
+            })
+            .collect();
+
+        Self {
+            title: title.to_string(),
+            num_sectors: num_sectors.max(1),
+            class_edges: class_edges.to_vec(),
+            unit: unit.to_string(),
+            class_colors,
+            observations: Vec::new(),
+            config,
+        }
+    }
+
+    /// Adds an observation.
+    ///
+    /// # Parameters
+    /// - `direction`: The compass bearing in degrees; any value is wrapped into `[0, 360)`.
+    /// - `magnitude`: The magnitude, such as the wind speed.
+    pub fn add_observation(&mut self, direction: f64, magnitude: f64) {
+        self.observations
+            .push((direction.rem_euclid(360.0), magnitude));
+    }
+
+    /// Adds several observations.
+    ///
+    /// # Parameters
+    /// - `observations`: The observations as `(direction, magnitude)` pairs.
+    pub fn add_observations(&mut self, observations: &[(f64, f64)]) {
+        for &(direction, magnitude) in observations {
+            self.add_observation(direction, magnitude);
+        }
+    }
+
+    /// Returns the angular width of a sector in degrees.
+    pub fn sector_width(&self) -> f64 {
+        360.0 / self.num_sectors as f64
+    }
+
+    /// Finds the sector containing a direction.
+    pub fn sector_of(&self, direction: f64) -> usize {
+        let width = self.sector_width();
+        ((direction.rem_euclid(360.0) + width / 2.0) / width) as usize % self.num_sectors
+    }
+
+    /// Finds the magnitude class of a magnitude; values below the first edge fall in the
+    /// first class.
+    pub fn class_of(&self, magnitude: f64) -> usize {
+        self.class_edges
+            .iter()
+            .rposition(|&edge| magnitude >= edge)
+            .unwrap_or(0)
+    }
+
+    /// Returns the bearings of the edges of a sector, in degrees.
+    ///
+    /// # Returns
+    /// A tuple `(start, end)`; the first sector starts at a negative bearing.
+    pub fn sector_bounds(&self, sector: usize) -> (f64, f64) {
+        let width = self.sector_width();
+        let center = sector as f64 * width;
+        (center - width / 2.0, center + width / 2.0)
+    }
+
+    /// Names a sector by its center, e.g. `NNE` for 16 sectors or `10°` for 36.
+    pub fn sector_label(&self, sector: usize) -> String {
+        let center = sector as f64 * self.sector_width();
+        let point = center / 22.5;
+        if (point - point.round()).abs() < 1e-9 {
+            COMPASS_POINTS[point.round() as usize % 16].to_string()
+        } else {
+            format!("{center:.0}°")
+        }
+    }
+
+    /// Names a magnitude class by its range, e.g. `2–4` or `≥ 8`.
+    pub fn class_label(&self, class: usize) -> String {
+        match (self.class_edges.get(class), self.class_edges.get(class + 1)) {
+            (Some(low), Some(high)) => format!("{low}–{high}"),
+            (Some(low), None) => format!("≥ {low}"),
+            _ => String::new(),
+        }
+    }
+
+    /// Computes the share of the observations in each sector and magnitude class.
+    ///
+    /// # Returns
+    /// Percentages of all observations, indexed as `[sector][class]`.
+    pub fn frequencies(&self) -> Vec<Vec<f64>> {
+        let num_classes = self.class_edges.len().max(1);
+        let mut frequencies = vec![vec![0.0; num_classes]; self.num_sectors];
+        if self.observations.is_empty() {
+            return frequencies;
+        }
+
+        let share = 100.0 / self.observations.len() as f64;
+        for &(direction, magnitude) in &self.observations {
+            frequencies[self.sector_of(direction)][self.class_of(magnitude)] += share;
+        }
+        frequencies
+    }
+
+    /// Computes the percentages of the frequency rings, the last one enclosing every sector.
+    pub fn ring_values(&self) -> Vec<f64> {
+        let max_total = self
+            .frequencies()
+            .iter()
+            .map(|classes| classes.iter().sum::<f64>())
+            .fold(0.0, f64::max);
+        let num_rings = 4;
+        let step = (max_total / num_rings as f64).ceil().max(1.0);
+        (1..=num_rings).map(|ring| ring as f64 * step).collect()
+    }
+
+    /// Computes the center and radius of the rose on a canvas.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: The canvas size in pixels.
+    /// - `margin`: The canvas margin in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(center_x, center_y, radius)`.
+    pub fn layout(&self, width: f64, height: f64, margin: f64) -> (f64, f64, f64) {
+        let radius = (width.min(height) / 2.0 - margin - COMPASS_SPACE).max(0.0);
+        (width / 2.0, height / 2.0, radius)
+    }
+
+    /// Converts a bearing and a distance from the center to canvas coordinates.
+    ///
+    /// # Parameters
+    /// - `bearing`: The compass bearing in degrees.
+    /// - `distance`: The distance from the center in pixels.
+    /// - `layout`: The layout returned by `layout`.
+    pub fn to_canvas(&self, bearing: f64, distance: f64, layout: (f64, f64, f64)) -> (f64, f64) {
+        let (center_x, center_y, _) = layout;
+        let angle = bearing.to_radians();
+        (
+            center_x + distance * angle.sin(),
+            center_y - distance * angle.cos(),
+        )
+    }
+
+    /// Computes the stacked bar segments of the rose.
+    ///
+    /// # Parameters
+    /// - `layout`: The layout returned by `layout`.
+    ///
+    /// # Returns
+    /// For each non-empty segment, its sector, its class and the polygon outlining it in
+    /// canvas coordinates. Segments are ordered from the center outwards.
+    #[allow(clippy::type_complexity)]
+    pub fn wedges(&self, layout: (f64, f64, f64)) -> Vec<(usize, usize, Vec<(f64, f64)>)> {
+        let radius = layout.2;
+        let outer = self.ring_values().last().copied().unwrap_or(1.0);
+        // Bars are slightly narrower than their sector to keep them apart
+        let gap = self.sector_width() * 0.05;
+
+        let mut wedges = Vec::new();
+        for (sector, classes) in self.frequencies().iter().enumerate() {
+            let (start, end) = self.sector_bounds(sector);
+            let (start, end) = (start + gap, end - gap);
+            let mut inner = 0.0;
+            for (class, &frequency) in classes.iter().enumerate() {
+                if frequency <= 0.0 {
+                    continue;
+                }
+                let r0 = inner / outer * radius;
+                let r1 = (inner + frequency) / outer * radius;
+                inner += frequency;
+
+                // Arcs are approximated with a point every two degrees
+                let steps = ((end - start) / 2.0).ceil().max(1.0) as usize;
+                let bearings =
+                    (0..=steps).map(|step| start + (end - start) * step as f64 / steps as f64);
+                let mut polygon: Vec<(f64, f64)> = bearings
+                    .clone()
+                    .map(|bearing| self.to_canvas(bearing, r1, layout))
+                    .collect();
+                polygon.extend(
+                    bearings
+                        .rev()
+                        .map(|bearing| self.to_canvas(bearing, r0, layout)),
+                );
+                wedges.push((sector, class, polygon));
+            }
+        }
+        wedges
+    }
+
+    /// Finds the segment under a canvas position.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The canvas position.
+    /// - `layout`: The layout returned by `layout`.
+    ///
+    /// # Returns
+    /// The sector and class of the segment, or `None` outside every bar.
+    pub fn wedge_at(&self, x: f64, y: f64, layout: (f64, f64, f64)) -> Option<(usize, usize)> {
+        let (center_x, center_y, radius) = layout;
+        let outer = self.ring_values().last().copied().unwrap_or(1.0);
+        let (dx, dy) = (x - center_x, center_y - y);
+        let value = dx.hypot(dy) / radius * outer;
+        let sector = self.sector_of(dx.atan2(dy).to_degrees());
+
+        let mut inner = 0.0;
+        for (class, frequency) in self.frequencies()[sector].iter().enumerate() {
+            inner += frequency;
+            if value < inner {
+                return Some((sector, class));
+            }
+        }
+        None
+    }
+}
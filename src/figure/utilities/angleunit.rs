@@ -0,0 +1,56 @@
+use std::f64::consts::PI;
+
+/// The unit angles are labelled in, e.g. on the angular ticks of a polar graph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AngleUnit {
+    /// Degrees, labelled like `90°`.
+    Degrees,
+    /// Radians, labelled as fractions of π like `π/2` where possible.
+    Radians,
+}
+
+impl AngleUnit {
+    /// Formats an angle in this unit.
+    ///
+    /// # Parameters
+    /// - `radians`: The angle in radians.
+    ///
+    /// # Returns
+    /// The label, e.g. `45°` or `π/4`. Radians that are not a fraction of π with a
+    /// denominator up to 12 are written with two decimals.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::angleunit::AngleUnit;
+    ///
+    /// assert_eq!(AngleUnit::Degrees.format(std::f64::consts::FRAC_PI_2), "90°");
+    /// assert_eq!(AngleUnit::Radians.format(3.0 * std::f64::consts::FRAC_PI_4), "3π/4");
+    /// ```
+    pub fn format(&self, radians: f64) -> String {
+        match self {
+            AngleUnit::Degrees => {
+                let text = format!("{:.1}", radians.to_degrees());
+                format!("{}°", text.trim_end_matches('0').trim_end_matches('.'))
+            }
+            AngleUnit::Radians => {
+                let turns = radians / PI;
+                for denominator in 1..=12_i64 {
+                    let numerator = (turns * denominator as f64).round();
+                    if (turns * denominator as f64 - numerator).abs() > 1e-6 {
+                        continue;
+                    }
+                    let numerator = numerator as i64;
+                    let sign = if numerator < 0 { "-" } else { "" };
+                    return match (numerator.abs(), denominator) {
+                        (0, _) => "0".to_string(),
+                        (1, 1) => format!("{sign}π"),
+                        (n, 1) => format!("{sign}{n}π"),
+                        (1, d) => format!("{sign}π/{d}"),
+                        (n, d) => format!("{sign}{n}π/{d}"),
+                    };
+                }
+                format!("{radians:.2}")
+            }
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! Geometry of circular arcs and ring segments, shared by pie-like figures.
+//!
+//! Angles are in radians starting at the positive x-axis. Pixel helpers measure them
+//! counterclockwise with the y-axis pointing up, as in `PieChart::draw_slice`; SVG paths
+//! are built in SVG user space, where the y-axis points down and angles therefore run
+//! clockwise on screen.
+
+use std::f64::consts::{PI, TAU};
+
+use ab_glyph::{Font, FontRef, PxScale, ScaleFont};
+
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, utilities::textmetrics::load_font};
+
+/// Normalizes the angle of an offset from a circle's center into `[0, 2π)`.
+///
+/// # Parameters
+/// - `dx`, `dy`: The offset from the center, in the coordinate system of the angle.
+pub fn angle_of(dx: f64, dy: f64) -> f64 {
+    let angle = dy.atan2(dx);
+    if angle < 0.0 {
+        angle + TAU
+    } else {
+        angle
+    }
+}
+
+/// Builds the SVG path of a ring segment centered at the origin.
+///
+/// Segments without an inner radius are pie wedges. A segment spanning the full circle is
+/// drawn as two halves, as an SVG arc cannot end where it starts.
+///
+/// # Parameters
+/// - `inner`, `outer`: The inner and outer radius.
+/// - `start_angle`, `end_angle`: The angular span, running clockwise on screen.
+///
+/// # Returns
+/// The `d` attribute of the path.
+pub fn ring_segment_path(inner: f64, outer: f64, start_angle: f64, end_angle: f64) -> String {
+    let sweep_angle = end_angle - start_angle;
+    if sweep_angle >= TAU - 1e-9 {
+        let mid_angle = start_angle + sweep_angle / 2.0;
+        return format!(
+            "{} {}",
+            ring_segment_path(inner, outer, start_angle, mid_angle),
+            ring_segment_path(inner, outer, mid_angle, end_angle)
+        );
+    }
+
+    let (x1, y1) = (outer * start_angle.cos(), outer * start_angle.sin());
+    let (x2, y2) = (outer * end_angle.cos(), outer * end_angle.sin());
+    // Segments larger than 180 degrees take the long way around
+    let large_arc_flag = if sweep_angle > PI { 1 } else { 0 };
+
+    if inner > 0.0 {
+        format!(
+            "M {:.2} {:.2} A {:.2} {:.2} 0 {} 1 {:.2} {:.2} L {:.2} {:.2} A {:.2} {:.2} 0 {} 0 {:.2} {:.2} Z",
+            x1, y1, outer, outer, large_arc_flag, x2, y2,
+            inner * end_angle.cos(), inner * end_angle.sin(), inner, inner,
+            large_arc_flag, inner * start_angle.cos(), inner * start_angle.sin()
+        )
+    } else {
+        format!(
+            "M 0 0 L {:.2} {:.2} A {:.2} {:.2} 0 {} 1 {:.2} {:.2} Z",
+            x1, y1, outer, outer, large_arc_flag, x2, y2
+        )
+    }
+}
+
+/// Builds the SVG path of an arc centered at the origin for text to follow, running so
+/// the text reads from left to right: clockwise on screen over the upper half of the
+/// circle and counterclockwise over the lower half.
+///
+/// # Parameters
+/// - `radius`: The radius of the arc.
+/// - `start_angle`, `end_angle`: The angular span, running clockwise on screen.
+///
+/// # Returns
+/// The `d` attribute of the path.
+pub fn text_arc_path(radius: f64, start_angle: f64, end_angle: f64) -> String {
+    let sweep_angle = end_angle - start_angle;
+    let large_arc_flag = if sweep_angle > PI { 1 } else { 0 };
+    // On screen, the lower half has positive sines
+    let (from, to, sweep_flag) = if ((start_angle + end_angle) / 2.0).sin() > 0.0 {
+        (end_angle, start_angle, 0)
+    } else {
+        (start_angle, end_angle, 1)
+    };
+    format!(
+        "M {:.2} {:.2} A {:.2} {:.2} 0 {} {} {:.2} {:.2}",
+        radius * from.cos(),
+        radius * from.sin(),
+        radius,
+        radius,
+        large_arc_flag,
+        sweep_flag,
+        radius * to.cos(),
+        radius * to.sin()
+    )
+}
+
+/// Fills a ring segment on a pixel canvas.
+///
+/// Fills every pixel within the ring whose angle lies in `[start_angle, end_angle)`, with
+/// angles running counterclockwise.
+///
+/// # Parameters
+/// - `canvas`: The canvas to draw on.
+/// - `center`: The center of the circle in pixels.
+/// - `inner`, `outer`: The inner and outer radius in pixels.
+/// - `start_angle`, `end_angle`: The angular span, between 0 and 2π.
+/// - `color`: The fill color.
+pub fn fill_ring_segment(
+    canvas: &mut PixelCanvas,
+    (center_x, center_y): (i32, i32),
+    (inner, outer): (f64, i32),
+    (start_angle, end_angle): (f64, f64),
+    color: [u8; 3],
+) {
+    for y in -outer..=outer {
+        for x in -outer..=outer {
+            // Check if the point is within the ring
+            let distance = (x * x + y * y) as f64;
+            if distance <= (outer * outer) as f64 && distance >= inner * inner {
+                // Check if the angle is within the segment
+                let angle = angle_of(x as f64, y as f64);
+                if angle >= start_angle && angle < end_angle {
+                    canvas.draw_pixel((center_x + x) as u32, (center_y - y) as u32, color);
+                }
+            }
+        }
+    }
+}
+
+/// Draws text along a circular arc, one glyph at a time, each turned to follow the arc.
+///
+/// The text is centered on `angle` with its vertical center on the circle and reads from
+/// left to right: over the upper half of the circle the glyphs stand on the arc, over the
+/// lower half they hang from it.
+///
+/// # Parameters
+/// - `canvas`: The canvas to draw on.
+/// - `center`: The center of the circle in pixels.
+/// - `radius`: The radius of the arc in pixels.
+/// - `angle`: The angle of the center of the text, running counterclockwise.
+/// - `text`: The text to draw.
+/// - `color`: The text color.
+/// - `font_path`: Path to the font file.
+/// - `scale`: The font size.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_text_along_arc(
+    canvas: &mut PixelCanvas,
+    (center_x, center_y): (f64, f64),
+    radius: f64,
+    angle: f64,
+    text: &str,
+    color: [u8; 3],
+    font_path: &str,
+    scale: PxScale,
+) {
+    if radius <= 0.0 {
+        return;
+    }
+    let font = load_font(font_path);
+    let advances: Vec<f64> = text
+        .chars()
+        .map(|glyph| text_arc_advance(&font, glyph, scale))
+        .collect();
+    let total: f64 = advances.iter().sum();
+    // Left to right runs clockwise over the upper half and counterclockwise below it
+    let direction = if angle.sin() >= 0.0 { -1.0 } else { 1.0 };
+
+    let mut offset = -total / 2.0;
+    for (glyph, advance) in text.chars().zip(advances) {
+        let glyph_angle = angle + direction * (offset + advance / 2.0) / radius;
+        offset += advance;
+        if glyph.is_whitespace() {
+            continue;
+        }
+
+        let mut mask = PixelCanvas::new(
+            advance.ceil() as u32 + 2,
+            scale.y.ceil() as u32 + 4,
+            [0, 0, 0],
+            0,
+        );
+        mask.draw_text(1, 0, &glyph.to_string(), [255, 255, 255], &font, scale);
+        let (mask_width, mask_height) = (mask.width as f64, mask.height as f64);
+
+        // Screen position of the glyph center and the glyph's right and down directions
+        let (x, y) = (
+            center_x + radius * glyph_angle.cos(),
+            center_y - radius * glyph_angle.sin(),
+        );
+        let right = (
+            -direction * glyph_angle.sin(),
+            -direction * glyph_angle.cos(),
+        );
+        let down = (-right.1, right.0);
+
+        let reach = mask_width.hypot(mask_height) / 2.0 + 1.0;
+        let (x0, x1) = ((x - reach).max(0.0) as u32, (x + reach).max(0.0) as u32);
+        let (y0, y1) = ((y - reach).max(0.0) as u32, (y + reach).max(0.0) as u32);
+        for py in y0..=y1.min(canvas.height.saturating_sub(1)) {
+            for px in x0..=x1.min(canvas.width.saturating_sub(1)) {
+                let (dx, dy) = (px as f64 - x, py as f64 - y);
+                let u = dx * right.0 + dy * right.1 + mask_width / 2.0;
+                let v = dx * down.0 + dy * down.1 + mask_height / 2.0;
+                if u < 0.0 || v < 0.0 || u >= mask_width || v >= mask_height {
+                    continue;
+                }
+                let coverage = mask.buffer[((v as u32 * mask.width + u as u32) * 3) as usize];
+                if coverage > 0 {
+                    canvas.blend_pixel(px, py, color, coverage as f64 / 255.0);
+                }
+            }
+        }
+    }
+}
+
+/// Measures the length of text when drawn along an arc by `draw_text_along_arc`.
+///
+/// # Parameters
+/// - `text`: The text to measure.
+/// - `font_path`: Path to the font file.
+/// - `scale`: The font size.
+///
+/// # Returns
+/// The length of the text along the arc in pixels.
+pub fn text_arc_length(text: &str, font_path: &str, scale: PxScale) -> f64 {
+    let font = load_font(font_path);
+    text.chars()
+        .map(|glyph| text_arc_advance(&font, glyph, scale))
+        .sum()
+}
+
+/// Returns the horizontal advance of a glyph.
+fn text_arc_advance(font: &FontRef, glyph: char, scale: PxScale) -> f64 {
+    font.as_scaled(scale).h_advance(font.glyph_id(glyph)) as f64
+}
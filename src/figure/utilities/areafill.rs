@@ -0,0 +1,65 @@
+//! Filled bands between two lines, shared by area charts and streamgraphs.
+//!
+//! Lines are polylines in canvas coordinates, ordered by x. The band runs along the top
+//! line and back along the bottom line, so a filled area under a curve is the band between
+//! the curve and a baseline.
+
+use crate::figure::canvas::pixelcanvas::PixelCanvas;
+
+/// Returns the outline of the band between two lines: the top line followed by the bottom
+/// line reversed.
+///
+/// # Parameters
+/// - `top`, `bottom`: The lines bounding the band, each ordered by x.
+pub fn band_outline(top: &[(f64, f64)], bottom: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    top.iter().chain(bottom.iter().rev()).copied().collect()
+}
+
+/// Fills the band between two lines on a pixel canvas, blending it with the canvas.
+///
+/// # Parameters
+/// - `canvas`: The canvas to draw on.
+/// - `top`, `bottom`: The lines bounding the band, each ordered by x.
+/// - `color`: The RGB fill color.
+/// - `alpha`: The opacity of the fill (0.0 to 1.0).
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::areafill::fill_band;
+///
+/// // The area under a curve down to the x-axis at y = 400
+/// let curve = vec![(50.0, 300.0), (150.0, 200.0), (250.0, 250.0)];
+/// fill_band(&mut canvas, &curve, &[(50.0, 400.0), (250.0, 400.0)], [70, 130, 180], 0.5);
+/// ```
+pub fn fill_band(
+    canvas: &mut PixelCanvas,
+    top: &[(f64, f64)],
+    bottom: &[(f64, f64)],
+    color: [u8; 3],
+    alpha: f64,
+) {
+    if top.is_empty() {
+        return;
+    }
+    canvas.blend_polygon(&[band_outline(top, bottom)], color, alpha);
+}
+
+/// Returns the SVG path data of the band between two lines, closed back to its start.
+///
+/// # Parameters
+/// - `top`, `bottom`: The lines bounding the band, each ordered by x.
+///
+/// # Returns
+/// The path data, e.g. `"M 50.00,300.00 L 150.00,200.00 L 150.00,400.00 L 50.00,400.00 Z"`,
+/// or an empty string without points.
+pub fn band_path(top: &[(f64, f64)], bottom: &[(f64, f64)]) -> String {
+    let outline = band_outline(top, bottom);
+    if outline.is_empty() {
+        return String::new();
+    }
+    let vertices: Vec<String> = outline
+        .iter()
+        .map(|(x, y)| format!("{x:.2},{y:.2}"))
+        .collect();
+    format!("M {} Z", vertices.join(" L "))
+}
@@ -0,0 +1,48 @@
+//! Geometry of arrows, shared by the pixel and SVG canvases.
+
+/// Angle in radians between the shaft of an arrow and each side of its head.
+const HEAD_HALF_ANGLE: f64 = 0.45;
+
+/// The end of the shaft and the triangle of the head.
+type ShaftAndHead = ((f64, f64), [(f64, f64); 3]);
+
+/// Splits an arrow into its shaft and its head.
+///
+/// Heads are never longer than half the arrow, so short arrows keep a visible shaft.
+///
+/// # Parameters
+/// - `start`: The tail of the arrow.
+/// - `end`: The tip of the arrow.
+/// - `head_length`: The length of the head along the shaft.
+///
+/// # Returns
+/// The point where the shaft meets the head, and the head as a triangle starting at the
+/// tip, or `None` if the arrow has no length.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::arrowgeometry::arrow_head;
+///
+/// let (shaft_end, head) = arrow_head((0.0, 0.0), (20.0, 0.0), 6.0).unwrap();
+/// assert_eq!(shaft_end, (14.0, 0.0));
+/// assert_eq!(head[0], (20.0, 0.0));
+/// ```
+pub fn arrow_head(start: (f64, f64), end: (f64, f64), head_length: f64) -> Option<ShaftAndHead> {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length = dx.hypot(dy);
+    if length == 0.0 || !length.is_finite() {
+        return None;
+    }
+    let head_length = head_length.min(length / 2.0);
+    let (ux, uy) = (dx / length, dy / length);
+    let base = (end.0 - ux * head_length, end.1 - uy * head_length);
+    let half_width = head_length * HEAD_HALF_ANGLE.tan();
+    Some((
+        base,
+        [
+            end,
+            (base.0 - uy * half_width, base.1 + ux * half_width),
+            (base.0 + uy * half_width, base.1 - ux * half_width),
+        ],
+    ))
+}
@@ -0,0 +1,76 @@
+use super::{scaling::symmetric_range, statistics::quantile};
+
+/// How an axis range follows the data at every render, e.g. of a live chart fed with new
+/// points.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Autoscale {
+    /// Grows to include all data, symmetric around zero, and never shrinks.
+    #[default]
+    Symmetric,
+    /// Fits the range of the current data exactly, rescaling whenever it changes.
+    Fit,
+    /// Grows to include new data but never shrinks, so the scale stays put once the data
+    /// has settled.
+    Expanding,
+    /// Shows a range of fixed width ending at the largest value, e.g. the last minute of a
+    /// live x-axis. On the x-axis of a `CartesianGraph`, the y-axis then fits only the points
+    /// inside the window.
+    Window(f64),
+    /// Fits the range between two percentiles of the data, so rare spikes do not squash the
+    /// rest of the plot. The bounds are percentages, e.g. `1.0` and `99.0`.
+    Percentile {
+        /// The percentile at the lower end of the range.
+        lower: f64,
+        /// The percentile at the upper end of the range.
+        upper: f64,
+    },
+}
+
+impl Autoscale {
+    /// Computes the range of an axis.
+    ///
+    /// # Parameters
+    /// - `values`: The data values on the axis. Non-finite values are ignored.
+    /// - `current`: The current range, kept or grown by `Symmetric` and `Expanding`.
+    ///
+    /// # Returns
+    /// The new `(min, max)` range, or `None` without finite values. A range of equal bounds
+    /// is widened by 1 on both sides.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::autoscale::Autoscale;
+    ///
+    /// let range = Autoscale::Window(60.0).range([0.0, 30.0, 95.0], (0.0, 95.0));
+    /// assert_eq!(range, Some((35.0, 95.0)));
+    /// ```
+    pub fn range(
+        &self,
+        values: impl IntoIterator<Item = f64>,
+        current: (f64, f64),
+    ) -> Option<(f64, f64)> {
+        let mut values: Vec<f64> = values.into_iter().filter(|v| v.is_finite()).collect();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(f64::total_cmp);
+        let (min, max) = (values[0], values[values.len() - 1]);
+
+        let (min, max) = match *self {
+            Autoscale::Symmetric => symmetric_range(min.min(current.0), max.max(current.1)),
+            Autoscale::Fit => (min, max),
+            Autoscale::Expanding => (min.min(current.0), max.max(current.1)),
+            Autoscale::Window(width) if width.is_finite() && width > 0.0 => (max - width, max),
+            Autoscale::Window(_) => (min, max),
+            Autoscale::Percentile { lower, upper } => (
+                quantile(&values, lower.min(upper) / 100.0)?,
+                quantile(&values, lower.max(upper) / 100.0)?,
+            ),
+        };
+        if min < max {
+            Some((min, max))
+        } else {
+            Some((min - 1.0, max + 1.0))
+        }
+    }
+}
@@ -1,4 +1,5 @@
 /// Represents the type of axis in a graph or chart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AxisType {
     /// The horizontal axis, typically representing independent variables or categories.
     AxisX,
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+/// Computes the color of a bar from its value.
+pub type BarColorFn = Arc<dyn Fn(f64) -> [u8; 3] + Send + Sync>;
+
+/// A rule coloring bars by their value instead of by dataset, e.g. red below a target and
+/// green above it.
+///
+/// Rules are evaluated at render time, so the colors follow the data when it changes.
+#[derive(Clone)]
+pub enum BarColorRule {
+    /// Colors by threshold: bars below the first threshold use `below_color`, and bars at or
+    /// above a threshold use the color of the highest threshold they reach.
+    Thresholds {
+        /// Color of bars below every threshold.
+        below_color: [u8; 3],
+        /// Legend label of bars below every threshold.
+        below_label: String,
+        /// `(threshold, color, label)` triples, sorted by ascending threshold.
+        thresholds: Vec<(f64, [u8; 3], String)>,
+    },
+    /// Colors computed by a closure.
+    Custom {
+        /// Maps a bar's value to its color.
+        color: BarColorFn,
+        /// `(label, color)` legend entries describing the closure, if any.
+        legend: Vec<(String, [u8; 3])>,
+    },
+}
+
+impl BarColorRule {
+    /// Creates a rule splitting bars at a single target value.
+    ///
+    /// # Parameters
+    /// - `target`: Bars with a value of at least `target` are drawn in `above_color`.
+    /// - `below_color`: The RGB color of bars below the target.
+    /// - `above_color`: The RGB color of bars at or above the target.
+    ///
+    /// # Returns
+    /// A `BarColorRule::Thresholds` labelled `"< target"` and `"≥ target"`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::barcolorrule::BarColorRule;
+    ///
+    /// let rule = BarColorRule::target(100.0, [200, 0, 0], [0, 150, 0])
+    ///     .with_threshold(150.0, [0, 90, 200], "Stretch goal");
+    /// bar_chart.set_color_rule(rule, true);
+    /// ```
+    pub fn target(target: f64, below_color: [u8; 3], above_color: [u8; 3]) -> Self {
+        BarColorRule::Thresholds {
+            below_color,
+            below_label: format!("< {target}"),
+            thresholds: vec![(target, above_color, format!("≥ {target}"))],
+        }
+    }
+
+    /// Creates a rule computing the color of every bar with a closure.
+    ///
+    /// # Parameters
+    /// - `color`: Maps a bar's value to its RGB color.
+    /// - `legend`: `(label, color)` entries shown in the rule legend; may be empty.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::barcolorrule::BarColorRule;
+    ///
+    /// let rule = BarColorRule::custom(
+    ///     |value| if value % 2.0 == 0.0 { [0, 0, 200] } else { [200, 200, 0] },
+    ///     &[("Even", [0, 0, 200]), ("Odd", [200, 200, 0])],
+    /// );
+    /// ```
+    pub fn custom<F>(color: F, legend: &[(&str, [u8; 3])]) -> Self
+    where
+        F: Fn(f64) -> [u8; 3] + Send + Sync + 'static,
+    {
+        BarColorRule::Custom {
+            color: Arc::new(color),
+            legend: legend
+                .iter()
+                .map(|(label, color)| (label.to_string(), *color))
+                .collect(),
+        }
+    }
+
+    /// Adds a threshold to a threshold rule, keeping the thresholds sorted. Custom rules are
+    /// returned unchanged.
+    ///
+    /// # Parameters
+    /// - `threshold`: Bars with a value of at least `threshold` use `color`, unless they
+    ///   reach a higher threshold.
+    /// - `color`: The RGB color of these bars.
+    /// - `label`: The legend label of these bars.
+    pub fn with_threshold(mut self, threshold: f64, color: [u8; 3], label: &str) -> Self {
+        if let BarColorRule::Thresholds { thresholds, .. } = &mut self {
+            thresholds.push((threshold, color, label.to_string()));
+            thresholds.sort_by(|a, b| a.0.total_cmp(&b.0));
+        }
+        self
+    }
+
+    /// Returns the color of a bar.
+    ///
+    /// # Parameters
+    /// - `value`: The value of the bar.
+    pub fn color(&self, value: f64) -> [u8; 3] {
+        match self {
+            BarColorRule::Thresholds {
+                below_color,
+                thresholds,
+                ..
+            } => thresholds
+                .iter()
+                .rev()
+                .find(|(threshold, _, _)| value >= *threshold)
+                .map_or(*below_color, |&(_, color, _)| color),
+            BarColorRule::Custom { color, .. } => color(value),
+        }
+    }
+
+    /// Returns the entries of the rule legend as `(label, color)` pairs, from the lowest to
+    /// the highest values for threshold rules.
+    pub fn legend_entries(&self) -> Vec<(String, [u8; 3])> {
+        match self {
+            BarColorRule::Thresholds {
+                below_color,
+                below_label,
+                thresholds,
+            } => std::iter::once((below_label.clone(), *below_color))
+                .chain(
+                    thresholds
+                        .iter()
+                        .map(|(_, color, label)| (label.clone(), *color)),
+                )
+                .collect(),
+            BarColorRule::Custom { legend, .. } => legend.clone(),
+        }
+    }
+}
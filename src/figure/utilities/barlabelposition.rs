@@ -0,0 +1,8 @@
+/// Controls where bar value labels are placed relative to their bar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BarLabelPosition {
+    /// Inside the bar, next to its tip. Labels that do not fit are placed outside.
+    Inside,
+    /// Outside the bar, just beyond its tip.
+    Outside,
+}
@@ -0,0 +1,11 @@
+/// Controls how the bars of several datasets share a category in a bar chart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BarStacking {
+    /// One bar per dataset, side by side.
+    Grouped,
+    /// One bar per category, with the datasets stacked on top of each other. Positive and
+    /// negative values are stacked separately, away from zero.
+    Stacked,
+    /// Like `Stacked`, with every category scaled so its segments total 100 (percent).
+    Normalized,
+}
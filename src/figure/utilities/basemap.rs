@@ -0,0 +1,65 @@
+use image::RgbImage;
+
+/// A raster background image for geographic figures, such as a shaded relief or a street map.
+///
+/// The image must use the equirectangular (plate carrée) projection, where pixel columns are
+/// evenly spaced in longitude and rows evenly spaced in latitude; it is reprojected to the
+/// figure's projection when drawn.
+#[derive(Clone)]
+pub struct Basemap {
+    /// The image pixels.
+    pub image: RgbImage,
+    /// The geographic extent of the image as `(lon_min, lon_max, lat_min, lat_max)` in degrees.
+    pub bounds: (f64, f64, f64, f64),
+}
+
+impl Basemap {
+    /// Creates a basemap from an image and its geographic extent.
+    ///
+    /// # Parameters
+    /// - `image`: The equirectangular image.
+    /// - `bounds`: The extent covered by the image as `(lon_min, lon_max, lat_min, lat_max)`.
+    pub fn new(image: RgbImage, bounds: (f64, f64, f64, f64)) -> Self {
+        Self { image, bounds }
+    }
+
+    /// Loads a basemap from an image file.
+    ///
+    /// # Parameters
+    /// - `path`: Path to the image file (PNG, JPEG, ...).
+    /// - `bounds`: The extent covered by the image as `(lon_min, lon_max, lat_min, lat_max)`.
+    ///
+    /// # Returns
+    /// The basemap, or a message if the image could not be read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::basemap::Basemap;
+    ///
+    /// let world = Basemap::open("world.png", (-180.0, 180.0, -90.0, 90.0)).unwrap();
+    /// ```
+    pub fn open(path: &str, bounds: (f64, f64, f64, f64)) -> Result<Self, String> {
+        let image = image::open(path).map_err(|e| format!("Failed to load basemap {path}: {e}"))?;
+        Ok(Self::new(image.to_rgb8(), bounds))
+    }
+
+    /// Samples the color of the image at a geographic coordinate.
+    ///
+    /// # Parameters
+    /// - `longitude`, `latitude`: The coordinate in degrees.
+    ///
+    /// # Returns
+    /// The color of the nearest pixel, or `None` outside the image.
+    pub fn sample(&self, longitude: f64, latitude: f64) -> Option<[u8; 3]> {
+        let (lon_min, lon_max, lat_min, lat_max) = self.bounds;
+        let u = (longitude - lon_min) / (lon_max - lon_min);
+        let v = (lat_max - latitude) / (lat_max - lat_min);
+        if !(0.0..1.0).contains(&u) || !(0.0..1.0).contains(&v) {
+            return None;
+        }
+
+        let x = (u * self.image.width() as f64) as u32;
+        let y = (v * self.image.height() as f64) as u32;
+        Some(self.image.get_pixel(x, y).0)
+    }
+}
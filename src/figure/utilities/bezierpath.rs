@@ -0,0 +1,238 @@
+use std::f64::consts::FRAC_PI_2;
+
+/// A segment of a `BezierPath`, ending at its last point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathSegment {
+    /// A straight line to the end point.
+    Line((f64, f64)),
+    /// A cubic Bézier curve through two control points to the end point.
+    Cubic((f64, f64), (f64, f64), (f64, f64)),
+}
+
+/// A closed shape bounded by straight lines and cubic Bézier curves, e.g. the ribbons of a
+/// chord diagram.
+///
+/// The same path is written as SVG path data by `svg_path` and flattened into a polygon
+/// for pixel canvases by `outline`, so both canvases draw the same shape.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BezierPath {
+    /// The first point of the path.
+    pub start: (f64, f64),
+    /// The segments following the start point. The path is closed implicitly.
+    pub segments: Vec<PathSegment>,
+}
+
+impl BezierPath {
+    /// Creates a path starting at a point, without segments.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::bezierpath::BezierPath;
+    ///
+    /// let mut path = BezierPath::new((10.0, 10.0));
+    /// path.line_to((50.0, 10.0));
+    /// path.cubic_to((50.0, 40.0), (10.0, 40.0), (10.0, 70.0));
+    /// canvas.blend_bezier_path(&path, [31, 119, 180], 0.5);
+    /// ```
+    pub fn new(start: (f64, f64)) -> Self {
+        Self {
+            start,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Creates the outline of a ring segment: the outer arc, then the inner arc back.
+    ///
+    /// Angles are in radians in canvas coordinates, running clockwise on screen as in
+    /// `arc_to`.
+    ///
+    /// # Parameters
+    /// - `center`: The center of the ring.
+    /// - `inner`, `outer`: The inner and outer radius.
+    /// - `start_angle`, `end_angle`: The angular span.
+    pub fn ring_segment(
+        center: (f64, f64),
+        (inner, outer): (f64, f64),
+        (start_angle, end_angle): (f64, f64),
+    ) -> Self {
+        let point = |r: f64, angle: f64| (center.0 + r * angle.cos(), center.1 + r * angle.sin());
+        let mut path = Self::new(point(outer, start_angle));
+        path.arc_to(center, outer, start_angle, end_angle);
+        path.line_to(point(inner, end_angle));
+        path.arc_to(center, inner, end_angle, start_angle);
+        path
+    }
+
+    /// Creates the outline of an axis-aligned rectangle.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the top-left corner.
+    /// - `width`, `height`: Dimensions of the rectangle.
+    pub fn rectangle(x: f64, y: f64, width: f64, height: f64) -> Self {
+        let mut path = Self::new((x, y));
+        path.line_to((x + width, y));
+        path.line_to((x + width, y + height));
+        path.line_to((x, y + height));
+        path
+    }
+
+    /// Returns the current end point of the path.
+    pub fn end(&self) -> (f64, f64) {
+        match self.segments.last() {
+            Some(PathSegment::Line(end)) | Some(PathSegment::Cubic(_, _, end)) => *end,
+            None => self.start,
+        }
+    }
+
+    /// Appends a straight line to a point.
+    pub fn line_to(&mut self, end: (f64, f64)) {
+        self.segments.push(PathSegment::Line(end));
+    }
+
+    /// Appends a cubic Bézier curve.
+    ///
+    /// # Parameters
+    /// - `control1`, `control2`: The control points.
+    /// - `end`: The end point of the curve.
+    pub fn cubic_to(&mut self, control1: (f64, f64), control2: (f64, f64), end: (f64, f64)) {
+        self.segments
+            .push(PathSegment::Cubic(control1, control2, end));
+    }
+
+    /// Appends a quadratic Bézier curve, stored as the equivalent cubic curve.
+    ///
+    /// # Parameters
+    /// - `control`: The control point.
+    /// - `end`: The end point of the curve.
+    pub fn quadratic_to(&mut self, control: (f64, f64), end: (f64, f64)) {
+        let (x0, y0) = self.end();
+        let (cx, cy) = control;
+        self.cubic_to(
+            (x0 + 2.0 / 3.0 * (cx - x0), y0 + 2.0 / 3.0 * (cy - y0)),
+            (
+                end.0 + 2.0 / 3.0 * (cx - end.0),
+                end.1 + 2.0 / 3.0 * (cy - end.1),
+            ),
+            end,
+        );
+    }
+
+    /// Appends a circular arc, approximated by one cubic curve per quarter circle or less.
+    ///
+    /// The arc starts at `start_angle`; the path should already end there. Angles are in
+    /// radians in canvas coordinates, where the y-axis points down, so increasing angles
+    /// run clockwise on screen.
+    ///
+    /// # Parameters
+    /// - `center`: The center of the circle.
+    /// - `radius`: The radius of the circle.
+    /// - `start_angle`, `end_angle`: The angular span; the arc runs counterclockwise on
+    ///   screen when `end_angle` is smaller.
+    pub fn arc_to(&mut self, center: (f64, f64), radius: f64, start_angle: f64, end_angle: f64) {
+        let sweep = end_angle - start_angle;
+        let pieces = (sweep.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+        let step = sweep / pieces as f64;
+        // Distance of the control points along the tangents
+        let k = 4.0 / 3.0 * (step / 4.0).tan() * radius;
+        let point = |angle: f64| {
+            (
+                center.0 + radius * angle.cos(),
+                center.1 + radius * angle.sin(),
+            )
+        };
+        for piece in 0..pieces {
+            let a0 = start_angle + step * piece as f64;
+            let a1 = a0 + step;
+            let (x0, y0) = point(a0);
+            let (x1, y1) = point(a1);
+            self.cubic_to(
+                (x0 - k * a0.sin(), y0 + k * a0.cos()),
+                (x1 + k * a1.sin(), y1 - k * a1.cos()),
+                (x1, y1),
+            );
+        }
+    }
+
+    /// Builds the SVG path data of the closed path.
+    pub fn svg_path(&self) -> String {
+        let mut path = format!("M {:.2} {:.2}", self.start.0, self.start.1);
+        for segment in &self.segments {
+            match segment {
+                PathSegment::Line((x, y)) => path.push_str(&format!(" L {x:.2} {y:.2}")),
+                PathSegment::Cubic((x1, y1), (x2, y2), (x, y)) => {
+                    path.push_str(&format!(" C {x1:.2} {y1:.2} {x2:.2} {y2:.2} {x:.2} {y:.2}"))
+                }
+            }
+        }
+        path.push_str(" Z");
+        path
+    }
+
+    /// Approximates the path by a polygon. Curves are split into pieces about 2 pixels
+    /// long, measured along their control polygon.
+    ///
+    /// # Returns
+    /// The polygon vertices, starting at the start point.
+    pub fn outline(&self) -> Vec<(f64, f64)> {
+        let mut points = vec![self.start];
+        let mut current = self.start;
+        for segment in &self.segments {
+            match *segment {
+                PathSegment::Line(end) => {
+                    points.push(end);
+                    current = end;
+                }
+                PathSegment::Cubic(c1, c2, end) => {
+                    let length = distance(current, c1) + distance(c1, c2) + distance(c2, end);
+                    let pieces = (length / 2.0).ceil().max(1.0) as usize;
+                    for piece in 1..=pieces {
+                        points.push(cubic_point(
+                            current,
+                            c1,
+                            c2,
+                            end,
+                            piece as f64 / pieces as f64,
+                        ));
+                    }
+                    current = end;
+                }
+            }
+        }
+        points
+    }
+
+    /// Checks whether a point lies inside the path, using the even-odd rule on its
+    /// outline.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        let outline = self.outline();
+        let mut inside = false;
+        for (index, &(x1, y1)) in outline.iter().enumerate() {
+            let (x2, y2) = outline[(index + 1) % outline.len()];
+            if (y1 <= y) != (y2 <= y) && x < x1 + (y - y1) / (y2 - y1) * (x2 - x1) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+}
+
+/// Returns the distance between two points.
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (b.0 - a.0).hypot(b.1 - a.1)
+}
+
+/// Evaluates a cubic Bézier curve at `t` in `[0, 1]`.
+fn cubic_point(
+    p0: (f64, f64),
+    p1: (f64, f64),
+    p2: (f64, f64),
+    p3: (f64, f64),
+    t: f64,
+) -> (f64, f64) {
+    let u = 1.0 - t;
+    let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+    (
+        a * p0.0 + b * p1.0 + c * p2.0 + d * p3.0,
+        a * p0.1 + b * p1.1 + c * p2.1 + d * p3.1,
+    )
+}
@@ -0,0 +1,308 @@
+//! Agglomerative hierarchical clustering and dendrogram layout.
+//!
+//! Clustering the rows or columns of a matrix gives an order that places similar rows next
+//! to each other, and a `Dendrogram` that can be drawn along the margin of the matrix.
+
+use super::linkage::Linkage;
+
+/// One merge step of a hierarchical clustering.
+///
+/// Nodes `0..n` are the leaves (the clustered items); the node created by merge `i` has
+/// id `n + i`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Merge {
+    /// Id of the first merged node.
+    pub left: usize,
+    /// Id of the second merged node.
+    pub right: usize,
+    /// Distance between the two merged clusters; the height of the merge in a dendrogram.
+    pub distance: f64,
+    /// Number of leaves in the merged cluster.
+    pub size: usize,
+}
+
+/// The result of a hierarchical clustering: the sequence of merges that joins all leaves.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dendrogram {
+    /// Number of clustered items.
+    pub leaves: usize,
+    /// Merges in the order they were performed, with non-decreasing distances for
+    /// single, complete and average linkage.
+    pub merges: Vec<Merge>,
+}
+
+impl Dendrogram {
+    /// Builds a dendrogram from a linkage computed elsewhere, e.g. by another clustering
+    /// library.
+    ///
+    /// # Parameters
+    /// - `leaves`: The number of clustered items.
+    /// - `merges`: The merges as `(left, right, distance)`, in the order they were performed.
+    ///   Nodes `0..leaves` are the items; merge `i` creates node `leaves + i`.
+    ///
+    /// # Returns
+    /// The `Dendrogram` with the cluster size of every merge.
+    ///
+    /// # Errors
+    /// Returns an error unless the merges join all leaves into one tree: there must be
+    /// `leaves - 1` merges, each joining two existing nodes that were not merged before, at
+    /// a finite, non-negative distance.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::clustering::Dendrogram;
+    ///
+    /// let tree = Dendrogram::from_linkage(3, &[(0, 2, 0.5), (1, 3, 2.0)])?;
+    /// assert_eq!(tree.leaf_order(), vec![1, 0, 2]);
+    /// ```
+    pub fn from_linkage(leaves: usize, merges: &[(usize, usize, f64)]) -> Result<Self, String> {
+        if merges.len() + 1 != leaves.max(1) {
+            return Err(format!(
+                "{leaves} leaves need {} merges, got {}",
+                leaves.saturating_sub(1),
+                merges.len()
+            ));
+        }
+
+        let mut sizes = vec![1; leaves];
+        let mut merged = vec![false; leaves + merges.len()];
+        let mut tree = Vec::with_capacity(merges.len());
+        for (index, &(left, right, distance)) in merges.iter().enumerate() {
+            if left == right {
+                return Err(format!("Merge {index} merges node {left} with itself"));
+            }
+            let created = leaves + index;
+            for node in [left, right] {
+                if node >= created {
+                    return Err(format!("Merge {index} refers to unknown node {node}"));
+                }
+                if merged[node] {
+                    return Err(format!("Merge {index} merges node {node} a second time"));
+                }
+                merged[node] = true;
+            }
+            if !(distance.is_finite() && distance >= 0.0) {
+                return Err(format!("Merge {index} has invalid distance {distance}"));
+            }
+            let size = sizes[left] + sizes[right];
+            sizes.push(size);
+            tree.push(Merge {
+                left,
+                right,
+                distance,
+                size,
+            });
+        }
+
+        Ok(Dendrogram {
+            leaves,
+            merges: tree,
+        })
+    }
+
+    /// Computes the order in which the leaves appear along the dendrogram.
+    ///
+    /// # Returns
+    /// The leaf ids from left to right. Reordering the clustered rows by this order
+    /// places similar rows next to each other.
+    pub fn leaf_order(&self) -> Vec<usize> {
+        let Some(root) = self.merges.len().checked_sub(1) else {
+            return (0..self.leaves).collect();
+        };
+
+        let mut order = Vec::with_capacity(self.leaves);
+        let mut stack = vec![self.leaves + root];
+        while let Some(node) = stack.pop() {
+            if node < self.leaves {
+                order.push(node);
+            } else {
+                let merge = &self.merges[node - self.leaves];
+                stack.push(merge.right);
+                stack.push(merge.left);
+            }
+        }
+        order
+    }
+
+    /// Returns the largest merge distance, i.e. the height of the dendrogram.
+    pub fn height(&self) -> f64 {
+        self.merges
+            .iter()
+            .map(|merge| merge.distance)
+            .fold(0.0, f64::max)
+    }
+
+    /// Computes the positions of all nodes of the dendrogram, in the units of `segments`.
+    ///
+    /// # Returns
+    /// One `(position, distance)` pair per node id: the leaves at distance `0.0`, and each
+    /// merged node centered above its two children at its merge distance.
+    pub fn node_positions(&self) -> Vec<(f64, f64)> {
+        let mut nodes = vec![(0.0, 0.0); self.leaves + self.merges.len()];
+        for (index, leaf) in self.leaf_order().into_iter().enumerate() {
+            nodes[leaf] = (index as f64 + 0.5, 0.0);
+        }
+        for (index, merge) in self.merges.iter().enumerate() {
+            let center = (nodes[merge.left].0 + nodes[merge.right].0) / 2.0;
+            nodes[self.leaves + index] = (center, merge.distance);
+        }
+        nodes
+    }
+
+    /// Computes the line segments of the dendrogram.
+    ///
+    /// Segments are given in dendrogram units: the first coordinate is the leaf position,
+    /// where the leaf at index `i` of `leaf_order` is centered on `i + 0.5`, and the second
+    /// is the merge distance, with leaves at `0.0`. Callers map these onto the margin of
+    /// a figure with the usual scaling helpers, flipping or rotating as needed.
+    ///
+    /// # Returns
+    /// A list of `((position, distance), (position, distance))` segments, three per merge.
+    pub fn segments(&self) -> Vec<((f64, f64), (f64, f64))> {
+        let nodes = self.node_positions();
+        let mut segments = Vec::with_capacity(self.merges.len() * 3);
+        for merge in &self.merges {
+            let (left_x, left_y) = nodes[merge.left];
+            let (right_x, right_y) = nodes[merge.right];
+            let y = merge.distance;
+
+            segments.push(((left_x, left_y), (left_x, y)));
+            segments.push(((right_x, right_y), (right_x, y)));
+            segments.push(((left_x, y), (right_x, y)));
+        }
+        segments
+    }
+}
+
+/// Computes the Euclidean distance between two vectors.
+///
+/// # Parameters
+/// - `a`, `b`: The vectors to compare. Extra elements of the longer vector are ignored.
+pub fn euclidean_distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Clusters the rows of a matrix by agglomerative hierarchical clustering.
+///
+/// Rows are compared by Euclidean distance. The naive algorithm runs in `O(n³)` time for
+/// `n` rows, which is fine for the few hundred rows a figure can show.
+///
+/// # Parameters
+/// - `rows`: The items to cluster, one vector per item.
+/// - `linkage`: The rule used to measure the distance between clusters.
+///
+/// # Returns
+/// The `Dendrogram` describing the merges.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::{clustering::cluster, linkage::Linkage};
+///
+/// let rows = vec![vec![0.0, 1.0], vec![5.0, 5.0], vec![0.1, 1.1]];
+/// let dendrogram = cluster(&rows, Linkage::Average);
+/// assert_eq!(dendrogram.leaf_order(), vec![0, 2, 1]);
+/// ```
+pub fn cluster(rows: &[Vec<f64>], linkage: Linkage) -> Dendrogram {
+    let n = rows.len();
+    let mut distances: Vec<Vec<f64>> = rows
+        .iter()
+        .map(|a| rows.iter().map(|b| euclidean_distance(a, b)).collect())
+        .collect();
+
+    // Active clusters as (node id, row of `distances` holding its distances, size)
+    let mut active: Vec<(usize, usize, usize)> = (0..n).map(|i| (i, i, 1)).collect();
+    let mut merges = Vec::with_capacity(n.saturating_sub(1));
+
+    while active.len() > 1 {
+        let mut closest = (0, 1, f64::INFINITY);
+        for a in 0..active.len() {
+            for b in a + 1..active.len() {
+                let distance = distances[active[a].1][active[b].1];
+                if distance < closest.2 {
+                    closest = (a, b, distance);
+                }
+            }
+        }
+
+        let (a, b, distance) = closest;
+        let (left, slot, left_size) = active[a];
+        let (right, other_slot, right_size) = active[b];
+        let size = left_size + right_size;
+
+        // Distances from the merged cluster, stored in the first cluster's slot
+        for &(_, k, _) in &active {
+            if k == slot || k == other_slot {
+                continue;
+            }
+            let (d_left, d_right) = (distances[slot][k], distances[other_slot][k]);
+            let merged = match linkage {
+                Linkage::Single => d_left.min(d_right),
+                Linkage::Complete => d_left.max(d_right),
+                Linkage::Average => {
+                    (d_left * left_size as f64 + d_right * right_size as f64) / size as f64
+                }
+            };
+            distances[slot][k] = merged;
+            distances[k][slot] = merged;
+        }
+
+        merges.push(Merge {
+            left,
+            right,
+            distance,
+            size,
+        });
+        active[a] = (n + merges.len() - 1, slot, size);
+        active.remove(b);
+    }
+
+    Dendrogram { leaves: n, merges }
+}
+
+/// Reorders the rows and columns of a matrix.
+///
+/// # Parameters
+/// - `matrix`: The matrix, as a list of rows.
+/// - `row_order`: The original row index of each output row.
+/// - `column_order`: The original column index of each output column.
+///
+/// # Returns
+/// The reordered matrix.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::clustering::{cluster, reorder_matrix, transpose};
+/// use dataviz::figure::utilities::linkage::Linkage;
+///
+/// let rows = cluster(&matrix, Linkage::Average).leaf_order();
+/// let columns = cluster(&transpose(&matrix), Linkage::Average).leaf_order();
+/// let clustered = reorder_matrix(&matrix, &rows, &columns);
+/// ```
+pub fn reorder_matrix(
+    matrix: &[Vec<f64>],
+    row_order: &[usize],
+    column_order: &[usize],
+) -> Vec<Vec<f64>> {
+    row_order
+        .iter()
+        .map(|&row| column_order.iter().map(|&col| matrix[row][col]).collect())
+        .collect()
+}
+
+/// Transposes a matrix, so that its columns can be clustered like rows.
+///
+/// # Parameters
+/// - `matrix`: The matrix, as a list of rows of equal length.
+///
+/// # Returns
+/// The transposed matrix.
+pub fn transpose(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let columns = matrix.first().map_or(0, Vec::len);
+    (0..columns)
+        .map(|col| matrix.iter().map(|row| row[col]).collect())
+        .collect()
+}
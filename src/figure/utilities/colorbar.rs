@@ -0,0 +1,38 @@
+//! Layout of the color bar shown right of colormapped figures.
+
+/// Width in pixels kept right of the plot area for the color bar and its labels.
+pub const COLOR_BAR_SPACE: f64 = 70.0;
+
+/// Width of the color bar in pixels.
+pub const COLOR_BAR_WIDTH: f64 = 15.0;
+
+/// Gap in pixels between the plot area and the color bar.
+pub const COLOR_BAR_GAP: f64 = 10.0;
+
+/// Computes the plot area of a figure inside the canvas margins, leaving extra room above
+/// and right of it, e.g. `COLOR_BAR_SPACE` for a color bar. The area is at least one pixel
+/// wide and high.
+///
+/// # Parameters
+/// - `width`, `height`: The canvas size in pixels.
+/// - `margin`: The canvas margin in pixels.
+/// - `top_space`: Room kept between the top margin and the plot area.
+/// - `right_space`: Room kept between the plot area and the right margin.
+///
+/// # Returns
+/// A tuple `(left, top, right, bottom)` in canvas coordinates.
+pub fn plot_area(
+    width: f64,
+    height: f64,
+    margin: f64,
+    top_space: f64,
+    right_space: f64,
+) -> (f64, f64, f64, f64) {
+    let top = margin + top_space;
+    (
+        margin,
+        top,
+        (width - margin - right_space).max(margin + 1.0),
+        (height - margin).max(top + 1.0),
+    )
+}
@@ -0,0 +1,150 @@
+//! Mapping of numeric values to colors.
+
+/// Blue to white to red, for values diverging around a center.
+pub const DIVERGING_BLUE_RED: [[u8; 3]; 3] = [[33, 102, 172], [247, 247, 247], [178, 24, 43]];
+
+/// Light yellow to dark blue, for values on a single increasing scale.
+pub const SEQUENTIAL_YELLOW_BLUE: [[u8; 3]; 5] = [
+    [255, 255, 204],
+    [161, 218, 180],
+    [65, 182, 196],
+    [44, 127, 184],
+    [37, 52, 148],
+];
+
+/// Black to purple to light yellow (after matplotlib's magma), for magnitudes such as
+/// spectrogram levels.
+pub const SEQUENTIAL_MAGMA: [[u8; 3]; 5] = [
+    [0, 0, 4],
+    [81, 18, 124],
+    [183, 55, 121],
+    [252, 137, 97],
+    [252, 253, 191],
+];
+
+/// Color used for missing (`NaN`) values.
+pub const MISSING_COLOR: [u8; 3] = [200, 200, 200];
+
+/// Samples a color from evenly spaced color stops, interpolating linearly between them.
+///
+/// # Parameters
+/// - `stops`: The color stops, from `t = 0.0` to `t = 1.0`.
+/// - `t`: The position to sample, clamped to `[0, 1]`.
+///
+/// # Returns
+/// The interpolated color, or `MISSING_COLOR` if `t` is `NaN` or `stops` is empty.
+pub fn interpolate(stops: &[[u8; 3]], t: f64) -> [u8; 3] {
+    if t.is_nan() || stops.is_empty() {
+        return MISSING_COLOR;
+    }
+    if stops.len() == 1 {
+        return stops[0];
+    }
+
+    let position = t.clamp(0.0, 1.0) * (stops.len() - 1) as f64;
+    let index = (position.floor() as usize).min(stops.len() - 2);
+    let fraction = position - index as f64;
+    let (from, to) = (stops[index], stops[index + 1]);
+
+    [0, 1, 2].map(|c| (from[c] as f64 + (to[c] as f64 - from[c] as f64) * fraction).round() as u8)
+}
+
+/// Maps a value onto a diverging color scale centered at zero.
+///
+/// # Parameters
+/// - `value`: The value to map.
+/// - `limit`: The magnitude mapped to the ends of the scale; `-limit` is blue, `0` is white
+///   and `limit` is red.
+///
+/// # Returns
+/// The color of `value`, or `MISSING_COLOR` for `NaN`.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::colormap::diverging_color;
+///
+/// let color = diverging_color(-0.4, 1.0); // light blue
+/// ```
+pub fn diverging_color(value: f64, limit: f64) -> [u8; 3] {
+    interpolate(&DIVERGING_BLUE_RED, (value / limit + 1.0) / 2.0)
+}
+
+/// Computes the relative luminance of an sRGB color, as defined by WCAG 2.
+///
+/// # Parameters
+/// - `color`: The color.
+///
+/// # Returns
+/// The luminance, from 0.0 for black to 1.0 for white.
+pub fn relative_luminance(color: [u8; 3]) -> f64 {
+    let [r, g, b] = color.map(|channel| {
+        let c = channel as f64 / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    });
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// Computes the WCAG contrast ratio between two colors.
+///
+/// # Returns
+/// The ratio, from 1.0 for equal luminances to 21.0 for black on white.
+pub fn contrast_ratio(a: [u8; 3], b: [u8; 3]) -> f64 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// Chooses whichever of two text colors contrasts more with a fill.
+///
+/// # Parameters
+/// - `background`: The fill the text is drawn on, e.g. a bar, pie slice or heatmap cell.
+/// - `dark`, `light`: The candidate text colors.
+///
+/// # Returns
+/// The candidate with the higher contrast ratio against `background`, `dark` on ties.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::colormap::contrast_text_color;
+///
+/// // Dark gray text on the light slices of a pie, off-white on the dark ones
+/// let color = contrast_text_color(slice_color, [40, 40, 40], [250, 250, 250]);
+/// ```
+pub fn contrast_text_color(background: [u8; 3], dark: [u8; 3], light: [u8; 3]) -> [u8; 3] {
+    if contrast_ratio(background, light) > contrast_ratio(background, dark) {
+        light
+    } else {
+        dark
+    }
+}
+
+/// Chooses black or white text for legibility on a background color.
+///
+/// # Parameters
+/// - `background`: The background color.
+///
+/// # Returns
+/// White on dark backgrounds, black otherwise.
+pub fn text_color_for(background: [u8; 3]) -> [u8; 3] {
+    contrast_text_color(background, [0, 0, 0], [255, 255, 255])
+}
+
+/// Lightens a color by mixing it with white.
+///
+/// # Parameters
+/// - `color`: The color to lighten.
+/// - `amount`: The share of white, clamped to `[0, 1]`; 0 keeps the color and 1 gives white.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::colormap::tint;
+///
+/// let light_blue = tint([31, 119, 180], 0.3);
+/// ```
+pub fn tint(color: [u8; 3], amount: f64) -> [u8; 3] {
+    let amount = amount.clamp(0.0, 1.0);
+    color.map(|channel| (channel as f64 + (255.0 - channel as f64) * amount).round() as u8)
+}
@@ -0,0 +1,213 @@
+//! Simulation of color vision deficiencies and checks that dataset colors stay
+//! distinguishable, for accessible charts.
+//!
+//! Deficiencies are simulated with the matrices of Machado, Oliveira and Fernandes (2009)
+//! at full severity, applied to linear RGB. Colors are compared by their distance in the
+//! CIELAB color space (CIE76 ΔE), where a distance of about 2.3 is just noticeable.
+
+/// A color vision deficiency that can be simulated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorVisionDeficiency {
+    /// Missing red cones: reds look dark and are confused with greens.
+    Protanopia,
+    /// Missing green cones, the most common deficiency: reds and greens are confused.
+    Deuteranopia,
+    /// Missing blue cones: blues are confused with greens, and yellows with pinks.
+    Tritanopia,
+}
+
+impl ColorVisionDeficiency {
+    /// All simulated deficiencies.
+    pub const ALL: [ColorVisionDeficiency; 3] = [
+        ColorVisionDeficiency::Protanopia,
+        ColorVisionDeficiency::Deuteranopia,
+        ColorVisionDeficiency::Tritanopia,
+    ];
+
+    /// Returns the lowercase name of the deficiency, e.g. for warnings.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorVisionDeficiency::Protanopia => "protanopia",
+            ColorVisionDeficiency::Deuteranopia => "deuteranopia",
+            ColorVisionDeficiency::Tritanopia => "tritanopia",
+        }
+    }
+
+    /// Returns the matrix transforming linear RGB as seen with normal vision to linear
+    /// RGB as seen with the deficiency.
+    fn matrix(&self) -> [[f64; 3]; 3] {
+        match self {
+            ColorVisionDeficiency::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            ColorVisionDeficiency::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            ColorVisionDeficiency::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+
+    /// Simulates how a color looks with the deficiency.
+    ///
+    /// # Parameters
+    /// - `color`: The sRGB color.
+    ///
+    /// # Returns
+    /// The sRGB color seen with the deficiency.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::colorvision::ColorVisionDeficiency;
+    ///
+    /// // Red and green both turn into similar olive tones
+    /// let red = ColorVisionDeficiency::Deuteranopia.simulate([214, 39, 40]);
+    /// let green = ColorVisionDeficiency::Deuteranopia.simulate([44, 160, 44]);
+    /// ```
+    pub fn simulate(&self, color: [u8; 3]) -> [u8; 3] {
+        let linear = color.map(to_linear);
+        self.matrix().map(|row| {
+            let value = row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2];
+            from_linear(value)
+        })
+    }
+}
+
+/// The smallest distance in CIELAB at which two dataset colors are considered
+/// distinguishable by `check_palette`.
+pub const MIN_DISTINGUISHABLE_DISTANCE: f64 = 10.0;
+
+/// Two dataset colors that are hard to tell apart, found by `check_palette`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaletteWarning {
+    /// Label of the first dataset.
+    pub first: String,
+    /// Label of the second dataset.
+    pub second: String,
+    /// The deficiency under which the colors are confused, or `None` for normal vision.
+    pub deficiency: Option<ColorVisionDeficiency>,
+    /// The distance between the colors as seen with the deficiency.
+    pub distance: f64,
+    /// The smallest acceptable distance the palette was checked against.
+    pub min_distance: f64,
+}
+
+impl PaletteWarning {
+    /// Describes the warning, e.g. for logging.
+    pub fn message(&self) -> String {
+        let vision = self
+            .deficiency
+            .map(|deficiency| format!("with {}", deficiency.name()))
+            .unwrap_or_else(|| "with normal vision".to_string());
+        format!(
+            "'{}' and '{}' are hard to tell apart {vision} (distance {:.1}, use at least {:.1})",
+            self.first, self.second, self.distance, self.min_distance
+        )
+    }
+}
+
+/// Checks that the colors of datasets can be told apart with normal vision and with
+/// each simulated deficiency.
+///
+/// Every pair of colors closer than `min_distance` in CIELAB is reported once per
+/// vision type. Pairs of equal colors are reported under normal vision only.
+///
+/// # Parameters
+/// - `colors`: The datasets as `(label, color)` pairs, e.g. from a legend.
+/// - `min_distance`: The smallest acceptable distance, usually
+///   `MIN_DISTINGUISHABLE_DISTANCE`.
+///
+/// # Returns
+/// The warnings, empty if the palette is safe.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::colorvision::{check_palette, MIN_DISTINGUISHABLE_DISTANCE};
+///
+/// let colors = [("Revenue", [214, 39, 40]), ("Costs", [44, 160, 44])];
+/// for warning in check_palette(&colors, MIN_DISTINGUISHABLE_DISTANCE) {
+///     eprintln!("{}", warning.message());
+/// }
+/// ```
+pub fn check_palette(colors: &[(&str, [u8; 3])], min_distance: f64) -> Vec<PaletteWarning> {
+    let visions = std::iter::once(None).chain(ColorVisionDeficiency::ALL.map(Some));
+    let mut warnings = Vec::new();
+    for deficiency in visions {
+        for (i, &(first, a)) in colors.iter().enumerate() {
+            for &(second, b) in &colors[i + 1..] {
+                if deficiency.is_some() && a == b {
+                    continue;
+                }
+                let (a, b) = match deficiency {
+                    Some(deficiency) => (deficiency.simulate(a), deficiency.simulate(b)),
+                    None => (a, b),
+                };
+                let distance = color_distance(a, b);
+                if distance < min_distance {
+                    warnings.push(PaletteWarning {
+                        first: first.to_string(),
+                        second: second.to_string(),
+                        deficiency,
+                        distance,
+                        min_distance,
+                    });
+                }
+            }
+        }
+    }
+    warnings
+}
+
+/// Computes the distance between two colors in the CIELAB color space (CIE76 ΔE).
+///
+/// # Returns
+/// The distance, 0.0 for equal colors and about 100 between black and white.
+pub fn color_distance(a: [u8; 3], b: [u8; 3]) -> f64 {
+    let (la, lb) = (to_lab(a), to_lab(b));
+    ((la[0] - lb[0]).powi(2) + (la[1] - lb[1]).powi(2) + (la[2] - lb[2]).powi(2)).sqrt()
+}
+
+/// Converts an sRGB channel to linear light in `[0, 1]`.
+fn to_linear(channel: u8) -> f64 {
+    let c = channel as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts linear light to an sRGB channel, clamping values outside `[0, 1]`.
+fn from_linear(value: f64) -> u8 {
+    let v = value.clamp(0.0, 1.0);
+    let c = if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+/// Converts an sRGB color to CIELAB under the D65 white point.
+fn to_lab(color: [u8; 3]) -> [f64; 3] {
+    let [r, g, b] = color.map(to_linear);
+    let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883;
+    let f = |t: f64| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
@@ -0,0 +1,158 @@
+use std::f64::consts::FRAC_PI_2;
+
+/// Radii of the four corners of a rounded rectangle, in pixels.
+///
+/// Radii too large for the rectangle are scaled down together, as in CSS, so adjacent
+/// corners never overlap.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CornerRadii {
+    /// Radius of the top-left corner.
+    pub top_left: f64,
+    /// Radius of the top-right corner.
+    pub top_right: f64,
+    /// Radius of the bottom-right corner.
+    pub bottom_right: f64,
+    /// Radius of the bottom-left corner.
+    pub bottom_left: f64,
+}
+
+impl CornerRadii {
+    /// Creates radii for each corner, clockwise from the top-left one.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::cornerradii::CornerRadii;
+    ///
+    /// // Rounded top of a vertical bar
+    /// let radii = CornerRadii::new(4.0, 4.0, 0.0, 0.0);
+    /// ```
+    pub fn new(top_left: f64, top_right: f64, bottom_right: f64, bottom_left: f64) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// Creates the same radius for all four corners.
+    pub fn uniform(radius: f64) -> Self {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    /// Returns the radii fitted to a rectangle: negative radii become 0 and all radii are
+    /// scaled by the same factor until every side can hold its two corners.
+    ///
+    /// # Parameters
+    /// - `width`, `height`: Dimensions of the rectangle.
+    pub fn clamped(&self, width: f64, height: f64) -> Self {
+        let radii = Self::new(
+            self.top_left.max(0.0),
+            self.top_right.max(0.0),
+            self.bottom_right.max(0.0),
+            self.bottom_left.max(0.0),
+        );
+        let factor = [
+            (width, radii.top_left + radii.top_right),
+            (width, radii.bottom_left + radii.bottom_right),
+            (height, radii.top_left + radii.bottom_left),
+            (height, radii.top_right + radii.bottom_right),
+        ]
+        .into_iter()
+        .filter(|&(_, sum)| sum > 0.0)
+        .map(|(side, sum)| side.max(0.0) / sum)
+        .fold(1.0, f64::min);
+
+        Self::new(
+            radii.top_left * factor,
+            radii.top_right * factor,
+            radii.bottom_right * factor,
+            radii.bottom_left * factor,
+        )
+    }
+
+    /// Approximates the outline of a rounded rectangle by a polygon, clockwise from the
+    /// top-left corner. Arcs are split into segments about 2 pixels long.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the top-left corner of the rectangle.
+    /// - `width`, `height`: Dimensions of the rectangle.
+    ///
+    /// # Returns
+    /// The polygon vertices in canvas coordinates.
+    pub fn outline(&self, x: f64, y: f64, width: f64, height: f64) -> Vec<(f64, f64)> {
+        let radii = self.clamped(width, height);
+        // Arc center and start angle of each corner, with y pointing down
+        let corners = [
+            (x + radii.top_left, y + radii.top_left, radii.top_left, 2.0),
+            (
+                x + width - radii.top_right,
+                y + radii.top_right,
+                radii.top_right,
+                3.0,
+            ),
+            (
+                x + width - radii.bottom_right,
+                y + height - radii.bottom_right,
+                radii.bottom_right,
+                0.0,
+            ),
+            (
+                x + radii.bottom_left,
+                y + height - radii.bottom_left,
+                radii.bottom_left,
+                1.0,
+            ),
+        ];
+
+        let mut points = Vec::new();
+        for (center_x, center_y, radius, quadrant) in corners {
+            if radius <= 0.0 {
+                points.push((center_x, center_y));
+                continue;
+            }
+            let segments = (radius * FRAC_PI_2 / 2.0).ceil().max(2.0) as usize;
+            for step in 0..=segments {
+                let angle = (quadrant + step as f64 / segments as f64) * FRAC_PI_2;
+                points.push((
+                    center_x + radius * angle.cos(),
+                    center_y + radius * angle.sin(),
+                ));
+            }
+        }
+        points
+    }
+
+    /// Builds the SVG path data of a rounded rectangle, clockwise from the top-left corner.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the top-left corner of the rectangle.
+    /// - `width`, `height`: Dimensions of the rectangle.
+    pub fn svg_path(&self, x: f64, y: f64, width: f64, height: f64) -> String {
+        let radii = self.clamped(width, height);
+        let arc = |radius: f64, end_x: f64, end_y: f64| {
+            if radius > 0.0 {
+                format!(" A {radius:.2} {radius:.2} 0 0 1 {end_x:.2} {end_y:.2}")
+            } else {
+                String::new()
+            }
+        };
+
+        format!(
+            "M {:.2} {y:.2} H {:.2}{} V {:.2}{} H {:.2}{} V {:.2}{} Z",
+            x + radii.top_left,
+            x + width - radii.top_right,
+            arc(radii.top_right, x + width, y + radii.top_right),
+            y + height - radii.bottom_right,
+            arc(
+                radii.bottom_right,
+                x + width - radii.bottom_right,
+                y + height
+            ),
+            x + radii.bottom_left,
+            arc(radii.bottom_left, x, y + height - radii.bottom_left),
+            y + radii.top_left,
+            arc(radii.top_left, x + radii.top_left, y),
+        )
+    }
+}
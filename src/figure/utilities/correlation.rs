@@ -0,0 +1,113 @@
+//! Correlation coefficients between numeric series.
+
+use super::correlationmethod::CorrelationMethod;
+
+/// Computes Pearson's correlation coefficient between two series.
+///
+/// Series of different lengths are compared over their common prefix.
+///
+/// # Parameters
+/// - `a`, `b`: The series to compare.
+///
+/// # Returns
+/// The coefficient in `[-1, 1]`, or `NaN` if either series is constant or has fewer
+/// than two values.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::correlation::pearson;
+///
+/// assert_eq!(pearson(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]), 1.0);
+/// ```
+pub fn pearson(a: &[f64], b: &[f64]) -> f64 {
+    let n = a.len().min(b.len());
+    if n < 2 {
+        return f64::NAN;
+    }
+    let (a, b) = (&a[..n], &b[..n]);
+    let mean_a = a.iter().sum::<f64>() / n as f64;
+    let mean_b = b.iter().sum::<f64>() / n as f64;
+
+    let (mut covariance, mut variance_a, mut variance_b) = (0.0, 0.0, 0.0);
+    for (x, y) in a.iter().zip(b) {
+        let (dx, dy) = (x - mean_a, y - mean_b);
+        covariance += dx * dy;
+        variance_a += dx * dx;
+        variance_b += dy * dy;
+    }
+
+    if variance_a == 0.0 || variance_b == 0.0 {
+        return f64::NAN;
+    }
+    (covariance / (variance_a * variance_b).sqrt()).clamp(-1.0, 1.0)
+}
+
+/// Computes the rank of every value, averaging the ranks of ties.
+///
+/// # Parameters
+/// - `values`: The values to rank.
+///
+/// # Returns
+/// The 1-based rank of each value, in the order of `values`.
+pub fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        // Positions start..end hold ties; they all get the mean of ranks start+1..=end
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &index in &order[start..end] {
+            ranks[index] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// Computes Spearman's rank correlation coefficient between two series.
+///
+/// Series of different lengths are compared over their common prefix.
+///
+/// # Parameters
+/// - `a`, `b`: The series to compare.
+///
+/// # Returns
+/// The coefficient in `[-1, 1]`, or `NaN` if either series is constant or has fewer
+/// than two values.
+pub fn spearman(a: &[f64], b: &[f64]) -> f64 {
+    let n = a.len().min(b.len());
+    pearson(&ranks(&a[..n]), &ranks(&b[..n]))
+}
+
+/// Computes the correlation matrix of several series.
+///
+/// # Parameters
+/// - `series`: The series to correlate.
+/// - `method`: The correlation coefficient to compute.
+///
+/// # Returns
+/// A symmetric `N x N` matrix where entry `[i][j]` is the correlation between series `i`
+/// and `j`. Diagonal entries are `1.0`.
+pub fn correlation_matrix(series: &[Vec<f64>], method: CorrelationMethod) -> Vec<Vec<f64>> {
+    let coefficient = match method {
+        CorrelationMethod::Pearson => pearson,
+        CorrelationMethod::Spearman => spearman,
+    };
+
+    let n = series.len();
+    let mut matrix = vec![vec![1.0; n]; n];
+    for i in 0..n {
+        for j in i + 1..n {
+            let value = coefficient(&series[i], &series[j]);
+            matrix[i][j] = value;
+            matrix[j][i] = value;
+        }
+    }
+    matrix
+}
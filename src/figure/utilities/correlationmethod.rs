@@ -0,0 +1,8 @@
+/// The correlation coefficient computed between two series.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CorrelationMethod {
+    /// Pearson's linear correlation coefficient.
+    Pearson,
+    /// Spearman's rank correlation coefficient, robust to outliers and monotonic non-linearity.
+    Spearman,
+}
@@ -0,0 +1,57 @@
+/// Describes how amounts of money are written: a currency symbol before or after the
+/// number.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CurrencyFormat {
+    /// The currency symbol or code, e.g. `"$"`, `"€"` or `"CHF"`.
+    pub symbol: String,
+    /// Whether the symbol precedes the number (`"$12.50"`) rather than follows it
+    /// (`"12,50 €"`).
+    pub symbol_first: bool,
+    /// Whether a space separates the symbol from the number.
+    pub spaced: bool,
+}
+
+impl CurrencyFormat {
+    /// Creates a currency written before the number without a space, e.g. `"$12.50"`.
+    ///
+    /// # Parameters
+    /// - `symbol`: The currency symbol.
+    pub fn prefix(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            symbol_first: true,
+            spaced: false,
+        }
+    }
+
+    /// Creates a currency written after the number with a space, e.g. `"12,50 €"`.
+    ///
+    /// # Parameters
+    /// - `symbol`: The currency symbol.
+    pub fn suffix(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            symbol_first: false,
+            spaced: true,
+        }
+    }
+
+    /// Adds the symbol to a formatted number. A leading sign stays in front of the symbol
+    /// (`"-$5.00"`).
+    ///
+    /// # Parameters
+    /// - `number`: The number as formatted for the locale.
+    ///
+    /// # Returns
+    /// The amount.
+    pub fn apply(&self, number: &str) -> String {
+        let space = if self.spaced { " " } else { "" };
+        if !self.symbol_first {
+            return format!("{number}{space}{}", self.symbol);
+        }
+        match number.strip_prefix(['-', '+']) {
+            Some(magnitude) => format!("{}{}{space}{magnitude}", &number[..1], self.symbol),
+            None => format!("{}{space}{number}", self.symbol),
+        }
+    }
+}
@@ -0,0 +1,331 @@
+//! Data-reduction transforms attached to datasets and evaluated at render time.
+//!
+//! A `TransformPipeline` is a chain of steps such as resampling, gap filling and smoothing.
+//! It is stored with the figure definition and applied to the raw points whenever the figure
+//! is drawn, so points pushed by streaming updates are preprocessed the same way without any
+//! extra call.
+
+/// How missing values are treated by `TransformPipeline::fill_gaps`.
+///
+/// Missing values are empty resampling intervals and points with a non-finite y value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Gap {
+    /// Leaves the values missing; the line is broken at them.
+    Null,
+    /// Replaces missing values by zero.
+    Zero,
+    /// Repeats the last known value.
+    Previous,
+    /// Interpolates linearly between the known values around the gap. Gaps at the start or
+    /// end of the data stay missing.
+    Linear,
+}
+
+/// How the points of one resampling interval are reduced to a single value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Aggregate {
+    /// The mean of the values.
+    Mean,
+    /// The sum of the values.
+    Sum,
+    /// The smallest value.
+    Min,
+    /// The largest value.
+    Max,
+    /// The value of the last point.
+    Last,
+}
+
+/// A smoothing filter applied by `TransformPipeline::smooth`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Smoothing {
+    /// Exponential moving average with the weight `alpha` of the newest value, in `(0, 1]`.
+    Ema(f64),
+    /// Simple moving average over the last `window` values.
+    Sma(usize),
+}
+
+/// Creates an exponential moving average filter.
+///
+/// # Parameters
+/// - `alpha`: The weight of the newest value, clamped to `(0, 1]`; smaller values smooth more.
+///
+/// # Returns
+/// `Smoothing::Ema(alpha)`.
+pub fn ema(alpha: f64) -> Smoothing {
+    Smoothing::Ema(alpha)
+}
+
+/// Creates a simple moving average filter.
+///
+/// # Parameters
+/// - `window`: The number of values averaged, at least `1`.
+///
+/// # Returns
+/// `Smoothing::Sma(window)`.
+pub fn sma(window: usize) -> Smoothing {
+    Smoothing::Sma(window)
+}
+
+/// One step of a `TransformPipeline`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Transform {
+    Resample { interval: f64, aggregate: Aggregate },
+    FillGaps(Gap),
+    Smooth(Smoothing),
+}
+
+/// Points with possibly missing y values, in x order.
+type Series = Vec<(f64, Option<f64>)>;
+
+/// A chain of transforms applied lazily to the points of a dataset.
+///
+/// The steps run in the order they were added. Missing values (empty intervals and
+/// non-finite y values) travel through the pipeline and break the output into segments,
+/// unless a `fill_gaps` step replaces them.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::datatransform::{ema, Gap, TransformPipeline};
+///
+/// let pipeline = TransformPipeline::new()
+///     .resample(60.0)
+///     .fill_gaps(Gap::Null)
+///     .smooth(ema(0.2));
+/// dataset.set_transform(pipeline);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransformPipeline {
+    steps: Vec<Transform>,
+}
+
+impl TransformPipeline {
+    /// Creates an empty pipeline, which returns the points unchanged.
+    ///
+    /// # Returns
+    /// A new `TransformPipeline` without steps.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a resampling step that averages the points of each interval.
+    ///
+    /// # Parameters
+    /// - `interval`: The interval length in x units, e.g. `60.0` for one minute of timestamps
+    ///   in seconds. Intervals are aligned to multiples of `interval`.
+    ///
+    /// # Returns
+    /// The pipeline with the step appended.
+    pub fn resample(self, interval: f64) -> Self {
+        self.resample_with(interval, Aggregate::Mean)
+    }
+
+    /// Appends a resampling step that reduces the points of each interval to one point at the
+    /// start of the interval. Intervals without points between the first and the last one
+    /// become missing values.
+    ///
+    /// A non-positive or non-finite interval makes the step a no-op.
+    ///
+    /// # Parameters
+    /// - `interval`: The interval length in x units.
+    /// - `aggregate`: How the values of an interval are combined.
+    ///
+    /// # Returns
+    /// The pipeline with the step appended.
+    pub fn resample_with(mut self, interval: f64, aggregate: Aggregate) -> Self {
+        self.steps.push(Transform::Resample {
+            interval,
+            aggregate,
+        });
+        self
+    }
+
+    /// Appends a step that fills missing values.
+    ///
+    /// # Parameters
+    /// - `gap`: How missing values are replaced; `Gap::Null` keeps them as line breaks.
+    ///
+    /// # Returns
+    /// The pipeline with the step appended.
+    pub fn fill_gaps(mut self, gap: Gap) -> Self {
+        self.steps.push(Transform::FillGaps(gap));
+        self
+    }
+
+    /// Appends a smoothing step. Filters restart after each missing value, so smoothing
+    /// never bridges a gap.
+    ///
+    /// # Parameters
+    /// - `smoothing`: The filter, e.g. `ema(0.2)` or `sma(5)`.
+    ///
+    /// # Returns
+    /// The pipeline with the step appended.
+    pub fn smooth(mut self, smoothing: Smoothing) -> Self {
+        self.steps.push(Transform::Smooth(smoothing));
+        self
+    }
+
+    /// Returns `true` if the pipeline has no steps.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Runs the pipeline over a sequence of points.
+    ///
+    /// # Parameters
+    /// - `points`: The raw `(x, y)` points. Points with a non-finite x are dropped.
+    ///
+    /// # Returns
+    /// The transformed points, split into segments at the remaining missing values. Empty
+    /// segments are omitted.
+    pub fn apply(&self, points: &[(f64, f64)]) -> Vec<Vec<(f64, f64)>> {
+        let mut series: Series = points
+            .iter()
+            .filter(|(x, _)| x.is_finite())
+            .map(|&(x, y)| (x, Some(y).filter(|y| y.is_finite())))
+            .collect();
+
+        for step in &self.steps {
+            series = match *step {
+                Transform::Resample {
+                    interval,
+                    aggregate,
+                } => resample(&series, interval, aggregate),
+                Transform::FillGaps(gap) => fill_gaps(series, gap),
+                Transform::Smooth(smoothing) => smooth(series, smoothing),
+            };
+        }
+
+        let mut segments = vec![Vec::new()];
+        for (x, y) in series {
+            match y {
+                Some(y) => segments.last_mut().unwrap().push((x, y)),
+                None if !segments.last().unwrap().is_empty() => segments.push(Vec::new()),
+                None => {}
+            }
+        }
+        segments.retain(|segment| !segment.is_empty());
+        segments
+    }
+}
+
+fn resample(series: &[(f64, Option<f64>)], interval: f64, aggregate: Aggregate) -> Series {
+    if !(interval.is_finite() && interval > 0.0) || series.is_empty() {
+        return series.to_vec();
+    }
+
+    let mut sorted = series.to_vec();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let bucket_of = |x: f64| (x / interval).floor() as i64;
+    let first = bucket_of(sorted[0].0);
+    let last = bucket_of(sorted[sorted.len() - 1].0);
+
+    let mut resampled = Vec::new();
+    let mut index = 0;
+    for bucket in first..=last {
+        let mut values = Vec::new();
+        while index < sorted.len() && bucket_of(sorted[index].0) == bucket {
+            values.extend(sorted[index].1);
+            index += 1;
+        }
+        resampled.push((bucket as f64 * interval, reduce(&values, aggregate)));
+    }
+    resampled
+}
+
+fn reduce(values: &[f64], aggregate: Aggregate) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(match aggregate {
+        Aggregate::Mean => values.iter().sum::<f64>() / values.len() as f64,
+        Aggregate::Sum => values.iter().sum(),
+        Aggregate::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+        Aggregate::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        Aggregate::Last => values[values.len() - 1],
+    })
+}
+
+fn fill_gaps(mut series: Series, gap: Gap) -> Series {
+    match gap {
+        Gap::Null => {}
+        Gap::Zero => {
+            for (_, y) in &mut series {
+                y.get_or_insert(0.0);
+            }
+        }
+        Gap::Previous => {
+            let mut previous = None;
+            for (_, y) in &mut series {
+                match y {
+                    Some(value) => previous = Some(*value),
+                    None => *y = previous,
+                }
+            }
+        }
+        Gap::Linear => {
+            let mut before: Option<(f64, f64)> = None;
+            let mut run_start = None;
+            for index in 0..series.len() {
+                match series[index] {
+                    (_, None) => {
+                        run_start.get_or_insert(index);
+                    }
+                    (x, Some(y)) => {
+                        if let (Some((x0, y0)), Some(start)) = (before, run_start) {
+                            for point in &mut series[start..index] {
+                                let t = if x > x0 {
+                                    (point.0 - x0) / (x - x0)
+                                } else {
+                                    0.0
+                                };
+                                point.1 = Some(y0 + (y - y0) * t);
+                            }
+                        }
+                        before = Some((x, y));
+                        run_start = None;
+                    }
+                }
+            }
+        }
+    }
+    series
+}
+
+fn smooth(mut series: Series, smoothing: Smoothing) -> Series {
+    match smoothing {
+        Smoothing::Ema(alpha) => {
+            let alpha = if alpha.is_finite() {
+                alpha.clamp(f64::EPSILON, 1.0)
+            } else {
+                1.0
+            };
+            let mut state: Option<f64> = None;
+            for (_, y) in &mut series {
+                state = y.map(|value| match state {
+                    Some(previous) => previous + alpha * (value - previous),
+                    None => value,
+                });
+                *y = state;
+            }
+        }
+        Smoothing::Sma(window) => {
+            let window = window.max(1);
+            let mut recent: Vec<f64> = Vec::with_capacity(window);
+            for (_, y) in &mut series {
+                match y {
+                    Some(value) => {
+                        if recent.len() == window {
+                            recent.remove(0);
+                        }
+                        recent.push(*value);
+                        *value = recent.iter().sum::<f64>() / recent.len() as f64;
+                    }
+                    None => recent.clear(),
+                }
+            }
+        }
+    }
+    series
+}
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use super::random::SeededRng;
+
+/// Render-time thinning of dense point clouds.
+///
+/// The plot area is divided into square cells of `cell_size` pixels. Cells with at most
+/// `max_per_cell` points keep all of them, so sparse regions and isolated outliers are drawn
+/// unchanged. Denser cells keep their extreme points (smallest and largest x and y) and a
+/// seeded random sample of the others, up to `max_per_cell` points in total, so the outline
+/// and range of a cluster survive while its interior is subsampled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DensityThinning {
+    /// Side length of a cell, in pixels.
+    pub cell_size: f64,
+    /// Number of points a cell keeps. A cell always keeps its extreme points, even if there
+    /// are more of them.
+    pub max_per_cell: usize,
+    /// Seed of the generator choosing the sampled points.
+    pub seed: u64,
+}
+
+impl DensityThinning {
+    /// Creates a thinning mode.
+    ///
+    /// # Parameters
+    /// - `cell_size`: Side length of a cell in pixels, at least `1.0`.
+    /// - `max_per_cell`: Number of points a cell keeps, at least `1`.
+    /// - `seed`: Seed of the sampling generator. Equal seeds thin equal data the same way.
+    ///
+    /// # Returns
+    /// A new `DensityThinning`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::densitythinning::DensityThinning;
+    ///
+    /// let thinning = DensityThinning::new(4.0, 3, 0);
+    /// ```
+    pub fn new(cell_size: f64, max_per_cell: usize, seed: u64) -> Self {
+        Self {
+            cell_size: cell_size.max(1.0),
+            max_per_cell: max_per_cell.max(1),
+            seed,
+        }
+    }
+
+    /// Selects the points to draw.
+    ///
+    /// # Parameters
+    /// - `pixels`: The canvas positions of the points.
+    ///
+    /// # Returns
+    /// The indices of the kept points, in their original order.
+    pub fn keep(&self, pixels: &[(f64, f64)]) -> Vec<usize> {
+        // Ordered cells, so the sampling sequence and the result do not depend on hashing
+        let mut cells: BTreeMap<(i64, i64), Vec<usize>> = BTreeMap::new();
+        for (index, &(x, y)) in pixels.iter().enumerate() {
+            let cell = (
+                (x / self.cell_size).floor() as i64,
+                (y / self.cell_size).floor() as i64,
+            );
+            cells.entry(cell).or_default().push(index);
+        }
+
+        let mut rng = SeededRng::new(self.seed);
+        let mut kept = vec![false; pixels.len()];
+        for members in cells.values() {
+            if members.len() <= self.max_per_cell {
+                for &index in members {
+                    kept[index] = true;
+                }
+                continue;
+            }
+
+            let by_x = |&a: &usize, &b: &usize| pixels[a].0.total_cmp(&pixels[b].0);
+            let by_y = |&a: &usize, &b: &usize| pixels[a].1.total_cmp(&pixels[b].1);
+            let extremes = [
+                members.iter().copied().min_by(by_x),
+                members.iter().copied().max_by(by_x),
+                members.iter().copied().min_by(by_y),
+                members.iter().copied().max_by(by_y),
+            ];
+            let mut count = 0;
+            for index in extremes.into_iter().flatten() {
+                if !kept[index] {
+                    kept[index] = true;
+                    count += 1;
+                }
+            }
+
+            // Partial Fisher-Yates shuffle of the remaining points
+            let mut rest: Vec<usize> = members
+                .iter()
+                .copied()
+                .filter(|&index| !kept[index])
+                .collect();
+            let wanted = self.max_per_cell.saturating_sub(count).min(rest.len());
+            for position in 0..wanted {
+                let pick = position + (rng.next_u64() % (rest.len() - position) as u64) as usize;
+                rest.swap(position, pick);
+                kept[rest[position]] = true;
+            }
+        }
+
+        (0..pixels.len()).filter(|&index| kept[index]).collect()
+    }
+}
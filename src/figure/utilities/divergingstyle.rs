@@ -0,0 +1,47 @@
+/// Styling of a diverging bar chart, where bars grow away from a baseline in both directions.
+///
+/// Values above the baseline are drawn in `positive_color` to the right (horizontal) or
+/// upwards (vertical); values below it in `negative_color` to the left or downwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DivergingStyle {
+    /// The value bars grow from, e.g. `0.0` for profit/loss or a survey's neutral answer.
+    pub baseline: f64,
+    /// Color of bars above the baseline.
+    pub positive_color: [u8; 3],
+    /// Color of bars below the baseline.
+    pub negative_color: [u8; 3],
+    /// Legend label of bars above the baseline.
+    pub positive_label: String,
+    /// Legend label of bars below the baseline.
+    pub negative_label: String,
+}
+
+impl DivergingStyle {
+    /// Creates a diverging style around a baseline, labelled "Positive" and "Negative".
+    ///
+    /// # Parameters
+    /// - `baseline`: The value bars grow from.
+    /// - `positive_color`: The RGB color of bars above the baseline.
+    /// - `negative_color`: The RGB color of bars below the baseline.
+    ///
+    /// # Returns
+    /// A new `DivergingStyle`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::divergingstyle::DivergingStyle;
+    ///
+    /// let mut style = DivergingStyle::new(0.0, [0, 150, 0], [200, 0, 0]);
+    /// style.positive_label = "Profit".to_string();
+    /// style.negative_label = "Loss".to_string();
+    /// ```
+    pub fn new(baseline: f64, positive_color: [u8; 3], negative_color: [u8; 3]) -> Self {
+        Self {
+            baseline,
+            positive_color,
+            negative_color,
+            positive_label: "Positive".to_string(),
+            negative_label: "Negative".to_string(),
+        }
+    }
+}
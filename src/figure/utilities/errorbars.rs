@@ -0,0 +1,181 @@
+use crate::figure::canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas};
+
+use super::{axistype::AxisType, linetype::LineType};
+
+/// Half the width in pixels of the caps at the ends of an error bar.
+pub const CAP_HALF_WIDTH: f64 = 4.0;
+
+/// Per-point x and y error values of a dataset, drawn as capped error bars.
+///
+/// Errors are stored as `(minus, plus)` pairs aligned with the dataset's points by index:
+/// the bar of a point spans from `value - minus` to `value + plus`. Points without an entry
+/// have no error bar on that axis.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ErrorBars {
+    /// `(minus, plus)` x errors, one per point.
+    pub x: Vec<(f64, f64)>,
+    /// `(minus, plus)` y errors, one per point.
+    pub y: Vec<(f64, f64)>,
+}
+
+impl ErrorBars {
+    /// Sets the errors on one axis, replacing previous ones.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis the errors apply to.
+    /// - `errors`: `(minus, plus)` errors, one per point; negative values are treated as
+    ///   positive.
+    pub fn set(&mut self, axis: AxisType, errors: &[(f64, f64)]) {
+        let errors = errors
+            .iter()
+            .map(|&(minus, plus)| (minus.abs(), plus.abs()))
+            .collect();
+        match axis {
+            AxisType::AxisX => self.x = errors,
+            AxisType::AxisY => self.y = errors,
+        }
+    }
+
+    /// Returns whether no point has an error bar.
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty() && self.y.is_empty()
+    }
+
+    /// Computes the rectangle covered by a point and its error bars.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the point in the dataset.
+    /// - `point`: The point.
+    ///
+    /// # Returns
+    /// A tuple `(x_min, x_max, y_min, y_max)`; the point itself on axes without an error.
+    pub fn extent(&self, index: usize, point: (f64, f64)) -> (f64, f64, f64, f64) {
+        let (x, y) = point;
+        let (x_minus, x_plus) = self.x.get(index).copied().unwrap_or((0.0, 0.0));
+        let (y_minus, y_plus) = self.y.get(index).copied().unwrap_or((0.0, 0.0));
+        (x - x_minus, x + x_plus, y - y_minus, y + y_plus)
+    }
+
+    /// Extends points by the ends of their error bars, for range computations.
+    ///
+    /// # Parameters
+    /// - `points`: The points of the dataset.
+    ///
+    /// # Returns
+    /// The lower-left and upper-right corners of every point's extent.
+    pub fn extent_points<'a>(
+        &'a self,
+        points: &'a [(f64, f64)],
+    ) -> impl Iterator<Item = (f64, f64)> + 'a {
+        points.iter().enumerate().flat_map(|(index, &point)| {
+            let (x_min, x_max, y_min, y_max) = self.extent(index, point);
+            [(x_min, y_min), (x_max, y_max)]
+        })
+    }
+
+    /// Keeps the errors of some points, e.g. after filtering the points of a dataset.
+    ///
+    /// # Parameters
+    /// - `indices`: The indices of the kept points, in their new order.
+    pub fn select(&self, indices: &[usize]) -> ErrorBars {
+        let pick = |errors: &[(f64, f64)]| -> Vec<(f64, f64)> {
+            if errors.is_empty() {
+                return Vec::new();
+            }
+            indices
+                .iter()
+                .map(|&index| errors.get(index).copied().unwrap_or((0.0, 0.0)))
+                .collect()
+        };
+        ErrorBars {
+            x: pick(&self.x),
+            y: pick(&self.y),
+        }
+    }
+
+    /// Computes the lines of the error bars of a point in canvas coordinates.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the point in the dataset.
+    /// - `point`: The point.
+    /// - `to_pixel`: Maps a data point to its canvas position.
+    ///
+    /// # Returns
+    /// The bars and their caps as `(x1, y1, x2, y2)` lines; empty without errors.
+    pub fn lines(
+        &self,
+        index: usize,
+        point: (f64, f64),
+        to_pixel: impl Fn(f64, f64) -> (f64, f64),
+    ) -> Vec<(f64, f64, f64, f64)> {
+        let (x, y) = point;
+        let (x_min, x_max, y_min, y_max) = self.extent(index, point);
+        let mut lines = Vec::new();
+        if self.x.get(index).is_some() {
+            let (left, py) = to_pixel(x_min, y);
+            let (right, _) = to_pixel(x_max, y);
+            lines.push((left, py, right, py));
+            for end in [left, right] {
+                lines.push((end, py - CAP_HALF_WIDTH, end, py + CAP_HALF_WIDTH));
+            }
+        }
+        if self.y.get(index).is_some() {
+            let (px, bottom) = to_pixel(x, y_min);
+            let (_, top) = to_pixel(x, y_max);
+            lines.push((px, bottom, px, top));
+            for end in [bottom, top] {
+                lines.push((px - CAP_HALF_WIDTH, end, px + CAP_HALF_WIDTH, end));
+            }
+        }
+        lines
+    }
+
+    /// Draws the error bars of all points on a pixel canvas.
+    ///
+    /// # Parameters
+    /// - `canvas`: The canvas.
+    /// - `points`: The points of the dataset.
+    /// - `to_pixel`: Maps a data point to its canvas position.
+    /// - `color`: The RGB color of the bars.
+    pub fn draw(
+        &self,
+        canvas: &mut PixelCanvas,
+        points: &[(f64, f64)],
+        to_pixel: impl Fn(f64, f64) -> (f64, f64),
+        color: [u8; 3],
+    ) {
+        for (index, &point) in points.iter().enumerate() {
+            for (x1, y1, x2, y2) in self.lines(index, point, &to_pixel) {
+                canvas.draw_line(
+                    x1.round() as i32,
+                    y1.round() as i32,
+                    x2.round() as i32,
+                    y2.round() as i32,
+                    color,
+                    LineType::Solid,
+                );
+            }
+        }
+    }
+
+    /// Draws the error bars of all points on an SVG canvas.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The canvas.
+    /// - `points`: The points of the dataset.
+    /// - `to_pixel`: Maps a data point to its canvas position.
+    /// - `color`: The RGB color of the bars.
+    pub fn draw_svg(
+        &self,
+        svg_canvas: &mut SvgCanvas,
+        points: &[(f64, f64)],
+        to_pixel: impl Fn(f64, f64) -> (f64, f64),
+        color: [u8; 3],
+    ) {
+        for (index, &point) in points.iter().enumerate() {
+            for (x1, y1, x2, y2) in self.lines(index, point, &to_pixel) {
+                svg_canvas.draw_line_rgb(x1, y1, x2, y2, color, 1.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Locale-dependent formatting of the numbers, percentages, amounts and dates shown in
+//! labels.
+
+use crate::figure::utilities::{
+    currencyformat::CurrencyFormat, percentstyle::PercentStyle, timezone::TimeZone,
+};
+
+/// Describes how numbers, percentages, amounts and dates are written in the labels of a
+/// figure: tick labels, value labels, percentages and legend summaries.
+///
+/// Figures format numbers the Rust way (`"1234.5"`, `"45%"`) and pass the text through
+/// `localize`, so a single context controls the separators and the percent style
+/// everywhere. The default context keeps that format unchanged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormatContext {
+    /// The character between the integer and the fractional part (e.g., `'.'` or `','`).
+    pub decimal_separator: char,
+    /// The character between groups of three integer digits, or `None` for no grouping.
+    pub grouping_separator: Option<char>,
+    /// The pattern of dates shown by figures with time axes (see `TimeZone::format`), or
+    /// `None` to keep each figure's own pattern.
+    pub date_format: Option<String>,
+    /// How the percent sign is written.
+    pub percent_style: PercentStyle,
+    /// The currency of axes marked with `FigureConfig::set_currency_axis`.
+    pub currency: Option<CurrencyFormat>,
+}
+
+impl Default for FormatContext {
+    /// Provides the format of Rust's `format!`: a `.` decimal separator, no grouping,
+    /// attached percent signs, and no date format or currency.
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            grouping_separator: None,
+            date_format: None,
+            percent_style: PercentStyle::Attached,
+            currency: None,
+        }
+    }
+}
+
+impl FormatContext {
+    /// Creates the context of US English: `1,234.5`, `45%`, `$12.50` and `01/31/2024`.
+    pub fn en_us() -> Self {
+        Self {
+            decimal_separator: '.',
+            grouping_separator: Some(','),
+            date_format: Some("%m/%d/%Y".to_string()),
+            percent_style: PercentStyle::Attached,
+            currency: Some(CurrencyFormat::prefix("$")),
+        }
+    }
+
+    /// Creates the context of German: `1.234,5`, `45 %`, `12,50 €` and `31.01.2024`.
+    pub fn de_de() -> Self {
+        Self {
+            decimal_separator: ',',
+            grouping_separator: Some('.'),
+            date_format: Some("%d.%m.%Y".to_string()),
+            percent_style: PercentStyle::Spaced,
+            currency: Some(CurrencyFormat::suffix("€")),
+        }
+    }
+
+    /// Creates the context of French: `1 234,5`, `45 %`, `12,50 €` and `31/01/2024`, with
+    /// no-break spaces between digit groups.
+    pub fn fr_fr() -> Self {
+        Self {
+            decimal_separator: ',',
+            grouping_separator: Some('\u{a0}'),
+            date_format: Some("%d/%m/%Y".to_string()),
+            percent_style: PercentStyle::Spaced,
+            currency: Some(CurrencyFormat::suffix("€")),
+        }
+    }
+
+    /// Rewrites the numbers of a label formatted the Rust way into this context: digit
+    /// groups and decimal separators are inserted, and percent signs following a number are
+    /// placed by the percent style. Other text is kept as it is.
+    ///
+    /// # Parameters
+    /// - `text`: The label, e.g. `"-1234.5"` or `"Share: 12.5%"`.
+    ///
+    /// # Returns
+    /// The localized label.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::formatcontext::FormatContext;
+    ///
+    /// assert_eq!(FormatContext::de_de().localize("-1234.5"), "-1.234,5");
+    /// assert_eq!(FormatContext::de_de().localize("Share: 12.5%"), "Share: 12,5 %");
+    /// ```
+    pub fn localize(&self, text: &str) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let mut localized = String::with_capacity(text.len());
+        let mut i = 0;
+        while i < chars.len() {
+            if !chars[i].is_ascii_digit() {
+                localized.push(chars[i]);
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            let mut number = self.group_digits(&chars[start..i]);
+            if chars.get(i) == Some(&'.') && chars.get(i + 1).is_some_and(char::is_ascii_digit) {
+                let fraction = i + 1;
+                i = fraction;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                number.push(self.decimal_separator);
+                number.extend(&chars[fraction..i]);
+            }
+
+            if chars.get(i) == Some(&'%') {
+                i += 1;
+                match self.percent_style {
+                    PercentStyle::Attached => number.push('%'),
+                    PercentStyle::Spaced => number.push_str(" %"),
+                    PercentStyle::Leading => {
+                        let sign = if localized.ends_with(['-', '+']) {
+                            localized.pop()
+                        } else {
+                            None
+                        };
+                        number = match sign {
+                            Some(sign) => format!("%{sign}{number}"),
+                            None => format!("%{number}"),
+                        };
+                    }
+                }
+            }
+            localized.push_str(&number);
+        }
+        localized
+    }
+
+    /// Inserts the grouping separator between groups of three integer digits.
+    fn group_digits(&self, digits: &[char]) -> String {
+        let Some(separator) = self.grouping_separator else {
+            return digits.iter().collect();
+        };
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+        for (index, &digit) in digits.iter().enumerate() {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    /// Formats a number with a fixed number of decimals.
+    ///
+    /// # Parameters
+    /// - `value`: The number.
+    /// - `decimals`: The number of decimals.
+    pub fn format_number(&self, value: f64, decimals: usize) -> String {
+        self.localize(&format!("{value:.decimals$}"))
+    }
+
+    /// Formats a number with a fixed number of decimals, followed by units if there are
+    /// any, e.g. `1.234,5 km/h`.
+    ///
+    /// # Parameters
+    /// - `value`: The number.
+    /// - `decimals`: The number of decimals.
+    /// - `units`: The units, or an empty string for none.
+    pub fn format_with_units(&self, value: f64, decimals: usize, units: &str) -> String {
+        let number = self.format_number(value, decimals);
+        if units.is_empty() {
+            number
+        } else {
+            format!("{number} {units}")
+        }
+    }
+
+    /// Formats a percentage with a fixed number of decimals.
+    ///
+    /// # Parameters
+    /// - `percent`: The percentage, e.g. `45.0` for 45%.
+    /// - `decimals`: The number of decimals.
+    pub fn format_percent(&self, percent: f64, decimals: usize) -> String {
+        self.localize(&format!("{percent:.decimals$}%"))
+    }
+
+    /// Formats an amount of money with a fixed number of decimals, with the currency
+    /// symbol if a currency is set.
+    ///
+    /// # Parameters
+    /// - `value`: The amount.
+    /// - `decimals`: The number of decimals.
+    pub fn format_currency(&self, value: f64, decimals: usize) -> String {
+        self.with_currency(self.format_number(value, decimals))
+    }
+
+    /// Adds the currency symbol to a localized number.
+    ///
+    /// # Returns
+    /// The amount, or the number unchanged if no currency is set.
+    pub fn with_currency(&self, number: String) -> String {
+        match &self.currency {
+            Some(currency) => currency.apply(&number),
+            None => number,
+        }
+    }
+
+    /// Formats a Unix timestamp in seconds as a date with the date format.
+    ///
+    /// # Parameters
+    /// - `timestamp`: The time in seconds since the Unix epoch.
+    /// - `zone`: The time zone the date is shown in.
+    ///
+    /// # Returns
+    /// The date, or `None` if no date format is set.
+    pub fn format_date(&self, timestamp: f64, zone: &TimeZone) -> Option<String> {
+        self.date_format
+            .as_deref()
+            .map(|pattern| zone.format(timestamp, pattern))
+    }
+}
@@ -0,0 +1,123 @@
+//! Loading of polygon features from GeoJSON documents.
+
+use super::json::{parse, JsonValue};
+
+/// A named region made of one or more polygons.
+///
+/// Each polygon is a list of rings: the outer boundary followed by its holes, as
+/// `(longitude, latitude)` pairs in degrees.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeoFeature {
+    /// Name of the region, used to match it with its value.
+    pub name: String,
+    /// The polygons of the region.
+    pub polygons: Vec<Vec<Vec<(f64, f64)>>>,
+}
+
+/// Reads the polygon features of a GeoJSON document.
+///
+/// Accepts a `FeatureCollection`, a single `Feature`, or a bare geometry. Only `Polygon` and
+/// `MultiPolygon` geometries are kept; features with other geometries are skipped.
+///
+/// # Parameters
+/// - `text`: The GeoJSON text.
+/// - `name_property`: The feature property holding the region name. Features without it
+///   fall back to their `id`, then to `"Feature <index>"`.
+///
+/// # Returns
+/// The polygon features in document order, or a message describing why the document could
+/// not be read.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::geojson::parse_features;
+///
+/// let text = std::fs::read_to_string("countries.geojson").unwrap();
+/// let features = parse_features(&text, "name").unwrap();
+/// ```
+pub fn parse_features(text: &str, name_property: &str) -> Result<Vec<GeoFeature>, String> {
+    let document = parse(text)?;
+    let features: Vec<&JsonValue> = match document.get("type").and_then(JsonValue::as_str) {
+        Some("FeatureCollection") => document
+            .get("features")
+            .and_then(JsonValue::as_array)
+            .ok_or("FeatureCollection without a features array")?
+            .iter()
+            .collect(),
+        Some(_) => vec![&document],
+        None => return Err("GeoJSON object without a type".to_string()),
+    };
+
+    let mut result = Vec::new();
+    for (index, feature) in features.into_iter().enumerate() {
+        let geometry = match feature.get("type").and_then(JsonValue::as_str) {
+            Some("Feature") => match feature.get("geometry") {
+                Some(geometry) => geometry,
+                None => continue,
+            },
+            _ => feature,
+        };
+        let Some(polygons) = read_polygons(geometry)? else {
+            continue;
+        };
+
+        let properties = feature.get("properties");
+        let name = [
+            properties.and_then(|p| p.get(name_property)),
+            feature.get("id"),
+        ]
+        .into_iter()
+        .flatten()
+        .find_map(|value| match value {
+            JsonValue::String(text) => Some(text.clone()),
+            JsonValue::Number(number) => Some(number.to_string()),
+            _ => None,
+        })
+        .unwrap_or_else(|| format!("Feature {index}"));
+
+        result.push(GeoFeature { name, polygons });
+    }
+    Ok(result)
+}
+
+/// Reads the polygons of a geometry, or `None` for non-polygon geometries.
+#[allow(clippy::type_complexity)]
+fn read_polygons(geometry: &JsonValue) -> Result<Option<Vec<Vec<Vec<(f64, f64)>>>>, String> {
+    let coordinates = geometry.get("coordinates");
+    match geometry.get("type").and_then(JsonValue::as_str) {
+        Some("Polygon") => Ok(Some(vec![read_polygon(coordinates)?])),
+        Some("MultiPolygon") => coordinates
+            .and_then(JsonValue::as_array)
+            .ok_or("MultiPolygon without coordinates")?
+            .iter()
+            .map(|polygon| read_polygon(Some(polygon)))
+            .collect::<Result<_, _>>()
+            .map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Reads the rings of a polygon.
+fn read_polygon(coordinates: Option<&JsonValue>) -> Result<Vec<Vec<(f64, f64)>>, String> {
+    coordinates
+        .and_then(JsonValue::as_array)
+        .ok_or("Polygon without coordinates")?
+        .iter()
+        .map(|ring| {
+            ring.as_array()
+                .ok_or("Polygon ring is not an array")?
+                .iter()
+                .map(|position| {
+                    let position = position.as_array().unwrap_or(&[]);
+                    match (
+                        position.first().and_then(JsonValue::as_f64),
+                        position.get(1).and_then(JsonValue::as_f64),
+                    ) {
+                        (Some(longitude), Some(latitude)) => Ok((longitude, latitude)),
+                        _ => Err("Position without longitude and latitude".to_string()),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
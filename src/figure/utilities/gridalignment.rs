@@ -0,0 +1,10 @@
+/// Controls where the grid lines of an axis are placed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GridAlignment {
+    /// One grid line at every tick of the axis, so grid lines and tick labels coincide.
+    Ticks,
+    /// Grid lines evenly divide the plot area, independently of the ticks. The number of
+    /// divisions is `num_grid_vertical` for the x axis and `num_grid_horizontal` for the
+    /// y axis.
+    Even,
+}
@@ -0,0 +1,9 @@
+/// Controls how a histogram draws several sample sets in the same bins.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HistogramComparison {
+    /// The bars of every set span the whole bin and are drawn semi-transparent on top of
+    /// each other.
+    Overlay,
+    /// The bins are split into one narrower bar per set, side by side.
+    Dodge,
+}
@@ -0,0 +1,13 @@
+/// Controls what the bar heights of a histogram show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HistogramNormalization {
+    /// The number of samples in each bin, or the sum of their weights.
+    Count,
+    /// The share of all samples (or of the total weight) in each bin. The bar heights sum
+    /// to 1.
+    RelativeFrequency,
+    /// The relative frequency divided by the bin width, so that the bar areas sum to 1.
+    /// Histograms with different bin widths or sample sizes can be compared on the same
+    /// axes.
+    Density,
+}
@@ -0,0 +1,147 @@
+//! Technical indicators computed from price series, for overlays on financial charts.
+//!
+//! Every indicator returns one entry per input value, `None` until enough values are
+//! available, so results can be drawn against the same x positions as the prices.
+
+/// Computes the simple moving average: the mean of the last `period` values.
+///
+/// # Parameters
+/// - `values`: The series, such as closing prices.
+/// - `period`: The number of values averaged.
+///
+/// # Returns
+/// The averages, `None` for the first `period - 1` values (or all of them if `period` is 0).
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::indicators::sma;
+///
+/// assert_eq!(sma(&[1.0, 2.0, 3.0, 4.0], 2), vec![None, Some(1.5), Some(2.5), Some(3.5)]);
+/// ```
+pub fn sma(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut averages = vec![None; values.len()];
+    if period == 0 {
+        return averages;
+    }
+
+    let mut sum = 0.0;
+    for (index, value) in values.iter().enumerate() {
+        sum += value;
+        if index >= period {
+            sum -= values[index - period];
+        }
+        if index + 1 >= period {
+            averages[index] = Some(sum / period as f64);
+        }
+    }
+    averages
+}
+
+/// Computes the exponential moving average with smoothing factor `2 / (period + 1)`.
+///
+/// The average is seeded with the simple moving average of the first `period` values.
+///
+/// # Parameters
+/// - `values`: The series, such as closing prices.
+/// - `period`: The span of the average.
+///
+/// # Returns
+/// The averages, `None` for the first `period - 1` values (or all of them if `period` is 0).
+pub fn ema(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut averages = vec![None; values.len()];
+    if period == 0 || values.len() < period {
+        return averages;
+    }
+
+    let alpha = 2.0 / (period as f64 + 1.0);
+    let mut average = values[..period].iter().sum::<f64>() / period as f64;
+    averages[period - 1] = Some(average);
+    for (index, value) in values.iter().enumerate().skip(period) {
+        average += alpha * (value - average);
+        averages[index] = Some(average);
+    }
+    averages
+}
+
+/// A point of a Bollinger band.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BollingerPoint {
+    /// The moving average minus `k` standard deviations.
+    pub lower: f64,
+    /// The simple moving average.
+    pub middle: f64,
+    /// The moving average plus `k` standard deviations.
+    pub upper: f64,
+}
+
+/// Computes Bollinger bands: the simple moving average with bands `k` population standard
+/// deviations above and below it.
+///
+/// # Parameters
+/// - `values`: The series, such as closing prices.
+/// - `period`: The number of values in the window, commonly 20.
+/// - `k`: The band width in standard deviations, commonly 2.
+///
+/// # Returns
+/// The bands, `None` for the first `period - 1` values (or all of them if `period` is 0).
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::indicators::bollinger;
+///
+/// let bands = bollinger(&closes, 20, 2.0);
+/// ```
+pub fn bollinger(values: &[f64], period: usize, k: f64) -> Vec<Option<BollingerPoint>> {
+    sma(values, period)
+        .into_iter()
+        .enumerate()
+        .map(|(index, middle)| {
+            let middle = middle?;
+            let window = &values[index + 1 - period..=index];
+            let variance = window
+                .iter()
+                .map(|value| (value - middle).powi(2))
+                .sum::<f64>()
+                / period as f64;
+            let deviation = variance.sqrt();
+            Some(BollingerPoint {
+                lower: middle - k * deviation,
+                middle,
+                upper: middle + k * deviation,
+            })
+        })
+        .collect()
+}
+
+/// A moving average drawn as a built-in overlay of a candlestick chart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MovingAverage {
+    /// The simple moving average over the given number of values, see `sma`.
+    Simple(usize),
+    /// The exponential moving average with the given span, see `ema`.
+    Exponential(usize),
+}
+
+impl MovingAverage {
+    /// Computes the average of a series.
+    ///
+    /// # Parameters
+    /// - `values`: The series, such as closing prices.
+    ///
+    /// # Returns
+    /// One entry per value, `None` until the period is filled.
+    pub fn compute(&self, values: &[f64]) -> Vec<Option<f64>> {
+        match *self {
+            MovingAverage::Simple(period) => sma(values, period),
+            MovingAverage::Exponential(period) => ema(values, period),
+        }
+    }
+
+    /// Returns the legend label of the average, e.g. `"SMA 20"`.
+    pub fn label(&self) -> String {
+        match self {
+            MovingAverage::Simple(period) => format!("SMA {period}"),
+            MovingAverage::Exponential(period) => format!("EMA {period}"),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+/// How a line series connects consecutive points.
+///
+/// Step modes draw staircases of horizontal and vertical runs, as used for monitoring data
+/// whose values hold until the next sample.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Straight lines between the points.
+    #[default]
+    Linear,
+    /// The value changes at the start of each interval: vertical first, then horizontal.
+    StepPre,
+    /// The value holds until the next point: horizontal first, then vertical.
+    StepPost,
+    /// The value changes halfway between the points.
+    StepMid,
+}
+
+impl Interpolation {
+    /// Expands a polyline into the vertices drawn for this interpolation.
+    ///
+    /// # Parameters
+    /// - `points`: The `(x, y)` points of the polyline, in drawing order.
+    ///
+    /// # Returns
+    /// The vertices of the drawn line; the points themselves for `Linear`, with the corner
+    /// vertices of the steps inserted otherwise.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::interpolation::Interpolation;
+    ///
+    /// let steps = Interpolation::StepPost.apply(&[(0.0, 1.0), (1.0, 3.0)]);
+    /// assert_eq!(steps, vec![(0.0, 1.0), (1.0, 1.0), (1.0, 3.0)]);
+    /// ```
+    pub fn apply(&self, points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+        if *self == Interpolation::Linear || points.len() < 2 {
+            return points.to_vec();
+        }
+        let mut vertices = Vec::with_capacity(points.len() * 3);
+        vertices.push(points[0]);
+        for window in points.windows(2) {
+            let ((x1, y1), (x2, y2)) = (window[0], window[1]);
+            match self {
+                Interpolation::StepPre => vertices.push((x1, y2)),
+                Interpolation::StepPost => vertices.push((x2, y1)),
+                Interpolation::StepMid => {
+                    let x = (x1 + x2) / 2.0;
+                    vertices.push((x, y1));
+                    vertices.push((x, y2));
+                }
+                Interpolation::Linear => {}
+            }
+            vertices.push((x2, y2));
+        }
+        vertices
+    }
+}
+
+/// Smooths a line through points with a monotone cubic (Fritsch–Carlson) curve.
+///
+/// The curve passes through every point and never overshoots between two of them, so a
+/// line of non-negative values stays non-negative and flat runs stay flat.
+///
+/// # Parameters
+/// - `points`: The `(x, y)` points, ordered by increasing x.
+/// - `samples_per_interval`: The number of vertices per interval between points.
+///
+/// # Returns
+/// The vertices of the curve, starting at the first point and ending at the last one. Fewer
+/// than three points are returned unchanged.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::interpolation::monotone_cubic;
+///
+/// let curve = monotone_cubic(&[(0.0, 0.0), (1.0, 2.0), (2.0, 2.0)], 8);
+/// assert_eq!(curve.len(), 17);
+/// ```
+pub fn monotone_cubic(points: &[(f64, f64)], samples_per_interval: usize) -> Vec<(f64, f64)> {
+    if points.len() < 3 || samples_per_interval < 2 {
+        return points.to_vec();
+    }
+    let slopes: Vec<f64> = points
+        .windows(2)
+        .map(|pair| {
+            let dx = pair[1].0 - pair[0].0;
+            if dx == 0.0 {
+                0.0
+            } else {
+                (pair[1].1 - pair[0].1) / dx
+            }
+        })
+        .collect();
+
+    // Tangents at the points, zero at local extrema and limited to avoid overshoots
+    let mut tangents = vec![0.0; points.len()];
+    tangents[0] = slopes[0];
+    tangents[points.len() - 1] = slopes[slopes.len() - 1];
+    for index in 1..points.len() - 1 {
+        let (before, after) = (slopes[index - 1], slopes[index]);
+        if before * after > 0.0 {
+            tangents[index] = (before + after) / 2.0;
+        }
+    }
+    for (index, &slope) in slopes.iter().enumerate() {
+        if slope == 0.0 {
+            tangents[index] = 0.0;
+            tangents[index + 1] = 0.0;
+            continue;
+        }
+        let (a, b) = (tangents[index] / slope, tangents[index + 1] / slope);
+        let length = a.hypot(b);
+        if length > 3.0 {
+            tangents[index] = 3.0 * a / length * slope;
+            tangents[index + 1] = 3.0 * b / length * slope;
+        }
+    }
+
+    let mut vertices = Vec::with_capacity(slopes.len() * samples_per_interval + 1);
+    for (index, pair) in points.windows(2).enumerate() {
+        let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+        let dx = x1 - x0;
+        for sample in 0..samples_per_interval {
+            let t = sample as f64 / samples_per_interval as f64;
+            let (t2, t3) = (t * t, t * t * t);
+            let y = (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+                + (t3 - 2.0 * t2 + t) * dx * tangents[index]
+                + (-2.0 * t3 + 3.0 * t2) * y1
+                + (t3 - t2) * dx * tangents[index + 1];
+            vertices.push((x0 + t * dx, y));
+        }
+    }
+    vertices.push(points[points.len() - 1]);
+    vertices
+}
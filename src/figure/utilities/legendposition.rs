@@ -0,0 +1,12 @@
+/// Controls on which side of the plot a legend is placed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LegendPosition {
+    /// The legend is laid out in rows above the plot.
+    Top,
+    /// The legend is laid out in rows below the plot.
+    Bottom,
+    /// The legend is a column left of the plot.
+    Left,
+    /// The legend is a column right of the plot.
+    Right,
+}
@@ -0,0 +1,90 @@
+//! Legend entries with value summaries, as shown by monitoring dashboards.
+
+use crate::figure::utilities::{
+    formatcontext::FormatContext,
+    statistics::{mean, quantile, std_dev},
+};
+
+/// Formats a legend entry from a format string and the y values of a dataset.
+///
+/// The placeholders are `{label}`, `{last}`, `{mean}`, `{min}`, `{max}`, `{count}`,
+/// `{median}`, `{std}` (sample standard deviation) and percentiles such as `{p95}`.
+/// Numbers are shown with 2 decimals unless the placeholder names a precision, as in
+/// `{mean:.1}`. Without values, numeric placeholders show `n/a`. Unknown placeholders and
+/// unmatched braces are kept as they are. Numbers are localized with `context`.
+///
+/// # Parameters
+/// - `format`: The format string, e.g. `"{label} (last {last}, avg {mean:.1})"`.
+/// - `label`: The label of the dataset.
+/// - `values`: The y values of the dataset, in point order; the last one is `{last}`.
+/// - `context`: The format context of the figure.
+///
+/// # Returns
+/// The legend text.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::{formatcontext::FormatContext, legendsummary::format_legend};
+///
+/// let values = [12.0, 48.4, 30.25];
+/// let text = format_legend("{label}: {last} (max {max:.0})", "CPU", &values, &FormatContext::default());
+/// assert_eq!(text, "CPU: 30.25 (max 48)");
+/// ```
+pub fn format_legend(format: &str, label: &str, values: &[f64], context: &FormatContext) -> String {
+    let finite: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    let mut text = String::new();
+    let mut rest = format;
+
+    while let Some(start) = rest.find('{') {
+        let Some(length) = rest[start..].find('}') else {
+            break;
+        };
+        text.push_str(&rest[..start]);
+        let placeholder = &rest[start + 1..start + length];
+        match summary(placeholder, label, &finite, context) {
+            Some(value) => text.push_str(&value),
+            None => text.push_str(&rest[start..=start + length]),
+        }
+        rest = &rest[start + length + 1..];
+    }
+    text.push_str(rest);
+    text
+}
+
+/// Returns the text of one placeholder, or `None` if it is unknown.
+fn summary(
+    placeholder: &str,
+    label: &str,
+    values: &[f64],
+    context: &FormatContext,
+) -> Option<String> {
+    let (name, precision) = match placeholder.split_once(":.") {
+        Some((name, precision)) => (name, precision.parse().ok()?),
+        None => (placeholder, 2),
+    };
+    let value = match name {
+        "label" => return Some(label.to_string()),
+        "count" => return Some(context.localize(&values.len().to_string())),
+        "last" => values.last().copied(),
+        "mean" => mean(values),
+        "min" => values.iter().copied().reduce(f64::min),
+        "max" => values.iter().copied().reduce(f64::max),
+        "std" => std_dev(values),
+        "median" => percentile(values, 50.0),
+        _ => match name.strip_prefix('p').and_then(|p| p.parse::<f64>().ok()) {
+            Some(p) if (0.0..=100.0).contains(&p) => percentile(values, p),
+            _ => return None,
+        },
+    };
+    Some(match value {
+        Some(value) => context.format_number(value, precision),
+        None => "n/a".to_string(),
+    })
+}
+
+/// Computes a percentile between `0` and `100` of the values.
+fn percentile(values: &[f64], p: f64) -> Option<f64> {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    quantile(&sorted, p / 100.0)
+}
@@ -0,0 +1,10 @@
+/// The rule used to measure the distance between two clusters in hierarchical clustering.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Linkage {
+    /// The distance between the closest members of the clusters.
+    Single,
+    /// The distance between the farthest members of the clusters.
+    Complete,
+    /// The mean distance between all pairs of members (UPGMA).
+    Average,
+}
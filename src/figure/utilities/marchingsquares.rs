@@ -0,0 +1,130 @@
+//! Extraction of iso-lines from gridded scalar fields with the marching squares algorithm.
+
+/// Computes the line segments where a gridded field crosses a level.
+///
+/// Each grid cell is classified by which of its four corners lie at or above the level, and
+/// the crossings on its edges are found by linear interpolation between the corner values.
+/// Saddle cells, where diagonally opposite corners are on the same side, are resolved by
+/// the average of the four corners.
+///
+/// # Parameters
+/// - `values`: The field, indexed as `[row][column]`. Cells with a missing or non-finite
+///   corner are skipped.
+/// - `level`: The value of the iso-line.
+///
+/// # Returns
+/// The segments as pairs of `(column, row)` grid positions, in no particular order.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::marchingsquares::iso_segments;
+///
+/// let field = vec![vec![0.0, 1.0], vec![1.0, 2.0]];
+/// let segments = iso_segments(&field, 1.0);
+/// ```
+pub fn iso_segments(values: &[Vec<f64>], level: f64) -> Vec<[(f64, f64); 2]> {
+    let mut segments = Vec::new();
+    for row in 0..values.len().saturating_sub(1) {
+        let columns = values[row].len().min(values[row + 1].len());
+        for column in 0..columns.saturating_sub(1) {
+            // Corners counterclockwise from the cell origin
+            let corners = [
+                ((column as f64, row as f64), values[row][column]),
+                ((column as f64 + 1.0, row as f64), values[row][column + 1]),
+                (
+                    (column as f64 + 1.0, row as f64 + 1.0),
+                    values[row + 1][column + 1],
+                ),
+                ((column as f64, row as f64 + 1.0), values[row + 1][column]),
+            ];
+            if corners.iter().any(|(_, value)| !value.is_finite()) {
+                continue;
+            }
+            cell_segments(&corners, level, &mut segments);
+        }
+    }
+    segments
+}
+
+/// Adds the segments of one grid cell.
+///
+/// # Parameters
+/// - `corners`: The grid positions and values of the corners, in order around the cell.
+/// - `level`: The value of the iso-line.
+/// - `segments`: The segments found so far.
+fn cell_segments(
+    corners: &[((f64, f64), f64); 4],
+    level: f64,
+    segments: &mut Vec<[(f64, f64); 2]>,
+) {
+    let above = corners.map(|(_, value)| value >= level);
+
+    // Crossing of edge `i`, which runs from corner `i` to the next corner
+    let crossing = |edge: usize| -> (f64, f64) {
+        let ((x1, y1), v1) = corners[edge];
+        let ((x2, y2), v2) = corners[(edge + 1) % 4];
+        let t = if v2 != v1 {
+            ((level - v1) / (v2 - v1)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        (x1 + t * (x2 - x1), y1 + t * (y2 - y1))
+    };
+    let crossed: Vec<usize> = (0..4)
+        .filter(|&edge| above[edge] != above[(edge + 1) % 4])
+        .collect();
+
+    match crossed.as_slice() {
+        [first, second] => segments.push([crossing(*first), crossing(*second)]),
+        [_, _, _, _] => {
+            // Saddle: if the center is on the side of corner 0, corners 0 and 2 connect
+            // through it and the line cuts off corners 1 and 3, otherwise corners 0 and 2
+            let center = corners.iter().map(|(_, value)| value).sum::<f64>() / 4.0;
+            if (center >= level) == above[0] {
+                segments.push([crossing(0), crossing(1)]);
+                segments.push([crossing(2), crossing(3)]);
+            } else {
+                segments.push([crossing(3), crossing(0)]);
+                segments.push([crossing(1), crossing(2)]);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Samples a gridded field between its grid points by bilinear interpolation.
+///
+/// # Parameters
+/// - `values`: The field, indexed as `[row][column]`.
+/// - `column`, `row`: The fractional grid position.
+///
+/// # Returns
+/// The interpolated value, or `NaN` outside the grid or next to a missing value.
+pub fn bilinear(values: &[Vec<f64>], column: f64, row: f64) -> f64 {
+    if !(column >= 0.0 && row >= 0.0) {
+        return f64::NAN;
+    }
+    let (c0, r0) = (column.floor() as usize, row.floor() as usize);
+    let value = |r: usize, c: usize| -> f64 {
+        values
+            .get(r)
+            .and_then(|cells| cells.get(c))
+            .copied()
+            .unwrap_or(f64::NAN)
+    };
+    // Points on the last row or column interpolate within the cell before them
+    let c0 = if c0 > 0 && value(r0, c0 + 1).is_nan() && column == c0 as f64 {
+        c0 - 1
+    } else {
+        c0
+    };
+    let r0 = if r0 > 0 && value(r0 + 1, c0).is_nan() && row == r0 as f64 {
+        r0 - 1
+    } else {
+        r0
+    };
+    let (fx, fy) = (column - c0 as f64, row - r0 as f64);
+    let bottom = value(r0, c0) * (1.0 - fx) + value(r0, c0 + 1) * fx;
+    let top = value(r0 + 1, c0) * (1.0 - fx) + value(r0 + 1, c0 + 1) * fx;
+    bottom * (1.0 - fy) + top * fy
+}
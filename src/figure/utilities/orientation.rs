@@ -1,4 +1,5 @@
 /// Represents the orientation of a chart, graph, or component.
+#[derive(Clone)]
 pub enum Orientation {
     /// Vertical orientation, typically used for charts where data is arranged along a vertical axis.
     Vertical,
@@ -0,0 +1,65 @@
+//! Point packing used to lay out markers without overlap.
+
+/// Computes beeswarm offsets for markers placed along a single axis.
+///
+/// Markers are placed one by one, from the smallest position to the largest. Each one gets the
+/// offset closest to zero that keeps it at least `diameter` away from every marker placed
+/// before it, so markers never overlap and stay as close to the center line as possible.
+///
+/// # Parameters
+/// - `positions`: Marker positions along the value axis, in pixels, in any order.
+/// - `diameter`: Minimum distance between marker centers, in pixels.
+///
+/// # Returns
+/// The offset perpendicular to the value axis of each marker, in pixels, in the order of
+/// `positions`.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::packing::beeswarm_offsets;
+///
+/// // Three markers at the same position are placed side by side.
+/// let offsets = beeswarm_offsets(&[10.0, 10.0, 10.0], 4.0);
+/// assert_eq!(offsets, vec![0.0, 4.0, -4.0]);
+/// ```
+pub fn beeswarm_offsets(positions: &[f64], diameter: f64) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..positions.len()).collect();
+    order.sort_by(|&a, &b| positions[a].total_cmp(&positions[b]));
+
+    let mut offsets = vec![0.0; positions.len()];
+    let mut placed: Vec<(f64, f64)> = Vec::with_capacity(positions.len());
+    let min_distance_sq = diameter * diameter;
+
+    for index in order {
+        let position = positions[index];
+
+        // Only markers closer than `diameter` along the axis can collide
+        let neighbours: Vec<(f64, f64)> = placed
+            .iter()
+            .copied()
+            .filter(|&(p, _)| (position - p).abs() < diameter)
+            .collect();
+
+        let mut candidates = vec![0.0];
+        for &(p, o) in &neighbours {
+            let reach = (min_distance_sq - (position - p).powi(2)).sqrt();
+            candidates.push(o + reach);
+            candidates.push(o - reach);
+        }
+        candidates.sort_by(|a: &f64, b| a.abs().total_cmp(&b.abs()).then(b.total_cmp(a)));
+
+        let offset = candidates
+            .into_iter()
+            .find(|&candidate| {
+                neighbours.iter().all(|&(p, o)| {
+                    (position - p).powi(2) + (candidate - o).powi(2) >= min_distance_sq - 1e-9
+                })
+            })
+            .unwrap_or(0.0);
+
+        offsets[index] = offset;
+        placed.push((position, offset));
+    }
+
+    offsets
+}
@@ -0,0 +1,10 @@
+/// Controls how the percent sign is written next to a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PercentStyle {
+    /// The sign follows the number directly (e.g., `"45%"`).
+    Attached,
+    /// The sign follows the number after a space (e.g., `"45 %"`).
+    Spaced,
+    /// The sign precedes the number, including its sign (e.g., `"%45"` and `"%-3"`).
+    Leading,
+}
@@ -0,0 +1,72 @@
+/// The map projection used to turn longitude and latitude into plane coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Projection {
+    /// Plots longitude and latitude directly; simple, but stretches areas away from the equator.
+    Equirectangular,
+    /// Web Mercator; preserves shapes locally but inflates areas towards the poles.
+    /// Latitudes are clamped to ±85° to keep the projection finite.
+    Mercator,
+}
+
+impl Projection {
+    /// Projects a geographic coordinate.
+    ///
+    /// # Parameters
+    /// - `longitude`, `latitude`: The coordinate in degrees.
+    ///
+    /// # Returns
+    /// The projected `(x, y)`, with `y` increasing northwards.
+    pub fn project(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        match self {
+            Projection::Equirectangular => (longitude, latitude),
+            Projection::Mercator => {
+                let phi = latitude.clamp(-85.0, 85.0).to_radians();
+                (
+                    longitude,
+                    (std::f64::consts::FRAC_PI_4 + phi / 2.0)
+                        .tan()
+                        .ln()
+                        .to_degrees(),
+                )
+            }
+        }
+    }
+
+    /// Inverts the projection.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: A projected coordinate, as returned by `project`.
+    ///
+    /// # Returns
+    /// The `(longitude, latitude)` in degrees.
+    pub fn unproject(&self, x: f64, y: f64) -> (f64, f64) {
+        match self {
+            Projection::Equirectangular => (x, y),
+            Projection::Mercator => (
+                x,
+                (2.0 * y.to_radians().exp().atan() - std::f64::consts::FRAC_PI_2).to_degrees(),
+            ),
+        }
+    }
+}
+
+/// Fits projected bounds into a rectangular canvas area, preserving the aspect ratio and
+/// centering the result.
+///
+/// # Parameters
+/// - `bounds`: The projected bounds `(x_min, x_max, y_min, y_max)`.
+/// - `area`: The canvas area `(left, top, width, height)` in pixels.
+///
+/// # Returns
+/// A tuple `(scale, origin_x, origin_y)`: a projected point `(x, y)` is drawn at
+/// `(origin_x + x * scale, origin_y - y * scale)`.
+pub fn fit_bounds(bounds: (f64, f64, f64, f64), area: (f64, f64, f64, f64)) -> (f64, f64, f64) {
+    let (x_min, x_max, y_min, y_max) = bounds;
+    let (left, top, width, height) = area;
+    let scale =
+        (width / (x_max - x_min).max(f64::EPSILON)).min(height / (y_max - y_min).max(f64::EPSILON));
+
+    let origin_x = left + (width - (x_max - x_min) * scale) / 2.0 - x_min * scale;
+    let origin_y = top + (height + (y_max - y_min) * scale) / 2.0 + y_min * scale;
+    (scale, origin_x, origin_y)
+}
@@ -0,0 +1,52 @@
+/// A small, seedable pseudo-random number generator (SplitMix64).
+///
+/// Figures use it wherever randomness affects the layout, such as jitter, so the same
+/// seed always renders the same image. It is not suitable for cryptographic use.
+#[derive(Clone, Debug)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    /// Creates a new generator from a seed.
+    ///
+    /// # Parameters
+    /// - `seed`: The seed. Equal seeds produce equal sequences.
+    ///
+    /// # Returns
+    /// A new `SeededRng`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::random::SeededRng;
+    ///
+    /// let mut rng = SeededRng::new(42);
+    /// let offset = rng.range(-0.5, 0.5);
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniformly distributed value in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a uniformly distributed value in `[min, max)`.
+    ///
+    /// # Parameters
+    /// - `min`: The lower bound (inclusive).
+    /// - `max`: The upper bound (exclusive).
+    pub fn range(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.next_f64()
+    }
+}
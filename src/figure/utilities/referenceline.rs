@@ -0,0 +1,35 @@
+use crate::figure::datasets::dataset::DatasetStatistics;
+
+/// A horizontal line marking a statistic of a dataset's values, drawn in the dataset's color
+/// with a small label, e.g. for quick-look monitoring charts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReferenceLine {
+    /// A line at the arithmetic mean of the y values.
+    Mean,
+    /// A line at the median of the y values.
+    Median,
+}
+
+impl ReferenceLine {
+    /// Returns the name of the statistic, used in the line label.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReferenceLine::Mean => "mean",
+            ReferenceLine::Median => "median",
+        }
+    }
+
+    /// Computes the value the line is drawn at.
+    ///
+    /// # Parameters
+    /// - `dataset`: The dataset whose values are summarized.
+    ///
+    /// # Returns
+    /// The statistic, or `None` if the dataset has no finite values.
+    pub fn value<D: DatasetStatistics + ?Sized>(&self, dataset: &D) -> Option<f64> {
+        match self {
+            ReferenceLine::Mean => dataset.mean(),
+            ReferenceLine::Median => dataset.median(),
+        }
+    }
+}
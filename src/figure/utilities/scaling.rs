@@ -0,0 +1,218 @@
+//! Scaling and tick helpers shared by the built-in drawers.
+//!
+//! These functions are part of the extension API: a custom `Drawer` can use them to map
+//! data onto a canvas exactly like the built-in figures do, instead of copying their internals.
+
+/// Computes the bounding box of a set of points.
+///
+/// # Parameters
+/// - `points`: The `(x, y)` points to measure.
+///
+/// # Returns
+/// `Some((x_min, x_max, y_min, y_max))`, or `None` if there are no points.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::scaling::data_bounds;
+///
+/// let bounds = data_bounds([(1.0, 5.0), (-2.0, 3.0)]);
+/// assert_eq!(bounds, Some((-2.0, 1.0, 3.0, 5.0)));
+/// ```
+pub fn data_bounds<I>(points: I) -> Option<(f64, f64, f64, f64)>
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    points
+        .into_iter()
+        .fold(None, |bounds, (x, y)| match bounds {
+            None => Some((x, x, y, y)),
+            Some((x_min, x_max, y_min, y_max)) => {
+                Some((x_min.min(x), x_max.max(x), y_min.min(y), y_max.max(y)))
+            }
+        })
+}
+
+/// Widens a range so it is symmetric around zero.
+///
+/// The bound with the larger magnitude is mirrored, so `(-2.0, 5.0)` becomes `(-5.0, 5.0)`.
+///
+/// # Parameters
+/// - `min`: The lower bound of the range.
+/// - `max`: The upper bound of the range.
+///
+/// # Returns
+/// The symmetric `(min, max)` range.
+pub fn symmetric_range(min: f64, max: f64) -> (f64, f64) {
+    let abs_min = min.abs();
+    let abs_max = max.abs();
+
+    if abs_min > abs_max {
+        (min, abs_min)
+    } else {
+        (-abs_max, max)
+    }
+}
+
+/// Widens one of two ranges so that one y unit is drawn `aspect` times as long as one x unit.
+///
+/// The range that would be drawn at the larger scale is widened. Ranges containing zero are
+/// scaled about zero, so axes through the origin stay in place; other ranges are widened
+/// about their midpoint.
+///
+/// # Parameters
+/// - `x_range`: The `(min, max)` range of the x-axis.
+/// - `y_range`: The `(min, max)` range of the y-axis.
+/// - `plot_width`, `plot_height`: The size of the plot area in pixels.
+/// - `aspect`: The length of one y unit relative to one x unit, 1.0 for equal scales.
+///
+/// # Returns
+/// The `(x_range, y_range)` pair. Empty ranges, empty plot areas and non-positive aspects
+/// leave both ranges unchanged.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::scaling::lock_aspect;
+///
+/// // A 400 x 200 plot area shows 4 x units for every 2 y units at equal scale
+/// let ranges = lock_aspect((0.0, 2.0), (0.0, 2.0), 400.0, 200.0, 1.0);
+/// assert_eq!(ranges, ((0.0, 4.0), (0.0, 2.0)));
+/// ```
+pub fn lock_aspect(
+    x_range: (f64, f64),
+    y_range: (f64, f64),
+    plot_width: f64,
+    plot_height: f64,
+    aspect: f64,
+) -> ((f64, f64), (f64, f64)) {
+    let scale_x = scale_factor(x_range.0, x_range.1, plot_width);
+    let scale_y = scale_factor(y_range.0, y_range.1, plot_height);
+    if scale_x <= 0.0 || scale_y <= 0.0 || aspect <= 0.0 || !aspect.is_finite() {
+        return (x_range, y_range);
+    }
+
+    let widen = |(min, max): (f64, f64), factor: f64| {
+        let anchor = if min <= 0.0 && max >= 0.0 {
+            0.0
+        } else {
+            (min + max) / 2.0
+        };
+        (
+            anchor + (min - anchor) * factor,
+            anchor + (max - anchor) * factor,
+        )
+    };
+
+    let ratio = scale_y / (aspect * scale_x);
+    if ratio > 1.0 {
+        (x_range, widen(y_range, ratio))
+    } else {
+        (widen(x_range, 1.0 / ratio), y_range)
+    }
+}
+
+/// Computes the number of pixels per data unit for a range drawn across `pixel_span` pixels.
+///
+/// # Parameters
+/// - `min`: The lower bound of the data range.
+/// - `max`: The upper bound of the data range.
+/// - `pixel_span`: The length of the axis in pixels.
+///
+/// # Returns
+/// The scale factor, or `0.0` if the range is empty.
+pub fn scale_factor(min: f64, max: f64, pixel_span: f64) -> f64 {
+    let range = max - min;
+    if range == 0.0 || !range.is_finite() {
+        0.0
+    } else {
+        pixel_span / range
+    }
+}
+
+/// Maps a value onto the horizontal pixel axis, starting at the left margin.
+///
+/// # Parameters
+/// - `value`: The data value.
+/// - `min`: The lower bound of the data range.
+/// - `scale`: Pixels per data unit (see `scale_factor`).
+/// - `margin`: The left margin in pixels.
+pub fn to_pixel_x(value: f64, min: f64, scale: f64, margin: f64) -> f64 {
+    margin + (value - min) * scale
+}
+
+/// Maps a value onto the vertical pixel axis, growing upwards from the bottom margin.
+///
+/// # Parameters
+/// - `value`: The data value.
+/// - `min`: The lower bound of the data range.
+/// - `scale`: Pixels per data unit (see `scale_factor`).
+/// - `height`: The canvas height in pixels.
+/// - `margin`: The bottom margin in pixels.
+pub fn to_pixel_y(value: f64, min: f64, scale: f64, height: f64, margin: f64) -> f64 {
+    height - margin - (value - min) * scale
+}
+
+/// Computes evenly spaced tick values covering a range, including both ends.
+///
+/// # Parameters
+/// - `min`: The first tick value.
+/// - `max`: The last tick value.
+/// - `num_ticks`: The number of intervals between ticks.
+///
+/// # Returns
+/// `num_ticks + 1` tick values from `min` to `max`.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::scaling::tick_values;
+///
+/// assert_eq!(tick_values(0.0, 10.0, 2), vec![0.0, 5.0, 10.0]);
+/// ```
+pub fn tick_values(min: f64, max: f64, num_ticks: usize) -> Vec<f64> {
+    let num_ticks = num_ticks.max(1);
+    (0..=num_ticks)
+        .map(|i| min + i as f64 * (max - min) / num_ticks as f64)
+        .collect()
+}
+
+/// Clips a line segment to a rectangle (Liang–Barsky).
+///
+/// # Parameters
+/// - `start`, `end`: The segment end points.
+/// - `area`: The rectangle as `(left, top, right, bottom)`.
+///
+/// # Returns
+/// The visible part of the segment, or `None` if it lies entirely outside.
+pub fn clip_segment(
+    start: (f64, f64),
+    end: (f64, f64),
+    area: (f64, f64, f64, f64),
+) -> Option<((f64, f64), (f64, f64))> {
+    let (left, top, right, bottom) = area;
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let (mut t0, mut t1) = (0.0_f64, 1.0_f64);
+
+    for (p, q) in [
+        (-dx, start.0 - left),
+        (dx, right - start.0),
+        (-dy, start.1 - top),
+        (dy, bottom - start.1),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+
+    (t0 <= t1).then_some((
+        (start.0 + t0 * dx, start.1 + t0 * dy),
+        (start.0 + t1 * dx, start.1 + t1 * dy),
+    ))
+}
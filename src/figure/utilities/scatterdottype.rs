@@ -1,3 +1,5 @@
+use crate::figure::canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas};
+
 /// Represents the shape and size of scatter plot points.
 #[derive(Clone)]
 pub enum ScatterDotType {
@@ -14,3 +16,93 @@ pub enum ScatterDotType {
     /// - The `u32` value specifies the base width of the triangle in pixels.
     Triangle(u32),
 }
+
+impl ScatterDotType {
+    /// Draws a dot of this shape on a pixel canvas.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw on.
+    /// - `x`, `y`: The center of the dot in pixels.
+    /// - `color`: The RGB color of the dot.
+    pub fn draw(&self, canvas: &mut PixelCanvas, x: i32, y: i32, color: [u8; 3]) {
+        match *self {
+            ScatterDotType::Circle(radius) => {
+                for dy in -(radius as i32)..=radius as i32 {
+                    for dx in -(radius as i32)..=radius as i32 {
+                        if dx * dx + dy * dy <= (radius * radius) as i32 {
+                            canvas.draw_pixel((x + dx) as u32, (y + dy) as u32, color);
+                        }
+                    }
+                }
+            }
+            ScatterDotType::Square(size) => {
+                for dy in -(size as i32) / 2..=(size as i32) / 2 {
+                    for dx in -(size as i32) / 2..=(size as i32) / 2 {
+                        canvas.draw_pixel((x + dx) as u32, (y + dy) as u32, color);
+                    }
+                }
+            }
+            ScatterDotType::Cross(thickness) => {
+                for i in -(thickness as i32)..=(thickness as i32) {
+                    canvas.draw_pixel((x + i) as u32, y as u32, color); // Horizontal line
+                    canvas.draw_pixel(x as u32, (y + i) as u32, color); // Vertical line
+                }
+            }
+            ScatterDotType::Triangle(base) => {
+                for dy in 0..=base as i32 {
+                    let dx = (base as f64 * (1.0 - dy as f64 / base as f64)) as i32;
+                    for x_offset in -dx..=dx {
+                        canvas.draw_pixel((x + x_offset) as u32, (y - dy) as u32, color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draws a dot of this shape on an SVG canvas.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The `SvgCanvas` to draw on.
+    /// - `x`, `y`: The center of the dot.
+    /// - `color`: The RGB color of the dot.
+    pub fn draw_svg(&self, svg_canvas: &mut SvgCanvas, x: f64, y: f64, color: [u8; 3]) {
+        let fill = format!("rgb({},{},{})", color[0], color[1], color[2]);
+        match *self {
+            ScatterDotType::Circle(radius) => {
+                svg_canvas.draw_circle(x, y, radius as f64, &fill);
+            }
+            ScatterDotType::Square(side) => {
+                let half_side = side as f64 / 2.0;
+                svg_canvas.draw_rect(
+                    x - half_side,
+                    y - half_side,
+                    side as f64,
+                    side as f64,
+                    &fill,
+                    "none",
+                    1.0,
+                    1.0,
+                );
+            }
+            ScatterDotType::Cross(thickness) => {
+                let arm = thickness as f64;
+                svg_canvas.draw_line(x - arm, y, x + arm, y, &fill, 2.0);
+                svg_canvas.draw_line(x, y - arm, x, y + arm, &fill, 2.0);
+            }
+            ScatterDotType::Triangle(base_size) => {
+                let half_base = base_size as f64 / 2.0;
+                let height = base_size as f64 * 0.866; // Height of an equilateral triangle
+                svg_canvas.elements.push(format!(
+                    r#"<polygon points="{:.2},{:.2} {:.2},{:.2} {:.2},{:.2}" fill="{}"/>"#,
+                    x,
+                    y - height / 2.0,
+                    x - half_base,
+                    y + height / 2.0,
+                    x + half_base,
+                    y + height / 2.0,
+                    fill
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use crate::figure::utilities::{scaling::tick_values, tickprecision::TickPrecision};
+
+/// Converts a value between the units of the primary and the secondary axis.
+pub type AxisTransform = Arc<dyn Fn(f64) -> f64 + Send + Sync>;
+
+/// A second y-axis on the right of the plot, showing the primary axis in other units
+/// (e.g. °C on the left and °F on the right).
+///
+/// The secondary axis has no data of its own: its values are derived from the primary
+/// axis through a pair of inverse, monotonic transforms.
+#[derive(Clone)]
+pub struct SecondaryAxis {
+    /// Label of the axis, e.g. `"Temperature (°F)"`.
+    pub label: String,
+    /// Converts a primary axis value into secondary units.
+    pub to_secondary: AxisTransform,
+    /// Converts a secondary axis value back into primary units, to place its ticks.
+    pub to_primary: AxisTransform,
+    /// Decimals of the tick labels.
+    pub precision: TickPrecision,
+}
+
+impl SecondaryAxis {
+    /// Creates a secondary axis from a pair of transforms.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the axis.
+    /// - `to_secondary`: Converts primary values into secondary units.
+    /// - `to_primary`: The inverse of `to_secondary`.
+    ///
+    /// # Returns
+    /// A `SecondaryAxis` with `TickPrecision::Auto` tick labels.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::secondaryaxis::SecondaryAxis;
+    ///
+    /// config.set_secondary_axis(SecondaryAxis::new(
+    ///     "°F",
+    ///     |celsius| celsius * 9.0 / 5.0 + 32.0,
+    ///     |fahrenheit| (fahrenheit - 32.0) * 5.0 / 9.0,
+    /// ));
+    /// ```
+    pub fn new<F, I>(label: &str, to_secondary: F, to_primary: I) -> Self
+    where
+        F: Fn(f64) -> f64 + Send + Sync + 'static,
+        I: Fn(f64) -> f64 + Send + Sync + 'static,
+    {
+        Self {
+            label: label.to_string(),
+            to_secondary: Arc::new(to_secondary),
+            to_primary: Arc::new(to_primary),
+            precision: TickPrecision::Auto,
+        }
+    }
+
+    /// Computes the ticks of the secondary axis, evenly spaced in secondary units.
+    ///
+    /// # Parameters
+    /// - `primary_min`, `primary_max`: The range of the primary axis.
+    /// - `num_ticks`: The number of intervals between ticks.
+    ///
+    /// # Returns
+    /// `(position, label)` pairs, where `position` is in primary units. Ticks whose
+    /// position falls outside the primary range or is not finite are left out.
+    pub fn ticks(
+        &self,
+        primary_min: f64,
+        primary_max: f64,
+        num_ticks: usize,
+    ) -> Vec<(f64, String)> {
+        let start = (self.to_secondary)(primary_min);
+        let end = (self.to_secondary)(primary_max);
+        if !start.is_finite() || !end.is_finite() {
+            return Vec::new();
+        }
+        let values = tick_values(start.min(end), start.max(end), num_ticks);
+        let labels = self.precision.format_all(&values);
+        let (low, high) = (primary_min.min(primary_max), primary_min.max(primary_max));
+        let tolerance = (high - low) * 1e-9;
+        values
+            .into_iter()
+            .zip(labels)
+            .map(|(value, label)| ((self.to_primary)(value), label))
+            .filter(|(position, _)| {
+                position.is_finite() && (low - tolerance..=high + tolerance).contains(position)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,54 @@
+//! Showing and hiding the series of a figure by their legend label.
+
+/// Shows or hides the series of a figure by label, e.g. to render variants of the same
+/// figure with and without a series.
+///
+/// Hidden series are not drawn, not listed in the legend, and do not count towards the axis
+/// ranges, which are fitted to the visible series. Implemented by `CartesianGraph`,
+/// `StemPlot`, `Quadrant1Graph`, `PolarGraph`, `ScatterGraph`, `AreaChart`,
+/// `GroupBarChart`, `RadarChart` and `DensityPlot`. Figures whose legend does not list
+/// series of their own, such as the comparison sets of a `Histogram` or the overlays of a
+/// `CandlestickChart`, don't support it.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::seriesvisibility::SeriesVisibility;
+///
+/// graph.set_visible("Raw", false)?;
+/// graph.draw_svg(&mut svg_canvas);
+/// ```
+pub trait SeriesVisibility {
+    /// Returns the label and the visibility flag of every series, in order.
+    fn series_visibility(&mut self) -> Vec<(&str, &mut bool)>;
+
+    /// Refits the axis ranges to the visible series after series are shown or hidden.
+    ///
+    /// Figures that compute their ranges while drawing keep the default, which does nothing;
+    /// figures that store their ranges recompute them here.
+    fn refit_ranges(&mut self) {}
+
+    /// Shows or hides the series with a label and refits the axis ranges.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the series.
+    /// - `visible`: Whether they are shown.
+    ///
+    /// # Errors
+    /// Returns an error if no series has the label.
+    fn set_visible(&mut self, label: &str, visible: bool) -> Result<(), String> {
+        let mut found = false;
+        for (_, flag) in self
+            .series_visibility()
+            .into_iter()
+            .filter(|(series_label, _)| *series_label == label)
+        {
+            *flag = visible;
+            found = true;
+        }
+        if !found {
+            return Err(format!("No dataset labelled '{label}'"));
+        }
+        self.refit_ranges();
+        Ok(())
+    }
+}
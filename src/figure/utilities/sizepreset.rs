@@ -0,0 +1,34 @@
+/// Named figure sizes for common destinations, each with a matching margin.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizePreset {
+    /// An A4 page in landscape orientation at 96 DPI (1123 × 794 pixels).
+    A4Landscape,
+    /// An A4 page in portrait orientation at 96 DPI (794 × 1123 pixels).
+    A4Portrait,
+    /// A full HD 16:9 presentation slide (1920 × 1080 pixels).
+    Slide16x9,
+    /// A link preview card for social networks (1200 × 630 pixels).
+    SocialCard,
+}
+
+impl SizePreset {
+    /// Returns the `(width, height)` of the preset in pixels.
+    pub fn size(&self) -> (u32, u32) {
+        match self {
+            SizePreset::A4Landscape => (1123, 794),
+            SizePreset::A4Portrait => (794, 1123),
+            SizePreset::Slide16x9 => (1920, 1080),
+            SizePreset::SocialCard => (1200, 630),
+        }
+    }
+
+    /// Returns the margin of the preset in pixels, large enough for the title, tick labels
+    /// and legend at the default font sizes.
+    pub fn margin(&self) -> u32 {
+        match self {
+            SizePreset::A4Landscape | SizePreset::A4Portrait => 80,
+            SizePreset::Slide16x9 => 120,
+            SizePreset::SocialCard => 70,
+        }
+    }
+}
@@ -0,0 +1,259 @@
+//! Descriptive statistics used by distribution figures such as strip, box and violin plots,
+//! and by the `DatasetStatistics` accessors of datasets.
+
+/// Computes a quantile of sorted data using linear interpolation between the closest ranks.
+///
+/// # Parameters
+/// - `sorted`: The data, sorted in ascending order.
+/// - `q`: The quantile to compute, between `0.0` and `1.0`.
+///
+/// # Returns
+/// The quantile value, or `None` if `sorted` is empty.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::statistics::quantile;
+///
+/// assert_eq!(quantile(&[1.0, 2.0, 3.0, 4.0], 0.5), Some(2.5));
+/// ```
+pub fn quantile(sorted: &[f64], q: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let position = q.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let fraction = position - lower as f64;
+    Some(sorted[lower] + (sorted[upper] - sorted[lower]) * fraction)
+}
+
+/// Computes the arithmetic mean of the finite values.
+///
+/// # Parameters
+/// - `values`: The values, in any order.
+///
+/// # Returns
+/// The mean, or `None` if there are no finite values.
+pub fn mean(values: &[f64]) -> Option<f64> {
+    let (sum, count) = values
+        .iter()
+        .filter(|v| v.is_finite())
+        .fold((0.0, 0usize), |(sum, count), v| (sum + v, count + 1));
+    (count > 0).then(|| sum / count as f64)
+}
+
+/// Computes the sample standard deviation (with `n - 1` degrees of freedom) of the finite
+/// values.
+///
+/// # Parameters
+/// - `values`: The values, in any order.
+///
+/// # Returns
+/// The standard deviation, or `None` if there are fewer than two finite values.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::statistics::std_dev;
+///
+/// assert_eq!(std_dev(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]), Some(2.138089935299395));
+/// ```
+pub fn std_dev(values: &[f64]) -> Option<f64> {
+    let finite: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    if finite.len() < 2 {
+        return None;
+    }
+    let mean = mean(&finite)?;
+    let variance =
+        finite.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (finite.len() - 1) as f64;
+    Some(variance.sqrt())
+}
+
+/// The five-number summary of a sample, with Tukey whiskers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoxStats {
+    /// The first quartile.
+    pub q1: f64,
+    /// The median.
+    pub median: f64,
+    /// The third quartile.
+    pub q3: f64,
+    /// The smallest sample within `1.5 * IQR` below `q1`.
+    pub lower_whisker: f64,
+    /// The largest sample within `1.5 * IQR` above `q3`.
+    pub upper_whisker: f64,
+}
+
+impl BoxStats {
+    /// Computes the box plot summary of a sample.
+    ///
+    /// Non-finite values are ignored. Samples outside the whiskers are outliers.
+    ///
+    /// # Parameters
+    /// - `samples`: The sample values, in any order.
+    ///
+    /// # Returns
+    /// The summary, or `None` if the sample has no finite values.
+    pub fn from_samples(samples: &[f64]) -> Option<Self> {
+        let mut sorted: Vec<f64> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let q1 = quantile(&sorted, 0.25)?;
+        let median = quantile(&sorted, 0.5)?;
+        let q3 = quantile(&sorted, 0.75)?;
+        let reach = 1.5 * (q3 - q1);
+
+        let lower_whisker = sorted
+            .iter()
+            .copied()
+            .find(|&v| v >= q1 - reach)
+            .unwrap_or(q1);
+        let upper_whisker = sorted
+            .iter()
+            .rev()
+            .copied()
+            .find(|&v| v <= q3 + reach)
+            .unwrap_or(q3);
+
+        Some(Self {
+            q1,
+            median,
+            q3,
+            lower_whisker,
+            upper_whisker,
+        })
+    }
+}
+
+/// Computes the bandwidth of a Gaussian kernel density estimate with Silverman's rule of
+/// thumb, `0.9 * min(std, IQR / 1.34) * n^(-1/5)`.
+///
+/// Non-finite values are ignored.
+///
+/// # Parameters
+/// - `samples`: The sample values, in any order.
+///
+/// # Returns
+/// The bandwidth, or `None` if the sample has fewer than two distinct finite values.
+pub fn silverman_bandwidth(samples: &[f64]) -> Option<f64> {
+    let mut sorted: Vec<f64> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let n = sorted.len() as f64;
+    if sorted.len() < 2 {
+        return None;
+    }
+
+    let std = std_dev(&sorted)?;
+    let iqr = quantile(&sorted, 0.75)? - quantile(&sorted, 0.25)?;
+    let spread = if iqr > 0.0 { std.min(iqr / 1.34) } else { std };
+    (spread > 0.0).then(|| 0.9 * spread * n.powf(-0.2))
+}
+
+/// Estimates the probability density of a sample with a Gaussian kernel, evaluated at
+/// evenly spaced values from the smallest to the largest sample.
+///
+/// Non-finite values are ignored.
+///
+/// # Parameters
+/// - `samples`: The sample values, in any order.
+/// - `bandwidth`: The standard deviation of the kernel; `silverman_bandwidth` when `None`.
+/// - `points`: The number of values the density is evaluated at, at least 2.
+///
+/// # Returns
+/// Pairs of `(value, density)` in ascending order of value, or an empty vector if the sample
+/// has fewer than two distinct finite values.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::statistics::kernel_density;
+///
+/// let density = kernel_density(&[1.0, 2.0, 2.5, 3.0, 7.0], None, 50);
+/// assert_eq!(density.first().map(|&(value, _)| value), Some(1.0));
+/// ```
+pub fn kernel_density(samples: &[f64], bandwidth: Option<f64>, points: usize) -> Vec<(f64, f64)> {
+    let finite: Vec<f64> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+    let min = finite.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = finite.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    kernel_density_between(&finite, bandwidth, (min, max), points)
+}
+
+/// Resolves the bandwidth of a Gaussian kernel density estimate: the given bandwidth if it
+/// is positive and finite, otherwise `silverman_bandwidth`.
+///
+/// # Parameters
+/// - `samples`: The sample values, in any order.
+/// - `bandwidth`: The requested bandwidth, if any.
+///
+/// # Returns
+/// The bandwidth, or `None` if none is given and the sample has fewer than two distinct
+/// finite values.
+pub fn kde_bandwidth(samples: &[f64], bandwidth: Option<f64>) -> Option<f64> {
+    bandwidth
+        .filter(|&bandwidth| bandwidth > 0.0 && bandwidth.is_finite())
+        .or_else(|| silverman_bandwidth(samples))
+}
+
+/// Estimates the probability density of a sample with a Gaussian kernel, evaluated at
+/// evenly spaced values over a given range.
+///
+/// Unlike `kernel_density`, the range may extend beyond the samples, so the tails of the
+/// estimate can be drawn down to zero.
+///
+/// # Parameters
+/// - `samples`: The sample values, in any order. Non-finite values are ignored.
+/// - `bandwidth`: The standard deviation of the kernel; `silverman_bandwidth` when `None`.
+/// - `range`: The `(start, end)` values the density is evaluated between.
+/// - `points`: The number of values the density is evaluated at, at least 2.
+///
+/// # Returns
+/// Pairs of `(value, density)` in ascending order of value, or an empty vector if the range
+/// is empty or no bandwidth can be resolved.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::statistics::kernel_density_between;
+///
+/// let density = kernel_density_between(&[1.0, 2.0, 2.5, 3.0, 7.0], Some(0.5), (-1.0, 9.0), 200);
+/// ```
+pub fn kernel_density_between(
+    samples: &[f64],
+    bandwidth: Option<f64>,
+    (min, max): (f64, f64),
+    points: usize,
+) -> Vec<(f64, f64)> {
+    let finite: Vec<f64> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+    let Some(bandwidth) = kde_bandwidth(&finite, bandwidth) else {
+        return Vec::new();
+    };
+    if finite.is_empty() || !min.is_finite() || !max.is_finite() || min >= max {
+        return Vec::new();
+    }
+
+    let points = points.max(2);
+    (0..points)
+        .map(|index| {
+            let value = min + (max - min) * index as f64 / (points - 1) as f64;
+            (value, gaussian_density_at(&finite, bandwidth, value))
+        })
+        .collect()
+}
+
+/// Evaluates a Gaussian kernel density estimate at a single value.
+///
+/// # Parameters
+/// - `samples`: The finite sample values, in any order.
+/// - `bandwidth`: The standard deviation of the kernel, positive.
+/// - `value`: The value the density is evaluated at.
+///
+/// # Returns
+/// The estimated density, `0.0` for an empty sample.
+pub fn gaussian_density_at(samples: &[f64], bandwidth: f64, value: f64) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let norm = 1.0 / (samples.len() as f64 * bandwidth * (2.0 * std::f64::consts::PI).sqrt());
+    samples
+        .iter()
+        .map(|sample| (-0.5 * ((value - sample) / bandwidth).powi(2)).exp())
+        .sum::<f64>()
+        * norm
+}
@@ -0,0 +1,97 @@
+//! Short-time Fourier transform of sampled signals, for spectrograms.
+
+use std::f64::consts::PI;
+
+/// Computes the magnitude spectrum of overlapping, Hann-windowed frames of a signal.
+///
+/// Frames start every `hop` samples; the last partial frame is dropped. Each frame keeps
+/// the `window_size / 2 + 1` non-negative frequency bins, from 0 Hz up to the Nyquist
+/// frequency, so bin `k` lies at `k * sample_rate / window_size`.
+///
+/// # Parameters
+/// - `samples`: The signal.
+/// - `window_size`: The frame length, a power of two.
+/// - `hop`: The distance between frame starts in samples.
+///
+/// # Returns
+/// The magnitudes indexed as `[frame][bin]`, or a message if `window_size` is not a power
+/// of two, `hop` is zero, or the signal is shorter than one frame.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::stft::stft;
+///
+/// let magnitudes = stft(&samples, 1024, 256)?;
+/// ```
+pub fn stft(samples: &[f64], window_size: usize, hop: usize) -> Result<Vec<Vec<f64>>, String> {
+    if !window_size.is_power_of_two() {
+        return Err(format!("Window size {window_size} is not a power of two"));
+    }
+    if hop == 0 {
+        return Err("Hop size must be positive".to_string());
+    }
+    if samples.len() < window_size {
+        return Err(format!(
+            "Signal of {} samples is shorter than the window size {window_size}",
+            samples.len()
+        ));
+    }
+
+    let window: Vec<f64> = (0..window_size)
+        .map(|n| 0.5 - 0.5 * (2.0 * PI * n as f64 / window_size as f64).cos())
+        .collect();
+
+    Ok(samples
+        .windows(window_size)
+        .step_by(hop)
+        .map(|frame| {
+            let mut buffer: Vec<(f64, f64)> = frame
+                .iter()
+                .zip(&window)
+                .map(|(sample, weight)| (sample * weight, 0.0))
+                .collect();
+            fft(&mut buffer);
+            buffer[..=window_size / 2]
+                .iter()
+                .map(|(re, im)| re.hypot(*im))
+                .collect()
+        })
+        .collect())
+}
+
+/// Transforms complex values `(re, im)` in place with an iterative radix-2 FFT.
+///
+/// The length of `buffer` must be a power of two.
+fn fft(buffer: &mut [(f64, f64)]) {
+    let n = buffer.len();
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            buffer.swap(i, j);
+        }
+    }
+
+    let mut length = 2;
+    while length <= n {
+        let angle = -2.0 * PI / length as f64;
+        for start in (0..n).step_by(length) {
+            for k in 0..length / 2 {
+                let (sin, cos) = (angle * k as f64).sin_cos();
+                let (re, im) = buffer[start + k + length / 2];
+                let odd = (re * cos - im * sin, re * sin + im * cos);
+                let even = buffer[start + k];
+                buffer[start + k] = (even.0 + odd.0, even.1 + odd.1);
+                buffer[start + k + length / 2] = (even.0 - odd.0, even.1 - odd.1);
+            }
+        }
+        length <<= 1;
+    }
+}
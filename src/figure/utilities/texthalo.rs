@@ -0,0 +1,128 @@
+//! Halos that keep labels readable over dense data, heatmaps and grid lines.
+
+/// A halo drawn behind a label, separating it from whatever lies underneath.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextHalo {
+    /// An outline around every glyph, as wide as `width` pixels, in `color`.
+    Outline {
+        /// The RGB color of the outline, usually the background color.
+        color: [u8; 3],
+        /// The width of the outline in pixels.
+        width: f64,
+    },
+    /// A rounded box behind the label, extending `padding` pixels beyond the text.
+    Pill {
+        /// The RGB fill color of the box.
+        color: [u8; 3],
+        /// Space between the text and the edge of the box, in pixels.
+        padding: f64,
+        /// The opacity of the box (0.0 to 1.0).
+        opacity: f64,
+    },
+}
+
+impl TextHalo {
+    /// Creates a white outline, 2 pixels wide.
+    pub fn outline() -> Self {
+        TextHalo::Outline {
+            color: [255, 255, 255],
+            width: 2.0,
+        }
+    }
+
+    /// Creates a white pill with 3 pixels of padding at 85% opacity.
+    pub fn pill() -> Self {
+        TextHalo::Pill {
+            color: [255, 255, 255],
+            padding: 3.0,
+            opacity: 0.85,
+        }
+    }
+
+    /// Returns the color of the halo, which the text it surrounds should contrast with.
+    pub fn color(&self) -> [u8; 3] {
+        match *self {
+            TextHalo::Outline { color, .. } | TextHalo::Pill { color, .. } => color,
+        }
+    }
+
+    /// Returns the halo with its width or padding multiplied by `factor`, for rendering at a
+    /// higher resolution.
+    pub fn scaled(&self, factor: f64) -> Self {
+        match *self {
+            TextHalo::Outline { color, width } => TextHalo::Outline {
+                color,
+                width: width * factor,
+            },
+            TextHalo::Pill {
+                color,
+                padding,
+                opacity,
+            } => TextHalo::Pill {
+                color,
+                padding: padding * factor,
+                opacity,
+            },
+        }
+    }
+
+    /// Adds the halo to the markup of an SVG `<text>` element.
+    ///
+    /// An outline becomes a stroke painted below the glyph fill; a pill becomes a rounded
+    /// `<rect>` inserted before the text.
+    ///
+    /// # Parameters
+    /// - `text_element`: The markup of the text element, starting with `<text`.
+    /// - `bounds`: The box covered by the text as `(x, y, width, height)`, in the user
+    ///   space of the SVG. It is only used by pills.
+    ///
+    /// # Returns
+    /// The markup of the text with its halo.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let halo = TextHalo::outline();
+    /// let markup = halo.apply_svg(r#"<text x="10" y="20">42</text>"#, (10.0, 8.0, 14.0, 12.0));
+    /// // <text stroke="rgb(255,255,255)" stroke-width="4.00" ... x="10" y="20">42</text>
+    /// ```
+    pub fn apply_svg(
+        &self,
+        text_element: &str,
+        (x, y, width, height): (f64, f64, f64, f64),
+    ) -> String {
+        match *self {
+            TextHalo::Outline { color, width } => text_element.replacen(
+                "<text",
+                &format!(
+                    // The stroke is centered on the glyph edges, so it is twice the halo width
+                    r#"<text stroke="rgb({},{},{})" stroke-width="{:.2}" stroke-linejoin="round" paint-order="stroke""#,
+                    color[0],
+                    color[1],
+                    color[2],
+                    2.0 * width
+                ),
+                1,
+            ),
+            TextHalo::Pill {
+                color,
+                padding,
+                opacity,
+            } => {
+                let (box_width, box_height) = (width + 2.0 * padding, height + 2.0 * padding);
+                format!(
+                    r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" rx="{:.2}" fill="rgb({},{},{})" fill-opacity="{:.2}"/>{}"#,
+                    x - padding,
+                    y - padding,
+                    box_width,
+                    box_height,
+                    box_width.min(box_height) / 2.0,
+                    color[0],
+                    color[1],
+                    color[2],
+                    opacity,
+                    text_element
+                )
+            }
+        }
+    }
+}
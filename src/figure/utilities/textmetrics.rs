@@ -0,0 +1,230 @@
+use ab_glyph::{Font, FontRef, PxScale};
+use imageproc::drawing::text_size;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+/// Loaded font files, keyed by path. Font data is kept for the lifetime of the program
+/// so the returned `FontRef`s can be shared freely between drawers.
+static FONT_CACHE: OnceLock<Mutex<HashMap<String, &'static [u8]>>> = OnceLock::new();
+
+/// Loads a font from disk, reusing the cached data on subsequent calls.
+///
+/// # Parameters
+/// - `font_path`: Path to a TrueType or OpenType font file.
+///
+/// # Returns
+/// A `FontRef` backed by the cached font data.
+///
+/// # Panics
+/// Panics if the font file cannot be read or parsed.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::textmetrics::load_font;
+///
+/// let font = load_font("fonts/Arial.ttf");
+/// ```
+pub fn load_font(font_path: &str) -> FontRef<'static> {
+    FontRef::try_from_slice(load_font_data(font_path)).expect("Failed to parse font file")
+}
+
+/// Loads the raw bytes of a font file, reusing the cached data on subsequent calls.
+///
+/// # Parameters
+/// - `font_path`: Path to a TrueType or OpenType font file.
+///
+/// # Returns
+/// The font file contents.
+///
+/// # Panics
+/// Panics if the font file cannot be read.
+pub fn load_font_data(font_path: &str) -> &'static [u8] {
+    let cache = FONT_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut cache = cache.lock().expect("Font cache is poisoned");
+
+    cache.entry(font_path.to_string()).or_insert_with(|| {
+        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
+        Box::leak(font_bytes.into_boxed_slice())
+    })
+}
+
+/// Measures the rendered size of a text string.
+///
+/// # Parameters
+/// - `text`: The text to measure.
+/// - `font`: The font used for rendering.
+/// - `font_size`: The font size in pixels.
+///
+/// # Returns
+/// A tuple `(width, height)` in pixels.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::textmetrics::{load_font, measure_text};
+///
+/// let font = load_font("fonts/Arial.ttf");
+/// let (w, h) = measure_text("Revenue", &font, 12.0);
+/// ```
+pub fn measure_text(text: &str, font: &FontRef, font_size: f32) -> (u32, u32) {
+    let scale = PxScale {
+        x: font_size,
+        y: font_size,
+    };
+    text_size(scale, font, text)
+}
+
+/// Measures the rendered size of a text string using a font file path.
+///
+/// # Parameters
+/// - `text`: The text to measure.
+/// - `font_path`: Path to the font file (loaded through the font cache).
+/// - `font_size`: The font size in pixels.
+///
+/// # Returns
+/// A tuple `(width, height)` in pixels.
+///
+/// # Panics
+/// Panics if the font file cannot be read or parsed.
+///
+/// # Details
+/// With the `shaping` feature enabled, the width is the advance of the shaped text,
+/// matching what `PixelCanvas::draw_text_from_path` renders.
+pub fn measure_text_with_path(text: &str, font_path: &str, font_size: f32) -> (u32, u32) {
+    #[cfg(feature = "shaping")]
+    {
+        let (_, height) = measure_text(text, &load_font(font_path), font_size);
+        let (_, advance) =
+            super::textshaping::shape_text(text, load_font_data(font_path), font_size);
+        (advance.ceil() as u32, height)
+    }
+    #[cfg(not(feature = "shaping"))]
+    {
+        measure_text(text, &load_font(font_path), font_size)
+    }
+}
+
+/// Splits text into runs that can each be rendered with a single font.
+///
+/// Every character is assigned the first font in `font_path` followed by `fallbacks`
+/// that contains a glyph for it. Characters found in none of the fonts, as well as
+/// whitespace, stay with the font of the surrounding run.
+///
+/// # Parameters
+/// - `text`: The text to split.
+/// - `font_path`: Path to the primary font file.
+/// - `fallbacks`: Paths to fallback font files, in order of preference.
+///
+/// # Returns
+/// A list of `(text, font_path)` pairs covering `text` in order.
+///
+/// # Panics
+/// Panics if any of the font files cannot be read or parsed.
+pub fn split_font_runs<'a>(
+    text: &'a str,
+    font_path: &'a str,
+    fallbacks: &'a [String],
+) -> Vec<(&'a str, &'a str)> {
+    if fallbacks.is_empty() {
+        return vec![(text, font_path)];
+    }
+
+    let fonts: Vec<(&str, FontRef<'static>)> = std::iter::once(font_path)
+        .chain(fallbacks.iter().map(String::as_str))
+        .map(|path| (path, load_font(path)))
+        .collect();
+
+    let mut runs = Vec::new();
+    let mut run_start = 0;
+    let mut run_font = font_path;
+
+    for (index, ch) in text.char_indices() {
+        if ch.is_whitespace() {
+            continue;
+        }
+        let font = fonts
+            .iter()
+            .find(|(_, font)| font.glyph_id(ch).0 != 0)
+            .map_or(run_font, |(path, _)| *path);
+
+        if font != run_font {
+            if index > run_start {
+                runs.push((&text[run_start..index], run_font));
+            }
+            run_start = index;
+            run_font = font;
+        }
+    }
+    if run_start < text.len() || runs.is_empty() {
+        runs.push((&text[run_start..], run_font));
+    }
+
+    runs
+}
+
+/// Measures the rendered size of a text string, using fallback fonts for missing glyphs.
+///
+/// # Parameters
+/// - `text`: The text to measure.
+/// - `font_path`: Path to the primary font file.
+/// - `fallbacks`: Paths to fallback font files, in order of preference.
+/// - `font_size`: The font size in pixels.
+///
+/// # Returns
+/// A tuple `(width, height)` in pixels.
+///
+/// # Panics
+/// Panics if any of the font files cannot be read or parsed.
+pub fn measure_text_with_fallbacks(
+    text: &str,
+    font_path: &str,
+    fallbacks: &[String],
+    font_size: f32,
+) -> (u32, u32) {
+    split_font_runs(text, font_path, fallbacks)
+        .into_iter()
+        .map(|(run, path)| measure_text_with_path(run, path, font_size))
+        .fold((0, 0), |(width, height), (w, h)| (width + w, height.max(h)))
+}
+
+/// Shortens a text to fit a width, ending it with an ellipsis if it had to be cut.
+///
+/// # Parameters
+/// - `text`: The text to fit.
+/// - `max_width`: The available width in pixels.
+/// - `measure`: Measures the width of a text in pixels.
+///
+/// # Returns
+/// The whole text if it fits, otherwise its longest prefix that fits followed by `…`, or
+/// `None` if not even the ellipsis fits.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::figure::utilities::textmetrics::ellipsize;
+///
+/// let label = ellipsize("Engineering", 40.0, |text| text.chars().count() as f64 * 7.2);
+/// assert_eq!(label.as_deref(), Some("Engi…"));
+/// ```
+pub fn ellipsize(text: &str, max_width: f64, measure: impl Fn(&str) -> f64) -> Option<String> {
+    if measure(text) <= max_width {
+        return Some(text.to_string());
+    }
+    let ends: Vec<usize> = text.char_indices().map(|(index, _)| index).collect();
+    let fits = |count: usize| measure(&format!("{}…", &text[..ends[count]])) <= max_width;
+    if ends.is_empty() || !fits(0) {
+        return None;
+    }
+
+    // Longest prefix that fits, found by bisection as widths grow with the prefix
+    let (mut low, mut high) = (0, ends.len() - 1);
+    while low < high {
+        let middle = (low + high).div_ceil(2);
+        if fits(middle) {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    Some(format!("{}…", text[..ends[low]].trim_end()))
+}
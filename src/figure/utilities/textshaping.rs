@@ -0,0 +1,71 @@
+//! Complex text shaping and bidirectional layout, available with the `shaping` feature.
+//!
+//! Plain text rendering draws characters one after another in logical order, which breaks
+//! scripts that need contextual glyph forms (Arabic, Syriac) and right-to-left or
+//! mixed-direction strings (Hebrew, Arabic mixed with Latin digits). This module splits
+//! text into visual runs using the Unicode bidirectional algorithm and shapes every run
+//! with `rustybuzz`.
+
+use rustybuzz::{Direction, Face, UnicodeBuffer};
+use unicode_bidi::BidiInfo;
+
+/// A glyph positioned by the shaper.
+#[derive(Clone, Copy, Debug)]
+pub struct ShapedGlyph {
+    /// Glyph index inside the font.
+    pub glyph_id: u16,
+    /// Horizontal pen position of the glyph origin, in pixels from the start of the line.
+    pub x: f32,
+    /// Vertical offset from the baseline, in pixels (positive is up).
+    pub y: f32,
+}
+
+/// Shapes a single line of text into positioned glyphs in visual (left-to-right) order.
+///
+/// # Parameters
+/// - `text`: The text to shape. Line breaks are not handled.
+/// - `font_data`: The raw bytes of the font file.
+/// - `font_size`: The font size in pixels, interpreted like `ab_glyph::PxScale`
+///   (the distance from descent to ascent), so shaped text matches unshaped text.
+///
+/// # Returns
+/// A tuple of the positioned glyphs and the total advance width in pixels. If the font
+/// cannot be parsed, an empty glyph list is returned.
+pub fn shape_text(text: &str, font_data: &[u8], font_size: f32) -> (Vec<ShapedGlyph>, f32) {
+    let Some(face) = Face::from_slice(font_data, 0) else {
+        return (Vec::new(), 0.0);
+    };
+    let line_height = (face.ascender() - face.descender()) as f32;
+    let units_to_px = font_size / line_height;
+
+    let mut glyphs = Vec::new();
+    let mut pen_x = 0.0;
+
+    let bidi_info = BidiInfo::new(text, None);
+    for paragraph in &bidi_info.paragraphs {
+        let (levels, runs) = bidi_info.visual_runs(paragraph, paragraph.range.clone());
+
+        for run in runs {
+            let mut buffer = UnicodeBuffer::new();
+            buffer.push_str(&text[run.clone()]);
+            buffer.set_direction(if levels[run.start].is_rtl() {
+                Direction::RightToLeft
+            } else {
+                Direction::LeftToRight
+            });
+            buffer.guess_segment_properties();
+
+            let shaped = rustybuzz::shape(&face, &[], buffer);
+            for (info, position) in shaped.glyph_infos().iter().zip(shaped.glyph_positions()) {
+                glyphs.push(ShapedGlyph {
+                    glyph_id: info.glyph_id as u16,
+                    x: pen_x + position.x_offset as f32 * units_to_px,
+                    y: position.y_offset as f32 * units_to_px,
+                });
+                pen_x += position.x_advance as f32 * units_to_px;
+            }
+        }
+    }
+
+    (glyphs, pen_x)
+}
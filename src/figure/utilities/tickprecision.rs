@@ -0,0 +1,126 @@
+/// Largest number of decimals chosen by `TickPrecision::Auto`.
+pub const MAX_AUTO_DECIMALS: usize = 10;
+
+/// Controls how many decimals numeric tick labels show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TickPrecision {
+    /// Exactly the given number of decimals, trailing zeros included (e.g. `"0.50"`).
+    Fixed(usize),
+    /// At most the given number of decimals, with trailing zeros and a trailing decimal
+    /// point removed (e.g. `"0.5"` and `"2"`).
+    Trimmed(usize),
+    /// The fewest decimals that tell the ticks of an axis apart and show each tick value
+    /// accurately, shared by all ticks of the axis (e.g. `"0.0"`, `"0.3"`, `"0.6"`).
+    /// Rounding noise such as `0.30000000000000004` never reaches the label.
+    Auto,
+}
+
+impl TickPrecision {
+    /// Formats a single tick value.
+    ///
+    /// Without the other ticks of the axis, `Auto` uses the fewest decimals that represent
+    /// the value itself.
+    ///
+    /// # Parameters
+    /// - `value`: The tick value.
+    ///
+    /// # Returns
+    /// The formatted label, without a sign for values that round to zero.
+    pub fn format(&self, value: f64) -> String {
+        match *self {
+            TickPrecision::Fixed(decimals) => format_decimals(value, decimals),
+            TickPrecision::Trimmed(decimals) => trim_zeros(format_decimals(value, decimals)),
+            TickPrecision::Auto => {
+                let tolerance = 1e-9 * value.abs().max(1.0);
+                let decimals = (0..=MAX_AUTO_DECIMALS)
+                    .find(|&decimals| rounding_error(value, decimals) <= tolerance)
+                    .unwrap_or(MAX_AUTO_DECIMALS);
+                format_decimals(value, decimals)
+            }
+        }
+    }
+
+    /// Formats all ticks of an axis.
+    ///
+    /// # Parameters
+    /// - `values`: The tick values, in axis order.
+    ///
+    /// # Returns
+    /// One label per value.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::tickprecision::TickPrecision;
+    ///
+    /// let labels = TickPrecision::Auto.format_all(&[0.0, 0.1 + 0.2, 0.6]);
+    /// assert_eq!(labels, vec!["0.0", "0.3", "0.6"]);
+    /// ```
+    pub fn format_all(&self, values: &[f64]) -> Vec<String> {
+        let decimals = match *self {
+            TickPrecision::Auto => auto_decimals(values),
+            _ => return values.iter().map(|&value| self.format(value)).collect(),
+        };
+        values
+            .iter()
+            .map(|&value| format_decimals(value, decimals))
+            .collect()
+    }
+}
+
+/// Returns the fewest decimals at which the labels of distinct values differ and every
+/// label is off by less than 0.5% of the smallest gap between ticks.
+fn auto_decimals(values: &[f64]) -> usize {
+    let mut sorted: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    sorted.sort_by(f64::total_cmp);
+    let min_gap = sorted
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|&gap| gap > 1e-12 * largest_magnitude(&sorted))
+        .fold(f64::INFINITY, f64::min);
+    let tolerance = if min_gap.is_finite() {
+        min_gap * 0.005
+    } else {
+        1e-9 * largest_magnitude(&sorted)
+    };
+
+    (0..=MAX_AUTO_DECIMALS)
+        .find(|&decimals| {
+            sorted
+                .iter()
+                .all(|&value| rounding_error(value, decimals) <= tolerance)
+        })
+        .unwrap_or(MAX_AUTO_DECIMALS)
+}
+
+/// Returns the largest magnitude among the values, at least 1.
+fn largest_magnitude(values: &[f64]) -> f64 {
+    values
+        .iter()
+        .fold(1.0, |scale, value| scale.max(value.abs()))
+}
+
+/// Returns how far the value moves when shown with the given number of decimals.
+fn rounding_error(value: f64, decimals: usize) -> f64 {
+    let shown: f64 = format!("{value:.decimals$}").parse().unwrap_or(value);
+    (shown - value).abs()
+}
+
+/// Formats a value with a fixed number of decimals, dropping the sign of a rounded zero.
+fn format_decimals(value: f64, decimals: usize) -> String {
+    let label = format!("{value:.decimals$}");
+    match label.strip_prefix('-') {
+        Some(magnitude) if magnitude.chars().all(|c| c == '0' || c == '.') => magnitude.to_string(),
+        _ => label,
+    }
+}
+
+/// Removes trailing zeros after the decimal point, and the point itself if nothing follows.
+fn trim_zeros(label: String) -> String {
+    if !label.contains('.') {
+        return label;
+    }
+    label
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
@@ -0,0 +1,190 @@
+#[cfg(feature = "tzdb")]
+use std::sync::Arc;
+
+#[cfg(feature = "tzdb")]
+use crate::figure::utilities::tzdb::ZoneRules;
+
+/// Abbreviated month names used by the `%b` pattern.
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The time zone datetime tick labels are shown in.
+///
+/// Timestamps are Unix seconds (UTC); the zone decides the local wall-clock time they are
+/// labelled with.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimeZone {
+    /// Coordinated Universal Time.
+    Utc,
+    /// A constant offset from UTC in seconds, positive east of Greenwich (e.g. `3_600`
+    /// for UTC+01:00).
+    Fixed(i32),
+    /// A zone from the tz database (e.g. `"Europe/Berlin"`), following its daylight saving
+    /// and historical offset changes. Created with `TimeZone::named`.
+    #[cfg(feature = "tzdb")]
+    Named(Arc<ZoneRules>),
+}
+
+impl TimeZone {
+    /// Creates a zone with a constant offset from UTC.
+    ///
+    /// # Parameters
+    /// - `hours`, `minutes`: The offset, positive east of Greenwich. The sign of `hours`
+    ///   applies to `minutes` as well, so `fixed(-3, 30)` is UTC-03:30.
+    pub fn fixed(hours: i32, minutes: i32) -> Self {
+        let sign = if hours < 0 { -1 } else { 1 };
+        TimeZone::Fixed(hours * 3_600 + sign * minutes.abs() * 60)
+    }
+
+    /// Loads a zone from the tz database, read from the directory in the `TZDIR`
+    /// environment variable or `/usr/share/zoneinfo`.
+    ///
+    /// # Parameters
+    /// - `name`: The zone name, e.g. `"America/New_York"`.
+    ///
+    /// # Errors
+    /// Returns a message if the zone file is missing or malformed.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::{axistype::AxisType, timezone::TimeZone};
+    ///
+    /// config.set_time_zone(TimeZone::named("Europe/Berlin")?);
+    /// config.set_datetime_format(AxisType::AxisX, "%d %b\n%H:%M");
+    /// ```
+    #[cfg(feature = "tzdb")]
+    pub fn named(name: &str) -> Result<Self, String> {
+        ZoneRules::load(name).map(|rules| TimeZone::Named(Arc::new(rules)))
+    }
+
+    /// Returns the offset from UTC in effect at a time.
+    ///
+    /// # Parameters
+    /// - `timestamp`: The time in Unix seconds.
+    ///
+    /// # Returns
+    /// The offset in seconds, positive east of Greenwich.
+    #[cfg_attr(not(feature = "tzdb"), allow(unused_variables))]
+    pub fn offset_at(&self, timestamp: i64) -> i32 {
+        match self {
+            TimeZone::Utc => 0,
+            TimeZone::Fixed(offset) => *offset,
+            #[cfg(feature = "tzdb")]
+            TimeZone::Named(rules) => rules.local_time_type(timestamp).offset,
+        }
+    }
+
+    /// Returns the abbreviation of the zone at a time, e.g. `"UTC"`, `"+05:30"` or `"CEST"`.
+    ///
+    /// # Parameters
+    /// - `timestamp`: The time in Unix seconds.
+    #[cfg_attr(not(feature = "tzdb"), allow(unused_variables))]
+    pub fn abbreviation(&self, timestamp: i64) -> String {
+        match self {
+            TimeZone::Utc => "UTC".to_string(),
+            TimeZone::Fixed(offset) => format_offset(*offset),
+            #[cfg(feature = "tzdb")]
+            TimeZone::Named(rules) => rules.local_time_type(timestamp).abbreviation.clone(),
+        }
+    }
+
+    /// Formats a time as local wall-clock time in this zone.
+    ///
+    /// The pattern supports `%Y` (year), `%y` (two-digit year), `%m` (month), `%b` (month
+    /// name), `%d` (day), `%H` (hour), `%M` (minute), `%S` (second), `%z` (offset such as
+    /// `+01:00`), `%Z` (zone abbreviation) and `%%`. Other characters, including `\n` for
+    /// two-line labels, are copied.
+    ///
+    /// # Parameters
+    /// - `timestamp`: The time in Unix seconds; fractions are rounded down.
+    /// - `pattern`: The format pattern.
+    ///
+    /// # Returns
+    /// The formatted time, or an empty string for non-finite timestamps.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use dataviz::figure::utilities::timezone::TimeZone;
+    ///
+    /// let label = TimeZone::fixed(2, 0).format(1_700_000_000.0, "%Y-%m-%d %H:%M");
+    /// assert_eq!(label, "2023-11-15 00:13");
+    /// ```
+    pub fn format(&self, timestamp: f64, pattern: &str) -> String {
+        if !timestamp.is_finite() {
+            return String::new();
+        }
+        let timestamp = timestamp.floor() as i64;
+        let offset = self.offset_at(timestamp);
+        let local = timestamp + i64::from(offset);
+        let (days, seconds) = (local.div_euclid(86_400), local.rem_euclid(86_400));
+        let (year, month, day) = civil_from_days(days);
+
+        let mut label = String::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                label.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('Y') => label.push_str(&format!("{year:04}")),
+                Some('y') => label.push_str(&format!("{:02}", year.rem_euclid(100))),
+                Some('m') => label.push_str(&format!("{month:02}")),
+                Some('b') => label.push_str(MONTH_NAMES[month as usize - 1]),
+                Some('d') => label.push_str(&format!("{day:02}")),
+                Some('H') => label.push_str(&format!("{:02}", seconds / 3_600)),
+                Some('M') => label.push_str(&format!("{:02}", seconds % 3_600 / 60)),
+                Some('S') => label.push_str(&format!("{:02}", seconds % 60)),
+                Some('z') => label.push_str(&format_offset(offset)),
+                Some('Z') => label.push_str(&self.abbreviation(timestamp)),
+                Some('%') => label.push('%'),
+                Some(other) => {
+                    label.push('%');
+                    label.push(other);
+                }
+                None => label.push('%'),
+            }
+        }
+        label
+    }
+}
+
+/// Formats an offset from UTC as `+hh:mm`.
+fn format_offset(offset: i32) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let minutes = offset.abs() / 60;
+    format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// Converts days since 1970-01-01 to a `(year, month, day)` date in the proleptic
+/// Gregorian calendar (Howard Hinnant's algorithm).
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month as u32, day as u32)
+}
+
+/// Converts a date in the proleptic Gregorian calendar to days since 1970-01-01, the
+/// inverse of `civil_from_days`.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = i64::from((month + 9) % 12);
+    let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
@@ -0,0 +1,392 @@
+use std::path::PathBuf;
+
+use crate::figure::utilities::timezone::{civil_from_days, days_from_civil};
+
+/// Directory searched for zone files when `TZDIR` is not set.
+const DEFAULT_ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+/// An offset from UTC and its abbreviation, as used during some period of a zone.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalTimeType {
+    /// Offset from UTC in seconds, positive east of Greenwich.
+    pub offset: i32,
+    /// Whether the period is daylight saving time.
+    pub is_dst: bool,
+    /// Abbreviation such as `"CET"` or `"+0530"`.
+    pub abbreviation: String,
+}
+
+/// The day of the year a daylight saving rule switches on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RuleDay {
+    /// `Jn`: day 1 to 365, never counting February 29.
+    JulianNoLeap(u32),
+    /// `n`: day 0 to 365, counting February 29.
+    Julian(u32),
+    /// `Mm.w.d`: weekday `d` (0 = Sunday) of week `w` (5 = last) of month `m`.
+    MonthWeekDay(u32, u32, u32),
+}
+
+/// A rule switch: the day and the local time of day in seconds.
+type RuleTransition = (RuleDay, i32);
+
+/// The recurring rule from the footer of a zone file, used after its last transition.
+#[derive(Clone, Debug, PartialEq)]
+struct PosixRule {
+    standard: LocalTimeType,
+    /// Daylight saving time with its start and end.
+    daylight: Option<(LocalTimeType, RuleTransition, RuleTransition)>,
+}
+
+/// The offset history of a tz database zone, read from a compiled TZif file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZoneRules {
+    /// Transition times in Unix seconds, ascending, with the index of the type they start.
+    transitions: Vec<(i64, usize)>,
+    types: Vec<LocalTimeType>,
+    /// Rule for times after the last transition.
+    rule: Option<PosixRule>,
+}
+
+impl ZoneRules {
+    /// Loads a zone from the directory in `TZDIR` or `/usr/share/zoneinfo`.
+    ///
+    /// # Parameters
+    /// - `name`: The zone name, e.g. `"Asia/Kolkata"`.
+    ///
+    /// # Errors
+    /// Returns a message if the name is invalid or the file is missing or malformed.
+    pub fn load(name: &str) -> Result<Self, String> {
+        if name.is_empty() || name.starts_with('/') || name.split('/').any(|part| part == "..") {
+            return Err(format!("invalid time zone name \"{name}\""));
+        }
+        let directory = std::env::var_os("TZDIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_ZONEINFO_DIR));
+        let path = directory.join(name);
+        let data =
+            std::fs::read(&path).map_err(|error| format!("{}: {}", path.display(), error))?;
+        Self::parse(&data).map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    /// Parses the contents of a TZif file (RFC 8536), versions 1 to 4.
+    ///
+    /// # Errors
+    /// Returns a message if the data is not a valid TZif file.
+    pub fn parse(data: &[u8]) -> Result<Self, String> {
+        let header = Header::read(data)?;
+        if header.version == 0 {
+            return header.read_body(data, 44, 4);
+        }
+        // Version 2 and later repeat the data with 64-bit times after the version 1 block
+        let second = 44 + header.body_length(4);
+        let header = Header::read(data.get(second..).ok_or("truncated file")?)?;
+        let mut rules = header.read_body(data, second + 44, 8)?;
+        let footer = &data[(second + 44 + header.body_length(8)).min(data.len())..];
+        let footer = std::str::from_utf8(footer).map_err(|_| "invalid footer")?;
+        let footer = footer.trim_matches('\n');
+        if !footer.is_empty() {
+            rules.rule = Some(parse_posix_rule(footer)?);
+        }
+        Ok(rules)
+    }
+
+    /// Returns the offset and abbreviation in effect at a time.
+    ///
+    /// # Parameters
+    /// - `timestamp`: The time in Unix seconds.
+    pub fn local_time_type(&self, timestamp: i64) -> &LocalTimeType {
+        let after_last = self
+            .transitions
+            .last()
+            .is_none_or(|&(last, _)| timestamp >= last);
+        if let (true, Some(rule)) = (after_last, &self.rule) {
+            return rule.local_time_type(timestamp);
+        }
+        let index = self
+            .transitions
+            .partition_point(|&(time, _)| time <= timestamp);
+        match index.checked_sub(1) {
+            Some(index) => &self.types[self.transitions[index].1],
+            // Before the first transition the first type applies
+            None => &self.types[0],
+        }
+    }
+}
+
+/// The counts from a TZif header.
+struct Header {
+    version: u8,
+    is_ut_count: usize,
+    is_std_count: usize,
+    leap_count: usize,
+    time_count: usize,
+    type_count: usize,
+    char_count: usize,
+}
+
+impl Header {
+    fn read(data: &[u8]) -> Result<Self, String> {
+        if data.len() < 44 || &data[..4] != b"TZif" {
+            return Err("not a TZif file".to_string());
+        }
+        let count = |index: usize| {
+            let start = 20 + index * 4;
+            u32::from_be_bytes([
+                data[start],
+                data[start + 1],
+                data[start + 2],
+                data[start + 3],
+            ]) as usize
+        };
+        let header = Header {
+            version: data[4].saturating_sub(b'0'),
+            is_ut_count: count(0),
+            is_std_count: count(1),
+            leap_count: count(2),
+            time_count: count(3),
+            type_count: count(4),
+            char_count: count(5),
+        };
+        if header.type_count == 0 {
+            return Err("no local time types".to_string());
+        }
+        Ok(header)
+    }
+
+    /// Returns the length of the data block following the header.
+    fn body_length(&self, time_size: usize) -> usize {
+        self.time_count * (time_size + 1)
+            + self.type_count * 6
+            + self.char_count
+            + self.leap_count * (time_size + 4)
+            + self.is_std_count
+            + self.is_ut_count
+    }
+
+    /// Reads the transitions and local time types of the data block at `start`.
+    fn read_body(&self, data: &[u8], start: usize, time_size: usize) -> Result<ZoneRules, String> {
+        let body = data
+            .get(start..start + self.body_length(time_size))
+            .ok_or("truncated file")?;
+        let (times, rest) = body.split_at(self.time_count * time_size);
+        let (indices, rest) = rest.split_at(self.time_count);
+        let (type_records, rest) = rest.split_at(self.type_count * 6);
+        let designations = &rest[..self.char_count];
+
+        let transitions = times
+            .chunks(time_size)
+            .zip(indices)
+            .map(|(time, &index)| {
+                let time = if time_size == 8 {
+                    i64::from_be_bytes(time.try_into().expect("8-byte time"))
+                } else {
+                    i64::from(i32::from_be_bytes(time.try_into().expect("4-byte time")))
+                };
+                if usize::from(index) < self.type_count {
+                    Ok((time, usize::from(index)))
+                } else {
+                    Err("transition to an unknown type".to_string())
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let types = type_records
+            .chunks(6)
+            .map(|record| {
+                let offset = i32::from_be_bytes([record[0], record[1], record[2], record[3]]);
+                let start = usize::from(record[5]);
+                let designation = designations.get(start..).ok_or("invalid abbreviation")?;
+                let end = designation
+                    .iter()
+                    .position(|&byte| byte == 0)
+                    .unwrap_or(designation.len());
+                Ok(LocalTimeType {
+                    offset,
+                    is_dst: record[4] != 0,
+                    abbreviation: String::from_utf8_lossy(&designation[..end]).into_owned(),
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(ZoneRules {
+            transitions,
+            types,
+            rule: None,
+        })
+    }
+}
+
+impl PosixRule {
+    fn local_time_type(&self, timestamp: i64) -> &LocalTimeType {
+        let Some((daylight, start, end)) = &self.daylight else {
+            return &self.standard;
+        };
+        // Rules switch at local time: the start in standard time, the end in daylight time
+        let local = timestamp + i64::from(self.standard.offset);
+        let (year, _, _) = civil_from_days(local.div_euclid(86_400));
+        let start = rule_time(year, *start) - i64::from(self.standard.offset);
+        let end = rule_time(year, *end) - i64::from(daylight.offset);
+        let in_daylight = if start <= end {
+            (start..end).contains(&timestamp)
+        } else {
+            // Southern hemisphere: daylight saving time spans the new year
+            !(end..start).contains(&timestamp)
+        };
+        if in_daylight {
+            daylight
+        } else {
+            &self.standard
+        }
+    }
+}
+
+/// Returns the local time in seconds since the epoch at which a rule switches in `year`.
+fn rule_time(year: i64, (day, time): RuleTransition) -> i64 {
+    let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let january_first = days_from_civil(year, 1, 1);
+    let days = match day {
+        RuleDay::JulianNoLeap(day) => {
+            january_first + i64::from(day) - 1 + i64::from(leap && day >= 60)
+        }
+        RuleDay::Julian(day) => january_first + i64::from(day),
+        RuleDay::MonthWeekDay(month, week, weekday) => {
+            let first = days_from_civil(year, month, 1);
+            // 1970-01-01 was a Thursday (weekday 4)
+            let first_weekday = (first + 4).rem_euclid(7);
+            let mut days = first + (i64::from(weekday) - first_weekday).rem_euclid(7);
+            days += 7 * (i64::from(week) - 1);
+            let (next_year, next_month) = if month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+            while days >= days_from_civil(next_year, next_month, 1) {
+                days -= 7;
+            }
+            days
+        }
+    };
+    days * 86_400 + i64::from(time)
+}
+
+/// Parses a POSIX TZ string such as `CET-1CEST,M3.5.0,M10.5.0/3`.
+fn parse_posix_rule(text: &str) -> Result<PosixRule, String> {
+    let invalid = || format!("unsupported time zone rule \"{text}\"");
+    let mut rest = text;
+    let standard_name = take_name(&mut rest).ok_or_else(invalid)?;
+    // POSIX offsets count west of Greenwich, the opposite of TZif offsets
+    let standard_offset = -take_time(&mut rest).ok_or_else(invalid)?;
+    let standard = LocalTimeType {
+        offset: standard_offset,
+        is_dst: false,
+        abbreviation: standard_name,
+    };
+    if rest.is_empty() {
+        return Ok(PosixRule {
+            standard,
+            daylight: None,
+        });
+    }
+
+    let daylight_name = take_name(&mut rest).ok_or_else(invalid)?;
+    let daylight_offset = if rest.starts_with(',') {
+        standard_offset + 3_600
+    } else {
+        -take_time(&mut rest).ok_or_else(invalid)?
+    };
+    let transition = |rest: &mut &str| -> Option<RuleTransition> {
+        *rest = rest.strip_prefix(',')?;
+        let day = take_rule_day(rest)?;
+        let time = match rest.strip_prefix('/') {
+            Some(after) => {
+                *rest = after;
+                take_time(rest)?
+            }
+            None => 7_200,
+        };
+        Some((day, time))
+    };
+    let start = transition(&mut rest).ok_or_else(invalid)?;
+    let end = transition(&mut rest).ok_or_else(invalid)?;
+    if !rest.is_empty() {
+        return Err(invalid());
+    }
+    Ok(PosixRule {
+        standard,
+        daylight: Some((
+            LocalTimeType {
+                offset: daylight_offset,
+                is_dst: true,
+                abbreviation: daylight_name,
+            },
+            start,
+            end,
+        )),
+    })
+}
+
+/// Takes a zone abbreviation: letters, or any characters quoted in `<>`.
+fn take_name(rest: &mut &str) -> Option<String> {
+    let (name, after) = if let Some(quoted) = rest.strip_prefix('<') {
+        let end = quoted.find('>')?;
+        (&quoted[..end], &quoted[end + 1..])
+    } else {
+        let end = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        rest.split_at(end)
+    };
+    if name.len() < 3 {
+        return None;
+    }
+    *rest = after;
+    Some(name.to_string())
+}
+
+/// Takes a signed `hh[:mm[:ss]]` time and returns it in seconds.
+fn take_time(rest: &mut &str) -> Option<i32> {
+    let (sign, unsigned) = match rest.as_bytes().first() {
+        Some(b'-') => (-1, &rest[1..]),
+        Some(b'+') => (1, &rest[1..]),
+        _ => (1, *rest),
+    };
+    let end = unsigned
+        .find(|c: char| !c.is_ascii_digit() && c != ':')
+        .unwrap_or(unsigned.len());
+    let mut seconds = 0;
+    for (index, part) in unsigned[..end].split(':').enumerate() {
+        let value: i32 = part.parse().ok()?;
+        seconds += value * [3_600, 60, 1].get(index)?;
+    }
+    *rest = &unsigned[end..];
+    Some(sign * seconds)
+}
+
+/// Takes a `Jn`, `n` or `Mm.w.d` rule day.
+fn take_rule_day(rest: &mut &str) -> Option<RuleDay> {
+    let end = rest.find([',', '/']).unwrap_or(rest.len());
+    let (day, after) = rest.split_at(end);
+    let day = if let Some(julian) = day.strip_prefix('J') {
+        RuleDay::JulianNoLeap(julian.parse().ok().filter(|day| (1..=365).contains(day))?)
+    } else if let Some(month_week_day) = day.strip_prefix('M') {
+        let mut parts = month_week_day.split('.').map(|part| part.parse::<u32>());
+        let (month, week, weekday) = (
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+        );
+        if parts.next().is_some()
+            || !(1..=12).contains(&month)
+            || !(1..=5).contains(&week)
+            || weekday > 6
+        {
+            return None;
+        }
+        RuleDay::MonthWeekDay(month, week, weekday)
+    } else {
+        RuleDay::Julian(day.parse().ok().filter(|day| *day <= 365)?)
+    };
+    *rest = after;
+    Some(day)
+}
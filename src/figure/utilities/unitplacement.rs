@@ -0,0 +1,8 @@
+/// Controls where axis unit strings are displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnitPlacement {
+    /// The unit is appended to every tick label (e.g., `"250 ms"`).
+    TickLabels,
+    /// The unit is shown once, at the end of the axis.
+    AxisEnd,
+}
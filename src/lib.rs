@@ -18,19 +18,61 @@
 //! The main module that houses all types of charts and graphs. It includes:
 //! - **Chart Types**:
 //!   - [`areachart`](crate::figure::figuretypes::areachart): Create area charts for visualizing data trends.
+//!   - [`boxplot`](crate::figure::figuretypes::boxplot): Quartiles, whiskers and outliers per category, from raw samples or precomputed summaries.
+//!   - `choroplethmap`: Regions from GeoJSON filled by value (requires the `geojson` feature).
+//!   - [`bulletchart`](crate::figure::figuretypes::bulletchart): A single measure bar over qualitative range bands with a comparative marker, horizontal or vertical.
+//!   - [`candlestickchart`](crate::figure::figuretypes::candlestickchart): OHLC price candles with filled or hollow bodies and indicator overlays.
 //!   - [`cartesiangraph`](crate::figure::figuretypes::cartesiangraph): Cartesian graphs for mathematical and data plotting.
+//!   - [`chorddiagram`](crate::figure::figuretypes::chorddiagram): Square flow matrices as arcs around a circle joined by ribbons.
+//!   - [`contourplot`](crate::figure::figuretypes::contourplot): Iso-lines of gridded scalar fields at set or automatic levels, optionally with filled bands.
+//!   - [`correlationmatrix`](crate::figure::figuretypes::correlationmatrix): Annotated Pearson or Spearman correlation heatmaps.
+//!   - [`dendrogram`](crate::figure::figuretypes::dendrogram): Hierarchical clustering trees with labelled leaves, rooted at the top or on the left.
+//!   - [`densityplot`](crate::figure::figuretypes::densityplot): Smooth Gaussian kernel density curves of raw samples, drawn as filled areas.
+//!   - [`funnelchart`](crate::figure::figuretypes::funnelchart): Ordered stages drawn as stacked trapezoids or centered bars, with percentages of the first stage.
+//!   - [`ganttchart`](crate::figure::figuretypes::ganttchart): Task time spans as horizontal bars on a time axis, colored by group.
+//!   - [`gaugechart`](crate::figure::figuretypes::gaugechart): A single value shown by a needle on a circular dial with colored zones.
+//!   - [`geoscatter`](crate::figure::figuretypes::geoscatter): Longitude/latitude scatter plots over a projected basemap and coastlines.
 //!   - [`groupbarchart`](crate::figure::figuretypes::groupbarchart): Grouped bar charts for comparative data visualization.
+//!   - [`heatmap`](crate::figure::figuretypes::heatmap): Matrices of values drawn as colored cell grids with row and column labels and a color bar.
+//!   - [`hist2d`](crate::figure::figuretypes::hist2d): Large point clouds binned into rectangles or hexagons colored by count.
 //!   - [`histogram`](crate::figure::figuretypes::histogram): Histograms for frequency distribution analysis.
+//!   - [`marimekkochart`](crate::figure::figuretypes::marimekkochart): Mosaic charts where both cell width and height encode values.
+//!   - [`parallelcoordinates`](crate::figure::figuretypes::parallelcoordinates): Records drawn as translucent polylines across independently scaled vertical axes, colored by category.
 //!   - [`piechart`](crate::figure::figuretypes::piechart): Pie charts for proportional data representation.
+//!   - [`polargraph`](crate::figure::figuretypes::polargraph): Polar graphs of `(theta, r)` series over grid circles and angular spokes.
 //!   - [`quadrant1graph`](crate::figure::figuretypes::quadrant1graph): Graphs restricted to the first quadrant.
+//!   - [`quiverplot`](crate::figure::figuretypes::quiverplot): Vector fields drawn as arrows scaled to their spacing, optionally colored by magnitude.
+//!   - [`radarchart`](crate::figure::figuretypes::radarchart): Radar (spider) charts with one polygon per series over radially spread axes.
+//!   - [`ridgelineplot`](crate::figure::figuretypes::ridgelineplot): Density curves of several sample sets stacked as slightly overlapping rows over a shared value axis.
+//!   - [`sankeydiagram`](crate::figure::figuretypes::sankeydiagram): Flows between nodes in columns, drawn as ribbons as wide as the flow.
 //!   - [`scattergraph`](crate::figure::figuretypes::scattergraph): Scatter plots for individual data point visualization.
+//!   - [`spectrogram`](crate::figure::figuretypes::spectrogram): Time-frequency magnitude heatmaps with a decibel color scale.
+//!   - [`stemplot`](crate::figure::figuretypes::stemplot): Vertical stems from a baseline to each value, topped by markers, on Cartesian axes.
+//!   - [`streamgraph`](crate::figure::figuretypes::streamgraph): Series over time stacked as smooth layers around a zero, silhouette or wiggle baseline.
+//!   - [`stripplot`](crate::figure::figuretypes::stripplot): Jittered raw observations per category, with optional box overlays.
+//!   - [`swarmplot`](crate::figure::figuretypes::swarmplot): Beeswarm plots packing raw observations per category without overlap.
+//!   - [`sunburstchart`](crate::figure::figuretypes::sunburstchart): Hierarchical values as concentric rings, labelled along their arcs.
+//!   - [`ternaryplot`](crate::figure::figuretypes::ternaryplot): Three-component compositions plotted inside a triangular grid.
+//!   - [`treemap`](crate::figure::figuretypes::treemap): Hierarchical values as nested, squarified rectangles.
+//!   - [`violinplot`](crate::figure::figuretypes::violinplot): Mirrored kernel density estimates per category, with quartile and median overlays.
+//!   - [`waterfallchart`](crate::figure::figuretypes::waterfallchart): Running-total bars broken down into increases, decreases and totals, with connectors.
+//!   - [`waveform`](crate::figure::figuretypes::waveform): Long audio buffers drawn as a min/max envelope with an `m:ss` time axis.
+//!   - [`windrose`](crate::figure::figuretypes::windrose): Directional observations binned into sectors and stacked by magnitude class.
 //!
 //! ## Datasets
 //! Defines reusable and modular datasets for different chart types, enabling structured data representation. Includes:
 //! - [`areachartdataset`](crate::figure::datasets::areachartdataset)
 //! - [`bardataset`](crate::figure::datasets::bardataset)
+//! - [`boxplotdataset`](crate::figure::datasets::boxplotdataset)
+//! - [`candlestickdataset`](crate::figure::datasets::candlestickdataset)
 //! - [`cartesiangraphdataset`](crate::figure::datasets::cartesiangraphdataset)
+//! - [`categorydataset`](crate::figure::datasets::categorydataset)
+//! - [`densityplotdataset`](crate::figure::datasets::densityplotdataset)
+//! - [`radardataset`](crate::figure::datasets::radardataset)
 //! - [`scattergraphdataset`](crate::figure::datasets::scattergraphdataset)
+//! - [`segmentdataset`](crate::figure::datasets::segmentdataset)
+//! - [`ternarydataset`](crate::figure::datasets::ternarydataset)
+//! - [`violinplotdataset`](crate::figure::datasets::violinplotdataset)
 //! - [`dataset`](crate::figure::datasets::dataset): Common dataset traits for unifying data operations.
 //!
 //! ## Drawers
@@ -44,10 +86,62 @@
 //!
 //! ## Utilities
 //! Utility modules for managing chart attributes and behaviors. Includes:
+//! - [`angleunit`](crate::figure::utilities::angleunit): Degree or radian labels for angles.
+//! - [`arcgeometry`](crate::figure::utilities::arcgeometry): Ring segments, arc paths and text along arcs for pie-like figures.
+//! - [`areafill`](crate::figure::utilities::areafill): Filled bands between two lines, shared by area charts and streamgraphs.
+//! - [`arrowgeometry`](crate::figure::utilities::arrowgeometry): Shafts and heads of arrows, shared by both canvases.
+//! - [`autoscale`](crate::figure::utilities::autoscale): Axis ranges following live data: sliding windows, expanding-only and percentile-based ranges.
 //! - [`axistype`](crate::figure::utilities::axistype): Enum for axis types (X or Y).
+//! - [`barcolorrule`](crate::figure::utilities::barcolorrule): Bar colors computed from values by thresholds or a closure.
+//! - [`barlabelposition`](crate::figure::utilities::barlabelposition): Placement of bar value labels (inside or outside).
+//! - [`barstacking`](crate::figure::utilities::barstacking): Grouped, stacked or 100%-normalized bars per category.
+//! - [`basemap`](crate::figure::utilities::basemap): Equirectangular raster basemaps for geographic figures.
+//! - [`bezierpath`](crate::figure::utilities::bezierpath): Closed paths of lines and cubic Bézier curves, written as SVG paths or flattened for pixel canvases.
+//! - [`clustering`](crate::figure::utilities::clustering): Hierarchical clustering, leaf ordering and dendrogram layout.
+//! - [`colorbar`](crate::figure::utilities::colorbar): Room, width and plot area of the color bar right of colormapped figures.
+//! - [`colormap`](crate::figure::utilities::colormap): Value-to-color mapping, including a diverging scale centered at zero, and tints for nested levels.
+//! - [`colorvision`](crate::figure::utilities::colorvision): Simulated protanopia, deuteranopia and tritanopia, and checks for indistinguishable dataset colors.
+//! - [`cornerradii`](crate::figure::utilities::cornerradii): Per-corner radii and outlines of rounded rectangles.
+//! - [`correlation`](crate::figure::utilities::correlation): Pearson and Spearman coefficients and correlation matrices.
+//! - [`correlationmethod`](crate::figure::utilities::correlationmethod): Choice of correlation coefficient.
+//! - [`currencyformat`](crate::figure::utilities::currencyformat): Currency symbols placed before or after amounts.
+//! - [`datatransform`](crate::figure::utilities::datatransform): Render-time resampling, gap filling and smoothing pipelines for datasets.
+//! - [`densitythinning`](crate::figure::utilities::densitythinning): Render-time subsampling of dense scatter clusters that keeps sparse points and extremes.
+//! - [`divergingstyle`](crate::figure::utilities::divergingstyle): Baseline, colors and labels of diverging bar charts.
+//! - [`errorbars`](crate::figure::utilities::errorbars): Symmetric or asymmetric per-point x/y errors drawn as capped bars.
+//! - [`formatcontext`](crate::figure::utilities::formatcontext): Figure-wide decimal and grouping separators, percent style, currency and date format of labels.
+//! - `geojson`: Polygon features from GeoJSON documents (requires the `geojson` feature).
+//! - [`gridalignment`](crate::figure::utilities::gridalignment): Grid lines placed at the axis ticks or evenly divided.
+//! - [`histogramcomparison`](crate::figure::utilities::histogramcomparison): Overlaid or dodged bars when a histogram compares sample sets.
+//! - [`histogramnormalization`](crate::figure::utilities::histogramnormalization): Histogram bar heights as counts, relative frequencies or densities.
+//! - [`indicators`](crate::figure::utilities::indicators): Moving averages and Bollinger bands for financial overlays.
+//! - [`interpolation`](crate::figure::utilities::interpolation): Linear or step (pre, post, mid) connection of line points, and monotone cubic smoothing.
+//! - `json`: A minimal JSON reader used for GeoJSON and chart specs (requires the `geojson` or `cli` feature).
+//! - [`legendposition`](crate::figure::utilities::legendposition): Side of the plot a legend is placed on.
+//! - [`legendsummary`](crate::figure::utilities::legendsummary): Legend entries with last, mean, min and max value summaries.
 //! - [`linetype`](crate::figure::utilities::linetype): Styles for chart lines (solid, dashed, dotted).
+//! - [`linkage`](crate::figure::utilities::linkage): Linkage rules for hierarchical clustering (single, complete, average).
+//! - [`marchingsquares`](crate::figure::utilities::marchingsquares): Iso-line extraction and bilinear sampling of gridded fields.
 //! - [`orientation`](crate::figure::utilities::orientation): Orientation handling (horizontal or vertical).
+//! - [`packing`](crate::figure::utilities::packing): Non-overlapping marker packing for beeswarm layouts.
+//! - [`percentstyle`](crate::figure::utilities::percentstyle): Placement of the percent sign (attached, spaced or leading).
+//! - [`projection`](crate::figure::utilities::projection): Equirectangular and Mercator map projections.
+//! - [`random`](crate::figure::utilities::random): Seeded random numbers for reproducible layouts such as jitter.
+//! - [`referenceline`](crate::figure::utilities::referenceline): Mean or median lines drawn over a dataset.
+//! - [`scaling`](crate::figure::utilities::scaling): Shared data-to-pixel scaling and tick helpers for built-in and custom drawers.
 //! - [`scatterdottype`](crate::figure::utilities::scatterdottype): Dot styles for scatter plots (circle, square, triangle, etc.).
+//! - [`secondaryaxis`](crate::figure::utilities::secondaryaxis): Right-hand y-axes in other units, derived from the primary axis by a transform pair.
+//! - [`seriesvisibility`](crate::figure::utilities::seriesvisibility): Showing and hiding the series of a figure by label.
+//! - [`sizepreset`](crate::figure::utilities::sizepreset): Named canvas sizes and margins (A4, 16:9 slide, social card).
+//! - [`statistics`](crate::figure::utilities::statistics): Quantiles and box plot summaries.
+//! - `stft`: Short-time Fourier transform of sampled signals (requires the `stft` feature).
+//! - [`textmetrics`](crate::figure::utilities::textmetrics): Cached font loading, text measurement and ellipsis shortening for layout code.
+//! - [`texthalo`](crate::figure::utilities::texthalo): Outlines and pills behind labels drawn over busy backgrounds.
+//! - [`tickprecision`](crate::figure::utilities::tickprecision): Fixed, trimmed or automatic decimals of tick labels.
+//! - [`timezone`](crate::figure::utilities::timezone): UTC, fixed-offset and tz database zones for datetime tick labels.
+//! - `textshaping`: Complex script shaping and right-to-left layout (requires the `shaping` feature).
+//! - `tzdb`: Zone rules read from compiled tz database files (requires the `tzdb` feature).
+//! - [`unitplacement`](crate::figure::utilities::unitplacement): Placement of axis unit strings (tick labels or axis end).
 //!
 //! ## Configuration
 //! Centralized configuration for charts, providing a single source for appearance settings. Includes:
@@ -55,16 +149,37 @@
 //!
 //! ## Canvas
 //! Abstractions for rendering surfaces, including:
+//! - [`htmlexport`](crate::figure::canvas::htmlexport): Standalone HTML pages with tooltips and series toggling around SVG output.
+//! - [`pdfdocument`](crate::figure::canvas::pdfdocument): Minimal PDF writer for images, lines and text in the standard fonts.
 //! - [`pixelcanvas`](crate::figure::canvas::pixelcanvas): Raster-based rendering for charts.
+//! - [`pngmetadata`](crate::figure::canvas::pngmetadata): Title, description, creation time and figure spec embedded in PNG exports.
+//! - [`rasterizer`](crate::figure::canvas::rasterizer): Pluggable backends filling the shapes of a `PixelCanvas`, with the CPU scanline fill as default.
+//! - [`rendertarget`](crate::figure::canvas::rendertarget): The pixel or SVG canvas passed to render hooks.
+//! - [`report`](crate::figure::canvas::report): Multi-page PDF reports with numbered headings, captioned figures and a table of contents.
+//! - [`skiarasterizer`](crate::figure::canvas::skiarasterizer): Anti-aliased shape fills for raster output (`antialiasing` feature).
 //! - [`svgcanvas`](crate::figure::canvas::svgcanvas): Scalable vector graphics rendering for high-quality outputs.
+//! - [`svgelement`](crate::figure::canvas::svgelement): Typed SVG elements with ids and layers, inserted and removed without index bookkeeping.
 //!
 //! ## Display
 //! Modules for interactivity and display management. Includes:
 //! - Hover functionality for charts like:
 //!   - [`hovercartesian`](crate::figure::display::hovercartesian)
 //!   - [`hoverpiechart`](crate::figure::display::hoverpiechart), and more.
+//! - [`framebuffer`](crate::figure::display::framebuffer): Front and back window buffers for flicker-free live updates.
+//! - [`framethrottle`](crate::figure::display::framethrottle): Update rate, refresh limit and change detection of live displays.
+//! - [`linkedcursor`](crate::figure::display::linkedcursor): Stacked panels with a crosshair and x-range linked across figures.
+//! - [`viewstate`](crate::figure::display::viewstate): Axis-range bookmarks that can be encoded as text and applied to figures.
 //! - [`winop`](crate::figure::display::winop): Manage window operations for interactive displays.
 //!
+//! ## Templates
+//! - [`figuretemplate`](crate::figure::figuretemplate): Define a styled figure once and stamp out instances with different data.
+//!
+//! ## Composed Figures
+//! - [`figurelegend`](crate::figure::figurelegend): One legend for linked panels and overlays, with a heading per figure.
+//!
+//! ## Testing
+//! - [`imagediff`](crate::testing::imagediff): Tolerant image comparison, ignore regions and diff images for chart regression tests.
+//!
 //! ## Getting Started
 //! Here's a quick example of creating and rendering a pie chart:
 //!
@@ -87,38 +202,173 @@
 pub mod figure {
     pub mod figuretypes {
         pub mod areachart;
+        pub mod boxplot;
+        pub mod bulletchart;
+        pub mod candlestickchart;
         pub mod cartesiangraph;
+        pub mod chorddiagram;
+        #[cfg(feature = "geojson")]
+        pub mod choroplethmap;
+        pub mod contourplot;
+        pub mod correlationmatrix;
+        pub mod dendrogram;
+        pub mod densityplot;
+        pub mod funnelchart;
+        pub mod ganttchart;
+        pub mod gaugechart;
+        pub mod geoscatter;
         pub mod groupbarchart;
+        pub mod heatmap;
+        pub mod hist2d;
         pub mod histogram;
+        pub mod marimekkochart;
+        pub mod parallelcoordinates;
         pub mod piechart;
+        pub mod polargraph;
         pub mod quadrant1graph;
+        pub mod quiverplot;
+        pub mod radarchart;
+        pub mod ridgelineplot;
+        pub mod sankeydiagram;
         pub mod scattergraph;
+        pub mod spectrogram;
+        pub mod stemplot;
+        pub mod streamgraph;
+        pub mod stripplot;
+        pub mod sunburstchart;
+        pub mod swarmplot;
+        pub mod ternaryplot;
+        pub mod treemap;
+        pub mod violinplot;
+        pub mod waterfallchart;
+        pub mod waveform;
+        pub mod windrose;
     }
 
     pub mod datasets {
         pub mod areachartdataset;
         pub mod bardataset;
+        pub mod boxplotdataset;
+        pub mod candlestickdataset;
         pub mod cartesiangraphdataset;
+        pub mod categorydataset;
         pub mod dataset;
+        pub mod densityplotdataset;
+        pub mod radardataset;
         pub mod scattergraphdataset;
+        pub mod segmentdataset;
+        pub mod ternarydataset;
+        pub mod violinplotdataset;
     }
 
     pub mod drawers {
         pub mod drawer;
         pub mod drawerareachart;
         pub mod drawerbarchart;
+        pub mod drawerboxplot;
+        pub mod drawerbulletchart;
+        pub mod drawercandlestickchart;
         pub mod drawercartesiangraph;
+        pub mod drawerchorddiagram;
+        #[cfg(feature = "geojson")]
+        pub mod drawerchoroplethmap;
+        pub mod drawercontourplot;
+        pub mod drawercorrelationmatrix;
+        pub mod drawerdendrogram;
+        pub mod drawerdensityplot;
+        pub mod drawerfunnelchart;
+        pub mod drawerganttchart;
+        pub mod drawergaugechart;
+        pub mod drawergeoscatter;
+        pub mod drawerheatmap;
+        pub mod drawerhist2d;
         pub mod drawerhistogram;
+        pub mod drawermarimekkochart;
+        pub mod drawerparallelcoordinates;
         pub mod drawerpiechart;
+        pub mod drawerpolargraph;
         pub mod drawerquadrant1graph;
+        pub mod drawerquiverplot;
+        pub mod drawerradarchart;
+        pub mod drawerridgelineplot;
+        pub mod drawersankeydiagram;
         pub mod drawerscattergraph;
+        pub mod drawerspectrogram;
+        pub mod drawerstemplot;
+        pub mod drawerstreamgraph;
+        pub mod drawerstripplot;
+        pub mod drawersunburstchart;
+        pub mod drawerswarmplot;
+        pub mod drawerternaryplot;
+        pub mod drawertreemap;
+        pub mod drawerviolinplot;
+        pub mod drawerwaterfallchart;
+        pub mod drawerwaveform;
+        pub mod drawerwindrose;
     }
 
     pub mod utilities {
+        pub mod angleunit;
+        pub mod arcgeometry;
+        pub mod areafill;
+        pub mod arrowgeometry;
+        pub mod autoscale;
         pub mod axistype;
+        pub mod barcolorrule;
+        pub mod barlabelposition;
+        pub mod barstacking;
+        pub mod basemap;
+        pub mod bezierpath;
+        pub mod clustering;
+        pub mod colorbar;
+        pub mod colormap;
+        pub mod colorvision;
+        pub mod cornerradii;
+        pub mod correlation;
+        pub mod correlationmethod;
+        pub mod currencyformat;
+        pub mod datatransform;
+        pub mod densitythinning;
+        pub mod divergingstyle;
+        pub mod errorbars;
+        pub mod formatcontext;
+        #[cfg(feature = "geojson")]
+        pub mod geojson;
+        pub mod gridalignment;
+        pub mod histogramcomparison;
+        pub mod histogramnormalization;
+        pub mod indicators;
+        pub mod interpolation;
+        #[cfg(any(feature = "geojson", feature = "cli"))]
+        pub mod json;
+        pub mod legendposition;
+        pub mod legendsummary;
         pub mod linetype;
+        pub mod linkage;
+        pub mod marchingsquares;
         pub mod orientation;
+        pub mod packing;
+        pub mod percentstyle;
+        pub mod projection;
+        pub mod random;
+        pub mod referenceline;
+        pub mod scaling;
         pub mod scatterdottype;
+        pub mod secondaryaxis;
+        pub mod seriesvisibility;
+        pub mod sizepreset;
+        pub mod statistics;
+        #[cfg(feature = "stft")]
+        pub mod stft;
+        pub mod texthalo;
+        pub mod textmetrics;
+        #[cfg(feature = "shaping")]
+        pub mod textshaping;
+        pub mod tickprecision;
+        pub mod timezone;
+        #[cfg(feature = "tzdb")]
+        pub mod tzdb;
+        pub mod unitplacement;
     }
 
     pub mod configuration {
@@ -126,21 +376,75 @@ pub mod figure {
     }
 
     pub mod canvas {
+        pub mod htmlexport;
+        pub mod pdfdocument;
         pub mod pixelcanvas;
+        pub mod pngmetadata;
+        pub mod rasterizer;
+        pub mod rendertarget;
+        pub mod report;
+        #[cfg(feature = "antialiasing")]
+        pub mod skiarasterizer;
         pub mod svgcanvas;
+        pub mod svgelement;
     }
 
     pub mod display {
+        pub mod framebuffer;
+        pub mod framethrottle;
         pub mod hover;
         pub mod hoverareachart;
+        pub mod hoverboxplot;
+        pub mod hoverbulletchart;
+        pub mod hovercandlestickchart;
         pub mod hovercartesian;
+        pub mod hoverchorddiagram;
+        #[cfg(feature = "geojson")]
+        pub mod hoverchoroplethmap;
+        pub mod hovercontourplot;
+        pub mod hovercorrelationmatrix;
+        pub mod hoverdendrogram;
+        pub mod hoverdensityplot;
+        pub mod hoverfunnelchart;
+        pub mod hoverganttchart;
+        pub mod hovergaugechart;
+        pub mod hovergeoscatter;
         pub mod hovergroupbarchart;
+        pub mod hoverheatmap;
+        pub mod hoverhist2d;
         pub mod hoverhistogram;
+        pub mod hovermarimekkochart;
+        pub mod hoverparallelcoordinates;
         pub mod hoverpiechart;
+        pub mod hoverpolargraph;
         pub mod hoverquadrant1graph;
+        pub mod hoverquiverplot;
+        pub mod hoverradarchart;
+        pub mod hoverridgelineplot;
+        pub mod hoversankeydiagram;
         pub mod hoverscatterchart;
+        pub mod hoverspectrogram;
+        pub mod hoverstemplot;
+        pub mod hoverstreamgraph;
+        pub mod hoverstripplot;
+        pub mod hoversunburstchart;
+        pub mod hoverswarmplot;
+        pub mod hoverternaryplot;
+        pub mod hovertreemap;
+        pub mod hoverviolinplot;
+        pub mod hoverwaterfallchart;
+        pub mod hoverwaveform;
+        pub mod hoverwindrose;
+        pub mod linkedcursor;
+        pub mod viewstate;
         pub mod winop;
     }
 
     pub mod figurefactory;
+    pub mod figurelegend;
+    pub mod figuretemplate;
+}
+
+pub mod testing {
+    pub mod imagediff;
 }
@@ -0,0 +1,340 @@
+//! Image comparison for chart regression tests.
+//!
+//! Rendered charts are compared pixel by pixel against reference images. Small differences,
+//! such as anti-aliasing that varies between font rasterizers, are tolerated through a
+//! per-channel tolerance and a perceptual threshold, and regions that legitimately change
+//! between runs (timestamps, random jitter) can be masked out. When a comparison fails, a
+//! diff image highlighting the changed pixels can be written as a test artifact.
+
+use std::path::Path;
+
+use image::RgbImage;
+
+use crate::figure::canvas::pixelcanvas::PixelCanvas;
+
+/// Environment variable that makes `assert_matches_reference` write the rendered image as
+/// the new reference instead of comparing against it.
+pub const UPDATE_REFERENCES_VAR: &str = "DATAVIZ_UPDATE_REFERENCES";
+
+/// Largest possible YIQ distance between two colors, used to normalize the perceptual
+/// difference into `0.0..=1.0`.
+const MAX_YIQ_DELTA: f64 = 35215.0;
+
+/// Options controlling which pixel differences are accepted.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiffOptions {
+    /// Largest difference of a single color channel (0-255) that is ignored.
+    pub channel_tolerance: u8,
+    /// Largest perceived color difference that is ignored, from 0.0 (identical) to 1.0 (the
+    /// largest possible difference). Measured in the YIQ color space, which weighs
+    /// brightness changes more than hue changes, like the eye does.
+    pub perceptual_threshold: f64,
+    /// Number of differing pixels that still counts as a match.
+    pub max_different_pixels: usize,
+    /// Rectangles `(x, y, width, height)` excluded from the comparison.
+    pub ignore_regions: Vec<(u32, u32, u32, u32)>,
+}
+
+impl Default for DiffOptions {
+    /// Provides options that accept only anti-aliasing noise.
+    ///
+    /// # Default Values
+    /// - `channel_tolerance`: 2
+    /// - `perceptual_threshold`: 0.01
+    /// - `max_different_pixels`: 0
+    /// - `ignore_regions`: empty
+    fn default() -> Self {
+        Self {
+            channel_tolerance: 2,
+            perceptual_threshold: 0.01,
+            max_different_pixels: 0,
+            ignore_regions: Vec::new(),
+        }
+    }
+}
+
+impl DiffOptions {
+    /// Excludes a rectangle from the comparison.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: Coordinates of the top-left corner.
+    /// - `width`, `height`: Dimensions of the rectangle.
+    pub fn ignore_region(&mut self, x: u32, y: u32, width: u32, height: u32) {
+        self.ignore_regions.push((x, y, width, height));
+    }
+
+    /// Checks whether a pixel lies in an ignored region.
+    fn is_ignored(&self, x: u32, y: u32) -> bool {
+        self.ignore_regions
+            .iter()
+            .any(|&(left, top, width, height)| {
+                x >= left && x - left < width && y >= top && y - top < height
+            })
+    }
+}
+
+/// Whether a pixel matched, differed or was ignored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PixelStatus {
+    /// The pixel is equal within the tolerances.
+    Match,
+    /// The pixel differs beyond the tolerances.
+    Different,
+    /// The pixel lies in an ignored region.
+    Ignored,
+}
+
+/// The result of comparing two images.
+#[derive(Clone, Debug)]
+pub struct ImageDiff {
+    /// Width of the compared images in pixels.
+    pub width: u32,
+    /// Height of the compared images in pixels.
+    pub height: u32,
+    /// Number of pixels that differ beyond the tolerances.
+    pub different_pixels: usize,
+    /// Largest perceived difference of a compared pixel, from 0.0 to 1.0.
+    pub max_difference: f64,
+    /// Number of differing pixels that still counts as a match.
+    pub max_different_pixels: usize,
+    /// Status of every pixel, row by row.
+    pub statuses: Vec<PixelStatus>,
+    /// Bounding box `(x_min, y_min, x_max, y_max)` of the differing pixels, inclusive.
+    pub bounds: Option<(u32, u32, u32, u32)>,
+    /// The actual image, kept to render the diff image.
+    actual: Vec<u8>,
+}
+
+impl ImageDiff {
+    /// Returns `true` if no more than the accepted number of pixels differ.
+    pub fn is_match(&self) -> bool {
+        self.different_pixels <= self.max_different_pixels
+    }
+
+    /// Renders a diff image: the actual image faded to light gray, differing pixels in red
+    /// and ignored regions tinted yellow.
+    ///
+    /// # Returns
+    /// The RGB pixels, row by row.
+    pub fn diff_image(&self) -> Vec<u8> {
+        self.actual
+            .chunks_exact(3)
+            .zip(&self.statuses)
+            .flat_map(|(rgb, status)| match status {
+                PixelStatus::Different => [255, 0, 0],
+                PixelStatus::Ignored => [255, 240, 150],
+                PixelStatus::Match => {
+                    let gray = (luma(rgb) * 0.1 + 255.0 * 0.9) as u8;
+                    [gray, gray, gray]
+                }
+            })
+            .collect()
+    }
+
+    /// Saves the diff image as a test artifact.
+    ///
+    /// # Parameters
+    /// - `file_path`: The path to save the image; the format follows the extension.
+    ///
+    /// # Errors
+    /// Returns an error message if the image cannot be written.
+    pub fn save_diff_image(&self, file_path: &str) -> Result<(), String> {
+        let image = RgbImage::from_raw(self.width, self.height, self.diff_image())
+            .ok_or("Diff image buffer has the wrong size")?;
+        image
+            .save(file_path)
+            .map_err(|e| format!("Failed to save diff image {file_path}: {e}"))
+    }
+
+    /// Describes the result for test failure messages.
+    pub fn summary(&self) -> String {
+        let total = self.width as usize * self.height as usize;
+        let mut summary = format!(
+            "{} of {total} pixels differ (max difference {:.3})",
+            self.different_pixels, self.max_difference
+        );
+        if let Some((x_min, y_min, x_max, y_max)) = self.bounds {
+            summary.push_str(&format!(" within ({x_min}, {y_min})-({x_max}, {y_max})"));
+        }
+        summary
+    }
+}
+
+/// Compares two RGB images of the same size.
+///
+/// # Parameters
+/// - `expected`: The reference pixels, row by row, 3 bytes per pixel.
+/// - `actual`: The rendered pixels, in the same layout.
+/// - `width`, `height`: The size of both images in pixels.
+/// - `options`: The accepted differences.
+///
+/// # Returns
+/// The comparison result, or an error message if the buffers do not match the size.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::testing::imagediff::{compare, DiffOptions};
+///
+/// let diff = compare(&reference.buffer, &canvas.buffer, (800, 600), &DiffOptions::default())?;
+/// assert!(diff.is_match(), "{}", diff.summary());
+/// ```
+pub fn compare(
+    expected: &[u8],
+    actual: &[u8],
+    (width, height): (u32, u32),
+    options: &DiffOptions,
+) -> Result<ImageDiff, String> {
+    let len = width as usize * height as usize * 3;
+    if expected.len() != len || actual.len() != len {
+        return Err(format!(
+            "Image sizes differ: expected {} and actual {} bytes for {width}x{height} pixels",
+            expected.len(),
+            actual.len()
+        ));
+    }
+
+    let mut diff = ImageDiff {
+        width,
+        height,
+        different_pixels: 0,
+        max_difference: 0.0,
+        max_different_pixels: options.max_different_pixels,
+        statuses: Vec::with_capacity(len / 3),
+        bounds: None,
+        actual: actual.to_vec(),
+    };
+
+    for (index, (a, b)) in expected
+        .chunks_exact(3)
+        .zip(actual.chunks_exact(3))
+        .enumerate()
+    {
+        let x = (index % width as usize) as u32;
+        let y = (index / width as usize) as u32;
+        if options.is_ignored(x, y) {
+            diff.statuses.push(PixelStatus::Ignored);
+            continue;
+        }
+
+        let channel_delta = a.iter().zip(b).map(|(a, b)| a.abs_diff(*b)).max();
+        let difference = perceptual_delta(a, b);
+        diff.max_difference = diff.max_difference.max(difference);
+        if channel_delta.unwrap_or(0) > options.channel_tolerance
+            && difference > options.perceptual_threshold
+        {
+            diff.different_pixels += 1;
+            diff.statuses.push(PixelStatus::Different);
+            diff.bounds = Some(match diff.bounds {
+                None => (x, y, x, y),
+                Some((x0, y0, x1, y1)) => (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+            });
+        } else {
+            diff.statuses.push(PixelStatus::Match);
+        }
+    }
+    Ok(diff)
+}
+
+/// Compares a canvas against a reference image file.
+///
+/// # Parameters
+/// - `canvas`: The rendered canvas.
+/// - `reference_path`: The path of the reference image, e.g. a PNG saved earlier with
+///   `save_as_image`.
+/// - `options`: The accepted differences.
+///
+/// # Returns
+/// The comparison result, or an error message if the reference cannot be loaded or has
+/// another size than the canvas.
+pub fn compare_with_reference(
+    canvas: &PixelCanvas,
+    reference_path: &str,
+    options: &DiffOptions,
+) -> Result<ImageDiff, String> {
+    let reference = image::open(reference_path)
+        .map_err(|e| format!("Failed to load reference image {reference_path}: {e}"))?
+        .into_rgb8();
+    if reference.dimensions() != (canvas.width, canvas.height) {
+        return Err(format!(
+            "Reference image {reference_path} is {}x{} pixels, the canvas {}x{}",
+            reference.width(),
+            reference.height(),
+            canvas.width,
+            canvas.height
+        ));
+    }
+    compare(
+        reference.as_raw(),
+        &canvas.buffer,
+        (canvas.width, canvas.height),
+        options,
+    )
+}
+
+/// Asserts that a canvas matches its reference image, for use in regression tests.
+///
+/// On a mismatch the diff image is saved next to the reference, with `.diff.png` replacing
+/// the extension, and the rendered image with `.actual.png`. When the reference does not
+/// exist yet or the `DATAVIZ_UPDATE_REFERENCES` environment variable is set, the canvas is
+/// saved as the new reference instead.
+///
+/// # Parameters
+/// - `canvas`: The rendered canvas.
+/// - `reference_path`: The path of the reference PNG.
+/// - `options`: The accepted differences.
+///
+/// # Panics
+/// Panics with a summary of the differences if the canvas does not match, or if the
+/// reference cannot be read.
+///
+/// # Example
+/// ```rust,ignore
+/// use dataviz::testing::imagediff::{assert_matches_reference, DiffOptions};
+///
+/// let mut options = DiffOptions::default();
+/// options.ignore_region(0, 0, 800, 40); // title with the current date
+/// assert_matches_reference(&canvas, "tests/references/revenue.png", &options);
+/// ```
+pub fn assert_matches_reference(canvas: &PixelCanvas, reference_path: &str, options: &DiffOptions) {
+    if std::env::var_os(UPDATE_REFERENCES_VAR).is_some() || !Path::new(reference_path).exists() {
+        canvas.save_as_image(reference_path);
+        return;
+    }
+
+    let diff = compare_with_reference(canvas, reference_path, options)
+        .unwrap_or_else(|message| panic!("{message}"));
+    if !diff.is_match() {
+        let diff_path = artifact_path(reference_path, "diff");
+        let actual_path = artifact_path(reference_path, "actual");
+        diff.save_diff_image(&diff_path)
+            .unwrap_or_else(|message| panic!("{message}"));
+        canvas.save_as_image(&actual_path);
+        panic!(
+            "Canvas does not match {reference_path}: {}. Diff image: {diff_path}, rendered image: {actual_path}",
+            diff.summary()
+        );
+    }
+}
+
+/// Builds the path of a test artifact next to a reference image, e.g. `chart.diff.png`.
+fn artifact_path(reference_path: &str, kind: &str) -> String {
+    Path::new(reference_path)
+        .with_extension(format!("{kind}.png"))
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Computes the brightness of a color, from 0.0 to 255.0.
+fn luma(rgb: &[u8]) -> f64 {
+    0.299 * rgb[0] as f64 + 0.587 * rgb[1] as f64 + 0.114 * rgb[2] as f64
+}
+
+/// Computes the perceived difference of two colors in the YIQ color space, normalized to
+/// `0.0..=1.0`.
+fn perceptual_delta(a: &[u8], b: &[u8]) -> f64 {
+    let [r, g, b] = [0, 1, 2].map(|channel| a[channel] as f64 - b[channel] as f64);
+    let y = 0.298_895_31 * r + 0.586_622_47 * g + 0.114_482_23 * b;
+    let i = 0.595_977_99 * r - 0.274_176_10 * g - 0.321_801_89 * b;
+    let q = 0.211_470_17 * r - 0.522_617_20 * g + 0.311_147_03 * b;
+    (0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q) / MAX_YIQ_DELTA
+}
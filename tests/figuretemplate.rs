@@ -0,0 +1,47 @@
+use dataviz::figure::{
+    configuration::figureconfig::FigureConfig, datasets::bardataset::BarDataset,
+    figuretemplate::FigureTemplate, figuretypes::groupbarchart::GroupBarChart,
+    utilities::orientation::Orientation,
+};
+
+fn bar_chart(values: &[(f64, f64)]) -> GroupBarChart {
+    let mut chart = GroupBarChart::new(
+        "Sales",
+        "Month",
+        "Units",
+        Orientation::Vertical,
+        FigureConfig::default(),
+    );
+    let mut dataset = BarDataset::new("Store", [31, 119, 180]);
+    for &(x, y) in values {
+        dataset.add_data(x, y);
+    }
+    chart.add_dataset(dataset);
+    chart
+}
+
+#[test]
+fn group_bar_chart_template_keeps_value_limits() {
+    let mut template = bar_chart(&[]);
+    template.value_limits = Some((0.0, 500.0));
+
+    let mut dataset = BarDataset::new("Store", [31, 119, 180]);
+    dataset.add_data(1.0, 42.0);
+    let figure = template.instantiate(vec![dataset]);
+
+    assert_eq!(figure.value_limits, Some((0.0, 500.0)));
+    assert_eq!(figure.value_range(), (0.0, 500.0));
+}
+
+#[test]
+fn group_bar_chart_pages_keep_shared_range_through_template() {
+    let chart = bar_chart(&[(1.0, 10.0), (2.0, 20.0), (3.0, 300.0), (4.0, 5.0)]);
+    let pages = chart.pages(2);
+    let first = &pages[0];
+
+    let mut dataset = BarDataset::new("Store", [31, 119, 180]);
+    dataset.add_data(1.0, 10.0);
+    let figure = first.instantiate(vec![dataset]);
+
+    assert_eq!(figure.value_range(), chart.value_range());
+}